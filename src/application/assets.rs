@@ -0,0 +1,379 @@
+//! Per-ADR asset directories.
+//!
+//! Images and attachments belonging to an ADR live in a directory named
+//! after it, either next to the ADR (`adr-0042/diagram.png`) or under
+//! `assets/` (`assets/adr-0042/diagram.png`), and are referenced with
+//! relative links. Generate inlines them into the single-file viewer, wiki
+//! copies them next to the ADR pages, and validate reports references to
+//! files that do not exist. Generate also inlines images referenced from
+//! elsewhere relative to the ADR, such as `./images/diagram.png`, up to a
+//! size limit.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+use crate::domain::{Adr, ValidationIssue};
+use crate::error::Result;
+use crate::infrastructure::FileSystem;
+use crate::infrastructure::parser::MarkdownRenderer;
+
+/// Rule name and code of the error emitted for missing assets.
+const ASSET_EXISTS_RULE: (&str, &str) = ("asset-exists", "V0006");
+
+/// Rule name and code of the warning emitted for assets too large to inline.
+const INLINE_SIZE_RULE: (&str, &str) = ("inline-size", "V0009");
+
+/// Largest file, in bytes, inlined into the viewer unless configured.
+pub const DEFAULT_MAX_INLINE_SIZE: u64 = 1024 * 1024;
+
+/// An asset referenced from an ADR body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Asset {
+    /// Link destination as written in the markdown.
+    pub target: String,
+    /// Path relative to the directory holding the ADR.
+    pub relative: PathBuf,
+    /// Path of the asset on disk.
+    pub path: PathBuf,
+}
+
+/// Returns the assets an ADR references, without duplicates.
+pub fn referenced_assets(adr: &Adr) -> Vec<Asset> {
+    let Some(stem) = adr.source_path().file_stem().and_then(|s| s.to_str()) else {
+        return Vec::new();
+    };
+    let dir = adr.source_path().parent().unwrap_or_else(|| Path::new(""));
+
+    let mut assets: Vec<Asset> = Vec::new();
+    for target in MarkdownRenderer::new().link_destinations(adr.body_markdown()) {
+        if assets.iter().any(|asset| asset.target == target) {
+            continue;
+        }
+        if let Some(relative) = asset_path(&target, stem) {
+            assets.push(Asset {
+                path: dir.join(&relative),
+                target,
+                relative,
+            });
+        }
+    }
+    assets
+}
+
+/// Returns the images an ADR embeds with paths relative to it, such as
+/// `./images/diagram.png` or `../shared/logo.svg`, without duplicates.
+pub fn referenced_images(adr: &Adr) -> Vec<Asset> {
+    let dir = adr.source_path().parent().unwrap_or_else(|| Path::new(""));
+
+    let mut images: Vec<Asset> = Vec::new();
+    for target in MarkdownRenderer::new().image_destinations(adr.body_markdown()) {
+        if images.iter().any(|image| image.target == target) {
+            continue;
+        }
+        if let Some((relative, path)) = resolve_relative(dir, &target) {
+            images.push(Asset {
+                target,
+                relative,
+                path,
+            });
+        }
+    }
+    images
+}
+
+/// Reports assets referenced by an ADR that do not exist.
+pub fn missing_assets<F: FileSystem>(fs: &F, adr: &Adr) -> Vec<ValidationIssue> {
+    referenced_assets(adr)
+        .into_iter()
+        .filter(|asset| !fs.exists(&asset.path))
+        .map(|asset| {
+            ValidationIssue::error(
+                adr.source_path().clone(),
+                format!("referenced asset '{}' does not exist", asset.target),
+                ASSET_EXISTS_RULE.0,
+            )
+            .with_code(ASSET_EXISTS_RULE.1)
+        })
+        .collect()
+}
+
+/// Returns `data:` URLs for an ADR's existing assets and relative images,
+/// keyed by link target as written, for inlining them into the viewer.
+///
+/// Files larger than `max_size` bytes are left out and reported as warnings.
+///
+/// # Errors
+///
+/// Returns an error if an existing asset cannot be read.
+pub fn inline_urls<F: FileSystem>(
+    fs: &F,
+    adr: &Adr,
+    max_size: u64,
+) -> Result<(HashMap<String, String>, Vec<ValidationIssue>)> {
+    let mut assets = referenced_assets(adr);
+    for image in referenced_images(adr) {
+        if !assets.iter().any(|asset| asset.target == image.target) {
+            assets.push(image);
+        }
+    }
+
+    let mut urls = HashMap::new();
+    let mut warnings = Vec::new();
+    for asset in assets.into_iter().filter(|asset| fs.exists(&asset.path)) {
+        let bytes = fs.read_bytes(&asset.path)?;
+        let size = bytes.len() as u64;
+        if size > max_size {
+            warnings.push(
+                ValidationIssue::warning(
+                    adr.source_path().clone(),
+                    format!(
+                        "'{}' is {} KB, over the {} KB inline limit; leaving it as a link",
+                        asset.target,
+                        size.div_ceil(1024),
+                        max_size / 1024
+                    ),
+                    INLINE_SIZE_RULE.0,
+                )
+                .with_code(INLINE_SIZE_RULE.1),
+            );
+        } else {
+            urls.insert(asset.target, data_url(&asset.relative, &bytes));
+        }
+    }
+    Ok((urls, warnings))
+}
+
+/// Copies an ADR's existing assets below `output_dir`, keeping their paths
+/// relative to the ADR. Returns the paths written.
+///
+/// # Errors
+///
+/// Returns an error if an asset cannot be copied.
+pub fn copy_assets<F: FileSystem>(fs: &F, adr: &Adr, output_dir: &str) -> Result<Vec<String>> {
+    let mut copied = Vec::new();
+    for asset in referenced_assets(adr) {
+        if fs.exists(&asset.path) {
+            let dest = Path::new(output_dir).join(&asset.relative);
+            fs.copy(&asset.path, &dest)?;
+            copied.push(dest.to_string_lossy().into_owned());
+        }
+    }
+    Ok(copied)
+}
+
+/// Returns the path of a link target inside the ADR's asset directory.
+///
+/// Only relative targets starting with `<stem>/` or `assets/<stem>/` are
+/// assets; URLs, absolute paths, and paths leaving the directory are not.
+fn asset_path(target: &str, stem: &str) -> Option<PathBuf> {
+    let path = target.split(['?', '#']).next().unwrap_or_default();
+    let path = path.strip_prefix("./").unwrap_or(path);
+    if path.contains(':') || path.starts_with('/') {
+        return None;
+    }
+
+    let parts: Vec<&str> = path.split('/').collect();
+    let file = match parts.as_slice() {
+        [dir, file @ ..] if *dir == stem => file,
+        ["assets", dir, file @ ..] if *dir == stem => file,
+        _ => return None,
+    };
+    if file.is_empty() || file.iter().any(|part| matches!(*part, "" | "." | "..")) {
+        return None;
+    }
+
+    Some(PathBuf::from(path))
+}
+
+/// Resolves a relative link target against the directory holding the ADR,
+/// returning the target's path and the resolved path.
+///
+/// URLs, absolute paths, and paths climbing above `dir` are not resolved.
+pub fn resolve_relative(dir: &Path, target: &str) -> Option<(PathBuf, PathBuf)> {
+    let path = target.split(['?', '#']).next().unwrap_or_default();
+    if path.is_empty() || path.contains(':') || path.starts_with('/') {
+        return None;
+    }
+
+    let mut resolved = dir.to_path_buf();
+    for part in path.split('/') {
+        match part {
+            "" | "." => {},
+            ".." => {
+                if !resolved.pop() {
+                    return None;
+                }
+            },
+            part => resolved.push(part),
+        }
+    }
+    Some((PathBuf::from(path), resolved))
+}
+
+/// Encodes file contents as a `data:` URL.
+fn data_url(path: &Path, bytes: &[u8]) -> String {
+    format!(
+        "data:{};base64,{}",
+        media_type(path),
+        STANDARD.encode(bytes)
+    )
+}
+
+/// Guesses a file's media type from its extension.
+fn media_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "pdf" => "application/pdf",
+        "json" => "application/json",
+        "txt" | "md" => "text/plain",
+        "csv" => "text/csv",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+    use crate::infrastructure::{AdrParser, DefaultAdrParser};
+
+    const ADR: &str = "---\ntitle: Use SVG diagrams\n---\n\n\
+        ![Flow](adr-0042/flow.svg)\n\n\
+        ![Sequence](./assets/adr-0042/sequence.svg)\n\n\
+        [Missing](adr-0042/missing.pdf) [Other](adr-0001.md) [Web](https://example.com/adr-0042/x.png)\n";
+
+    fn parse(fs: &InMemoryFileSystem) -> Adr {
+        let path = Path::new("docs/decisions/adr-0042.md");
+        let content = fs.read_to_string(path).unwrap();
+        DefaultAdrParser::new().parse(path, &content).unwrap()
+    }
+
+    fn fixture() -> InMemoryFileSystem {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0042.md", ADR);
+        fs.add_file("docs/decisions/adr-0042/flow.svg", "<svg/>");
+        fs.add_file("docs/decisions/assets/adr-0042/sequence.svg", "<svg/>");
+        fs
+    }
+
+    #[test]
+    fn test_asset_path() {
+        assert_eq!(
+            asset_path("adr-0042/flow.svg", "adr-0042"),
+            Some(PathBuf::from("adr-0042/flow.svg"))
+        );
+        assert_eq!(
+            asset_path("./assets/adr-0042/a/b.png?raw=1", "adr-0042"),
+            Some(PathBuf::from("assets/adr-0042/a/b.png"))
+        );
+        assert_eq!(asset_path("adr-0001/flow.svg", "adr-0042"), None);
+        assert_eq!(asset_path("adr-0042/../secret.txt", "adr-0042"), None);
+        assert_eq!(asset_path("adr-0042/", "adr-0042"), None);
+        assert_eq!(
+            asset_path("https://example.com/adr-0042/x", "adr-0042"),
+            None
+        );
+        assert_eq!(asset_path("/adr-0042/flow.svg", "adr-0042"), None);
+    }
+
+    #[test]
+    fn test_referenced_and_missing_assets() {
+        let fs = fixture();
+        let adr = parse(&fs);
+
+        let targets: Vec<_> = referenced_assets(&adr)
+            .into_iter()
+            .map(|asset| asset.target)
+            .collect();
+        assert_eq!(
+            targets,
+            vec![
+                "adr-0042/flow.svg",
+                "./assets/adr-0042/sequence.svg",
+                "adr-0042/missing.pdf"
+            ]
+        );
+
+        let issues = missing_assets(&fs, &adr);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].code.as_deref(), Some("V0006"));
+        assert!(issues[0].message.contains("adr-0042/missing.pdf"));
+    }
+
+    #[test]
+    fn test_inline_urls() {
+        let fs = fixture();
+        let (urls, warnings) = inline_urls(&fs, &parse(&fs), DEFAULT_MAX_INLINE_SIZE).unwrap();
+
+        let url = format!("data:image/svg+xml;base64,{}", STANDARD.encode("<svg/>"));
+        assert_eq!(urls.len(), 2);
+        assert_eq!(urls["adr-0042/flow.svg"], url);
+        assert_eq!(urls["./assets/adr-0042/sequence.svg"], url);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_inline_relative_images() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0042.md",
+            "---\ntitle: Images\n---\n\n\
+             ![Flow](./images/flow.svg) ![Logo](../logo.svg) ![Large](images/large.png)\n",
+        );
+        fs.add_file("docs/decisions/images/flow.svg", "<svg/>");
+        fs.add_file("docs/logo.svg", "<svg/>");
+        fs.add_file("docs/decisions/images/large.png", "x".repeat(2048));
+
+        let (urls, warnings) = inline_urls(&fs, &parse(&fs), 1024).unwrap();
+
+        let mut targets: Vec<_> = urls.keys().collect();
+        targets.sort();
+        assert_eq!(targets, ["../logo.svg", "./images/flow.svg"]);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code.as_deref(), Some("V0009"));
+        assert!(warnings[0].message.contains("'images/large.png' is 2 KB"));
+    }
+
+    #[test]
+    fn test_resolve_relative() {
+        let dir = Path::new("docs/decisions");
+        assert_eq!(
+            resolve_relative(dir, "./images/flow.png?raw=1"),
+            Some((
+                PathBuf::from("./images/flow.png"),
+                PathBuf::from("docs/decisions/images/flow.png")
+            ))
+        );
+        assert_eq!(
+            resolve_relative(dir, "../../logo.png"),
+            Some((PathBuf::from("../../logo.png"), PathBuf::from("logo.png")))
+        );
+        assert_eq!(resolve_relative(dir, "../../../logo.png"), None);
+        assert_eq!(resolve_relative(dir, "https://example.com/a.png"), None);
+        assert_eq!(resolve_relative(dir, "/logo.png"), None);
+    }
+
+    #[test]
+    fn test_copy_assets() {
+        let fs = fixture();
+        let copied = copy_assets(&fs, &parse(&fs), "wiki").unwrap();
+
+        assert_eq!(copied.len(), 2);
+        assert!(fs.exists(Path::new("wiki/adr-0042/flow.svg")));
+        assert!(fs.exists(Path::new("wiki/assets/adr-0042/sequence.svg")));
+    }
+}
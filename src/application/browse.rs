@@ -0,0 +1,131 @@
+//! Browse ADRs use case.
+//!
+//! Loads ADRs for interactive browsing, e.g. by the terminal browser.
+
+use super::source::{LoadedAdrs, SourceOptions, load_adrs};
+use crate::domain::{Adr, ValidationIssue};
+use crate::error::Result;
+use crate::infrastructure::{DefaultAdrParser, FileSystem};
+
+/// Options for the browse command.
+#[derive(Debug, Clone)]
+pub struct BrowseOptions {
+    /// Input directory containing ADR files.
+    pub input_dir: String,
+    /// Glob pattern for matching ADR files.
+    pub pattern: String,
+    /// Where ADR files are discovered from.
+    pub source: SourceOptions,
+}
+
+impl Default for BrowseOptions {
+    fn default() -> Self {
+        Self {
+            input_dir: "docs/decisions".to_string(),
+            pattern: "**/*.md".to_string(),
+            source: SourceOptions::default(),
+        }
+    }
+}
+
+impl BrowseOptions {
+    /// Creates new options with the given input directory.
+    #[must_use]
+    pub fn new(input_dir: impl Into<String>) -> Self {
+        Self {
+            input_dir: input_dir.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the glob pattern for matching files.
+    #[must_use]
+    pub fn with_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.pattern = pattern.into();
+        self
+    }
+
+    /// Sets the ADR source options.
+    #[must_use]
+    pub fn with_source(mut self, source: SourceOptions) -> Self {
+        self.source = source;
+        self
+    }
+}
+
+/// Use case for loading ADRs to browse.
+#[derive(Debug)]
+pub struct BrowseUseCase<F: FileSystem> {
+    fs: F,
+    parser: DefaultAdrParser,
+}
+
+impl<F: FileSystem> BrowseUseCase<F> {
+    /// Creates a new browse use case.
+    #[must_use]
+    pub fn new(fs: F) -> Self {
+        Self {
+            fs,
+            parser: DefaultAdrParser::new(),
+        }
+    }
+
+    /// Executes the browse use case.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no ADR files are found.
+    pub fn execute(&self, options: &BrowseOptions) -> Result<BrowseResult> {
+        let LoadedAdrs {
+            adrs,
+            parse_errors,
+            warnings,
+            ..
+        } = load_adrs(
+            &self.fs,
+            &self.parser,
+            &options.input_dir,
+            &options.pattern,
+            &options.source,
+        )?;
+
+        Ok(BrowseResult {
+            adrs,
+            parse_errors,
+            warnings,
+        })
+    }
+}
+
+/// Result of the browse use case.
+#[derive(Debug)]
+pub struct BrowseResult {
+    /// Parsed ADRs, sorted by ID.
+    pub adrs: Vec<Adr>,
+    /// Files that failed to parse.
+    pub parse_errors: Vec<(std::path::PathBuf, crate::error::Error)>,
+    /// Non-fatal problems found while loading, such as colliding IDs.
+    pub warnings: Vec<ValidationIssue>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+
+    #[test]
+    fn test_browse_loads_sorted_adrs() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0002.md", "---\ntitle: Second\n---\n");
+        fs.add_file("docs/decisions/adr-0001.md", "---\ntitle: First\n---\n");
+        fs.add_file("docs/decisions/broken.md", "no frontmatter");
+
+        let result = BrowseUseCase::new(fs)
+            .execute(&BrowseOptions::new("docs/decisions"))
+            .unwrap();
+
+        let titles: Vec<_> = result.adrs.iter().map(Adr::title).collect();
+        assert_eq!(titles, vec!["First", "Second"]);
+        assert_eq!(result.parse_errors.len(), 1);
+    }
+}
@@ -0,0 +1,523 @@
+//! Build use case.
+//!
+//! Runs several outputs (validation reports, viewer, wiki, statistics) from a
+//! single load of the ADRs. Files whose content would not change are left
+//! untouched, so repeated builds are idempotent and every written file is
+//! reported as created, changed, or unchanged.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use super::source::{LoadedAdrs, SourceOptions, load_adrs};
+use super::{
+    GenerateOptions, GenerateResult, GenerateUseCase, StatsFormat, StatsOptions, StatsResult,
+    ValidateOptions, ValidateResult, ValidateUseCase, WikiOptions, WikiResult, WikiUseCase,
+};
+use crate::domain::ValidationIssue;
+use crate::error::{Error, Result};
+use crate::infrastructure::{BuildStep, DefaultAdrParser, FileSystem};
+
+/// Options for the build command.
+///
+/// Each step is run when its options are set. The input directory, pattern,
+/// and source of the step options are ignored in favor of the build's own.
+#[derive(Debug, Clone)]
+pub struct BuildOptions {
+    /// Input directory containing ADR files.
+    pub input_dir: String,
+    /// Glob pattern for matching ADR files.
+    pub pattern: String,
+    /// Where ADR files are discovered from.
+    pub source: SourceOptions,
+    /// Validation step.
+    pub validate: Option<ValidateOptions>,
+    /// Viewer generation step.
+    pub generate: Option<GenerateOptions>,
+    /// Wiki generation step.
+    pub wiki: Option<WikiOptions>,
+    /// Statistics step, with the format and path of its output file.
+    pub stats: Option<(StatsFormat, String)>,
+}
+
+impl Default for BuildOptions {
+    fn default() -> Self {
+        Self {
+            input_dir: "docs/decisions".to_string(),
+            pattern: "**/*.md".to_string(),
+            source: SourceOptions::default(),
+            validate: None,
+            generate: None,
+            wiki: None,
+            stats: None,
+        }
+    }
+}
+
+impl BuildOptions {
+    /// Creates options with the given input directory and no steps.
+    #[must_use]
+    pub fn new(input_dir: impl Into<String>) -> Self {
+        Self {
+            input_dir: input_dir.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the glob pattern for matching files.
+    #[must_use]
+    pub fn with_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.pattern = pattern.into();
+        self
+    }
+
+    /// Sets the ADR source options.
+    #[must_use]
+    pub fn with_source(mut self, source: SourceOptions) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Adds the validation step.
+    #[must_use]
+    pub fn with_validate(mut self, options: ValidateOptions) -> Self {
+        self.validate = Some(options);
+        self
+    }
+
+    /// Adds the viewer generation step.
+    #[must_use]
+    pub fn with_generate(mut self, options: GenerateOptions) -> Self {
+        self.generate = Some(options);
+        self
+    }
+
+    /// Adds the wiki generation step.
+    #[must_use]
+    pub fn with_wiki(mut self, options: WikiOptions) -> Self {
+        self.wiki = Some(options);
+        self
+    }
+
+    /// Adds the statistics step, writing to the given file.
+    #[must_use]
+    pub fn with_stats(mut self, format: StatsFormat, path: impl Into<String>) -> Self {
+        self.stats = Some((format, path.into()));
+        self
+    }
+}
+
+/// Use case for running several outputs from one load of the ADRs.
+#[derive(Debug)]
+pub struct BuildUseCase<F: FileSystem + Clone> {
+    fs: OutputTracker<F>,
+    parser: DefaultAdrParser,
+}
+
+impl<F: FileSystem + Clone> BuildUseCase<F> {
+    /// Creates a new build use case.
+    #[must_use]
+    pub fn new(fs: F) -> Self {
+        Self {
+            fs: OutputTracker::new(fs),
+            parser: DefaultAdrParser::new(),
+        }
+    }
+
+    /// Executes the build.
+    ///
+    /// Steps run in the order validate, generate, wiki, stats. A failing
+    /// validation does not stop the later steps; check
+    /// [`BuildResult::passed`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no ADR files are found, or a step fails to read
+    /// or write a file.
+    pub fn execute(&self, options: &BuildOptions) -> Result<BuildResult> {
+        let LoadedAdrs {
+            source_dir,
+            adrs,
+            mut parse_errors,
+            warnings,
+        } = load_adrs(
+            &self.fs,
+            &self.parser,
+            &options.input_dir,
+            &options.pattern,
+            &options.source,
+        )?;
+        // Parse errors cannot be copied, so only validation, which fails on
+        // them, receives them
+        let loaded = |parse_errors| LoadedAdrs {
+            source_dir: source_dir.clone(),
+            adrs: adrs.clone(),
+            parse_errors,
+            warnings: warnings.clone(),
+        };
+
+        let mut outputs = Vec::new();
+        let validate = options
+            .validate
+            .as_ref()
+            .map(|validate| {
+                ValidateUseCase::new(self.fs.clone())
+                    .execute_loaded(validate, loaded(std::mem::take(&mut parse_errors)))
+            })
+            .transpose()?;
+        self.finish_step(BuildStep::Validate, validate.is_some(), &mut outputs);
+
+        let generate = options
+            .generate
+            .as_ref()
+            .map(|generate| {
+                GenerateUseCase::new(self.fs.clone()).execute_loaded(generate, loaded(Vec::new()))
+            })
+            .transpose()?;
+        self.finish_step(BuildStep::Generate, generate.is_some(), &mut outputs);
+
+        let wiki = options
+            .wiki
+            .as_ref()
+            .map(|wiki| WikiUseCase::new(self.fs.clone()).execute_loaded(wiki, loaded(Vec::new())))
+            .transpose()?;
+        self.finish_step(BuildStep::Wiki, wiki.is_some(), &mut outputs);
+
+        let stats = options
+            .stats
+            .as_ref()
+            .map(|(format, path)| {
+                let stats = StatsResult::from_loaded(
+                    &StatsOptions::new(&options.input_dir).with_format(*format),
+                    loaded(Vec::new()),
+                );
+                self.fs
+                    .write(Path::new(path), &format!("{}\n", stats.output))?;
+                Ok::<_, Error>(stats)
+            })
+            .transpose()?;
+        self.finish_step(BuildStep::Stats, stats.is_some(), &mut outputs);
+
+        Ok(BuildResult {
+            adr_count: adrs.len(),
+            parse_errors,
+            warnings,
+            validate,
+            generate,
+            wiki,
+            stats,
+            outputs,
+        })
+    }
+
+    /// Records the files written by a step that ran.
+    fn finish_step(
+        &self,
+        step: BuildStep,
+        ran: bool,
+        outputs: &mut Vec<(BuildStep, Vec<BuildOutput>)>,
+    ) {
+        if ran {
+            outputs.push((step, self.fs.take()));
+        }
+    }
+}
+
+/// Whether a build output differs from the file it replaced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OutputStatus {
+    /// The file did not exist.
+    Created,
+    /// The file existed with different content.
+    Changed,
+    /// The file already had this content and was not rewritten.
+    Unchanged,
+}
+
+impl OutputStatus {
+    /// Returns the status name.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Changed => "changed",
+            Self::Unchanged => "unchanged",
+        }
+    }
+}
+
+/// A file written by a build step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildOutput {
+    /// Path of the file.
+    pub path: PathBuf,
+    /// Whether the file changed.
+    pub status: OutputStatus,
+}
+
+/// Result of the build use case.
+#[derive(Debug)]
+pub struct BuildResult {
+    /// Number of ADRs loaded.
+    pub adr_count: usize,
+    /// Files that failed to parse, unless the validate step ran; then they
+    /// are part of its result (see [`Self::load_errors`]).
+    pub parse_errors: Vec<(PathBuf, Error)>,
+    /// Non-fatal problems found while loading, such as colliding IDs.
+    pub warnings: Vec<ValidationIssue>,
+    /// Result of the validate step, if it ran.
+    pub validate: Option<ValidateResult>,
+    /// Result of the generate step, if it ran.
+    pub generate: Option<GenerateResult>,
+    /// Result of the wiki step, if it ran.
+    pub wiki: Option<WikiResult>,
+    /// Result of the stats step, if it ran.
+    pub stats: Option<StatsResult>,
+    /// Files written by each step that ran, in step order.
+    pub outputs: Vec<(BuildStep, Vec<BuildOutput>)>,
+}
+
+impl BuildResult {
+    /// Returns the files that failed to parse.
+    #[must_use]
+    pub fn load_errors(&self) -> &[(PathBuf, Error)] {
+        self.validate
+            .as_ref()
+            .map_or(&self.parse_errors, |validate| &validate.parse_errors)
+    }
+
+    /// Returns true unless the validate step ran and failed.
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.validate
+            .as_ref()
+            .is_none_or(|validate| validate.passed)
+    }
+
+    /// Returns the overall status of a step's outputs: created or changed if
+    /// any file was, unchanged otherwise.
+    #[must_use]
+    pub fn step_status(outputs: &[BuildOutput]) -> Option<OutputStatus> {
+        outputs.iter().map(|output| output.status).min()
+    }
+}
+
+/// File system wrapper that skips writes leaving a file's content as it is,
+/// and records the status of every written file.
+#[derive(Debug, Clone)]
+struct OutputTracker<F> {
+    inner: F,
+    written: Arc<Mutex<Vec<BuildOutput>>>,
+}
+
+impl<F: FileSystem> OutputTracker<F> {
+    fn new(inner: F) -> Self {
+        Self {
+            inner,
+            written: Arc::default(),
+        }
+    }
+
+    /// Returns and forgets the files written so far, sorted by path.
+    fn take(&self) -> Vec<BuildOutput> {
+        let mut written =
+            std::mem::take(&mut *self.written.lock().unwrap_or_else(PoisonError::into_inner));
+        written.sort_by(|a, b| a.path.cmp(&b.path));
+        written
+    }
+
+    fn record(&self, path: &Path, status: OutputStatus) {
+        self.written
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(BuildOutput {
+                path: path.to_path_buf(),
+                status,
+            });
+    }
+
+    fn status(&self, path: &Path, unchanged: impl FnOnce() -> bool) -> OutputStatus {
+        if !self.inner.exists(path) {
+            OutputStatus::Created
+        } else if unchanged() {
+            OutputStatus::Unchanged
+        } else {
+            OutputStatus::Changed
+        }
+    }
+}
+
+impl<F: FileSystem> FileSystem for OutputTracker<F> {
+    fn read_to_string(&self, path: &Path) -> Result<String> {
+        self.inner.read_to_string(path)
+    }
+
+    fn read_bytes(&self, path: &Path) -> Result<Vec<u8>> {
+        self.inner.read_bytes(path)
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> Result<()> {
+        let status = self.status(path, || {
+            self.inner
+                .read_to_string(path)
+                .is_ok_and(|existing| existing == contents)
+        });
+        if status != OutputStatus::Unchanged {
+            self.inner.write(path, contents)?;
+        }
+        self.record(path, status);
+        Ok(())
+    }
+
+    fn create(&self, path: &Path) -> Result<Box<dyn Write>> {
+        // The contents are not known up front, so an existing file counts as changed
+        let status = self.status(path, || false);
+        let file = self.inner.create(path)?;
+        self.record(path, status);
+        Ok(file)
+    }
+
+    fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+        let status = self.status(to, || {
+            match (self.inner.read_bytes(from), self.inner.read_bytes(to)) {
+                (Ok(source), Ok(existing)) => source == existing,
+                _ => false,
+            }
+        });
+        if status != OutputStatus::Unchanged {
+            self.inner.copy(from, to)?;
+        }
+        self.record(to, status);
+        Ok(())
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        self.inner.rename(from, to)
+    }
+
+    fn glob(&self, base: &Path, pattern: &str) -> Result<Vec<PathBuf>> {
+        self.inner.glob(base, pattern)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.inner.exists(path)
+    }
+
+    fn create_dir_all(&self, path: &Path) -> Result<()> {
+        self.inner.create_dir_all(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+
+    fn sample_adr_content() -> &'static str {
+        r"---
+title: Use PostgreSQL for persistence
+status: accepted
+category: database
+created: 2025-01-15
+description: We decided to use PostgreSQL as our primary database.
+---
+
+# Use PostgreSQL for persistence
+
+We will use PostgreSQL.
+"
+    }
+
+    fn options() -> BuildOptions {
+        BuildOptions::new("docs/decisions")
+            .with_validate(ValidateOptions::default())
+            .with_generate(
+                GenerateOptions::default()
+                    .with_output("out/adrs.html")
+                    .with_reproducible(true),
+            )
+            .with_wiki(WikiOptions::default().with_output_dir("out/wiki"))
+            .with_stats(StatsFormat::Json, "out/stats.json")
+    }
+
+    fn statuses(result: &BuildResult) -> Vec<(BuildStep, Option<OutputStatus>)> {
+        result
+            .outputs
+            .iter()
+            .map(|(step, outputs)| (*step, BuildResult::step_status(outputs)))
+            .collect()
+    }
+
+    #[test]
+    fn test_build_runs_all_steps() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", sample_adr_content());
+
+        let result = BuildUseCase::new(fs.clone()).execute(&options()).unwrap();
+
+        assert!(result.passed());
+        assert_eq!(result.adr_count, 1);
+        assert_eq!(result.generate.as_ref().unwrap().adr_count, 1);
+        assert_eq!(result.wiki.as_ref().unwrap().adr_count, 1);
+        assert_eq!(
+            statuses(&result),
+            vec![
+                (BuildStep::Validate, None),
+                (BuildStep::Generate, Some(OutputStatus::Created)),
+                (BuildStep::Wiki, Some(OutputStatus::Created)),
+                (BuildStep::Stats, Some(OutputStatus::Created)),
+            ]
+        );
+        assert!(
+            fs.read_to_string(Path::new("out/stats.json"))
+                .unwrap()
+                .contains("\"total_count\": 1")
+        );
+    }
+
+    #[test]
+    fn test_build_is_idempotent() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", sample_adr_content());
+        let use_case = BuildUseCase::new(fs.clone());
+        use_case.execute(&options()).unwrap();
+
+        let result = use_case.execute(&options()).unwrap();
+        assert!(
+            result
+                .outputs
+                .iter()
+                .flat_map(|(_, outputs)| outputs)
+                .all(|output| output.status == OutputStatus::Unchanged)
+        );
+
+        fs.add_file(
+            "docs/decisions/adr-0001.md",
+            sample_adr_content().replace("accepted", "deprecated"),
+        );
+        let result = use_case.execute(&options()).unwrap();
+        assert_eq!(
+            statuses(&result)[1],
+            (BuildStep::Generate, Some(OutputStatus::Changed))
+        );
+    }
+
+    #[test]
+    fn test_build_reports_parse_errors_once() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", sample_adr_content());
+        fs.add_file("docs/decisions/broken.md", "no frontmatter");
+
+        let result = BuildUseCase::new(fs.clone()).execute(&options()).unwrap();
+        assert!(!result.passed());
+        assert!(result.parse_errors.is_empty());
+        assert_eq!(result.load_errors().len(), 1);
+        assert!(result.generate.as_ref().unwrap().parse_errors.is_empty());
+
+        let options =
+            BuildOptions::new("docs/decisions").with_stats(StatsFormat::Text, "out/stats.txt");
+        let result = BuildUseCase::new(fs).execute(&options).unwrap();
+        assert!(result.passed());
+        assert_eq!(result.load_errors().len(), 1);
+    }
+}
@@ -0,0 +1,187 @@
+//! Shell completion use case.
+//!
+//! Scans the ADR source for the values offered by dynamic shell completion:
+//! ADR IDs, statuses, tags, and categories.
+
+use super::source::{LoadedAdrs, SourceOptions, load_adrs};
+use crate::domain::{FacetValue, Facets};
+use crate::error::Result;
+use crate::infrastructure::{DefaultAdrParser, FileSystem};
+
+/// Options for computing completions.
+#[derive(Debug, Clone)]
+pub struct CompleteOptions {
+    /// Input directory containing ADR files.
+    pub input_dir: String,
+    /// Glob pattern for matching ADR files.
+    pub pattern: String,
+    /// Where ADR files are discovered from.
+    pub source: SourceOptions,
+}
+
+impl Default for CompleteOptions {
+    fn default() -> Self {
+        Self {
+            input_dir: "docs/decisions".to_string(),
+            pattern: "**/*.md".to_string(),
+            source: SourceOptions::default(),
+        }
+    }
+}
+
+impl CompleteOptions {
+    /// Creates new options with the given input directory.
+    #[must_use]
+    pub fn new(input_dir: impl Into<String>) -> Self {
+        Self {
+            input_dir: input_dir.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the glob pattern for matching files.
+    #[must_use]
+    pub fn with_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.pattern = pattern.into();
+        self
+    }
+
+    /// Sets the ADR source options.
+    #[must_use]
+    pub fn with_source(mut self, source: SourceOptions) -> Self {
+        self.source = source;
+        self
+    }
+}
+
+/// Use case for computing shell completion values.
+#[derive(Debug)]
+pub struct CompleteUseCase<F: FileSystem> {
+    fs: F,
+    parser: DefaultAdrParser,
+}
+
+impl<F: FileSystem> CompleteUseCase<F> {
+    /// Creates a new completion use case.
+    #[must_use]
+    pub fn new(fs: F) -> Self {
+        Self {
+            fs,
+            parser: DefaultAdrParser::new(),
+        }
+    }
+
+    /// Executes the completion use case.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no ADR files are found.
+    pub fn execute(&self, options: &CompleteOptions) -> Result<Completions> {
+        let LoadedAdrs { adrs, .. } = load_adrs(
+            &self.fs,
+            &self.parser,
+            &options.input_dir,
+            &options.pattern,
+            &options.source,
+        )?;
+        let facets = Facets::from_adrs(&adrs);
+
+        Ok(Completions {
+            ids: adrs
+                .iter()
+                .map(|adr| Completion::new(adr.id().as_str(), adr.title()))
+                .collect(),
+            statuses: counted(facets.statuses),
+            tags: counted(facets.tags),
+            categories: counted(facets.categories),
+        })
+    }
+}
+
+/// A completion value with a short description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Completion {
+    /// The value inserted on the command line.
+    pub value: String,
+    /// Description shown by shells that support it.
+    pub help: String,
+}
+
+impl Completion {
+    /// Creates a completion value.
+    #[must_use]
+    pub fn new(value: impl Into<String>, help: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            help: help.into(),
+        }
+    }
+}
+
+/// Values offered by shell completion.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Completions {
+    /// ADR IDs, described by their titles.
+    pub ids: Vec<Completion>,
+    /// Statuses, described by how many ADRs have them.
+    pub statuses: Vec<Completion>,
+    /// Tags, described by how many ADRs have them.
+    pub tags: Vec<Completion>,
+    /// Categories, described by how many ADRs are in them.
+    pub categories: Vec<Completion>,
+}
+
+/// Turns facet values into completions described by their counts.
+fn counted(values: Vec<FacetValue>) -> Vec<Completion> {
+    values
+        .into_iter()
+        .map(|facet| {
+            let help = match facet.count {
+                1 => "1 ADR".to_string(),
+                count => format!("{count} ADRs"),
+            };
+            Completion::new(facet.value, help)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+
+    #[test]
+    fn test_completions() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0001.md",
+            "---\ntitle: Use PostgreSQL\nstatus: accepted\ncategory: database\ntags: [sql, storage]\n---\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            "---\ntitle: Use Redis\ncategory: database\ntags: [storage]\n---\n",
+        );
+
+        let completions = CompleteUseCase::new(fs)
+            .execute(&CompleteOptions::new("docs/decisions"))
+            .unwrap();
+
+        assert_eq!(
+            completions.ids,
+            vec![
+                Completion::new("adr-0001", "Use PostgreSQL"),
+                Completion::new("adr-0002", "Use Redis"),
+            ]
+        );
+        assert_eq!(
+            completions.categories,
+            vec![Completion::new("database", "2 ADRs")]
+        );
+        assert!(completions.tags.contains(&Completion::new("sql", "1 ADR")));
+        assert!(
+            completions
+                .statuses
+                .contains(&Completion::new("accepted", "1 ADR"))
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Links between ADRs.
+//!
+//! ADR bodies link to each other by relative file path, as they would on
+//! GitHub (`[see ADR 5](adr-0005.md#consequences)`). In the single-file
+//! viewer those files do not exist, so generate points such links at the
+//! viewer's deep link for the target ADR instead.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use super::assets::resolve_relative;
+use crate::domain::{Adr, AdrId};
+use crate::infrastructure::parser::MarkdownRenderer;
+use crate::infrastructure::renderer::{adr_fragment, section_fragment};
+
+/// Resolves relative links in ADR bodies to the ADRs they point at.
+#[derive(Debug)]
+pub struct CrossLinks {
+    ids: HashMap<PathBuf, AdrId>,
+}
+
+impl CrossLinks {
+    /// Creates a resolver over the given ADRs.
+    pub fn new(adrs: &[Adr]) -> Self {
+        let ids = adrs
+            .iter()
+            .map(|adr| (adr.source_path().clone(), adr.id().clone()))
+            .collect();
+        Self { ids }
+    }
+
+    /// Returns viewer deep links for the links in an ADR's body that point
+    /// at other ADRs, keyed by the link target as written.
+    ///
+    /// A fragment is kept as the section to open, since heading anchors
+    /// match GitHub's.
+    pub fn targets(&self, adr: &Adr) -> HashMap<String, String> {
+        let dir = adr.source_path().parent().unwrap_or_else(|| "".as_ref());
+
+        MarkdownRenderer::new()
+            .link_destinations(adr.body_markdown())
+            .into_iter()
+            .filter_map(|target| {
+                let (path, section) = target
+                    .split_once('#')
+                    .map_or((target.as_str(), None), |(path, section)| {
+                        (path, Some(section))
+                    });
+                let (_, resolved) = resolve_relative(dir, path)?;
+                let id = self.ids.get(&resolved)?;
+                let fragment = section
+                    .filter(|s| !s.is_empty())
+                    .map_or_else(|| adr_fragment(id), |section| section_fragment(id, section));
+                Some((target, format!("#{fragment}")))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::{AdrParser, DefaultAdrParser};
+    use std::path::Path;
+
+    fn parse(path: &str, content: &str) -> Adr {
+        DefaultAdrParser::new()
+            .parse(Path::new(path), content)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_cross_link_targets() {
+        let adrs = [
+            parse(
+                "docs/decisions/adr-0001.md",
+                "---\ntitle: One\n---\n\n\
+                 [Five](adr-0005.md) [Context](./adr-0005.md#context) \
+                 [Payments](../../payments/docs/decisions/adr-0003.md) \
+                 [Missing](adr-0009.md) [Readme](../README.md) [Web](https://example.com/adr-0005.md)\n",
+            ),
+            parse("docs/decisions/adr-0005.md", "---\ntitle: Five\n---\n"),
+            parse(
+                "payments/docs/decisions/adr-0003.md",
+                "---\ntitle: Three\n---\n",
+            )
+            .with_package("payments"),
+        ];
+
+        let targets = CrossLinks::new(&adrs).targets(&adrs[0]);
+
+        assert_eq!(targets.len(), 3);
+        assert_eq!(targets["adr-0005.md"], "#adr=adr-0005");
+        assert_eq!(targets["./adr-0005.md#context"], "#adr=adr-0005/context");
+        assert_eq!(
+            targets["../../payments/docs/decisions/adr-0003.md"],
+            "#adr=payments%2Fadr-0003"
+        );
+    }
+}
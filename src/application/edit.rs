@@ -0,0 +1,502 @@
+//! Bulk frontmatter edit use case.
+//!
+//! Applies the same frontmatter changes to every ADR matching a set of
+//! conditions, e.g. renaming a category across the whole collection. Files
+//! are edited in place, so formatting and comments outside the changed
+//! fields are kept.
+
+use std::path::PathBuf;
+
+use serde_yaml::Value;
+
+use super::source::{LoadedAdrs, SourceOptions, load_adrs};
+use crate::domain::{AdrId, Frontmatter};
+use crate::error::{Error, Result};
+use crate::infrastructure::parser::{read_fields, set_field};
+use crate::infrastructure::{DefaultAdrParser, FileSystem};
+
+/// How a change modifies a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// Replaces the value; list fields take a comma-separated value, which
+    /// replaces only the item a condition on the same field matched, or the
+    /// whole list without one.
+    Set,
+    /// Adds a value to a list field, unless it is already present.
+    Add,
+    /// Removes a value from a list field.
+    Remove,
+}
+
+/// A change to one frontmatter field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    /// How the field is modified.
+    pub kind: ChangeKind,
+    /// Name of the field.
+    pub field: String,
+    /// Value to set, add, or remove.
+    pub value: String,
+}
+
+impl FieldChange {
+    /// Creates a change of the given kind.
+    #[must_use]
+    pub fn new(kind: ChangeKind, field: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            kind,
+            field: field.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Applies the change to a field value, returning the new value.
+    ///
+    /// `matched` is the value a condition on the same field matched, whose
+    /// item alone a list `Set` replaces. Adding to or removing from a field
+    /// that is not a list leaves it unchanged.
+    fn apply(&self, current: Option<&Value>, matched: Option<&str>) -> Option<Value> {
+        let is_list = Frontmatter::LIST_FIELDS.contains(&self.field.as_str());
+        let items = || match current {
+            Some(Value::Sequence(items)) => items.clone(),
+            _ => Vec::new(),
+        };
+
+        match self.kind {
+            ChangeKind::Set if is_list => {
+                let values: Vec<&str> = split(&self.value).collect();
+                let Some(matched) = matched.filter(|matched| !matched.is_empty()) else {
+                    return Some(Value::Sequence(
+                        values.into_iter().map(Value::from).collect(),
+                    ));
+                };
+
+                let items = items();
+                let mut replaced = Vec::with_capacity(items.len() + values.len());
+                for item in &items {
+                    if !equals(item, matched) {
+                        replaced.push(item.clone());
+                        continue;
+                    }
+                    // Values the list already has are not added twice
+                    for value in &values {
+                        let mut existing = items
+                            .iter()
+                            .filter(|item| !equals(item, matched))
+                            .chain(&replaced);
+                        if !existing.any(|item| equals(item, value)) {
+                            replaced.push(Value::from(*value));
+                        }
+                    }
+                }
+                Some(Value::Sequence(replaced))
+            },
+            ChangeKind::Set => Some(Value::from(self.value.as_str())),
+            ChangeKind::Add if is_list => {
+                let mut items = items();
+                if !items.iter().any(|item| equals(item, &self.value)) {
+                    items.push(Value::from(self.value.as_str()));
+                }
+                Some(Value::Sequence(items))
+            },
+            ChangeKind::Remove if is_list => {
+                let mut items = items();
+                items.retain(|item| !equals(item, &self.value));
+                Some(Value::Sequence(items))
+            },
+            ChangeKind::Add | ChangeKind::Remove => current.cloned(),
+        }
+    }
+}
+
+/// Options for the edit command.
+#[derive(Debug, Clone)]
+pub struct EditOptions {
+    /// Input directory containing ADR files.
+    pub input_dir: String,
+    /// Glob pattern for matching ADR files.
+    pub pattern: String,
+    /// Where ADR files are discovered from.
+    pub source: SourceOptions,
+    /// `(field, value)` conditions an ADR must all satisfy to be edited.
+    pub conditions: Vec<(String, String)>,
+    /// Changes applied to every matching ADR, in order.
+    pub changes: Vec<FieldChange>,
+    /// Whether to only report the changes without writing them.
+    pub dry_run: bool,
+}
+
+impl Default for EditOptions {
+    fn default() -> Self {
+        Self {
+            input_dir: "docs/decisions".to_string(),
+            pattern: "**/*.md".to_string(),
+            source: SourceOptions::default(),
+            conditions: Vec::new(),
+            changes: Vec::new(),
+            dry_run: false,
+        }
+    }
+}
+
+impl EditOptions {
+    /// Creates new options with the given input directory.
+    #[must_use]
+    pub fn new(input_dir: impl Into<String>) -> Self {
+        Self {
+            input_dir: input_dir.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the glob pattern for matching files.
+    #[must_use]
+    pub fn with_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.pattern = pattern.into();
+        self
+    }
+
+    /// Sets the ADR source options.
+    #[must_use]
+    pub fn with_source(mut self, source: SourceOptions) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Only edits ADRs whose field has the given value.
+    ///
+    /// List fields match if they contain the value; an empty value matches a
+    /// missing or empty field. Values are compared ignoring case.
+    #[must_use]
+    pub fn with_condition(mut self, field: impl Into<String>, value: impl Into<String>) -> Self {
+        self.conditions.push((field.into(), value.into()));
+        self
+    }
+
+    /// Adds a change applied to every matching ADR.
+    #[must_use]
+    pub fn with_change(mut self, change: FieldChange) -> Self {
+        self.changes.push(change);
+        self
+    }
+
+    /// Sets whether to only report the changes.
+    #[must_use]
+    pub const fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+}
+
+/// Use case for editing the frontmatter of many ADRs at once.
+#[derive(Debug)]
+pub struct EditUseCase<F: FileSystem> {
+    fs: F,
+    parser: DefaultAdrParser,
+}
+
+impl<F: FileSystem> EditUseCase<F> {
+    /// Creates a new edit use case.
+    #[must_use]
+    pub fn new(fs: F) -> Self {
+        Self {
+            fs,
+            parser: DefaultAdrParser::new(),
+        }
+    }
+
+    /// Executes the edit use case.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no ADR files are found, or a matching file cannot
+    /// be read or written.
+    pub fn execute(&self, options: &EditOptions) -> Result<EditResult> {
+        let LoadedAdrs {
+            adrs, parse_errors, ..
+        } = load_adrs(
+            &self.fs,
+            &self.parser,
+            &options.input_dir,
+            &options.pattern,
+            &options.source,
+        )?;
+
+        let mut matched = 0;
+        let mut edits = Vec::new();
+        for adr in &adrs {
+            let path = adr.source_path();
+            let mut content = self.fs.read_to_string(path)?;
+            let Some(mut fields) = read_fields(&content) else {
+                continue;
+            };
+            if !options
+                .conditions
+                .iter()
+                .all(|(field, value)| matches(fields.get(field.as_str()), value))
+            {
+                continue;
+            }
+            matched += 1;
+
+            let mut changes = Vec::new();
+            for change in &options.changes {
+                let before = fields.get(change.field.as_str()).cloned();
+                let matched = options
+                    .conditions
+                    .iter()
+                    .find(|(field, _)| *field == change.field)
+                    .map(|(_, value)| value.as_str());
+                let Some(after) = change.apply(before.as_ref(), matched) else {
+                    continue;
+                };
+                if before.as_ref() == Some(&after) {
+                    continue;
+                }
+                content = set_field(&content, &change.field, &after).ok_or_else(|| {
+                    Error::InvalidFrontmatter {
+                        path: path.clone(),
+                        message: "missing or invalid frontmatter delimiters (--- or +++)"
+                            .to_string(),
+                    }
+                })?;
+                changes.push(ChangedField {
+                    field: change.field.clone(),
+                    before: before.as_ref().map(display).unwrap_or_default(),
+                    after: display(&after),
+                });
+                fields.insert(Value::from(change.field.as_str()), after);
+            }
+
+            if changes.is_empty() {
+                continue;
+            }
+            if !options.dry_run {
+                self.fs.write(path, &content)?;
+            }
+            edits.push(FileEdit {
+                id: adr.id().clone(),
+                path: path.clone(),
+                changes,
+            });
+        }
+
+        Ok(EditResult {
+            matched,
+            edits,
+            dry_run: options.dry_run,
+            parse_errors,
+        })
+    }
+}
+
+/// A field changed by the edit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangedField {
+    /// Name of the field.
+    pub field: String,
+    /// Value before the edit (empty if the field was missing).
+    pub before: String,
+    /// Value after the edit.
+    pub after: String,
+}
+
+/// The changes made to one ADR.
+#[derive(Debug, Clone)]
+pub struct FileEdit {
+    /// ID of the ADR.
+    pub id: AdrId,
+    /// Path of the ADR file.
+    pub path: PathBuf,
+    /// Changed fields, in the order the changes were given.
+    pub changes: Vec<ChangedField>,
+}
+
+/// Result of the edit use case.
+#[derive(Debug)]
+pub struct EditResult {
+    /// Number of ADRs matching the conditions.
+    pub matched: usize,
+    /// ADRs whose frontmatter changed (or would change, in a dry run).
+    pub edits: Vec<FileEdit>,
+    /// Whether the changes were only previewed.
+    pub dry_run: bool,
+    /// Files that failed to parse and were skipped.
+    pub parse_errors: Vec<(PathBuf, Error)>,
+}
+
+impl EditResult {
+    /// Returns true if there were any parse errors.
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        !self.parse_errors.is_empty()
+    }
+}
+
+/// Returns true if a field value satisfies a condition.
+fn matches(value: Option<&Value>, wanted: &str) -> bool {
+    match value {
+        None | Some(Value::Null) => wanted.is_empty(),
+        Some(Value::Sequence(items)) if wanted.is_empty() => items.is_empty(),
+        Some(Value::Sequence(items)) => items.iter().any(|item| equals(item, wanted)),
+        Some(value) => equals(value, wanted),
+    }
+}
+
+/// Compares a scalar value with a string, ignoring case.
+fn equals(value: &Value, wanted: &str) -> bool {
+    display(value).eq_ignore_ascii_case(wanted)
+}
+
+/// Formats a field value for matching and reporting.
+fn display(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n.to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Sequence(items) => items.iter().map(display).collect::<Vec<_>>().join(", "),
+        Value::Null | Value::Mapping(_) | Value::Tagged(_) => String::new(),
+    }
+}
+
+fn split(value: &str) -> impl Iterator<Item = &str> {
+    value.split(',').map(str::trim).filter(|v| !v.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+    use std::path::Path;
+
+    fn setup() -> InMemoryFileSystem {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0001.md",
+            "---\ntitle: One\ncategory: db\ntags:\n  - db\n  - sql\n---\n\nBody\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            "---\ntitle: Two\ncategory: DB # legacy\n---\n\nBody\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0003.md",
+            "---\ntitle: Three\ncategory: api\n---\n\nBody\n",
+        );
+        fs
+    }
+
+    #[test]
+    fn test_edit_renames_category() {
+        let fs = setup();
+        let options = EditOptions::new("docs/decisions")
+            .with_condition("category", "db")
+            .with_change(FieldChange::new(ChangeKind::Set, "category", "database"));
+
+        let result = EditUseCase::new(fs.clone()).execute(&options).unwrap();
+
+        assert_eq!(result.matched, 2);
+        assert_eq!(result.edits.len(), 2);
+        assert_eq!(
+            result.edits[1].changes,
+            vec![ChangedField {
+                field: "category".to_string(),
+                before: "DB".to_string(),
+                after: "database".to_string(),
+            }]
+        );
+        assert_eq!(
+            fs.read_to_string(Path::new("docs/decisions/adr-0002.md"))
+                .unwrap(),
+            "---\ntitle: Two\ncategory: database\n---\n\nBody\n"
+        );
+        assert!(
+            fs.read_to_string(Path::new("docs/decisions/adr-0003.md"))
+                .unwrap()
+                .contains("category: api")
+        );
+    }
+
+    #[test]
+    fn test_edit_list_fields() {
+        let fs = setup();
+        let options = EditOptions::new("docs/decisions")
+            .with_condition("tags", "DB")
+            .with_change(FieldChange::new(ChangeKind::Remove, "tags", "db"))
+            .with_change(FieldChange::new(ChangeKind::Add, "tags", "database"))
+            .with_change(FieldChange::new(ChangeKind::Add, "tags", "sql"));
+
+        let result = EditUseCase::new(fs.clone()).execute(&options).unwrap();
+
+        assert_eq!(result.matched, 1);
+        let changes = &result.edits[0].changes;
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[1].before, "sql");
+        assert_eq!(changes[1].after, "sql, database");
+        assert!(
+            fs.read_to_string(Path::new("docs/decisions/adr-0001.md"))
+                .unwrap()
+                .contains("tags:\n  - sql\n  - database\n---")
+        );
+    }
+
+    #[test]
+    fn test_edit_set_replaces_matched_item() {
+        let fs = setup();
+        fs.add_file(
+            "docs/decisions/adr-0004.md",
+            "---\ntitle: Four\ntags:\n  - storage\n  - DB\n  - sql\n---\n\nBody\n",
+        );
+        let options = EditOptions::new("docs/decisions")
+            .with_condition("tags", "db")
+            .with_change(FieldChange::new(ChangeKind::Set, "tags", "database"));
+
+        let result = EditUseCase::new(fs.clone()).execute(&options).unwrap();
+
+        assert_eq!(result.matched, 2);
+        assert_eq!(result.edits[1].changes[0].before, "storage, DB, sql");
+        assert_eq!(result.edits[1].changes[0].after, "storage, database, sql");
+        assert!(
+            fs.read_to_string(Path::new("docs/decisions/adr-0001.md"))
+                .unwrap()
+                .contains("tags:\n  - database\n  - sql\n---")
+        );
+
+        // Changing only the case of the matched item keeps it
+        let options = EditOptions::new("docs/decisions")
+            .with_condition("tags", "database")
+            .with_change(FieldChange::new(ChangeKind::Set, "tags", "Database"));
+        let result = EditUseCase::new(fs.clone()).execute(&options).unwrap();
+        assert_eq!(result.edits[0].changes[0].after, "Database, sql");
+
+        // Without a condition on the field, the whole list is replaced
+        let options = EditOptions::new("docs/decisions")
+            .with_condition("title", "four")
+            .with_change(FieldChange::new(ChangeKind::Set, "tags", "sql, storage"));
+        EditUseCase::new(fs.clone()).execute(&options).unwrap();
+        assert!(
+            fs.read_to_string(Path::new("docs/decisions/adr-0004.md"))
+                .unwrap()
+                .contains("tags:\n  - sql\n  - storage\n---")
+        );
+    }
+
+    #[test]
+    fn test_edit_dry_run() {
+        let fs = setup();
+        let options = EditOptions::new("docs/decisions")
+            .with_condition("project", "")
+            .with_change(FieldChange::new(ChangeKind::Set, "project", "backend"))
+            .with_dry_run(true);
+
+        let result = EditUseCase::new(fs.clone()).execute(&options).unwrap();
+
+        assert_eq!(result.matched, 3);
+        assert_eq!(result.edits.len(), 3);
+        assert!(
+            !fs.read_to_string(Path::new("docs/decisions/adr-0001.md"))
+                .unwrap()
+                .contains("project")
+        );
+    }
+}
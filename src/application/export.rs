@@ -0,0 +1,482 @@
+//! Export use case.
+//!
+//! Writes the data a generated viewer embeds (metadata, records, facets, and
+//! graph) without the HTML around it, so dashboards, data lakes, and other
+//! tools can ingest ADR metadata directly. JSON exports follow the viewer
+//! data schema, so `verify` checks them as it checks viewers.
+//!
+//! The ADRs can also be written to a SQLite database of normalized tables,
+//! for querying the decision history with SQL; see
+//! [`write_database`](crate::infrastructure::sqlite::write_database), or as
+//! JSON Lines, one ADR per line, for `jq`, bulk indexing, and log pipelines.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use super::generate::{GenerateOptions, GenerateUseCase, ViewerDataResult};
+use super::source::{LoadedAdrs, load_adrs};
+use crate::domain::{Adr, Frontmatter, ValidationIssue};
+use crate::error::{Error, Result};
+use crate::infrastructure::{DefaultAdrParser, FileSystem, sqlite};
+
+/// Output format for the export command.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// The viewer data as one JSON document.
+    #[default]
+    Json,
+    /// A SQLite database with `adrs`, `tags`, `technologies`, and
+    /// `relations` tables; only written to a file.
+    Sqlite,
+    /// JSON Lines: one ADR per line, with its frontmatter and body.
+    Ndjson,
+}
+
+impl ExportFormat {
+    /// Infers the format from an output file's extension, defaulting to JSON.
+    #[must_use]
+    pub fn from_path(path: &str) -> Self {
+        Path::new(path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| ext.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl std::str::FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "sqlite" | "sqlite3" | "db" => Ok(Self::Sqlite),
+            "ndjson" | "jsonl" => Ok(Self::Ndjson),
+            _ => Err(format!("invalid format: {s}")),
+        }
+    }
+}
+
+/// Options for the export command.
+#[derive(Debug, Clone, Default)]
+pub struct ExportOptions {
+    /// Options the viewer data is built with; also locate the ADR files.
+    pub generate: GenerateOptions,
+    /// Output format.
+    pub format: ExportFormat,
+    /// File the export is written to; `None` leaves it to the caller.
+    pub output: Option<String>,
+}
+
+impl ExportOptions {
+    /// Creates new options building the data with the given options.
+    #[must_use]
+    pub fn new(generate: GenerateOptions) -> Self {
+        Self {
+            generate,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the output format.
+    #[must_use]
+    pub const fn with_format(mut self, format: ExportFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the file the export is written to.
+    #[must_use]
+    pub fn with_output(mut self, output: impl Into<String>) -> Self {
+        self.output = Some(output.into());
+        self
+    }
+}
+
+/// Use case for exporting the ADR collection.
+#[derive(Debug)]
+pub struct ExportUseCase<F: FileSystem + Clone> {
+    fs: F,
+    parser: DefaultAdrParser,
+}
+
+impl<F: FileSystem + Clone> ExportUseCase<F> {
+    /// Creates a new export use case.
+    #[must_use]
+    pub fn new(fs: F) -> Self {
+        Self {
+            fs,
+            parser: DefaultAdrParser::new(),
+        }
+    }
+
+    /// Executes the export use case.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no ADR files are found, reading or parsing fails,
+    /// the output file cannot be written, or a SQLite export has no output
+    /// file.
+    pub fn execute(&self, options: &ExportOptions) -> Result<ExportResult> {
+        match options.format {
+            ExportFormat::Json => self.export_json(options),
+            ExportFormat::Sqlite => self.export_sqlite(options),
+            ExportFormat::Ndjson => self.export_ndjson(options),
+        }
+    }
+
+    /// Writes the selected ADRs as JSON Lines, one ADR at a time, so the
+    /// collection is never held as one string.
+    ///
+    /// The format and output file in `options` are ignored; the lines go to
+    /// `out`, and the result carries no output.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no ADR files are found, reading or parsing fails,
+    /// or writing to `out` fails.
+    pub fn stream<W: Write>(&self, options: &ExportOptions, out: &mut W) -> Result<ExportResult> {
+        let LoadedAdrs {
+            adrs,
+            parse_errors,
+            warnings,
+            ..
+        } = self.load(options)?;
+
+        let target = options.output.as_deref().unwrap_or("-");
+        let write_error = |source| Error::FileWrite {
+            path: PathBuf::from(target),
+            source,
+        };
+        for adr in &adrs {
+            serde_json::to_writer(&mut *out, &AdrLine::new(adr)).map_err(|e| {
+                if e.is_io() {
+                    write_error(e.into())
+                } else {
+                    Error::JsonSerialize(e.to_string())
+                }
+            })?;
+            out.write_all(b"\n").map_err(write_error)?;
+        }
+        out.flush().map_err(write_error)?;
+
+        Ok(ExportResult {
+            output: None,
+            adr_count: adrs.len(),
+            parse_errors,
+            warnings,
+        })
+    }
+
+    /// Exports the viewer data as JSON.
+    fn export_json(&self, options: &ExportOptions) -> Result<ExportResult> {
+        let ViewerDataResult {
+            data,
+            adr_count,
+            parse_errors,
+            warnings,
+        } = GenerateUseCase::new(self.fs.clone()).data(&options.generate)?;
+
+        let output =
+            serde_json::to_string_pretty(&data).map_err(|e| Error::JsonSerialize(e.to_string()))?;
+        if let Some(path) = &options.output {
+            let path = Path::new(path);
+            self.create_parent(path)?;
+            self.fs.write(path, &output)?;
+        }
+
+        Ok(ExportResult {
+            output: Some(output),
+            adr_count,
+            parse_errors,
+            warnings,
+        })
+    }
+
+    /// Writes the selected ADRs to a SQLite database.
+    fn export_sqlite(&self, options: &ExportOptions) -> Result<ExportResult> {
+        let path = options
+            .output
+            .as_deref()
+            .map(Path::new)
+            .ok_or_else(|| Error::Sqlite {
+                path: PathBuf::new(),
+                message: "a SQLite export needs an output file".to_string(),
+            })?;
+        let LoadedAdrs {
+            adrs,
+            parse_errors,
+            warnings,
+            ..
+        } = self.load(options)?;
+
+        self.create_parent(path)?;
+        sqlite::write_database(path, &adrs)?;
+
+        Ok(ExportResult {
+            output: None,
+            adr_count: adrs.len(),
+            parse_errors,
+            warnings,
+        })
+    }
+
+    /// Writes the selected ADRs as JSON Lines, to the output file if there
+    /// is one.
+    fn export_ndjson(&self, options: &ExportOptions) -> Result<ExportResult> {
+        let Some(output) = &options.output else {
+            let mut buffer = Vec::new();
+            let result = self.stream(options, &mut buffer)?;
+            return Ok(ExportResult {
+                output: Some(String::from_utf8_lossy(&buffer).into_owned()),
+                ..result
+            });
+        };
+
+        let mut file = self.fs.create(Path::new(output))?;
+        self.stream(options, &mut file)
+    }
+
+    /// Loads the ADRs the options select.
+    fn load(&self, options: &ExportOptions) -> Result<LoadedAdrs> {
+        let generate = &options.generate;
+        let mut loaded = load_adrs(
+            &self.fs,
+            &self.parser,
+            &generate.input_dir,
+            &generate.pattern,
+            &generate.source,
+        )?;
+        loaded.adrs.retain(|adr| generate.filter.matches(adr));
+        Ok(loaded)
+    }
+
+    /// Creates the directory an output file is written to.
+    fn create_parent(&self, path: &Path) -> Result<()> {
+        match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => self.fs.create_dir_all(parent),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// One line of a JSON Lines export.
+#[derive(Serialize)]
+struct AdrLine<'a> {
+    id: &'a str,
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    package: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    team: Option<&'a str>,
+    frontmatter: &'a Frontmatter,
+    body_markdown: &'a str,
+    body_text: &'a str,
+}
+
+impl<'a> AdrLine<'a> {
+    fn new(adr: &'a Adr) -> Self {
+        Self {
+            id: adr.id().as_str(),
+            path: adr.source_path().display().to_string(),
+            package: adr.package(),
+            team: adr.team(),
+            frontmatter: adr.frontmatter(),
+            body_markdown: adr.body_markdown(),
+            body_text: adr.body_text(),
+        }
+    }
+}
+
+/// Result of the export use case.
+#[derive(Debug)]
+pub struct ExportResult {
+    /// The export in the requested format, unless it is only written to a
+    /// file.
+    pub output: Option<String>,
+    /// Number of ADRs exported.
+    pub adr_count: usize,
+    /// Files that failed to parse.
+    pub parse_errors: Vec<(std::path::PathBuf, crate::error::Error)>,
+    /// Non-fatal problems found while loading, such as colliding IDs.
+    pub warnings: Vec<ValidationIssue>,
+}
+
+impl ExportResult {
+    /// Returns true if there were any parse errors.
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        !self.parse_errors.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::{VerifyOptions, VerifyUseCase};
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+    use serde_json::Value;
+
+    fn sample_fs() -> InMemoryFileSystem {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0001.md",
+            "---\ntitle: Use PostgreSQL\nstatus: accepted\ncategory: database\n---\n\n## Context\n\nWe need a database.\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            "---\ntitle: Use Redis\nstatus: proposed\nrelated:\n  - adr-0001.md\n---\n",
+        );
+        fs
+    }
+
+    #[test]
+    fn test_export_json() {
+        let fs = sample_fs();
+        let options = ExportOptions::new(GenerateOptions::new("docs/decisions"));
+
+        let result = ExportUseCase::new(fs).execute(&options).unwrap();
+
+        assert_eq!(result.adr_count, 2);
+        let data: Value = serde_json::from_str(result.output.as_deref().unwrap()).unwrap();
+        assert_eq!(data["records"].as_array().unwrap().len(), 2);
+        assert_eq!(data["facets"]["categories"][0]["value"], "database");
+        assert_eq!(data["graph"]["edges"][0]["source"], "adr-0002");
+        assert!(
+            data["records"][0]["body_html"]
+                .as_str()
+                .unwrap()
+                .contains("We need a database.")
+        );
+    }
+
+    #[test]
+    fn test_export_output_file() {
+        let fs = sample_fs();
+        let options = ExportOptions::new(GenerateOptions::new("docs/decisions"))
+            .with_output("public/adrs.json");
+
+        let result = ExportUseCase::new(fs.clone()).execute(&options).unwrap();
+
+        assert_eq!(
+            fs.read_to_string(Path::new("public/adrs.json")).ok(),
+            result.output
+        );
+        let verified = VerifyUseCase::new(fs)
+            .execute(&VerifyOptions::new("public/adrs.json"))
+            .unwrap();
+        assert!(verified.passed(), "{:?}", verified.check);
+        assert_eq!(verified.check.record_count, 2);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_export_sqlite() {
+        use crate::domain::AdrFilter;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("adrs.db");
+        let generate = GenerateOptions::new("docs/decisions")
+            .with_filter(AdrFilter::default().with_statuses(vec!["accepted".to_string()]));
+        let options = ExportOptions::new(generate)
+            .with_format(ExportFormat::Sqlite)
+            .with_output(path.display().to_string());
+
+        let result = ExportUseCase::new(sample_fs()).execute(&options).unwrap();
+
+        assert_eq!(result.adr_count, 1);
+        assert!(result.output.is_none());
+        let connection = rusqlite::Connection::open(&path).unwrap();
+        let titles: String = connection
+            .query_row("SELECT group_concat(title) FROM adrs", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(titles, "Use PostgreSQL");
+    }
+
+    #[test]
+    fn test_export_ndjson_stream() {
+        let options = ExportOptions::new(GenerateOptions::new("docs/decisions"))
+            .with_format(ExportFormat::Ndjson);
+        let mut out = Vec::new();
+
+        let result = ExportUseCase::new(sample_fs())
+            .stream(&options, &mut out)
+            .unwrap();
+
+        assert_eq!(result.adr_count, 2);
+        assert!(result.output.is_none());
+        let lines: Vec<Value> = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["id"], "adr-0001");
+        assert_eq!(lines[0]["path"], "docs/decisions/adr-0001.md");
+        assert_eq!(lines[0]["frontmatter"]["status"], "accepted");
+        assert!(
+            lines[0]["body_text"]
+                .as_str()
+                .unwrap()
+                .contains("We need a database.")
+        );
+        assert!(
+            lines[0]["body_markdown"]
+                .as_str()
+                .unwrap()
+                .contains("## Context")
+        );
+        assert_eq!(lines[1]["frontmatter"]["related"][0], "adr-0001.md");
+    }
+
+    #[test]
+    fn test_export_ndjson_output_file() {
+        let fs = sample_fs();
+        let options = ExportOptions::new(GenerateOptions::new("docs/decisions"))
+            .with_format(ExportFormat::Ndjson)
+            .with_output("out/adrs.ndjson");
+
+        let result = ExportUseCase::new(fs.clone()).execute(&options).unwrap();
+
+        assert!(result.output.is_none());
+        let content = fs.read_to_string(Path::new("out/adrs.ndjson")).unwrap();
+        assert_eq!(content.lines().count(), 2);
+        assert!(content.ends_with('\n'));
+    }
+
+    #[test]
+    fn test_export_sqlite_needs_output() {
+        let options = ExportOptions::new(GenerateOptions::new("docs/decisions"))
+            .with_format(ExportFormat::Sqlite);
+        assert!(matches!(
+            ExportUseCase::new(sample_fs()).execute(&options),
+            Err(Error::Sqlite { .. })
+        ));
+    }
+
+    #[test]
+    fn test_export_no_adrs() {
+        let options = ExportOptions::new(GenerateOptions::new("empty"));
+        assert!(
+            ExportUseCase::new(InMemoryFileSystem::new())
+                .execute(&options)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_export_format_from_path() {
+        assert_eq!(
+            "JSON".parse::<ExportFormat>().ok(),
+            Some(ExportFormat::Json)
+        );
+        assert!("xml".parse::<ExportFormat>().is_err());
+        assert_eq!(ExportFormat::from_path("adrs.json"), ExportFormat::Json);
+        assert_eq!(ExportFormat::from_path("out/adrs.db"), ExportFormat::Sqlite);
+        assert_eq!(ExportFormat::from_path("adrs.sqlite"), ExportFormat::Sqlite);
+        assert_eq!(ExportFormat::from_path("adrs.jsonl"), ExportFormat::Ndjson);
+        assert_eq!(ExportFormat::from_path("adrs"), ExportFormat::Json);
+    }
+}
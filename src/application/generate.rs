@@ -1,17 +1,37 @@
 //! Generate HTML viewer use case.
 //!
-//! Orchestrates ADR discovery, parsing, and HTML generation.
+//! Orchestrates ADR discovery, parsing, and HTML generation. When the output
+//! file already exists, its records are compared with the new ones to report
+//! which ADRs were added, changed, or removed. Optionally, an `index.json`
+//! holding the viewer data without ADR bodies is written next to the viewer.
 
+use std::collections::BTreeMap;
 use std::path::Path;
 
-use crate::domain::Adr;
-use crate::error::Result;
+use serde_json::{Value, json};
+
+use super::assets::{DEFAULT_MAX_INLINE_SIZE, inline_urls};
+use super::crosslinks::CrossLinks;
+use super::source::{LoadedAdrs, SourceOptions, load_adrs};
+use crate::domain::{Adr, AdrFilter, GraphGrouping, ValidationIssue};
+use crate::error::{Error, Result};
+use crate::infrastructure::parser::{CacheStats, CachingParser, MarkdownRenderer};
+use crate::infrastructure::renderer::schema::extract_viewer_json;
+use crate::infrastructure::renderer::{content_hash, external_references, index_data};
 use crate::infrastructure::{
-    AdrParser, DefaultAdrParser, FileSystem, HtmlRenderer, RenderConfig, Theme,
+    Analytics, BadgeRule, DefaultAdrParser, FileSystem, HtmlRenderer, RecordField, RenderConfig,
+    Theme,
 };
 
+/// File name of the index written next to the viewer.
+const INDEX_FILE: &str = "index.json";
+
 /// Options for the generate command.
 #[derive(Debug, Clone)]
+#[allow(
+    clippy::struct_excessive_bools,
+    reason = "independent output switches, each set by its own builder method"
+)]
 pub struct GenerateOptions {
     /// Input directory containing ADR files.
     pub input_dir: String,
@@ -23,6 +43,46 @@ pub struct GenerateOptions {
     pub theme: Theme,
     /// Glob pattern for matching ADR files.
     pub pattern: String,
+    /// Where ADR files are discovered from.
+    pub source: SourceOptions,
+    /// Criteria selecting which ADRs are included.
+    pub filter: AdrFilter,
+    /// Whether to produce byte-identical output for identical input.
+    pub reproducible: bool,
+    /// Whether to embed each ADR's raw markdown in the viewer data.
+    pub embed_markdown: bool,
+    /// Whether ADR bodies render curly quotes, dashes, and ellipses.
+    pub smart_punctuation: bool,
+    /// Optional record fields to embed, replacing the default selection.
+    pub embed_fields: Option<Vec<RecordField>>,
+    /// Optional record fields to leave out of the viewer data.
+    pub omit_fields: Vec<RecordField>,
+    /// Custom frontmatter fields shown as badges on ADR cards.
+    pub badges: Vec<BadgeRule>,
+    /// Custom frontmatter fields offered as viewer filters.
+    pub facets: Vec<String>,
+    /// Whether to write an `index.json` next to the viewer.
+    pub index: bool,
+    /// Absolute URL of the directory the viewer is served from.
+    pub base_url: Option<String>,
+    /// Whether to insert the content hash into the output file name.
+    pub hash_filename: bool,
+    /// File with HTML inserted into the viewer header.
+    pub header_html: Option<String>,
+    /// File with HTML inserted into the viewer footer.
+    pub footer_html: Option<String>,
+    /// Analytics script added to the viewer.
+    pub analytics: Option<Analytics>,
+    /// File with a Mermaid build embedded for drawing diagrams.
+    pub mermaid_js: Option<String>,
+    /// Largest asset, in bytes, inlined into the viewer.
+    pub max_inline_size: u64,
+    /// Whether to refuse writing a viewer that loads resources from other hosts.
+    pub strict_offline: bool,
+    /// File caching parsed ADRs between runs, so only changed files are parsed.
+    pub cache: Option<String>,
+    /// ADR attribute the viewer's graph clusters nodes by, if any.
+    pub graph_grouping: Option<GraphGrouping>,
 }
 
 impl Default for GenerateOptions {
@@ -33,6 +93,26 @@ impl Default for GenerateOptions {
             title: "Architecture Decision Records".to_string(),
             theme: Theme::Auto,
             pattern: "**/*.md".to_string(),
+            source: SourceOptions::default(),
+            filter: AdrFilter::default(),
+            reproducible: false,
+            embed_markdown: false,
+            smart_punctuation: false,
+            embed_fields: None,
+            omit_fields: Vec::new(),
+            badges: Vec::new(),
+            facets: Vec::new(),
+            index: false,
+            base_url: None,
+            hash_filename: false,
+            header_html: None,
+            footer_html: None,
+            analytics: None,
+            mermaid_js: None,
+            max_inline_size: DEFAULT_MAX_INLINE_SIZE,
+            strict_offline: false,
+            cache: None,
+            graph_grouping: None,
         }
     }
 }
@@ -74,6 +154,153 @@ impl GenerateOptions {
         self.pattern = pattern.into();
         self
     }
+
+    /// Sets the ADR source options.
+    #[must_use]
+    pub fn with_source(mut self, source: SourceOptions) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Sets the criteria selecting which ADRs are included.
+    #[must_use]
+    pub fn with_filter(mut self, filter: AdrFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Sets whether output is reproducible.
+    #[must_use]
+    pub const fn with_reproducible(mut self, reproducible: bool) -> Self {
+        self.reproducible = reproducible;
+        self
+    }
+
+    /// Sets whether raw markdown is embedded in the viewer data.
+    #[must_use]
+    pub const fn with_embed_markdown(mut self, embed_markdown: bool) -> Self {
+        self.embed_markdown = embed_markdown;
+        self
+    }
+
+    /// Sets whether ADR bodies render curly quotes, dashes, and ellipses.
+    #[must_use]
+    pub const fn with_smart_punctuation(mut self, smart_punctuation: bool) -> Self {
+        self.smart_punctuation = smart_punctuation;
+        self
+    }
+
+    /// Sets the record fields to embed in the viewer data.
+    #[must_use]
+    pub fn with_embed_fields(mut self, fields: Vec<RecordField>) -> Self {
+        self.embed_fields = Some(fields);
+        self
+    }
+
+    /// Sets the record fields to leave out of the viewer data.
+    #[must_use]
+    pub fn with_omit_fields(mut self, fields: Vec<RecordField>) -> Self {
+        self.omit_fields = fields;
+        self
+    }
+
+    /// Sets the custom frontmatter fields shown as badges.
+    #[must_use]
+    pub fn with_badges(mut self, badges: Vec<BadgeRule>) -> Self {
+        self.badges = badges;
+        self
+    }
+
+    /// Sets the custom frontmatter fields offered as viewer filters.
+    #[must_use]
+    pub fn with_facets(mut self, facets: Vec<String>) -> Self {
+        self.facets = facets;
+        self
+    }
+
+    /// Sets whether an `index.json` is written next to the viewer.
+    #[must_use]
+    pub const fn with_index(mut self, index: bool) -> Self {
+        self.index = index;
+        self
+    }
+
+    /// Sets the URL of the directory the viewer is served from, e.g.
+    /// `https://example.com/docs/adrs/`.
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sets whether the content hash is inserted into the output file name,
+    /// e.g. `adrs.3f2a9c1e.html`.
+    #[must_use]
+    pub const fn with_hash_filename(mut self, hash_filename: bool) -> Self {
+        self.hash_filename = hash_filename;
+        self
+    }
+
+    /// Sets a file whose HTML is inserted into the viewer header, e.g. a
+    /// navigation bar.
+    #[must_use]
+    pub fn with_header_html(mut self, path: impl Into<String>) -> Self {
+        self.header_html = Some(path.into());
+        self
+    }
+
+    /// Sets a file whose HTML is inserted into the viewer footer, e.g. a
+    /// compliance notice.
+    #[must_use]
+    pub fn with_footer_html(mut self, path: impl Into<String>) -> Self {
+        self.footer_html = Some(path.into());
+        self
+    }
+
+    /// Sets the analytics script added to the viewer.
+    #[must_use]
+    pub fn with_analytics(mut self, analytics: Analytics) -> Self {
+        self.analytics = Some(analytics);
+        self
+    }
+
+    /// Sets a file with a Mermaid build to embed, so that diagrams are drawn
+    /// without loading Mermaid from its CDN.
+    #[must_use]
+    pub fn with_mermaid_js(mut self, path: impl Into<String>) -> Self {
+        self.mermaid_js = Some(path.into());
+        self
+    }
+
+    /// Sets the size, in bytes, above which assets are linked rather than
+    /// inlined into the viewer.
+    #[must_use]
+    pub const fn with_max_inline_size(mut self, max_inline_size: u64) -> Self {
+        self.max_inline_size = max_inline_size;
+        self
+    }
+
+    /// Sets whether generation fails when the viewer would load resources
+    /// from other hosts, such as remote images or analytics scripts.
+    #[must_use]
+    pub const fn with_strict_offline(mut self, strict_offline: bool) -> Self {
+        self.strict_offline = strict_offline;
+        self
+    }
+
+    /// Caches parsed ADRs in the given file between runs.
+    #[must_use]
+    pub fn with_cache(mut self, path: impl Into<String>) -> Self {
+        self.cache = Some(path.into());
+        self
+    }
+
+    /// Sets the ADR attribute the viewer's graph clusters nodes by.
+    #[must_use]
+    pub const fn with_graph_grouping(mut self, grouping: GraphGrouping) -> Self {
+        self.graph_grouping = Some(grouping);
+        self
+    }
 }
 
 /// Use case for generating HTML viewers.
@@ -106,57 +333,332 @@ impl<F: FileSystem> GenerateUseCase<F> {
     /// - HTML generation fails
     /// - File writing fails
     pub fn execute(&self, options: &GenerateOptions) -> Result<GenerateResult> {
-        // Discover ADR files
-        let base = Path::new(&options.input_dir);
-        let files = self.fs.glob(base, &options.pattern)?;
-
-        if files.is_empty() {
-            return Err(crate::error::Error::NoAdrsFound {
-                path: base.to_path_buf(),
-            });
-        }
+        let (loaded, cache) = self.load(options)?;
+        let mut result = self.execute_loaded(options, loaded)?;
+        result.cache = cache;
+        Ok(result)
+    }
 
-        // Parse all ADRs
-        let mut adrs = Vec::with_capacity(files.len());
-        let mut errors = Vec::new();
+    /// Renders the viewer in memory without writing any files.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no ADR files are found, or reading, parsing, or
+    /// HTML generation fails.
+    pub fn render(&self, options: &GenerateOptions) -> Result<RenderedViewer> {
+        let (
+            LoadedAdrs {
+                source_dir,
+                adrs,
+                parse_errors,
+                warnings,
+            },
+            _,
+        ) = self.load(options)?;
+        let rendered = self.render_adrs(options, adrs, &source_dir)?;
 
-        for file_path in &files {
-            match self.parse_adr(file_path) {
-                Ok(adr) => adrs.push(adr),
-                Err(e) => errors.push((file_path.clone(), e)),
+        Ok(RenderedViewer {
+            html: rendered.html,
+            adr_count: rendered.adr_count,
+            parse_errors,
+            warnings: [warnings, rendered.warnings].concat(),
+        })
+    }
+
+    /// Loads the ADRs, through the parse cache if one is configured.
+    fn load(&self, options: &GenerateOptions) -> Result<(LoadedAdrs, Option<CacheStats>)> {
+        let Some(cache) = options.cache.as_deref().map(Path::new) else {
+            let loaded = load_adrs(
+                &self.fs,
+                &self.parser,
+                &options.input_dir,
+                &options.pattern,
+                &options.source,
+            )?;
+            return Ok((loaded, None));
+        };
+
+        let parser = CachingParser::load(&self.parser, &self.fs, cache);
+        let loaded = load_adrs(
+            &self.fs,
+            &parser,
+            &options.input_dir,
+            &options.pattern,
+            &options.source,
+        )?;
+        parser.save(&self.fs, cache)?;
+        Ok((loaded, Some(parser.stats())))
+    }
+
+    /// Executes the use case on ADRs that have already been loaded.
+    pub(super) fn execute_loaded(
+        &self,
+        options: &GenerateOptions,
+        loaded: LoadedAdrs,
+    ) -> Result<GenerateResult> {
+        let LoadedAdrs {
+            source_dir,
+            adrs,
+            parse_errors,
+            mut warnings,
+        } = loaded;
+        let Rendered {
+            data,
+            adr_count,
+            html,
+            content_hash,
+            output_path,
+            url,
+            warnings: inline_warnings,
+        } = self.render_adrs(options, adrs, &source_dir)?;
+        warnings.extend(inline_warnings);
+        let output = Path::new(&output_path);
+
+        if options.strict_offline {
+            let mut urls = external_references(&html);
+            // Matomo loads its script from JavaScript, out of sight of the scan
+            if let Some(analytics) = &options.analytics {
+                let script = analytics.script_url();
+                if !urls.contains(&script) {
+                    urls.push(script);
+                }
+            }
+            if !urls.is_empty() {
+                return Err(Error::ExternalResources {
+                    path: output.to_path_buf(),
+                    urls,
+                });
             }
         }
 
-        // Sort by ID for consistent ordering
-        adrs.sort_by(|a, b| a.id().cmp(b.id()));
-
-        // Generate HTML
-        let config = RenderConfig::new(&options.title).with_theme(options.theme);
-        let html = self
-            .renderer
-            .render(adrs.clone(), &options.input_dir, &config)?;
+        // Compare with the previous output, if any
+        let changes = if self.fs.exists(output) {
+            let previous = self.fs.read_to_string(output)?;
+            Some(OutputChanges::between(&previous, &html))
+        } else {
+            None
+        };
 
         // Write output
-        if let Some(parent) = Path::new(&options.output).parent() {
+        if let Some(parent) = output.parent() {
             if !parent.as_os_str().is_empty() {
                 self.fs.create_dir_all(parent)?;
             }
         }
-        self.fs.write(Path::new(&options.output), &html)?;
+        self.fs.write(output, &html)?;
+
+        let index_path = if options.index {
+            let path = output.with_file_name(INDEX_FILE);
+            let index = serde_json::to_string_pretty(&index_data(&data))
+                .map_err(|e| Error::JsonSerialize(e.to_string()))?;
+            self.fs.write(&path, &index)?;
+            Some(path.display().to_string())
+        } else {
+            None
+        };
 
         Ok(GenerateResult {
-            output_path: options.output.clone(),
-            adr_count: adrs.len(),
-            parse_errors: errors,
+            output_path,
+            adr_count,
+            parse_errors,
+            warnings,
+            changes,
+            index_path,
+            url,
+            content_hash,
+            cache: None,
+        })
+    }
+
+    /// Builds the viewer data without rendering the viewer or writing any
+    /// files.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no ADR files are found, or reading or parsing
+    /// fails.
+    pub(super) fn data(&self, options: &GenerateOptions) -> Result<ViewerDataResult> {
+        let (
+            LoadedAdrs {
+                source_dir,
+                adrs,
+                parse_errors,
+                warnings,
+            },
+            _,
+        ) = self.load(options)?;
+        let prepared = self.prepare(options, adrs, &source_dir)?;
+
+        Ok(ViewerDataResult {
+            data: prepared.data,
+            adr_count: prepared.adr_count,
+            parse_errors,
+            warnings: [warnings, prepared.warnings].concat(),
         })
     }
 
-    fn parse_adr(&self, path: &Path) -> Result<Adr> {
-        let content = self.fs.read_to_string(path)?;
-        self.parser.parse(path, &content)
+    /// Filters the ADRs, inlines their assets, and renders the viewer.
+    fn render_adrs(
+        &self,
+        options: &GenerateOptions,
+        adrs: Vec<Adr>,
+        source_dir: &str,
+    ) -> Result<Rendered> {
+        let Prepared {
+            data,
+            config,
+            adr_count,
+            content_hash,
+            output_path,
+            url,
+            warnings,
+        } = self.prepare(options, adrs, source_dir)?;
+        let html = self.renderer.render_data(&data, &config)?;
+
+        Ok(Rendered {
+            data,
+            adr_count,
+            html,
+            content_hash,
+            output_path,
+            url,
+            warnings,
+        })
+    }
+
+    /// Filters the ADRs, inlines their assets, and builds the viewer data.
+    fn prepare(
+        &self,
+        options: &GenerateOptions,
+        mut adrs: Vec<Adr>,
+        source_dir: &str,
+    ) -> Result<Prepared> {
+        adrs.retain(|adr| options.filter.matches(adr));
+
+        // Inline assets and local images so the viewer stays a single file,
+        // and point links to other ADRs at them inside the viewer
+        let cross_links = CrossLinks::new(&adrs);
+        let renderer = MarkdownRenderer::new().with_smart_punctuation(options.smart_punctuation);
+        let mut warnings = Vec::new();
+        let adrs = adrs
+            .into_iter()
+            .map(|adr| {
+                let (mut targets, oversized) =
+                    inline_urls(&self.fs, &adr, options.max_inline_size)?;
+                warnings.extend(oversized);
+                targets.extend(cross_links.targets(&adr));
+                // Parsed bodies use the default typography; re-render when it differs
+                if targets.is_empty() && !options.smart_punctuation {
+                    return Ok(adr);
+                }
+                let html = renderer
+                    .render_with_links(adr.body_markdown(), |target| targets.get(target).cloned());
+                Ok(adr.with_body_html(html))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Generate HTML
+        let mut config = RenderConfig::new(&options.title)
+            .with_theme(options.theme)
+            .with_reproducible(options.reproducible)
+            .with_embed_markdown(options.embed_markdown)
+            .with_omit_fields(options.omit_fields.clone())
+            .with_badges(options.badges.clone())
+            .with_facets(options.facets.clone());
+        if let Some(fields) = &options.embed_fields {
+            config = config.with_embed_fields(fields.clone());
+        }
+        if let Some(path) = &options.header_html {
+            config = config.with_header_html(self.fs.read_to_string(Path::new(path))?.trim_end());
+        }
+        if let Some(analytics) = &options.analytics {
+            config = config.with_analytics(analytics.clone());
+        }
+        if let Some(path) = &options.footer_html {
+            config = config.with_footer_html(self.fs.read_to_string(Path::new(path))?.trim_end());
+        }
+        if let Some(path) = &options.mermaid_js {
+            config = config.with_mermaid_js(self.fs.read_to_string(Path::new(path))?);
+        }
+        if let Some(grouping) = options.graph_grouping {
+            config = config.with_graph_grouping(grouping);
+        }
+        let content_hash = content_hash(&adrs);
+        let output_path = if options.hash_filename {
+            hashed_filename(&options.output, &content_hash)
+        } else {
+            options.output.clone()
+        };
+        let url = options
+            .base_url
+            .as_deref()
+            .and_then(|base| viewer_url(base, &output_path));
+        if let Some(url) = &url {
+            config = config.with_url(url);
+        }
+        let adr_count = adrs.len();
+        let data = self.renderer.data(adrs, source_dir, &config)?;
+
+        Ok(Prepared {
+            data,
+            config,
+            adr_count,
+            content_hash,
+            output_path,
+            url,
+            warnings,
+        })
     }
 }
 
+/// Viewer data and the configuration to render it with.
+struct Prepared {
+    data: Value,
+    config: RenderConfig,
+    adr_count: usize,
+    content_hash: String,
+    output_path: String,
+    url: Option<String>,
+    warnings: Vec<ValidationIssue>,
+}
+
+/// A rendered viewer and the values derived while rendering it.
+struct Rendered {
+    data: Value,
+    adr_count: usize,
+    html: String,
+    content_hash: String,
+    output_path: String,
+    url: Option<String>,
+    warnings: Vec<ValidationIssue>,
+}
+
+/// A viewer rendered in memory by [`GenerateUseCase::render`].
+#[derive(Debug)]
+pub struct RenderedViewer {
+    /// The viewer HTML.
+    pub html: String,
+    /// Number of ADRs included.
+    pub adr_count: usize,
+    /// Files that failed to parse.
+    pub parse_errors: Vec<(std::path::PathBuf, crate::error::Error)>,
+    /// Non-fatal problems found while loading, such as colliding IDs.
+    pub warnings: Vec<ValidationIssue>,
+}
+
+/// Viewer data built by [`GenerateUseCase::data`].
+#[derive(Debug)]
+pub(super) struct ViewerDataResult {
+    /// The data a viewer embeds.
+    pub data: Value,
+    /// Number of ADRs included.
+    pub adr_count: usize,
+    /// Files that failed to parse.
+    pub parse_errors: Vec<(std::path::PathBuf, crate::error::Error)>,
+    /// Non-fatal problems found while loading, such as colliding IDs.
+    pub warnings: Vec<ValidationIssue>,
+}
+
 /// Result of the generate use case.
 #[derive(Debug)]
 pub struct GenerateResult {
@@ -166,6 +668,18 @@ pub struct GenerateResult {
     pub adr_count: usize,
     /// Files that failed to parse.
     pub parse_errors: Vec<(std::path::PathBuf, crate::error::Error)>,
+    /// Non-fatal problems found while loading, such as colliding IDs.
+    pub warnings: Vec<ValidationIssue>,
+    /// ADRs that differ from the previous output, if the output existed.
+    pub changes: Option<OutputChanges>,
+    /// Path to the written `index.json`, if requested.
+    pub index_path: Option<String>,
+    /// Absolute URL of the viewer, if a base URL was set.
+    pub url: Option<String>,
+    /// SHA-256 of the ADR corpus, as recorded in the viewer metadata.
+    pub content_hash: String,
+    /// Files reused from and added to the parse cache, if one was used.
+    pub cache: Option<CacheStats>,
 }
 
 impl GenerateResult {
@@ -174,12 +688,173 @@ impl GenerateResult {
     pub fn has_errors(&self) -> bool {
         !self.parse_errors.is_empty()
     }
+
+    /// Summarizes the generated viewer and any loading problems as markdown.
+    #[must_use]
+    pub fn markdown(&self) -> String {
+        use std::fmt::Write;
+        let mut output = String::new();
+
+        let _ = writeln!(output, "# ADR Viewer\n");
+        let _ = writeln!(
+            output,
+            "Generated `{}` with **{}** ADRs.",
+            self.output_path, self.adr_count
+        );
+
+        if self.has_errors() || !self.warnings.is_empty() {
+            let _ = writeln!(output, "\n## Warnings\n");
+            for (path, error) in &self.parse_errors {
+                let _ = writeln!(
+                    output,
+                    "- `{}`: {} ({})",
+                    path.display(),
+                    error,
+                    error.code()
+                );
+            }
+            for issue in &self.warnings {
+                let _ = writeln!(
+                    output,
+                    "- `{}`: {} ({})",
+                    issue.path.display(),
+                    issue.message,
+                    issue.label()
+                );
+            }
+        }
+        output
+    }
+
+    /// Builds the webhook notification payload.
+    ///
+    /// The `text` field makes the payload usable as-is by Slack and Teams
+    /// incoming webhooks; the other fields are for custom consumers.
+    #[must_use]
+    pub fn notification(&self) -> Value {
+        let mut text = format!(
+            "Generated {} with {} ADRs",
+            self.output_path, self.adr_count
+        );
+        if let Some(changes) = &self.changes {
+            let summary: Vec<_> = [
+                ("new", &changes.added),
+                ("changed", &changes.changed),
+                ("removed", &changes.removed),
+            ]
+            .into_iter()
+            .filter(|(_, ids)| !ids.is_empty())
+            .map(|(label, ids)| format!("{} {label}: {}", ids.len(), ids.join(", ")))
+            .collect();
+            if summary.is_empty() {
+                text.push_str(" (no changes)");
+            } else {
+                text = format!("{text} ({})", summary.join("; "));
+            }
+        }
+
+        json!({
+            "text": text,
+            "output": self.output_path,
+            "url": self.url,
+            "content_hash": self.content_hash,
+            "adr_count": self.adr_count,
+            "parse_errors": self.parse_errors.len(),
+            "warnings": self.warnings.len(),
+            "changes": self.changes,
+        })
+    }
+}
+
+/// Number of hex digits of the content hash used in file names.
+const FILENAME_HASH_LEN: usize = 8;
+
+/// Inserts a short content hash before the extension of an output path.
+fn hashed_filename(output: &str, hash: &str) -> String {
+    let path = Path::new(output);
+    let stem = path
+        .file_stem()
+        .map_or_else(String::new, |stem| stem.to_string_lossy().into_owned());
+    let short = &hash[..FILENAME_HASH_LEN.min(hash.len())];
+    let file_name = path.extension().map_or_else(
+        || format!("{stem}.{short}"),
+        |ext| format!("{stem}.{short}.{}", ext.to_string_lossy()),
+    );
+    path.with_file_name(file_name).display().to_string()
+}
+
+/// Joins a base URL and the viewer's file name.
+fn viewer_url(base: &str, output: &str) -> Option<String> {
+    let file_name = Path::new(output).file_name()?.to_string_lossy();
+    Some(format!("{}/{file_name}", base.trim_end_matches('/')))
+}
+
+/// ADRs that differ between two generated viewers, by ID.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize)]
+pub struct OutputChanges {
+    /// ADRs only in the new output.
+    pub added: Vec<String>,
+    /// ADRs whose record changed.
+    pub changed: Vec<String>,
+    /// ADRs only in the previous output.
+    pub removed: Vec<String>,
+}
+
+impl OutputChanges {
+    /// Compares the records embedded in two generated viewers.
+    ///
+    /// A previous file without readable viewer data counts as empty, so
+    /// every ADR is reported as added.
+    #[must_use]
+    pub fn between(previous: &str, current: &str) -> Self {
+        let previous = records(previous);
+        let current = records(current);
+
+        Self {
+            added: current
+                .keys()
+                .filter(|id| !previous.contains_key(*id))
+                .cloned()
+                .collect(),
+            changed: current
+                .iter()
+                .filter(|(id, record)| previous.get(*id).is_some_and(|old| old != *record))
+                .map(|(id, _)| id.clone())
+                .collect(),
+            removed: previous
+                .keys()
+                .filter(|id| !current.contains_key(*id))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Returns true if no ADR was added, changed, or removed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Extracts the embedded records of a generated viewer, keyed by ID.
+fn records(html: &str) -> BTreeMap<String, Value> {
+    extract_viewer_json(html)
+        .and_then(|json| serde_json::from_str::<Value>(json).ok())
+        .and_then(|mut data| match data.get_mut("records")?.take() {
+            Value::Array(records) => Some(records),
+            _ => None,
+        })
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|record| Some((record.get("id")?.as_str()?.to_string(), record)))
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+    use crate::infrastructure::renderer::MERMAID_SCRIPT;
 
     fn sample_adr_content() -> &'static str {
         r"---
@@ -222,6 +897,303 @@ We will use PostgreSQL.
         assert_eq!(result.adr_count, 1);
         assert_eq!(result.output_path, "output.html");
         assert!(!result.has_errors());
+        assert_eq!(
+            result.markdown(),
+            "# ADR Viewer\n\nGenerated `output.html` with **1** ADRs.\n"
+        );
+    }
+
+    #[test]
+    fn test_generate_cache() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", sample_adr_content());
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            sample_adr_content().replace("persistence", "caching"),
+        );
+
+        let use_case = GenerateUseCase::new(fs.clone());
+        let options = GenerateOptions::new("docs/decisions")
+            .with_reproducible(true)
+            .with_cache(".adrscope-cache.json");
+
+        let first = use_case.execute(&options).expect("should generate");
+        assert_eq!(
+            first.cache,
+            Some(CacheStats {
+                reused: 0,
+                parsed: 2
+            })
+        );
+        assert!(fs.exists(Path::new(".adrscope-cache.json")));
+        let html = fs.read_to_string(Path::new("adrs.html")).unwrap();
+
+        let second = use_case.execute(&options).expect("should generate");
+        assert_eq!(
+            second.cache,
+            Some(CacheStats {
+                reused: 2,
+                parsed: 0
+            })
+        );
+        assert_eq!(fs.read_to_string(Path::new("adrs.html")).unwrap(), html);
+
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            sample_adr_content().replace("persistence", "search"),
+        );
+        let third = use_case.execute(&options).expect("should generate");
+        assert_eq!(
+            third.cache,
+            Some(CacheStats {
+                reused: 1,
+                parsed: 1
+            })
+        );
+        assert!(
+            fs.read_to_string(Path::new("adrs.html"))
+                .unwrap()
+                .contains("Use PostgreSQL for search")
+        );
+    }
+
+    #[test]
+    fn test_generate_render_in_memory() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", sample_adr_content());
+
+        let use_case = GenerateUseCase::new(fs.clone());
+        let rendered = use_case
+            .render(&GenerateOptions::new("docs/decisions").with_output("output.html"))
+            .expect("should render");
+
+        assert_eq!(rendered.adr_count, 1);
+        assert!(rendered.html.contains("<!DOCTYPE html>"));
+        assert!(rendered.parse_errors.is_empty());
+        assert!(!fs.exists(Path::new("output.html")));
+    }
+
+    #[test]
+    fn test_generate_index() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", sample_adr_content());
+
+        let use_case = GenerateUseCase::new(fs.clone());
+        let options = GenerateOptions::new("docs/decisions")
+            .with_output("public/adrs.html")
+            .with_index(true);
+
+        let result = use_case.execute(&options).unwrap();
+        assert_eq!(result.index_path.as_deref(), Some("public/index.json"));
+
+        let index: Value =
+            serde_json::from_str(&fs.read_to_string(Path::new("public/index.json")).unwrap())
+                .unwrap();
+        assert_eq!(index["records"][0]["id"], "adr-0001");
+        assert!(index["records"][0].get("body_html").is_none());
+        assert!(index["facets"]["statuses"].is_array());
+        assert!(index["graph"]["nodes"].is_array());
+    }
+
+    #[test]
+    fn test_generate_header_footer_html() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", sample_adr_content());
+        fs.add_file("branding/nav.html", "<a href=\"/portal\">Portal</a>\n");
+
+        let use_case = GenerateUseCase::new(fs.clone());
+        use_case
+            .execute(&GenerateOptions::default().with_header_html("branding/nav.html"))
+            .unwrap();
+        let html = fs.read_to_string(Path::new("adrs.html")).unwrap();
+        assert!(html.contains("<a href=\"/portal\">Portal</a>"));
+
+        let result =
+            use_case.execute(&GenerateOptions::default().with_footer_html("branding/missing.html"));
+        assert!(matches!(result, Err(Error::FileRead { .. })));
+    }
+
+    #[test]
+    fn test_generate_mermaid() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", sample_adr_content());
+        let use_case = GenerateUseCase::new(fs.clone());
+        let options = GenerateOptions::default().with_strict_offline(true);
+
+        // Mermaid is not loaded without diagrams
+        use_case.execute(&options).unwrap();
+        let html = fs.read_to_string(Path::new("adrs.html")).unwrap();
+        assert!(!html.contains("mermaid.min.js"));
+
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            "---\ntitle: Flow\n---\n\n```mermaid\ngraph TD\n  A-->B\n```\n",
+        );
+        let result = use_case.execute(&options);
+        assert!(matches!(
+            result,
+            Err(Error::ExternalResources { ref urls, .. }) if urls == &[MERMAID_SCRIPT]
+        ));
+
+        fs.add_file("vendor/mermaid.min.js", "window.mermaid = {}; // </script>");
+        use_case
+            .execute(&options.with_mermaid_js("vendor/mermaid.min.js"))
+            .unwrap();
+        let html = fs.read_to_string(Path::new("adrs.html")).unwrap();
+        assert!(html.contains("<script>\nwindow.mermaid = {}; // <\\/script>\n</script>"));
+    }
+
+    #[test]
+    fn test_generate_smart_punctuation() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0001.md",
+            "---\ntitle: Typography\n---\n\nKeep it \"simple\" -- really...\n",
+        );
+        let use_case = GenerateUseCase::new(fs.clone());
+
+        use_case.execute(&GenerateOptions::default()).unwrap();
+        let html = fs.read_to_string(Path::new("adrs.html")).unwrap();
+        assert!(html.contains("-- really..."));
+
+        use_case
+            .execute(&GenerateOptions::default().with_smart_punctuation(true))
+            .unwrap();
+        let html = fs.read_to_string(Path::new("adrs.html")).unwrap();
+        assert!(html.contains("\u{201c}simple\u{201d} \u{2013} really\u{2026}"));
+    }
+
+    #[test]
+    fn test_generate_inline_size_warning() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0001.md",
+            "---\ntitle: Images\n---\n\n![Flow](images/flow.png)\n",
+        );
+        fs.add_file("docs/decisions/images/flow.png", "x".repeat(4096));
+        let use_case = GenerateUseCase::new(fs.clone());
+
+        let result = use_case.execute(&GenerateOptions::default()).unwrap();
+        assert!(result.warnings.is_empty());
+
+        let result = use_case
+            .execute(&GenerateOptions::default().with_max_inline_size(1024))
+            .unwrap();
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].code.as_deref(), Some("V0009"));
+        let html = fs.read_to_string(Path::new("adrs.html")).unwrap();
+        assert!(html.contains("images/flow.png"));
+    }
+
+    #[test]
+    fn test_generate_strict_offline() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", sample_adr_content());
+        let use_case = GenerateUseCase::new(fs.clone());
+        let options = GenerateOptions::default().with_strict_offline(true);
+
+        use_case.execute(&options).unwrap();
+
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            "---\ntitle: Diagram\n---\n\n![Flow](https://example.com/flow.png)\n\n[Docs](https://example.com/docs)\n",
+        );
+        let result = use_case.execute(&options.clone().with_output("offline.html"));
+        assert!(matches!(
+            result,
+            Err(Error::ExternalResources { ref urls, .. }) if urls == &["https://example.com/flow.png"]
+        ));
+        assert!(!fs.exists(Path::new("offline.html")));
+
+        fs.add_file("docs/decisions/adr-0002.md", "---\ntitle: Plain\n---\n");
+        let result = use_case.execute(&options.with_analytics(Analytics::Matomo {
+            url: "https://matomo.example.com".to_string(),
+            site_id: 1,
+        }));
+        assert!(matches!(
+            result,
+            Err(Error::ExternalResources { ref urls, .. }) if urls == &["https://matomo.example.com/matomo.js"]
+        ));
+    }
+
+    #[test]
+    fn test_generate_base_url() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", sample_adr_content());
+
+        let options = GenerateOptions::new("docs/decisions")
+            .with_output("public/adrs.html")
+            .with_base_url("https://example.com/docs/adrs/");
+        let result = GenerateUseCase::new(fs.clone()).execute(&options).unwrap();
+        assert_eq!(
+            result.url.as_deref(),
+            Some("https://example.com/docs/adrs/adrs.html")
+        );
+        assert_eq!(
+            result.notification()["url"],
+            "https://example.com/docs/adrs/adrs.html"
+        );
+
+        let html = fs.read_to_string(Path::new("public/adrs.html")).unwrap();
+        assert!(
+            html.contains(
+                r#"<link rel="canonical" href="https://example.com/docs/adrs/adrs.html">"#
+            )
+        );
+        let data: Value = serde_json::from_str(extract_viewer_json(&html).unwrap()).unwrap();
+        assert_eq!(
+            data["meta"]["url"],
+            "https://example.com/docs/adrs/adrs.html"
+        );
+    }
+
+    #[test]
+    fn test_generate_hash_filename() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", sample_adr_content());
+        let use_case = GenerateUseCase::new(fs.clone());
+        let options = GenerateOptions::new("docs/decisions")
+            .with_output("public/adrs.html")
+            .with_base_url("https://example.com/adrs")
+            .with_hash_filename(true);
+
+        let result = use_case.execute(&options).unwrap();
+        let short = &result.content_hash[..8];
+        assert_eq!(result.output_path, format!("public/adrs.{short}.html"));
+        assert_eq!(
+            result.url,
+            Some(format!("https://example.com/adrs/adrs.{short}.html"))
+        );
+
+        let html = fs.read_to_string(Path::new(&result.output_path)).unwrap();
+        let data: Value = serde_json::from_str(extract_viewer_json(&html).unwrap()).unwrap();
+        assert_eq!(data["meta"]["content_hash"], result.content_hash.as_str());
+
+        // Unchanged ADRs keep the hash; changed ones get a new one
+        assert_eq!(
+            use_case.execute(&options).unwrap().output_path,
+            result.output_path
+        );
+        fs.add_file(
+            "docs/decisions/adr-0001.md",
+            sample_adr_content().replace("accepted", "deprecated"),
+        );
+        assert_ne!(
+            use_case.execute(&options).unwrap().output_path,
+            result.output_path
+        );
+    }
+
+    #[test]
+    fn test_hashed_filename() {
+        assert_eq!(
+            hashed_filename("adrs.html", "0123456789abcdef"),
+            "adrs.01234567.html"
+        );
+        assert_eq!(
+            hashed_filename("out/viewer", "0123456789abcdef"),
+            "out/viewer.01234567"
+        );
     }
 
     #[test]
@@ -234,6 +1206,90 @@ We will use PostgreSQL.
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_generate_discover() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "repo/services/billing/docs/decisions/adr-0001.md",
+            sample_adr_content(),
+        );
+        fs.add_file(
+            "repo/services/payments/adr/adr-0001.md",
+            sample_adr_content(),
+        );
+
+        let use_case = GenerateUseCase::new(fs.clone());
+        let options = GenerateOptions::new("docs/decisions")
+            .with_output("output.html")
+            .with_source(SourceOptions::new().with_discover("repo"));
+
+        let result = use_case.execute(&options).unwrap();
+        assert_eq!(result.adr_count, 2);
+
+        let html = fs.files().remove(Path::new("output.html")).unwrap();
+        assert!(html.contains("\"package\":\"services/billing\""));
+        assert!(html.contains("services/payments/adr-0001"));
+    }
+
+    #[test]
+    fn test_generate_filtered() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", sample_adr_content());
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            sample_adr_content().replace("2025-01-15", "2024-03-01"),
+        );
+
+        let use_case = GenerateUseCase::new(fs);
+        let options = GenerateOptions::new("docs/decisions")
+            .with_output("output.html")
+            .with_filter(AdrFilter::new().with_since(time::macros::date!(2025 - 01 - 01)));
+
+        let result = use_case.execute(&options).unwrap();
+        assert_eq!(result.adr_count, 1);
+    }
+
+    #[test]
+    fn test_generate_reports_changes() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", sample_adr_content());
+        fs.add_file("docs/decisions/adr-0002.md", sample_adr_content());
+        let use_case = GenerateUseCase::new(fs.clone());
+        let options = GenerateOptions::new("docs/decisions").with_output("output.html");
+
+        let first = use_case.execute(&options).unwrap();
+        assert!(first.changes.is_none());
+        assert_eq!(
+            first.notification()["text"],
+            "Generated output.html with 2 ADRs"
+        );
+
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            sample_adr_content().replace("accepted", "deprecated"),
+        );
+        fs.add_file("docs/decisions/adr-0003.md", sample_adr_content());
+        let second = use_case.execute(&options).unwrap();
+
+        assert_eq!(
+            second.changes,
+            Some(OutputChanges {
+                added: vec!["adr-0003".to_string()],
+                changed: vec!["adr-0002".to_string()],
+                removed: Vec::new(),
+            })
+        );
+        let notification = second.notification();
+        assert_eq!(
+            notification["text"],
+            "Generated output.html with 3 ADRs (1 new: adr-0003; 1 changed: adr-0002)"
+        );
+        assert_eq!(notification["changes"]["added"][0], "adr-0003");
+
+        let third = use_case.execute(&options).unwrap();
+        assert!(third.changes.is_some_and(|changes| changes.is_empty()));
+    }
+
     #[test]
     fn test_generate_options_builder() {
         let options = GenerateOptions::new("input")
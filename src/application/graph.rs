@@ -0,0 +1,756 @@
+//! Graph export use case.
+//!
+//! Writes the decision graph, the ADRs and the `related` and `supersedes`
+//! relationships between them, in formats other tools render, so teams can
+//! draw it with their own tooling or embed it in documentation.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use serde::Serialize;
+
+use super::source::{LoadedAdrs, SourceOptions, load_adrs};
+use super::validate::escape_xml;
+use crate::domain::{Adr, EdgeType, Graph, GraphGrouping, Node, ValidationIssue};
+use crate::error::Result;
+use crate::infrastructure::{DefaultAdrParser, FileSystem};
+
+/// Output format for the decision graph.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// GraphViz DOT.
+    #[default]
+    Dot,
+    /// Mermaid flowchart, rendered natively in GitHub markdown.
+    Mermaid,
+    /// GraphML, for Gephi, Cytoscape, and yEd.
+    Graphml,
+    /// Node-link JSON, for scripts and network analysis libraries.
+    Json,
+}
+
+impl GraphFormat {
+    /// Infers the format from an output file's extension, defaulting to DOT.
+    #[must_use]
+    pub fn from_path(path: &str) -> Self {
+        Path::new(path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| ext.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl std::str::FromStr for GraphFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "dot" | "gv" => Ok(Self::Dot),
+            "mermaid" | "mmd" => Ok(Self::Mermaid),
+            "graphml" => Ok(Self::Graphml),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("invalid format: {s}")),
+        }
+    }
+}
+
+/// Options for the graph command.
+#[derive(Debug, Clone)]
+pub struct GraphOptions {
+    /// Input directory containing ADR files.
+    pub input_dir: String,
+    /// Glob pattern for matching ADR files.
+    pub pattern: String,
+    /// Where ADR files are discovered from.
+    pub source: SourceOptions,
+    /// Output format.
+    pub format: GraphFormat,
+    /// File the graph is written to; `None` leaves it to the caller.
+    pub output: Option<String>,
+    /// ADR attribute nodes are clustered by, if any.
+    pub grouping: Option<GraphGrouping>,
+}
+
+impl Default for GraphOptions {
+    fn default() -> Self {
+        Self {
+            input_dir: "docs/decisions".to_string(),
+            pattern: "**/*.md".to_string(),
+            source: SourceOptions::default(),
+            format: GraphFormat::Dot,
+            output: None,
+            grouping: None,
+        }
+    }
+}
+
+impl GraphOptions {
+    /// Creates new options with the given input directory.
+    #[must_use]
+    pub fn new(input_dir: impl Into<String>) -> Self {
+        Self {
+            input_dir: input_dir.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the glob pattern for matching files.
+    #[must_use]
+    pub fn with_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.pattern = pattern.into();
+        self
+    }
+
+    /// Sets the ADR source options.
+    #[must_use]
+    pub fn with_source(mut self, source: SourceOptions) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Sets the output format.
+    #[must_use]
+    pub const fn with_format(mut self, format: GraphFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the file the graph is written to.
+    #[must_use]
+    pub fn with_output(mut self, output: impl Into<String>) -> Self {
+        self.output = Some(output.into());
+        self
+    }
+
+    /// Sets the ADR attribute nodes are clustered by.
+    #[must_use]
+    pub const fn with_grouping(mut self, grouping: GraphGrouping) -> Self {
+        self.grouping = Some(grouping);
+        self
+    }
+}
+
+/// Use case for exporting the decision graph.
+#[derive(Debug)]
+pub struct GraphUseCase<F: FileSystem> {
+    fs: F,
+    parser: DefaultAdrParser,
+}
+
+impl<F: FileSystem> GraphUseCase<F> {
+    /// Creates a new graph use case.
+    #[must_use]
+    pub fn new(fs: F) -> Self {
+        Self {
+            fs,
+            parser: DefaultAdrParser::new(),
+        }
+    }
+
+    /// Executes the graph use case.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no ADR files are found or the output file cannot
+    /// be written.
+    pub fn execute(&self, options: &GraphOptions) -> Result<GraphResult> {
+        let LoadedAdrs {
+            adrs,
+            parse_errors,
+            warnings,
+            ..
+        } = load_adrs(
+            &self.fs,
+            &self.parser,
+            &options.input_dir,
+            &options.pattern,
+            &options.source,
+        )?;
+
+        let mut graph = Graph::from_adrs(&adrs);
+        if let Some(grouping) = options.grouping {
+            graph = graph.with_groups(&adrs, grouping);
+        }
+        let output = match options.format {
+            GraphFormat::Dot => format_dot(&graph, &adrs),
+            GraphFormat::Mermaid => format_mermaid(&graph, &adrs),
+            GraphFormat::Graphml => format_graphml(&graph, &adrs),
+            GraphFormat::Json => format_json(&graph, &adrs),
+        };
+        if let Some(path) = &options.output {
+            self.fs.write(Path::new(path), &output)?;
+        }
+
+        Ok(GraphResult {
+            graph,
+            output,
+            parse_errors,
+            warnings,
+        })
+    }
+}
+
+/// Result of the graph use case.
+#[derive(Debug)]
+pub struct GraphResult {
+    /// The decision graph.
+    pub graph: Graph,
+    /// The graph in the requested format.
+    pub output: String,
+    /// Files that failed to parse.
+    pub parse_errors: Vec<(std::path::PathBuf, crate::error::Error)>,
+    /// Non-fatal problems found while loading, such as colliding IDs.
+    pub warnings: Vec<ValidationIssue>,
+}
+
+impl GraphResult {
+    /// Returns true if there were any parse errors.
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        !self.parse_errors.is_empty()
+    }
+}
+
+/// Formats the graph as GraphViz DOT.
+///
+/// ADRs are filled with their status color; referenced ADRs missing from
+/// the collection are drawn dashed and unfilled. Edges point from the ADR
+/// that records the relationship and are labeled with its type. Groups
+/// are drawn as boxed clusters.
+fn format_dot(graph: &Graph, adrs: &[Adr]) -> String {
+    let colors = status_colors(adrs);
+
+    let mut output = String::new();
+    let _ = writeln!(output, "digraph adrs {{");
+    let _ = writeln!(output, "  rankdir=LR;");
+    let _ = writeln!(
+        output,
+        "  node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];"
+    );
+    let _ = writeln!(output, "  edge [fontname=\"Helvetica\", fontsize=10];");
+    let _ = writeln!(output);
+
+    for (i, (group, nodes)) in clusters(graph).into_iter().enumerate() {
+        if let Some(group) = group {
+            let _ = writeln!(output, "  subgraph \"cluster_{i}\" {{");
+            let _ = writeln!(output, "    label=\"{}\";", escape_dot(group));
+            let _ = writeln!(output, "    style=\"rounded,dashed\";");
+        }
+        let indent = if group.is_some() { "    " } else { "  " };
+        for node in nodes {
+            let id = escape_dot(&node.id);
+            match (colors.get(node.id.as_str()), &node.title) {
+                (Some(color), Some(title)) => {
+                    let _ = writeln!(
+                        output,
+                        "{indent}\"{id}\" [label=\"{id}\\n{}\", fillcolor=\"{color}\", tooltip=\"{}\"];",
+                        escape_dot(title),
+                        escape_dot(&node.status)
+                    );
+                },
+                _ => {
+                    let _ = writeln!(output, "{indent}\"{id}\" [style=\"rounded,dashed\"];");
+                },
+            }
+        }
+        if group.is_some() {
+            let _ = writeln!(output, "  }}");
+        }
+    }
+
+    if !graph.edges.is_empty() {
+        let _ = writeln!(output);
+    }
+    for edge in &graph.edges {
+        let style = match edge.edge_type {
+            EdgeType::Related => ", style=dashed",
+            EdgeType::Supersedes => "",
+        };
+        let _ = writeln!(
+            output,
+            "  \"{}\" -> \"{}\" [label=\"{}\"{style}];",
+            escape_dot(&edge.source),
+            escape_dot(&edge.target),
+            edge.edge_type.as_str()
+        );
+    }
+
+    let _ = writeln!(output, "}}");
+    output
+}
+
+/// Formats the graph as a Mermaid `graph TD` flowchart.
+///
+/// Mermaid node IDs cannot hold the characters ADR IDs may contain, so
+/// nodes are numbered and labeled with the ADR ID and title. Colors, line
+/// styles, and groups follow [`format_dot`].
+fn format_mermaid(graph: &Graph, adrs: &[Adr]) -> String {
+    let colors = status_colors(adrs);
+    let keys: HashMap<&str, String> = graph
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (node.id.as_str(), format!("n{i}")))
+        .collect();
+
+    let mut output = String::new();
+    let _ = writeln!(output, "graph TD");
+    let mut styles = String::new();
+    for (i, (group, nodes)) in clusters(graph).into_iter().enumerate() {
+        if let Some(group) = group {
+            let _ = writeln!(output, "  subgraph g{i}[\"{}\"]", escape_mermaid(group));
+        }
+        let indent = if group.is_some() { "    " } else { "  " };
+        for node in nodes {
+            let key = keys.get(node.id.as_str()).map_or("", String::as_str);
+            let id = escape_mermaid(&node.id);
+            match (colors.get(node.id.as_str()), &node.title) {
+                (Some(color), Some(title)) => {
+                    let _ = writeln!(
+                        output,
+                        "{indent}{key}[\"{id}<br/>{}\"]",
+                        escape_mermaid(title)
+                    );
+                    let _ = writeln!(styles, "  style {key} fill:{color}");
+                },
+                _ => {
+                    let _ = writeln!(output, "{indent}{key}[\"{id}\"]:::missing");
+                },
+            }
+        }
+        if group.is_some() {
+            let _ = writeln!(output, "  end");
+        }
+    }
+    for edge in &graph.edges {
+        let (Some(source), Some(target)) = (
+            keys.get(edge.source.as_str()),
+            keys.get(edge.target.as_str()),
+        ) else {
+            continue;
+        };
+        let arrow = match edge.edge_type {
+            EdgeType::Related => "-.->",
+            EdgeType::Supersedes => "-->",
+        };
+        let _ = writeln!(
+            output,
+            "  {source} {arrow}|{}| {target}",
+            edge.edge_type.as_str()
+        );
+    }
+    output.push_str(&styles);
+    let _ = writeln!(output, "  classDef missing fill:none,stroke-dasharray:5 5");
+    output
+}
+
+/// Formats the graph as GraphML.
+///
+/// Nodes carry their title, status, status color, group, degrees, and
+/// centrality as attributes, and edges their type; referenced ADRs missing
+/// from the collection are marked `missing` and have only the metrics.
+fn format_graphml(graph: &Graph, adrs: &[Adr]) -> String {
+    let colors = status_colors(adrs);
+
+    let mut output = String::new();
+    let _ = writeln!(output, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(
+        output,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+    );
+    for (key, domain) in [
+        ("title", "node"),
+        ("status", "node"),
+        ("color", "node"),
+        ("group", "node"),
+        ("type", "edge"),
+    ] {
+        let _ = writeln!(
+            output,
+            r#"  <key id="{key}" for="{domain}" attr.name="{key}" attr.type="string"/>"#
+        );
+    }
+    for (key, kind) in [
+        ("in_degree", "int"),
+        ("out_degree", "int"),
+        ("centrality", "double"),
+    ] {
+        let _ = writeln!(
+            output,
+            r#"  <key id="{key}" for="node" attr.name="{key}" attr.type="{kind}"/>"#
+        );
+    }
+    let _ = writeln!(
+        output,
+        r#"  <key id="missing" for="node" attr.name="missing" attr.type="boolean"><default>false</default></key>"#
+    );
+    let _ = writeln!(output, r#"  <graph id="adrs" edgedefault="directed">"#);
+
+    for node in &graph.nodes {
+        let _ = writeln!(output, r#"    <node id="{}">"#, escape_xml(&node.id));
+        match (colors.get(node.id.as_str()), &node.title) {
+            (Some(color), Some(title)) => {
+                let attributes = [
+                    ("title", Some(title.as_str())),
+                    ("status", Some(node.status.as_str())),
+                    ("color", Some(*color)),
+                    ("group", node.group.as_deref()),
+                ];
+                for (key, value) in attributes
+                    .into_iter()
+                    .filter_map(|(key, value)| Some((key, value?)))
+                {
+                    let _ = writeln!(
+                        output,
+                        r#"      <data key="{key}">{}</data>"#,
+                        escape_xml(value)
+                    );
+                }
+            },
+            _ => {
+                let _ = writeln!(output, r#"      <data key="missing">true</data>"#);
+            },
+        }
+        let _ = writeln!(
+            output,
+            r#"      <data key="in_degree">{}</data>"#,
+            node.in_degree
+        );
+        let _ = writeln!(
+            output,
+            r#"      <data key="out_degree">{}</data>"#,
+            node.out_degree
+        );
+        let _ = writeln!(
+            output,
+            r#"      <data key="centrality">{}</data>"#,
+            node.centrality
+        );
+        let _ = writeln!(output, "    </node>");
+    }
+    for edge in &graph.edges {
+        let _ = writeln!(
+            output,
+            r#"    <edge source="{}" target="{}"><data key="type">{}</data></edge>"#,
+            escape_xml(&edge.source),
+            escape_xml(&edge.target),
+            edge.edge_type.as_str()
+        );
+    }
+
+    let _ = writeln!(output, "  </graph>");
+    let _ = writeln!(output, "</graphml>");
+    output
+}
+
+/// Graph in the node-link JSON layout read by NetworkX and D3.
+#[derive(Serialize)]
+struct NodeLinkGraph<'a> {
+    directed: bool,
+    multigraph: bool,
+    nodes: Vec<NodeLinkNode<'a>>,
+    links: Vec<NodeLinkEdge<'a>>,
+}
+
+/// A node of [`NodeLinkGraph`].
+#[derive(Serialize)]
+struct NodeLinkNode<'a> {
+    id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    current: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<&'a str>,
+    in_degree: usize,
+    out_degree: usize,
+    centrality: f64,
+    missing: bool,
+}
+
+/// A link of [`NodeLinkGraph`].
+#[derive(Serialize)]
+struct NodeLinkEdge<'a> {
+    source: &'a str,
+    target: &'a str,
+    #[serde(rename = "type")]
+    edge_type: EdgeType,
+}
+
+/// Formats the graph as node-link JSON.
+///
+/// Nodes carry the same attributes as in [`format_graphml`], plus the
+/// decision that currently stands in place of a superseded ADR.
+fn format_json(graph: &Graph, adrs: &[Adr]) -> String {
+    let colors = status_colors(adrs);
+    let nodes = graph
+        .nodes
+        .iter()
+        .map(|node| {
+            let color = colors.get(node.id.as_str()).copied();
+            let found = color.is_some() && node.title.is_some();
+            NodeLinkNode {
+                id: &node.id,
+                title: node.title.as_deref().filter(|_| found),
+                status: Some(node.status.as_str()).filter(|_| found),
+                color: color.filter(|_| found),
+                current: node.current.as_deref(),
+                group: node.group.as_deref(),
+                in_degree: node.in_degree,
+                out_degree: node.out_degree,
+                centrality: node.centrality,
+                missing: !found,
+            }
+        })
+        .collect();
+    let links = graph
+        .edges
+        .iter()
+        .map(|edge| NodeLinkEdge {
+            source: &edge.source,
+            target: &edge.target,
+            edge_type: edge.edge_type,
+        })
+        .collect();
+
+    let json = NodeLinkGraph {
+        directed: true,
+        multigraph: false,
+        nodes,
+        links,
+    };
+    serde_json::to_string_pretty(&json).unwrap_or_else(|_| "{}".to_string()) + "\n"
+}
+
+/// Splits the nodes into the ungrouped ones, listed first, and one cluster
+/// per group, keeping the graph's order within each.
+fn clusters(graph: &Graph) -> Vec<(Option<&str>, Vec<&Node>)> {
+    let mut clusters: Vec<(Option<&str>, Vec<&Node>)> = vec![(None, Vec::new())];
+    clusters.extend(
+        graph
+            .groups()
+            .into_iter()
+            .map(|group| (Some(group), Vec::new())),
+    );
+    for node in &graph.nodes {
+        let group = node.group.as_deref();
+        if let Some((_, nodes)) = clusters.iter_mut().find(|(g, _)| *g == group) {
+            nodes.push(node);
+        }
+    }
+    clusters.retain(|(_, nodes)| !nodes.is_empty());
+    clusters
+}
+
+/// Maps the IDs of ADRs to their status colors.
+fn status_colors(adrs: &[Adr]) -> HashMap<&str, &str> {
+    adrs.iter()
+        .map(|adr| (adr.id().as_str(), adr.status_color()))
+        .collect()
+}
+
+/// Escapes text for a quoted Mermaid label, using Mermaid's entity codes.
+fn escape_mermaid(text: &str) -> String {
+    text.replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+        .replace('\n', " ")
+}
+
+/// Escapes text for a quoted DOT string.
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+
+    fn sample_fs() -> InMemoryFileSystem {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0001.md",
+            "---\ntitle: Use MySQL\nstatus: superseded\n---\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            "---\ntitle: Use \"Postgres\"\nstatus: accepted\nsupersedes: [adr-0001.md]\n\
+             related: [adr-0009.md]\n---\n",
+        );
+        fs
+    }
+
+    #[test]
+    fn test_graph_dot() {
+        let result = GraphUseCase::new(sample_fs())
+            .execute(&GraphOptions::new("docs/decisions"))
+            .unwrap();
+
+        assert_eq!(result.graph.node_count(), 3);
+        assert!(!result.has_errors());
+        let dot = &result.output;
+        assert!(dot.starts_with("digraph adrs {\n"));
+        assert!(dot.contains(
+            "  \"adr-0001\" [label=\"adr-0001\\nUse MySQL\", fillcolor=\"#6b7280\", tooltip=\"superseded\"];"
+        ));
+        assert!(dot.contains("label=\"adr-0002\\nUse \\\"Postgres\\\"\", fillcolor=\"#10b981\""));
+        assert!(dot.contains("  \"adr-0009\" [style=\"rounded,dashed\"];"));
+        assert!(dot.contains("  \"adr-0002\" -> \"adr-0001\" [label=\"supersedes\"];"));
+        assert!(dot.contains("  \"adr-0002\" -> \"adr-0009\" [label=\"related\", style=dashed];"));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_graph_mermaid() {
+        let options = GraphOptions::new("docs/decisions").with_format(GraphFormat::Mermaid);
+        let result = GraphUseCase::new(sample_fs()).execute(&options).unwrap();
+
+        assert_eq!(
+            result.output,
+            "graph TD\n\
+             \x20 n0[\"adr-0001<br/>Use MySQL\"]\n\
+             \x20 n1[\"adr-0002<br/>Use #quot;Postgres#quot;\"]\n\
+             \x20 n2[\"adr-0009\"]:::missing\n\
+             \x20 n1 -.->|related| n2\n\
+             \x20 n1 -->|supersedes| n0\n\
+             \x20 style n0 fill:#6b7280\n\
+             \x20 style n1 fill:#10b981\n\
+             \x20 classDef missing fill:none,stroke-dasharray:5 5\n"
+        );
+    }
+
+    #[test]
+    fn test_graph_graphml() {
+        let options = GraphOptions::new("docs/decisions").with_format(GraphFormat::Graphml);
+        let result = GraphUseCase::new(sample_fs()).execute(&options).unwrap();
+
+        let xml = &result.output;
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<graphml"));
+        assert!(xml.contains(r#"<graph id="adrs" edgedefault="directed">"#));
+        assert!(xml.contains(
+            "    <node id=\"adr-0002\">\n\
+             \x20     <data key=\"title\">Use &quot;Postgres&quot;</data>\n\
+             \x20     <data key=\"status\">accepted</data>\n\
+             \x20     <data key=\"color\">#10b981</data>\n\
+             \x20     <data key=\"in_degree\">0</data>\n\
+             \x20     <data key=\"out_degree\">2</data>\n\
+             \x20     <data key=\"centrality\">1</data>\n\
+             \x20   </node>\n"
+        ));
+        assert!(xml.contains(
+            "    <node id=\"adr-0009\">\n\
+             \x20     <data key=\"missing\">true</data>\n\
+             \x20     <data key=\"in_degree\">1</data>\n"
+        ));
+        assert!(xml.contains(
+            r#"<edge source="adr-0002" target="adr-0001"><data key="type">supersedes</data></edge>"#
+        ));
+        assert!(xml.ends_with("</graphml>\n"));
+    }
+
+    #[test]
+    fn test_graph_json() {
+        let options = GraphOptions::new("docs/decisions").with_format(GraphFormat::Json);
+        let result = GraphUseCase::new(sample_fs()).execute(&options).unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&result.output).unwrap();
+        assert_eq!(json["directed"], true);
+        assert_eq!(json["nodes"][1]["centrality"], 1.0);
+        assert_eq!(
+            json["nodes"][0],
+            serde_json::json!({
+                "id": "adr-0001",
+                "title": "Use MySQL",
+                "status": "superseded",
+                "color": "#6b7280",
+                "current": "adr-0002",
+                "in_degree": 1,
+                "out_degree": 0,
+                "centrality": 0.0,
+                "missing": false,
+            })
+        );
+        assert_eq!(
+            json["nodes"][2],
+            serde_json::json!({
+                "id": "adr-0009",
+                "in_degree": 1,
+                "out_degree": 0,
+                "centrality": 0.0,
+                "missing": true,
+            })
+        );
+        assert_eq!(
+            json["links"][1],
+            serde_json::json!({ "source": "adr-0002", "target": "adr-0001", "type": "supersedes" })
+        );
+    }
+
+    #[test]
+    fn test_graph_grouped() {
+        let fs = sample_fs();
+        fs.add_file(
+            "docs/decisions/adr-0003.md",
+            "---\ntitle: Cache sessions\ncategory: storage\n---\n",
+        );
+        let options = GraphOptions::new("docs/decisions").with_grouping(GraphGrouping::Category);
+        let dot = GraphUseCase::new(fs.clone())
+            .execute(&options)
+            .unwrap()
+            .output;
+
+        assert!(dot.contains(
+            "  \"adr-0001\" [label=\"adr-0001\\nUse MySQL\", fillcolor=\"#6b7280\", tooltip=\"superseded\"];\n\
+             \x20 \"adr-0002\" [label="
+        ));
+        assert!(dot.contains(
+            "  subgraph \"cluster_1\" {\n\
+             \x20   label=\"storage\";\n\
+             \x20   style=\"rounded,dashed\";\n\
+             \x20   \"adr-0003\" [label=\"adr-0003\\nCache sessions\", fillcolor=\"#f59e0b\", tooltip=\"proposed\"];\n\
+             \x20 }\n"
+        ));
+
+        let options = options.with_format(GraphFormat::Mermaid);
+        let mermaid = GraphUseCase::new(fs).execute(&options).unwrap().output;
+        assert!(mermaid.contains(
+            "  subgraph g1[\"storage\"]\n\
+             \x20   n2[\"adr-0003<br/>Cache sessions\"]\n\
+             \x20 end\n"
+        ));
+    }
+
+    #[test]
+    fn test_graph_output_file() {
+        let fs = sample_fs();
+        let options = GraphOptions::new("docs/decisions").with_output("docs/decisions.dot");
+        let result = GraphUseCase::new(fs.clone()).execute(&options).unwrap();
+
+        assert_eq!(
+            fs.files().get(Path::new("docs/decisions.dot")),
+            Some(&result.output)
+        );
+    }
+
+    #[test]
+    fn test_graph_format_from_path() {
+        assert_eq!(GraphFormat::from_path("graph.dot"), GraphFormat::Dot);
+        assert_eq!(GraphFormat::from_path("graph.gv"), GraphFormat::Dot);
+        assert_eq!(GraphFormat::from_path("graph.mmd"), GraphFormat::Mermaid);
+        assert_eq!(
+            GraphFormat::from_path("graph.graphml"),
+            GraphFormat::Graphml
+        );
+        assert_eq!(GraphFormat::from_path("graph.json"), GraphFormat::Json);
+        assert_eq!(GraphFormat::from_path("graph"), GraphFormat::Dot);
+    }
+}
@@ -0,0 +1,279 @@
+//! Bootstrap a decisions directory use case.
+//!
+//! Creates the decisions directory with a first ADR recording the decision to
+//! use ADRs, a README explaining the practice, and an `adrscope.toml` whose
+//! settings match the new layout. Existing files are left alone unless
+//! overwriting is requested.
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use time::{Date, OffsetDateTime};
+
+use crate::error::Result;
+use crate::infrastructure::{CONFIG_FILE_NAME, FileSystem};
+
+/// File name of the first ADR.
+const FIRST_ADR: &str = "adr-0001-record-architecture-decisions.md";
+
+/// Body of the first ADR, which also serves as an example to copy.
+const FIRST_ADR_BODY: &str = "\
+## Context
+
+We need to record the architectural decisions made on this project, so
+that new team members can learn why the system looks the way it does and
+decisions are not relitigated without new information.
+
+## Decision
+
+We will use Architecture Decision Records in the structured-madr format,
+kept in this directory and numbered in order (`adr-NNNN-title.md`).
+New records are created with `adrscope new \"Title\"` and start as
+`proposed`; `adrscope accept` and `adrscope deprecate` record their
+outcome.
+
+## Consequences
+
+- Decisions and their rationale are reviewed like code and versioned with it.
+- `adrscope generate` publishes a searchable viewer of every decision.
+- Writing a record takes a few minutes for each significant decision.
+";
+
+/// Options for the init command.
+#[derive(Debug, Clone)]
+pub struct InitOptions {
+    /// Decisions directory to create.
+    pub input_dir: String,
+    /// Whether to overwrite files that already exist.
+    pub force: bool,
+    /// Creation date of the first ADR (defaults to today).
+    pub created: Option<Date>,
+}
+
+impl Default for InitOptions {
+    fn default() -> Self {
+        Self {
+            input_dir: "docs/decisions".to_string(),
+            force: false,
+            created: None,
+        }
+    }
+}
+
+impl InitOptions {
+    /// Creates options that bootstrap the given directory.
+    #[must_use]
+    pub fn new(input_dir: impl Into<String>) -> Self {
+        Self {
+            input_dir: input_dir.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets whether existing files are overwritten.
+    #[must_use]
+    pub const fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Sets the creation date of the first ADR.
+    #[must_use]
+    pub const fn with_created(mut self, created: Date) -> Self {
+        self.created = Some(created);
+        self
+    }
+}
+
+/// Use case for bootstrapping a decisions directory.
+#[derive(Debug)]
+pub struct InitUseCase<F: FileSystem> {
+    fs: F,
+}
+
+impl<F: FileSystem> InitUseCase<F> {
+    /// Creates a new init use case.
+    #[must_use]
+    pub const fn new(fs: F) -> Self {
+        Self { fs }
+    }
+
+    /// Executes the init use case.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or a file cannot be written.
+    pub fn execute(&self, options: &InitOptions) -> Result<InitResult> {
+        let dir = Path::new(&options.input_dir);
+        self.fs.create_dir_all(dir)?;
+
+        let files = [
+            (dir.join(FIRST_ADR), first_adr(options)),
+            (dir.join("README.md"), readme()),
+            (PathBuf::from(CONFIG_FILE_NAME), config(options)),
+        ];
+
+        let mut result = InitResult::default();
+        for (path, content) in files {
+            if self.fs.exists(&path) && !options.force {
+                result.skipped.push(path);
+            } else {
+                self.fs.write(&path, &content)?;
+                result.created.push(path);
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Result of the init use case.
+#[derive(Debug, Default)]
+pub struct InitResult {
+    /// Files written.
+    pub created: Vec<PathBuf>,
+    /// Files that already existed and were left alone.
+    pub skipped: Vec<PathBuf>,
+}
+
+/// Renders the first ADR.
+fn first_adr(options: &InitOptions) -> String {
+    let created = options
+        .created
+        .unwrap_or_else(|| OffsetDateTime::now_utc().date());
+
+    let mut out = String::from("---\n");
+    out.push_str("title: Record architecture decisions\n");
+    out.push_str(
+        "description: Use Architecture Decision Records to document significant decisions\n",
+    );
+    out.push_str("type: adr\n");
+    out.push_str("category: process\n");
+    out.push_str("tags:\n  - adr\n  - documentation\n");
+    out.push_str("status: accepted\n");
+    let _ = writeln!(out, "created: {created}");
+    out.push_str("---\n\n");
+    out.push_str(FIRST_ADR_BODY);
+    out
+}
+
+/// Renders the README explaining the decisions directory.
+fn readme() -> String {
+    format!(
+        "\
+# Architecture Decision Records
+
+This directory records the significant architectural decisions made on this
+project, one markdown file per decision, in the
+[structured-madr](https://github.com/zircote/structured-madr) format.
+
+| ADR | Title | Status |
+|-----|-------|--------|
+| [0001]({FIRST_ADR}) | Record architecture decisions | accepted |
+
+## Working with ADRs
+
+```bash
+adrscope new \"Use PostgreSQL for storage\"   # create the next ADR
+adrscope accept 2                           # record the outcome
+adrscope validate                           # check frontmatter
+adrscope generate                           # build the HTML viewer
+```
+
+Settings shared by these commands live in `adrscope.toml`.
+"
+    )
+}
+
+/// Renders an `adrscope.toml` matching the new layout.
+///
+/// The pattern only matches numbered ADR files, so the README is not read
+/// as an ADR.
+fn config(options: &InitOptions) -> String {
+    let input = toml::Value::String(options.input_dir.clone());
+    format!(
+        "\
+# ADRScope configuration. Command-line flags override these settings;
+# see docs/configuration.md in the ADRScope repository for every option.
+
+input = {input}
+pattern = \"adr-*.md\"
+
+[generate]
+output = \"adrs.html\"
+title = \"Architecture Decision Records\"
+theme = \"auto\"
+
+[wiki]
+output = \"wiki\"
+
+[validate]
+strict = false
+"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::{ValidateOptions, ValidateUseCase};
+    use crate::infrastructure::Config;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+    use time::macros::date;
+
+    #[test]
+    fn test_init_creates_files() {
+        let fs = InMemoryFileSystem::new();
+        let options = InitOptions::new("docs/adr").with_created(date!(2025 - 01 - 15));
+        let result = InitUseCase::new(fs.clone()).execute(&options).unwrap();
+
+        assert_eq!(
+            result.created,
+            vec![
+                PathBuf::from("docs/adr/adr-0001-record-architecture-decisions.md"),
+                PathBuf::from("docs/adr/README.md"),
+                PathBuf::from("adrscope.toml"),
+            ]
+        );
+        assert!(result.skipped.is_empty());
+
+        let config = Config::parse(
+            &fs.read_to_string(Path::new("adrscope.toml")).unwrap(),
+            None,
+        )
+        .expect("config should parse");
+        assert_eq!(config.input.as_deref(), Some("docs/adr"));
+
+        // The generated ADR validates cleanly and the README is not read as an ADR
+        let validation = ValidateUseCase::new(fs)
+            .execute(&ValidateOptions::new("docs/adr").with_pattern("adr-*.md"))
+            .unwrap();
+        assert!(validation.parse_errors.is_empty());
+        assert_eq!(validation.all_issues().count(), 0);
+    }
+
+    #[test]
+    fn test_init_keeps_existing_files() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("adrscope.toml", "input = \"decisions\"\n");
+        let result = InitUseCase::new(fs.clone())
+            .execute(&InitOptions::default())
+            .unwrap();
+
+        assert_eq!(result.created.len(), 2);
+        assert_eq!(result.skipped, vec![PathBuf::from("adrscope.toml")]);
+        assert_eq!(
+            fs.read_to_string(Path::new("adrscope.toml")).unwrap(),
+            "input = \"decisions\"\n"
+        );
+
+        let result = InitUseCase::new(fs.clone())
+            .execute(&InitOptions::default().with_force(true))
+            .unwrap();
+        assert_eq!(result.created.len(), 3);
+        assert!(
+            fs.read_to_string(Path::new("adrscope.toml"))
+                .unwrap()
+                .contains("input = \"docs/decisions\"")
+        );
+    }
+}
@@ -0,0 +1,387 @@
+//! Link use case.
+//!
+//! Connects two ADRs by editing their frontmatter in place. `related` links
+//! are reciprocal: each ADR lists the other. `supersedes` links are listed
+//! by the newer ADR, and mark the older one as superseded with a
+//! `superseded-by` reference back.
+
+use std::path::PathBuf;
+
+use serde_yaml::Value;
+use time::{Date, OffsetDateTime};
+
+use super::source::{LoadedAdrs, SourceOptions, find_adr, load_adrs};
+use crate::domain::{Adr, AdrId, EdgeType, ReferenceResolver, Status};
+use crate::error::{Error, Result};
+use crate::infrastructure::parser::{read_fields, set_field};
+use crate::infrastructure::{DefaultAdrParser, FileSystem};
+
+/// Options for the link command.
+#[derive(Debug, Clone)]
+pub struct LinkOptions {
+    /// Input directory containing ADR files.
+    pub input_dir: String,
+    /// Glob pattern for matching ADR files.
+    pub pattern: String,
+    /// Where ADR files are discovered from.
+    pub source: SourceOptions,
+    /// ADR the link starts from, by ID, file name, or number.
+    pub from: String,
+    /// ADR the link points to, by ID, file name, or number.
+    pub to: String,
+    /// Kind of relationship.
+    pub link_type: EdgeType,
+    /// Date recorded in `updated` when an ADR is superseded (defaults to today).
+    pub date: Option<Date>,
+}
+
+impl Default for LinkOptions {
+    fn default() -> Self {
+        Self {
+            input_dir: "docs/decisions".to_string(),
+            pattern: "**/*.md".to_string(),
+            source: SourceOptions::default(),
+            from: String::new(),
+            to: String::new(),
+            link_type: EdgeType::Related,
+            date: None,
+        }
+    }
+}
+
+impl LinkOptions {
+    /// Creates options linking `from` to `to`.
+    #[must_use]
+    pub fn new(
+        input_dir: impl Into<String>,
+        from: impl Into<String>,
+        to: impl Into<String>,
+        link_type: EdgeType,
+    ) -> Self {
+        Self {
+            input_dir: input_dir.into(),
+            from: from.into(),
+            to: to.into(),
+            link_type,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the glob pattern for matching files.
+    #[must_use]
+    pub fn with_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.pattern = pattern.into();
+        self
+    }
+
+    /// Sets the ADR source options.
+    #[must_use]
+    pub fn with_source(mut self, source: SourceOptions) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Sets the date recorded when an ADR is superseded.
+    #[must_use]
+    pub const fn with_date(mut self, date: Date) -> Self {
+        self.date = Some(date);
+        self
+    }
+}
+
+/// Use case for linking two ADRs.
+#[derive(Debug)]
+pub struct LinkUseCase<F: FileSystem> {
+    fs: F,
+    parser: DefaultAdrParser,
+}
+
+impl<F: FileSystem> LinkUseCase<F> {
+    /// Creates a new link use case.
+    #[must_use]
+    pub fn new(fs: F) -> Self {
+        Self {
+            fs,
+            parser: DefaultAdrParser::new(),
+        }
+    }
+
+    /// Executes the link.
+    ///
+    /// References that already exist are left alone, so linking twice
+    /// changes nothing. Linking an ADR to itself does nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either ADR cannot be found or a file cannot be
+    /// read or written.
+    pub fn execute(&self, options: &LinkOptions) -> Result<LinkResult> {
+        let LoadedAdrs { adrs, .. } = load_adrs(
+            &self.fs,
+            &self.parser,
+            &options.input_dir,
+            &options.pattern,
+            &options.source,
+        )?;
+        let from = find_adr(&adrs, &options.from)?;
+        let to = find_adr(&adrs, &options.to)?;
+
+        let mut result = LinkResult {
+            from: from.id().clone(),
+            to: to.id().clone(),
+            link_type: options.link_type,
+            changes: Vec::new(),
+        };
+        if from.id() == to.id() {
+            return Ok(result);
+        }
+
+        let resolver = ReferenceResolver::new(&adrs);
+        match options.link_type {
+            EdgeType::Related => {
+                self.add_reference(&resolver, from, to, "related", &mut result)?;
+                self.add_reference(&resolver, to, from, "related", &mut result)?;
+            },
+            EdgeType::Supersedes => {
+                self.add_reference(&resolver, from, to, "supersedes", &mut result)?;
+                self.add_reference(&resolver, to, from, "superseded-by", &mut result)?;
+                if to.status() != Status::Superseded {
+                    let date = options
+                        .date
+                        .unwrap_or_else(|| OffsetDateTime::now_utc().date())
+                        .to_string();
+                    let content = self.fs.read_to_string(to.source_path())?;
+                    let updated = set_field(
+                        &content,
+                        "status",
+                        &Value::from(Status::Superseded.as_str()),
+                    )
+                    .and_then(|content| set_field(&content, "updated", &Value::from(date.as_str())))
+                    .ok_or_else(|| invalid_frontmatter(to))?;
+                    self.fs.write(to.source_path(), &updated)?;
+                    result.changes.push(LinkChange {
+                        path: to.source_path().clone(),
+                        field: "status",
+                        value: Status::Superseded.as_str().to_string(),
+                    });
+                }
+            },
+        }
+
+        Ok(result)
+    }
+
+    /// Adds a reference to `target` to a list field of `adr`, unless one of
+    /// its entries already resolves to it.
+    fn add_reference(
+        &self,
+        resolver: &ReferenceResolver,
+        adr: &Adr,
+        target: &Adr,
+        field: &'static str,
+        result: &mut LinkResult,
+    ) -> Result<()> {
+        let existing = match field {
+            "related" => adr.related(),
+            "supersedes" => adr.supersedes(),
+            _ => adr.superseded_by(),
+        };
+        if existing
+            .iter()
+            .any(|reference| resolver.resolve(adr, reference) == Some(target.id().as_str()))
+        {
+            return Ok(());
+        }
+
+        // Prefer the file name, as written by `new`; fall back to the full ID
+        // where the file name alone would resolve elsewhere
+        let reference = if resolver.resolve(adr, target.filename()) == Some(target.id().as_str()) {
+            target.filename().to_string()
+        } else {
+            target.id().to_string()
+        };
+
+        let references = existing
+            .iter()
+            .cloned()
+            .chain([reference.clone()])
+            .map(Value::from)
+            .collect();
+        let content = self.fs.read_to_string(adr.source_path())?;
+
+        // Keep the key the file already uses for `superseded-by`
+        let key = if field == "superseded-by"
+            && read_fields(&content).is_some_and(|fields| fields.contains_key("superseded_by"))
+        {
+            "superseded_by"
+        } else {
+            field
+        };
+        let updated = set_field(&content, key, &Value::Sequence(references))
+            .ok_or_else(|| invalid_frontmatter(adr))?;
+        self.fs.write(adr.source_path(), &updated)?;
+
+        result.changes.push(LinkChange {
+            path: adr.source_path().clone(),
+            field,
+            value: reference,
+        });
+        Ok(())
+    }
+}
+
+/// A frontmatter change made by the link use case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkChange {
+    /// Path of the changed ADR file.
+    pub path: PathBuf,
+    /// Changed field: `related`, `supersedes`, `superseded-by`, or `status`.
+    pub field: &'static str,
+    /// Reference added to a list field, or the new status.
+    pub value: String,
+}
+
+/// Result of the link use case.
+#[derive(Debug)]
+pub struct LinkResult {
+    /// ID of the ADR the link starts from.
+    pub from: AdrId,
+    /// ID of the ADR the link points to.
+    pub to: AdrId,
+    /// Kind of relationship.
+    pub link_type: EdgeType,
+    /// Changes made, empty if the ADRs were already linked.
+    pub changes: Vec<LinkChange>,
+}
+
+fn invalid_frontmatter(adr: &Adr) -> Error {
+    Error::InvalidFrontmatter {
+        path: adr.source_path().clone(),
+        message: "missing or invalid frontmatter delimiters (--- or +++)".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+    use std::path::Path;
+    use time::macros::date;
+
+    fn setup() -> InMemoryFileSystem {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0017.md",
+            "---\ntitle: Use Redis\nstatus: accepted\n---\n\nCaching.\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0042.md",
+            "---\ntitle: Use Valkey\nstatus: proposed\nrelated:\n  - adr-0001.md\n---\n\nCaching.\n",
+        );
+        fs
+    }
+
+    #[test]
+    fn test_link_related_is_reciprocal() {
+        let fs = setup();
+        let use_case = LinkUseCase::new(fs.clone());
+        let options = LinkOptions::new("docs/decisions", "42", "17", EdgeType::Related);
+
+        let result = use_case.execute(&options).unwrap();
+        assert_eq!(result.changes.len(), 2);
+        assert_eq!(
+            fs.read_to_string(Path::new("docs/decisions/adr-0042.md"))
+                .unwrap(),
+            "---\ntitle: Use Valkey\nstatus: proposed\nrelated:\n  - adr-0001.md\n  - adr-0017.md\n---\n\nCaching.\n"
+        );
+        assert_eq!(
+            fs.read_to_string(Path::new("docs/decisions/adr-0017.md"))
+                .unwrap(),
+            "---\ntitle: Use Redis\nstatus: accepted\nrelated:\n  - adr-0042.md\n---\n\nCaching.\n"
+        );
+
+        assert!(use_case.execute(&options).unwrap().changes.is_empty());
+    }
+
+    #[test]
+    fn test_link_supersedes_marks_superseded() {
+        let fs = setup();
+
+        let result = LinkUseCase::new(fs.clone())
+            .execute(
+                &LinkOptions::new(
+                    "docs/decisions",
+                    "adr-0042",
+                    "adr-0017",
+                    EdgeType::Supersedes,
+                )
+                .with_date(date!(2025 - 03 - 01)),
+            )
+            .unwrap();
+
+        assert_eq!(
+            result
+                .changes
+                .iter()
+                .map(|change| change.field)
+                .collect::<Vec<_>>(),
+            vec!["supersedes", "superseded-by", "status"]
+        );
+        assert!(
+            fs.read_to_string(Path::new("docs/decisions/adr-0042.md"))
+                .unwrap()
+                .contains("supersedes:\n  - adr-0017.md\n")
+        );
+        assert_eq!(
+            fs.read_to_string(Path::new("docs/decisions/adr-0017.md"))
+                .unwrap(),
+            "---\ntitle: Use Redis\nstatus: superseded\nsuperseded-by:\n  - adr-0042.md\nupdated: 2025-03-01\n---\n\nCaching.\n"
+        );
+    }
+
+    #[test]
+    fn test_link_supersedes_keeps_superseded_by_key() {
+        let fs = setup();
+        fs.add_file(
+            "docs/decisions/adr-0017.md",
+            "---\ntitle: Use Redis\nstatus: superseded\nsuperseded_by: [adr-0001]\n---\n",
+        );
+        let use_case = LinkUseCase::new(fs.clone());
+        let options = LinkOptions::new("docs/decisions", "42", "17", EdgeType::Supersedes);
+
+        assert_eq!(use_case.execute(&options).unwrap().changes.len(), 2);
+        assert_eq!(
+            fs.read_to_string(Path::new("docs/decisions/adr-0017.md"))
+                .unwrap(),
+            "---\ntitle: Use Redis\nstatus: superseded\nsuperseded_by:\n  - adr-0001\n  - adr-0042.md\n---\n"
+        );
+        assert!(use_case.execute(&options).unwrap().changes.is_empty());
+    }
+
+    #[test]
+    fn test_link_errors() {
+        let use_case = LinkUseCase::new(setup());
+
+        assert!(matches!(
+            use_case.execute(&LinkOptions::new(
+                "docs/decisions",
+                "42",
+                "99",
+                EdgeType::Related
+            )),
+            Err(Error::AdrNotFound(_))
+        ));
+        assert!(
+            use_case
+                .execute(&LinkOptions::new(
+                    "docs/decisions",
+                    "42",
+                    "42",
+                    EdgeType::Related
+                ))
+                .unwrap()
+                .changes
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,368 @@
+//! List use case.
+//!
+//! Prints the ADRs matching a filter and search query as a table, or as
+//! JSON for scripts, to answer questions like "which accepted database
+//! decisions do we have" without opening the viewer.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use super::source::{LoadedAdrs, SourceOptions, load_adrs};
+use crate::domain::{Adr, AdrFilter, SearchField, SearchIndex, SearchQuery, ValidationIssue};
+use crate::error::Result;
+use crate::infrastructure::{DefaultAdrParser, FileSystem};
+
+/// Output format for the list command.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ListFormat {
+    /// Human-readable table.
+    #[default]
+    Text,
+    /// JSON array, for scripts.
+    Json,
+}
+
+/// Options for the list command.
+#[derive(Debug, Clone)]
+pub struct ListOptions {
+    /// Input directory containing ADR files.
+    pub input_dir: String,
+    /// Glob pattern for matching ADR files.
+    pub pattern: String,
+    /// Where ADR files are discovered from.
+    pub source: SourceOptions,
+    /// Which ADRs to list.
+    pub filter: AdrFilter,
+    /// Search query the ADRs must also match.
+    pub query: SearchQuery,
+    /// Output format.
+    pub format: ListFormat,
+}
+
+impl Default for ListOptions {
+    fn default() -> Self {
+        Self {
+            input_dir: "docs/decisions".to_string(),
+            pattern: "**/*.md".to_string(),
+            source: SourceOptions::default(),
+            filter: AdrFilter::default(),
+            query: SearchQuery::default(),
+            format: ListFormat::Text,
+        }
+    }
+}
+
+impl ListOptions {
+    /// Creates new options with the given input directory.
+    #[must_use]
+    pub fn new(input_dir: impl Into<String>) -> Self {
+        Self {
+            input_dir: input_dir.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the glob pattern for matching files.
+    #[must_use]
+    pub fn with_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.pattern = pattern.into();
+        self
+    }
+
+    /// Sets the ADR source options.
+    #[must_use]
+    pub fn with_source(mut self, source: SourceOptions) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Sets the filter selecting which ADRs to list.
+    #[must_use]
+    pub fn with_filter(mut self, filter: AdrFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Sets the search query the ADRs must match.
+    #[must_use]
+    pub fn with_query(mut self, query: SearchQuery) -> Self {
+        self.query = query;
+        self
+    }
+
+    /// Sets the output format.
+    #[must_use]
+    pub const fn with_format(mut self, format: ListFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+/// Use case for listing ADRs.
+#[derive(Debug)]
+pub struct ListUseCase<F: FileSystem> {
+    fs: F,
+    parser: DefaultAdrParser,
+}
+
+impl<F: FileSystem> ListUseCase<F> {
+    /// Creates a new list use case.
+    #[must_use]
+    pub fn new(fs: F) -> Self {
+        Self {
+            fs,
+            parser: DefaultAdrParser::new(),
+        }
+    }
+
+    /// Executes the list use case.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no ADR files are found.
+    pub fn execute(&self, options: &ListOptions) -> Result<ListResult> {
+        let LoadedAdrs {
+            mut adrs,
+            parse_errors,
+            warnings,
+            ..
+        } = load_adrs(
+            &self.fs,
+            &self.parser,
+            &options.input_dir,
+            &options.pattern,
+            &options.source,
+        )?;
+        adrs.retain(|adr| options.filter.matches(adr));
+
+        // A query lists its matches best first
+        if !options.query.is_empty() {
+            let ranks: HashMap<String, usize> = SearchIndex::new(&adrs, SearchField::ALL)
+                .search(&adrs, &options.query)
+                .into_iter()
+                .enumerate()
+                .map(|(rank, (id, _))| (id.to_string(), rank))
+                .collect();
+            adrs.retain(|adr| ranks.contains_key(adr.id().as_str()));
+            adrs.sort_by_key(|adr| ranks[adr.id().as_str()]);
+        }
+
+        let mut result = ListResult {
+            adrs,
+            output: String::new(),
+            parse_errors,
+            warnings,
+        };
+        result.output = match options.format {
+            ListFormat::Text => result.table(),
+            ListFormat::Json => result.json(),
+        };
+        Ok(result)
+    }
+}
+
+/// Result of the list use case.
+#[derive(Debug)]
+pub struct ListResult {
+    /// ADRs matching the filter and query, sorted by ID, or best match
+    /// first for a query.
+    pub adrs: Vec<Adr>,
+    /// List in the requested format.
+    pub output: String,
+    /// Files that failed to parse.
+    pub parse_errors: Vec<(std::path::PathBuf, crate::error::Error)>,
+    /// Non-fatal problems found while loading, such as colliding IDs.
+    pub warnings: Vec<ValidationIssue>,
+}
+
+impl ListResult {
+    /// Returns a table of the ADRs with their status, creation date, and
+    /// category, followed by a count.
+    #[must_use]
+    pub fn table(&self) -> String {
+        if self.adrs.is_empty() {
+            return "No matching ADRs.".to_string();
+        }
+
+        let rows: Vec<[String; 5]> = self
+            .adrs
+            .iter()
+            .map(|adr| {
+                [
+                    adr.id().to_string(),
+                    adr.status_name().to_string(),
+                    adr.created()
+                        .map_or_else(|| "-".to_string(), |date| date.to_string()),
+                    text(adr.category()).unwrap_or("-").to_string(),
+                    adr.title().to_string(),
+                ]
+            })
+            .collect();
+        let header = ["ID", "STATUS", "CREATED", "CATEGORY", "TITLE"].map(str::to_string);
+        let mut widths = [0; 4];
+        for row in std::iter::once(&header).chain(&rows) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let mut output = String::new();
+        for row in std::iter::once(&header).chain(&rows) {
+            for (width, cell) in widths.iter().zip(row) {
+                let _ = write!(output, "{cell:width$}  ");
+            }
+            output.push_str(&row[4]);
+            output.push('\n');
+        }
+        let _ = write!(
+            output,
+            "\n{} ADR{}",
+            self.adrs.len(),
+            if self.adrs.len() == 1 { "" } else { "s" }
+        );
+
+        output
+    }
+
+    /// Returns the ADRs as a JSON array.
+    ///
+    /// Fields an ADR leaves empty are null, and `tags` an empty array.
+    #[must_use]
+    pub fn json(&self) -> String {
+        #[derive(Serialize)]
+        struct Entry<'a> {
+            id: &'a str,
+            title: &'a str,
+            status: &'a str,
+            category: Option<&'a str>,
+            tags: &'a [String],
+            author: Option<&'a str>,
+            created: Option<String>,
+            updated: Option<String>,
+            path: String,
+        }
+
+        let entries: Vec<Entry<'_>> = self
+            .adrs
+            .iter()
+            .map(|adr| Entry {
+                id: adr.id().as_str(),
+                title: adr.title(),
+                status: adr.status_name(),
+                category: text(adr.category()),
+                tags: adr.tags(),
+                author: text(adr.author()),
+                created: adr.created().map(|date| date.to_string()),
+                updated: adr.updated().map(|date| date.to_string()),
+                path: adr.source_path().display().to_string(),
+            })
+            .collect();
+
+        serde_json::to_string_pretty(&entries).unwrap_or_else(|_| "[]".to_string())
+    }
+}
+
+/// Returns `None` for a field left empty.
+fn text(value: &str) -> Option<&str> {
+    Some(value).filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+    use time::macros::date;
+
+    fn sample_fs() -> InMemoryFileSystem {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0001.md",
+            "---\ntitle: Use PostgreSQL\nstatus: accepted\ncategory: database\ntags:\n  - database\ncreated: 2023-03-01\n---\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            "---\ntitle: Use Redis for caching\nstatus: accepted\ntags:\n  - database\n  - cache\ncreated: 2024-05-01\n---\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0003.md",
+            "---\ntitle: Use Kafka\nstatus: proposed\ntags:\n  - database\ncreated: 2024-06-01\n---\n",
+        );
+        fs
+    }
+
+    #[test]
+    fn test_list_table() {
+        let result = ListUseCase::new(sample_fs())
+            .execute(&ListOptions::new("docs/decisions"))
+            .unwrap();
+
+        assert_eq!(
+            result.output,
+            "ID        STATUS    CREATED     CATEGORY  TITLE\n\
+             adr-0001  accepted  2023-03-01  database  Use PostgreSQL\n\
+             adr-0002  accepted  2024-05-01  -         Use Redis for caching\n\
+             adr-0003  proposed  2024-06-01  -         Use Kafka\n\
+             \n3 ADRs"
+        );
+    }
+
+    #[test]
+    fn test_list_filtered_json() {
+        let filter = AdrFilter::new()
+            .with_statuses(vec!["accepted".to_string()])
+            .with_tags(vec!["database".to_string()])
+            .with_since(date!(2024 - 01 - 01));
+        let options = ListOptions::new("docs/decisions")
+            .with_filter(filter)
+            .with_format(ListFormat::Json);
+
+        let result = ListUseCase::new(sample_fs()).execute(&options).unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&result.output).unwrap();
+        let entries = json.as_array().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["id"], "adr-0002");
+        assert_eq!(entries[0]["status"], "accepted");
+        assert_eq!(entries[0]["tags"][1], "cache");
+        assert_eq!(entries[0]["created"], "2024-05-01");
+        assert!(entries[0]["category"].is_null());
+        assert_eq!(entries[0]["path"], "docs/decisions/adr-0002.md");
+    }
+
+    #[test]
+    fn test_list_query() {
+        let options = ListOptions::new("docs/decisions").with_query(SearchQuery::parse(
+            "tag:database status:accepted use -redis",
+        ));
+
+        let result = ListUseCase::new(sample_fs()).execute(&options).unwrap();
+
+        let ids: Vec<_> = result.adrs.iter().map(|adr| adr.id().as_str()).collect();
+        assert_eq!(ids, ["adr-0001"]);
+
+        // Best match first: a title hit outweighs a body hit
+        let fs = sample_fs();
+        fs.add_file(
+            "docs/decisions/adr-0004.md",
+            "---\ntitle: Use Kafka Streams\n---\n\nKafka also caches.\n",
+        );
+        let options = ListOptions::new("docs/decisions").with_query(SearchQuery::parse("cach"));
+        let result = ListUseCase::new(fs).execute(&options).unwrap();
+        let ids: Vec<_> = result.adrs.iter().map(|adr| adr.id().as_str()).collect();
+        assert_eq!(ids, ["adr-0002", "adr-0004"]);
+    }
+
+    #[test]
+    fn test_list_no_matches() {
+        let options = ListOptions::new("docs/decisions")
+            .with_filter(AdrFilter::new().with_tags(vec!["security".to_string()]));
+
+        let result = ListUseCase::new(sample_fs()).execute(&options).unwrap();
+
+        assert!(result.adrs.is_empty());
+        assert_eq!(result.output, "No matching ADRs.");
+        assert_eq!(result.json(), "[]");
+    }
+}
@@ -3,12 +3,53 @@
 //! This module orchestrates domain logic and infrastructure to implement
 //! the core business operations of ADRScope.
 
+mod assets;
+mod browse;
+mod build;
+mod complete;
+mod crosslinks;
+mod edit;
+mod export;
 mod generate;
+mod graph;
+mod init;
+mod link;
+mod list;
+mod new;
+mod open;
+mod rename;
+mod review;
+mod schema;
+mod serve;
+mod source;
 pub mod stats;
+mod transition;
 mod validate;
+mod verify;
 mod wiki;
 
-pub use generate::{GenerateOptions, GenerateResult, GenerateUseCase};
+pub use browse::{BrowseOptions, BrowseResult, BrowseUseCase};
+pub use build::{BuildOptions, BuildOutput, BuildResult, BuildUseCase, OutputStatus};
+pub use complete::{CompleteOptions, CompleteUseCase, Completion, Completions};
+pub use edit::{
+    ChangeKind, ChangedField, EditOptions, EditResult, EditUseCase, FieldChange, FileEdit,
+};
+pub use export::{ExportFormat, ExportOptions, ExportResult, ExportUseCase};
+pub use generate::{
+    GenerateOptions, GenerateResult, GenerateUseCase, OutputChanges, RenderedViewer,
+};
+pub use graph::{GraphFormat, GraphOptions, GraphResult, GraphUseCase};
+pub use init::{InitOptions, InitResult, InitUseCase};
+pub use link::{LinkChange, LinkOptions, LinkResult, LinkUseCase};
+pub use list::{ListFormat, ListOptions, ListResult, ListUseCase};
+pub use new::{NewOptions, NewResult, NewUseCase};
+pub use open::{OpenOptions, OpenResult, OpenTarget, OpenUseCase};
+pub use rename::{RenameOptions, RenameResult, RenameUseCase};
+pub use review::{ReviewFormat, ReviewOptions, ReviewResult, ReviewUseCase};
+pub use serve::{ServeOptions, ServeUseCase};
+pub use source::{DEFAULT_CONVENTIONS, SourceOptions, find_adr};
 pub use stats::{StatsFormat, StatsOptions, StatsResult, StatsUseCase};
-pub use validate::{ValidateOptions, ValidateResult, ValidateUseCase};
+pub use transition::{TransitionOptions, TransitionResult, TransitionUseCase};
+pub use validate::{ValidateFormat, ValidateOptions, ValidateResult, ValidateUseCase};
+pub use verify::{VerifyOptions, VerifyResult, VerifyUseCase};
 pub use wiki::{WikiOptions, WikiResult, WikiUseCase};
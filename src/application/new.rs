@@ -0,0 +1,368 @@
+//! Create a new ADR use case.
+//!
+//! Numbers the new ADR after the existing ones in the input directory and
+//! writes a file with complete structured-madr frontmatter and the standard
+//! body sections, ready to be filled in.
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use time::{Date, OffsetDateTime};
+
+use super::source::{LoadedAdrs, SourceOptions, find_adr, load_adrs};
+use crate::domain::{Adr, AdrId, Status};
+use crate::error::{Error, Result};
+use crate::infrastructure::parser::slugify;
+use crate::infrastructure::{DefaultAdrParser, FileSystem};
+
+/// Body written below the frontmatter of a new ADR.
+const BODY: &str = "\
+## Context
+
+[Describe the problem and the forces at play]
+
+## Decision
+
+[Describe the decision that was made]
+
+## Consequences
+
+[Describe what becomes easier or harder as a result]
+";
+
+/// Options for the new command.
+#[derive(Debug, Clone)]
+pub struct NewOptions {
+    /// Directory the ADR is written to; existing ADRs are read from here.
+    pub input_dir: String,
+    /// Glob pattern for matching existing ADR files.
+    pub pattern: String,
+    /// Title of the new ADR.
+    pub title: String,
+    /// One-sentence summary.
+    pub description: String,
+    /// Decision category.
+    pub category: String,
+    /// Keywords for categorization.
+    pub tags: Vec<String>,
+    /// Related ADRs, by ID, file name, or number.
+    pub related: Vec<String>,
+    /// People or teams who made the decision, written to `author`.
+    pub deciders: Vec<String>,
+    /// Initial status, a built-in or custom status name.
+    pub status: String,
+    /// Creation date (defaults to today).
+    pub created: Option<Date>,
+}
+
+impl Default for NewOptions {
+    fn default() -> Self {
+        Self {
+            input_dir: "docs/decisions".to_string(),
+            pattern: "**/*.md".to_string(),
+            title: String::new(),
+            description: String::new(),
+            category: String::new(),
+            tags: Vec::new(),
+            related: Vec::new(),
+            deciders: Vec::new(),
+            status: Status::Proposed.as_str().to_string(),
+            created: None,
+        }
+    }
+}
+
+impl NewOptions {
+    /// Creates new options for an ADR with the given title.
+    #[must_use]
+    pub fn new(input_dir: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            input_dir: input_dir.into(),
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the glob pattern for matching existing files.
+    #[must_use]
+    pub fn with_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.pattern = pattern.into();
+        self
+    }
+
+    /// Sets the description.
+    #[must_use]
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Sets the category.
+    #[must_use]
+    pub fn with_category(mut self, category: impl Into<String>) -> Self {
+        self.category = category.into();
+        self
+    }
+
+    /// Sets the tags.
+    #[must_use]
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Sets the related ADRs.
+    #[must_use]
+    pub fn with_related(mut self, related: Vec<String>) -> Self {
+        self.related = related;
+        self
+    }
+
+    /// Sets the deciders.
+    #[must_use]
+    pub fn with_deciders(mut self, deciders: Vec<String>) -> Self {
+        self.deciders = deciders;
+        self
+    }
+
+    /// Sets the initial status.
+    #[must_use]
+    pub fn with_status(mut self, status: impl Into<String>) -> Self {
+        self.status = status.into();
+        self
+    }
+
+    /// Sets the creation date.
+    #[must_use]
+    pub const fn with_created(mut self, created: Date) -> Self {
+        self.created = Some(created);
+        self
+    }
+}
+
+/// Use case for creating a new ADR file.
+#[derive(Debug)]
+pub struct NewUseCase<F: FileSystem> {
+    fs: F,
+    parser: DefaultAdrParser,
+}
+
+impl<F: FileSystem> NewUseCase<F> {
+    /// Creates a new ADR creation use case.
+    #[must_use]
+    pub fn new(fs: F) -> Self {
+        Self {
+            fs,
+            parser: DefaultAdrParser::new(),
+        }
+    }
+
+    /// Executes the new use case.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the title is empty, a related ADR cannot be
+    /// resolved, or the file cannot be written.
+    pub fn execute(&self, options: &NewOptions) -> Result<NewResult> {
+        let adrs = self.existing(options)?;
+        let number = adrs
+            .iter()
+            .filter_map(|adr| adr.id().number())
+            .max()
+            .unwrap_or(0)
+            + 1;
+
+        let slug = slugify(&options.title);
+        let filename = if slug.is_empty() {
+            format!("adr-{number:04}.md")
+        } else {
+            format!("adr-{number:04}-{slug}.md")
+        };
+        let path = Path::new(&options.input_dir).join(&filename);
+
+        if options.title.trim().is_empty() {
+            return Err(Error::MissingField {
+                path,
+                field: "title",
+            });
+        }
+        if self.fs.exists(&path) {
+            return Err(Error::FileWrite {
+                path,
+                source: std::io::ErrorKind::AlreadyExists.into(),
+            });
+        }
+
+        let related = options
+            .related
+            .iter()
+            .map(|query| find_adr(&adrs, query).map(|adr| adr.filename().to_string()))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.fs.create_dir_all(Path::new(&options.input_dir))?;
+        self.fs.write(&path, &render(options, &related))?;
+
+        Ok(NewResult {
+            id: AdrId::from_path(&path),
+            path,
+        })
+    }
+
+    /// Loads the ADRs already in the input directory, if any.
+    fn existing(&self, options: &NewOptions) -> Result<Vec<Adr>> {
+        match load_adrs(
+            &self.fs,
+            &self.parser,
+            &options.input_dir,
+            &options.pattern,
+            &SourceOptions::default(),
+        ) {
+            Ok(LoadedAdrs { adrs, .. }) => Ok(adrs),
+            Err(Error::NoAdrsFound { .. }) => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Result of the new use case.
+#[derive(Debug)]
+pub struct NewResult {
+    /// ID of the new ADR.
+    pub id: AdrId,
+    /// Path of the written file.
+    pub path: PathBuf,
+}
+
+/// Renders the file contents of a new ADR.
+fn render(options: &NewOptions, related: &[String]) -> String {
+    let created = options
+        .created
+        .unwrap_or_else(|| OffsetDateTime::now_utc().date());
+
+    let mut out = String::from("---\n");
+    let _ = writeln!(out, "title: {}", scalar(options.title.trim()));
+    if !options.description.is_empty() {
+        let _ = writeln!(out, "description: {}", scalar(&options.description));
+    }
+    out.push_str("type: adr\n");
+    if !options.category.is_empty() {
+        let _ = writeln!(out, "category: {}", scalar(&options.category));
+    }
+    write_list(&mut out, "tags", &options.tags);
+    let _ = writeln!(out, "status: {}", scalar(&options.status));
+    let _ = writeln!(out, "created: {created}");
+    if !options.deciders.is_empty() {
+        let _ = writeln!(out, "author: {}", scalar(&options.deciders.join(", ")));
+    }
+    write_list(&mut out, "related", related);
+    out.push_str("---\n\n");
+    out.push_str(BODY);
+    out
+}
+
+fn write_list(out: &mut String, key: &str, values: &[String]) {
+    if values.is_empty() {
+        return;
+    }
+    let _ = writeln!(out, "{key}:");
+    for value in values {
+        let _ = writeln!(out, "  - {}", scalar(value));
+    }
+}
+
+/// Formats a string as a YAML scalar, quoting it where YAML requires.
+fn scalar(value: &str) -> String {
+    serde_yaml::to_string(value)
+        .map_or_else(|_| format!("{value:?}"), |yaml| yaml.trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+    use crate::infrastructure::parser::AdrParser;
+    use time::macros::date;
+
+    #[test]
+    fn test_new_numbers_after_existing() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0001-first.md",
+            "---\ntitle: First\n---\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0007-seventh.md",
+            "---\ntitle: Seventh\n---\n",
+        );
+
+        let options = NewOptions::new("docs/decisions", "Cache: Use Redis")
+            .with_description("Use Redis for caching")
+            .with_category("infrastructure")
+            .with_tags(vec!["cache".to_string(), "redis".to_string()])
+            .with_related(vec!["7".to_string()])
+            .with_deciders(vec!["Alice".to_string(), "Bob".to_string()])
+            .with_created(date!(2025 - 01 - 15));
+        let result = NewUseCase::new(fs.clone()).execute(&options).unwrap();
+
+        assert_eq!(
+            result.path,
+            PathBuf::from("docs/decisions/adr-0008-cache-use-redis.md")
+        );
+        assert_eq!(result.id.as_str(), "adr-0008-cache-use-redis");
+
+        let content = fs.read_to_string(&result.path).unwrap();
+        let adr = DefaultAdrParser::new()
+            .parse(&result.path, &content)
+            .unwrap();
+        assert_eq!(adr.title(), "Cache: Use Redis");
+        assert_eq!(adr.category(), "infrastructure");
+        assert_eq!(adr.tags(), ["cache", "redis"]);
+        assert_eq!(adr.status(), Status::Proposed);
+        assert_eq!(adr.created(), Some(date!(2025 - 01 - 15)));
+        assert_eq!(adr.author(), "Alice, Bob");
+        assert_eq!(adr.related(), ["adr-0007-seventh.md"]);
+        assert!(content.contains("## Consequences"));
+    }
+
+    #[test]
+    fn test_new_in_empty_directory() {
+        let fs = InMemoryFileSystem::new();
+        let result = NewUseCase::new(fs)
+            .execute(&NewOptions::new("docs/adr", "First decision"))
+            .unwrap();
+
+        assert_eq!(
+            result.path,
+            PathBuf::from("docs/adr/adr-0001-first-decision.md")
+        );
+    }
+
+    #[test]
+    fn test_new_with_custom_status() {
+        let fs = InMemoryFileSystem::new();
+        let result = NewUseCase::new(fs.clone())
+            .execute(&NewOptions::new("docs/adr", "Pause the rollout").with_status("on-hold"))
+            .unwrap();
+
+        let content = fs.read_to_string(&result.path).unwrap();
+        assert!(content.contains("\nstatus: on-hold\n"));
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_input() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", "---\ntitle: First\n---\n");
+        let use_case = NewUseCase::new(fs);
+
+        assert!(matches!(
+            use_case.execute(&NewOptions::new("docs/decisions", "  ")),
+            Err(Error::MissingField { field: "title", .. })
+        ));
+        assert!(matches!(
+            use_case.execute(
+                &NewOptions::new("docs/decisions", "Second").with_related(vec!["9".to_string()])
+            ),
+            Err(Error::AdrNotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,284 @@
+//! Open the viewer or an ADR use case.
+//!
+//! Generates (or locates) the HTML viewer and works out what the `open`
+//! command should show: the viewer, optionally deep-linked to one ADR or one
+//! of its sections, or that ADR's markdown source. Launching the browser or editor is left to
+//! the caller.
+
+use std::path::{Path, PathBuf};
+
+use super::generate::{GenerateOptions, GenerateResult, GenerateUseCase};
+use super::source::{LoadedAdrs, find_adr, load_adrs};
+use crate::domain::AdrId;
+use crate::error::{Error, Result};
+use crate::infrastructure::parser::slugify;
+use crate::infrastructure::renderer::{adr_fragment, section_fragment};
+use crate::infrastructure::{DefaultAdrParser, FileSystem};
+
+/// Options for the open command.
+#[derive(Debug, Clone)]
+pub struct OpenOptions {
+    /// Options used to generate the viewer; also locate the ADR files.
+    pub generate: GenerateOptions,
+    /// ADR to show, by ID, file name, or number.
+    pub id: Option<String>,
+    /// Heading of the ADR's section to scroll to, or its anchor.
+    pub section: Option<String>,
+    /// Whether to show the ADR's markdown source instead of the viewer.
+    pub editor: bool,
+    /// Whether to regenerate the viewer even if it already exists.
+    pub regenerate: bool,
+}
+
+impl Default for OpenOptions {
+    fn default() -> Self {
+        Self {
+            generate: GenerateOptions::default(),
+            id: None,
+            section: None,
+            editor: false,
+            regenerate: true,
+        }
+    }
+}
+
+impl OpenOptions {
+    /// Creates new options generating the viewer with the given options.
+    #[must_use]
+    pub fn new(generate: GenerateOptions) -> Self {
+        Self {
+            generate,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the ADR to show.
+    #[must_use]
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the section of the ADR to scroll to, by heading or anchor.
+    #[must_use]
+    pub fn with_section(mut self, section: impl Into<String>) -> Self {
+        self.section = Some(section.into());
+        self
+    }
+
+    /// Shows the ADR's markdown source instead of the viewer.
+    #[must_use]
+    pub const fn with_editor(mut self, editor: bool) -> Self {
+        self.editor = editor;
+        self
+    }
+
+    /// Sets whether an existing viewer is regenerated.
+    #[must_use]
+    pub const fn with_regenerate(mut self, regenerate: bool) -> Self {
+        self.regenerate = regenerate;
+        self
+    }
+}
+
+/// What the open command should show.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OpenTarget {
+    /// The HTML viewer, with a fragment selecting an ADR.
+    Viewer {
+        /// Path of the viewer file.
+        path: PathBuf,
+        /// URL fragment opening the requested ADR, if any.
+        fragment: Option<String>,
+    },
+    /// The markdown source of an ADR.
+    Source(PathBuf),
+}
+
+/// Use case for opening the viewer or an ADR.
+#[derive(Debug)]
+pub struct OpenUseCase<F: FileSystem + Clone> {
+    fs: F,
+    parser: DefaultAdrParser,
+}
+
+impl<F: FileSystem + Clone> OpenUseCase<F> {
+    /// Creates a new open use case.
+    #[must_use]
+    pub fn new(fs: F) -> Self {
+        Self {
+            fs,
+            parser: DefaultAdrParser::new(),
+        }
+    }
+
+    /// Executes the open use case.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - No ADR, or more than one, matches the requested ID
+    /// - The viewer does not exist and regeneration is disabled
+    /// - Generating the viewer fails
+    pub fn execute(&self, options: &OpenOptions) -> Result<OpenResult> {
+        let adr = options
+            .id
+            .as_deref()
+            .map(|query| self.find(options, query))
+            .transpose()?;
+
+        if options.editor {
+            if let Some((_, source_path)) = adr {
+                return Ok(OpenResult {
+                    target: OpenTarget::Source(source_path),
+                    generated: None,
+                });
+            }
+        }
+
+        let path = Path::new(&options.generate.output);
+        let generated = if options.regenerate {
+            Some(GenerateUseCase::new(self.fs.clone()).execute(&options.generate)?)
+        } else if self.fs.exists(path) {
+            None
+        } else {
+            return Err(Error::FileRead {
+                path: path.to_path_buf(),
+                source: std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    "viewer has not been generated",
+                ),
+            });
+        };
+
+        // A hashed file name is only known once generated
+        let path = generated.as_ref().map_or_else(
+            || path.to_path_buf(),
+            |result| result.output_path.clone().into(),
+        );
+
+        Ok(OpenResult {
+            target: OpenTarget::Viewer {
+                path,
+                fragment: adr.map(|(id, _)| {
+                    options.section.as_ref().map_or_else(
+                        || adr_fragment(&id),
+                        |section| section_fragment(&id, &slugify(section)),
+                    )
+                }),
+            },
+            generated,
+        })
+    }
+
+    /// Resolves a query to the ID and source path of exactly one ADR.
+    fn find(&self, options: &OpenOptions, query: &str) -> Result<(AdrId, PathBuf)> {
+        let LoadedAdrs { adrs, .. } = load_adrs(
+            &self.fs,
+            &self.parser,
+            &options.generate.input_dir,
+            &options.generate.pattern,
+            &options.generate.source,
+        )?;
+
+        let adr = find_adr(&adrs, query)?;
+        Ok((adr.id().clone(), adr.source_path().clone()))
+    }
+}
+
+/// Result of the open use case.
+#[derive(Debug)]
+pub struct OpenResult {
+    /// What to show.
+    pub target: OpenTarget,
+    /// Outcome of generating the viewer, if it was generated.
+    pub generated: Option<GenerateResult>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+
+    fn fixture() -> InMemoryFileSystem {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0017.md",
+            "---\ntitle: Use Kafka\nstatus: accepted\n---\n\nBody\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0018.md",
+            "---\ntitle: Use Redis\nstatus: proposed\n---\n\nBody\n",
+        );
+        fs
+    }
+
+    #[test]
+    fn test_open_viewer_deep_link() {
+        let fs = fixture();
+        let options = OpenOptions::new(GenerateOptions::new("docs/decisions")).with_id("17");
+
+        let result = OpenUseCase::new(fs.clone()).execute(&options).unwrap();
+
+        assert_eq!(
+            result.target,
+            OpenTarget::Viewer {
+                path: PathBuf::from("adrs.html"),
+                fragment: Some("adr=adr-0017".to_string()),
+            }
+        );
+        assert_eq!(result.generated.unwrap().adr_count, 2);
+        assert!(fs.exists(Path::new("adrs.html")));
+
+        let result = OpenUseCase::new(fs)
+            .execute(&options.with_section("Decision Outcome"))
+            .unwrap();
+        assert!(matches!(
+            result.target,
+            OpenTarget::Viewer { fragment: Some(ref f), .. } if f == "adr=adr-0017/decision-outcome"
+        ));
+    }
+
+    #[test]
+    fn test_open_source_in_editor() {
+        let fs = fixture();
+        let options = OpenOptions::new(GenerateOptions::new("docs/decisions"))
+            .with_id("adr-0018.md")
+            .with_editor(true);
+
+        let result = OpenUseCase::new(fs.clone()).execute(&options).unwrap();
+
+        assert_eq!(
+            result.target,
+            OpenTarget::Source(PathBuf::from("docs/decisions/adr-0018.md"))
+        );
+        assert!(result.generated.is_none());
+        assert!(!fs.exists(Path::new("adrs.html")));
+    }
+
+    #[test]
+    fn test_open_existing_viewer() {
+        let fs = fixture();
+        let options =
+            OpenOptions::new(GenerateOptions::new("docs/decisions")).with_regenerate(false);
+        let use_case = OpenUseCase::new(fs.clone());
+
+        assert!(use_case.execute(&options).is_err());
+
+        fs.add_file("adrs.html", "<html></html>");
+        let result = use_case.execute(&options).unwrap();
+        assert!(result.generated.is_none());
+        assert_eq!(
+            fs.read_to_string(Path::new("adrs.html")).unwrap(),
+            "<html></html>"
+        );
+    }
+
+    #[test]
+    fn test_open_unknown_adr() {
+        let options = OpenOptions::new(GenerateOptions::new("docs/decisions")).with_id("99");
+
+        let error = OpenUseCase::new(fixture()).execute(&options).unwrap_err();
+        assert_eq!(error.code(), "E0014");
+    }
+}
@@ -0,0 +1,572 @@
+//! Rename use case.
+//!
+//! Renames an ADR file, and optionally its title, then rewrites every
+//! `related`, `supersedes`, and `superseded-by` reference, every inline
+//! markdown link, and every `[[wikilink]]` to it across the collection so
+//! the decision graph stays intact.
+
+use std::path::{Path, PathBuf};
+
+use serde_yaml::Value;
+
+use super::assets::resolve_relative;
+use super::source::{LoadedAdrs, SourceOptions, find_adr, load_adrs};
+use crate::domain::{Adr, AdrId, ReferenceResolver};
+use crate::error::{Error, Result};
+use crate::infrastructure::parser::{MarkdownRenderer, read_fields, set_field, slugify};
+use crate::infrastructure::{DefaultAdrParser, FileSystem};
+
+/// Options for the rename command.
+#[derive(Debug, Clone)]
+pub struct RenameOptions {
+    /// Input directory containing ADR files.
+    pub input_dir: String,
+    /// Glob pattern for matching ADR files.
+    pub pattern: String,
+    /// Where ADR files are discovered from.
+    pub source: SourceOptions,
+    /// ADR to rename, by ID, file name, or number.
+    pub id: String,
+    /// New file name (`.md` is appended if missing).
+    pub new_name: Option<String>,
+    /// New title; also names the file if no new name is given.
+    pub title: Option<String>,
+    /// Report the changes without writing them.
+    pub dry_run: bool,
+}
+
+impl Default for RenameOptions {
+    fn default() -> Self {
+        Self {
+            input_dir: "docs/decisions".to_string(),
+            pattern: "**/*.md".to_string(),
+            source: SourceOptions::default(),
+            id: String::new(),
+            new_name: None,
+            title: None,
+            dry_run: false,
+        }
+    }
+}
+
+impl RenameOptions {
+    /// Creates options renaming the given ADR.
+    #[must_use]
+    pub fn new(input_dir: impl Into<String>, id: impl Into<String>) -> Self {
+        Self {
+            input_dir: input_dir.into(),
+            id: id.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the glob pattern for matching files.
+    #[must_use]
+    pub fn with_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.pattern = pattern.into();
+        self
+    }
+
+    /// Sets the ADR source options.
+    #[must_use]
+    pub fn with_source(mut self, source: SourceOptions) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Sets the new file name.
+    #[must_use]
+    pub fn with_new_name(mut self, new_name: impl Into<String>) -> Self {
+        self.new_name = Some(new_name.into());
+        self
+    }
+
+    /// Sets the new title.
+    #[must_use]
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets whether to only report the changes.
+    #[must_use]
+    pub const fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+}
+
+/// Use case for renaming an ADR.
+#[derive(Debug)]
+pub struct RenameUseCase<F: FileSystem> {
+    fs: F,
+    parser: DefaultAdrParser,
+    renderer: MarkdownRenderer,
+}
+
+impl<F: FileSystem> RenameUseCase<F> {
+    /// Creates a new rename use case.
+    #[must_use]
+    pub fn new(fs: F) -> Self {
+        Self {
+            fs,
+            parser: DefaultAdrParser::new(),
+            renderer: MarkdownRenderer::new(),
+        }
+    }
+
+    /// Executes the rename.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ADR cannot be found, neither a new name nor a
+    /// title is given, the target file already exists, or a file cannot be
+    /// read or written.
+    pub fn execute(&self, options: &RenameOptions) -> Result<RenameResult> {
+        let LoadedAdrs { adrs, .. } = load_adrs(
+            &self.fs,
+            &self.parser,
+            &options.input_dir,
+            &options.pattern,
+            &options.source,
+        )?;
+        let adr = find_adr(&adrs, &options.id)?;
+
+        let filename = match (&options.new_name, &options.title) {
+            (Some(name), _) => format!("{}.md", name.strip_suffix(".md").unwrap_or(name)),
+            (None, Some(title)) => titled_filename(adr.filename(), title),
+            (None, None) => {
+                return Err(Error::MissingField {
+                    path: adr.source_path().clone(),
+                    field: "title",
+                });
+            },
+        };
+
+        let old_stem = adr.filename().trim_end_matches(".md");
+        let new_stem = filename.trim_end_matches(".md");
+        let new_path = adr.source_path().with_file_name(&filename);
+        if new_path != *adr.source_path() && self.fs.exists(&new_path) {
+            return Err(Error::FileWrite {
+                path: new_path,
+                source: std::io::ErrorKind::AlreadyExists.into(),
+            });
+        }
+
+        let resolver = ReferenceResolver::new(&adrs);
+        let mut rewrites = Vec::new();
+        for other in &adrs {
+            let is_target = other.id() == adr.id();
+            let mut content = self.fs.read_to_string(other.source_path())?;
+            let original = content.clone();
+
+            content = rewrite_references(&content, other, &resolver, adr.id(), new_stem)
+                .ok_or_else(|| invalid_frontmatter(other.source_path()))?;
+
+            if is_target && let Some(title) = &options.title {
+                content = set_field(&content, "title", &Value::from(title.as_str()))
+                    .ok_or_else(|| invalid_frontmatter(other.source_path()))?;
+            }
+
+            for reference in other.wikilinks() {
+                if resolver.resolve(other, reference) == Some(adr.id().as_str()) {
+                    let replacement = rewrite_reference(reference, new_stem);
+                    content = replace_wikilink(&content, reference, &replacement);
+                }
+            }
+
+            // Links are relative to the linking ADR, so a file of the same
+            // name in another directory is left alone
+            let dir = other.source_path().parent().unwrap_or_else(|| "".as_ref());
+            for destination in self.renderer.link_destinations(other.body_markdown()) {
+                let links_to_adr = resolve_relative(dir, &destination)
+                    .is_some_and(|(_, resolved)| resolved == *adr.source_path());
+                if links_to_adr
+                    && let Some(replacement) = rewrite_link(&destination, adr.filename(), &filename)
+                {
+                    content = replace_link(&content, &destination, &replacement);
+                }
+            }
+
+            if is_target || content != original {
+                rewrites.push((other.source_path().clone(), content, is_target));
+            }
+        }
+
+        let mut updated = Vec::new();
+        for (path, content, is_target) in rewrites {
+            if !options.dry_run {
+                self.fs.write(&path, &content)?;
+            }
+            if !is_target {
+                updated.push(path);
+            }
+        }
+        if !options.dry_run && new_path != *adr.source_path() {
+            self.fs.rename(adr.source_path(), &new_path)?;
+        }
+
+        let new_id = match adr.id().as_str().rsplit_once('/') {
+            Some((prefix, stem)) if stem == old_stem => format!("{prefix}/{new_stem}"),
+            _ => new_stem.to_string(),
+        };
+
+        Ok(RenameResult {
+            old_id: adr.id().clone(),
+            new_id: AdrId::new(new_id),
+            old_path: adr.source_path().clone(),
+            new_path,
+            updated,
+            dry_run: options.dry_run,
+        })
+    }
+}
+
+/// Result of the rename use case.
+#[derive(Debug)]
+pub struct RenameResult {
+    /// ID of the ADR before the rename.
+    pub old_id: AdrId,
+    /// ID of the ADR after the rename.
+    pub new_id: AdrId,
+    /// Path of the ADR before the rename.
+    pub old_path: PathBuf,
+    /// Path of the ADR after the rename.
+    pub new_path: PathBuf,
+    /// Other ADRs whose references were rewritten.
+    pub updated: Vec<PathBuf>,
+    /// Whether the changes were only reported.
+    pub dry_run: bool,
+}
+
+/// Builds a file name from a new title, keeping the numbered prefix of the
+/// old name: `adr-0042-use-redis.md` becomes `adr-0042-use-valkey.md`.
+fn titled_filename(old: &str, title: &str) -> String {
+    let stem = old.trim_end_matches(".md");
+    let prefix = stem.find(|c: char| c.is_ascii_digit()).map_or("", |start| {
+        let end = stem[start..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(stem.len(), |offset| start + offset);
+        &stem[..end]
+    });
+
+    match (prefix, slugify(title)) {
+        ("", slug) => format!("{slug}.md"),
+        (prefix, slug) if slug.is_empty() => format!("{prefix}.md"),
+        (prefix, slug) => format!("{prefix}-{slug}.md"),
+    }
+}
+
+/// Points the `related`, `supersedes`, and `superseded-by` references of
+/// `other` that resolve to `target` at the new file name.
+///
+/// Returns `None` if the content has no frontmatter block.
+fn rewrite_references(
+    content: &str,
+    other: &Adr,
+    resolver: &ReferenceResolver,
+    target: &AdrId,
+    new_stem: &str,
+) -> Option<String> {
+    let mut content = content.to_string();
+
+    // Keep the key the file already uses for `superseded-by`
+    let superseded_by =
+        if read_fields(&content).is_some_and(|fields| fields.contains_key("superseded_by")) {
+            "superseded_by"
+        } else {
+            "superseded-by"
+        };
+    for (field, references) in [
+        ("related", other.related()),
+        ("supersedes", other.supersedes()),
+        (superseded_by, other.superseded_by()),
+    ] {
+        let rewritten: Vec<String> = references
+            .iter()
+            .map(|reference| {
+                if resolver.resolve(other, reference) == Some(target.as_str()) {
+                    rewrite_reference(reference, new_stem)
+                } else {
+                    reference.clone()
+                }
+            })
+            .collect();
+        if rewritten != references {
+            let value = Value::Sequence(rewritten.into_iter().map(Value::from).collect());
+            content = set_field(&content, field, &value)?;
+        }
+    }
+    Some(content)
+}
+
+/// Points a frontmatter reference at the new file name, keeping any
+/// package or project qualifier and the `.md` suffix if it had one.
+fn rewrite_reference(reference: &str, new_stem: &str) -> String {
+    let suffix = reference.strip_suffix(".md").map_or("", |_| ".md");
+    match reference.rsplit_once('/') {
+        Some((qualifier, _)) => format!("{qualifier}/{new_stem}{suffix}"),
+        None => format!("{new_stem}{suffix}"),
+    }
+}
+
+/// Returns the new destination of a link to `old`, keeping its directory and
+/// fragment, or `None` if the link points elsewhere.
+fn rewrite_link(destination: &str, old: &str, new: &str) -> Option<String> {
+    let (path, fragment) = destination
+        .find('#')
+        .map_or((destination, ""), |index| destination.split_at(index));
+    let (directory, file) = path
+        .rfind('/')
+        .map_or(("", path), |index| path.split_at(index + 1));
+    (file == old).then(|| format!("{directory}{new}{fragment}"))
+}
+
+/// Replaces a link destination in inline links (`](dest)`, `](dest "title")`,
+/// `](<dest>)`) and reference definitions (`]: dest`).
+fn replace_link(content: &str, old: &str, new: &str) -> String {
+    [("](", ")"), ("](", " "), ("](<", ">"), ("]: ", "")]
+        .iter()
+        .fold(content.to_string(), |content, (open, close)| {
+            content.replace(
+                &format!("{open}{old}{close}"),
+                &format!("{open}{new}{close}"),
+            )
+        })
+}
+
+/// Replaces the target of wikilinks to `old` (`[[old]]`, `[[old.md|label]]`,
+/// `[[old#Section]]`), keeping any `.md` suffix, section, and label.
+fn replace_wikilink(content: &str, old: &str, new: &str) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(start) = rest.find("[[") {
+        let (before, link) = rest.split_at(start + 2);
+        out.push_str(before);
+        let Some(end) = link.find("]]") else {
+            rest = link;
+            break;
+        };
+
+        let inner = &link[..end];
+        let target_end = inner.find(['|', '#']).unwrap_or(inner.len());
+        let target = &inner[..target_end];
+        let reference = target.trim();
+        if reference.strip_suffix(".md").unwrap_or(reference) == old {
+            out.push_str(&target.replacen(old, new, 1));
+            out.push_str(&inner[target_end..]);
+        } else {
+            out.push_str(inner);
+        }
+        rest = &link[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn invalid_frontmatter(path: &Path) -> Error {
+    Error::InvalidFrontmatter {
+        path: path.to_path_buf(),
+        message: "missing or invalid frontmatter delimiters (--- or +++)".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+
+    fn setup() -> InMemoryFileSystem {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0001-use-redis.md",
+            "---\ntitle: Use Redis\nstatus: accepted\n---\n\nCaching.\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0002-cache-keys.md",
+            "---\ntitle: Cache keys\nrelated:\n  - adr-0001-use-redis.md\n---\n\nBuilds on [Redis](adr-0001-use-redis.md#context) and [docs](https://example.com).\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0003-managed-cache.md",
+            "---\ntitle: Managed cache\nsupersedes: [adr-0001-use-redis]\n---\n\nSee [old][1].\n\n[1]: ./adr-0001-use-redis.md\n",
+        );
+        fs
+    }
+
+    #[test]
+    fn test_rename_rewrites_references() {
+        let fs = setup();
+
+        let result = RenameUseCase::new(fs.clone())
+            .execute(&RenameOptions::new("docs/decisions", "1").with_title("Use Valkey"))
+            .unwrap();
+
+        assert_eq!(result.old_id.as_str(), "adr-0001-use-redis");
+        assert_eq!(result.new_id.as_str(), "adr-0001-use-valkey");
+        assert_eq!(
+            result.new_path,
+            PathBuf::from("docs/decisions/adr-0001-use-valkey.md")
+        );
+        assert_eq!(result.updated.len(), 2);
+        assert!(!fs.exists(&result.old_path));
+        assert_eq!(
+            fs.read_to_string(&result.new_path).unwrap(),
+            "---\ntitle: Use Valkey\nstatus: accepted\n---\n\nCaching.\n"
+        );
+        assert_eq!(
+            fs.read_to_string(Path::new("docs/decisions/adr-0002-cache-keys.md"))
+                .unwrap(),
+            "---\ntitle: Cache keys\nrelated:\n  - adr-0001-use-valkey.md\n---\n\nBuilds on [Redis](adr-0001-use-valkey.md#context) and [docs](https://example.com).\n"
+        );
+        assert_eq!(
+            fs.read_to_string(Path::new("docs/decisions/adr-0003-managed-cache.md"))
+                .unwrap(),
+            "---\ntitle: Managed cache\nsupersedes:\n  - adr-0001-use-valkey\n---\n\nSee [old][1].\n\n[1]: ./adr-0001-use-valkey.md\n"
+        );
+    }
+
+    #[test]
+    fn test_rename_rewrites_superseded_by() {
+        let fs = setup();
+        fs.add_file(
+            "docs/decisions/adr-0004-no-cache.md",
+            "---\ntitle: No cache\nsuperseded_by: [adr-0003-managed-cache]\n---\n",
+        );
+
+        RenameUseCase::new(fs.clone())
+            .execute(&RenameOptions::new("docs/decisions", "3").with_title("Hosted cache"))
+            .unwrap();
+
+        assert_eq!(
+            fs.read_to_string(Path::new("docs/decisions/adr-0004-no-cache.md"))
+                .unwrap(),
+            "---\ntitle: No cache\nsuperseded_by:\n  - adr-0003-hosted-cache\n---\n"
+        );
+    }
+
+    #[test]
+    fn test_rename_rewrites_wikilinks() {
+        let fs = setup();
+        fs.add_file(
+            "docs/decisions/adr-0004-cache-ttl.md",
+            "---\ntitle: Cache TTL\n---\n\nAs in [[adr-0001-use-redis]], [[adr-0001-use-redis.md|Redis]], and [[adr-0001-use-redis#Context]]; see [[adr-0002-cache-keys]].\n",
+        );
+
+        let result = RenameUseCase::new(fs.clone())
+            .execute(&RenameOptions::new("docs/decisions", "1").with_title("Use Valkey"))
+            .unwrap();
+
+        assert_eq!(result.updated.len(), 3);
+        assert_eq!(
+            fs.read_to_string(Path::new("docs/decisions/adr-0004-cache-ttl.md"))
+                .unwrap(),
+            "---\ntitle: Cache TTL\n---\n\nAs in [[adr-0001-use-valkey]], [[adr-0001-use-valkey.md|Redis]], and [[adr-0001-use-valkey#Context]]; see [[adr-0002-cache-keys]].\n"
+        );
+    }
+
+    #[test]
+    fn test_rename_rewrites_links_across_packages() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "services/payments/docs/decisions/adr-0001-use-redis.md",
+            "---\ntitle: Use Redis\n---\n",
+        );
+        fs.add_file(
+            "services/billing/docs/decisions/adr-0001-use-redis.md",
+            "---\ntitle: Use Redis for billing\n---\n",
+        );
+        fs.add_file(
+            "services/billing/docs/decisions/adr-0002-cache-keys.md",
+            "---\ntitle: Cache keys\n---\n\nLike [ours](adr-0001-use-redis.md) and [theirs](../../../payments/docs/decisions/adr-0001-use-redis.md).\n",
+        );
+
+        let options = RenameOptions::new(
+            "services/payments/docs/decisions",
+            "services/payments/adr-0001-use-redis",
+        )
+        .with_source(
+            SourceOptions::new().with_inputs(["services/billing/docs/decisions".to_string()]),
+        )
+        .with_title("Use Valkey");
+        let result = RenameUseCase::new(fs.clone()).execute(&options).unwrap();
+
+        assert_eq!(
+            result.new_path,
+            PathBuf::from("services/payments/docs/decisions/adr-0001-use-valkey.md")
+        );
+        // Only the link resolving to the renamed ADR is rewritten
+        assert_eq!(
+            fs.read_to_string(Path::new(
+                "services/billing/docs/decisions/adr-0002-cache-keys.md"
+            ))
+            .unwrap(),
+            "---\ntitle: Cache keys\n---\n\nLike [ours](adr-0001-use-redis.md) and [theirs](../../../payments/docs/decisions/adr-0001-use-valkey.md).\n"
+        );
+        assert!(fs.exists(Path::new(
+            "services/billing/docs/decisions/adr-0001-use-redis.md"
+        )));
+    }
+
+    #[test]
+    fn test_rename_dry_run_and_conflicts() {
+        let fs = setup();
+        let use_case = RenameUseCase::new(fs.clone());
+
+        let result = use_case
+            .execute(
+                &RenameOptions::new("docs/decisions", "1")
+                    .with_new_name("adr-0001-valkey")
+                    .with_dry_run(true),
+            )
+            .unwrap();
+        assert_eq!(result.new_id.as_str(), "adr-0001-valkey");
+        assert_eq!(result.updated.len(), 2);
+        assert!(fs.exists(&result.old_path));
+        assert!(!fs.exists(&result.new_path));
+
+        assert!(matches!(
+            use_case.execute(
+                &RenameOptions::new("docs/decisions", "1").with_new_name("adr-0002-cache-keys.md")
+            ),
+            Err(Error::FileWrite { .. })
+        ));
+        assert!(matches!(
+            use_case.execute(&RenameOptions::new("docs/decisions", "1")),
+            Err(Error::MissingField { .. })
+        ));
+    }
+
+    #[test]
+    fn test_titled_filename() {
+        assert_eq!(
+            titled_filename("adr-0042-use-redis.md", "Use Valkey!"),
+            "adr-0042-use-valkey.md"
+        );
+        assert_eq!(titled_filename("0007.md", "Caching"), "0007-caching.md");
+        assert_eq!(
+            titled_filename("caching.md", "Edge caching"),
+            "edge-caching.md"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_reference_and_link() {
+        assert_eq!(
+            rewrite_reference("payments/adr-0001.md", "adr-0001-x"),
+            "payments/adr-0001-x.md"
+        );
+        assert_eq!(rewrite_reference("adr-0001", "adr-0001-x"), "adr-0001-x");
+        assert_eq!(
+            rewrite_link(
+                "../decisions/adr-0001.md#options",
+                "adr-0001.md",
+                "adr-0001-x.md"
+            ),
+            Some("../decisions/adr-0001-x.md#options".to_string())
+        );
+        assert_eq!(
+            rewrite_link("adr-0010.md", "adr-0001.md", "adr-0001-x.md"),
+            None
+        );
+    }
+}
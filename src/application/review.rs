@@ -0,0 +1,427 @@
+//! Review report use case.
+//!
+//! Lists the decisions whose `review-by` date has passed, or will pass within
+//! a given number of days, for organizations that re-validate decisions
+//! periodically. Besides plain text, the list can be written as JSON or as a
+//! GitHub issue body for scheduled reminders.
+
+use std::fmt::Write;
+
+use serde::Serialize;
+use time::{Date, Duration, OffsetDateTime};
+
+use super::source::{LoadedAdrs, SourceOptions, load_adrs};
+use crate::domain::{Adr, ValidationIssue};
+use crate::error::Result;
+use crate::infrastructure::{DefaultAdrParser, FileSystem};
+
+/// Output format for review reports.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReviewFormat {
+    /// Human-readable text.
+    #[default]
+    Text,
+    /// JSON, for scripts and chat notifications.
+    Json,
+    /// Markdown task list, for a GitHub issue body.
+    Github,
+}
+
+/// Options for the review and remind commands.
+#[derive(Debug, Clone)]
+pub struct ReviewOptions {
+    /// Input directory containing ADR files.
+    pub input_dir: String,
+    /// Glob pattern for matching ADR files.
+    pub pattern: String,
+    /// Where ADR files are discovered from.
+    pub source: SourceOptions,
+    /// Date reviews are checked against (defaults to today).
+    pub date: Option<Date>,
+    /// Also lists decisions due within this many days.
+    pub due_within: u32,
+    /// Output format.
+    pub format: ReviewFormat,
+}
+
+impl Default for ReviewOptions {
+    fn default() -> Self {
+        Self {
+            input_dir: "docs/decisions".to_string(),
+            pattern: "**/*.md".to_string(),
+            source: SourceOptions::default(),
+            date: None,
+            due_within: 0,
+            format: ReviewFormat::Text,
+        }
+    }
+}
+
+impl ReviewOptions {
+    /// Creates new options with the given input directory.
+    #[must_use]
+    pub fn new(input_dir: impl Into<String>) -> Self {
+        Self {
+            input_dir: input_dir.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the glob pattern for matching files.
+    #[must_use]
+    pub fn with_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.pattern = pattern.into();
+        self
+    }
+
+    /// Sets the ADR source options.
+    #[must_use]
+    pub fn with_source(mut self, source: SourceOptions) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Sets the date reviews are checked against.
+    #[must_use]
+    pub const fn with_date(mut self, date: Date) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Sets how many days ahead to look for upcoming reviews.
+    #[must_use]
+    pub const fn with_due_within(mut self, days: u32) -> Self {
+        self.due_within = days;
+        self
+    }
+
+    /// Sets the output format.
+    #[must_use]
+    pub const fn with_format(mut self, format: ReviewFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+/// Use case for listing decisions due for review.
+#[derive(Debug)]
+pub struct ReviewUseCase<F: FileSystem> {
+    fs: F,
+    parser: DefaultAdrParser,
+}
+
+impl<F: FileSystem> ReviewUseCase<F> {
+    /// Creates a new review use case.
+    #[must_use]
+    pub fn new(fs: F) -> Self {
+        Self {
+            fs,
+            parser: DefaultAdrParser::new(),
+        }
+    }
+
+    /// Executes the review use case.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no ADR files are found.
+    pub fn execute(&self, options: &ReviewOptions) -> Result<ReviewResult> {
+        let LoadedAdrs {
+            adrs,
+            parse_errors,
+            warnings,
+            ..
+        } = load_adrs(
+            &self.fs,
+            &self.parser,
+            &options.input_dir,
+            &options.pattern,
+            &options.source,
+        )?;
+
+        let date = options
+            .date
+            .unwrap_or_else(|| OffsetDateTime::now_utc().date());
+        let due_by = date
+            .checked_add(Duration::days(i64::from(options.due_within)))
+            .unwrap_or(Date::MAX);
+
+        // Most overdue first
+        let mut due: Vec<Adr> = adrs
+            .into_iter()
+            .filter(|adr| adr.review_due(due_by))
+            .collect();
+        due.sort_by_key(Adr::review_by);
+
+        let mut result = ReviewResult {
+            date,
+            due_by,
+            due,
+            output: String::new(),
+            parse_errors,
+            warnings,
+        };
+        result.output = match options.format {
+            ReviewFormat::Text => result.summary(),
+            ReviewFormat::Json => result.json(),
+            ReviewFormat::Github => result.github_issue(),
+        };
+        Ok(result)
+    }
+}
+
+/// Result of the review use case.
+#[derive(Debug)]
+pub struct ReviewResult {
+    /// Date reviews were checked against.
+    pub date: Date,
+    /// Last review date included, `date` plus the look-ahead window.
+    pub due_by: Date,
+    /// Decisions due for review, most overdue first.
+    pub due: Vec<Adr>,
+    /// Report in the requested format.
+    pub output: String,
+    /// Files that failed to parse.
+    pub parse_errors: Vec<(std::path::PathBuf, crate::error::Error)>,
+    /// Non-fatal problems found while loading, such as colliding IDs.
+    pub warnings: Vec<ValidationIssue>,
+}
+
+impl ReviewResult {
+    /// Returns how many days past its review date a decision is; negative
+    /// for reviews still ahead.
+    #[must_use]
+    pub fn days_overdue(&self, adr: &Adr) -> i64 {
+        adr.review_by()
+            .map_or(0, |review_by| (self.date - review_by).whole_days())
+    }
+
+    /// Describes when a decision's review is due, e.g. "3 days overdue".
+    fn due_label(&self, adr: &Adr) -> String {
+        match self.days_overdue(adr) {
+            0 => "due today".to_string(),
+            1 => "1 day overdue".to_string(),
+            -1 => "due tomorrow".to_string(),
+            days if days > 0 => format!("{days} days overdue"),
+            days => format!("due in {} days", -days),
+        }
+    }
+
+    /// Returns "as of DATE", or "by DATE" when looking ahead.
+    fn horizon(&self) -> String {
+        if self.due_by == self.date {
+            format!("as of {}", self.date)
+        } else {
+            format!("by {}", self.due_by)
+        }
+    }
+
+    /// Returns a plain text report of the decisions due for review.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        if self.due.is_empty() {
+            return format!("No ADRs due for review {}.", self.horizon());
+        }
+
+        let width = self
+            .due
+            .iter()
+            .map(|adr| adr.id().as_str().len())
+            .max()
+            .unwrap_or(0);
+
+        let mut output = format!(
+            "{} ADR{} due for review {}:\n",
+            self.due.len(),
+            if self.due.len() == 1 { "" } else { "s" },
+            self.horizon()
+        );
+        for adr in &self.due {
+            let overdue = self.due_label(adr);
+            let review_by = adr.review_by().map(|d| d.to_string()).unwrap_or_default();
+            let _ = write!(
+                output,
+                "\n  {:width$}  {review_by}  {overdue:<16}  {}",
+                adr.id().as_str(),
+                adr.title()
+            );
+            if !adr.author().is_empty() {
+                let _ = write!(output, " ({})", adr.author());
+            }
+        }
+
+        output
+    }
+
+    /// Returns the decisions due for review as JSON.
+    ///
+    /// `days_overdue` is negative for reviews still ahead; `owner` is the
+    /// ADR's author, or null.
+    #[must_use]
+    pub fn json(&self) -> String {
+        #[derive(Serialize)]
+        struct Report<'a> {
+            date: String,
+            due_by: String,
+            decisions: Vec<Reminder<'a>>,
+        }
+
+        #[derive(Serialize)]
+        struct Reminder<'a> {
+            id: &'a str,
+            title: &'a str,
+            path: String,
+            status: &'a str,
+            review_by: String,
+            days_overdue: i64,
+            owner: Option<&'a str>,
+        }
+
+        let report = Report {
+            date: self.date.to_string(),
+            due_by: self.due_by.to_string(),
+            decisions: self
+                .due
+                .iter()
+                .map(|adr| Reminder {
+                    id: adr.id().as_str(),
+                    title: adr.title(),
+                    path: adr.source_path().display().to_string(),
+                    status: adr.status().as_str(),
+                    review_by: adr.review_by().map(|d| d.to_string()).unwrap_or_default(),
+                    days_overdue: self.days_overdue(adr),
+                    owner: Some(adr.author()).filter(|author| !author.is_empty()),
+                })
+                .collect(),
+        };
+
+        serde_json::to_string_pretty(&report).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Returns a GitHub issue body with a task per decision due for review,
+    /// or an empty string if none are due, so scheduled jobs can skip
+    /// opening an issue.
+    #[must_use]
+    pub fn github_issue(&self) -> String {
+        if self.due.is_empty() {
+            return String::new();
+        }
+
+        let mut output = format!("## ADRs due for review {}\n\n", self.horizon());
+        for adr in &self.due {
+            let _ = write!(
+                output,
+                "- [ ] **{}** {} (`{}`): review by {}, {}",
+                adr.id(),
+                adr.title(),
+                adr.source_path().display(),
+                adr.review_by().map(|d| d.to_string()).unwrap_or_default(),
+                self.due_label(adr)
+            );
+            if !adr.author().is_empty() {
+                let _ = write!(output, "; owner: {}", adr.author());
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+    use time::macros::date;
+
+    #[test]
+    fn test_review_lists_overdue_decisions() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0001.md",
+            "---\ntitle: Use Redis\nstatus: accepted\nauthor: Platform Team\nreview-by: 2025-05-01\n---\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            "---\ntitle: Use Kafka\nstatus: accepted\nexpires: 2025-06-01\n---\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0003.md",
+            "---\ntitle: Use gRPC\nstatus: accepted\nreview-by: 2025-07-01\n---\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0004.md",
+            "---\ntitle: Use SOAP\nstatus: deprecated\nreview-by: 2025-01-01\n---\n",
+        );
+
+        let result = ReviewUseCase::new(fs)
+            .execute(&ReviewOptions::new("docs/decisions").with_date(date!(2025 - 06 - 01)))
+            .unwrap();
+
+        let ids: Vec<_> = result.due.iter().map(|adr| adr.id().as_str()).collect();
+        assert_eq!(ids, vec!["adr-0001", "adr-0002"]);
+        assert_eq!(result.days_overdue(&result.due[0]), 31);
+        assert_eq!(
+            result.summary(),
+            "2 ADRs due for review as of 2025-06-01:\n\
+             \n  adr-0001  2025-05-01  31 days overdue   Use Redis (Platform Team)\
+             \n  adr-0002  2025-06-01  due today         Use Kafka"
+        );
+    }
+
+    #[test]
+    fn test_remind_due_within() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0001.md",
+            "---\ntitle: Use Redis\nstatus: accepted\nauthor: Platform Team\nreview-by: 2025-05-31\n---\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            "---\ntitle: Use Kafka\nstatus: proposed\nreview-by: 2025-06-20\n---\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0003.md",
+            "---\ntitle: Use gRPC\nstatus: accepted\nreview-by: 2025-08-01\n---\n",
+        );
+
+        let options = ReviewOptions::new("docs/decisions")
+            .with_date(date!(2025 - 06 - 01))
+            .with_due_within(30);
+
+        let result = ReviewUseCase::new(fs.clone())
+            .execute(&options.clone().with_format(ReviewFormat::Json))
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&result.output).unwrap();
+        assert_eq!(json["due_by"], "2025-07-01");
+        assert_eq!(json["decisions"].as_array().unwrap().len(), 2);
+        assert_eq!(json["decisions"][0]["days_overdue"], 1);
+        assert_eq!(json["decisions"][0]["owner"], "Platform Team");
+        assert_eq!(json["decisions"][1]["days_overdue"], -19);
+        assert!(json["decisions"][1]["owner"].is_null());
+
+        let result = ReviewUseCase::new(fs)
+            .execute(&options.with_format(ReviewFormat::Github))
+            .unwrap();
+        assert_eq!(
+            result.output,
+            "## ADRs due for review by 2025-07-01\n\n\
+             - [ ] **adr-0001** Use Redis (`docs/decisions/adr-0001.md`): review by 2025-05-31, 1 day overdue; owner: Platform Team\n\
+             - [ ] **adr-0002** Use Kafka (`docs/decisions/adr-0002.md`): review by 2025-06-20, due in 19 days\n"
+        );
+    }
+
+    #[test]
+    fn test_review_none_due() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", "---\ntitle: Use Redis\n---\n");
+
+        let result = ReviewUseCase::new(fs)
+            .execute(&ReviewOptions::new("docs/decisions").with_date(date!(2025 - 06 - 01)))
+            .unwrap();
+
+        assert!(result.due.is_empty());
+        assert_eq!(result.summary(), "No ADRs due for review as of 2025-06-01.");
+        assert!(result.github_issue().is_empty());
+    }
+}
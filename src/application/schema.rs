@@ -0,0 +1,166 @@
+//! Frontmatter JSON Schemas.
+//!
+//! Organizations extending structured-madr with their own fields can
+//! describe the frontmatter they expect as a JSON Schema. Validate checks
+//! every ADR's frontmatter against it, fields outside the structured-madr
+//! schema included, as the fields were written rather than as ADRScope
+//! reads them.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use jsonschema::Validator;
+use jsonschema::paths::{Location, LocationSegment};
+use serde_json::Value;
+
+use crate::domain::{Adr, ValidationIssue};
+use crate::error::{Error, Result};
+use crate::infrastructure::FileSystem;
+use crate::infrastructure::parser::read_fields;
+
+/// Rule name and code of the errors emitted for schema violations.
+const SCHEMA_RULE: (&str, &str) = ("schema", "V0017");
+
+/// A JSON Schema that ADR frontmatter must conform to.
+#[derive(Debug)]
+pub struct FrontmatterSchema {
+    validator: Validator,
+}
+
+impl FrontmatterSchema {
+    /// Loads a schema from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, is not valid JSON, or is
+    /// not a valid JSON Schema.
+    pub fn load<F: FileSystem>(fs: &F, path: &Path) -> Result<Self> {
+        let invalid = |message: String| Error::InvalidSchema {
+            path: path.to_path_buf(),
+            message,
+        };
+        let schema: Value =
+            serde_json::from_str(&fs.read_to_string(path)?).map_err(|e| invalid(e.to_string()))?;
+        let validator = jsonschema::validator_for(&schema).map_err(|e| invalid(e.to_string()))?;
+        Ok(Self { validator })
+    }
+
+    /// Reports the ways an ADR's frontmatter violates the schema.
+    ///
+    /// ADRs without a frontmatter block, read from their headings instead,
+    /// are not checked.
+    pub fn check<F: FileSystem>(&self, fs: &F, adr: &Adr) -> Vec<ValidationIssue> {
+        let Some(instance) = fs
+            .read_to_string(adr.source_path())
+            .ok()
+            .and_then(|content| read_fields(&content))
+            .and_then(|fields| serde_json::to_value(fields).ok())
+        else {
+            return Vec::new();
+        };
+
+        self.validator
+            .iter_errors(&instance)
+            .map(|error| {
+                let field = field_path(error.instance_path());
+                let message = if field.is_empty() {
+                    error.to_string()
+                } else {
+                    format!("{field}: {error}")
+                };
+                ValidationIssue::error(adr.source_path().clone(), message, SCHEMA_RULE.0)
+                    .with_code(SCHEMA_RULE.1)
+            })
+            .collect()
+    }
+}
+
+/// Formats a location within the frontmatter as a field path, such as
+/// `reviewers[0].team`.
+fn field_path(location: &Location) -> String {
+    let mut path = String::new();
+    for segment in location {
+        match segment {
+            LocationSegment::Index(index) => {
+                let _ = write!(path, "[{index}]");
+            },
+            LocationSegment::Property(name) => {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(&name);
+            },
+        }
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+    use crate::infrastructure::{AdrParser, DefaultAdrParser};
+
+    const SCHEMA: &str = r#"{
+        "type": "object",
+        "required": ["title", "cost-center"],
+        "properties": {
+            "cost-center": { "type": "string", "pattern": "^CC-[0-9]+$" },
+            "reviewers": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": { "team": { "enum": ["platform", "security"] } }
+                }
+            }
+        }
+    }"#;
+
+    fn check(content: &str) -> Vec<String> {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("schema.json", SCHEMA);
+        fs.add_file("docs/decisions/adr-0001.md", content);
+        let adr = DefaultAdrParser::new()
+            .parse(Path::new("docs/decisions/adr-0001.md"), content)
+            .unwrap();
+
+        let schema = FrontmatterSchema::load(&fs, Path::new("schema.json")).unwrap();
+        schema
+            .check(&fs, &adr)
+            .into_iter()
+            .map(|issue| {
+                assert_eq!(issue.code.as_deref(), Some("V0017"));
+                issue.message
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_schema_check() {
+        assert!(check("---\ntitle: One\ncost-center: CC-42\n---\n").is_empty());
+
+        let messages = check("---\ntitle: One\nreviewers:\n  - team: payments\n---\n");
+        assert_eq!(messages.len(), 2);
+        assert!(messages[0].contains("\"cost-center\" is a required property"));
+        assert!(messages[1].starts_with("reviewers[0].team: "));
+
+        let messages = check("---\ntitle: One\ncost-center: 42\n---\n");
+        assert_eq!(messages, ["cost-center: 42 is not of type \"string\""]);
+    }
+
+    #[test]
+    fn test_schema_load_errors() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("broken.json", "{ not json");
+        fs.add_file("invalid.json", r#"{ "type": "no-such-type" }"#);
+
+        for path in ["broken.json", "invalid.json"] {
+            let result = FrontmatterSchema::load(&fs, Path::new(path));
+            assert!(matches!(result, Err(Error::InvalidSchema { .. })), "{path}");
+        }
+        assert!(matches!(
+            FrontmatterSchema::load(&fs, Path::new("missing.json")),
+            Err(Error::FileRead { .. })
+        ));
+    }
+}
@@ -0,0 +1,216 @@
+//! Local preview server use case.
+//!
+//! Answers the requests of the `serve` command: the viewer, rendered in
+//! memory from the current ADR files on every request, and the raw markdown
+//! of each ADR. Listening for connections is left to the caller.
+//!
+//! | Path | Response |
+//! |------|----------|
+//! | `/` | The viewer |
+//! | `/adrs/<ADR>` | Redirect to the viewer with the ADR selected |
+//! | `/adrs/<ADR>.md` | The ADR's markdown source |
+//!
+//! ADRs can be given by ID, file name, or number, as with `open`.
+
+use super::generate::{GenerateOptions, GenerateUseCase, RenderedViewer};
+use super::source::{LoadedAdrs, find_adr, load_adrs};
+use crate::error::{Error, Result};
+use crate::infrastructure::http::Response;
+use crate::infrastructure::renderer::adr_fragment;
+use crate::infrastructure::{DefaultAdrParser, FileSystem};
+
+/// Path prefix under which ADRs are served.
+const ADR_PREFIX: &str = "/adrs/";
+
+/// Options for the serve command.
+#[derive(Debug, Clone)]
+pub struct ServeOptions {
+    /// Options used to render the viewer; also locate the ADR files.
+    pub generate: GenerateOptions,
+    /// Host name or address to listen on.
+    pub host: String,
+    /// Port to listen on.
+    pub port: u16,
+}
+
+impl Default for ServeOptions {
+    fn default() -> Self {
+        Self {
+            generate: GenerateOptions::default(),
+            host: "127.0.0.1".to_string(),
+            port: 8080,
+        }
+    }
+}
+
+impl ServeOptions {
+    /// Creates new options rendering the viewer with the given options.
+    #[must_use]
+    pub fn new(generate: GenerateOptions) -> Self {
+        Self {
+            generate,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the host to listen on.
+    #[must_use]
+    pub fn with_host(mut self, host: impl Into<String>) -> Self {
+        self.host = host.into();
+        self
+    }
+
+    /// Sets the port to listen on.
+    #[must_use]
+    pub const fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    /// Returns the URL the viewer is served at.
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!("http://{}:{}/", self.host, self.port)
+    }
+}
+
+/// Use case for serving the viewer and ADR sources.
+#[derive(Debug)]
+pub struct ServeUseCase<F: FileSystem + Clone> {
+    fs: F,
+    parser: DefaultAdrParser,
+}
+
+impl<F: FileSystem + Clone> ServeUseCase<F> {
+    /// Creates a new serve use case.
+    #[must_use]
+    pub fn new(fs: F) -> Self {
+        Self {
+            fs,
+            parser: DefaultAdrParser::new(),
+        }
+    }
+
+    /// Renders the viewer from the current ADR files.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no ADR files are found or rendering fails.
+    pub fn render(&self, options: &ServeOptions) -> Result<RenderedViewer> {
+        GenerateUseCase::new(self.fs.clone()).render(&options.generate)
+    }
+
+    /// Answers a request for `path`.
+    ///
+    /// Unknown paths and ADRs are answered with `404 Not Found`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ADRs cannot be loaded or the viewer cannot be
+    /// rendered.
+    pub fn respond(&self, options: &ServeOptions, path: &str) -> Result<Response> {
+        if path == "/" || path == "/index.html" {
+            let viewer = self.render(options)?;
+            return Ok(Response::ok("text/html; charset=utf-8", viewer.html));
+        }
+
+        let Some(query) = path.strip_prefix(ADR_PREFIX).filter(|q| !q.is_empty()) else {
+            return Ok(Response::not_found(format!("{path} not found")));
+        };
+
+        let (query, markdown) = query
+            .strip_suffix(".md")
+            .map_or((query, false), |query| (query, true));
+        let generate = &options.generate;
+        let LoadedAdrs { adrs, .. } = load_adrs(
+            &self.fs,
+            &self.parser,
+            &generate.input_dir,
+            &generate.pattern,
+            &generate.source,
+        )?;
+        let adr = match find_adr(&adrs, query) {
+            Ok(adr) => adr,
+            Err(err @ (Error::AdrNotFound(_) | Error::AmbiguousAdr { .. })) => {
+                return Ok(Response::not_found(err.to_string()));
+            },
+            Err(err) => return Err(err),
+        };
+
+        if markdown {
+            let markdown = self.fs.read_to_string(adr.source_path())?;
+            Ok(Response::ok("text/markdown; charset=utf-8", markdown))
+        } else {
+            Ok(Response::redirect(format!("/#{}", adr_fragment(adr.id()))))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+
+    const ADR: &str = "---\ntitle: Use Kafka\nstatus: accepted\n---\n\nBody\n";
+
+    fn fixture() -> (ServeUseCase<InMemoryFileSystem>, ServeOptions) {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0017.md", ADR);
+        fs.add_file(
+            "docs/decisions/payments/adr-0018.md",
+            "---\ntitle: Use Redis\nstatus: proposed\n---\n\nBody\n",
+        );
+        (ServeUseCase::new(fs), ServeOptions::default())
+    }
+
+    fn text(response: &Response) -> &str {
+        std::str::from_utf8(&response.body).expect("body should be UTF-8")
+    }
+
+    #[test]
+    fn test_serve_viewer() {
+        let (use_case, options) = fixture();
+        let response = use_case.respond(&options, "/").expect("should respond");
+        assert_eq!(response.status, 200);
+        assert_eq!(response.content_type, "text/html; charset=utf-8");
+        assert!(text(&response).contains("Use Kafka"));
+    }
+
+    #[test]
+    fn test_serve_markdown() {
+        let (use_case, options) = fixture();
+        for path in ["/adrs/adr-0017.md", "/adrs/17.md"] {
+            let response = use_case.respond(&options, path).expect("should respond");
+            assert_eq!(response.status, 200, "{path}");
+            assert_eq!(response.content_type, "text/markdown; charset=utf-8");
+            assert_eq!(text(&response), ADR);
+        }
+    }
+
+    #[test]
+    fn test_serve_deep_link() {
+        let (use_case, options) = fixture();
+        let response = use_case
+            .respond(&options, "/adrs/18")
+            .expect("should respond");
+        assert_eq!(response.status, 302);
+        assert_eq!(response.location.as_deref(), Some("/#adr=adr-0018"));
+    }
+
+    #[test]
+    fn test_serve_not_found() {
+        let (use_case, options) = fixture();
+        for path in ["/favicon.ico", "/adrs/", "/adrs/adr-0099.md", "/adrs/99"] {
+            let response = use_case.respond(&options, path).expect("should respond");
+            assert_eq!(response.status, 404, "{path}");
+        }
+    }
+
+    #[test]
+    fn test_serve_url() {
+        let options = ServeOptions::default()
+            .with_host("localhost")
+            .with_port(4000);
+        assert_eq!(options.url(), "http://localhost:4000/");
+    }
+}
@@ -0,0 +1,906 @@
+//! ADR source discovery and loading.
+//!
+//! Shared by every use case: finds ADR files (under one or more input
+//! directories, or by walking a monorepo for decision directories), parses
+//! them in parallel, and returns them in a deterministic order.
+
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::path::{Component, Path, PathBuf};
+use std::thread;
+
+use glob::{MatchOptions, Pattern};
+
+use crate::domain::{Adr, AdrId, CustomStatus, Status, TeamMapping, ValidationIssue, find_adrs};
+use crate::error::{Error, Result};
+use crate::infrastructure::{AdrParser, FileSystem};
+
+/// Directory conventions that identify a decision directory during discovery.
+pub const DEFAULT_CONVENTIONS: &[&str] = &["docs/decisions", "docs/adr", "adr"];
+
+/// Rule name and code of the warning emitted for colliding ADR IDs.
+const UNIQUE_IDS_RULE: (&str, &str) = ("unique-ids", "V0003");
+
+/// Directories that are never treated as part of a package during discovery.
+const IGNORED_DIRS: &[&str] = &["node_modules", "target", "vendor"];
+
+/// Fewest files given to each parsing thread; smaller sets are parsed on
+/// the calling thread, where spawning would cost more than it saves.
+const FILES_PER_THREAD: usize = 32;
+
+/// Options controlling where ADR files are loaded from and how they are read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceOptions {
+    /// Monorepo root to walk for decision directories instead of the input directory.
+    pub discover_root: Option<String>,
+    /// Further input directories whose ADRs are merged with the input directory's.
+    pub inputs: Vec<String>,
+    /// Directory conventions that identify a decision directory during discovery.
+    pub conventions: Vec<String>,
+    /// Nonstandard status values mapped to canonical statuses.
+    pub status_synonyms: BTreeMap<String, Status>,
+    /// Statuses defined on top of the built-in lifecycle.
+    pub custom_statuses: Vec<CustomStatus>,
+    /// Mapping of ADRs to their owning teams.
+    pub teams: TeamMapping,
+    /// Glob patterns of files to leave out, such as templates and drafts.
+    pub exclude: Vec<String>,
+}
+
+impl Default for SourceOptions {
+    fn default() -> Self {
+        Self {
+            discover_root: None,
+            inputs: Vec::new(),
+            conventions: DEFAULT_CONVENTIONS
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            status_synonyms: BTreeMap::new(),
+            custom_statuses: Vec::new(),
+            teams: TeamMapping::default(),
+            exclude: Vec::new(),
+        }
+    }
+}
+
+impl SourceOptions {
+    /// Creates source options that read ADRs from the input directory only.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables monorepo discovery rooted at the given directory.
+    #[must_use]
+    pub fn with_discover(mut self, root: impl Into<String>) -> Self {
+        self.discover_root = Some(root.into());
+        self
+    }
+
+    /// Adds input directories whose ADRs are merged with the input directory's.
+    ///
+    /// When several directories are read, each ADR is assigned a package
+    /// named after its directory: the path preceding a conventional decision
+    /// directory (`services/payments` for `services/payments/docs/decisions`),
+    /// or the whole path otherwise. IDs are qualified with it as in discovery.
+    #[must_use]
+    pub fn with_inputs(mut self, inputs: impl IntoIterator<Item = String>) -> Self {
+        self.inputs.extend(inputs);
+        self
+    }
+
+    /// Sets the directory conventions used during discovery.
+    #[must_use]
+    pub fn with_conventions(mut self, conventions: Vec<String>) -> Self {
+        self.conventions = conventions;
+        self
+    }
+
+    /// Sets the synonyms mapping nonstandard status values to canonical ones.
+    #[must_use]
+    pub fn with_status_synonyms(mut self, synonyms: BTreeMap<String, Status>) -> Self {
+        self.status_synonyms = synonyms;
+        self
+    }
+
+    /// Sets the statuses defined on top of the built-in lifecycle.
+    ///
+    /// ADRs in a custom status count as its base status and are displayed
+    /// under its name. Custom statuses take precedence over synonyms.
+    #[must_use]
+    pub fn with_custom_statuses(mut self, statuses: Vec<CustomStatus>) -> Self {
+        self.custom_statuses = statuses;
+        self
+    }
+
+    /// Sets the mapping of ADRs to their owning teams.
+    #[must_use]
+    pub fn with_teams(mut self, teams: TeamMapping) -> Self {
+        self.teams = teams;
+        self
+    }
+
+    /// Adds glob patterns of files to leave out.
+    ///
+    /// As in `.gitignore`, a pattern without a `/` matches the file name at
+    /// any depth (`template.md`); other patterns match the path relative to
+    /// the input directory or discovery root (`**/drafts/**`).
+    #[must_use]
+    pub fn with_exclude(mut self, patterns: impl IntoIterator<Item = String>) -> Self {
+        self.exclude.extend(patterns);
+        self
+    }
+}
+
+/// ADRs loaded from a source, along with the files that failed to parse.
+#[derive(Debug)]
+pub struct LoadedAdrs {
+    /// Directory the ADRs were loaded from (the discovery root when discovering,
+    /// or a comma-separated list when merging several input directories).
+    pub source_dir: String,
+    /// Successfully parsed ADRs, sorted by ID.
+    pub adrs: Vec<Adr>,
+    /// Files that failed to read or parse.
+    pub parse_errors: Vec<(PathBuf, Error)>,
+    /// Non-fatal problems found while loading, such as colliding IDs.
+    pub warnings: Vec<ValidationIssue>,
+}
+
+/// Discovers and parses ADR files.
+///
+/// # Errors
+///
+/// Returns an error if a glob pattern is invalid or no ADR files are found.
+pub fn load_adrs<F: FileSystem, P: AdrParser>(
+    fs: &F,
+    parser: &P,
+    input_dir: &str,
+    pattern: &str,
+    source: &SourceOptions,
+) -> Result<LoadedAdrs> {
+    let mut input_dirs = vec![input_dir];
+    for dir in &source.inputs {
+        if !input_dirs.contains(&dir.as_str()) {
+            input_dirs.push(dir);
+        }
+    }
+    let source_dir = source
+        .discover_root
+        .clone()
+        .unwrap_or_else(|| input_dirs.join(", "));
+
+    let exclude = source
+        .exclude
+        .iter()
+        .map(|pattern| {
+            Pattern::new(pattern).map_err(|e| Error::GlobPattern(format!("{pattern}: {e}")))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let conventions = split_conventions(&source.conventions);
+    let mut files = Vec::new();
+    if let Some(root) = &source.discover_root {
+        let root = Path::new(root);
+        files = discover_files(fs, root, pattern, &conventions)?;
+        files.retain(|(path, _)| !is_excluded(path, root, &exclude));
+    } else {
+        for dir in &input_dirs {
+            let package = (input_dirs.len() > 1)
+                .then(|| input_package(dir, &conventions))
+                .flatten();
+            let base = Path::new(dir);
+            files.extend(
+                fs.glob(base, pattern)?
+                    .into_iter()
+                    .filter(|path| !is_excluded(path, base, &exclude))
+                    .map(|path| (path, package.clone())),
+            );
+        }
+    }
+
+    if files.is_empty() {
+        return Err(Error::NoAdrsFound {
+            path: PathBuf::from(source_dir),
+        });
+    }
+
+    let mut adrs = Vec::with_capacity(files.len());
+    let mut parse_errors = Vec::new();
+
+    let threads = thread::available_parallelism()
+        .map_or(1, NonZeroUsize::get)
+        .min(files.len() / FILES_PER_THREAD);
+    for (path, parsed) in parse_files(fs, parser, source, &files, threads) {
+        match parsed {
+            Ok(adr) => adrs.push(adr),
+            Err(e) => parse_errors.push((path, e)),
+        }
+    }
+
+    let warnings = disambiguate_ids(&mut adrs);
+
+    // Sort by ID for consistent ordering
+    adrs.sort_by(|a, b| a.id().cmp(b.id()));
+
+    Ok(LoadedAdrs {
+        source_dir,
+        adrs,
+        parse_errors,
+        warnings,
+    })
+}
+
+/// Returns true if any exclude pattern matches the file (see
+/// [`SourceOptions::with_exclude`]).
+fn is_excluded(path: &Path, base: &Path, exclude: &[Pattern]) -> bool {
+    let options = MatchOptions {
+        require_literal_separator: true,
+        ..MatchOptions::new()
+    };
+    let relative = path.strip_prefix(base).unwrap_or(path);
+    exclude.iter().any(|pattern| {
+        if pattern.as_str().contains('/') {
+            pattern.matches_path_with(relative, options)
+        } else {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| pattern.matches_with(name, options))
+        }
+    })
+}
+
+/// Reads and parses files, returning the results in the order of `files`.
+///
+/// With more than one thread, the files are split into contiguous chunks
+/// parsed on scoped threads; joining the chunks in order keeps the result
+/// independent of scheduling.
+fn parse_files<F: FileSystem, P: AdrParser>(
+    fs: &F,
+    parser: &P,
+    source: &SourceOptions,
+    files: &[(PathBuf, Option<String>)],
+    threads: usize,
+) -> Vec<(PathBuf, Result<Adr>)> {
+    let parse = |(path, package): &(PathBuf, Option<String>)| {
+        let parsed = fs
+            .read_to_string(path)
+            .and_then(|content| parser.parse(path, &content))
+            .map(|adr| {
+                let team = source.teams.team_for(&adr).map(str::to_string);
+                let adr = adr
+                    .with_custom_statuses(&source.custom_statuses)
+                    .with_status_synonyms(&source.status_synonyms)
+                    .with_team(team);
+                match package {
+                    Some(package) => adr.with_package(package.clone()),
+                    None => adr,
+                }
+            });
+        (path.clone(), parsed)
+    };
+
+    if threads <= 1 {
+        return files.iter().map(parse).collect();
+    }
+
+    let parse = &parse;
+    thread::scope(|scope| {
+        // Spawn every chunk before joining any, so they run concurrently
+        let mut handles = Vec::with_capacity(threads);
+        for chunk in files.chunks(files.len().div_ceil(threads)) {
+            handles.push(scope.spawn(move || chunk.iter().map(parse).collect::<Vec<_>>()));
+        }
+        handles
+            .into_iter()
+            .flat_map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    })
+}
+
+/// Resolves an ID, file name, or number to exactly one ADR.
+///
+/// # Errors
+///
+/// Returns an error if no ADR or more than one ADR matches.
+pub fn find_adr<'a>(adrs: &'a [Adr], query: &str) -> Result<&'a Adr> {
+    match find_adrs(adrs, query).as_slice() {
+        [] => Err(Error::AdrNotFound(query.to_string())),
+        [adr] => Ok(adr),
+        matches => Err(Error::AmbiguousAdr {
+            query: query.to_string(),
+            matches: matches.iter().map(|adr| adr.id().to_string()).collect(),
+        }),
+    }
+}
+
+/// Renames ADRs whose IDs collide, returning a warning for each rename.
+///
+/// Colliding ADRs are qualified with their directory relative to the
+/// deepest directory the colliding files share, so
+/// `decisions/adr-0001.md` keeps its ID while `decisions/archive/adr-0001.md`
+/// becomes `archive/adr-0001`. Files in the same directory (differing only in
+/// extension) fall back to their full filename.
+fn disambiguate_ids(adrs: &mut [Adr]) -> Vec<ValidationIssue> {
+    let mut groups: BTreeMap<AdrId, Vec<usize>> = BTreeMap::new();
+    for (index, adr) in adrs.iter().enumerate() {
+        groups.entry(adr.id().clone()).or_default().push(index);
+    }
+
+    let mut warnings = Vec::new();
+    for (id, mut indices) in groups.into_iter().filter(|(_, g)| g.len() > 1) {
+        indices.sort_by(|&a, &b| adrs[a].source_path().cmp(adrs[b].source_path()));
+
+        let paths: Vec<PathBuf> = indices
+            .iter()
+            .map(|&i| adrs[i].source_path().clone())
+            .collect();
+        let common = common_parent(&paths);
+        let mut renamed: Vec<String> = paths
+            .iter()
+            .map(|path| qualified_id(&id, path, &common, false))
+            .collect();
+        for (i, path) in paths.iter().enumerate() {
+            if renamed.iter().filter(|r| **r == renamed[i]).count() > 1 {
+                renamed[i] = qualified_id(&id, path, &common, true);
+            }
+        }
+
+        for ((&index, path), new_id) in indices.iter().zip(&paths).zip(renamed) {
+            if new_id == id.as_str() {
+                continue;
+            }
+            let others: Vec<String> = paths
+                .iter()
+                .filter(|other| *other != path)
+                .map(|other| other.display().to_string())
+                .collect();
+            warnings.push(
+                ValidationIssue::warning(
+                    path.clone(),
+                    format!(
+                        "ID '{id}' is also used by {}; using '{new_id}'",
+                        others.join(", ")
+                    ),
+                    UNIQUE_IDS_RULE.0,
+                )
+                .with_code(UNIQUE_IDS_RULE.1),
+            );
+            adrs[index] = adrs[index].clone().with_id(AdrId::new(new_id));
+        }
+    }
+
+    warnings
+}
+
+/// Returns the deepest directory containing all of the given files.
+fn common_parent(paths: &[PathBuf]) -> PathBuf {
+    let mut parents = paths.iter().filter_map(|path| path.parent());
+    let Some(first) = parents.next() else {
+        return PathBuf::new();
+    };
+
+    parents.fold(first.to_path_buf(), |common, parent| {
+        common
+            .components()
+            .zip(parent.components())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a)
+            .collect()
+    })
+}
+
+/// Qualifies an ID with the file's directory relative to `common`.
+///
+/// Any package prefix of the ID is preserved; with `full_name` the file's
+/// name including extension replaces its stem.
+fn qualified_id(id: &AdrId, path: &Path, common: &Path, full_name: bool) -> String {
+    let stem = AdrId::from_path(path);
+    let prefix = id.as_str().strip_suffix(stem.as_str()).unwrap_or_default();
+    let name = if full_name {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(stem.as_str())
+    } else {
+        stem.as_str()
+    };
+
+    let subdir = path
+        .parent()
+        .and_then(|parent| parent.strip_prefix(common).ok())
+        .map(|dir| dir.to_string_lossy().replace('\\', "/"))
+        .unwrap_or_default();
+
+    if subdir.is_empty() {
+        format!("{prefix}{name}")
+    } else {
+        format!("{prefix}{subdir}/{name}")
+    }
+}
+
+/// Finds ADR files beneath decision directories anywhere under `root`.
+///
+/// Each file is paired with the package path owning its decision directory,
+/// or `None` when the decision directory sits at the root itself.
+fn discover_files<F: FileSystem>(
+    fs: &F,
+    root: &Path,
+    pattern: &str,
+    conventions: &[Vec<&str>],
+) -> Result<Vec<(PathBuf, Option<String>)>> {
+    let mut found = Vec::new();
+    for path in fs.glob(root, pattern)? {
+        let relative = path.strip_prefix(root).unwrap_or(&path);
+        let dirs: Vec<&str> = relative
+            .parent()
+            .map(|parent| {
+                parent
+                    .components()
+                    .filter_map(|c| match c {
+                        Component::Normal(name) => name.to_str(),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if dirs
+            .iter()
+            .any(|d| d.starts_with('.') || IGNORED_DIRS.contains(d))
+        {
+            continue;
+        }
+
+        if let Some(package) = owning_package(&dirs, conventions) {
+            found.push((path, (!package.is_empty()).then_some(package)));
+        }
+    }
+
+    Ok(found)
+}
+
+/// Splits directory conventions into their path segments.
+fn split_conventions(conventions: &[String]) -> Vec<Vec<&str>> {
+    conventions
+        .iter()
+        .map(|c| c.split('/').filter(|s| !s.is_empty()).collect::<Vec<_>>())
+        .filter(|c| !c.is_empty())
+        .collect()
+}
+
+/// Returns the package of an input directory merged with others (see
+/// [`SourceOptions::with_inputs`]), or `None` for a decision directory at
+/// the top level.
+fn input_package(dir: &str, conventions: &[Vec<&str>]) -> Option<String> {
+    let dirs: Vec<&str> = Path::new(dir)
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(name) => name.to_str(),
+            _ => None,
+        })
+        .collect();
+    let package = conventions
+        .iter()
+        .find_map(|convention| dirs.strip_suffix(convention.as_slice()))
+        .unwrap_or(&dirs)
+        .join("/");
+    (!package.is_empty()).then_some(package)
+}
+
+/// Returns the package path preceding the outermost decision directory in `dirs`.
+fn owning_package(dirs: &[&str], conventions: &[Vec<&str>]) -> Option<String> {
+    (0..dirs.len()).find_map(|start| {
+        conventions
+            .iter()
+            .any(|convention| dirs[start..].starts_with(convention))
+            .then(|| dirs[..start].join("/"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::DefaultAdrParser;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+
+    fn adr_content(title: &str) -> String {
+        format!("---\ntitle: {title}\n---\n\nBody.\n")
+    }
+
+    fn conventions() -> Vec<Vec<&'static str>> {
+        DEFAULT_CONVENTIONS
+            .iter()
+            .map(|c| c.split('/').collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_owning_package() {
+        let conventions = conventions();
+
+        assert_eq!(
+            owning_package(&["services", "payments", "docs", "decisions"], &conventions),
+            Some("services/payments".to_string())
+        );
+        assert_eq!(
+            owning_package(&["docs", "decisions"], &conventions),
+            Some(String::new())
+        );
+        assert_eq!(
+            owning_package(&["libs", "core", "adr", "drafts"], &conventions),
+            Some("libs/core".to_string())
+        );
+        assert_eq!(owning_package(&["src", "docs"], &conventions), None);
+    }
+
+    #[test]
+    fn test_load_adrs_from_input_dir() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0002.md", adr_content("Second"));
+        fs.add_file("docs/decisions/adr-0001.md", adr_content("First"));
+
+        let loaded = load_adrs(
+            &fs,
+            &DefaultAdrParser::new(),
+            "docs/decisions",
+            "**/*.md",
+            &SourceOptions::new(),
+        )
+        .expect("should load");
+
+        assert_eq!(loaded.source_dir, "docs/decisions");
+        assert_eq!(loaded.adrs.len(), 2);
+        assert_eq!(loaded.adrs[0].id().as_str(), "adr-0001");
+        assert!(loaded.adrs[0].package().is_none());
+    }
+
+    #[test]
+    fn test_parse_files_keeps_order() {
+        let fs = InMemoryFileSystem::new();
+        let files: Vec<_> = (1..=FILES_PER_THREAD * 8)
+            .map(|n| {
+                let path = PathBuf::from(format!("docs/decisions/adr-{n:04}.md"));
+                // Every seventh file is malformed
+                let content = if n % 7 == 0 {
+                    "no frontmatter".to_string()
+                } else {
+                    adr_content(&format!("Decision {n}"))
+                };
+                fs.add_file(&path, content);
+                (path, (n % 2 == 0).then(|| "services/payments".to_string()))
+            })
+            .collect();
+
+        let parser = DefaultAdrParser::new();
+        let sequential = parse_files(&fs, &parser, &SourceOptions::new(), &files, 1);
+        let parsed = parse_files(&fs, &parser, &SourceOptions::new(), &files, 3);
+
+        assert_eq!(parsed.len(), files.len());
+        assert_eq!(
+            parsed.iter().map(|(path, _)| path).collect::<Vec<_>>(),
+            sequential.iter().map(|(path, _)| path).collect::<Vec<_>>()
+        );
+        for ((path, result), (n, (expected, package))) in parsed.iter().zip((1..).zip(&files)) {
+            assert_eq!(path, expected);
+            match result {
+                Ok(adr) => {
+                    assert_ne!(n % 7, 0);
+                    assert_eq!(adr.title(), format!("Decision {n}"));
+                    assert_eq!(adr.package(), package.as_deref());
+                },
+                Err(_) => assert_eq!(n % 7, 0),
+            }
+        }
+    }
+
+    #[test]
+    fn test_load_adrs_applies_status_synonyms() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0001.md",
+            "---\ntitle: Approved\nstatus: approved\n---\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            "---\ntitle: Published\nstatus: published\n---\n",
+        );
+
+        let source = SourceOptions::new()
+            .with_status_synonyms(BTreeMap::from([("approved".to_string(), Status::Accepted)]));
+        let loaded = load_adrs(
+            &fs,
+            &DefaultAdrParser::new(),
+            "docs/decisions",
+            "**/*.md",
+            &source,
+        )
+        .expect("should load");
+
+        assert_eq!(loaded.adrs[0].status(), Status::Accepted);
+        assert!(loaded.adrs[0].frontmatter().normalized.is_empty());
+        assert_eq!(loaded.adrs[1].status(), Status::Proposed);
+        assert_eq!(loaded.adrs[1].frontmatter().normalized.len(), 1);
+    }
+
+    #[test]
+    fn test_load_adrs_applies_custom_statuses() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0001.md",
+            "---\ntitle: Rejected\nstatus: Rejected\n---\n",
+        );
+
+        let source = SourceOptions::new()
+            .with_custom_statuses(vec![CustomStatus::new("rejected", Status::Deprecated)])
+            .with_status_synonyms(BTreeMap::from([("rejected".to_string(), Status::Accepted)]));
+        let loaded = load_adrs(
+            &fs,
+            &DefaultAdrParser::new(),
+            "docs/decisions",
+            "**/*.md",
+            &source,
+        )
+        .expect("should load");
+
+        assert_eq!(loaded.adrs[0].status(), Status::Deprecated);
+        assert_eq!(loaded.adrs[0].status_name(), "rejected");
+        assert!(loaded.adrs[0].frontmatter().normalized.is_empty());
+    }
+
+    #[test]
+    fn test_load_adrs_assigns_teams() {
+        use crate::domain::OwnerRule;
+
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0001.md",
+            "---\ntitle: First\nauthor: alice\n---\n",
+        );
+        fs.add_file(
+            "docs/decisions/payments/adr-0002.md",
+            "---\ntitle: Second\nauthor: alice\n---\n",
+        );
+        fs.add_file(
+            "docs/decisions/adr-0003.md",
+            "---\ntitle: Third\nauthor: dave\n---\n",
+        );
+
+        let source = SourceOptions::new().with_teams(TeamMapping::new(
+            BTreeMap::from([("platform".to_string(), vec!["Alice".to_string()])]),
+            vec![OwnerRule::new("docs/decisions/payments/", "payments")],
+        ));
+        let loaded = load_adrs(
+            &fs,
+            &DefaultAdrParser::new(),
+            "docs/decisions",
+            "**/*.md",
+            &source,
+        )
+        .expect("should load");
+
+        let teams: Vec<_> = loaded.adrs.iter().map(Adr::team).collect();
+        assert_eq!(teams, vec![Some("platform"), Some("payments"), None]);
+    }
+
+    #[test]
+    fn test_load_adrs_discovers_packages() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("repo/docs/decisions/adr-0001.md", adr_content("Root"));
+        fs.add_file(
+            "repo/services/payments/docs/decisions/adr-0001.md",
+            adr_content("Payments"),
+        );
+        fs.add_file("repo/libs/core/adr/adr-0001.md", adr_content("Core"));
+        fs.add_file("repo/services/payments/README.md", adr_content("Readme"));
+        fs.add_file(
+            "repo/node_modules/pkg/docs/decisions/adr-0001.md",
+            adr_content("Vendored"),
+        );
+
+        let source = SourceOptions::new().with_discover("repo");
+        let loaded = load_adrs(
+            &fs,
+            &DefaultAdrParser::new(),
+            "docs/decisions",
+            "**/*.md",
+            &source,
+        )
+        .expect("should load");
+
+        assert_eq!(loaded.source_dir, "repo");
+        let ids: Vec<&str> = loaded.adrs.iter().map(|a| a.id().as_str()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "adr-0001",
+                "libs/core/adr-0001",
+                "services/payments/adr-0001"
+            ]
+        );
+        assert_eq!(loaded.adrs[2].package(), Some("services/payments"));
+    }
+
+    #[test]
+    fn test_load_adrs_custom_conventions() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("repo/svc/architecture/adr-0001.md", adr_content("Svc"));
+        fs.add_file("repo/svc/docs/decisions/adr-0002.md", adr_content("Other"));
+
+        let source = SourceOptions::new()
+            .with_discover("repo")
+            .with_conventions(vec!["architecture".to_string()]);
+        let loaded =
+            load_adrs(&fs, &DefaultAdrParser::new(), "", "**/*.md", &source).expect("should load");
+
+        assert_eq!(loaded.adrs.len(), 1);
+        assert_eq!(loaded.adrs[0].id().as_str(), "svc/adr-0001");
+    }
+
+    #[test]
+    fn test_load_adrs_merges_input_dirs() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", adr_content("Root"));
+        fs.add_file(
+            "services/payments/docs/decisions/adr-0001.md",
+            adr_content("Payments"),
+        );
+        fs.add_file("legacy/records/adr-0002.md", adr_content("Legacy"));
+
+        let source = SourceOptions::new().with_inputs([
+            "services/payments/docs/decisions".to_string(),
+            "legacy/records".to_string(),
+            "docs/decisions".to_string(),
+        ]);
+        let loaded = load_adrs(
+            &fs,
+            &DefaultAdrParser::new(),
+            "docs/decisions",
+            "**/*.md",
+            &source,
+        )
+        .expect("should load");
+
+        assert_eq!(
+            loaded.source_dir,
+            "docs/decisions, services/payments/docs/decisions, legacy/records"
+        );
+        let ids: Vec<&str> = loaded.adrs.iter().map(|a| a.id().as_str()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "adr-0001",
+                "legacy/records/adr-0002",
+                "services/payments/adr-0001"
+            ]
+        );
+        assert_eq!(loaded.adrs[2].package(), Some("services/payments"));
+        assert!(loaded.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_input_package() {
+        let conventions = conventions();
+
+        assert_eq!(
+            input_package("./services/payments/docs/decisions", &conventions),
+            Some("services/payments".to_string())
+        );
+        assert_eq!(input_package("docs/adr", &conventions), None);
+        assert_eq!(
+            input_package("legacy/records", &conventions),
+            Some("legacy/records".to_string())
+        );
+    }
+
+    #[test]
+    fn test_load_adrs_excludes_patterns() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", adr_content("First"));
+        fs.add_file("docs/decisions/template.md", adr_content("Template"));
+        fs.add_file("docs/decisions/drafts/adr-0002.md", adr_content("Draft"));
+        fs.add_file("docs/decisions/old/drafts/adr-0003.md", adr_content("Old"));
+
+        let source = SourceOptions::new()
+            .with_exclude(["template.md".to_string()])
+            .with_exclude(["**/drafts/**".to_string()]);
+        let loaded = load_adrs(
+            &fs,
+            &DefaultAdrParser::new(),
+            "docs/decisions",
+            "**/*.md",
+            &source,
+        )
+        .expect("should load");
+
+        let ids: Vec<&str> = loaded.adrs.iter().map(|a| a.id().as_str()).collect();
+        assert_eq!(ids, vec!["adr-0001"]);
+
+        // Excluding everything is the same as finding nothing
+        let source = SourceOptions::new().with_exclude(["*.md".to_string()]);
+        let result = load_adrs(
+            &fs,
+            &DefaultAdrParser::new(),
+            "docs/decisions",
+            "**/*.md",
+            &source,
+        );
+        assert!(matches!(result, Err(Error::NoAdrsFound { .. })));
+
+        let source = SourceOptions::new().with_exclude(["[".to_string()]);
+        let result = load_adrs(
+            &fs,
+            &DefaultAdrParser::new(),
+            "docs/decisions",
+            "**/*.md",
+            &source,
+        );
+        assert!(matches!(result, Err(Error::GlobPattern(_))));
+    }
+
+    #[test]
+    fn test_load_adrs_disambiguates_colliding_ids() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", adr_content("Current"));
+        fs.add_file("docs/decisions/archive/adr-0001.md", adr_content("Old"));
+        fs.add_file("docs/decisions/teams/a/adr-0002.md", adr_content("A"));
+        fs.add_file("docs/decisions/teams/b/adr-0002.md", adr_content("B"));
+
+        let loaded = load_adrs(
+            &fs,
+            &DefaultAdrParser::new(),
+            "docs/decisions",
+            "**/*.md",
+            &SourceOptions::new(),
+        )
+        .expect("should load");
+
+        let ids: Vec<&str> = loaded.adrs.iter().map(|a| a.id().as_str()).collect();
+        assert_eq!(
+            ids,
+            vec!["a/adr-0002", "adr-0001", "archive/adr-0001", "b/adr-0002"]
+        );
+
+        assert_eq!(loaded.warnings.len(), 3);
+        let warning = &loaded.warnings[0];
+        assert_eq!(
+            warning.path,
+            PathBuf::from("docs/decisions/archive/adr-0001.md")
+        );
+        assert_eq!(warning.code.as_deref(), Some("V0003"));
+        assert!(warning.message.contains("docs/decisions/adr-0001.md"));
+        assert!(warning.message.contains("'archive/adr-0001'"));
+    }
+
+    #[test]
+    fn test_qualified_id() {
+        let common = Path::new("repo/svc/docs/decisions");
+        let path = Path::new("repo/svc/docs/decisions/old/adr-0001.md");
+        let id = AdrId::new("svc/adr-0001");
+
+        assert_eq!(qualified_id(&id, path, common, false), "svc/old/adr-0001");
+        assert_eq!(qualified_id(&id, path, common, true), "svc/old/adr-0001.md");
+        assert_eq!(
+            common_parent(&[
+                PathBuf::from("a/b/x.md"),
+                PathBuf::from("a/b/c/x.md"),
+                PathBuf::from("a/b/d/x.md")
+            ]),
+            PathBuf::from("a/b")
+        );
+    }
+
+    #[test]
+    fn test_load_adrs_nothing_discovered() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("repo/src/notes.md", adr_content("Notes"));
+
+        let source = SourceOptions::new().with_discover("repo");
+        let result = load_adrs(&fs, &DefaultAdrParser::new(), "", "**/*.md", &source);
+
+        assert!(matches!(result, Err(Error::NoAdrsFound { .. })));
+    }
+}
@@ -2,11 +2,10 @@
 //!
 //! Orchestrates ADR discovery, parsing, and statistics computation.
 
-use std::path::Path;
-
-use crate::domain::AdrStatistics;
+use super::source::{LoadedAdrs, SourceOptions, load_adrs};
+use crate::domain::{AdrStatistics, DEFAULT_TOP_N, ValidationIssue};
 use crate::error::Result;
-use crate::infrastructure::{AdrParser, DefaultAdrParser, FileSystem};
+use crate::infrastructure::{DefaultAdrParser, FileSystem};
 
 /// Output format for statistics.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
@@ -20,6 +19,18 @@ pub enum StatsFormat {
     Markdown,
 }
 
+impl StatsFormat {
+    /// Infers the format from an output file's extension, defaulting to text.
+    #[must_use]
+    pub fn from_path(path: &str) -> Self {
+        std::path::Path::new(path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| ext.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
 impl std::str::FromStr for StatsFormat {
     type Err = String;
 
@@ -42,6 +53,10 @@ pub struct StatsOptions {
     pub pattern: String,
     /// Output format.
     pub format: StatsFormat,
+    /// Where ADR files are discovered from.
+    pub source: SourceOptions,
+    /// Values listed per dimension in text output; `None` lists all.
+    pub limit: Option<usize>,
 }
 
 impl Default for StatsOptions {
@@ -50,6 +65,8 @@ impl Default for StatsOptions {
             input_dir: "docs/decisions".to_string(),
             pattern: "**/*.md".to_string(),
             format: StatsFormat::Text,
+            source: SourceOptions::default(),
+            limit: Some(DEFAULT_TOP_N),
         }
     }
 }
@@ -77,6 +94,21 @@ impl StatsOptions {
         self.format = format;
         self
     }
+
+    /// Sets the ADR source options.
+    #[must_use]
+    pub fn with_source(mut self, source: SourceOptions) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Sets how many values per dimension text output lists, or all of them
+    /// when `None`.
+    #[must_use]
+    pub const fn with_limit(mut self, limit: Option<usize>) -> Self {
+        self.limit = limit;
+        self
+    }
 }
 
 /// Use case for generating ADR statistics.
@@ -104,72 +136,71 @@ impl<F: FileSystem> StatsUseCase<F> {
     /// - No ADR files are found
     /// - File reading fails
     pub fn execute(&self, options: &StatsOptions) -> Result<StatsResult> {
-        // Discover ADR files
-        let base = Path::new(&options.input_dir);
-        let files = self.fs.glob(base, &options.pattern)?;
-
-        if files.is_empty() {
-            return Err(crate::error::Error::NoAdrsFound {
-                path: base.to_path_buf(),
-            });
-        }
+        let loaded = load_adrs(
+            &self.fs,
+            &self.parser,
+            &options.input_dir,
+            &options.pattern,
+            &options.source,
+        )?;
+        Ok(StatsResult::from_loaded(options, loaded))
+    }
+}
 
-        // Parse all ADRs
-        let mut adrs = Vec::with_capacity(files.len());
-        let mut parse_errors = Vec::new();
-
-        for file_path in &files {
-            let content = match self.fs.read_to_string(file_path) {
-                Ok(c) => c,
-                Err(e) => {
-                    parse_errors.push((file_path.clone(), e));
-                    continue;
-                },
-            };
-
-            match self.parser.parse(file_path, &content) {
-                Ok(adr) => adrs.push(adr),
-                Err(e) => parse_errors.push((file_path.clone(), e)),
-            }
-        }
+/// Result of the statistics use case.
+#[derive(Debug)]
+pub struct StatsResult {
+    /// Computed statistics.
+    pub statistics: AdrStatistics,
+    /// Formatted output string.
+    pub output: String,
+    /// Files that failed to parse.
+    pub parse_errors: Vec<(std::path::PathBuf, crate::error::Error)>,
+    /// Non-fatal problems found while loading, such as colliding IDs.
+    pub warnings: Vec<ValidationIssue>,
+}
+
+impl StatsResult {
+    /// Computes statistics for ADRs that have already been loaded.
+    pub(super) fn from_loaded(options: &StatsOptions, loaded: LoadedAdrs) -> Self {
+        let LoadedAdrs {
+            adrs,
+            parse_errors,
+            warnings,
+            ..
+        } = loaded;
 
         // Compute statistics
         let statistics = AdrStatistics::from_adrs(&adrs);
 
         // Format output
         let output = match options.format {
-            StatsFormat::Text => statistics.summary(),
+            StatsFormat::Text => statistics.summary_limited(options.limit),
             StatsFormat::Json => {
                 serde_json::to_string_pretty(&statistics).unwrap_or_else(|_| "{}".to_string())
             },
             StatsFormat::Markdown => format_markdown(&statistics),
         };
 
-        Ok(StatsResult {
+        Self {
             statistics,
             output,
             parse_errors,
-        })
+            warnings,
+        }
     }
-}
-
-/// Result of the statistics use case.
-#[derive(Debug)]
-pub struct StatsResult {
-    /// Computed statistics.
-    pub statistics: AdrStatistics,
-    /// Formatted output string.
-    pub output: String,
-    /// Files that failed to parse.
-    pub parse_errors: Vec<(std::path::PathBuf, crate::error::Error)>,
-}
 
-impl StatsResult {
     /// Returns true if there were any parse errors.
     #[must_use]
     pub fn has_errors(&self) -> bool {
         !self.parse_errors.is_empty()
     }
+
+    /// Returns the statistics as markdown, whatever the output format.
+    #[must_use]
+    pub fn markdown(&self) -> String {
+        format_markdown(&self.statistics)
+    }
 }
 
 /// Formats statistics as markdown.
@@ -177,16 +208,28 @@ fn format_markdown(stats: &AdrStatistics) -> String {
     use std::fmt::Write;
     let mut output = String::new();
 
-    let _ = writeln!(output, " ADR Statistics\n");
+    let _ = writeln!(output, "# ADR Statistics\n");
     let _ = writeln!(output, "**Total ADRs:** {}\n", stats.total_count);
 
-    let _ = writeln!(output, "# By Status\n");
+    let _ = writeln!(output, "## By Status\n");
     let _ = writeln!(output, "| Status | Count |");
     let _ = writeln!(output, "|--------|-------|");
     for (status, count) in &stats.by_status {
         let _ = writeln!(output, "| {status} | {count} |");
     }
 
+    for (heading, counts) in [("Impact", &stats.by_impact), ("Risk", &stats.by_risk)] {
+        if counts.is_empty() {
+            continue;
+        }
+        let _ = writeln!(output, "\n## By {heading}\n");
+        let _ = writeln!(output, "| {heading} | Count |");
+        let _ = writeln!(output, "|--------|-------|");
+        for (level, count) in AdrStatistics::level_counts(counts) {
+            let _ = writeln!(output, "| {level} | {count} |");
+        }
+    }
+
     if !stats.by_category.is_empty() {
         let _ = writeln!(output, "\n## By Category\n");
         let _ = writeln!(output, "| Category | Count |");
@@ -196,6 +239,20 @@ fn format_markdown(stats: &AdrStatistics) -> String {
         }
     }
 
+    if !stats.by_category_status.is_empty() {
+        let _ = writeln!(output, "\n## By Category and Status\n");
+        output.push_str(&stats.category_status_table());
+    }
+
+    if !stats.by_team.is_empty() {
+        let _ = writeln!(output, "\n## By Team\n");
+        let _ = writeln!(output, "| Team | Count |");
+        let _ = writeln!(output, "|------|-------|");
+        for (team, count) in &stats.by_team {
+            let _ = writeln!(output, "| {team} | {count} |");
+        }
+    }
+
     if !stats.by_author.is_empty() {
         let _ = writeln!(output, "\n## By Author\n");
         let _ = writeln!(output, "| Author | Count |");
@@ -211,6 +268,32 @@ fn format_markdown(stats: &AdrStatistics) -> String {
         let _ = writeln!(output, "- **Latest:** {latest}");
     }
 
+    if !stats.proposed_burndown.is_empty() {
+        let _ = writeln!(output, "\n## Proposed Backlog\n");
+        let _ = writeln!(output, "| Month | Opened | Decided | Open |");
+        let _ = writeln!(output, "|-------|--------|---------|------|");
+        for point in &stats.proposed_burndown {
+            let _ = writeln!(
+                output,
+                "| {} | {} | {} | {} |",
+                point.month, point.opened, point.closed, point.open
+            );
+        }
+    }
+
+    if !stats.most_connected.is_empty() {
+        let _ = writeln!(output, "\n## Most Connected\n");
+        let _ = writeln!(output, "| ADR | Title | In | Out | Centrality |");
+        let _ = writeln!(output, "|-----|-------|----|-----|------------|");
+        for adr in &stats.most_connected {
+            let _ = writeln!(
+                output,
+                "| {} | {} | {} | {} | {:.2} |",
+                adr.id, adr.title, adr.in_degree, adr.out_degree, adr.centrality
+            );
+        }
+    }
+
     output
 }
 
@@ -280,6 +363,7 @@ Content here.
 
         let result = result.unwrap();
         assert!(result.output.contains("\"total_count\""));
+        assert!(result.markdown().contains("| accepted | 1 |"));
     }
 
     #[test]
@@ -290,7 +374,7 @@ Content here.
             &sample_adr_content("ADR 1", "accepted", "database"),
         );
 
-        let use_case = StatsUseCase::new(fs);
+        let use_case = StatsUseCase::new(fs.clone());
         let options = StatsOptions::new("docs/decisions").with_format(StatsFormat::Markdown);
 
         let result = use_case.execute(&options);
@@ -299,6 +383,16 @@ Content here.
         let result = result.unwrap();
         assert!(result.output.contains(" ADR Statistics"));
         assert!(result.output.contains("| Status | Count |"));
+        assert!(!result.output.contains("## Proposed Backlog"));
+
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            &sample_adr_content("ADR 2", "proposed", "api"),
+        );
+        let result = StatsUseCase::new(fs).execute(&options).unwrap();
+        assert!(result.output.contains("## Proposed Backlog"));
+        assert!(result.output.contains("| 2025-01 | 1 | 0 | 1 |"));
+        assert!(!result.output.contains("## Most Connected"));
     }
 
     #[test]
@@ -324,6 +418,12 @@ Content here.
             Some(StatsFormat::Markdown)
         );
         assert!("invalid".parse::<StatsFormat>().is_err());
+        assert_eq!(StatsFormat::from_path("stats.json"), StatsFormat::Json);
+        assert_eq!(
+            StatsFormat::from_path("out/stats.md"),
+            StatsFormat::Markdown
+        );
+        assert_eq!(StatsFormat::from_path("stats.txt"), StatsFormat::Text);
     }
 
     #[test]
@@ -0,0 +1,291 @@
+//! Status transition use case.
+//!
+//! Moves an ADR through its lifecycle (`accept`, `deprecate`) by editing the
+//! `status` and `updated` frontmatter fields in place, optionally recording
+//! the change in the frontmatter `history` list.
+
+use std::path::PathBuf;
+
+use serde_yaml::{Mapping, Value};
+use time::{Date, OffsetDateTime};
+
+use super::source::{LoadedAdrs, SourceOptions, find_adr, load_adrs};
+use crate::domain::{AdrId, Status};
+use crate::error::{Error, Result};
+use crate::infrastructure::parser::{read_fields, set_field};
+use crate::infrastructure::{DefaultAdrParser, FileSystem};
+
+/// Options for the status transition commands.
+#[derive(Debug, Clone)]
+pub struct TransitionOptions {
+    /// Input directory containing ADR files.
+    pub input_dir: String,
+    /// Glob pattern for matching ADR files.
+    pub pattern: String,
+    /// Where ADR files are discovered from.
+    pub source: SourceOptions,
+    /// ADR to change, by ID, file name, or number.
+    pub id: String,
+    /// Status to move the ADR to.
+    pub status: Status,
+    /// Why the status changed, recorded in the history entry.
+    pub reason: Option<String>,
+    /// Whether to append a status history entry.
+    pub history: bool,
+    /// Date of the change (defaults to today).
+    pub date: Option<Date>,
+}
+
+impl Default for TransitionOptions {
+    fn default() -> Self {
+        Self {
+            input_dir: "docs/decisions".to_string(),
+            pattern: "**/*.md".to_string(),
+            source: SourceOptions::default(),
+            id: String::new(),
+            status: Status::Accepted,
+            reason: None,
+            history: false,
+            date: None,
+        }
+    }
+}
+
+impl TransitionOptions {
+    /// Creates options moving the given ADR to `status`.
+    #[must_use]
+    pub fn new(input_dir: impl Into<String>, id: impl Into<String>, status: Status) -> Self {
+        Self {
+            input_dir: input_dir.into(),
+            id: id.into(),
+            status,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the glob pattern for matching files.
+    #[must_use]
+    pub fn with_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.pattern = pattern.into();
+        self
+    }
+
+    /// Sets the ADR source options.
+    #[must_use]
+    pub fn with_source(mut self, source: SourceOptions) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Sets the reason for the change; implies a history entry.
+    #[must_use]
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self.history = true;
+        self
+    }
+
+    /// Sets whether to append a status history entry.
+    #[must_use]
+    pub const fn with_history(mut self, history: bool) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Sets the date of the change.
+    #[must_use]
+    pub const fn with_date(mut self, date: Date) -> Self {
+        self.date = Some(date);
+        self
+    }
+}
+
+/// Use case for changing the status of an ADR.
+#[derive(Debug)]
+pub struct TransitionUseCase<F: FileSystem> {
+    fs: F,
+    parser: DefaultAdrParser,
+}
+
+impl<F: FileSystem> TransitionUseCase<F> {
+    /// Creates a new status transition use case.
+    #[must_use]
+    pub fn new(fs: F) -> Self {
+        Self {
+            fs,
+            parser: DefaultAdrParser::new(),
+        }
+    }
+
+    /// Executes the status transition.
+    ///
+    /// Does nothing if the ADR already has the requested status.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ADR cannot be found or its file cannot be
+    /// read or written.
+    pub fn execute(&self, options: &TransitionOptions) -> Result<TransitionResult> {
+        let LoadedAdrs { adrs, .. } = load_adrs(
+            &self.fs,
+            &self.parser,
+            &options.input_dir,
+            &options.pattern,
+            &options.source,
+        )?;
+        let adr = find_adr(&adrs, &options.id)?;
+
+        let result = TransitionResult {
+            id: adr.id().clone(),
+            path: adr.source_path().clone(),
+            from: adr.status(),
+            to: options.status,
+        };
+        if !result.changed() {
+            return Ok(result);
+        }
+
+        let date = options
+            .date
+            .unwrap_or_else(|| OffsetDateTime::now_utc().date())
+            .to_string();
+
+        let content = self.fs.read_to_string(&result.path)?;
+        let updated = set_field(&content, "status", &Value::from(options.status.as_str()))
+            .and_then(|content| set_field(&content, "updated", &Value::from(date.as_str())))
+            .and_then(|content| {
+                if options.history {
+                    append_history(&content, &date, options.status, options.reason.as_deref())
+                } else {
+                    Some(content)
+                }
+            })
+            .ok_or_else(|| Error::InvalidFrontmatter {
+                path: result.path.clone(),
+                message: "missing or invalid frontmatter delimiters (--- or +++)".to_string(),
+            })?;
+
+        self.fs.write(&result.path, &updated)?;
+        Ok(result)
+    }
+}
+
+/// Result of the status transition use case.
+#[derive(Debug)]
+pub struct TransitionResult {
+    /// ID of the changed ADR.
+    pub id: AdrId,
+    /// Path of the ADR file.
+    pub path: PathBuf,
+    /// Status before the change.
+    pub from: Status,
+    /// Status after the change.
+    pub to: Status,
+}
+
+impl TransitionResult {
+    /// Returns true if the status changed.
+    #[must_use]
+    pub fn changed(&self) -> bool {
+        self.from != self.to
+    }
+}
+
+/// Appends a `{date, status, note}` entry to the frontmatter `history`
+/// list, creating the list if the ADR has none.
+fn append_history(content: &str, date: &str, status: Status, note: Option<&str>) -> Option<String> {
+    let mut history = match read_fields(content)?.remove("history") {
+        Some(Value::Sequence(history)) => history,
+        _ => Vec::new(),
+    };
+
+    let mut entry = Mapping::new();
+    entry.insert("date".into(), date.into());
+    entry.insert("status".into(), status.as_str().into());
+    if let Some(note) = note {
+        entry.insert("note".into(), note.into());
+    }
+    history.push(Value::Mapping(entry));
+
+    set_field(content, "history", &Value::Sequence(history))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+    use time::macros::date;
+
+    const ADR: &str = "---\ntitle: Use Redis\nstatus: proposed\ncreated: 2025-01-01\n---\n\n## Context\n\nCaching.\n";
+
+    #[test]
+    fn test_transition() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0042.md", ADR);
+
+        let result = TransitionUseCase::new(fs.clone())
+            .execute(
+                &TransitionOptions::new("docs/decisions", "42", Status::Accepted)
+                    .with_date(date!(2025 - 02 - 01)),
+            )
+            .unwrap();
+
+        assert!(result.changed());
+        assert_eq!(result.from, Status::Proposed);
+        assert_eq!(
+            fs.read_to_string(&result.path).unwrap(),
+            "---\ntitle: Use Redis\nstatus: accepted\ncreated: 2025-01-01\nupdated: 2025-02-01\n---\n\n## Context\n\nCaching.\n"
+        );
+    }
+
+    #[test]
+    fn test_transition_with_history() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0042.md", ADR);
+        let use_case = TransitionUseCase::new(fs.clone());
+
+        use_case
+            .execute(
+                &TransitionOptions::new("docs/decisions", "adr-0042", Status::Accepted)
+                    .with_history(true)
+                    .with_date(date!(2025 - 02 - 01)),
+            )
+            .unwrap();
+        let result = use_case
+            .execute(
+                &TransitionOptions::new("docs/decisions", "adr-0042", Status::Deprecated)
+                    .with_reason("Replaced by managed cache")
+                    .with_date(date!(2025 - 06 - 01)),
+            )
+            .unwrap();
+
+        assert_eq!(
+            fs.read_to_string(&result.path).unwrap(),
+            "---\ntitle: Use Redis\nstatus: deprecated\ncreated: 2025-01-01\nupdated: 2025-06-01\nhistory:\n  - date: 2025-02-01\n    status: accepted\n  - date: 2025-06-01\n    status: deprecated\n    note: Replaced by managed cache\n---\n\n## Context\n\nCaching.\n"
+        );
+
+        // The entries are read back as the frontmatter history
+        let (frontmatter, _) = crate::infrastructure::parser::FrontmatterParser::new()
+            .parse(&result.path, &fs.read_to_string(&result.path).unwrap())
+            .unwrap();
+        assert_eq!(frontmatter.history.len(), 2);
+        assert_eq!(frontmatter.history[1].status, Status::Deprecated);
+    }
+
+    #[test]
+    fn test_transition_unchanged() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0042.md", ADR);
+
+        let result = TransitionUseCase::new(fs.clone())
+            .execute(&TransitionOptions::new(
+                "docs/decisions",
+                "42",
+                Status::Proposed,
+            ))
+            .unwrap();
+
+        assert!(!result.changed());
+        assert_eq!(fs.read_to_string(&result.path).unwrap(), ADR);
+    }
+}
@@ -1,12 +1,81 @@
 //! Validate ADRs use case.
 //!
-//! Orchestrates ADR discovery, parsing, and validation.
+//! Orchestrates ADR discovery, parsing, and validation, and writes the
+//! requested report files.
 
-use std::path::Path;
+use std::fmt::Write;
+use std::path::{Component, Path};
 
-use crate::domain::{Severity, ValidationReport, Validator, default_rules};
-use crate::error::Result;
-use crate::infrastructure::{AdrParser, DefaultAdrParser, FileSystem};
+use serde::Serialize;
+
+use super::assets::missing_assets;
+use super::schema::FrontmatterSchema;
+use super::source::{LoadedAdrs, SourceOptions, load_adrs};
+use crate::domain::{
+    BodySectionsRule, DEFAULT_FILENAME_PATTERN, DEFAULT_ORPHAN_THRESHOLD,
+    DEFAULT_RECOMMENDED_FIELDS, DEFAULT_REQUIRED_FIELDS, DEFAULT_SECTIONS, FilenameRule,
+    OrphanRule, RecommendedFieldsRule, RequiredFieldsRule, RuleConfig, Severity, StaleProposalRule,
+    TitleStyle, TitleStyleRule, ValidationIssue, ValidationReport, Validator, VocabularyRule,
+    default_collection_rules, rules_with_fields, suppressed_rules,
+};
+use crate::error::{Error, Result};
+use crate::infrastructure::{DefaultAdrParser, FileSystem};
+
+/// Format of a validation report.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ValidateFormat {
+    /// Human-readable text, as printed to stdout.
+    #[default]
+    Text,
+    /// Markdown table of issues.
+    Markdown,
+    /// JUnit XML, with a test case per ADR, for CI test result views.
+    Junit,
+    /// GitHub Actions workflow commands, annotating the files in pull
+    /// requests.
+    Github,
+    /// JSON, for scripts and bots.
+    Json,
+}
+
+impl ValidateFormat {
+    /// Infers the format from a report file's extension, defaulting to text.
+    #[must_use]
+    pub fn from_path(path: &str) -> Self {
+        Path::new(path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| ext.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Returns the format name.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Markdown => "markdown",
+            Self::Junit => "junit",
+            Self::Github => "github",
+            Self::Json => "json",
+        }
+    }
+}
+
+impl std::str::FromStr for ValidateFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" | "txt" => Ok(Self::Text),
+            "markdown" | "md" => Ok(Self::Markdown),
+            "junit" | "xml" => Ok(Self::Junit),
+            "github" => Ok(Self::Github),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("invalid format: {s}")),
+        }
+    }
+}
 
 /// Options for the validate command.
 #[derive(Debug, Clone)]
@@ -17,6 +86,35 @@ pub struct ValidateOptions {
     pub pattern: String,
     /// Whether to fail on warnings.
     pub strict: bool,
+    /// Where ADR files are discovered from.
+    pub source: SourceOptions,
+    /// Report files to write, with their formats.
+    pub reports: Vec<(ValidateFormat, String)>,
+    /// Frontmatter fields every ADR must have.
+    pub required: Vec<String>,
+    /// Frontmatter fields every ADR should have.
+    pub recommended: Vec<String>,
+    /// Body sections every ADR is expected to have.
+    pub sections: Vec<String>,
+    /// Regular expression ADR filenames are expected to match.
+    pub filename_pattern: String,
+    /// Tags ADRs may use; any tag is allowed when empty.
+    pub allowed_tags: Vec<String>,
+    /// Categories ADRs may use; any category is allowed when empty.
+    pub allowed_categories: Vec<String>,
+    /// JSON Schema file the frontmatter of every ADR must conform to.
+    pub schema: Option<String>,
+    /// Style titles are expected to be written in; not checked when unset.
+    pub title_style: Option<TitleStyle>,
+    /// Number of ADRs from which ADRs related to no other are reported.
+    pub orphan_threshold: usize,
+    /// Days a decision may stay proposed after its creation date; not
+    /// checked when unset.
+    pub max_proposed_age: Option<u32>,
+    /// Which rules run, and at what severity.
+    pub rules: RuleConfig,
+    /// Files to validate, out of all ADRs loaded; every ADR when empty.
+    pub files: Vec<String>,
 }
 
 impl Default for ValidateOptions {
@@ -25,6 +123,26 @@ impl Default for ValidateOptions {
             input_dir: "docs/decisions".to_string(),
             pattern: "**/*.md".to_string(),
             strict: false,
+            source: SourceOptions::default(),
+            reports: Vec::new(),
+            required: DEFAULT_REQUIRED_FIELDS
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            recommended: DEFAULT_RECOMMENDED_FIELDS
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            sections: DEFAULT_SECTIONS.iter().map(ToString::to_string).collect(),
+            filename_pattern: DEFAULT_FILENAME_PATTERN.to_string(),
+            allowed_tags: Vec::new(),
+            allowed_categories: Vec::new(),
+            schema: None,
+            title_style: None,
+            orphan_threshold: DEFAULT_ORPHAN_THRESHOLD,
+            max_proposed_age: None,
+            rules: RuleConfig::default(),
+            files: Vec::new(),
         }
     }
 }
@@ -52,6 +170,125 @@ impl ValidateOptions {
         self.strict = strict;
         self
     }
+
+    /// Sets the ADR source options.
+    #[must_use]
+    pub fn with_source(mut self, source: SourceOptions) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Sets the frontmatter fields every ADR must have; see
+    /// [`RequiredFieldsRule`].
+    #[must_use]
+    pub fn with_required(mut self, fields: Vec<String>) -> Self {
+        self.required = fields;
+        self
+    }
+
+    /// Sets the frontmatter fields every ADR should have; see
+    /// [`RecommendedFieldsRule`].
+    #[must_use]
+    pub fn with_recommended(mut self, fields: Vec<String>) -> Self {
+        self.recommended = fields;
+        self
+    }
+
+    /// Sets the body sections every ADR is expected to have; see
+    /// [`BodySectionsRule`].
+    #[must_use]
+    pub fn with_sections(mut self, sections: Vec<String>) -> Self {
+        self.sections = sections;
+        self
+    }
+
+    /// Sets the regular expression ADR filenames are expected to match; see
+    /// [`FilenameRule`].
+    #[must_use]
+    pub fn with_filename_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.filename_pattern = pattern.into();
+        self
+    }
+
+    /// Sets the tags ADRs may use; see [`VocabularyRule`].
+    #[must_use]
+    pub fn with_allowed_tags(mut self, tags: Vec<String>) -> Self {
+        self.allowed_tags = tags;
+        self
+    }
+
+    /// Sets the categories ADRs may use; see [`VocabularyRule`].
+    #[must_use]
+    pub fn with_allowed_categories(mut self, categories: Vec<String>) -> Self {
+        self.allowed_categories = categories;
+        self
+    }
+
+    /// Sets a JSON Schema file the frontmatter of every ADR must conform to.
+    #[must_use]
+    pub fn with_schema(mut self, path: impl Into<String>) -> Self {
+        self.schema = Some(path.into());
+        self
+    }
+
+    /// Sets the style titles are expected to be written in; see
+    /// [`TitleStyleRule`].
+    #[must_use]
+    pub const fn with_title_style(mut self, style: TitleStyle) -> Self {
+        self.title_style = Some(style);
+        self
+    }
+
+    /// Sets the number of ADRs from which ADRs related to no other are
+    /// reported; see [`OrphanRule`].
+    #[must_use]
+    pub const fn with_orphan_threshold(mut self, threshold: usize) -> Self {
+        self.orphan_threshold = threshold;
+        self
+    }
+
+    /// Sets the days a decision may stay proposed after its creation date;
+    /// see [`StaleProposalRule`].
+    #[must_use]
+    pub const fn with_max_proposed_age(mut self, days: u32) -> Self {
+        self.max_proposed_age = Some(days);
+        self
+    }
+
+    /// Sets which rules run, and at what severity.
+    #[must_use]
+    pub fn with_rules(mut self, rules: RuleConfig) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Validates only the given files.
+    ///
+    /// All ADRs are still loaded, so that references to the others
+    /// resolve, but only the given files are checked and reported on.
+    /// Files that are not ADRs in the source are ignored.
+    #[must_use]
+    pub fn with_files(mut self, files: Vec<String>) -> Self {
+        self.files = files;
+        self
+    }
+
+    /// Checks whether a file is to be validated; see
+    /// [`ValidateOptions::with_files`].
+    fn selects(&self, path: &Path) -> bool {
+        self.files.is_empty()
+            || self
+                .files
+                .iter()
+                .any(|file| normal_components(Path::new(file)).eq(normal_components(path)))
+    }
+
+    /// Adds a report file to write in the given format.
+    #[must_use]
+    pub fn with_report(mut self, format: ValidateFormat, path: impl Into<String>) -> Self {
+        self.reports.push((format, path.into()));
+        self
+    }
 }
 
 /// Use case for validating ADRs.
@@ -78,38 +315,100 @@ impl<F: FileSystem> ValidateUseCase<F> {
     /// Returns an error if:
     /// - No ADR files are found
     /// - File reading fails
+    /// - The filename pattern or frontmatter schema is invalid
+    /// - A report file cannot be written
     pub fn execute(&self, options: &ValidateOptions) -> Result<ValidateResult> {
-        // Discover ADR files
-        let base = Path::new(&options.input_dir);
-        let files = self.fs.glob(base, &options.pattern)?;
-
-        if files.is_empty() {
-            return Err(crate::error::Error::NoAdrsFound {
-                path: base.to_path_buf(),
-            });
-        }
-
-        // Build validator with default rules
-        let validator = Validator::new(default_rules());
+        let loaded = load_adrs(
+            &self.fs,
+            &self.parser,
+            &options.input_dir,
+            &options.pattern,
+            &options.source,
+        )?;
+        self.execute_loaded(options, loaded)
+    }
 
-        // Validate each file
-        let mut reports = Vec::with_capacity(files.len());
-        let mut parse_errors = Vec::new();
+    /// Executes the use case on ADRs that have already been loaded.
+    pub(super) fn execute_loaded(
+        &self,
+        options: &ValidateOptions,
+        loaded: LoadedAdrs,
+    ) -> Result<ValidateResult> {
+        let LoadedAdrs {
+            adrs,
+            mut parse_errors,
+            warnings,
+            ..
+        } = loaded;
+        parse_errors.retain(|(path, _)| options.selects(path));
 
-        for file_path in &files {
-            match self.validate_file(file_path, &validator) {
-                Ok(report) => reports.push((file_path.clone(), report)),
-                Err(e) => parse_errors.push((file_path.clone(), e)),
-            }
+        // Build validator with default rules, including those that need
+        // the whole collection
+        let mut validator = Validator::new(rules_with_fields(
+            RequiredFieldsRule::new(options.required.clone()),
+            RecommendedFieldsRule::new(options.recommended.clone()),
+        ))
+        .with_collection_rules(default_collection_rules())
+        .with_config(options.rules.clone());
+        validator.add_rule(Box::new(BodySectionsRule::new(options.sections.clone())));
+        validator.add_rule(Box::new(
+            FilenameRule::new(&options.filename_pattern).map_err(|e| {
+                Error::FilenamePattern(format!("{}: {e}", options.filename_pattern))
+            })?,
+        ));
+        validator.add_rule(Box::new(VocabularyRule::new(
+            options.allowed_tags.clone(),
+            options.allowed_categories.clone(),
+        )));
+        validator.add_collection_rule(Box::new(OrphanRule::new(options.orphan_threshold)));
+        if let Some(style) = options.title_style {
+            validator.add_rule(Box::new(TitleStyleRule::new(style)));
+        }
+        if let Some(days) = options.max_proposed_age {
+            validator.add_rule(Box::new(StaleProposalRule::until_today(days)));
         }
+        let collection = validator.validate_collection(&adrs);
+        let schema = options
+            .schema
+            .as_deref()
+            .map(|path| FrontmatterSchema::load(&self.fs, Path::new(path)))
+            .transpose()?;
+
+        // Validate each ADR, including problems found while loading it or
+        // across the collection, references to missing assets, and
+        // frontmatter not conforming to the schema, at the configured
+        // severities and without the rules the ADR suppresses
+        let reports: Vec<_> = adrs
+            .iter()
+            .filter(|adr| options.selects(adr.source_path()))
+            .map(|adr| {
+                let mut report = validator.validate(adr);
+                report.add_issues(
+                    warnings
+                        .iter()
+                        .chain(collection.issues())
+                        .filter(|issue| issue.path == *adr.source_path())
+                        .cloned(),
+                );
+                report.add_issues(missing_assets(&self.fs, adr));
+                if let Some(schema) = &schema {
+                    report.add_issues(schema.check(&self.fs, adr));
+                }
+                options.rules.apply(&mut report);
+                report.suppress(&suppressed_rules(adr));
+                (adr.source_path().clone(), report)
+            })
+            .collect();
 
         // Aggregate results
         let mut total_errors = 0;
         let mut total_warnings = 0;
+        let mut total_suppressed = 0;
 
         for (_, report) in &reports {
             total_errors += report.errors().len();
             total_warnings += report.warnings().len();
+            total_suppressed += report.suppressed().len();
         }
 
         // Determine if validation passed
@@ -119,22 +418,31 @@ impl<F: FileSystem> ValidateUseCase<F> {
             total_errors == 0 && parse_errors.is_empty()
         };
 
-        Ok(ValidateResult {
+        let result = ValidateResult {
             reports,
             parse_errors,
             total_errors,
             total_warnings,
+            total_suppressed,
+            strict: options.strict,
             passed,
-        })
-    }
+        };
 
-    fn validate_file(&self, path: &Path, validator: &Validator) -> Result<ValidationReport> {
-        let content = self.fs.read_to_string(path)?;
-        let adr = self.parser.parse(path, &content)?;
-        Ok(validator.validate(&adr))
+        for (format, path) in &options.reports {
+            self.fs.write(Path::new(path), &result.render(*format))?;
+        }
+
+        Ok(result)
     }
 }
 
+/// Returns the components of a path without `.` components, so that
+/// `./docs/adr-0001.md` and `docs/adr-0001.md` compare equal.
+fn normal_components(path: &Path) -> impl Iterator<Item = Component<'_>> {
+    path.components()
+        .filter(|component| *component != Component::CurDir)
+}
+
 /// Result of the validation use case.
 #[derive(Debug)]
 pub struct ValidateResult {
@@ -146,6 +454,10 @@ pub struct ValidateResult {
     pub total_errors: usize,
     /// Total number of validation warnings.
     pub total_warnings: usize,
+    /// Total number of issues of rules suppressed for their ADR.
+    pub total_suppressed: usize,
+    /// Whether warnings failed validation.
+    pub strict: bool,
     /// Whether validation passed.
     pub passed: bool,
 }
@@ -178,6 +490,362 @@ impl ValidateResult {
         self.all_issues()
             .filter(|(_, issue)| issue.severity == Severity::Warning)
     }
+
+    /// Describes the totals, e.g. `0 errors, 2 warnings, 1 suppressed`.
+    #[must_use]
+    pub fn totals(&self) -> String {
+        let totals = format!(
+            "{} errors, {} warnings",
+            self.total_errors, self.total_warnings
+        );
+        if self.total_suppressed == 0 {
+            totals
+        } else {
+            format!("{totals}, {} suppressed", self.total_suppressed)
+        }
+    }
+
+    /// Renders the parse errors, issues, and summary as a report.
+    #[must_use]
+    pub fn render(&self, format: ValidateFormat) -> String {
+        match format {
+            ValidateFormat::Text => format_text(self),
+            ValidateFormat::Markdown => format_markdown(self),
+            ValidateFormat::Junit => format_junit(self),
+            ValidateFormat::Github => format_github(self),
+            ValidateFormat::Json => format_json(self),
+        }
+    }
+}
+
+fn format_text(result: &ValidateResult) -> String {
+    let mut output = String::new();
+
+    for (path, error) in &result.parse_errors {
+        let _ = writeln!(
+            output,
+            "ERROR: {} - {} [{}]",
+            path.display(),
+            error,
+            error.code()
+        );
+    }
+    for (path, issue) in result.all_issues() {
+        let _ = writeln!(
+            output,
+            "{}: {} - {} [{}]",
+            issue.severity.as_str().to_uppercase(),
+            path.display(),
+            issue.message,
+            issue.label()
+        );
+    }
+
+    write_summary(&mut output, result);
+    output
+}
+
+/// Writes the totals and outcome of a validation run.
+fn write_summary(output: &mut String, result: &ValidateResult) {
+    let _ = writeln!(output, "\nValidation complete: {}", result.totals());
+    let _ = writeln!(
+        output,
+        "{}",
+        if result.passed {
+            "All checks passed."
+        } else {
+            "Validation failed."
+        }
+    );
+}
+
+/// Formats the result as GitHub Actions workflow commands, one per issue,
+/// followed by the summary.
+///
+/// Issues without a known line are reported on the first line of their
+/// file, so that they still show inline on pull request diffs.
+fn format_github(result: &ValidateResult) -> String {
+    let mut output = String::new();
+
+    for (path, error) in &result.parse_errors {
+        let _ = writeln!(
+            output,
+            "::error file={},line={},title={}::{}",
+            escape_property(&path.display().to_string()),
+            error.location().map_or(1, |location| location.line),
+            error.code(),
+            escape_data(&error.to_string())
+        );
+    }
+    for (path, issue) in result.all_issues() {
+        let _ = writeln!(
+            output,
+            "::{} file={},line={},title={}::{}",
+            issue.severity.as_str(),
+            escape_property(&path.display().to_string()),
+            issue.line.unwrap_or(1),
+            escape_property(&issue.label()),
+            escape_data(&issue.message)
+        );
+    }
+
+    write_summary(&mut output, result);
+    output
+}
+
+/// Formats the result as JSON, with the issues of each file.
+///
+/// `code` and `line` are null when unknown.
+fn format_json(result: &ValidateResult) -> String {
+    #[derive(Serialize)]
+    struct Report<'a> {
+        passed: bool,
+        strict: bool,
+        total_errors: usize,
+        total_warnings: usize,
+        total_suppressed: usize,
+        files: Vec<File<'a>>,
+        parse_errors: Vec<ParseError>,
+    }
+
+    #[derive(Serialize)]
+    struct File<'a> {
+        path: String,
+        issues: Vec<Issue<'a>>,
+        suppressed: Vec<Issue<'a>>,
+    }
+
+    #[derive(Serialize)]
+    struct Issue<'a> {
+        severity: &'a str,
+        code: Option<&'a str>,
+        rule: &'a str,
+        message: &'a str,
+        line: Option<usize>,
+    }
+
+    impl<'a> From<&'a ValidationIssue> for Issue<'a> {
+        fn from(issue: &'a ValidationIssue) -> Self {
+            Self {
+                severity: issue.severity.as_str(),
+                code: issue.code.as_deref(),
+                rule: &issue.rule,
+                message: &issue.message,
+                line: issue.line,
+            }
+        }
+    }
+
+    #[derive(Serialize)]
+    struct ParseError {
+        path: String,
+        code: &'static str,
+        message: String,
+        line: Option<usize>,
+    }
+
+    let report = Report {
+        passed: result.passed,
+        strict: result.strict,
+        total_errors: result.total_errors,
+        total_warnings: result.total_warnings,
+        total_suppressed: result.total_suppressed,
+        files: result
+            .reports
+            .iter()
+            .map(|(path, report)| File {
+                path: path.display().to_string(),
+                issues: report.issues().iter().map(Issue::from).collect(),
+                suppressed: report.suppressed().iter().map(Issue::from).collect(),
+            })
+            .collect(),
+        parse_errors: result
+            .parse_errors
+            .iter()
+            .map(|(path, error)| ParseError {
+                path: path.display().to_string(),
+                code: error.code(),
+                message: error.to_string(),
+                line: error.location().map(|location| location.line),
+            })
+            .collect(),
+    };
+
+    let mut output = serde_json::to_string_pretty(&report).unwrap_or_else(|_| "{}".to_string());
+    output.push('\n');
+    output
+}
+
+/// Escapes the message of a workflow command.
+fn escape_data(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escapes a property value of a workflow command.
+fn escape_property(text: &str) -> String {
+    escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+fn format_markdown(result: &ValidateResult) -> String {
+    let mut output = String::new();
+
+    let _ = writeln!(output, "# ADR Validation Report\n");
+    let _ = writeln!(
+        output,
+        "**{}:** {} in {} ADRs\n",
+        if result.passed { "Passed" } else { "Failed" },
+        result.totals(),
+        result.reports.len()
+    );
+
+    if result.parse_errors.is_empty()
+        && result.all_issues().next().is_none()
+        && result.total_suppressed == 0
+    {
+        let _ = writeln!(output, "No issues found.");
+        return output;
+    }
+
+    let _ = writeln!(output, "| Severity | File | Issue | Code |");
+    let _ = writeln!(output, "|----------|------|-------|------|");
+    for (path, error) in &result.parse_errors {
+        let _ = writeln!(
+            output,
+            "| error | `{}` | {} | {} |",
+            path.display(),
+            escape_cell(&error.to_string()),
+            error.code()
+        );
+    }
+    for (path, issue) in result.all_issues() {
+        let _ = writeln!(
+            output,
+            "| {} | `{}` | {} | {} |",
+            issue.severity,
+            path.display(),
+            escape_cell(&issue.message),
+            issue.label()
+        );
+    }
+    for (path, report) in &result.reports {
+        for issue in report.suppressed() {
+            let _ = writeln!(
+                output,
+                "| suppressed | `{}` | {} | {} |",
+                path.display(),
+                escape_cell(&issue.message),
+                issue.label()
+            );
+        }
+    }
+    output
+}
+
+/// Formats the result as a JUnit test suite with a test case per file.
+///
+/// Each error is a failure of its file's test case, as is each warning in
+/// strict mode; otherwise warnings are listed in the test case's output.
+/// Files that failed to parse are test cases with an error.
+fn format_junit(result: &ValidateResult) -> String {
+    let failures = result
+        .all_issues()
+        .filter(|(_, issue)| result.strict || issue.severity == Severity::Error)
+        .count();
+    let tests = result.reports.len() + result.parse_errors.len();
+    let errors = result.parse_errors.len();
+
+    let mut output = String::new();
+    let _ = writeln!(output, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(
+        output,
+        r#"<testsuites name="adrscope" tests="{tests}" failures="{failures}" errors="{errors}">"#
+    );
+    let _ = writeln!(
+        output,
+        r#"  <testsuite name="validate" tests="{tests}" failures="{failures}" errors="{errors}">"#
+    );
+
+    for (path, error) in &result.parse_errors {
+        let name = escape_xml(&path.display().to_string());
+        let _ = writeln!(
+            output,
+            r#"    <testcase classname="validate" name="{name}">"#
+        );
+        let _ = writeln!(
+            output,
+            r#"      <error type="{}" message="{}"/>"#,
+            error.code(),
+            escape_xml(&error.to_string())
+        );
+        let _ = writeln!(output, "    </testcase>");
+    }
+
+    for (path, report) in &result.reports {
+        let name = escape_xml(&path.display().to_string());
+        if report.is_empty() && report.suppressed().is_empty() {
+            let _ = writeln!(
+                output,
+                r#"    <testcase classname="validate" name="{name}"/>"#
+            );
+            continue;
+        }
+
+        let _ = writeln!(
+            output,
+            r#"    <testcase classname="validate" name="{name}">"#
+        );
+        let mut out = String::new();
+        for issue in report.issues() {
+            if result.strict || issue.severity == Severity::Error {
+                let _ = writeln!(
+                    output,
+                    r#"      <failure type="{}" message="{}">{}: {}</failure>"#,
+                    escape_xml(&issue.label()),
+                    escape_xml(&issue.message),
+                    issue.severity.as_str().to_uppercase(),
+                    escape_xml(&issue.message)
+                );
+            } else {
+                let _ = writeln!(
+                    out,
+                    "WARNING: {} [{}]",
+                    escape_xml(&issue.message),
+                    escape_xml(&issue.label())
+                );
+            }
+        }
+        for issue in report.suppressed() {
+            let _ = writeln!(
+                out,
+                "SUPPRESSED: {} [{}]",
+                escape_xml(&issue.message),
+                escape_xml(&issue.label())
+            );
+        }
+        if !out.is_empty() {
+            let _ = writeln!(output, "      <system-out>{}</system-out>", out.trim_end());
+        }
+        let _ = writeln!(output, "    </testcase>");
+    }
+
+    let _ = writeln!(output, "  </testsuite>");
+    let _ = writeln!(output, "</testsuites>");
+    output
+}
+
+/// Escapes text for use in XML content and double-quoted attributes.
+pub(super) fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Keeps text on one line of a markdown table cell.
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
 }
 
 #[cfg(test)]
@@ -200,6 +868,14 @@ author: Jane Doe
 ## Context
 
 We need a database.
+
+## Decision
+
+Use PostgreSQL.
+
+## Consequences
+
+Operations must run PostgreSQL.
 "
     }
 
@@ -277,6 +953,87 @@ Some content.
         assert!(!result.passed);
     }
 
+    #[test]
+    fn test_validate_reports_colliding_ids() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", valid_adr_content());
+        fs.add_file("docs/decisions/archive/adr-0001.md", valid_adr_content());
+
+        let use_case = ValidateUseCase::new(fs);
+        let result = use_case
+            .execute(&ValidateOptions::new("docs/decisions").with_strict(true))
+            .unwrap();
+
+        assert_eq!(result.total_warnings, 1);
+        assert!(!result.passed);
+        let (path, issue) = result.warning_issues().next().unwrap();
+        assert_eq!(
+            path,
+            &std::path::PathBuf::from("docs/decisions/archive/adr-0001.md")
+        );
+        assert_eq!(issue.rule, "unique-ids");
+    }
+
+    #[test]
+    fn test_validate_reports_unresolved_references() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", valid_adr_content());
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            valid_adr_content().replace(
+                "author: Jane Doe\n",
+                "author: Jane Doe\nrelated:\n  - adr-0001.md\n  - adr-0007.md\n",
+            ),
+        );
+
+        let use_case = ValidateUseCase::new(fs);
+        let result = use_case
+            .execute(&ValidateOptions::new("docs/decisions"))
+            .unwrap();
+
+        assert_eq!(result.total_errors, 1);
+        assert!(!result.passed);
+    }
+
+    #[test]
+    fn test_validate_filename_pattern() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", valid_adr_content());
+        fs.add_file("docs/decisions/use-redis.md", valid_adr_content());
+
+        let use_case = ValidateUseCase::new(fs);
+        let result = use_case
+            .execute(&ValidateOptions::new("docs/decisions"))
+            .unwrap();
+        assert_eq!(result.total_warnings, 1);
+
+        let result = use_case
+            .execute(&ValidateOptions::new("docs/decisions").with_filename_pattern("adr-("));
+        assert!(matches!(result, Err(Error::FilenamePattern(_))));
+    }
+
+    #[test]
+    fn test_validate_schema() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", valid_adr_content());
+        fs.add_file(
+            "adr.schema.json",
+            r#"{ "required": ["cost-center"], "properties": { "author": { "const": "Jane Doe" } } }"#,
+        );
+
+        let use_case = ValidateUseCase::new(fs);
+        let result = use_case
+            .execute(&ValidateOptions::new("docs/decisions").with_schema("adr.schema.json"))
+            .unwrap();
+
+        assert_eq!(result.total_errors, 1);
+        assert!(!result.passed);
+
+        let result =
+            use_case.execute(&ValidateOptions::new("docs/decisions").with_schema("missing.json"));
+        assert!(matches!(result, Err(Error::FileRead { .. })));
+    }
+
     #[test]
     fn test_validate_invalid_adr() {
         let fs = InMemoryFileSystem::new();
@@ -287,13 +1044,7 @@ Some content.
 
         let result = use_case.execute(&options);
         // Should fail to parse due to missing title
-        assert!(
-            result.is_err()
-                || result
-                    .as_ref()
-                    .map(|r| !r.parse_errors.is_empty())
-                    .unwrap_or(false)
-        );
+        assert!(result.is_err() || result.as_ref().is_ok_and(|r| !r.parse_errors.is_empty()));
     }
 
     #[test]
@@ -316,4 +1067,209 @@ Some content.
         assert_eq!(options.pattern, "*.md");
         assert!(options.strict);
     }
+
+    #[test]
+    fn test_validate_writes_reports() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", minimal_adr_content());
+        fs.add_file("docs/decisions/adr-0002.md", invalid_adr_content());
+
+        let options = ValidateOptions::new("docs/decisions")
+            .with_report(ValidateFormat::Text, "reports/validation.txt")
+            .with_report(ValidateFormat::Markdown, "reports/validation.md");
+        let result = ValidateUseCase::new(fs.clone()).execute(&options).unwrap();
+
+        let text = fs
+            .read_to_string(Path::new("reports/validation.txt"))
+            .unwrap();
+        assert_eq!(text, result.render(ValidateFormat::Text));
+        assert!(text.starts_with("ERROR: docs/decisions/adr-0002.md - "));
+        assert!(text.contains("WARNING: docs/decisions/adr-0001.md - "));
+        assert!(text.ends_with("Validation failed.\n"));
+
+        let markdown = fs
+            .read_to_string(Path::new("reports/validation.md"))
+            .unwrap();
+        assert!(markdown.starts_with("# ADR Validation Report\n\n**Failed:** 0 errors, "));
+        assert!(markdown.contains("| warning | `docs/decisions/adr-0001.md` | "));
+    }
+
+    #[test]
+    fn test_validate_format_from_path() {
+        assert_eq!(
+            ValidateFormat::from_path("validation.md"),
+            ValidateFormat::Markdown
+        );
+        assert_eq!(
+            ValidateFormat::from_path("validation.log"),
+            ValidateFormat::Text
+        );
+        assert_eq!(
+            ValidateFormat::from_path("validation"),
+            ValidateFormat::Text
+        );
+        assert_eq!(
+            ValidateFormat::from_path("adr-results.xml"),
+            ValidateFormat::Junit
+        );
+    }
+
+    #[test]
+    fn test_validate_selected_files() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", valid_adr_content());
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            valid_adr_content().replace(
+                "author: Jane Doe\n",
+                "author: Jane Doe\nrelated:\n  - adr-0001.md\n",
+            ),
+        );
+        fs.add_file("docs/decisions/adr-0003.md", minimal_adr_content());
+        fs.add_file("docs/decisions/adr-0004.md", invalid_adr_content());
+
+        let use_case = ValidateUseCase::new(fs);
+        let result = use_case
+            .execute(&ValidateOptions::new("docs/decisions").with_files(vec![
+                "./docs/decisions/adr-0002.md".to_string(),
+                "README.md".to_string(),
+            ]))
+            .unwrap();
+
+        // References to ADRs that were not selected still resolve
+        assert!(result.passed);
+        assert_eq!(result.reports.len(), 1);
+        assert!(result.parse_errors.is_empty());
+        assert!(result.all_issues().next().is_none());
+
+        let result = use_case
+            .execute(
+                &ValidateOptions::new("docs/decisions")
+                    .with_files(vec!["docs/decisions/adr-0004.md".to_string()]),
+            )
+            .unwrap();
+        assert!(result.reports.is_empty());
+        assert_eq!(result.parse_errors.len(), 1);
+    }
+
+    #[test]
+    fn test_validate_suppressed_rules() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0001.md",
+            minimal_adr_content().replace(
+                "Some content.",
+                "<!-- adrscope-ignore: recommended-fields, body-structure -->",
+            ),
+        );
+
+        let result = ValidateUseCase::new(fs)
+            .execute(&ValidateOptions::new("docs/decisions").with_strict(true))
+            .unwrap();
+
+        assert!(result.passed);
+        assert_eq!(result.total_warnings, 0);
+        assert_eq!(result.total_suppressed, 6);
+        assert!(
+            result
+                .render(ValidateFormat::Text)
+                .contains("Validation complete: 0 errors, 0 warnings, 6 suppressed\n")
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&result.render(ValidateFormat::Json)).unwrap();
+        assert_eq!(json["files"][0]["suppressed"][0]["code"], "V0002");
+    }
+
+    #[test]
+    fn test_validate_render_json() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", valid_adr_content());
+        fs.add_file("docs/decisions/adr-0002.md", minimal_adr_content());
+        fs.add_file("docs/decisions/adr-0003.md", invalid_adr_content());
+        let options = ValidateOptions::new("docs/decisions").with_report(
+            ValidateFormat::from_path("validation.json"),
+            "validation.json",
+        );
+
+        let result = ValidateUseCase::new(fs.clone()).execute(&options).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&fs.read_to_string(Path::new("validation.json")).unwrap())
+                .unwrap();
+
+        assert_eq!(json["passed"], false);
+        assert_eq!(json["total_errors"], 0);
+        assert_eq!(json["total_warnings"], result.total_warnings);
+        assert_eq!(json["files"].as_array().unwrap().len(), 2);
+        let issue = &json["files"][1]["issues"][0];
+        assert_eq!(issue["severity"], "warning");
+        assert_eq!(issue["code"], "V0002");
+        assert_eq!(issue["rule"], "recommended-fields");
+        assert_eq!(issue["message"], "missing recommended field 'description'");
+        assert!(issue["line"].is_null());
+        assert_eq!(
+            json["parse_errors"][0]["path"],
+            "docs/decisions/adr-0003.md"
+        );
+        assert_eq!(json["parse_errors"][0]["code"], "E0004");
+    }
+
+    #[test]
+    fn test_validate_render_github() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", minimal_adr_content());
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            "---\ntitle: Two\ntags: [unclosed\n---\n",
+        );
+
+        let result = ValidateUseCase::new(fs)
+            .execute(&ValidateOptions::new("docs/decisions"))
+            .unwrap();
+        let output = result.render(ValidateFormat::Github);
+        let lines: Vec<_> = output.lines().collect();
+
+        assert!(lines[0].starts_with(
+            "::error file=docs/decisions/adr-0002.md,line=3,title=E0004::YAML parsing failed"
+        ));
+        assert_eq!(
+            lines[1],
+            "::warning file=docs/decisions/adr-0001.md,line=1,title=V0002 recommended-fields\
+             ::missing recommended field 'description'"
+        );
+        assert!(
+            output.ends_with("\nValidation complete: 0 errors, 6 warnings\nValidation failed.\n")
+        );
+        assert_eq!(escape_property("a,b:c%\n"), "a%2Cb%3Ac%25%0A");
+    }
+
+    #[test]
+    fn test_validate_render_junit() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", valid_adr_content());
+        fs.add_file("docs/decisions/adr-0002.md", minimal_adr_content());
+        fs.add_file("docs/decisions/adr-0003.md", invalid_adr_content());
+        let use_case = ValidateUseCase::new(fs);
+
+        let result = use_case
+            .execute(&ValidateOptions::new("docs/decisions"))
+            .unwrap();
+        let xml = result.render(ValidateFormat::Junit);
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"));
+        assert!(xml.contains(r#"<testsuite name="validate" tests="3" failures="0" errors="1">"#));
+        assert!(
+            xml.contains(r#"<testcase classname="validate" name="docs/decisions/adr-0001.md"/>"#)
+        );
+        assert!(xml.contains("<system-out>WARNING: missing recommended field 'description'"));
+        assert!(!xml.contains("<failure"));
+
+        let result = use_case
+            .execute(&ValidateOptions::new("docs/decisions").with_strict(true))
+            .unwrap();
+        let xml = result.render(ValidateFormat::Junit);
+        assert!(xml.contains(
+            r#"<failure type="V0002 recommended-fields" message="missing recommended field"#
+        ));
+        assert!(!xml.contains("<system-out>"));
+        assert!(xml.ends_with("  </testsuite>\n</testsuites>\n"));
+    }
 }
@@ -0,0 +1,146 @@
+//! Verify generated viewer data use case.
+//!
+//! Checks a previously generated HTML viewer or JSON export against the viewer
+//! data schema of this version of ADRScope.
+
+use std::path::Path;
+
+use crate::error::Result;
+use crate::infrastructure::FileSystem;
+use crate::infrastructure::renderer::schema::{self, SchemaCheck};
+
+/// Options for the verify command.
+#[derive(Debug, Clone)]
+pub struct VerifyOptions {
+    /// Generated HTML or JSON file to check.
+    pub path: String,
+}
+
+impl Default for VerifyOptions {
+    fn default() -> Self {
+        Self {
+            path: "adrs.html".to_string(),
+        }
+    }
+}
+
+impl VerifyOptions {
+    /// Creates new options for the given file.
+    #[must_use]
+    pub fn new(path: impl Into<String>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+/// Use case for verifying generated viewer data.
+#[derive(Debug)]
+pub struct VerifyUseCase<F: FileSystem> {
+    fs: F,
+}
+
+impl<F: FileSystem> VerifyUseCase<F> {
+    /// Creates a new verify use case.
+    #[must_use]
+    pub const fn new(fs: F) -> Self {
+        Self { fs }
+    }
+
+    /// Executes the verify use case.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read.
+    pub fn execute(&self, options: &VerifyOptions) -> Result<VerifyResult> {
+        let content = self.fs.read_to_string(Path::new(&options.path))?;
+
+        let check = schema::extract_viewer_json(&content)
+            .ok_or_else(|| "no embedded viewer data found".to_string())
+            .and_then(|json| {
+                serde_json::from_str(json)
+                    .map_err(|e| format!("embedded viewer data is not valid JSON: {e}"))
+            })
+            .map_or_else(
+                |error| SchemaCheck {
+                    errors: vec![error],
+                    ..SchemaCheck::default()
+                },
+                |data| schema::check_viewer_data(&data),
+            );
+
+        Ok(VerifyResult {
+            path: options.path.clone(),
+            check,
+        })
+    }
+}
+
+/// Result of the verify use case.
+#[derive(Debug)]
+pub struct VerifyResult {
+    /// Path of the verified file.
+    pub path: String,
+    /// Outcome of the schema check.
+    pub check: SchemaCheck,
+}
+
+impl VerifyResult {
+    /// Returns true if the file is compatible with this version of ADRScope.
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.check.is_compatible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::{GenerateOptions, GenerateUseCase};
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+    use crate::infrastructure::renderer::SCHEMA_VERSION;
+
+    #[test]
+    fn test_verify_generated_viewer() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(
+            "docs/decisions/adr-0001.md",
+            "---\ntitle: Use PostgreSQL\nstatus: accepted\n---\n\n# Use PostgreSQL\n",
+        );
+        GenerateUseCase::new(fs.clone())
+            .execute(&GenerateOptions::new("docs/decisions").with_output("out/adrs.html"))
+            .unwrap();
+
+        let result = VerifyUseCase::new(fs)
+            .execute(&VerifyOptions::new("out/adrs.html"))
+            .unwrap();
+
+        assert!(result.passed(), "{:?}", result.check.errors);
+        assert_eq!(result.check.schema_version.as_deref(), Some(SCHEMA_VERSION));
+        assert_eq!(result.check.record_count, 1);
+    }
+
+    #[test]
+    fn test_verify_without_viewer_data() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("page.html", "<html></html>");
+        fs.add_file("data.json", "{not json");
+
+        let use_case = VerifyUseCase::new(fs);
+        assert!(
+            !use_case
+                .execute(&VerifyOptions::new("page.html"))
+                .unwrap()
+                .passed()
+        );
+        assert!(
+            !use_case
+                .execute(&VerifyOptions::new("data.json"))
+                .unwrap()
+                .passed()
+        );
+        assert!(
+            use_case
+                .execute(&VerifyOptions::new("missing.html"))
+                .is_err()
+        );
+    }
+}
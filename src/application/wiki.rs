@@ -4,10 +4,12 @@
 
 use std::path::Path;
 
-use crate::domain::Adr;
+use super::assets::copy_assets;
+use super::source::{LoadedAdrs, SourceOptions, load_adrs};
+use crate::domain::{AdrFilter, ValidationIssue};
 use crate::error::Result;
 use crate::infrastructure::renderer::WikiRenderer;
-use crate::infrastructure::{AdrParser, DefaultAdrParser, FileSystem};
+use crate::infrastructure::{DefaultAdrParser, FileSystem};
 
 /// Options for the wiki command.
 #[derive(Debug, Clone)]
@@ -20,6 +22,12 @@ pub struct WikiOptions {
     pub pages_url: Option<String>,
     /// Glob pattern for matching ADR files.
     pub pattern: String,
+    /// Where ADR files are discovered from.
+    pub source: SourceOptions,
+    /// Criteria selecting which ADRs are included.
+    pub filter: AdrFilter,
+    /// Authors and tags listed on the statistics page; `None` lists all.
+    pub limit: Option<usize>,
 }
 
 impl Default for WikiOptions {
@@ -29,6 +37,9 @@ impl Default for WikiOptions {
             output_dir: "wiki".to_string(),
             pages_url: None,
             pattern: "**/*.md".to_string(),
+            source: SourceOptions::default(),
+            filter: AdrFilter::default(),
+            limit: Some(10),
         }
     }
 }
@@ -63,6 +74,28 @@ impl WikiOptions {
         self.pattern = pattern.into();
         self
     }
+
+    /// Sets the ADR source options.
+    #[must_use]
+    pub fn with_source(mut self, source: SourceOptions) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Sets the criteria selecting which ADRs are included.
+    #[must_use]
+    pub fn with_filter(mut self, filter: AdrFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Sets how many authors and tags the statistics page lists, or all of
+    /// them when `None`.
+    #[must_use]
+    pub const fn with_limit(mut self, limit: Option<usize>) -> Self {
+        self.limit = limit;
+        self
+    }
 }
 
 /// Use case for generating GitHub Wiki pages.
@@ -94,34 +127,34 @@ impl<F: FileSystem> WikiUseCase<F> {
     /// - Parsing fails
     /// - File writing fails
     pub fn execute(&self, options: &WikiOptions) -> Result<WikiResult> {
-        // Discover ADR files
-        let base = Path::new(&options.input_dir);
-        let files = self.fs.glob(base, &options.pattern)?;
-
-        if files.is_empty() {
-            return Err(crate::error::Error::NoAdrsFound {
-                path: base.to_path_buf(),
-            });
-        }
-
-        // Parse all ADRs
-        let mut adrs = Vec::with_capacity(files.len());
-        let mut errors = Vec::new();
-
-        for file_path in &files {
-            match self.parse_adr(file_path) {
-                Ok(adr) => adrs.push(adr),
-                Err(e) => errors.push((file_path.clone(), e)),
-            }
-        }
+        let loaded = load_adrs(
+            &self.fs,
+            &self.parser,
+            &options.input_dir,
+            &options.pattern,
+            &options.source,
+        )?;
+        self.execute_loaded(options, loaded)
+    }
 
-        // Sort by ID for consistent ordering
-        adrs.sort_by(|a, b| a.id().cmp(b.id()));
+    /// Executes the use case on ADRs that have already been loaded.
+    pub(super) fn execute_loaded(
+        &self,
+        options: &WikiOptions,
+        loaded: LoadedAdrs,
+    ) -> Result<WikiResult> {
+        let LoadedAdrs {
+            mut adrs,
+            parse_errors,
+            warnings,
+            ..
+        } = loaded;
+        adrs.retain(|adr| options.filter.matches(adr));
 
         // Generate wiki pages
         let pages = self
             .renderer
-            .render_all(&adrs, options.pages_url.as_deref())?;
+            .render_all(&adrs, options.pages_url.as_deref(), options.limit)?;
 
         // Create output directory
         self.fs.create_dir_all(Path::new(&options.output_dir))?;
@@ -134,26 +167,23 @@ impl<F: FileSystem> WikiUseCase<F> {
             generated_files.push(output_path);
         }
 
-        // Copy original ADR files to wiki directory
+        // Copy original ADR files and their assets to wiki directory
         for adr in &adrs {
             let dest_path = format!("{}/{}", options.output_dir, adr.filename());
             let content = self.fs.read_to_string(adr.source_path())?;
             self.fs.write(Path::new(&dest_path), &content)?;
             generated_files.push(dest_path);
+            generated_files.extend(copy_assets(&self.fs, adr, &options.output_dir)?);
         }
 
         Ok(WikiResult {
             output_dir: options.output_dir.clone(),
             generated_files,
             adr_count: adrs.len(),
-            parse_errors: errors,
+            parse_errors,
+            warnings,
         })
     }
-
-    fn parse_adr(&self, path: &Path) -> Result<Adr> {
-        let content = self.fs.read_to_string(path)?;
-        self.parser.parse(path, &content)
-    }
 }
 
 /// Result of the wiki generation use case.
@@ -167,6 +197,8 @@ pub struct WikiResult {
     pub adr_count: usize,
     /// Files that failed to parse.
     pub parse_errors: Vec<(std::path::PathBuf, crate::error::Error)>,
+    /// Non-fatal problems found while loading, such as colliding IDs.
+    pub warnings: Vec<ValidationIssue>,
 }
 
 impl WikiResult {
@@ -238,6 +270,23 @@ We will use PostgreSQL.
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_wiki_filtered() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file("docs/decisions/adr-0001.md", sample_adr_content());
+        fs.add_file(
+            "docs/decisions/adr-0002.md",
+            sample_adr_content().replace("2025-01-15", "2024-03-01"),
+        );
+
+        let use_case = WikiUseCase::new(fs);
+        let options = WikiOptions::new("docs/decisions")
+            .with_filter(AdrFilter::new().with_until(time::macros::date!(2024 - 12 - 31)));
+
+        let result = use_case.execute(&options).unwrap();
+        assert_eq!(result.adr_count, 1);
+    }
+
     #[test]
     fn test_wiki_options_builder() {
         let options = WikiOptions::new("input")
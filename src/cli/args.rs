@@ -1,6 +1,20 @@
 //! Command-line argument definitions using clap derive.
 
-use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+use clap::builder::BoolishValueParser;
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
+use clap_complete::ArgValueCandidates;
+
+use time::Date;
+use time::format_description::well_known::Iso8601;
+
+use super::complete;
+use crate::application::{
+    ChangeKind, ExportFormat, FieldChange, GraphFormat, ListFormat, ReviewFormat, SourceOptions,
+    ValidateFormat,
+};
+use crate::domain::{AdrFilter, Frontmatter, GraphGrouping};
 
 /// ADRScope - Generate self-contained HTML viewers for Architecture Decision Records.
 #[derive(Parser, Debug)]
@@ -8,9 +22,17 @@ use clap::{Parser, Subcommand, ValueEnum};
 #[command(author, version, about, long_about = None)]
 pub struct Cli {
     /// Enable verbose output.
-    #[arg(short, long, global = true)]
+    #[arg(short, long, global = true, env = "ADRSCOPE_VERBOSE", value_parser = BoolishValueParser::new())]
     pub verbose: bool,
 
+    /// Configuration file to use instead of ./adrscope.toml.
+    #[arg(long, global = true, value_name = "FILE", env = "ADRSCOPE_CONFIG")]
+    pub config: Option<PathBuf>,
+
+    /// Configuration profile from adrscope.toml to apply.
+    #[arg(long, global = true, value_name = "NAME", env = "ADRSCOPE_PROFILE")]
+    pub profile: Option<String>,
+
     /// The command to run.
     #[command(subcommand)]
     pub command: Commands,
@@ -30,82 +52,1183 @@ pub enum Commands {
 
     /// Show ADR statistics.
     Stats(StatsArgs),
+
+    /// List ADRs matching filters as a table or JSON.
+    List(ListArgs),
+
+    /// Export the decision graph as GraphViz DOT, Mermaid, GraphML, or JSON.
+    Graph(GraphArgs),
+
+    /// Export the ADRs as JSON viewer data, JSON Lines, or a SQLite database.
+    Export(ExportArgs),
+
+    /// List decisions past their review date.
+    Review(ReviewArgs),
+
+    /// List decisions due for review soon, as text, JSON, or a GitHub issue body.
+    Remind(RemindArgs),
+
+    /// Run several outputs (validate, generate, wiki, stats) from one scan of the ADRs.
+    Build(BuildArgs),
+
+    /// Check a generated viewer against the viewer data schema.
+    Verify(VerifyArgs),
+
+    /// Explain an error or validation code.
+    Explain(ExplainArgs),
+
+    /// Open the viewer in the browser, optionally at one ADR.
+    Open(OpenArgs),
+
+    /// Preview the viewer over HTTP on localhost, regenerated on every reload.
+    Serve(ServeArgs),
+
+    /// Set up a decisions directory with a first ADR, a README, and an adrscope.toml.
+    Init(InitArgs),
+
+    /// Create a new ADR from the standard template.
+    New(NewArgs),
+
+    /// Mark an ADR as accepted.
+    Accept(TransitionArgs),
+
+    /// Mark an ADR as deprecated.
+    Deprecate(TransitionArgs),
+
+    /// Change frontmatter fields across all matching ADRs.
+    Edit(EditArgs),
+
+    /// Rename an ADR and update every reference to it.
+    Rename(RenameArgs),
+
+    /// Link two ADRs as related, or mark one as superseding the other.
+    Link(LinkArgs),
+
+    /// Browse ADRs interactively in the terminal.
+    #[cfg(feature = "tui")]
+    Tui(TuiArgs),
 }
 
 /// Arguments for the generate command.
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Default)]
+#[allow(
+    clippy::struct_excessive_bools,
+    reason = "each bool is a separate command-line flag"
+)]
 pub struct GenerateArgs {
-    /// Input directory containing ADR files.
-    #[arg(short, long, default_value = "docs/decisions")]
-    pub input: String,
+    /// Input directories containing ADR files, merged into one set
+    /// (repeatable or comma-separated) [default: docs/decisions].
+    #[arg(
+        short,
+        long,
+        value_name = "DIR",
+        env = "ADRSCOPE_INPUT",
+        value_delimiter = ','
+    )]
+    pub input: Vec<String>,
+
+    /// Output HTML file path [default: adrs.html].
+    #[arg(short, long, env = "ADRSCOPE_OUTPUT")]
+    pub output: Option<String>,
+
+    /// Page title [default: Architecture Decision Records].
+    #[arg(short, long, env = "ADRSCOPE_TITLE")]
+    pub title: Option<String>,
+
+    /// Theme preference [default: auto].
+    #[arg(long, value_enum, env = "ADRSCOPE_THEME")]
+    pub theme: Option<ThemeArg>,
+
+    /// Glob pattern for matching ADR files [default: **/*.md].
+    #[arg(short, long, env = "ADRSCOPE_PATTERN")]
+    pub pattern: Option<String>,
+
+    /// ADR source options.
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    /// ADR selection options.
+    #[command(flatten)]
+    pub filter: FilterArgs,
+
+    /// Derive the generation timestamp from the ADRs for byte-identical output.
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        env = "ADRSCOPE_REPRODUCIBLE",
+        value_parser = BoolishValueParser::new()
+    )]
+    pub reproducible: Option<bool>,
+
+    /// Embed each ADR's raw markdown for the viewer's source view and copy button.
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        env = "ADRSCOPE_EMBED_MARKDOWN",
+        value_parser = BoolishValueParser::new()
+    )]
+    pub embed_markdown: Option<bool>,
+
+    /// Optional record fields to embed, replacing the default selection.
+    #[arg(long, value_enum, value_delimiter = ',', env = "ADRSCOPE_EMBED_FIELDS")]
+    pub embed_fields: Option<Vec<RecordFieldArg>>,
 
-    /// Output HTML file path.
-    #[arg(short, long, default_value = "adrs.html")]
-    pub output: String,
+    /// Optional record fields to leave out of the embedded data.
+    #[arg(long, value_enum, value_delimiter = ',', env = "ADRSCOPE_OMIT_FIELDS")]
+    pub omit_fields: Vec<RecordFieldArg>,
 
-    /// Page title.
-    #[arg(short, long, default_value = "Architecture Decision Records")]
-    pub title: String,
+    /// Also write an index.json with metadata, facets, and graph next to the viewer.
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        env = "ADRSCOPE_INDEX",
+        value_parser = BoolishValueParser::new()
+    )]
+    pub index: Option<bool>,
 
-    /// Theme preference.
-    #[arg(long, value_enum, default_value = "auto")]
-    pub theme: ThemeArg,
+    /// URL the viewer is served from, for canonical and OpenGraph links.
+    #[arg(long, value_name = "URL", env = "ADRSCOPE_BASE_URL")]
+    pub base_url: Option<String>,
 
-    /// Glob pattern for matching ADR files.
-    #[arg(short, long, default_value = "**/*.md")]
-    pub pattern: String,
+    /// Insert a hash of the ADR content into the output file name (adrs.<hash>.html).
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        env = "ADRSCOPE_HASH_FILENAME",
+        value_parser = BoolishValueParser::new()
+    )]
+    pub hash_filename: Option<bool>,
+
+    /// File with HTML to insert into the viewer header, e.g. a navigation bar.
+    #[arg(long, value_name = "FILE", env = "ADRSCOPE_HEADER_HTML")]
+    pub header_html: Option<String>,
+
+    /// File with HTML to insert into the viewer footer, e.g. a compliance notice.
+    #[arg(long, value_name = "FILE", env = "ADRSCOPE_FOOTER_HTML")]
+    pub footer_html: Option<String>,
+
+    /// Fail instead of writing a viewer that loads resources (images, fonts, scripts) from other hosts.
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        env = "ADRSCOPE_STRICT_OFFLINE",
+        value_parser = BoolishValueParser::new()
+    )]
+    pub strict_offline: Option<bool>,
+
+    /// POST a JSON summary (counts, changed ADRs, output path) to this webhook URL.
+    #[arg(long, value_name = "URL", env = "ADRSCOPE_NOTIFY_URL")]
+    pub notify_url: Option<String>,
+
+    /// Cache parsed ADRs in this file so later runs only parse changed files [default: .adrscope-cache.json].
+    #[arg(long, value_name = "FILE", num_args = 0..=1, default_missing_value = ".adrscope-cache.json", env = "ADRSCOPE_CACHE")]
+    pub cache: Option<String>,
 }
 
 /// Arguments for the wiki command.
 #[derive(Parser, Debug)]
 pub struct WikiArgs {
-    /// Input directory containing ADR files.
-    #[arg(short, long, default_value = "docs/decisions")]
-    pub input: String,
+    /// Input directories containing ADR files, merged into one set
+    /// (repeatable or comma-separated) [default: docs/decisions].
+    #[arg(
+        short,
+        long,
+        value_name = "DIR",
+        env = "ADRSCOPE_INPUT",
+        value_delimiter = ','
+    )]
+    pub input: Vec<String>,
 
-    /// Output directory for wiki files.
-    #[arg(short, long, default_value = "wiki")]
-    pub output: String,
+    /// Output directory for wiki files [default: wiki].
+    #[arg(short, long, env = "ADRSCOPE_WIKI_OUTPUT")]
+    pub output: Option<String>,
 
     /// URL to the GitHub Pages viewer (for cross-linking).
-    #[arg(long)]
+    #[arg(long, env = "ADRSCOPE_PAGES_URL")]
     pub pages_url: Option<String>,
 
-    /// Glob pattern for matching ADR files.
-    #[arg(short, long, default_value = "**/*.md")]
-    pub pattern: String,
+    /// Glob pattern for matching ADR files [default: **/*.md].
+    #[arg(short, long, env = "ADRSCOPE_PATTERN")]
+    pub pattern: Option<String>,
+
+    /// ADR source options.
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    /// ADR selection options.
+    #[command(flatten)]
+    pub filter: FilterArgs,
+
+    /// Number of authors and tags listed on the statistics page [default: 10].
+    #[arg(long, value_name = "N", env = "ADRSCOPE_TOP", conflicts_with = "full")]
+    pub top: Option<usize>,
+
+    /// List every author and tag on the statistics page.
+    #[arg(long, env = "ADRSCOPE_FULL", value_parser = BoolishValueParser::new())]
+    pub full: bool,
 }
 
 /// Arguments for the validate command.
 #[derive(Parser, Debug)]
 pub struct ValidateArgs {
-    /// Input directory containing ADR files.
-    #[arg(short, long, default_value = "docs/decisions")]
-    pub input: String,
+    /// ADR files to validate, out of those in the input directories [default: all].
+    #[arg(value_name = "FILE", value_delimiter = ',')]
+    pub files: Vec<String>,
+
+    /// Validate only the files listed on stdin, one per line (e.g. from `git diff --name-only`), along with any FILE.
+    #[arg(long, env = "ADRSCOPE_VALIDATE_CHANGED_ONLY", value_parser = BoolishValueParser::new())]
+    pub changed_only: bool,
 
-    /// Glob pattern for matching ADR files.
-    #[arg(short, long, default_value = "**/*.md")]
-    pub pattern: String,
+    /// Input directories containing ADR files, merged into one set
+    /// (repeatable or comma-separated) [default: docs/decisions].
+    #[arg(
+        short,
+        long,
+        value_name = "DIR",
+        env = "ADRSCOPE_INPUT",
+        value_delimiter = ','
+    )]
+    pub input: Vec<String>,
+
+    /// Glob pattern for matching ADR files [default: **/*.md].
+    #[arg(short, long, env = "ADRSCOPE_PATTERN")]
+    pub pattern: Option<String>,
+
+    /// ADR source options.
+    #[command(flatten)]
+    pub source: SourceArgs,
 
     /// Fail on warnings (strict mode).
-    #[arg(long)]
-    pub strict: bool,
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        env = "ADRSCOPE_STRICT",
+        value_parser = BoolishValueParser::new()
+    )]
+    pub strict: Option<bool>,
+
+    /// Regular expression ADR filenames must match [default: (adr-)?\d{4}(-[a-z0-9-]+)?\.md].
+    #[arg(long, value_name = "REGEX", env = "ADRSCOPE_FILENAME_PATTERN")]
+    pub filename_pattern: Option<String>,
+
+    /// JSON Schema file the frontmatter of every ADR must conform to, custom fields included.
+    #[arg(long, value_name = "PATH", env = "ADRSCOPE_SCHEMA")]
+    pub schema: Option<String>,
+
+    /// Warn about decisions proposed for longer than this since their creation date (e.g. 90d, 12w, or days).
+    #[arg(
+        long,
+        value_name = "DAYS",
+        value_parser = parse_days,
+        env = "ADRSCOPE_MAX_PROPOSED_AGE"
+    )]
+    pub max_proposed_age: Option<u32>,
+
+    /// Run only these rules, by name or code (repeatable or comma-separated).
+    #[arg(
+        long,
+        value_name = "RULE",
+        env = "ADRSCOPE_VALIDATE_ONLY",
+        value_delimiter = ','
+    )]
+    pub only: Vec<String>,
+
+    /// Skip these rules, by name or code (repeatable or comma-separated).
+    #[arg(
+        long,
+        value_name = "RULE",
+        env = "ADRSCOPE_VALIDATE_SKIP",
+        value_delimiter = ','
+    )]
+    pub skip: Vec<String>,
+
+    /// Format of the report printed to stdout.
+    #[arg(
+        short,
+        long,
+        value_enum,
+        default_value = "text",
+        env = "ADRSCOPE_VALIDATE_FORMAT"
+    )]
+    pub format: ValidateFormatArg,
+
+    /// Also write the report to a file; the format (text, markdown, junit, github, json) is taken from the prefix or extension (repeatable).
+    #[arg(
+        long,
+        value_name = "[FORMAT:]PATH",
+        value_parser = parse_report_file,
+        env = "ADRSCOPE_VALIDATE_REPORT_FILE"
+    )]
+    pub report_file: Vec<(ValidateFormat, String)>,
 }
 
 /// Arguments for the stats command.
 #[derive(Parser, Debug)]
 pub struct StatsArgs {
-    /// Input directory containing ADR files.
-    #[arg(short, long, default_value = "docs/decisions")]
-    pub input: String,
+    /// Input directories containing ADR files, merged into one set
+    /// (repeatable or comma-separated) [default: docs/decisions].
+    #[arg(
+        short,
+        long,
+        value_name = "DIR",
+        env = "ADRSCOPE_INPUT",
+        value_delimiter = ','
+    )]
+    pub input: Vec<String>,
 
-    /// Glob pattern for matching ADR files.
-    #[arg(short, long, default_value = "**/*.md")]
-    pub pattern: String,
+    /// Glob pattern for matching ADR files [default: **/*.md].
+    #[arg(short, long, env = "ADRSCOPE_PATTERN")]
+    pub pattern: Option<String>,
+
+    /// ADR source options.
+    #[command(flatten)]
+    pub source: SourceArgs,
 
     /// Output format.
-    #[arg(short, long, value_enum, default_value = "text")]
+    #[arg(
+        short,
+        long,
+        value_enum,
+        default_value = "text",
+        env = "ADRSCOPE_STATS_FORMAT"
+    )]
     pub format: FormatArg,
+
+    /// Number of values listed per dimension in text output [default: 5].
+    #[arg(long, value_name = "N", env = "ADRSCOPE_TOP", conflicts_with = "full")]
+    pub top: Option<usize>,
+
+    /// List every value per dimension instead of the top N.
+    #[arg(long, env = "ADRSCOPE_FULL", value_parser = BoolishValueParser::new())]
+    pub full: bool,
+}
+
+/// Arguments for the list command.
+#[derive(Parser, Debug)]
+pub struct ListArgs {
+    /// Search query, e.g. 'tag:database status:accepted postgres -redis'.
+    #[arg(value_name = "QUERY")]
+    pub query: Option<String>,
+
+    /// Input directories containing ADR files, merged into one set
+    /// (repeatable or comma-separated) [default: docs/decisions].
+    #[arg(
+        short,
+        long,
+        value_name = "DIR",
+        env = "ADRSCOPE_INPUT",
+        value_delimiter = ','
+    )]
+    pub input: Vec<String>,
+
+    /// Glob pattern for matching ADR files [default: **/*.md].
+    #[arg(short, long, env = "ADRSCOPE_PATTERN")]
+    pub pattern: Option<String>,
+
+    /// ADR source options.
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    /// ADR selection options.
+    #[command(flatten)]
+    pub filter: FilterArgs,
+
+    /// Output format.
+    #[arg(
+        short,
+        long,
+        value_enum,
+        default_value = "text",
+        env = "ADRSCOPE_LIST_FORMAT"
+    )]
+    pub format: ListFormatArg,
+}
+
+/// Arguments for the graph command.
+#[derive(Parser, Debug)]
+pub struct GraphArgs {
+    /// Input directories containing ADR files, merged into one set
+    /// (repeatable or comma-separated) [default: docs/decisions].
+    #[arg(
+        short,
+        long,
+        value_name = "DIR",
+        env = "ADRSCOPE_INPUT",
+        value_delimiter = ','
+    )]
+    pub input: Vec<String>,
+
+    /// Glob pattern for matching ADR files [default: **/*.md].
+    #[arg(short, long, env = "ADRSCOPE_PATTERN")]
+    pub pattern: Option<String>,
+
+    /// ADR source options.
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    /// Output format [default: from the output file's extension, else dot].
+    #[arg(short, long, value_enum, env = "ADRSCOPE_GRAPH_FORMAT")]
+    pub format: Option<GraphFormatArg>,
+
+    /// File to write the graph to instead of stdout.
+    #[arg(short, long, value_name = "FILE", env = "ADRSCOPE_GRAPH_OUTPUT")]
+    pub output: Option<String>,
+
+    /// Cluster the ADRs by category, project, or first tag.
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FIELD",
+        env = "ADRSCOPE_GRAPH_GROUP_BY"
+    )]
+    pub group_by: Option<GraphGroupArg>,
+}
+
+/// Arguments for the export command.
+#[derive(Parser, Debug)]
+pub struct ExportArgs {
+    /// Input directories containing ADR files, merged into one set
+    /// (repeatable or comma-separated) [default: docs/decisions].
+    #[arg(
+        short,
+        long,
+        value_name = "DIR",
+        env = "ADRSCOPE_INPUT",
+        value_delimiter = ','
+    )]
+    pub input: Vec<String>,
+
+    /// Glob pattern for matching ADR files [default: **/*.md].
+    #[arg(short, long, env = "ADRSCOPE_PATTERN")]
+    pub pattern: Option<String>,
+
+    /// ADR source options.
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    /// ADR selection options.
+    #[command(flatten)]
+    pub filter: FilterArgs,
+
+    /// Output format [default: from the output file's extension, else json].
+    #[arg(short, long, value_enum, env = "ADRSCOPE_EXPORT_FORMAT")]
+    pub format: Option<ExportFormatArg>,
+
+    /// File to write the export to instead of stdout; required for sqlite.
+    #[arg(
+        short,
+        long,
+        value_name = "FILE",
+        env = "ADRSCOPE_EXPORT_OUTPUT",
+        required_if_eq("format", "sqlite")
+    )]
+    pub output: Option<String>,
+
+    /// Derive the generation timestamp from the ADRs for byte-identical output.
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        env = "ADRSCOPE_REPRODUCIBLE",
+        value_parser = BoolishValueParser::new()
+    )]
+    pub reproducible: Option<bool>,
+
+    /// Include each ADR's raw markdown.
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        env = "ADRSCOPE_EMBED_MARKDOWN",
+        value_parser = BoolishValueParser::new()
+    )]
+    pub embed_markdown: Option<bool>,
+
+    /// Optional record fields to include, replacing the default selection.
+    #[arg(long, value_enum, value_delimiter = ',', env = "ADRSCOPE_EMBED_FIELDS")]
+    pub embed_fields: Option<Vec<RecordFieldArg>>,
+
+    /// Optional record fields to leave out.
+    #[arg(long, value_enum, value_delimiter = ',', env = "ADRSCOPE_OMIT_FIELDS")]
+    pub omit_fields: Vec<RecordFieldArg>,
+}
+
+/// Arguments for the review command.
+#[derive(Parser, Debug)]
+pub struct ReviewArgs {
+    /// Input directories containing ADR files, merged into one set
+    /// (repeatable or comma-separated) [default: docs/decisions].
+    #[arg(
+        short,
+        long,
+        value_name = "DIR",
+        env = "ADRSCOPE_INPUT",
+        value_delimiter = ','
+    )]
+    pub input: Vec<String>,
+
+    /// Glob pattern for matching ADR files [default: **/*.md].
+    #[arg(short, long, env = "ADRSCOPE_PATTERN")]
+    pub pattern: Option<String>,
+
+    /// ADR source options.
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    /// Check review dates against this date (YYYY-MM-DD) instead of today.
+    #[arg(long, value_name = "DATE", value_parser = parse_date, env = "ADRSCOPE_REVIEW_DATE")]
+    pub date: Option<Date>,
+}
+
+/// Arguments for the remind command.
+#[derive(Parser, Debug)]
+pub struct RemindArgs {
+    /// Input directories containing ADR files, merged into one set
+    /// (repeatable or comma-separated) [default: docs/decisions].
+    #[arg(
+        short,
+        long,
+        value_name = "DIR",
+        env = "ADRSCOPE_INPUT",
+        value_delimiter = ','
+    )]
+    pub input: Vec<String>,
+
+    /// Glob pattern for matching ADR files [default: **/*.md].
+    #[arg(short, long, env = "ADRSCOPE_PATTERN")]
+    pub pattern: Option<String>,
+
+    /// ADR source options.
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    /// Also list reviews due within this period (e.g. 30d, 4w, or days).
+    #[arg(
+        long,
+        value_name = "DAYS",
+        value_parser = parse_days,
+        default_value = "0",
+        env = "ADRSCOPE_REMIND_DUE_WITHIN"
+    )]
+    pub due_within: u32,
+
+    /// Output format.
+    #[arg(
+        short,
+        long,
+        value_enum,
+        default_value = "text",
+        env = "ADRSCOPE_REMIND_FORMAT"
+    )]
+    pub format: RemindFormatArg,
+
+    /// Check review dates against this date (YYYY-MM-DD) instead of today.
+    #[arg(long, value_name = "DATE", value_parser = parse_date, env = "ADRSCOPE_REVIEW_DATE")]
+    pub date: Option<Date>,
+}
+
+/// Arguments for the build command.
+#[derive(Parser, Debug)]
+pub struct BuildArgs {
+    /// Input directories containing ADR files, merged into one set
+    /// (repeatable or comma-separated) [default: docs/decisions].
+    #[arg(
+        short,
+        long,
+        value_name = "DIR",
+        env = "ADRSCOPE_INPUT",
+        value_delimiter = ','
+    )]
+    pub input: Vec<String>,
+
+    /// Glob pattern for matching ADR files [default: **/*.md].
+    #[arg(short, long, env = "ADRSCOPE_PATTERN")]
+    pub pattern: Option<String>,
+
+    /// ADR source options.
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    /// Steps to run, replacing [build] steps (comma-separated) [default: validate,generate].
+    #[arg(
+        long = "step",
+        value_enum,
+        value_delimiter = ',',
+        env = "ADRSCOPE_BUILD_STEPS"
+    )]
+    pub steps: Vec<BuildStepArg>,
+
+    /// Fail on validation warnings (strict mode).
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        env = "ADRSCOPE_STRICT",
+        value_parser = BoolishValueParser::new()
+    )]
+    pub strict: Option<bool>,
+
+    /// File the stats step writes to; the format is taken from the extension [default: adrs-stats.md].
+    #[arg(long, env = "ADRSCOPE_BUILD_STATS_OUTPUT")]
+    pub stats_output: Option<String>,
+}
+
+/// Arguments for the verify command.
+#[derive(Parser, Debug)]
+pub struct VerifyArgs {
+    /// Generated HTML viewer or JSON file to check [default: adrs.html].
+    pub file: Option<String>,
+}
+
+/// Arguments for the explain command.
+#[derive(Parser, Debug)]
+pub struct ExplainArgs {
+    /// Code to explain, e.g. E0003 (lists all codes when omitted).
+    pub code: Option<String>,
+}
+
+/// Arguments for the open command.
+#[derive(Parser, Debug)]
+pub struct OpenArgs {
+    /// ADR to show: its ID, file name, or number (e.g. 17 for adr-0017).
+    #[arg(add = ArgValueCandidates::new(complete::adr_ids))]
+    pub id: Option<String>,
+
+    /// Open the ADR's markdown source in $VISUAL or $EDITOR instead of the viewer.
+    #[arg(long, requires = "id", env = "ADRSCOPE_OPEN_EDITOR", value_parser = BoolishValueParser::new())]
+    pub editor: bool,
+
+    /// Scroll to a section of the ADR, by heading (e.g. "Consequences") or anchor.
+    #[arg(
+        long,
+        value_name = "HEADING",
+        requires = "id",
+        conflicts_with = "editor",
+        env = "ADRSCOPE_OPEN_SECTION"
+    )]
+    pub section: Option<String>,
+
+    /// Open the existing viewer instead of regenerating it.
+    #[arg(long, env = "ADRSCOPE_OPEN_NO_GENERATE", value_parser = BoolishValueParser::new())]
+    pub no_generate: bool,
+
+    /// Options for generating the viewer.
+    #[command(flatten)]
+    pub generate: GenerateArgs,
+}
+
+/// Arguments for the serve command.
+#[derive(Parser, Debug)]
+pub struct ServeArgs {
+    /// Port to listen on [default: 8080].
+    #[arg(long, env = "ADRSCOPE_SERVE_PORT")]
+    pub port: Option<u16>,
+
+    /// Host name or address to listen on [default: 127.0.0.1].
+    #[arg(long, env = "ADRSCOPE_SERVE_HOST")]
+    pub host: Option<String>,
+
+    /// Open the viewer in the browser once the server is listening.
+    #[arg(long, env = "ADRSCOPE_SERVE_OPEN", value_parser = BoolishValueParser::new())]
+    pub open: bool,
+
+    /// Options for generating the viewer.
+    #[command(flatten)]
+    pub generate: GenerateArgs,
+}
+
+/// Arguments for the init command.
+#[derive(Parser, Debug)]
+pub struct InitArgs {
+    /// Decisions directory to create [default: docs/decisions].
+    #[arg(short, long, env = "ADRSCOPE_INPUT")]
+    pub input: Option<String>,
+
+    /// Overwrite files that already exist.
+    #[arg(long, env = "ADRSCOPE_INIT_FORCE", value_parser = BoolishValueParser::new())]
+    pub force: bool,
+}
+
+/// Arguments for the new command.
+#[derive(Parser, Debug)]
+pub struct NewArgs {
+    /// Title of the new ADR.
+    #[arg(required_unless_present = "interactive")]
+    pub title: Option<String>,
+
+    /// Directory to write the ADR to [default: docs/decisions].
+    #[arg(short, long, env = "ADRSCOPE_INPUT")]
+    pub input: Option<String>,
+
+    /// Glob pattern for matching existing ADR files [default: **/*.md].
+    #[arg(short, long, env = "ADRSCOPE_PATTERN")]
+    pub pattern: Option<String>,
+
+    /// Prompt for each field, completing categories and tags from existing ADRs.
+    #[arg(short = 'I', long, env = "ADRSCOPE_NEW_INTERACTIVE", value_parser = BoolishValueParser::new())]
+    pub interactive: bool,
+
+    /// One-sentence summary.
+    #[arg(short, long, env = "ADRSCOPE_NEW_DESCRIPTION")]
+    pub description: Option<String>,
+
+    /// Decision category.
+    #[arg(
+        short,
+        long,
+        env = "ADRSCOPE_NEW_CATEGORY",
+        add = ArgValueCandidates::new(complete::categories)
+    )]
+    pub category: Option<String>,
+
+    /// Tags (comma-separated).
+    #[arg(
+        long = "tag",
+        value_name = "TAG",
+        value_delimiter = ',',
+        env = "ADRSCOPE_NEW_TAGS",
+        add = ArgValueCandidates::new(complete::tags)
+    )]
+    pub tags: Vec<String>,
+
+    /// Related ADRs by ID, file name, or number (comma-separated).
+    #[arg(
+        long,
+        value_name = "ADR",
+        value_delimiter = ',',
+        env = "ADRSCOPE_NEW_RELATED",
+        add = ArgValueCandidates::new(complete::adr_ids)
+    )]
+    pub related: Vec<String>,
+
+    /// People or teams who made the decision (comma-separated).
+    #[arg(
+        long = "decider",
+        value_name = "NAME",
+        value_delimiter = ',',
+        env = "ADRSCOPE_NEW_DECIDERS"
+    )]
+    pub deciders: Vec<String>,
+
+    /// Initial status, built-in or configured [default: proposed].
+    #[arg(
+        long,
+        env = "ADRSCOPE_NEW_STATUS",
+        add = ArgValueCandidates::new(complete::statuses)
+    )]
+    pub status: Option<String>,
+}
+
+/// Arguments for the status transition commands (accept, deprecate).
+#[derive(Parser, Debug)]
+pub struct TransitionArgs {
+    /// ADR to change: its ID, file name, or number.
+    #[arg(add = ArgValueCandidates::new(complete::adr_ids))]
+    pub id: String,
+
+    /// Input directories containing ADR files, merged into one set
+    /// (repeatable or comma-separated) [default: docs/decisions].
+    #[arg(
+        short,
+        long,
+        value_name = "DIR",
+        env = "ADRSCOPE_INPUT",
+        value_delimiter = ','
+    )]
+    pub input: Vec<String>,
+
+    /// Glob pattern for matching ADR files [default: **/*.md].
+    #[arg(short, long, env = "ADRSCOPE_PATTERN")]
+    pub pattern: Option<String>,
+
+    /// ADR source options.
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    /// Why the status changed; recorded in the status history.
+    #[arg(long, env = "ADRSCOPE_TRANSITION_REASON")]
+    pub reason: Option<String>,
+
+    /// Append an entry to the ADR's frontmatter `history` list.
+    #[arg(long, env = "ADRSCOPE_TRANSITION_HISTORY", value_parser = BoolishValueParser::new())]
+    pub history: bool,
+}
+
+/// Arguments for the edit command.
+#[derive(Parser, Debug)]
+#[command(group(ArgGroup::new("changes").required(true).multiple(true).args(["set", "add", "remove"])))]
+pub struct EditArgs {
+    /// Input directories containing ADR files, merged into one set
+    /// (repeatable or comma-separated) [default: docs/decisions].
+    #[arg(
+        short,
+        long,
+        value_name = "DIR",
+        env = "ADRSCOPE_INPUT",
+        value_delimiter = ','
+    )]
+    pub input: Vec<String>,
+
+    /// Glob pattern for matching ADR files [default: **/*.md].
+    #[arg(short, long, env = "ADRSCOPE_PATTERN")]
+    pub pattern: Option<String>,
+
+    /// ADR source options.
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    /// Only edit ADRs whose field has this value (repeatable; all must match).
+    #[arg(
+        long = "where",
+        value_name = "FIELD=VALUE",
+        value_parser = parse_assignment,
+        env = "ADRSCOPE_EDIT_WHERE"
+    )]
+    pub conditions: Vec<(String, String)>,
+
+    /// Replace a field's value; list fields take comma-separated values, replacing
+    /// only the item a --where on the same field matched (repeatable).
+    #[arg(long, value_name = "FIELD=VALUE", value_parser = parse_assignment, env = "ADRSCOPE_EDIT_SET")]
+    pub set: Vec<(String, String)>,
+
+    /// Add a value to a list field such as tags (repeatable).
+    #[arg(long, value_name = "FIELD=VALUE", value_parser = parse_list_assignment, env = "ADRSCOPE_EDIT_ADD")]
+    pub add: Vec<(String, String)>,
+
+    /// Remove a value from a list field such as tags (repeatable).
+    #[arg(long, value_name = "FIELD=VALUE", value_parser = parse_list_assignment, env = "ADRSCOPE_EDIT_REMOVE")]
+    pub remove: Vec<(String, String)>,
+
+    /// Show the changes without writing them.
+    #[arg(long, env = "ADRSCOPE_EDIT_DRY_RUN", value_parser = BoolishValueParser::new())]
+    pub dry_run: bool,
+}
+
+impl EditArgs {
+    /// Returns the changes in the order set, add, remove.
+    #[must_use]
+    pub fn changes(&self) -> Vec<FieldChange> {
+        let changes = |kind, values: &[(String, String)]| {
+            values
+                .iter()
+                .map(move |(field, value)| FieldChange::new(kind, field, value))
+                .collect::<Vec<_>>()
+        };
+
+        [
+            changes(ChangeKind::Set, &self.set),
+            changes(ChangeKind::Add, &self.add),
+            changes(ChangeKind::Remove, &self.remove),
+        ]
+        .concat()
+    }
+}
+
+/// Arguments for the rename command.
+#[derive(Parser, Debug)]
+#[command(group(ArgGroup::new("target").required(true).multiple(true).args(["new_name", "title"])))]
+pub struct RenameArgs {
+    /// ADR to rename: its ID, file name, or number.
+    #[arg(add = ArgValueCandidates::new(complete::adr_ids))]
+    pub id: String,
+
+    /// New file name; `.md` is appended if missing.
+    pub new_name: Option<String>,
+
+    /// New title; also names the file, keeping its number, if no new name is given.
+    #[arg(long, env = "ADRSCOPE_RENAME_TITLE")]
+    pub title: Option<String>,
+
+    /// Input directories containing ADR files, merged into one set
+    /// (repeatable or comma-separated) [default: docs/decisions].
+    #[arg(
+        short,
+        long,
+        value_name = "DIR",
+        env = "ADRSCOPE_INPUT",
+        value_delimiter = ','
+    )]
+    pub input: Vec<String>,
+
+    /// Glob pattern for matching ADR files [default: **/*.md].
+    #[arg(short, long, env = "ADRSCOPE_PATTERN")]
+    pub pattern: Option<String>,
+
+    /// ADR source options.
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    /// Show the changes without writing them.
+    #[arg(long, env = "ADRSCOPE_RENAME_DRY_RUN", value_parser = BoolishValueParser::new())]
+    pub dry_run: bool,
+}
+
+/// Arguments for the link command.
+#[derive(Parser, Debug)]
+pub struct LinkArgs {
+    /// ADR the link starts from: its ID, file name, or number.
+    #[arg(add = ArgValueCandidates::new(complete::adr_ids))]
+    pub from: String,
+
+    /// ADR the link points to: its ID, file name, or number.
+    #[arg(add = ArgValueCandidates::new(complete::adr_ids))]
+    pub to: String,
+
+    /// Kind of relationship.
+    #[arg(
+        long = "type",
+        value_enum,
+        default_value = "related",
+        env = "ADRSCOPE_LINK_TYPE"
+    )]
+    pub link_type: LinkTypeArg,
+
+    /// Input directories containing ADR files, merged into one set
+    /// (repeatable or comma-separated) [default: docs/decisions].
+    #[arg(
+        short,
+        long,
+        value_name = "DIR",
+        env = "ADRSCOPE_INPUT",
+        value_delimiter = ','
+    )]
+    pub input: Vec<String>,
+
+    /// Glob pattern for matching ADR files [default: **/*.md].
+    #[arg(short, long, env = "ADRSCOPE_PATTERN")]
+    pub pattern: Option<String>,
+
+    /// ADR source options.
+    #[command(flatten)]
+    pub source: SourceArgs,
+}
+
+/// Arguments for the tui command.
+#[cfg(feature = "tui")]
+#[derive(Parser, Debug)]
+pub struct TuiArgs {
+    /// Input directories containing ADR files, merged into one set
+    /// (repeatable or comma-separated) [default: docs/decisions].
+    #[arg(
+        short,
+        long,
+        value_name = "DIR",
+        env = "ADRSCOPE_INPUT",
+        value_delimiter = ','
+    )]
+    pub input: Vec<String>,
+
+    /// Glob pattern for matching ADR files [default: **/*.md].
+    #[arg(short, long, env = "ADRSCOPE_PATTERN")]
+    pub pattern: Option<String>,
+
+    /// ADR source options.
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    /// Initial filter, which can be changed in the browser.
+    #[command(flatten)]
+    pub filter: FilterArgs,
+}
+
+/// Arguments controlling where ADR files are discovered, shared by all commands.
+#[derive(Args, Debug, Clone, Default)]
+pub struct SourceArgs {
+    /// Walk a monorepo root for decision directories instead of reading --input.
+    #[arg(long, value_name = "DIR", num_args = 0..=1, default_missing_value = ".", env = "ADRSCOPE_DISCOVER")]
+    pub discover: Option<String>,
+
+    /// Directory convention identifying a decision directory (repeatable).
+    #[arg(
+        long = "convention",
+        value_name = "PATH",
+        requires = "discover",
+        env = "ADRSCOPE_CONVENTIONS",
+        value_delimiter = ','
+    )]
+    pub conventions: Vec<String>,
+
+    /// Leave out files matching this glob, e.g. `**/drafts/**` (repeatable).
+    #[arg(
+        long,
+        value_name = "GLOB",
+        env = "ADRSCOPE_EXCLUDE",
+        value_delimiter = ','
+    )]
+    pub exclude: Vec<String>,
+}
+
+impl From<SourceArgs> for SourceOptions {
+    fn from(args: SourceArgs) -> Self {
+        let mut options = Self::new();
+        if let Some(root) = args.discover {
+            options = options.with_discover(root);
+        }
+        if !args.conventions.is_empty() {
+            options = options.with_conventions(args.conventions);
+        }
+        options.with_exclude(args.exclude)
+    }
+}
+
+/// Arguments selecting which ADRs a command includes.
+#[derive(Args, Debug, Clone, Default)]
+pub struct FilterArgs {
+    /// Only include ADRs created or updated on or after this date (YYYY-MM-DD).
+    #[arg(long, value_name = "DATE", value_parser = parse_date, env = "ADRSCOPE_SINCE")]
+    pub since: Option<Date>,
+
+    /// Only include ADRs created or updated on or before this date (YYYY-MM-DD).
+    #[arg(long, value_name = "DATE", value_parser = parse_date, env = "ADRSCOPE_UNTIL")]
+    pub until: Option<Date>,
+
+    /// Only include ADRs with these statuses, built-in or configured
+    /// (comma-separated).
+    #[arg(
+        long,
+        value_name = "STATUS",
+        value_delimiter = ',',
+        env = "ADRSCOPE_STATUS",
+        add = ArgValueCandidates::new(complete::statuses)
+    )]
+    pub status: Vec<String>,
+
+    /// Only include ADRs with at least one of these tags (comma-separated).
+    #[arg(
+        long = "tag",
+        value_name = "TAG",
+        value_delimiter = ',',
+        env = "ADRSCOPE_TAGS",
+        add = ArgValueCandidates::new(complete::tags)
+    )]
+    pub tags: Vec<String>,
+
+    /// Exclude ADRs with any of these tags (comma-separated).
+    #[arg(
+        long = "exclude-tag",
+        value_name = "TAG",
+        value_delimiter = ',',
+        env = "ADRSCOPE_EXCLUDE_TAGS",
+        add = ArgValueCandidates::new(complete::tags)
+    )]
+    pub exclude_tags: Vec<String>,
+
+    /// Only include ADRs in these categories (comma-separated).
+    #[arg(
+        long = "category",
+        value_name = "CATEGORY",
+        value_delimiter = ',',
+        env = "ADRSCOPE_CATEGORIES",
+        add = ArgValueCandidates::new(complete::categories)
+    )]
+    pub categories: Vec<String>,
+
+    /// Exclude ADRs in these categories (comma-separated).
+    #[arg(
+        long = "exclude-category",
+        value_name = "CATEGORY",
+        value_delimiter = ',',
+        env = "ADRSCOPE_EXCLUDE_CATEGORIES",
+        add = ArgValueCandidates::new(complete::categories)
+    )]
+    pub exclude_categories: Vec<String>,
+}
+
+impl From<FilterArgs> for AdrFilter {
+    fn from(args: FilterArgs) -> Self {
+        let mut filter = Self::new()
+            .with_statuses(args.status)
+            .with_tags(args.tags)
+            .with_exclude_tags(args.exclude_tags)
+            .with_categories(args.categories)
+            .with_exclude_categories(args.exclude_categories);
+        if let Some(since) = args.since {
+            filter = filter.with_since(since);
+        }
+        if let Some(until) = args.until {
+            filter = filter.with_until(until);
+        }
+        filter
+    }
+}
+
+/// Parses a `FIELD=VALUE` argument.
+fn parse_assignment(value: &str) -> Result<(String, String), String> {
+    let (field, value) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected FIELD=VALUE, got '{value}'"))?;
+    let field = field.trim();
+    if field.is_empty() {
+        return Err("field name must not be empty".to_string());
+    }
+    Ok((field.to_string(), value.trim().to_string()))
+}
+
+/// Parses a `[FORMAT:]PATH` report file argument.
+fn parse_report_file(value: &str) -> Result<(ValidateFormat, String), String> {
+    let (format, path) = value
+        .split_once(':')
+        .and_then(|(format, path)| Some((format.parse().ok()?, path)))
+        .unwrap_or_else(|| (ValidateFormat::from_path(value), value));
+    if path.is_empty() {
+        return Err("report file path must not be empty".to_string());
+    }
+    Ok((format, path.to_string()))
+}
+
+/// Parses a `FIELD=VALUE` argument naming a list field.
+fn parse_list_assignment(value: &str) -> Result<(String, String), String> {
+    let (field, value) = parse_assignment(value)?;
+    if !Frontmatter::LIST_FIELDS.contains(&field.as_str()) {
+        return Err(format!(
+            "'{field}' is not a list field (expected one of: {})",
+            Frontmatter::LIST_FIELDS.join(", ")
+        ));
+    }
+    Ok((field, value))
+}
+
+/// Parses an ISO 8601 calendar date argument.
+fn parse_date(value: &str) -> Result<Date, String> {
+    Date::parse(value, &Iso8601::DATE).map_err(|e| format!("invalid date '{value}': {e}"))
+}
+
+/// Parses a period in days: `30`, `30d`, or `4w`.
+fn parse_days(value: &str) -> Result<u32, String> {
+    let (number, factor) = value.strip_suffix('w').map_or_else(
+        || (value.strip_suffix('d').unwrap_or(value), 1),
+        |weeks| (weeks, 7),
+    );
+    number
+        .trim()
+        .parse::<u32>()
+        .ok()
+        .and_then(|n| n.checked_mul(factor))
+        .ok_or_else(|| format!("invalid period '{value}': expected days such as 30, 30d, or 4w"))
 }
 
 /// Theme argument for CLI.
@@ -130,6 +1253,76 @@ impl From<ThemeArg> for crate::infrastructure::Theme {
     }
 }
 
+/// Link type argument for CLI.
+#[derive(ValueEnum, Clone, Copy, Debug, Default)]
+pub enum LinkTypeArg {
+    /// Both ADRs list each other in `related`.
+    #[default]
+    Related,
+    /// The first ADR supersedes the second, which is marked superseded.
+    Supersedes,
+}
+
+impl From<LinkTypeArg> for crate::domain::EdgeType {
+    fn from(arg: LinkTypeArg) -> Self {
+        match arg {
+            LinkTypeArg::Related => Self::Related,
+            LinkTypeArg::Supersedes => Self::Supersedes,
+        }
+    }
+}
+
+/// Build step argument for CLI.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuildStepArg {
+    /// Validate the ADRs.
+    Validate,
+    /// Generate the HTML viewer.
+    Generate,
+    /// Generate GitHub Wiki pages.
+    Wiki,
+    /// Write statistics to a file.
+    Stats,
+}
+
+impl From<BuildStepArg> for crate::infrastructure::BuildStep {
+    fn from(arg: BuildStepArg) -> Self {
+        match arg {
+            BuildStepArg::Validate => Self::Validate,
+            BuildStepArg::Generate => Self::Generate,
+            BuildStepArg::Wiki => Self::Wiki,
+            BuildStepArg::Stats => Self::Stats,
+        }
+    }
+}
+
+/// Embedded record field argument for CLI.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecordFieldArg {
+    /// Original filename.
+    Filename,
+    /// Rendered HTML body (detail panel).
+    BodyHtml,
+    /// Plain text body (full-text search).
+    BodyText,
+    /// Raw markdown body (source view).
+    BodyMarkdown,
+    /// Owning monorepo package.
+    Package,
+}
+
+impl From<RecordFieldArg> for crate::infrastructure::RecordField {
+    fn from(arg: RecordFieldArg) -> Self {
+        match arg {
+            RecordFieldArg::Filename => Self::Filename,
+            RecordFieldArg::BodyHtml => Self::BodyHtml,
+            RecordFieldArg::BodyText => Self::BodyText,
+            RecordFieldArg::BodyMarkdown => Self::BodyMarkdown,
+            RecordFieldArg::Package => Self::Package,
+        }
+    }
+}
+
 /// Output format argument for CLI.
 #[derive(ValueEnum, Clone, Debug, Default)]
 pub enum FormatArg {
@@ -142,6 +1335,143 @@ pub enum FormatArg {
     Markdown,
 }
 
+/// Output format argument for the remind command.
+#[derive(ValueEnum, Clone, Copy, Debug, Default)]
+pub enum RemindFormatArg {
+    /// Human-readable text.
+    #[default]
+    Text,
+    /// JSON, for scripts and chat notifications.
+    Json,
+    /// Markdown task list for a GitHub issue body; empty when nothing is due.
+    Github,
+}
+
+impl From<RemindFormatArg> for ReviewFormat {
+    fn from(arg: RemindFormatArg) -> Self {
+        match arg {
+            RemindFormatArg::Text => Self::Text,
+            RemindFormatArg::Json => Self::Json,
+            RemindFormatArg::Github => Self::Github,
+        }
+    }
+}
+
+/// Output format argument for the list command.
+#[derive(ValueEnum, Clone, Copy, Debug, Default)]
+pub enum ListFormatArg {
+    /// Human-readable table.
+    #[default]
+    Text,
+    /// JSON array, for scripts.
+    Json,
+}
+
+impl From<ListFormatArg> for ListFormat {
+    fn from(arg: ListFormatArg) -> Self {
+        match arg {
+            ListFormatArg::Text => Self::Text,
+            ListFormatArg::Json => Self::Json,
+        }
+    }
+}
+
+/// Output format argument for the validate command.
+#[derive(ValueEnum, Clone, Copy, Debug, Default)]
+pub enum ValidateFormatArg {
+    /// Human-readable text.
+    #[default]
+    Text,
+    /// Markdown table of issues.
+    Markdown,
+    /// JUnit XML, for CI systems that render test results.
+    Junit,
+    /// GitHub Actions annotations, shown inline on pull request diffs.
+    Github,
+    /// JSON, for scripts and bots.
+    Json,
+}
+
+impl From<ValidateFormatArg> for ValidateFormat {
+    fn from(arg: ValidateFormatArg) -> Self {
+        match arg {
+            ValidateFormatArg::Text => Self::Text,
+            ValidateFormatArg::Markdown => Self::Markdown,
+            ValidateFormatArg::Junit => Self::Junit,
+            ValidateFormatArg::Github => Self::Github,
+            ValidateFormatArg::Json => Self::Json,
+        }
+    }
+}
+
+/// Output format argument for the graph command.
+#[derive(ValueEnum, Clone, Copy, Debug, Default)]
+pub enum GraphFormatArg {
+    /// GraphViz DOT, for dot and other GraphViz tools.
+    #[default]
+    Dot,
+    /// Mermaid flowchart, rendered natively in GitHub markdown.
+    Mermaid,
+    /// GraphML, for Gephi, Cytoscape, and yEd.
+    Graphml,
+    /// Node-link JSON, for scripts and network analysis libraries.
+    Json,
+}
+
+impl From<GraphFormatArg> for GraphFormat {
+    fn from(arg: GraphFormatArg) -> Self {
+        match arg {
+            GraphFormatArg::Dot => Self::Dot,
+            GraphFormatArg::Mermaid => Self::Mermaid,
+            GraphFormatArg::Graphml => Self::Graphml,
+            GraphFormatArg::Json => Self::Json,
+        }
+    }
+}
+
+/// Output format argument for the export command.
+#[derive(ValueEnum, Clone, Copy, Debug, Default)]
+pub enum ExportFormatArg {
+    /// The viewer data as one JSON document.
+    #[default]
+    Json,
+    /// A SQLite database of ADRs, tags, technologies, and relations.
+    Sqlite,
+    /// JSON Lines, one ADR per line.
+    Ndjson,
+}
+
+impl From<ExportFormatArg> for ExportFormat {
+    fn from(arg: ExportFormatArg) -> Self {
+        match arg {
+            ExportFormatArg::Json => Self::Json,
+            ExportFormatArg::Sqlite => Self::Sqlite,
+            ExportFormatArg::Ndjson => Self::Ndjson,
+        }
+    }
+}
+
+/// Grouping argument for graph clusters.
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum GraphGroupArg {
+    /// The `category` field.
+    Category,
+    /// The `project` field, or the monorepo package.
+    Project,
+    /// The first tag.
+    Tag,
+}
+
+impl From<GraphGroupArg> for GraphGrouping {
+    fn from(arg: GraphGroupArg) -> Self {
+        match arg {
+            GraphGroupArg::Category => Self::Category,
+            GraphGroupArg::Project => Self::Project,
+            GraphGroupArg::Tag => Self::Tag,
+        }
+    }
+}
+
 impl From<FormatArg> for crate::application::stats::StatsFormat {
     fn from(arg: FormatArg) -> Self {
         match arg {
@@ -163,18 +1493,91 @@ mod tests {
         Cli::command().debug_assert();
     }
 
+    #[test]
+    fn test_every_option_has_env_override() {
+        fn check(command: &clap::Command) {
+            for arg in command.get_arguments() {
+                let id = arg.get_id().as_str();
+                if matches!(id, "help" | "version") {
+                    continue;
+                }
+                // Positional arguments are only taken from the command line
+                if arg.is_positional() {
+                    assert!(arg.get_env().is_none(), "{} {id}", command.get_name());
+                    continue;
+                }
+                assert!(
+                    arg.get_env()
+                        .is_some_and(|env| env.to_string_lossy().starts_with("ADRSCOPE_")),
+                    "{} --{id} has no ADRSCOPE_* environment variable",
+                    command.get_name()
+                );
+            }
+            command.get_subcommands().for_each(check);
+        }
+
+        check(&Cli::command());
+    }
+
     #[test]
     fn test_generate_defaults() {
         let args = GenerateArgs {
-            input: "docs/decisions".to_string(),
-            output: "adrs.html".to_string(),
-            title: "ADRs".to_string(),
-            theme: ThemeArg::Auto,
-            pattern: "**/*.md".to_string(),
+            input: vec!["docs/decisions".to_string()],
+            output: Some("adrs.html".to_string()),
+            title: Some("ADRs".to_string()),
+            theme: Some(ThemeArg::Auto),
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            filter: FilterArgs::default(),
+            reproducible: None,
+            embed_markdown: None,
+            embed_fields: None,
+            omit_fields: Vec::new(),
+            index: None,
+            base_url: None,
+            hash_filename: None,
+            header_html: None,
+            footer_html: None,
+            strict_offline: None,
+            notify_url: None,
+            cache: None,
         };
 
-        assert_eq!(args.input, "docs/decisions");
-        assert_eq!(args.output, "adrs.html");
+        assert_eq!(args.input, vec!["docs/decisions".to_string()]);
+        assert_eq!(args.output.as_deref(), Some("adrs.html"));
+    }
+
+    #[test]
+    fn test_source_conversion() {
+        let options = SourceOptions::from(SourceArgs::default());
+        assert_eq!(options, SourceOptions::new());
+
+        let options = SourceOptions::from(SourceArgs {
+            discover: Some(".".to_string()),
+            conventions: vec!["architecture".to_string()],
+            exclude: vec!["template.md".to_string()],
+        });
+        assert_eq!(options.discover_root.as_deref(), Some("."));
+        assert_eq!(options.conventions, vec!["architecture".to_string()]);
+        assert_eq!(options.exclude, vec!["template.md".to_string()]);
+    }
+
+    #[test]
+    fn test_filter_conversion() {
+        assert!(AdrFilter::from(FilterArgs::default()).is_empty());
+
+        let since = parse_date("2025-01-01").expect("should parse");
+        let filter = AdrFilter::from(FilterArgs {
+            since: Some(since),
+            until: None,
+            status: vec!["accepted".to_string()],
+            tags: vec!["security".to_string()],
+            ..FilterArgs::default()
+        });
+        assert_eq!(filter.since, Some(since));
+        assert_eq!(filter.statuses, vec!["accepted".to_string()]);
+        assert_eq!(filter.tags, vec!["security".to_string()]);
+        assert!(parse_date("01/02/2025").is_err());
     }
 
     #[test]
@@ -203,4 +1606,108 @@ mod tests {
             StatsFormat::Markdown
         ));
     }
+
+    #[test]
+    fn test_parse_build_steps() {
+        let cli = Cli::try_parse_from(["adrscope", "build", "--step", "wiki,stats"])
+            .expect("should parse");
+        let Commands::Build(args) = cli.command else {
+            panic!("expected build command");
+        };
+        assert_eq!(args.steps, vec![BuildStepArg::Wiki, BuildStepArg::Stats]);
+    }
+
+    #[test]
+    fn test_parse_cache() {
+        let cache = |args: &[&str]| {
+            let cli = Cli::try_parse_from([&["adrscope", "generate"], args].concat())
+                .expect("should parse");
+            let Commands::Generate(args) = cli.command else {
+                panic!("expected generate command");
+            };
+            args.cache
+        };
+        assert_eq!(cache(&[]), None);
+        assert_eq!(cache(&["--cache"]).as_deref(), Some(".adrscope-cache.json"));
+        assert_eq!(
+            cache(&["--cache", "target/adrs.json"]).as_deref(),
+            Some("target/adrs.json")
+        );
+    }
+
+    #[test]
+    fn test_parse_boolean_flags() {
+        let strict = |args: &[&str]| {
+            let cli = Cli::try_parse_from([&["adrscope", "validate"], args].concat())
+                .expect("should parse");
+            let Commands::Validate(args) = cli.command else {
+                panic!("expected validate command");
+            };
+            (args.strict, args.files)
+        };
+        // Unset flags leave the configured value in effect
+        assert_eq!(strict(&[]), (None, Vec::new()));
+        assert_eq!(
+            strict(&["--strict", "adr-0001.md"]),
+            (Some(true), vec!["adr-0001.md".to_string()])
+        );
+        assert_eq!(strict(&["--strict=false"]), (Some(false), Vec::new()));
+        assert_eq!(strict(&["--strict=no"]), (Some(false), Vec::new()));
+    }
+
+    #[test]
+    fn test_parse_multiple_inputs() {
+        let cli = Cli::try_parse_from([
+            "adrscope",
+            "validate",
+            "-i",
+            "docs/decisions",
+            "--input",
+            "services/payments/docs/decisions,legacy/adr",
+        ])
+        .expect("should parse");
+        let Commands::Validate(args) = cli.command else {
+            panic!("expected validate command");
+        };
+        assert_eq!(
+            args.input,
+            vec![
+                "docs/decisions",
+                "services/payments/docs/decisions",
+                "legacy/adr"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("30"), Ok(30));
+        assert_eq!(parse_days("30d"), Ok(30));
+        assert_eq!(parse_days("4w"), Ok(28));
+        assert!(parse_days("1m").is_err());
+        assert!(parse_days("-3d").is_err());
+    }
+
+    #[test]
+    fn test_parse_report_file() {
+        assert_eq!(
+            parse_report_file("reports/validation.md"),
+            Ok((
+                ValidateFormat::Markdown,
+                "reports/validation.md".to_string()
+            ))
+        );
+        assert_eq!(
+            parse_report_file("markdown:summary.txt"),
+            Ok((ValidateFormat::Markdown, "summary.txt".to_string()))
+        );
+        assert_eq!(
+            parse_report_file("C:/reports/validation.log"),
+            Ok((
+                ValidateFormat::Text,
+                "C:/reports/validation.log".to_string()
+            ))
+        );
+        assert!(parse_report_file("text:").is_err());
+    }
 }
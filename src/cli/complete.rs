@@ -0,0 +1,86 @@
+//! Dynamic shell completion of ADR IDs and frontmatter values.
+//!
+//! Completion scripts call back into the binary (see `CompleteEnv` in
+//! `main.rs`), so candidates always reflect the ADRs currently on disk. The
+//! ADRs are located the same way commands locate them, except that only
+//! environment variables and `adrscope.toml` are consulted: flags typed
+//! earlier on the command line are not visible to the completers.
+
+use std::env;
+use std::path::PathBuf;
+
+use clap_complete::CompletionCandidate;
+
+use super::SourceArgs;
+use super::handlers::source_options;
+use crate::application::{CompleteOptions, CompleteUseCase, Completion, Completions};
+use crate::infrastructure::{Config, RealFileSystem};
+
+/// Completes ADR IDs, described by their titles.
+pub fn adr_ids() -> Vec<CompletionCandidate> {
+    candidates(completions().ids)
+}
+
+/// Completes statuses, described by their ADR counts.
+pub fn statuses() -> Vec<CompletionCandidate> {
+    candidates(completions().statuses)
+}
+
+/// Completes tags, described by their ADR counts.
+pub fn tags() -> Vec<CompletionCandidate> {
+    candidates(completions().tags)
+}
+
+/// Completes categories, described by their ADR counts.
+pub fn categories() -> Vec<CompletionCandidate> {
+    candidates(completions().categories)
+}
+
+/// Scans the configured ADR source, returning no values on any error.
+fn completions() -> Completions {
+    let fs = RealFileSystem::new();
+    let Ok(config) = Config::resolve(
+        &fs,
+        env::var_os("ADRSCOPE_CONFIG").map(PathBuf::from).as_deref(),
+        env::var("ADRSCOPE_PROFILE").ok().as_deref(),
+    ) else {
+        return Completions::default();
+    };
+
+    let list = |name: &str| {
+        env::var(name)
+            .map(|values| values.split(',').map(String::from).collect::<Vec<_>>())
+            .unwrap_or_default()
+    };
+    let source = SourceArgs {
+        discover: env::var("ADRSCOPE_DISCOVER").ok(),
+        conventions: list("ADRSCOPE_CONVENTIONS"),
+        exclude: list("ADRSCOPE_EXCLUDE"),
+    };
+
+    let defaults = CompleteOptions::default();
+    let mut inputs = list("ADRSCOPE_INPUT").into_iter();
+    let input = inputs
+        .next()
+        .or_else(|| config.input.clone())
+        .unwrap_or(defaults.input_dir);
+    let options = CompleteOptions::new(input)
+        .with_pattern(
+            env::var("ADRSCOPE_PATTERN")
+                .ok()
+                .or_else(|| config.pattern.clone())
+                .unwrap_or(defaults.pattern),
+        )
+        .with_source(source_options(&config, source, inputs.collect()));
+
+    CompleteUseCase::new(fs)
+        .execute(&options)
+        .unwrap_or_default()
+}
+
+fn candidates(values: Vec<Completion>) -> Vec<CompletionCandidate> {
+    values
+        .into_iter()
+        .map(|value| CompletionCandidate::new(value.value).help(Some(value.help.into())))
+        .collect()
+}
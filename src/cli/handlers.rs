@@ -1,15 +1,32 @@
 //! Command handlers that execute use cases.
 
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
 use crate::application::{
-    GenerateOptions, GenerateUseCase, StatsOptions, StatsUseCase, ValidateOptions, ValidateUseCase,
-    WikiOptions, WikiUseCase,
+    BrowseOptions, BrowseUseCase, BuildOptions, BuildOutput, BuildResult, BuildUseCase,
+    EditOptions, EditUseCase, ExportFormat, ExportOptions, ExportUseCase, GenerateOptions,
+    GenerateResult, GenerateUseCase, GraphFormat, GraphOptions, GraphUseCase, InitOptions,
+    InitUseCase, LinkOptions, LinkUseCase, ListOptions, ListUseCase, NewOptions, NewUseCase,
+    OpenOptions, OpenTarget, OpenUseCase, OutputStatus, RenameOptions, RenameUseCase,
+    ReviewOptions, ReviewResult, ReviewUseCase, ServeOptions, ServeUseCase, SourceOptions,
+    StatsFormat, StatsOptions, StatsUseCase, TransitionOptions, TransitionUseCase, ValidateFormat,
+    ValidateOptions, ValidateResult, ValidateUseCase, VerifyOptions, VerifyUseCase, WikiOptions,
+    WikiUseCase,
 };
-use crate::cli::args::{Cli, Commands, GenerateArgs, StatsArgs, ValidateArgs, WikiArgs};
-use crate::domain::Severity;
-use crate::error::Result;
-use crate::infrastructure::RealFileSystem;
+use crate::cli::args::{
+    BuildArgs, Cli, Commands, EditArgs, ExplainArgs, ExportArgs, FilterArgs, GenerateArgs,
+    GraphArgs, InitArgs, LinkArgs, ListArgs, NewArgs, OpenArgs, RemindArgs, RenameArgs, ReviewArgs,
+    ServeArgs, SourceArgs, StatsArgs, TransitionArgs, ValidateArgs, VerifyArgs, WikiArgs,
+};
+use crate::cli::wizard::Wizard;
+use crate::codes;
+use crate::domain::{
+    AdrFilter, RuleConfig, SearchQuery, Severity, Status, ValidationIssue, status_names,
+};
+use crate::error::{Error, Result};
+use crate::infrastructure::renderer::SCHEMA_VERSION;
+use crate::infrastructure::{BuildStep, Config, RealFileSystem, ci, http, launch, webhook};
 
 /// Runs the CLI with the parsed arguments.
 ///
@@ -17,71 +34,624 @@ use crate::infrastructure::RealFileSystem;
 ///
 /// Returns an error if the command execution fails.
 pub fn run(cli: Cli) -> Result<i32> {
+    let config = Config::resolve(
+        &RealFileSystem::new(),
+        cli.config.as_deref(),
+        cli.profile.as_deref(),
+    )?;
+
+    if cli.verbose {
+        if let Some(path) = &cli.config {
+            eprintln!("Using configuration file: {}", path.display());
+        }
+        if let Some(profile) = &cli.profile {
+            eprintln!("Using configuration profile: {profile}");
+        }
+    }
+
     match cli.command {
-        Commands::Generate(args) => handle_generate(args, cli.verbose),
-        Commands::Wiki(args) => handle_wiki(args, cli.verbose),
-        Commands::Validate(args) => handle_validate(args, cli.verbose),
-        Commands::Stats(args) => handle_stats(args, cli.verbose),
+        Commands::Generate(args) => handle_generate(args, config, cli.verbose),
+        Commands::Wiki(args) => handle_wiki(args, config, cli.verbose),
+        Commands::Validate(args) => handle_validate(args, config, cli.verbose),
+        Commands::Stats(args) => handle_stats(args, config, cli.verbose),
+        Commands::List(args) => handle_list(args, config, cli.verbose),
+        Commands::Graph(args) => handle_graph(args, config, cli.verbose),
+        Commands::Export(args) => handle_export(args, config, cli.verbose),
+        Commands::Review(args) => handle_review(args, config, cli.verbose),
+        Commands::Remind(args) => handle_remind(args, config, cli.verbose),
+        Commands::Build(args) => handle_build(args, config, cli.verbose),
+        Commands::Verify(args) => handle_verify(args, config, cli.verbose),
+        Commands::Explain(args) => Ok(handle_explain(args)),
+        Commands::Open(args) => handle_open(args, config, cli.verbose),
+        Commands::Serve(args) => handle_serve(args, config, cli.verbose),
+        Commands::Init(args) => handle_init(args, config),
+        Commands::New(args) => handle_new(args, config, cli.verbose),
+        Commands::Accept(args) => handle_transition(args, Status::Accepted, config, cli.verbose),
+        Commands::Deprecate(args) => {
+            handle_transition(args, Status::Deprecated, config, cli.verbose)
+        },
+        Commands::Edit(args) => handle_edit(args, config, cli.verbose),
+        Commands::Rename(args) => handle_rename(args, config, cli.verbose),
+        Commands::Link(args) => handle_link(args, config, cli.verbose),
+        #[cfg(feature = "tui")]
+        Commands::Tui(args) => handle_tui(args, config, cli.verbose),
     }
 }
 
-fn handle_generate(args: GenerateArgs, verbose: bool) -> Result<i32> {
+// Each handler resolves settings as: command-line flag, then configuration
+// file, then the use case's default.
+
+fn handle_generate(args: GenerateArgs, config: Config, verbose: bool) -> Result<i32> {
     let fs = RealFileSystem::new();
     let use_case = GenerateUseCase::new(fs);
+    let notify_url = args
+        .notify_url
+        .clone()
+        .or_else(|| config.generate.notify_url.clone());
+    let options = generate_options(args, config)?;
+
+    if verbose {
+        eprintln!(
+            "Scanning for ADRs in: {}",
+            source_dir(&options.input_dir, &options.source)
+        );
+    }
+
+    let result = use_case.execute(&options)?;
+
+    report_warnings(&result.parse_errors, &result.warnings);
+
+    println!(
+        "Generated {} with {} ADRs",
+        result.output_path, result.adr_count
+    );
+    if let Some(path) = &result.index_path {
+        println!("Wrote index to {path}");
+    }
+    if let (Some(cache), true) = (result.cache, verbose) {
+        eprintln!(
+            "Parse cache: {} reused, {} parsed",
+            cache.reused, cache.parsed
+        );
+    }
+    write_step_summary(&result.markdown(), verbose)?;
+    notify(notify_url.as_deref(), &result, verbose)?;
+
+    Ok(0)
+}
+
+/// Resolves generate options from flags and configuration, shared by
+/// `generate`, `open`, and `serve`.
+fn generate_options(args: GenerateArgs, config: Config) -> Result<GenerateOptions> {
+    let defaults = GenerateOptions::default();
+    let (input, inputs) = input_dirs(args.input, config.input.clone(), defaults.input_dir);
+    let source = source_options(&config, args.source, inputs);
+    let filter = adr_filter(&config, args.filter)?;
+    let mut options = GenerateOptions::new(input)
+        .with_output(
+            args.output
+                .or(config.generate.output)
+                .unwrap_or(defaults.output),
+        )
+        .with_title(
+            args.title
+                .or(config.generate.title)
+                .unwrap_or(defaults.title),
+        )
+        .with_theme(
+            args.theme
+                .map(Into::into)
+                .or(config.generate.theme)
+                .unwrap_or(defaults.theme),
+        )
+        .with_pattern(args.pattern.or(config.pattern).unwrap_or(defaults.pattern))
+        .with_source(source)
+        .with_filter(filter)
+        .with_reproducible(args.reproducible.unwrap_or(config.generate.reproducible))
+        .with_embed_markdown(
+            args.embed_markdown
+                .unwrap_or(config.generate.embed_markdown),
+        )
+        .with_smart_punctuation(config.generate.smart_punctuation)
+        .with_index(args.index.unwrap_or(config.generate.index))
+        .with_hash_filename(args.hash_filename.unwrap_or(config.generate.hash_filename))
+        .with_strict_offline(
+            args.strict_offline
+                .unwrap_or(config.generate.strict_offline),
+        )
+        .with_badges(config.generate.badges)
+        .with_facets(config.generate.facets);
+
+    let embed_fields = args
+        .embed_fields
+        .map(|fields| fields.into_iter().map(Into::into).collect())
+        .or(config.generate.embed_fields);
+    if let Some(fields) = embed_fields {
+        options = options.with_embed_fields(fields);
+    }
+    if let Some(base_url) = args.base_url.or(config.generate.base_url) {
+        options = options.with_base_url(base_url);
+    }
+    if let Some(path) = args.header_html.or(config.generate.header_html) {
+        options = options.with_header_html(path);
+    }
+    if let Some(path) = args.footer_html.or(config.generate.footer_html) {
+        options = options.with_footer_html(path);
+    }
+    if let Some(path) = args.cache.or(config.generate.cache) {
+        options = options.with_cache(path);
+    }
+    if let Some(analytics) = config.generate.analytics {
+        options = options.with_analytics(analytics);
+    }
+    if let Some(path) = config.generate.mermaid_js {
+        options = options.with_mermaid_js(path);
+    }
+    if let Some(grouping) = config.generate.graph_group_by {
+        options = options.with_graph_grouping(grouping);
+    }
+    if let Some(kb) = config.generate.max_inline_kb {
+        options = options.with_max_inline_size(kb.saturating_mul(1024));
+    }
+    if args.omit_fields.is_empty() {
+        options = options.with_omit_fields(config.generate.omit_fields);
+    } else {
+        options = options.with_omit_fields(args.omit_fields.into_iter().map(Into::into).collect());
+    }
+    Ok(options)
+}
+
+/// Resolves validate options from configuration.
+fn validate_options(
+    config: &Config,
+    input: &str,
+    pattern: &str,
+    source: &SourceOptions,
+) -> ValidateOptions {
+    let validate = config.validate.clone();
+    let defaults = ValidateOptions::default();
+    ValidateOptions {
+        schema: validate.schema,
+        max_proposed_age: validate.max_proposed_age,
+        title_style: validate.title_style,
+        ..ValidateOptions::new(input)
+    }
+    .with_pattern(pattern)
+    .with_source(source.clone())
+    .with_required(validate.required.unwrap_or(defaults.required))
+    .with_recommended(validate.recommended.unwrap_or(defaults.recommended))
+    .with_sections(validate.sections.unwrap_or(defaults.sections))
+    .with_filename_pattern(
+        validate
+            .filename_pattern
+            .unwrap_or(defaults.filename_pattern),
+    )
+    .with_allowed_tags(validate.allowed_tags)
+    .with_allowed_categories(validate.allowed_categories)
+    .with_orphan_threshold(
+        validate
+            .orphan_threshold
+            .unwrap_or(defaults.orphan_threshold),
+    )
+    .with_rules(RuleConfig::new().with_levels(validate.rules))
+}
+
+fn handle_open(args: OpenArgs, config: Config, verbose: bool) -> Result<i32> {
+    let fs = RealFileSystem::new();
+    let use_case = OpenUseCase::new(fs);
+
+    let notify_url = args
+        .generate
+        .notify_url
+        .clone()
+        .or_else(|| config.generate.notify_url.clone());
+    let mut options = OpenOptions::new(generate_options(args.generate, config)?)
+        .with_editor(args.editor)
+        .with_regenerate(!args.no_generate);
+    if let Some(id) = args.id {
+        options = options.with_id(id);
+    }
+    if let Some(section) = args.section {
+        options = options.with_section(section);
+    }
+
+    let result = use_case.execute(&options)?;
+
+    if let Some(generated) = &result.generated {
+        report_warnings(&generated.parse_errors, &generated.warnings);
+        if verbose {
+            eprintln!(
+                "Generated {} with {} ADRs",
+                generated.output_path, generated.adr_count
+            );
+        }
+        notify(notify_url.as_deref(), generated, verbose)?;
+    }
+
+    match &result.target {
+        OpenTarget::Viewer { path, fragment } => {
+            let url = launch::file_url(path, fragment.as_deref());
+            println!("Opening {url}");
+            launch::open_in_browser(&url)?;
+        },
+        OpenTarget::Source(path) => {
+            println!("Opening {}", path.display());
+            launch::open_in_editor(path)?;
+        },
+    }
+
+    Ok(0)
+}
+
+fn handle_serve(args: ServeArgs, config: Config, verbose: bool) -> Result<i32> {
+    let fs = RealFileSystem::new();
+    let use_case = ServeUseCase::new(fs);
+
+    let defaults = ServeOptions::default();
+    let host = args
+        .host
+        .or_else(|| config.serve.host.clone())
+        .unwrap_or(defaults.host);
+    let port = args.port.or(config.serve.port).unwrap_or(defaults.port);
+    let options = ServeOptions::new(generate_options(args.generate, config)?)
+        .with_host(host)
+        .with_port(port);
+
+    // Fail before listening if the viewer cannot be rendered at all
+    let viewer = use_case.render(&options)?;
+    report_warnings(&viewer.parse_errors, &viewer.warnings);
+
+    let listener = http::bind(&options.host, options.port)?;
+    let url = options.url();
+    println!("Serving {} ADRs at {url}", viewer.adr_count);
+    println!("Press Ctrl+C to stop");
+    if args.open {
+        launch::open_in_browser(&url)?;
+    }
+
+    http::serve(&listener, |path| {
+        if verbose {
+            eprintln!("GET {path}");
+        }
+        use_case.respond(&options, path).unwrap_or_else(|e| {
+            eprintln!("Error [{}]: {e}", e.code());
+            http::Response::error(format!("Error [{}]: {e}", e.code()))
+        })
+    });
+    Ok(0)
+}
+
+#[cfg(feature = "tui")]
+fn handle_tui(args: crate::cli::TuiArgs, config: Config, verbose: bool) -> Result<i32> {
+    let fs = RealFileSystem::new();
+    let use_case = BrowseUseCase::new(fs);
+
+    let defaults = BrowseOptions::default();
+    let (input, inputs) = input_dirs(args.input, config.input.clone(), defaults.input_dir);
+    let source = source_options(&config, args.source, inputs);
+    let filter = adr_filter(&config, args.filter)?;
+    let options = BrowseOptions::new(input)
+        .with_pattern(args.pattern.or(config.pattern).unwrap_or(defaults.pattern))
+        .with_source(source);
+
+    if verbose {
+        eprintln!(
+            "Loading ADRs from: {}",
+            source_dir(&options.input_dir, &options.source)
+        );
+    }
+
+    let result = use_case.execute(&options)?;
+
+    crate::cli::tui::run(result.adrs, filter)?;
+
+    // Reported after the browser closes, as the alternate screen hides them
+    report_warnings(&result.parse_errors, &result.warnings);
+
+    Ok(0)
+}
+
+fn handle_new(args: NewArgs, config: Config, verbose: bool) -> Result<i32> {
+    let fs = RealFileSystem::new();
+    let use_case = NewUseCase::new(fs.clone());
+
+    let defaults = NewOptions::default();
+    let mut options = NewOptions::new(
+        args.input
+            .or_else(|| config.input.clone())
+            .unwrap_or(defaults.input_dir),
+        args.title.unwrap_or_default(),
+    )
+    .with_pattern(
+        args.pattern
+            .or_else(|| config.pattern.clone())
+            .unwrap_or(defaults.pattern),
+    )
+    .with_description(args.description.unwrap_or_default())
+    .with_category(args.category.unwrap_or_default())
+    .with_tags(args.tags)
+    .with_related(args.related)
+    .with_deciders(args.deciders)
+    .with_status(match args.status {
+        Some(status) => status_name(&config, &status)?,
+        None => defaults.status,
+    });
+
+    if args.interactive {
+        // Existing ADRs only feed completion, so an empty directory is fine
+        let existing = BrowseUseCase::new(fs)
+            .execute(
+                &BrowseOptions::new(&options.input_dir)
+                    .with_pattern(&options.pattern)
+                    .with_source(source_options(&config, SourceArgs::default(), Vec::new())),
+            )
+            .map_or_else(|_| Vec::new(), |result| result.adrs);
+        options = Wizard::new(io::stdin().lock(), io::stderr(), &existing)
+            .run(options)
+            .map_err(|source| Error::Terminal { source })?;
+    }
+
+    if verbose {
+        eprintln!("Creating ADR in: {}", options.input_dir);
+    }
+
+    let result = use_case.execute(&options)?;
+
+    println!("Created {} ({})", result.path.display(), result.id);
+
+    Ok(0)
+}
+
+fn handle_init(args: InitArgs, config: Config) -> Result<i32> {
+    let fs = RealFileSystem::new();
+    let use_case = InitUseCase::new(fs);
+
+    let defaults = InitOptions::default();
+    let options = InitOptions::new(args.input.or(config.input).unwrap_or(defaults.input_dir))
+        .with_force(args.force);
+    let result = use_case.execute(&options)?;
 
-    let options = GenerateOptions::new(&args.input)
-        .with_output(&args.output)
-        .with_title(&args.title)
-        .with_theme(args.theme.into())
-        .with_pattern(&args.pattern);
+    for path in &result.created {
+        println!("Created {}", path.display());
+    }
+    for path in &result.skipped {
+        println!(
+            "Skipped {} (already exists; use --force to overwrite)",
+            path.display()
+        );
+    }
+    if !result.created.is_empty() {
+        println!();
+        println!("Record the next decision with: adrscope new \"<title>\"");
+    }
+
+    Ok(0)
+}
+
+fn handle_transition(
+    args: TransitionArgs,
+    status: Status,
+    config: Config,
+    verbose: bool,
+) -> Result<i32> {
+    let fs = RealFileSystem::new();
+    let use_case = TransitionUseCase::new(fs);
+
+    let defaults = TransitionOptions::default();
+    let (input, inputs) = input_dirs(args.input, config.input.clone(), defaults.input_dir);
+    let source = source_options(&config, args.source, inputs);
+    let mut options = TransitionOptions::new(input, args.id, status)
+        .with_pattern(args.pattern.or(config.pattern).unwrap_or(defaults.pattern))
+        .with_source(source)
+        .with_history(args.history);
+    if let Some(reason) = args.reason {
+        options = options.with_reason(reason);
+    }
 
     if verbose {
-        eprintln!("Scanning for ADRs in: {}", args.input);
+        eprintln!(
+            "Looking up {} in: {}",
+            options.id,
+            source_dir(&options.input_dir, &options.source)
+        );
     }
 
     let result = use_case.execute(&options)?;
 
-    // Report parse errors
+    if result.changed() {
+        println!(
+            "{}: {} -> {} ({})",
+            result.id,
+            result.from,
+            result.to,
+            result.path.display()
+        );
+    } else {
+        println!("{} is already {}", result.id, result.to);
+    }
+
+    Ok(0)
+}
+
+fn handle_edit(args: EditArgs, config: Config, verbose: bool) -> Result<i32> {
+    let fs = RealFileSystem::new();
+    let use_case = EditUseCase::new(fs);
+
+    let changes = args.changes();
+    let defaults = EditOptions::default();
+    let (input, inputs) = input_dirs(args.input, config.input.clone(), defaults.input_dir);
+    let source = source_options(&config, args.source, inputs);
+    let mut options = EditOptions::new(input)
+        .with_pattern(args.pattern.or(config.pattern).unwrap_or(defaults.pattern))
+        .with_source(source)
+        .with_dry_run(args.dry_run);
+    for change in changes {
+        options = options.with_change(change);
+    }
+    for (field, value) in args.conditions {
+        options = options.with_condition(field, value);
+    }
+
+    if verbose {
+        eprintln!(
+            "Editing ADRs in: {}",
+            source_dir(&options.input_dir, &options.source)
+        );
+    }
+
+    let result = use_case.execute(&options)?;
+
+    report_warnings(&result.parse_errors, &[]);
     if result.has_errors() {
-        eprintln!("\nWarnings:");
-        for (path, error) in &result.parse_errors {
-            eprintln!("  {} - {}", path.display(), error);
+        eprintln!();
+    }
+
+    for edit in &result.edits {
+        println!("{}", edit.path.display());
+        for change in &edit.changes {
+            println!("  {}: {} -> {}", change.field, change.before, change.after);
         }
     }
 
+    let verb = if result.dry_run {
+        "Would update"
+    } else {
+        "Updated"
+    };
     println!(
-        "Generated {} with {} ADRs",
-        result.output_path, result.adr_count
+        "{verb} {} of {} matching ADRs",
+        result.edits.len(),
+        result.matched
     );
 
     Ok(0)
 }
 
-fn handle_wiki(args: WikiArgs, verbose: bool) -> Result<i32> {
+fn handle_rename(args: RenameArgs, config: Config, verbose: bool) -> Result<i32> {
+    let fs = RealFileSystem::new();
+    let use_case = RenameUseCase::new(fs);
+
+    let defaults = RenameOptions::default();
+    let (input, inputs) = input_dirs(args.input, config.input.clone(), defaults.input_dir);
+    let source = source_options(&config, args.source, inputs);
+    let mut options = RenameOptions::new(input, args.id)
+        .with_pattern(args.pattern.or(config.pattern).unwrap_or(defaults.pattern))
+        .with_source(source)
+        .with_dry_run(args.dry_run);
+    if let Some(new_name) = args.new_name {
+        options = options.with_new_name(new_name);
+    }
+    if let Some(title) = args.title {
+        options = options.with_title(title);
+    }
+
+    if verbose {
+        eprintln!(
+            "Looking up {} in: {}",
+            options.id,
+            source_dir(&options.input_dir, &options.source)
+        );
+    }
+
+    let result = use_case.execute(&options)?;
+
+    println!(
+        "{} -> {} ({} -> {})",
+        result.old_id,
+        result.new_id,
+        result.old_path.display(),
+        result.new_path.display()
+    );
+    for path in &result.updated {
+        println!("  updated references in {}", path.display());
+    }
+    if result.dry_run {
+        println!("Dry run: no files were changed");
+    }
+
+    Ok(0)
+}
+
+fn handle_link(args: LinkArgs, config: Config, verbose: bool) -> Result<i32> {
+    let fs = RealFileSystem::new();
+    let use_case = LinkUseCase::new(fs);
+
+    let defaults = LinkOptions::default();
+    let (input, inputs) = input_dirs(args.input, config.input.clone(), defaults.input_dir);
+    let source = source_options(&config, args.source, inputs);
+    let options = LinkOptions::new(input, args.from, args.to, args.link_type.into())
+        .with_pattern(args.pattern.or(config.pattern).unwrap_or(defaults.pattern))
+        .with_source(source);
+
+    if verbose {
+        eprintln!(
+            "Looking up {} and {} in: {}",
+            options.from,
+            options.to,
+            source_dir(&options.input_dir, &options.source)
+        );
+    }
+
+    let result = use_case.execute(&options)?;
+
+    if result.changes.is_empty() {
+        println!(
+            "{} and {} are already linked ({})",
+            result.from,
+            result.to,
+            result.link_type.as_str()
+        );
+    }
+    for change in &result.changes {
+        let operator = if change.field == "status" { "=" } else { "+=" };
+        println!(
+            "{}: {} {operator} {}",
+            change.path.display(),
+            change.field,
+            change.value
+        );
+    }
+
+    Ok(0)
+}
+
+fn handle_wiki(args: WikiArgs, config: Config, verbose: bool) -> Result<i32> {
     let fs = RealFileSystem::new();
     let use_case = WikiUseCase::new(fs);
 
-    let mut options = WikiOptions::new(&args.input)
-        .with_output_dir(&args.output)
-        .with_pattern(&args.pattern);
+    let defaults = WikiOptions::default();
+    let (input, inputs) = input_dirs(args.input, config.input.clone(), defaults.input_dir);
+    let source = source_options(&config, args.source, inputs);
+    let filter = adr_filter(&config, args.filter)?;
+    let mut options = WikiOptions::new(input)
+        .with_output_dir(
+            args.output
+                .or(config.wiki.output)
+                .unwrap_or(defaults.output_dir),
+        )
+        .with_pattern(args.pattern.or(config.pattern).unwrap_or(defaults.pattern))
+        .with_source(source)
+        .with_filter(filter)
+        .with_limit(if args.full {
+            None
+        } else {
+            args.top.or(defaults.limit)
+        });
 
-    if let Some(url) = &args.pages_url {
+    if let Some(url) = args.pages_url.or(config.wiki.pages_url) {
         options = options.with_pages_url(url);
     }
 
     if verbose {
-        eprintln!("Scanning for ADRs in: {}", args.input);
+        eprintln!(
+            "Scanning for ADRs in: {}",
+            source_dir(&options.input_dir, &options.source)
+        );
     }
 
     let result = use_case.execute(&options)?;
 
-    // Report parse errors
-    if result.has_errors() {
-        eprintln!("\nWarnings:");
-        for (path, error) in &result.parse_errors {
-            eprintln!("  {} - {}", path.display(), error);
-        }
-    }
+    report_warnings(&result.parse_errors, &result.warnings);
 
     println!(
         "Generated {} wiki files in {} from {} ADRs",
@@ -100,26 +670,103 @@ fn handle_wiki(args: WikiArgs, verbose: bool) -> Result<i32> {
     Ok(0)
 }
 
-fn handle_validate(args: ValidateArgs, verbose: bool) -> Result<i32> {
+fn handle_validate(args: ValidateArgs, config: Config, verbose: bool) -> Result<i32> {
     let fs = RealFileSystem::new();
     let use_case = ValidateUseCase::new(fs);
 
-    let options = ValidateOptions::new(&args.input)
-        .with_pattern(&args.pattern)
-        .with_strict(args.strict);
+    let mut files = args.files;
+    if args.changed_only {
+        files.extend(
+            io::stdin()
+                .lines()
+                .map_while(std::result::Result::ok)
+                .map(|line| line.trim().to_string())
+                .filter(|line| !line.is_empty()),
+        );
+        if files.is_empty() {
+            println!("No changed files to validate.");
+            return Ok(0);
+        }
+    }
+
+    let defaults = ValidateOptions::default();
+    let (input, inputs) = input_dirs(args.input, config.input.clone(), defaults.input_dir);
+    let pattern = args
+        .pattern
+        .or_else(|| config.pattern.clone())
+        .unwrap_or(defaults.pattern);
+    let source = source_options(&config, args.source, inputs);
+    let mut options = validate_options(&config, &input, &pattern, &source)
+        .with_strict(args.strict.unwrap_or(config.validate.strict))
+        .with_files(files)
+        .with_rules(
+            RuleConfig::new()
+                .with_levels(config.validate.rules)
+                .with_only(args.only)
+                .with_skip(args.skip),
+        );
+    if let Some(pattern) = args.filename_pattern {
+        options = options.with_filename_pattern(pattern);
+    }
+    if let Some(path) = args.schema {
+        options = options.with_schema(path);
+    }
+    if let Some(days) = args.max_proposed_age {
+        options = options.with_max_proposed_age(days);
+    }
+    let options = args
+        .report_file
+        .into_iter()
+        .fold(options, |options, (format, path)| {
+            options.with_report(format, path)
+        });
 
     if verbose {
-        eprintln!("Validating ADRs in: {}", args.input);
+        eprintln!(
+            "Validating ADRs in: {}",
+            source_dir(&options.input_dir, &options.source)
+        );
     }
 
     let result = use_case.execute(&options)?;
 
-    // Report parse errors
+    let format = ValidateFormat::from(args.format);
+    if format == ValidateFormat::Text {
+        report_validation(&result);
+
+        // Summary
+        println!("\nValidation complete: {}", result.totals());
+    } else {
+        print!("{}", result.render(format));
+    }
+
+    if verbose {
+        for (format, path) in &options.reports {
+            eprintln!("Wrote {} report to {path}", format.as_str());
+        }
+    }
+    write_step_summary(&result.render(ValidateFormat::Markdown), verbose)?;
+
+    let text = format == ValidateFormat::Text;
+    if result.passed {
+        if text {
+            println!("All checks passed.");
+        }
+        Ok(0)
+    } else {
+        if text {
+            println!("Validation failed.");
+        }
+        Ok(1)
+    }
+}
+
+/// Prints the parse errors and validation issues of a validation run.
+fn report_validation(result: &ValidateResult) {
     for (path, error) in &result.parse_errors {
-        eprintln!("ERROR: {} - {}", path.display(), error);
+        report_parse_error("ERROR: ", path, error);
     }
 
-    // Report validation issues
     let mut stdout = io::stdout();
     for (path, issue) in result.all_issues() {
         let prefix = match issue.severity {
@@ -132,18 +779,63 @@ fn handle_validate(args: ValidateArgs, verbose: bool) -> Result<i32> {
             prefix,
             path.display(),
             issue.message,
-            issue.rule
+            issue.label()
         );
     }
+}
 
-    // Summary
-    println!(
-        "\nValidation complete: {} errors, {} warnings",
-        result.total_errors, result.total_warnings
-    );
+fn handle_build(args: BuildArgs, config: Config, verbose: bool) -> Result<i32> {
+    let fs = RealFileSystem::new();
+    let use_case = BuildUseCase::new(fs);
 
-    if result.passed {
-        println!("All checks passed.");
+    let notify_url = config.generate.notify_url.clone();
+    let options = build_options(&args, &config)?;
+
+    if verbose {
+        eprintln!(
+            "Building from ADRs in: {}",
+            source_dir(&options.input_dir, &options.source)
+        );
+    }
+
+    let result = use_case.execute(&options)?;
+
+    match &result.validate {
+        Some(validate) => report_validation(validate),
+        None => report_warnings(&result.parse_errors, &result.warnings),
+    }
+
+    println!("\nBuilt {} ADRs:", result.adr_count);
+    for (step, outputs) in &result.outputs {
+        let summary = match (step, &result.validate) {
+            (BuildStep::Validate, Some(validate)) if outputs.is_empty() => validate.totals(),
+            _ => describe_outputs(outputs),
+        };
+        println!("  {}: {summary}", step.as_str());
+        if verbose && outputs.len() > 1 {
+            for output in outputs {
+                eprintln!("    {} ({})", output.path.display(), output.status.as_str());
+            }
+        }
+    }
+
+    let mut summary = String::new();
+    if let Some(validate) = &result.validate {
+        summary.push_str(&validate.render(ValidateFormat::Markdown));
+    }
+    if let Some(generate) = &result.generate {
+        summary.push_str(&generate.markdown());
+    }
+    if !summary.is_empty() {
+        write_step_summary(&summary, verbose)?;
+    }
+    if let Some(generate) = &result.generate
+        && viewer_changed(&result)
+    {
+        notify(notify_url.as_deref(), generate, verbose)?;
+    }
+
+    if result.passed() {
         Ok(0)
     } else {
         println!("Validation failed.");
@@ -151,34 +843,539 @@ fn handle_validate(args: ValidateArgs, verbose: bool) -> Result<i32> {
     }
 }
 
-fn handle_stats(args: StatsArgs, verbose: bool) -> Result<i32> {
+/// Resolves build options from flags and configuration.
+fn build_options(args: &BuildArgs, config: &Config) -> Result<BuildOptions> {
+    let defaults = BuildOptions::default();
+    let (input, inputs) = input_dirs(args.input.clone(), config.input.clone(), defaults.input_dir);
+    let pattern = args
+        .pattern
+        .clone()
+        .or_else(|| config.pattern.clone())
+        .unwrap_or(defaults.pattern);
+    let source = source_options(config, args.source.clone(), inputs);
+    let steps = if args.steps.is_empty() {
+        config
+            .build
+            .steps
+            .clone()
+            .unwrap_or_else(|| BuildStep::DEFAULT.to_vec())
+    } else {
+        args.steps.iter().copied().map(Into::into).collect()
+    };
+
+    let mut options = BuildOptions::new(&input)
+        .with_pattern(&pattern)
+        .with_source(source.clone());
+    for step in steps {
+        options = match step {
+            BuildStep::Validate => options.with_validate(
+                validate_options(config, &input, &pattern, &source)
+                    .with_strict(args.strict.unwrap_or(config.validate.strict)),
+            ),
+            // Reproducible output leaves the viewer untouched when no ADR changed
+            BuildStep::Generate => options.with_generate(
+                generate_options(
+                    GenerateArgs {
+                        input: args.input.clone(),
+                        pattern: Some(pattern.clone()),
+                        source: args.source.clone(),
+                        ..GenerateArgs::default()
+                    },
+                    config.clone(),
+                )?
+                .with_reproducible(true),
+            ),
+            BuildStep::Wiki => options.with_wiki(WikiOptions {
+                pages_url: config.wiki.pages_url.clone(),
+                ..WikiOptions::new(&input)
+                    .with_output_dir(
+                        config
+                            .wiki
+                            .output
+                            .clone()
+                            .unwrap_or_else(|| WikiOptions::default().output_dir),
+                    )
+                    .with_pattern(&pattern)
+                    .with_source(source.clone())
+            }),
+            BuildStep::Stats => {
+                let path = args
+                    .stats_output
+                    .clone()
+                    .or_else(|| config.build.stats_output.clone())
+                    .unwrap_or_else(|| DEFAULT_STATS_OUTPUT.to_string());
+                options.with_stats(StatsFormat::from_path(&path), path)
+            },
+        };
+    }
+    Ok(options)
+}
+
+/// Stats file written by `build` when none is configured.
+const DEFAULT_STATS_OUTPUT: &str = "adrs-stats.md";
+
+/// Summarizes the files written by a build step.
+fn describe_outputs(outputs: &[BuildOutput]) -> String {
+    match outputs {
+        [] => "no files written".to_string(),
+        [output] => format!("{} ({})", output.path.display(), output.status.as_str()),
+        _ => {
+            let count = |status| {
+                outputs
+                    .iter()
+                    .filter(|output| output.status == status)
+                    .count()
+            };
+            format!(
+                "{} files ({} created, {} changed, {} unchanged)",
+                outputs.len(),
+                count(OutputStatus::Created),
+                count(OutputStatus::Changed),
+                count(OutputStatus::Unchanged)
+            )
+        },
+    }
+}
+
+/// Returns true if the generate step wrote a new or different viewer.
+fn viewer_changed(result: &BuildResult) -> bool {
+    result
+        .outputs
+        .iter()
+        .filter(|(step, _)| *step == BuildStep::Generate)
+        .any(|(_, outputs)| {
+            BuildResult::step_status(outputs)
+                .is_some_and(|status| status != OutputStatus::Unchanged)
+        })
+}
+
+fn handle_stats(args: StatsArgs, config: Config, verbose: bool) -> Result<i32> {
     let fs = RealFileSystem::new();
     let use_case = StatsUseCase::new(fs);
 
-    let options = StatsOptions::new(&args.input)
-        .with_pattern(&args.pattern)
-        .with_format(args.format.into());
+    let defaults = StatsOptions::default();
+    let (input, inputs) = input_dirs(args.input, config.input.clone(), defaults.input_dir);
+    let source = source_options(&config, args.source, inputs);
+    let options = StatsOptions::new(input)
+        .with_pattern(args.pattern.or(config.pattern).unwrap_or(defaults.pattern))
+        .with_format(args.format.into())
+        .with_source(source)
+        .with_limit(if args.full {
+            None
+        } else {
+            args.top.or(defaults.limit)
+        });
 
     if verbose {
-        eprintln!("Computing statistics for ADRs in: {}", args.input);
+        eprintln!(
+            "Computing statistics for ADRs in: {}",
+            source_dir(&options.input_dir, &options.source)
+        );
     }
 
     let result = use_case.execute(&options)?;
 
-    // Report parse errors
-    if result.has_errors() {
-        eprintln!("\nWarnings:");
-        for (path, error) in &result.parse_errors {
-            eprintln!("  {} - {}", path.display(), error);
-        }
+    report_warnings(&result.parse_errors, &result.warnings);
+    if result.has_errors() || !result.warnings.is_empty() {
         eprintln!();
     }
 
     println!("{}", result.output);
+    write_step_summary(&result.markdown(), verbose)?;
 
     Ok(0)
 }
 
+fn handle_graph(args: GraphArgs, config: Config, verbose: bool) -> Result<i32> {
+    let fs = RealFileSystem::new();
+    let use_case = GraphUseCase::new(fs);
+
+    let defaults = GraphOptions::default();
+    let (input, inputs) = input_dirs(args.input, config.input.clone(), defaults.input_dir);
+    let source = source_options(&config, args.source, inputs);
+    let format = args.format.map_or_else(
+        || {
+            args.output
+                .as_deref()
+                .map_or(defaults.format, GraphFormat::from_path)
+        },
+        Into::into,
+    );
+    let mut options = GraphOptions::new(input)
+        .with_pattern(args.pattern.or(config.pattern).unwrap_or(defaults.pattern))
+        .with_format(format)
+        .with_source(source);
+    if let Some(output) = args.output {
+        options = options.with_output(output);
+    }
+    if let Some(group_by) = args.group_by {
+        options = options.with_grouping(group_by.into());
+    }
+
+    if verbose {
+        eprintln!(
+            "Building the decision graph of ADRs in: {}",
+            source_dir(&options.input_dir, &options.source)
+        );
+    }
+
+    let result = use_case.execute(&options)?;
+
+    report_warnings(&result.parse_errors, &result.warnings);
+    if result.has_errors() || !result.warnings.is_empty() {
+        eprintln!();
+    }
+
+    match &options.output {
+        Some(path) => println!(
+            "Wrote graph of {} ADRs and {} relationships to {path}",
+            result.graph.node_count(),
+            result.graph.edge_count()
+        ),
+        None => print!("{}", result.output),
+    }
+
+    Ok(0)
+}
+
+fn handle_export(args: ExportArgs, config: Config, verbose: bool) -> Result<i32> {
+    let fs = RealFileSystem::new();
+    let use_case = ExportUseCase::new(fs);
+
+    let format = args.format.map_or_else(
+        || {
+            args.output
+                .as_deref()
+                .map_or_else(ExportFormat::default, ExportFormat::from_path)
+        },
+        Into::into,
+    );
+    // The data is built as for the viewer, with the same configuration
+    let generate = generate_options(
+        GenerateArgs {
+            input: args.input,
+            pattern: args.pattern,
+            source: args.source,
+            filter: args.filter,
+            reproducible: args.reproducible,
+            embed_markdown: args.embed_markdown,
+            embed_fields: args.embed_fields,
+            omit_fields: args.omit_fields,
+            ..GenerateArgs::default()
+        },
+        config,
+    )?;
+    let mut options = ExportOptions::new(generate).with_format(format);
+    if let Some(output) = args.output {
+        options = options.with_output(output);
+    }
+
+    if verbose {
+        eprintln!(
+            "Exporting ADRs from: {}",
+            source_dir(&options.generate.input_dir, &options.generate.source)
+        );
+    }
+
+    // JSON Lines go to stdout as each ADR is written
+    let result = if options.format == ExportFormat::Ndjson && options.output.is_none() {
+        use_case.stream(&options, &mut io::stdout().lock())?
+    } else {
+        use_case.execute(&options)?
+    };
+
+    report_warnings(&result.parse_errors, &result.warnings);
+    if result.has_errors() || !result.warnings.is_empty() {
+        eprintln!();
+    }
+
+    match (&options.output, &result.output) {
+        (Some(path), _) => println!("Exported {} ADRs to {path}", result.adr_count),
+        (None, Some(output)) => println!("{output}"),
+        (None, None) => {},
+    }
+
+    Ok(0)
+}
+
+fn handle_list(args: ListArgs, config: Config, verbose: bool) -> Result<i32> {
+    let fs = RealFileSystem::new();
+    let use_case = ListUseCase::new(fs);
+
+    let defaults = ListOptions::default();
+    let (input, inputs) = input_dirs(args.input, config.input.clone(), defaults.input_dir);
+    let source = source_options(&config, args.source, inputs);
+    let filter = adr_filter(&config, args.filter)?;
+    let mut options = ListOptions::new(input)
+        .with_pattern(args.pattern.or(config.pattern).unwrap_or(defaults.pattern))
+        .with_source(source)
+        .with_filter(filter)
+        .with_format(args.format.into());
+    if let Some(query) = args.query {
+        options = options.with_query(SearchQuery::parse(&query));
+    }
+
+    if verbose {
+        eprintln!(
+            "Listing ADRs in: {}",
+            source_dir(&options.input_dir, &options.source)
+        );
+    }
+
+    let result = use_case.execute(&options)?;
+
+    report_warnings(&result.parse_errors, &result.warnings);
+    if !result.parse_errors.is_empty() || !result.warnings.is_empty() {
+        eprintln!();
+    }
+    println!("{}", result.output);
+
+    Ok(0)
+}
+
+fn handle_review(args: ReviewArgs, config: Config, verbose: bool) -> Result<i32> {
+    let fs = RealFileSystem::new();
+    let use_case = ReviewUseCase::new(fs);
+
+    let defaults = ReviewOptions::default();
+    let (input, inputs) = input_dirs(args.input, config.input.clone(), defaults.input_dir);
+    let source = source_options(&config, args.source, inputs);
+    let mut options = ReviewOptions::new(input)
+        .with_pattern(args.pattern.or(config.pattern).unwrap_or(defaults.pattern))
+        .with_source(source);
+    if let Some(date) = args.date {
+        options = options.with_date(date);
+    }
+
+    if verbose {
+        eprintln!(
+            "Checking review dates of ADRs in: {}",
+            source_dir(&options.input_dir, &options.source)
+        );
+    }
+
+    let result = use_case.execute(&options)?;
+    report_review(&result);
+
+    Ok(0)
+}
+
+fn handle_remind(args: RemindArgs, config: Config, verbose: bool) -> Result<i32> {
+    let fs = RealFileSystem::new();
+    let use_case = ReviewUseCase::new(fs);
+
+    let defaults = ReviewOptions::default();
+    let (input, inputs) = input_dirs(args.input, config.input.clone(), defaults.input_dir);
+    let source = source_options(&config, args.source, inputs);
+    let mut options = ReviewOptions::new(input)
+        .with_pattern(args.pattern.or(config.pattern).unwrap_or(defaults.pattern))
+        .with_source(source)
+        .with_due_within(args.due_within)
+        .with_format(args.format.into());
+    if let Some(date) = args.date {
+        options = options.with_date(date);
+    }
+
+    if verbose {
+        eprintln!(
+            "Checking reviews due within {} days for ADRs in: {}",
+            options.due_within,
+            source_dir(&options.input_dir, &options.source)
+        );
+    }
+
+    let result = use_case.execute(&options)?;
+    report_review(&result);
+
+    Ok(0)
+}
+
+/// Prints a review report, after any problems found while loading.
+fn report_review(result: &ReviewResult) {
+    report_warnings(&result.parse_errors, &result.warnings);
+    if !result.parse_errors.is_empty() || !result.warnings.is_empty() {
+        eprintln!();
+    }
+
+    // An empty GitHub issue body stays empty, so scripts can test for it
+    if !result.output.is_empty() {
+        println!("{}", result.output.trim_end());
+    }
+}
+
+fn handle_verify(args: VerifyArgs, config: Config, verbose: bool) -> Result<i32> {
+    let fs = RealFileSystem::new();
+    let use_case = VerifyUseCase::new(fs);
+
+    let defaults = VerifyOptions::default();
+    let options = VerifyOptions::new(
+        args.file
+            .or(config.generate.output)
+            .unwrap_or(defaults.path),
+    );
+
+    if verbose {
+        eprintln!("Verifying viewer data in: {}", options.path);
+    }
+
+    let result = use_case.execute(&options)?;
+    let check = &result.check;
+
+    for error in &check.errors {
+        println!("ERROR: {} - {error}", result.path);
+    }
+    for warning in &check.warnings {
+        println!("WARNING: {} - {warning}", result.path);
+    }
+
+    println!(
+        "\nSchema version {} ({} records), expected {SCHEMA_VERSION}",
+        check.schema_version.as_deref().unwrap_or("unknown"),
+        check.record_count
+    );
+
+    if result.passed() {
+        println!("Viewer data is compatible.");
+        Ok(0)
+    } else {
+        println!("Viewer data is incompatible.");
+        Ok(1)
+    }
+}
+
+fn handle_explain(args: ExplainArgs) -> i32 {
+    let Some(code) = args.code else {
+        for info in codes::CODES {
+            println!("{}  {}", info.code, info.summary);
+        }
+        return 0;
+    };
+
+    let Some(info) = codes::lookup(&code) else {
+        eprintln!("Unknown code: {code}. Run `adrscope explain` to list all codes.");
+        return 1;
+    };
+
+    println!("{}: {}\n\n{}", info.code, info.summary, info.explanation);
+
+    0
+}
+
+/// Prints files that failed to parse and problems found while loading ADRs.
+fn report_warnings(parse_errors: &[(PathBuf, Error)], warnings: &[ValidationIssue]) {
+    if parse_errors.is_empty() && warnings.is_empty() {
+        return;
+    }
+
+    eprintln!("\nWarnings:");
+    for (path, error) in parse_errors {
+        report_parse_error("  ", path, error);
+    }
+    for issue in warnings {
+        eprintln!(
+            "  {} - {} [{}]",
+            issue.path.display(),
+            issue.message,
+            issue.label()
+        );
+    }
+}
+
+/// Posts the generation summary to the configured webhook, if any.
+fn notify(url: Option<&str>, result: &GenerateResult, verbose: bool) -> Result<()> {
+    let Some(url) = url else {
+        return Ok(());
+    };
+    webhook::post_json(url, &result.notification())?;
+    if verbose {
+        eprintln!("Notified {url}");
+    }
+    Ok(())
+}
+
+/// Appends a summary to the GitHub Actions step summary when running in a
+/// workflow.
+fn write_step_summary(markdown: &str, verbose: bool) -> Result<()> {
+    if let Some(path) = ci::append_step_summary(markdown)?
+        && verbose
+    {
+        eprintln!("Wrote step summary to {}", path.display());
+    }
+    Ok(())
+}
+
+/// Prints a file that failed to parse, with a source snippet when available.
+fn report_parse_error(prefix: &str, path: &Path, error: &Error) {
+    eprintln!("{prefix}{} - {} [{}]", path.display(), error, error.code());
+    if let Some(snippet) = error.snippet() {
+        eprintln!("{snippet}");
+    }
+}
+
+/// Splits the input directories given as flags, or else the configured or
+/// default one, into the options' input directory and those merged with it.
+fn input_dirs(args: Vec<String>, config: Option<String>, default: String) -> (String, Vec<String>) {
+    let mut dirs = args.into_iter();
+    let input = dirs.next().or(config).unwrap_or(default);
+    (input, dirs.collect())
+}
+
+/// Builds the ADR source options from the command's source arguments, the
+/// input directories merged with the main one, and the configuration.
+pub(super) fn source_options(
+    config: &Config,
+    source: SourceArgs,
+    inputs: Vec<String>,
+) -> SourceOptions {
+    SourceOptions::from(source)
+        .with_inputs(inputs)
+        .with_status_synonyms(config.status_synonyms.clone())
+        .with_custom_statuses(config.statuses.clone())
+        .with_teams(config.team_mapping())
+        .with_exclude(config.exclude.clone())
+}
+
+/// Resolves a status given on the command line to the name ADRs carry it
+/// under: a built-in or custom status, or the status a synonym stands for.
+fn status_name(config: &Config, status: &str) -> Result<String> {
+    let known = status_names(&config.statuses);
+    if let Some(name) = known.iter().find(|name| name.eq_ignore_ascii_case(status)) {
+        return Ok((*name).to_string());
+    }
+    config
+        .status_synonyms
+        .iter()
+        .find(|(synonym, _)| synonym.eq_ignore_ascii_case(status))
+        .map(|(_, status)| status.as_str().to_string())
+        .ok_or_else(|| Error::UnknownStatus {
+            status: status.to_string(),
+            known: known.into_iter().map(str::to_string).collect(),
+        })
+}
+
+/// Builds the ADR filter from the command's filter arguments, resolving the
+/// statuses against the built-in and configured ones.
+fn adr_filter(config: &Config, mut args: FilterArgs) -> Result<AdrFilter> {
+    let statuses = std::mem::take(&mut args.status)
+        .iter()
+        .map(|status| status_name(config, status))
+        .collect::<Result<_>>()?;
+    Ok(AdrFilter::from(args).with_statuses(statuses))
+}
+
+/// Describes where ADRs are read from, for verbose output.
+fn source_dir(input_dir: &str, source: &SourceOptions) -> String {
+    source.discover_root.as_ref().map_or_else(
+        || {
+            std::iter::once(input_dir)
+                .chain(source.inputs.iter().map(String::as_str))
+                .collect::<Vec<_>>()
+                .join(", ")
+        },
+        |root| format!("{root} (discovering {})", source.conventions.join(", ")),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -191,9 +1388,11 @@ mod tests {
     fn test_handler_functions_exist() {
         // Verify that all handler functions are properly defined
         // by checking they can be referenced
-        let _: fn(GenerateArgs, bool) -> Result<i32> = handle_generate;
-        let _: fn(WikiArgs, bool) -> Result<i32> = handle_wiki;
-        let _: fn(ValidateArgs, bool) -> Result<i32> = handle_validate;
-        let _: fn(StatsArgs, bool) -> Result<i32> = handle_stats;
+        let _: fn(GenerateArgs, Config, bool) -> Result<i32> = handle_generate;
+        let _: fn(WikiArgs, Config, bool) -> Result<i32> = handle_wiki;
+        let _: fn(ValidateArgs, Config, bool) -> Result<i32> = handle_validate;
+        let _: fn(StatsArgs, Config, bool) -> Result<i32> = handle_stats;
+        let _: fn(VerifyArgs, Config, bool) -> Result<i32> = handle_verify;
+        let _: fn(ExplainArgs) -> i32 = handle_explain;
     }
 }
@@ -3,9 +3,19 @@
 //! This module handles argument parsing and command dispatch using clap.
 
 mod args;
+mod complete;
 mod handlers;
+#[cfg(feature = "tui")]
+mod tui;
+mod wizard;
 
+#[cfg(feature = "tui")]
+pub use args::TuiArgs;
 pub use args::{
-    Cli, Commands, FormatArg, GenerateArgs, StatsArgs, ThemeArg, ValidateArgs, WikiArgs,
+    Cli, Commands, EditArgs, ExplainArgs, ExportArgs, ExportFormatArg, FilterArgs, FormatArg,
+    GenerateArgs, GraphArgs, GraphFormatArg, GraphGroupArg, InitArgs, LinkArgs, LinkTypeArg,
+    ListArgs, ListFormatArg, NewArgs, OpenArgs, RecordFieldArg, RenameArgs, ReviewArgs, ServeArgs,
+    SourceArgs, StatsArgs, ThemeArg, TransitionArgs, ValidateArgs, ValidateFormatArg, VerifyArgs,
+    WikiArgs,
 };
 pub use handlers::run;
@@ -0,0 +1,408 @@
+//! State of the terminal browser and its key bindings.
+
+use std::collections::BTreeSet;
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::domain::{Adr, AdrFilter, status_names};
+
+/// Lines the detail pane scrolls per page.
+const PAGE: u16 = 10;
+
+/// Whether keys navigate or edit the search query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Keys move the selection and toggle filters.
+    Normal,
+    /// Keys are typed into the search query.
+    Search,
+}
+
+/// State of the terminal browser.
+#[derive(Debug)]
+pub struct App {
+    adrs: Vec<Adr>,
+    filter: AdrFilter,
+    query: String,
+    mode: Mode,
+    /// Indices into `adrs` of the ADRs matching the filter and query.
+    visible: Vec<usize>,
+    /// Index into `visible` of the selected ADR.
+    selected: usize,
+    scroll: u16,
+    quit: bool,
+    statuses: Vec<String>,
+    tags: Vec<String>,
+    categories: Vec<String>,
+}
+
+impl App {
+    /// Creates the browser state, initially showing the ADRs matching `filter`.
+    #[must_use]
+    pub fn new(adrs: Vec<Adr>, filter: AdrFilter) -> Self {
+        let statuses = status_names(adrs.iter().filter_map(Adr::custom_status))
+            .into_iter()
+            .filter(|name| adrs.iter().any(|adr| adr.status_name() == *name))
+            .map(str::to_string)
+            .collect();
+        let tags = adrs
+            .iter()
+            .flat_map(|adr| adr.tags().iter().cloned())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let categories = adrs
+            .iter()
+            .map(|adr| adr.category().to_string())
+            .filter(|category| !category.is_empty())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        let mut app = Self {
+            adrs,
+            filter,
+            query: String::new(),
+            mode: Mode::Normal,
+            visible: Vec::new(),
+            selected: 0,
+            scroll: 0,
+            quit: false,
+            statuses,
+            tags,
+            categories,
+        };
+        app.refresh();
+        app
+    }
+
+    /// Returns the ADRs matching the filter and search query.
+    pub fn visible(&self) -> impl Iterator<Item = &Adr> {
+        self.visible.iter().map(|&index| &self.adrs[index])
+    }
+
+    /// Returns the number of ADRs matching the filter and search query.
+    #[must_use]
+    pub fn visible_count(&self) -> usize {
+        self.visible.len()
+    }
+
+    /// Returns the total number of ADRs.
+    #[must_use]
+    pub fn total_count(&self) -> usize {
+        self.adrs.len()
+    }
+
+    /// Returns the position of the selected ADR among the visible ones.
+    #[must_use]
+    pub fn selected_index(&self) -> Option<usize> {
+        (!self.visible.is_empty()).then_some(self.selected)
+    }
+
+    /// Returns the selected ADR.
+    #[must_use]
+    pub fn selected(&self) -> Option<&Adr> {
+        self.visible
+            .get(self.selected)
+            .map(|&index| &self.adrs[index])
+    }
+
+    /// Returns the active filter.
+    #[must_use]
+    pub const fn filter(&self) -> &AdrFilter {
+        &self.filter
+    }
+
+    /// Returns the search query.
+    #[must_use]
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Returns the input mode.
+    #[must_use]
+    pub const fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Returns the scroll offset of the detail pane.
+    #[must_use]
+    pub const fn scroll(&self) -> u16 {
+        self.scroll
+    }
+
+    /// Returns true once the user asked to quit.
+    #[must_use]
+    pub const fn should_quit(&self) -> bool {
+        self.quit
+    }
+
+    /// Updates the state for a key press.
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.quit = true;
+            return;
+        }
+
+        match self.mode {
+            Mode::Normal => self.handle_normal_key(key.code),
+            Mode::Search => self.handle_search_key(key.code),
+        }
+    }
+
+    fn handle_normal_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Char('q') => self.quit = true,
+            KeyCode::Char('/') => self.mode = Mode::Search,
+            KeyCode::Esc if !self.query.is_empty() => {
+                self.query.clear();
+                self.refresh();
+            },
+            KeyCode::Down | KeyCode::Char('j') => self.select(self.selected.saturating_add(1)),
+            KeyCode::Up | KeyCode::Char('k') => self.select(self.selected.saturating_sub(1)),
+            KeyCode::Home | KeyCode::Char('g') => self.select(0),
+            KeyCode::End | KeyCode::Char('G') => self.select(usize::MAX),
+            KeyCode::Char('J') => self.scroll = self.scroll.saturating_add(1),
+            KeyCode::Char('K') => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(PAGE),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(PAGE),
+            KeyCode::Char('s') => {
+                let next = cycle(&self.statuses, single(&self.filter.statuses));
+                self.filter.statuses = next.into_iter().collect();
+                self.refresh();
+            },
+            KeyCode::Char('t') => {
+                let next = cycle(&self.tags, single(&self.filter.tags));
+                self.filter.tags = next.into_iter().collect();
+                self.refresh();
+            },
+            KeyCode::Char('c') => {
+                let next = cycle(&self.categories, single(&self.filter.categories));
+                self.filter.categories = next.into_iter().collect();
+                self.refresh();
+            },
+            KeyCode::Char('x') => {
+                self.filter = AdrFilter::new();
+                self.query.clear();
+                self.refresh();
+            },
+            _ => {},
+        }
+    }
+
+    fn handle_search_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Enter => self.mode = Mode::Normal,
+            KeyCode::Esc => {
+                self.mode = Mode::Normal;
+                self.query.clear();
+                self.refresh();
+            },
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.refresh();
+            },
+            KeyCode::Char(c) => {
+                self.query.push(c);
+                self.refresh();
+            },
+            _ => {},
+        }
+    }
+
+    /// Selects the visible ADR at `index`, clamped to the list.
+    fn select(&mut self, index: usize) {
+        let index = index.min(self.visible.len().saturating_sub(1));
+        if index != self.selected {
+            self.selected = index;
+            self.scroll = 0;
+        }
+    }
+
+    /// Recomputes the visible ADRs, keeping the selection where possible.
+    fn refresh(&mut self) {
+        let selected = self.visible.get(self.selected).copied();
+        let query = self.query.to_lowercase();
+
+        self.visible = self
+            .adrs
+            .iter()
+            .enumerate()
+            .filter(|(_, adr)| self.filter.matches(adr) && matches_query(adr, &query))
+            .map(|(index, _)| index)
+            .collect();
+
+        if let Some(position) =
+            selected.and_then(|index| self.visible.iter().position(|&i| i == index))
+        {
+            self.selected = position;
+        } else {
+            self.selected = 0;
+            self.scroll = 0;
+        }
+    }
+}
+
+/// Returns true if the ADR's ID, title, description, tags, or body contain
+/// the lowercased query.
+fn matches_query(adr: &Adr, query: &str) -> bool {
+    query.is_empty()
+        || [
+            adr.id().as_str(),
+            adr.title(),
+            adr.description(),
+            adr.body_text(),
+        ]
+        .into_iter()
+        .chain(adr.tags().iter().map(String::as_str))
+        .any(|text| text.to_lowercase().contains(query))
+}
+
+/// Returns the only value of a filter criterion, if it has exactly one.
+fn single<T>(values: &[T]) -> Option<&T> {
+    match values {
+        [value] => Some(value),
+        _ => None,
+    }
+}
+
+/// Advances a filter through "all", then each option in turn, then "all".
+fn cycle<T: Clone + PartialEq>(options: &[T], current: Option<&T>) -> Option<T> {
+    current.map_or_else(
+        || options.first().cloned(),
+        |value| {
+            options
+                .iter()
+                .position(|option| option == value)
+                .and_then(|position| options.get(position + 1))
+                .cloned()
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AdrId, Frontmatter, Status};
+    use std::path::PathBuf;
+
+    fn adr(id: &str, title: &str, status: Status, tags: &[&str]) -> Adr {
+        let mut frontmatter = Frontmatter::new(title);
+        frontmatter.status = status;
+        frontmatter.tags = tags.iter().map(ToString::to_string).collect();
+        Adr::new(
+            AdrId::new(id),
+            format!("{id}.md"),
+            PathBuf::from(format!("{id}.md")),
+            frontmatter,
+            String::new(),
+            String::new(),
+            String::new(),
+        )
+    }
+
+    fn app() -> App {
+        App::new(
+            vec![
+                adr(
+                    "adr-0001",
+                    "Use PostgreSQL",
+                    Status::Accepted,
+                    &["database"],
+                ),
+                adr("adr-0002", "Use Redis", Status::Proposed, &["cache"]),
+                adr("adr-0003", "Use MySQL", Status::Superseded, &["database"]),
+            ],
+            AdrFilter::new(),
+        )
+    }
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.handle_key(KeyEvent::from(code));
+    }
+
+    fn visible_ids(app: &App) -> Vec<&str> {
+        app.visible().map(|adr| adr.id().as_str()).collect()
+    }
+
+    #[test]
+    fn test_navigation() {
+        let mut app = app();
+        assert_eq!(app.selected_index(), Some(0));
+
+        press(&mut app, KeyCode::Char('j'));
+        press(&mut app, KeyCode::Char('J'));
+        assert_eq!(app.selected().unwrap().id().as_str(), "adr-0002");
+        assert_eq!(app.scroll(), 1);
+
+        press(&mut app, KeyCode::Char('G'));
+        assert_eq!(app.selected_index(), Some(2));
+        assert_eq!(app.scroll(), 0);
+        press(&mut app, KeyCode::Down);
+        assert_eq!(app.selected_index(), Some(2));
+
+        press(&mut app, KeyCode::Char('q'));
+        assert!(app.should_quit());
+    }
+
+    #[test]
+    fn test_search() {
+        let mut app = app();
+        press(&mut app, KeyCode::Char('/'));
+        assert_eq!(app.mode(), Mode::Search);
+        for c in "use m".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        assert_eq!(visible_ids(&app), vec!["adr-0003"]);
+
+        press(&mut app, KeyCode::Backspace);
+        press(&mut app, KeyCode::Backspace);
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.mode(), Mode::Normal);
+        assert_eq!(app.query(), "use");
+        assert_eq!(app.visible_count(), 3);
+
+        // Clearing the filters clears the query as well
+        press(&mut app, KeyCode::Char('x'));
+        assert_eq!(app.query(), "");
+    }
+
+    #[test]
+    fn test_filters() {
+        let mut app = app();
+
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.filter().statuses, vec!["proposed".to_string()]);
+        assert_eq!(visible_ids(&app), vec!["adr-0002"]);
+        press(&mut app, KeyCode::Char('s'));
+        press(&mut app, KeyCode::Char('s'));
+        press(&mut app, KeyCode::Char('s'));
+        assert!(app.filter().statuses.is_empty());
+
+        press(&mut app, KeyCode::Char('t'));
+        assert_eq!(app.filter().tags, vec!["cache".to_string()]);
+        press(&mut app, KeyCode::Char('t'));
+        assert_eq!(visible_ids(&app), vec!["adr-0001", "adr-0003"]);
+
+        press(&mut app, KeyCode::Char('x'));
+        assert!(app.filter().is_empty());
+        assert_eq!(app.visible_count(), app.total_count());
+    }
+
+    #[test]
+    fn test_selection_follows_filter() {
+        let mut app = app();
+        press(&mut app, KeyCode::Char('G'));
+        for _ in 0..3 {
+            press(&mut app, KeyCode::Char('s'));
+        }
+        assert_eq!(app.filter().statuses, vec!["superseded".to_string()]);
+        assert_eq!(app.selected().unwrap().id().as_str(), "adr-0003");
+
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.selected_index(), Some(2));
+
+        press(&mut app, KeyCode::Char('t'));
+        assert_eq!(app.selected().unwrap().id().as_str(), "adr-0002");
+    }
+}
@@ -0,0 +1,296 @@
+//! Markdown to styled terminal lines.
+//!
+//! A terminal cannot show the rendered HTML, so the detail pane renders the
+//! markdown source directly: headings, emphasis, and code are styled, lists
+//! get bullets or numbers, and block quotes and code blocks are indented.
+
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+
+/// Renders markdown as lines for a ratatui `Paragraph`.
+#[must_use]
+pub fn to_lines(markdown: &str) -> Vec<Line<'static>> {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+    options.insert(Options::ENABLE_FOOTNOTES);
+
+    let mut writer = LineWriter::default();
+    for event in Parser::new_ext(markdown, options) {
+        writer.event(event);
+    }
+    writer.finish()
+}
+
+/// Accumulates spans into lines while walking the markdown events.
+#[derive(Default)]
+struct LineWriter {
+    lines: Vec<Line<'static>>,
+    current: Vec<Span<'static>>,
+    styles: Vec<Style>,
+    /// Open lists, holding the next item number for ordered lists.
+    lists: Vec<Option<u64>>,
+    quote_depth: usize,
+    in_code_block: bool,
+}
+
+impl LineWriter {
+    fn event(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) if self.in_code_block => {
+                for line in text.lines() {
+                    self.current.push(Span::styled(
+                        format!("    {line}"),
+                        Style::new().fg(Color::Yellow),
+                    ));
+                    self.flush();
+                }
+            },
+            Event::Text(text) => self.push(text.into_string()),
+            Event::Code(code) => self.current.push(Span::styled(
+                code.into_string(),
+                self.style().fg(Color::Yellow),
+            )),
+            Event::SoftBreak => self.push(" ".to_string()),
+            Event::HardBreak => self.flush(),
+            Event::Rule => {
+                self.flush();
+                self.current.push(Span::styled(
+                    "─".repeat(40),
+                    Style::new().fg(Color::DarkGray),
+                ));
+                self.flush();
+                self.blank();
+            },
+            Event::TaskListMarker(checked) => {
+                self.push(if checked { "[x] " } else { "[ ] " }.to_string());
+            },
+            Event::FootnoteReference(label) => self.push(format!("[^{label}]")),
+            _ => {},
+        }
+    }
+
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Heading { level, .. } => {
+                self.flush();
+                self.blank();
+                let style = match level {
+                    HeadingLevel::H1 => Style::new()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                    HeadingLevel::H2 => Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                    _ => Style::new().add_modifier(Modifier::BOLD),
+                };
+                self.styles.push(style);
+            },
+            Tag::List(start) => {
+                self.flush();
+                self.lists.push(start);
+            },
+            Tag::Item => {
+                self.flush();
+                let indent = "  ".repeat(self.lists.len().saturating_sub(1));
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{indent}{}. ", *number - 1)
+                    },
+                    _ => format!("{indent}• "),
+                };
+                self.current
+                    .push(Span::styled(marker, Style::new().fg(Color::DarkGray)));
+            },
+            Tag::CodeBlock(kind) => {
+                self.flush();
+                if let CodeBlockKind::Fenced(language) = kind {
+                    if !language.is_empty() {
+                        self.current.push(Span::styled(
+                            format!("    {language}"),
+                            Style::new().fg(Color::DarkGray),
+                        ));
+                        self.flush();
+                    }
+                }
+                self.in_code_block = true;
+            },
+            Tag::BlockQuote(_) => {
+                self.flush();
+                self.quote_depth += 1;
+            },
+            Tag::Emphasis => self.push_style(Style::new().add_modifier(Modifier::ITALIC)),
+            Tag::Strong | Tag::TableHead => {
+                self.push_style(Style::new().add_modifier(Modifier::BOLD));
+            },
+            Tag::Strikethrough => {
+                self.push_style(Style::new().add_modifier(Modifier::CROSSED_OUT));
+            },
+            Tag::Link { .. } => self.push_style(
+                Style::new()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::UNDERLINED),
+            ),
+            Tag::Image { .. } => {
+                self.push("[image: ".to_string());
+                self.push_style(Style::new().add_modifier(Modifier::ITALIC));
+            },
+            Tag::TableCell if !self.current.is_empty() => {
+                self.current
+                    .push(Span::styled(" | ", Style::new().fg(Color::DarkGray)));
+            },
+            _ => {},
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Heading(_) => {
+                self.styles.pop();
+                self.flush();
+                self.blank();
+            },
+            TagEnd::Paragraph => {
+                self.flush();
+                if self.lists.is_empty() {
+                    self.blank();
+                }
+            },
+            TagEnd::List(_) => {
+                self.flush();
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.blank();
+                }
+            },
+            TagEnd::Item | TagEnd::TableRow => self.flush(),
+            TagEnd::CodeBlock => {
+                self.in_code_block = false;
+                self.blank();
+            },
+            TagEnd::BlockQuote(_) => {
+                self.flush();
+                self.quote_depth -= 1;
+                if self.quote_depth == 0 {
+                    self.blank();
+                }
+            },
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough | TagEnd::Link => {
+                self.styles.pop();
+            },
+            TagEnd::Image => {
+                self.styles.pop();
+                self.push("]".to_string());
+            },
+            TagEnd::TableHead => {
+                self.styles.pop();
+                self.flush();
+            },
+            TagEnd::Table => self.blank(),
+            _ => {},
+        }
+    }
+
+    /// Returns the combined style of all open inline elements.
+    fn style(&self) -> Style {
+        self.styles.last().copied().unwrap_or_default()
+    }
+
+    fn push_style(&mut self, style: Style) {
+        self.styles.push(self.style().patch(style));
+    }
+
+    fn push(&mut self, text: String) {
+        self.current.push(Span::styled(text, self.style()));
+    }
+
+    /// Ends the current line, prefixing it with block quote markers.
+    fn flush(&mut self) {
+        if self.current.is_empty() {
+            return;
+        }
+        let mut spans = Vec::with_capacity(self.current.len() + 1);
+        if self.quote_depth > 0 {
+            spans.push(Span::styled(
+                "│ ".repeat(self.quote_depth),
+                Style::new().fg(Color::DarkGray),
+            ));
+        }
+        spans.append(&mut self.current);
+        self.lines.push(Line::from(spans));
+    }
+
+    /// Adds an empty line, unless the previous line is already empty.
+    fn blank(&mut self) {
+        if self.lines.last().is_some_and(|line| line.width() > 0) {
+            self.lines.push(Line::default());
+        }
+    }
+
+    fn finish(mut self) -> Vec<Line<'static>> {
+        self.flush();
+        while self.lines.last().is_some_and(|line| line.width() == 0) {
+            self.lines.pop();
+        }
+        self.lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(lines: &[Line<'_>]) -> Vec<String> {
+        lines.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_to_lines() {
+        let lines = to_lines(
+            "# Title\n\nSome *text* and `code`.\n\n- one\n- two\n  1. nested\n\n> quoted\n\n```rust\nfn main() {}\n```\n",
+        );
+
+        assert_eq!(
+            plain(&lines),
+            vec![
+                "Title",
+                "",
+                "Some text and code.",
+                "",
+                "• one",
+                "• two",
+                "  1. nested",
+                "",
+                "│ quoted",
+                "",
+                "    rust",
+                "    fn main() {}",
+            ]
+        );
+        assert!(
+            lines[0].spans[0]
+                .style
+                .add_modifier
+                .contains(Modifier::BOLD)
+        );
+        assert!(
+            lines[2].spans[1]
+                .style
+                .add_modifier
+                .contains(Modifier::ITALIC)
+        );
+    }
+
+    #[test]
+    fn test_to_lines_table_and_tasks() {
+        let lines = to_lines("| A | B |\n|---|---|\n| 1 | 2 |\n\n- [x] done\n- [ ] todo\n");
+
+        assert_eq!(
+            plain(&lines),
+            vec!["A | B", "1 | 2", "", "• [x] done", "• [ ] todo"]
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! Interactive terminal browser (`adrscope tui`).
+//!
+//! Shows a filterable list of ADRs next to the selected ADR's frontmatter and
+//! markdown body. Only compiled with the `tui` feature, which is on by default.
+
+mod app;
+mod markdown;
+mod ui;
+
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+
+use crate::domain::{Adr, AdrFilter};
+use crate::error::{Error, Result};
+
+use app::App;
+
+/// Runs the browser until the user quits, restoring the terminal afterwards.
+///
+/// # Errors
+///
+/// Returns an error if the terminal cannot be set up or read from.
+pub fn run(adrs: Vec<Adr>, filter: AdrFilter) -> Result<()> {
+    let mut terminal = ratatui::try_init().map_err(|source| Error::Terminal { source })?;
+    let result = event_loop(&mut terminal, App::new(adrs, filter));
+    ratatui::restore();
+    result.map_err(|source| Error::Terminal { source })
+}
+
+fn event_loop(terminal: &mut ratatui::DefaultTerminal, mut app: App) -> std::io::Result<()> {
+    while !app.should_quit() {
+        terminal.draw(|frame| ui::draw(frame, &app))?;
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press {
+                app.handle_key(key);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,229 @@
+//! Layout and drawing of the terminal browser.
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+
+use super::app::{App, Mode};
+use super::markdown;
+use crate::domain::{Adr, Status};
+
+/// Draws the whole screen: header, ADR list, detail pane, and key help.
+pub fn draw(frame: &mut Frame<'_>, app: &App) {
+    let [header, main, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let [list, detail] =
+        Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)]).areas(main);
+
+    frame.render_widget(Paragraph::new(header_line(app)), header);
+    draw_list(frame, app, list);
+    draw_detail(frame, app, detail);
+    frame.render_widget(Paragraph::new(help_line(app.mode())), footer);
+}
+
+fn header_line(app: &App) -> Line<'static> {
+    let filter = app.filter();
+    let label = |values: &[String]| {
+        if values.is_empty() {
+            "all".to_string()
+        } else {
+            values.join(",")
+        }
+    };
+    let mut spans = vec![
+        Span::styled(" ADRScope ", Style::new().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(
+            " {}/{} ADRs   status: {}   tag: {}   category: {}",
+            app.visible_count(),
+            app.total_count(),
+            label(&filter.statuses),
+            label(&filter.tags),
+            label(&filter.categories),
+        )),
+    ];
+    if app.mode() == Mode::Search || !app.query().is_empty() {
+        spans.push(Span::raw("   search: "));
+        spans.push(Span::styled(
+            format!(
+                "{}{}",
+                app.query(),
+                if app.mode() == Mode::Search { "_" } else { "" }
+            ),
+            Style::new().fg(Color::Yellow),
+        ));
+    }
+    Line::from(spans)
+}
+
+fn draw_list(frame: &mut Frame<'_>, app: &App, area: Rect) {
+    let items: Vec<ListItem<'_>> = app
+        .visible()
+        .map(|adr| {
+            ListItem::new(Line::from(vec![
+                Span::styled("● ", Style::new().fg(status_color(adr.status()))),
+                Span::raw(adr.title().to_string()),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(" ADRs "))
+        .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default().with_selected(app.selected_index());
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn draw_detail(frame: &mut Frame<'_>, app: &App, area: Rect) {
+    let block = Block::default().borders(Borders::ALL);
+    let Some(adr) = app.selected() else {
+        frame.render_widget(
+            Paragraph::new("No ADRs match the current filters.").block(block),
+            area,
+        );
+        return;
+    };
+
+    let mut lines = metadata_lines(adr);
+    lines.push(Line::default());
+    lines.extend(markdown::to_lines(adr.body_markdown()));
+
+    let detail = Paragraph::new(Text::from(lines))
+        .block(block.title(format!(" {} ", adr.id())))
+        .wrap(Wrap { trim: false })
+        .scroll((app.scroll(), 0));
+    frame.render_widget(detail, area);
+}
+
+/// Returns the title and frontmatter summary shown above the ADR body.
+fn metadata_lines(adr: &Adr) -> Vec<Line<'static>> {
+    let label = |name: &str| Span::styled(format!("{name}: "), Style::new().fg(Color::DarkGray));
+
+    let mut lines = vec![
+        Line::styled(
+            adr.title().to_string(),
+            Style::new().add_modifier(Modifier::BOLD),
+        ),
+        Line::from(vec![
+            label("Status"),
+            Span::styled(
+                adr.status().as_str(),
+                Style::new().fg(status_color(adr.status())),
+            ),
+        ]),
+    ];
+    if !adr.description().is_empty() {
+        lines.insert(1, Line::raw(adr.description().to_string()));
+    }
+
+    let fields = [
+        ("Category", adr.category().to_string()),
+        ("Tags", adr.tags().join(", ")),
+        ("Author", adr.author().to_string()),
+        (
+            "Created",
+            adr.created()
+                .map(|date| date.to_string())
+                .unwrap_or_default(),
+        ),
+        (
+            "Updated",
+            adr.updated()
+                .map(|date| date.to_string())
+                .unwrap_or_default(),
+        ),
+        ("File", adr.source_path().display().to_string()),
+    ];
+    for (name, value) in fields {
+        if !value.is_empty() {
+            lines.push(Line::from(vec![label(name), Span::raw(value)]));
+        }
+    }
+    lines
+}
+
+fn help_line(mode: Mode) -> Line<'static> {
+    let keys: &[(&str, &str)] = match mode {
+        Mode::Normal => &[
+            ("↑↓/jk", "move"),
+            ("J/K PgUp/PgDn", "scroll"),
+            ("/", "search"),
+            ("s", "status"),
+            ("t", "tag"),
+            ("c", "category"),
+            ("x", "clear"),
+            ("q", "quit"),
+        ],
+        Mode::Search => &[("Enter", "apply"), ("Esc", "cancel")],
+    };
+
+    Line::from(
+        keys.iter()
+            .flat_map(|(key, action)| {
+                [
+                    Span::styled(format!(" {key}"), Style::new().fg(Color::Cyan)),
+                    Span::raw(format!(" {action} ")),
+                ]
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+const fn status_color(status: Status) -> Color {
+    match status {
+        Status::Proposed => Color::Yellow,
+        Status::Accepted => Color::Green,
+        Status::Deprecated => Color::Red,
+        Status::Superseded => Color::DarkGray,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AdrFilter, AdrId, Frontmatter};
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_draw() {
+        let mut frontmatter = Frontmatter::new("Use PostgreSQL");
+        frontmatter.category = "database".to_string();
+        let adr = Adr::new(
+            AdrId::new("adr-0001"),
+            "adr-0001.md".to_string(),
+            PathBuf::from("adr-0001.md"),
+            frontmatter,
+            "## Context\n\nWe need a database.".to_string(),
+            String::new(),
+            String::new(),
+        );
+        let app = App::new(vec![adr], AdrFilter::new());
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 16)).unwrap();
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect();
+        for expected in [
+            "1/1 ADRs",
+            "Use PostgreSQL",
+            "Category: database",
+            "Context",
+            "We need a database.",
+        ] {
+            assert!(screen.contains(expected), "missing {expected:?}");
+        }
+    }
+}
@@ -0,0 +1,231 @@
+//! Interactive prompts of `adrscope new --interactive`.
+//!
+//! Asks for each frontmatter field in turn, offering the values given on the
+//! command line as defaults. Categories and tags complete from the values
+//! existing ADRs use, and related ADRs are checked as they are entered.
+
+use std::io::{self, BufRead, Write};
+
+use crate::application::{NewOptions, find_adr};
+use crate::domain::{Adr, Facets};
+
+/// Number of existing values listed as hints for a field.
+const HINTS: usize = 8;
+
+/// Reads answers from `input`, writing prompts to `output`.
+pub struct Wizard<'a, R, W> {
+    input: R,
+    output: W,
+    existing: &'a [Adr],
+}
+
+impl<'a, R: BufRead, W: Write> Wizard<'a, R, W> {
+    /// Creates a wizard completing values from the `existing` ADRs.
+    pub const fn new(input: R, output: W, existing: &'a [Adr]) -> Self {
+        Self {
+            input,
+            output,
+            existing,
+        }
+    }
+
+    /// Prompts for every field, keeping the values in `options` as defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input ends before a title was given, or
+    /// reading or writing fails.
+    pub fn run(mut self, options: NewOptions) -> io::Result<NewOptions> {
+        let facets = Facets::from_adrs(self.existing);
+        let categories: Vec<String> = facets.categories.into_iter().map(|v| v.value).collect();
+        let tags: Vec<String> = facets.tags.into_iter().map(|v| v.value).collect();
+
+        let mut title = self
+            .ask("Title", &options.title)?
+            .unwrap_or_else(|| options.title.clone());
+        while title.trim().is_empty() {
+            title = self.ask("Title", "")?.unwrap_or_default();
+        }
+        let description = self
+            .ask("Description", &options.description)?
+            .unwrap_or_else(|| options.description.clone());
+
+        self.hint(&categories)?;
+        let category = match self.ask("Category", &options.category)? {
+            Some(value) => self.complete(&value, &categories)?,
+            None => options.category.clone(),
+        };
+
+        self.hint(&tags)?;
+        let tag_list = match self.ask("Tags (comma-separated)", &options.tags.join(", "))? {
+            Some(value) => split(&value)
+                .map(|tag| self.complete(tag, &tags))
+                .collect::<io::Result<_>>()?,
+            None => options.tags.clone(),
+        };
+
+        let related = self.ask_related(&options.related)?;
+        let deciders = self
+            .ask("Deciders (comma-separated)", &options.deciders.join(", "))?
+            .map_or_else(
+                || options.deciders.clone(),
+                |value| split(&value).map(String::from).collect(),
+            );
+
+        Ok(NewOptions {
+            title,
+            description,
+            category,
+            tags: tag_list,
+            related,
+            deciders,
+            ..options
+        })
+    }
+
+    /// Asks for related ADRs until every entry resolves to exactly one ADR.
+    fn ask_related(&mut self, default: &[String]) -> io::Result<Vec<String>> {
+        loop {
+            let Some(value) = self.ask("Related ADRs (IDs or numbers)", &default.join(", "))?
+            else {
+                return Ok(default.to_vec());
+            };
+
+            let related: Vec<String> = split(&value).map(String::from).collect();
+            let errors: Vec<String> = related
+                .iter()
+                .filter_map(|query| find_adr(self.existing, query).err())
+                .map(|error| error.to_string())
+                .collect();
+            if errors.is_empty() {
+                return Ok(related);
+            }
+            for error in errors {
+                writeln!(self.output, "  {error}")?;
+            }
+        }
+    }
+
+    /// Completes a value, telling the user when it was expanded.
+    fn complete(&mut self, value: &str, existing: &[String]) -> io::Result<String> {
+        let completed = complete(value, existing);
+        if completed != value {
+            writeln!(self.output, "  {value} -> {completed}")?;
+        }
+        Ok(completed)
+    }
+
+    /// Lists the most used existing values of a field.
+    fn hint(&mut self, values: &[String]) -> io::Result<()> {
+        if values.is_empty() {
+            return Ok(());
+        }
+        let shown: Vec<&str> = values.iter().take(HINTS).map(String::as_str).collect();
+        let more = if values.len() > HINTS { ", ..." } else { "" };
+        writeln!(self.output, "  existing: {}{more}", shown.join(", "))
+    }
+
+    /// Prompts for a value, returning `None` when the answer is empty (keep
+    /// the default) and an error when the input has ended.
+    fn ask(&mut self, label: &str, default: &str) -> io::Result<Option<String>> {
+        if default.is_empty() {
+            write!(self.output, "{label}: ")?;
+        } else {
+            write!(self.output, "{label} [{default}]: ")?;
+        }
+        self.output.flush()?;
+
+        let mut line = String::new();
+        if self.input.read_line(&mut line)? == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        let answer = line.trim();
+        Ok((!answer.is_empty()).then(|| answer.to_string()))
+    }
+}
+
+/// Splits a comma-separated answer.
+fn split(value: &str) -> impl Iterator<Item = &str> {
+    value.split(',').map(str::trim).filter(|v| !v.is_empty())
+}
+
+/// Completes a value to the existing value it matches ignoring case, or to
+/// the only existing value it is a prefix of.
+fn complete(value: &str, existing: &[String]) -> String {
+    if let Some(exact) = existing.iter().find(|e| e.eq_ignore_ascii_case(value)) {
+        return exact.clone();
+    }
+
+    let prefix = value.to_lowercase();
+    let mut matches = existing
+        .iter()
+        .filter(|e| e.to_lowercase().starts_with(&prefix));
+    match (matches.next(), matches.next()) {
+        (Some(only), None) => only.clone(),
+        _ => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AdrId, Frontmatter};
+    use std::path::PathBuf;
+
+    fn adr(id: &str, category: &str, tags: &[&str]) -> Adr {
+        let mut frontmatter = Frontmatter::new(id);
+        frontmatter.category = category.to_string();
+        frontmatter.tags = tags.iter().map(ToString::to_string).collect();
+        Adr::new(
+            AdrId::new(id),
+            format!("{id}.md"),
+            PathBuf::from(format!("{id}.md")),
+            frontmatter,
+            String::new(),
+            String::new(),
+            String::new(),
+        )
+    }
+
+    #[test]
+    fn test_wizard() {
+        let existing = vec![
+            adr("adr-0001", "architecture", &["database", "postgresql"]),
+            adr("adr-0002", "security", &["auth"]),
+        ];
+        let input = "\nUse Redis\n\narch\nDATA, cache\n9\n2\nAlice, Bob\n";
+        let mut output = Vec::new();
+
+        let options = Wizard::new(input.as_bytes(), &mut output, &existing)
+            .run(NewOptions::default().with_description("Caching layer"))
+            .unwrap();
+
+        assert_eq!(options.title, "Use Redis");
+        assert_eq!(options.description, "Caching layer");
+        assert_eq!(options.category, "architecture");
+        assert_eq!(options.tags, vec!["database", "cache"]);
+        assert_eq!(options.related, vec!["2"]);
+        assert_eq!(options.deciders, vec!["Alice", "Bob"]);
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Description [Caching layer]: "));
+        assert!(output.contains("existing: architecture, security"));
+        assert!(output.contains("arch -> architecture"));
+        assert!(output.contains("no ADR matches '9'"));
+    }
+
+    #[test]
+    fn test_wizard_requires_title() {
+        let result = Wizard::new(&b"\n"[..], io::sink(), &[]).run(NewOptions::default());
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_complete() {
+        let existing = vec!["database".to_string(), "deployment".to_string()];
+        assert_eq!(complete("DATABASE", &existing), "database");
+        assert_eq!(complete("dat", &existing), "database");
+        assert_eq!(complete("d", &existing), "d");
+        assert_eq!(complete("cache", &existing), "cache");
+    }
+}
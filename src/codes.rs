@@ -0,0 +1,689 @@
+//! Catalog of stable error and validation codes.
+//!
+//! Every [`Error`](crate::Error) variant and built-in validation rule carries
+//! a code (`E0003`, `V0001`, ...) that is printed alongside the message. The
+//! catalog maps each code to a longer explanation of causes and fixes, shown
+//! by `adrscope explain <CODE>`.
+
+/// Explanation of a single error or validation code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodeInfo {
+    /// The stable code, e.g. `E0003`.
+    pub code: &'static str,
+    /// One-line summary.
+    pub summary: &'static str,
+    /// Likely causes and fixes.
+    pub explanation: &'static str,
+}
+
+/// All known codes, in order.
+pub const CODES: &[CodeInfo] = &[
+    CodeInfo {
+        code: "E0001",
+        summary: "failed to read file",
+        explanation: "\
+A file could not be read from disk.
+
+Common causes:
+- The input directory or file does not exist (check --input)
+- The process lacks read permission for the file
+- The file is not valid UTF-8",
+    },
+    CodeInfo {
+        code: "E0002",
+        summary: "failed to write output",
+        explanation: "\
+An output file could not be written.
+
+Common causes:
+- The output path points into a read-only location
+- A directory exists where the output file should be written
+- The disk is full",
+    },
+    CodeInfo {
+        code: "E0003",
+        summary: "invalid frontmatter",
+        explanation: "\
+An ADR file has no frontmatter block, and cannot be read as a classic ADR
+either.
+
+An ADR begins with a YAML block between '---' lines or a TOML block between
+'+++' lines:
+
+    ---
+    title: Use PostgreSQL
+    status: accepted
+    ---
+
+Without one, the file is read as a classic adr-tools or MADR ADR, which must
+open with a '# Title' heading and state its status in a '## Status' section
+or a 'Status:' line.
+
+Common causes:
+- The opening delimiter is not the first non-blank line, or the block is
+  never closed
+- A markdown file that is not an ADR (a README, template, or draft) matches
+  the input pattern
+- accept, deprecate, link, or rename had to change a classic ADR, which has
+  no frontmatter to change
+
+Add a frontmatter block, or leave non-ADR files out with --exclude (e.g.
+--exclude README.md) or the 'exclude' setting in adrscope.toml.",
+    },
+    CodeInfo {
+        code: "E0004",
+        summary: "YAML parsing failed",
+        explanation: "\
+The frontmatter block is not valid YAML, or a field has the wrong type. The
+report points at the offending line and column of the ADR file.
+
+Common causes:
+- Values containing ':' that are not quoted (title: \"Cache: Redis\")
+- Inconsistent indentation in lists
+- A list field (tags, related, ...) given as a plain string",
+    },
+    CodeInfo {
+        code: "E0005",
+        summary: "missing required field",
+        explanation: "\
+A required frontmatter field is absent. 'title' must always be present.",
+    },
+    CodeInfo {
+        code: "E0006",
+        summary: "template rendering failed",
+        explanation: "\
+The HTML viewer template could not be rendered. This indicates a bug in
+ADRScope; please report it together with the command that was run.",
+    },
+    CodeInfo {
+        code: "E0007",
+        summary: "no ADR files found",
+        explanation: "\
+No files matched the glob pattern in the input directory.
+
+Check --input and --pattern, or use --discover to search a monorepo for
+decision directories.",
+    },
+    CodeInfo {
+        code: "E0008",
+        summary: "validation failed",
+        explanation: "\
+One or more ADRs failed validation. Run 'adrscope validate' to list the
+individual issues; each one carries its own V-code.",
+    },
+    CodeInfo {
+        code: "E0009",
+        summary: "invalid ADR filename",
+        explanation: "\
+An ADR filename could not be turned into an ID. Filenames must end in '.md'
+and contain at least one character before the extension.",
+    },
+    CodeInfo {
+        code: "E0010",
+        summary: "invalid glob pattern",
+        explanation: "\
+The value given to --pattern is not a valid glob. Patterns use '*' for any
+characters within a path segment and '**' for any number of directories,
+e.g. '**/*.md'.",
+    },
+    CodeInfo {
+        code: "E0011",
+        summary: "invalid date",
+        explanation: "\
+A date field (created, updated) could not be parsed. Dates should use the ISO
+8601 format YYYY-MM-DD, e.g. 2025-01-15. 2025/01/15, 15 Jan 2025, and full
+timestamps are accepted as well (see V0004).",
+    },
+    CodeInfo {
+        code: "E0012",
+        summary: "JSON serialization failed",
+        explanation: "\
+The ADR data could not be serialized to JSON. This indicates a bug in
+ADRScope; please report it together with the command that was run.",
+    },
+    CodeInfo {
+        code: "E0013",
+        summary: "invalid configuration",
+        explanation: "\
+The adrscope.toml configuration file could not be used.
+
+Common causes:
+- TOML syntax errors
+- Unknown keys (check spelling against docs/configuration.md)
+- --profile names a profile that is not defined under [profile.<name>]",
+    },
+    CodeInfo {
+        code: "E0014",
+        summary: "ADR not found",
+        explanation: "\
+No ADR matches the ID, file name, or number given on the command line. ADRs
+can be referred to by ID (adr-0017), file name (adr-0017.md), or number (17).
+
+Check --input and --pattern, and run 'adrscope validate' to list the ADRs
+that were found.",
+    },
+    CodeInfo {
+        code: "E0015",
+        summary: "ambiguous ADR reference",
+        explanation: "\
+The number or name given on the command line matches more than one ADR, for
+example the same number in several monorepo packages. Use the full ID shown
+in the message instead, e.g. payments/adr-0001.",
+    },
+    CodeInfo {
+        code: "E0016",
+        summary: "failed to launch program",
+        explanation: "\
+An external program could not be started.
+
+Common causes:
+- No default browser is configured (xdg-open, open, or start is missing)
+- $VISUAL or $EDITOR names a program that is not on the PATH",
+    },
+    CodeInfo {
+        code: "E0017",
+        summary: "terminal error",
+        explanation: "\
+The terminal browser (adrscope tui) could not switch the terminal into
+interactive mode or read key presses from it, or the prompts of
+'adrscope new --interactive' could not be answered.
+
+Common causes:
+- Standard input or output is not a terminal (e.g. the command is piped or
+  run in CI)
+- Standard input ended before a title was entered
+- The terminal does not support raw mode",
+    },
+    CodeInfo {
+        code: "E0018",
+        summary: "webhook notification failed",
+        explanation: "\
+The notification sent to --notify-url after generating the viewer was not
+accepted. The viewer itself was written.
+
+Common causes:
+- The URL is wrong, or the webhook was deleted or disabled
+- The endpoint rejected the payload (check the HTTP status in the message)
+- No network access from the CI runner
+- ADRScope was built without the 'webhook' feature",
+    },
+    CodeInfo {
+        code: "E0019",
+        summary: "external resources in offline viewer",
+        explanation: "\
+With --strict-offline, the generated viewer must not load anything from
+another host. The message lists the URLs it would have loaded; the viewer
+was not written.
+
+Common causes:
+- An ADR embeds a remote image (![Diagram](https://...)); download it into
+  the ADR's asset directory and link it relatively, so it is inlined
+- --header-html or --footer-html snippets load remote images or styles
+- [generate.analytics] is configured; disable it for offline builds
+
+Links (<a href>) to other hosts are allowed, since they load nothing until
+followed.",
+    },
+    CodeInfo {
+        code: "E0020",
+        summary: "preview server could not start",
+        explanation: "\
+'adrscope serve' could not listen on the requested host and port.
+
+Common causes:
+- Another program (or another 'adrscope serve') already uses the port;
+  pick another with --port
+- Ports below 1024 need elevated privileges on most systems
+- --host names an address that does not belong to this machine",
+    },
+    CodeInfo {
+        code: "E0021",
+        summary: "TOML parsing failed",
+        explanation: "\
+The frontmatter block between '+++' delimiters is not valid TOML, or a field
+has the wrong type. Syntax errors point at the offending line and column of
+the ADR file.
+
+Common causes:
+- Strings that are not quoted (title = \"Use Redis\")
+- A list field (tags, related, ...) given as a plain string
+- A key repeated within the block",
+    },
+    CodeInfo {
+        code: "E0022",
+        summary: "invalid filename pattern",
+        explanation: "\
+The filename pattern given with --filename-pattern or 'filename_pattern' in
+the [validate] section of adrscope.toml is not a valid regular expression.
+The pattern must match the whole filename, for example:
+
+    filename_pattern = 'adr-\\d{4}-[a-z0-9-]+\\.md'",
+    },
+    CodeInfo {
+        code: "E0023",
+        summary: "invalid JSON Schema",
+        explanation: "\
+The frontmatter schema given with --schema or 'schema' in the [validate]
+section of adrscope.toml is not valid JSON, or not a valid JSON Schema, such
+as one with an unknown 'type'. References to other schemas must be inline
+('#/$defs/...'), as remote and file references are not fetched.",
+    },
+    CodeInfo {
+        code: "E0024",
+        summary: "SQLite export failed",
+        explanation: "\
+'adrscope export --format sqlite' could not write the database file.
+
+Common causes:
+- The output path is a directory, or its directory is not writable
+- Another program holds the existing database open, so it cannot be replaced
+- ADRScope was built without the 'sqlite' feature",
+    },
+    CodeInfo {
+        code: "E0025",
+        summary: "unknown status",
+        explanation: "\
+A status given with --status is neither a built-in status (proposed,
+accepted, deprecated, superseded) nor one of the custom statuses or status
+synonyms configured in adrscope.toml. Statuses match ignoring case; define
+new ones in the [[statuses]] table:
+
+    [[statuses]]
+    name = \"rejected\"
+    base = \"deprecated\"",
+    },
+    CodeInfo {
+        code: "V0001",
+        summary: "missing required field",
+        explanation: "\
+Reported by the 'required-fields' rule when a required frontmatter field is
+missing or empty. Only 'title' is required unless 'required' in the
+[validate] section of adrscope.toml lists other fields:
+
+    [validate]
+    required = [\"title\", \"status\", \"created\", \"author\"]
+
+Add the field to the frontmatter. 'status' and 'type' count only when
+written, since they otherwise default to 'proposed' and 'adr'.",
+    },
+    CodeInfo {
+        code: "V0002",
+        summary: "missing recommended field",
+        explanation: "\
+Reported by the 'recommended-fields' rule when a recommended frontmatter
+field is missing or empty: by default 'description', 'created', and
+'category', which power search, the timeline, and the category facet. Set
+'recommended' in the [validate] section of adrscope.toml to change the list.
+This is a warning and only fails validation with --strict.",
+    },
+    CodeInfo {
+        code: "V0003",
+        summary: "colliding ADR ID",
+        explanation: "\
+Two or more files map to the same ADR ID because they share a file name, e.g.
+decisions/adr-0001.md and decisions/archive/adr-0001.md. ADRScope keeps them
+apart by qualifying the IDs with their directory (archive/adr-0001), but
+'related' and 'supersedes' references to the plain ID only reach the file in
+the shared parent directory.
+
+Rename one of the files, or reference the qualified ID. This is a warning
+and only fails validation with --strict.",
+    },
+    CodeInfo {
+        code: "V0004",
+        summary: "non-canonical date format",
+        explanation: "\
+Reported by the 'date-format' rule when 'created' or 'updated' is written in
+a format other than YYYY-MM-DD. ADRScope accepts 2025/01/15, 15 Jan 2025,
+15 January 2025, and full timestamps such as 2025-01-15T10:30:00Z, and uses
+the date part, but other tools reading the structured-madr schema may not.
+
+Rewrite the date as suggested, e.g. created: 2025-01-15. This is a warning
+and only fails validation with --strict.",
+    },
+    CodeInfo {
+        code: "V0005",
+        summary: "unknown status",
+        explanation: "\
+Reported by the 'status-value' rule when 'status' is not one of proposed,
+accepted, deprecated, or superseded. The ADR is treated as proposed.
+
+Either change the status, or map your team's term to a canonical status in
+adrscope.toml:
+
+    [status_synonyms]
+    approved = \"accepted\"
+    draft = \"proposed\"
+    rejected = \"deprecated\"
+
+Mapped statuses are not reported. This is a warning and only fails
+validation with --strict.",
+    },
+    CodeInfo {
+        code: "V0006",
+        summary: "missing asset",
+        explanation: "\
+Reported by the 'asset-exists' rule when an ADR links to a file in its asset
+directory that does not exist. Assets of docs/decisions/adr-0042.md live in
+docs/decisions/adr-0042/ or docs/decisions/assets/adr-0042/ and are
+referenced relative to the ADR:
+
+    ![Flow](adr-0042/flow.png)
+
+Check the spelling of the link, or add the missing file.",
+    },
+    CodeInfo {
+        code: "V0007",
+        summary: "inconsistent status history",
+        explanation: "\
+Reported by the 'status-history' rule when the 'history' frontmatter list
+does not agree with itself or with the rest of the ADR:
+
+- entries are not listed oldest first
+- an entry is dated before 'created' or after 'updated'
+- the last entry records a different status than 'status'
+- an entry's status is neither built in nor configured in adrscope.toml
+
+    status: accepted
+    history:
+      - date: 2025-01-15
+        status: proposed
+      - date: 2025-02-01
+        status: accepted
+        note: Approved by the architecture board
+
+This is a warning and only fails validation with --strict.",
+    },
+    CodeInfo {
+        code: "V0008",
+        summary: "review overdue",
+        explanation: "\
+Reported by the 'review-due' rule when a proposed or accepted ADR has a
+'review-by' (or 'expires') date on or before today. Review the decision,
+then either move the date forward or deprecate the ADR:
+
+    review-by: 2026-06-30
+
+`adrscope review` lists every decision due for review. This is a warning
+and only fails validation with --strict.",
+    },
+    CodeInfo {
+        code: "V0009",
+        summary: "asset too large to inline",
+        explanation: "\
+Reported by generate when an asset or image referenced from an ADR is larger
+than the inline limit (1024 KB by default). The file is not embedded in the
+viewer; its relative link is kept, so it only displays when the viewer is
+served next to the file.
+
+Shrink or compress the file, or raise the limit in adrscope.toml:
+
+    [generate]
+    max_inline_kb = 4096",
+    },
+    CodeInfo {
+        code: "V0010",
+        summary: "unresolved ADR reference",
+        explanation: "\
+Reported by the 'related-links' rule when an entry in 'related',
+'supersedes', or 'superseded-by', or a [[wikilink]] in the body, does not
+match any ADR. In the relationship graph such references appear as
+placeholder nodes.
+
+    related:
+      - adr-0005.md            # matched by ID, with or without .md
+      - payments/adr-0003      # package- or project-qualified
+
+    As decided in [[adr-0005|the caching ADR]].
+
+Check the spelling of the reference, or remove it if the ADR was deleted.
+'adrscope rename' keeps references up to date when an ADR is renamed.",
+    },
+    CodeInfo {
+        code: "V0011",
+        summary: "missing or empty body section",
+        explanation: "\
+Reported by the 'body-structure' rule when an ADR body has no heading for an
+expected section, or nothing but headings and HTML comments below it. By
+default every ADR needs Context, Decision, and Consequences sections (MADR's
+'Context and Problem Statement' and 'Decision Outcome' count too):
+
+    ## Context
+    ## Decision
+    ## Consequences
+
+Change the expected sections in adrscope.toml, or set an empty list to turn
+the rule off:
+
+    [validate]
+    sections = [\"Context\", \"Decision|Decision Outcome\", \"Risks\"]
+
+This is a warning and only fails validation with --strict.",
+    },
+    CodeInfo {
+        code: "V0012",
+        summary: "inconsistent dates",
+        explanation: "\
+Reported by the 'date-consistency' rule when the 'created' and 'updated'
+dates contradict each other or the ADR's status, which puts the ADR in the
+wrong place on the viewer's timeline:
+
+- 'updated' is earlier than 'created'
+- 'created' is in the future
+- a superseded ADR has no 'updated' date recording when it was superseded
+
+    status: superseded
+    created: 2024-03-01
+    updated: 2025-01-15
+
+This is a warning and only fails validation with --strict.",
+    },
+    CodeInfo {
+        code: "V0013",
+        summary: "broken supersession",
+        explanation: "\
+Reported by the 'supersession' rule when an ADR's status disagrees with the
+supersession references between ADRs:
+
+- an ADR supersedes itself (error)
+- a superseded ADR has no existing successor (warning)
+- the successor of a superseded ADR is itself superseded, with no successor
+  of its own (warning)
+
+Record the successor on either side, with 'superseded-by' on the old ADR or
+'supersedes' on the new one:
+
+    status: superseded
+    superseded-by: [adr-0012]",
+    },
+    CodeInfo {
+        code: "V0014",
+        summary: "unconventional filename",
+        explanation: "\
+Reported by the 'filename' rule when an ADR's filename does not match the
+naming convention, a regular expression matched against the whole filename.
+By default, filenames are a four-digit number, optionally prefixed with
+'adr-' and followed by a lowercase slug:
+
+    adr-0001-use-postgres.md
+    0001-use-postgres.md
+
+Set another pattern with --filename-pattern or in adrscope.toml:
+
+    [validate]
+    filename_pattern = 'ADR-\\d{3}-.+\\.md'
+
+This is a warning and only fails validation with --strict.",
+    },
+    CodeInfo {
+        code: "V0015",
+        summary: "tag not kebab-case",
+        explanation: "\
+Reported by the 'tag-format' rule when a tag is not written in kebab-case:
+lowercase words and numbers joined by single hyphens. Tags written several
+ways split the viewer's tag facet, so use the suggested form:
+
+    tags: [event-sourcing]    # not 'Event Sourcing' or 'event_sourcing'
+
+This is a warning and only fails validation with --strict.",
+    },
+    CodeInfo {
+        code: "V0016",
+        summary: "tag or category not allowed",
+        explanation: "\
+Reported by the 'vocabulary' rule when a tag or category is missing from the
+allowed lists in adrscope.toml. Values close to an allowed one, such as
+'databse' for 'database', come with a suggestion. Use an allowed value, or
+add the new one to the list:
+
+    [validate]
+    allowed_tags = [\"database\", \"caching\", \"security\"]
+    allowed_categories = [\"infrastructure\", \"api\"]
+
+Either list may be left out to allow any value. This is a warning and only
+fails validation with --strict.",
+    },
+    CodeInfo {
+        code: "V0017",
+        summary: "frontmatter violates schema",
+        explanation: "\
+Reported by the 'schema' rule when an ADR's frontmatter does not conform to
+the JSON Schema given with --schema or 'schema' in the [validate] section of
+adrscope.toml. The message names the offending field, such as
+'reviewers[0].team', followed by the violation:
+
+    cost-center: 42 is not of type \"string\"
+
+Fields are checked as written in the file, custom fields included. ADRs
+without a frontmatter block are not checked.",
+    },
+    CodeInfo {
+        code: "V0018",
+        summary: "decision proposed for too long",
+        explanation: "\
+Reported by the 'stale-proposal' rule when an ADR is still proposed longer
+after its 'created' date than --max-proposed-age or 'max_proposed_age' in
+the [validate] section of adrscope.toml allows:
+
+    [validate]
+    max_proposed_age = 90    # days
+
+Accept or reject the proposal, or update it and its 'created' date if it is
+still under discussion. ADRs without a 'created' date are not checked. This
+is a warning and only fails validation with --strict.",
+    },
+    CodeInfo {
+        code: "V0019",
+        summary: "ADR not related to any other",
+        explanation: "\
+Reported by the 'orphan' rule when an ADR neither relates to, supersedes,
+or links to another ADR, nor is referenced by one. Decisions are rarely made
+in isolation, so connect it to the decisions it builds on or affects:
+
+    related: [adr-0004-use-kafka.md]
+
+Collections with fewer ADRs than 'orphan_threshold' in the [validate]
+section of adrscope.toml (10 by default) are not checked. This is a warning
+and only fails validation with --strict.",
+    },
+    CodeInfo {
+        code: "V0020",
+        summary: "title or description too long",
+        explanation: "\
+Reported by the 'field-length' rule when a title is longer than 100
+characters or a description longer than 300, the limits set by the
+structured-madr frontmatter. Move the detail into the ADR body and keep the
+title and description short enough to read in a list. This is a warning and
+only fails validation with --strict.",
+    },
+    CodeInfo {
+        code: "V0021",
+        summary: "title not in the configured style",
+        explanation: "\
+Reported by the 'title-style' rule when 'title_style' is set in the
+[validate] section of adrscope.toml and a title does not follow it:
+
+    imperative    starts with a verb, as in 'Use Kafka for events'
+    title-case    capitalizes every word except short articles,
+                  conjunctions, and prepositions
+
+The rule is off unless 'title_style' is set. This is a warning and only
+fails validation with --strict.",
+    },
+];
+
+/// Looks up a code, ignoring case.
+#[must_use]
+pub fn lookup(code: &str) -> Option<&'static CodeInfo> {
+    CODES
+        .iter()
+        .find(|info| info.code.eq_ignore_ascii_case(code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use crate::domain::{
+        BodySectionsRule, CollectionRule, DEFAULT_FILENAME_PATTERN, FilenameRule, OrphanRule,
+        StaleProposalRule, TitleStyle, TitleStyleRule, ValidationRule, VocabularyRule,
+        default_collection_rules, default_rules,
+    };
+
+    #[test]
+    fn test_lookup() {
+        let info = lookup("e0003").expect("code should exist");
+        assert_eq!(info.code, "E0003");
+        assert!(lookup("E9999").is_none());
+    }
+
+    #[test]
+    fn test_codes_are_unique_and_sorted() {
+        let codes: Vec<_> = CODES.iter().map(|info| info.code).collect();
+        let mut sorted = codes.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(codes, sorted);
+    }
+
+    #[test]
+    fn test_every_code_is_documented() {
+        // Error codes are assigned in variant order, E0001 through E0025
+        assert_eq!(
+            Error::UnknownStatus {
+                status: String::new(),
+                known: Vec::new()
+            }
+            .code(),
+            "E0025"
+        );
+        for code in (1..=25).map(|n| format!("E{n:04}")) {
+            assert!(lookup(&code).is_some(), "{code} undocumented");
+        }
+        // Rules outside the defaults are added by validate when configured
+        let configured: Vec<Box<dyn ValidationRule>> = vec![
+            Box::new(BodySectionsRule::default()),
+            Box::new(FilenameRule::new(DEFAULT_FILENAME_PATTERN).unwrap()),
+            Box::new(VocabularyRule::default()),
+            Box::new(StaleProposalRule::until_today(90)),
+            Box::new(TitleStyleRule::new(TitleStyle::Imperative)),
+        ];
+        for rule in default_rules().into_iter().chain(configured) {
+            assert!(
+                lookup(rule.code()).is_some(),
+                "{} undocumented",
+                rule.code()
+            );
+        }
+        for rule in default_collection_rules()
+            .into_iter()
+            .chain([Box::new(OrphanRule::default()) as Box<dyn CollectionRule>])
+        {
+            assert!(
+                lookup(rule.code()).is_some(),
+                "{} undocumented",
+                rule.code()
+            );
+        }
+    }
+}
@@ -3,11 +3,12 @@
 //! This module defines the `Adr` struct which represents a fully parsed
 //! Architecture Decision Record with all its metadata and content.
 
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use serde::Serialize;
 
-use super::{Frontmatter, Status};
+use super::{CustomStatus, Frontmatter, HistoryEntry, Level, Status};
 
 /// Unique identifier for an ADR, typically derived from the filename.
 ///
@@ -46,6 +47,26 @@ impl AdrId {
             .unwrap_or("unknown");
         Self::new(id)
     }
+
+    /// Returns the ADR number, taken from the first run of digits in the
+    /// final path segment (`adr-0017` and `payments/0017-use-kafka` are 17).
+    ///
+    /// ```
+    /// use adrscope::domain::AdrId;
+    ///
+    /// assert_eq!(AdrId::new("adr-0017").number(), Some(17));
+    /// assert_eq!(AdrId::new("readme").number(), None);
+    /// ```
+    #[must_use]
+    pub fn number(&self) -> Option<u64> {
+        let name = self.0.rsplit('/').next().unwrap_or_default();
+        let digits: String = name
+            .chars()
+            .skip_while(|c| !c.is_ascii_digit())
+            .take_while(char::is_ascii_digit)
+            .collect();
+        digits.parse().ok()
+    }
 }
 
 impl std::fmt::Display for AdrId {
@@ -88,6 +109,22 @@ pub struct Adr {
 
     /// Plain text version of body (for search indexing).
     body_text: String,
+
+    /// Path of the monorepo package owning this ADR, when discovered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    package: Option<String>,
+
+    /// Team owning this ADR, when mapped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    team: Option<String>,
+
+    /// Configured custom status this ADR's status matched, if any.
+    #[serde(skip)]
+    custom_status: Option<CustomStatus>,
+
+    /// ADRs referenced by `[[wikilinks]]` in the body.
+    #[serde(skip)]
+    wikilinks: Vec<String>,
 }
 
 impl Adr {
@@ -110,9 +147,67 @@ impl Adr {
             body_markdown,
             body_html,
             body_text,
+            package: None,
+            team: None,
+            custom_status: None,
+            wikilinks: Vec::new(),
         }
     }
 
+    /// Assigns the ADR to a monorepo package.
+    ///
+    /// The ID is qualified with the package path (e.g. `payments/adr_0003`)
+    /// so that identically named ADRs in different packages stay distinct.
+    #[must_use]
+    pub fn with_package(mut self, package: impl Into<String>) -> Self {
+        let package = package.into();
+        self.id = AdrId::new(format!("{package}/{}", self.id));
+        self.package = Some(package);
+        self
+    }
+
+    /// Assigns the ADR to its owning team, if any.
+    #[must_use]
+    pub fn with_team(mut self, team: Option<String>) -> Self {
+        self.team = team;
+        self
+    }
+
+    /// Replaces the identifier, e.g. to disambiguate colliding IDs.
+    #[must_use]
+    pub fn with_id(mut self, id: AdrId) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Sets the ADRs referenced by `[[wikilinks]]` in the body.
+    #[must_use]
+    pub fn with_wikilinks(mut self, wikilinks: Vec<String>) -> Self {
+        self.wikilinks = wikilinks;
+        self
+    }
+
+    /// Replaces the pre-rendered HTML body.
+    #[must_use]
+    pub fn with_body_html(mut self, body_html: String) -> Self {
+        self.body_html = body_html;
+        self
+    }
+
+    /// Maps an unknown status to a canonical one using the given synonyms.
+    #[must_use]
+    pub fn with_status_synonyms(mut self, synonyms: &BTreeMap<String, Status>) -> Self {
+        self.frontmatter.apply_status_synonyms(synonyms);
+        self
+    }
+
+    /// Matches an unknown status against the configured custom statuses.
+    #[must_use]
+    pub fn with_custom_statuses(mut self, statuses: &[CustomStatus]) -> Self {
+        self.custom_status = self.frontmatter.apply_custom_statuses(statuses).cloned();
+        self
+    }
+
     /// Returns the unique identifier.
     #[must_use]
     pub fn id(&self) -> &AdrId {
@@ -155,6 +250,18 @@ impl Adr {
         &self.body_text
     }
 
+    /// Returns the owning monorepo package path, if discovered.
+    #[must_use]
+    pub fn package(&self) -> Option<&str> {
+        self.package.as_deref()
+    }
+
+    /// Returns the owning team, if mapped.
+    #[must_use]
+    pub fn team(&self) -> Option<&str> {
+        self.team.as_deref()
+    }
+
     // Convenience accessors delegating to frontmatter
 
     /// Returns the ADR title.
@@ -175,6 +282,29 @@ impl Adr {
         self.frontmatter.status
     }
 
+    /// Returns the custom status the ADR is in, if configured.
+    #[must_use]
+    pub fn custom_status(&self) -> Option<&CustomStatus> {
+        self.custom_status.as_ref()
+    }
+
+    /// Returns the name the status is displayed under: the custom status
+    /// name if there is one, the built-in status otherwise.
+    #[must_use]
+    pub fn status_name(&self) -> &str {
+        self.custom_status
+            .as_ref()
+            .map_or_else(|| self.status().as_str(), |status| status.name.as_str())
+    }
+
+    /// Returns the display color of the status in hex format.
+    #[must_use]
+    pub fn status_color(&self) -> &str {
+        self.custom_status
+            .as_ref()
+            .map_or_else(|| self.status().color(), CustomStatus::display_color)
+    }
+
     /// Returns the ADR category.
     #[must_use]
     pub fn category(&self) -> &str {
@@ -199,6 +329,18 @@ impl Adr {
         &self.frontmatter.project
     }
 
+    /// Returns the impact level, if rated.
+    #[must_use]
+    pub fn impact(&self) -> Option<Level> {
+        self.frontmatter.impact
+    }
+
+    /// Returns the risk level, if rated.
+    #[must_use]
+    pub fn risk(&self) -> Option<Level> {
+        self.frontmatter.risk
+    }
+
     /// Returns the technologies affected by this ADR.
     #[must_use]
     pub fn technologies(&self) -> &[String] {
@@ -211,6 +353,48 @@ impl Adr {
         &self.frontmatter.related
     }
 
+    /// Returns the ADRs referenced by `[[wikilinks]]` in the body, as written.
+    #[must_use]
+    pub fn wikilinks(&self) -> &[String] {
+        &self.wikilinks
+    }
+
+    /// Returns the people who made the decision.
+    #[must_use]
+    pub fn deciders(&self) -> &[String] {
+        &self.frontmatter.deciders
+    }
+
+    /// Returns the forces and concerns that shaped the decision.
+    #[must_use]
+    pub fn decision_drivers(&self) -> &[String] {
+        &self.frontmatter.decision_drivers
+    }
+
+    /// Returns the options that were weighed.
+    #[must_use]
+    pub fn considered_options(&self) -> &[String] {
+        &self.frontmatter.considered_options
+    }
+
+    /// Returns the filenames of ADRs superseding this decision.
+    #[must_use]
+    pub fn superseded_by(&self) -> &[String] {
+        &self.frontmatter.superseded_by
+    }
+
+    /// Returns the filenames of ADRs this decision supersedes.
+    #[must_use]
+    pub fn supersedes(&self) -> &[String] {
+        &self.frontmatter.supersedes
+    }
+
+    /// Returns the recorded status changes, oldest first.
+    #[must_use]
+    pub fn history(&self) -> &[HistoryEntry] {
+        &self.frontmatter.history
+    }
+
     /// Returns the created date if available.
     #[must_use]
     pub fn created(&self) -> Option<time::Date> {
@@ -222,6 +406,68 @@ impl Adr {
     pub fn updated(&self) -> Option<time::Date> {
         self.frontmatter.updated
     }
+
+    /// Returns the date the decision should be reviewed by, if set.
+    #[must_use]
+    pub fn review_by(&self) -> Option<time::Date> {
+        self.frontmatter.review_by
+    }
+
+    /// Returns true if the decision is in effect and its review date is on
+    /// or before `date`.
+    ///
+    /// Deprecated and superseded decisions are no longer reviewed.
+    #[must_use]
+    pub fn review_due(&self, date: time::Date) -> bool {
+        matches!(self.status(), Status::Proposed | Status::Accepted)
+            && self.review_by().is_some_and(|review_by| review_by <= date)
+    }
+}
+
+/// Finds the ADRs a user refers to by ID, file name, or number.
+///
+/// The query is matched, in order, against IDs, file names (with or without
+/// the `.md` extension), and ADR numbers (`17` matches `adr-0017`); the
+/// first kind of match that finds anything wins. More than one result means
+/// the query is ambiguous, e.g. the same number in several packages.
+#[must_use]
+pub fn find_adrs<'a>(adrs: &'a [Adr], query: &str) -> Vec<&'a Adr> {
+    let query = query.trim();
+    let by_id: Vec<_> = adrs
+        .iter()
+        .filter(|adr| adr.id().as_str().eq_ignore_ascii_case(query))
+        .collect();
+    if !by_id.is_empty() {
+        return by_id;
+    }
+
+    let name = query.strip_suffix(".md").unwrap_or(query);
+    let by_filename: Vec<_> = adrs
+        .iter()
+        .filter(|adr| {
+            let filename = adr.filename();
+            filename
+                .strip_suffix(".md")
+                .unwrap_or(filename)
+                .eq_ignore_ascii_case(name)
+        })
+        .collect();
+    if !by_filename.is_empty() {
+        return by_filename;
+    }
+
+    let digits = query.trim_start_matches('#');
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Vec::new();
+    }
+    digits.parse::<u64>().map_or_else(
+        |_| Vec::new(),
+        |number| {
+            adrs.iter()
+                .filter(|adr| adr.id().number() == Some(number))
+                .collect()
+        },
+    )
 }
 
 #[cfg(test)]
@@ -235,6 +481,42 @@ mod tests {
         assert_eq!(id.as_str(), "adr_0001");
     }
 
+    #[test]
+    fn test_find_adrs() {
+        let adr = |id: &str, filename: &str| {
+            Adr::new(
+                AdrId::new(id),
+                filename.to_string(),
+                PathBuf::from(filename),
+                Frontmatter::new(id),
+                String::new(),
+                String::new(),
+                String::new(),
+            )
+        };
+        let adrs = vec![
+            adr("adr-0001", "adr-0001.md"),
+            adr("adr-0017", "adr-0017.md"),
+            adr("payments/adr-0001", "adr-0001.md"),
+            adr("0042-use-kafka", "0042-use-kafka.md"),
+        ];
+        let ids = |query: &str| -> Vec<&str> {
+            find_adrs(&adrs, query)
+                .into_iter()
+                .map(|adr| adr.id().as_str())
+                .collect()
+        };
+
+        assert_eq!(ids("ADR-0017"), vec!["adr-0017"]);
+        assert_eq!(ids("0042-use-kafka.md"), vec!["0042-use-kafka"]);
+        assert_eq!(ids("17"), vec!["adr-0017"]);
+        assert_eq!(ids("#42"), vec!["0042-use-kafka"]);
+        assert_eq!(ids("payments/adr-0001"), vec!["payments/adr-0001"]);
+        assert_eq!(ids("1"), vec!["adr-0001", "payments/adr-0001"]);
+        assert!(ids("99").is_empty());
+        assert!(ids("kafka").is_empty());
+    }
+
     #[test]
     fn test_adr_id_display() {
         let id = AdrId::new("adr_0001");
@@ -332,4 +614,53 @@ mod tests {
         assert_eq!(adr.created(), Some(date!(2025 - 01 - 10)));
         assert_eq!(adr.updated(), Some(date!(2025 - 01 - 15)));
     }
+
+    #[test]
+    fn test_adr_with_package() {
+        let adr = Adr::new(
+            AdrId::new("adr_0003"),
+            "adr_0003.md".to_string(),
+            PathBuf::from("services/payments/docs/decisions/adr_0003.md"),
+            Frontmatter::new("Payments ADR"),
+            String::new(),
+            String::new(),
+            String::new(),
+        );
+        assert!(adr.package().is_none());
+
+        let adr = adr.with_package("services/payments");
+        assert_eq!(adr.id().as_str(), "services/payments/adr_0003");
+        assert_eq!(adr.package(), Some("services/payments"));
+
+        let json = serde_json::to_string(&adr).expect("should serialize");
+        assert!(json.contains("\"package\":\"services/payments\""));
+    }
+
+    #[test]
+    fn test_adr_with_custom_statuses() {
+        let mut frontmatter = Frontmatter::new("On hold");
+        frontmatter.normalized.push(crate::domain::Normalization {
+            field: "status".to_string(),
+            original: "on-hold".to_string(),
+            normalized: "proposed".to_string(),
+        });
+        let adr = Adr::new(
+            AdrId::new("adr_0004"),
+            "adr_0004.md".to_string(),
+            PathBuf::from("adr_0004.md"),
+            frontmatter,
+            String::new(),
+            String::new(),
+            String::new(),
+        );
+        assert_eq!(adr.status_name(), "proposed");
+        assert_eq!(adr.status_color(), Status::Proposed.color());
+
+        let statuses = [CustomStatus::new("on-hold", Status::Proposed).with_color("#8b5cf6")];
+        let adr = adr.with_custom_statuses(&statuses);
+        assert_eq!(adr.status(), Status::Proposed);
+        assert_eq!(adr.status_name(), "on-hold");
+        assert_eq!(adr.status_color(), "#8b5cf6");
+        assert!(adr.frontmatter().normalized.is_empty());
+    }
 }
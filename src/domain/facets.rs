@@ -3,11 +3,11 @@
 //! Facets provide aggregated counts for filterable fields in the ADR collection,
 //! enabling the UI to show filter options with their counts.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use serde::Serialize;
 
-use super::{Adr, Status};
+use super::{Adr, Level, Status};
 
 /// A single facet value with its count.
 #[derive(Debug, Clone, Serialize)]
@@ -72,10 +72,21 @@ pub struct Facets {
     pub tags: Vec<FacetValue>,
     /// Authors facet.
     pub authors: Vec<FacetValue>,
+    /// Deciders facet.
+    pub deciders: Vec<FacetValue>,
     /// Projects facet.
     pub projects: Vec<FacetValue>,
     /// Technologies facet.
     pub technologies: Vec<FacetValue>,
+    /// Owning teams facet.
+    pub teams: Vec<FacetValue>,
+    /// Impact levels facet, lowest first.
+    pub impacts: Vec<FacetValue>,
+    /// Risk levels facet, lowest first.
+    pub risks: Vec<FacetValue>,
+    /// Facets of configured custom frontmatter fields, by field name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom: BTreeMap<String, Vec<FacetValue>>,
 }
 
 impl Facets {
@@ -86,8 +97,10 @@ impl Facets {
         let mut categories: HashMap<String, usize> = HashMap::new();
         let mut tags: HashMap<String, usize> = HashMap::new();
         let mut authors: HashMap<String, usize> = HashMap::new();
+        let mut deciders: HashMap<String, usize> = HashMap::new();
         let mut projects: HashMap<String, usize> = HashMap::new();
         let mut technologies: HashMap<String, usize> = HashMap::new();
+        let mut teams: HashMap<String, usize> = HashMap::new();
 
         // Initialize all status values with 0
         for status in Status::all() {
@@ -96,9 +109,7 @@ impl Facets {
 
         for adr in adrs {
             // Count status
-            *statuses
-                .entry(adr.status().as_str().to_string())
-                .or_insert(0) += 1;
+            *statuses.entry(adr.status_name().to_string()).or_insert(0) += 1;
 
             // Count category
             if !adr.category().is_empty() {
@@ -115,6 +126,11 @@ impl Facets {
                 *authors.entry(adr.author().to_string()).or_insert(0) += 1;
             }
 
+            // Count deciders
+            for decider in adr.deciders() {
+                *deciders.entry(decider.clone()).or_insert(0) += 1;
+            }
+
             // Count project
             if !adr.project().is_empty() {
                 *projects.entry(adr.project().to_string()).or_insert(0) += 1;
@@ -124,6 +140,11 @@ impl Facets {
             for tech in adr.technologies() {
                 *technologies.entry(tech.clone()).or_insert(0) += 1;
             }
+
+            // Count owning team
+            if let Some(team) = adr.team() {
+                *teams.entry(team.to_string()).or_insert(0) += 1;
+            }
         }
 
         Self {
@@ -131,12 +152,50 @@ impl Facets {
             categories: sorted_facet_values(categories),
             tags: sorted_facet_values(tags),
             authors: sorted_facet_values(authors),
+            deciders: sorted_facet_values(deciders),
             projects: sorted_facet_values(projects),
             technologies: sorted_facet_values(technologies),
+            teams: sorted_facet_values(teams),
+            impacts: level_facet_values(adrs, Adr::impact),
+            risks: level_facet_values(adrs, Adr::risk),
+            custom: BTreeMap::new(),
+        }
+    }
+
+    /// Adds facets for the given custom frontmatter fields.
+    ///
+    /// Each item of a list field is counted separately; fields no ADR sets
+    /// are left out.
+    #[must_use]
+    pub fn with_custom_fields(mut self, adrs: &[Adr], fields: &[String]) -> Self {
+        for field in fields {
+            let mut counts: HashMap<String, usize> = HashMap::new();
+            for value in adrs
+                .iter()
+                .flat_map(|adr| adr.frontmatter().custom_values(field))
+            {
+                *counts.entry(value).or_insert(0) += 1;
+            }
+            if !counts.is_empty() {
+                self.custom
+                    .insert(field.clone(), sorted_facet_values(counts));
+            }
         }
+        self
     }
 }
 
+/// Counts the ADRs at each level, listing only the levels in use, lowest first.
+fn level_facet_values(adrs: &[Adr], level: fn(&Adr) -> Option<Level>) -> Vec<FacetValue> {
+    Level::all()
+        .iter()
+        .filter_map(|&value| {
+            let count = adrs.iter().filter(|adr| level(adr) == Some(value)).count();
+            (count > 0).then(|| FacetValue::new(value.as_str(), count))
+        })
+        .collect()
+}
+
 /// Converts a count map to sorted facet values.
 fn sorted_facet_values(counts: HashMap<String, usize>) -> Vec<FacetValue> {
     let mut values: Vec<_> = counts
@@ -210,16 +269,22 @@ mod tests {
             .with_category("architecture")
             .with_author("Alice")
             .with_project("project-alpha")
+            .with_impact(Level::High)
+            .with_risk(Level::Medium)
             .with_tags(vec!["database".to_string(), "performance".to_string()])
-            .with_technologies(vec!["rust".to_string(), "postgres".to_string()]);
+            .with_technologies(vec!["rust".to_string(), "postgres".to_string()])
+            .with_deciders(vec!["Alice".to_string(), "Carol".to_string()]);
 
         let frontmatter2 = Frontmatter::new("ADR 2")
             .with_status(Status::Proposed)
             .with_category("api")
             .with_author("Bob")
             .with_project("project-beta")
+            .with_impact(Level::Low)
+            .with_risk(Level::Medium)
             .with_tags(vec!["rest".to_string(), "database".to_string()])
-            .with_technologies(vec!["rust".to_string(), "redis".to_string()]);
+            .with_technologies(vec!["rust".to_string(), "redis".to_string()])
+            .with_deciders(vec!["Carol".to_string()]);
 
         let adr1 = Adr::new(
             AdrId::new("adr_0001"),
@@ -229,7 +294,8 @@ mod tests {
             String::new(),
             String::new(),
             String::new(),
-        );
+        )
+        .with_team(Some("platform".to_string()));
 
         let adr2 = Adr::new(
             AdrId::new("adr_0002"),
@@ -326,5 +392,66 @@ mod tests {
                 .iter()
                 .any(|f| f.value == "redis" && f.count == 1)
         );
+
+        // Check levels (listed lowest first, only when used)
+        assert_eq!(facets.impacts.len(), 2);
+        assert_eq!(facets.impacts[0].value, "low");
+        assert_eq!(facets.impacts[1].value, "high");
+        assert_eq!(facets.risks.len(), 1);
+        assert_eq!(facets.risks[0].value, "medium");
+        assert_eq!(facets.risks[0].count, 2);
+
+        // Check deciders
+        assert_eq!(facets.deciders.len(), 2);
+        assert_eq!(facets.deciders[0].value, "Carol");
+        assert_eq!(facets.deciders[0].count, 2);
+
+        // Check teams (only mapped ADRs are counted)
+        assert_eq!(facets.teams.len(), 1);
+        assert_eq!(facets.teams[0].value, "platform");
+        assert_eq!(facets.teams[0].count, 1);
+        assert!(facets.custom.is_empty());
+    }
+
+    #[test]
+    fn test_facets_with_custom_fields() {
+        use crate::domain::{Adr, AdrId, Frontmatter};
+        use std::path::PathBuf;
+
+        let adr = |id: &str, custom: &str| {
+            let mut frontmatter = Frontmatter::new(id);
+            frontmatter.custom = serde_yaml::from_str(custom).expect("should parse");
+            Adr::new(
+                AdrId::new(id),
+                format!("{id}.md"),
+                PathBuf::from(format!("{id}.md")),
+                frontmatter,
+                String::new(),
+                String::new(),
+                String::new(),
+            )
+        };
+        let adrs = [
+            adr("adr_0001", "cost_center: CC-1\nregions: [eu, us]"),
+            adr("adr_0002", "cost_center: CC-1\nregions: [eu]"),
+            adr("adr_0003", "risk_level: 2"),
+        ];
+
+        let fields = ["cost_center", "regions", "risk_level", "unused"].map(String::from);
+        let facets = Facets::from_adrs(&adrs).with_custom_fields(&adrs, &fields);
+
+        assert_eq!(
+            facets.custom.keys().collect::<Vec<_>>(),
+            vec!["cost_center", "regions", "risk_level"]
+        );
+        assert_eq!(facets.custom["cost_center"][0].value, "CC-1");
+        assert_eq!(facets.custom["cost_center"][0].count, 2);
+        assert_eq!(facets.custom["regions"][0].value, "eu");
+        assert_eq!(facets.custom["regions"][0].count, 2);
+        assert_eq!(facets.custom["regions"][1].value, "us");
+        assert_eq!(facets.custom["risk_level"][0].value, "2");
+
+        let json = serde_json::to_value(&facets).expect("should serialize");
+        assert_eq!(json["custom"]["regions"][1]["count"], 1);
     }
 }
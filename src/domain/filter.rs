@@ -0,0 +1,279 @@
+//! Filtering of ADR collections.
+//!
+//! An `AdrFilter` selects the subset of ADRs that a use case operates on,
+//! e.g. to publish a viewer containing only the decisions of a given year.
+
+use time::Date;
+
+use super::Adr;
+
+/// Criteria selecting a subset of ADRs.
+///
+/// An empty filter matches every ADR.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AdrFilter {
+    /// Earliest created/updated date (inclusive).
+    pub since: Option<Date>,
+    /// Latest created/updated date (inclusive).
+    pub until: Option<Date>,
+    /// Status names to include, custom statuses under their own name (all
+    /// when empty).
+    pub statuses: Vec<String>,
+    /// Tags of which an ADR must carry at least one (any when empty).
+    pub tags: Vec<String>,
+    /// Tags excluding an ADR that carries any of them.
+    pub exclude_tags: Vec<String>,
+    /// Categories to include (all when empty).
+    pub categories: Vec<String>,
+    /// Categories to exclude.
+    pub exclude_categories: Vec<String>,
+}
+
+impl AdrFilter {
+    /// Creates an empty filter that matches every ADR.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only matches ADRs created or updated on or after the given date.
+    #[must_use]
+    pub const fn with_since(mut self, since: Date) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Only matches ADRs created or updated on or before the given date.
+    #[must_use]
+    pub const fn with_until(mut self, until: Date) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Only matches ADRs with one of the given statuses (case-insensitive).
+    #[must_use]
+    pub fn with_statuses(mut self, statuses: Vec<String>) -> Self {
+        self.statuses = statuses;
+        self
+    }
+
+    /// Only matches ADRs carrying at least one of the given tags.
+    #[must_use]
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Excludes ADRs carrying any of the given tags.
+    #[must_use]
+    pub fn with_exclude_tags(mut self, tags: Vec<String>) -> Self {
+        self.exclude_tags = tags;
+        self
+    }
+
+    /// Only matches ADRs in one of the given categories.
+    #[must_use]
+    pub fn with_categories(mut self, categories: Vec<String>) -> Self {
+        self.categories = categories;
+        self
+    }
+
+    /// Excludes ADRs in any of the given categories.
+    #[must_use]
+    pub fn with_exclude_categories(mut self, categories: Vec<String>) -> Self {
+        self.exclude_categories = categories;
+        self
+    }
+
+    /// Returns true if the filter matches every ADR.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns true if the ADR satisfies every criterion.
+    #[must_use]
+    pub fn matches(&self, adr: &Adr) -> bool {
+        self.matches_dates(adr)
+            && (self.statuses.is_empty() || contains_ignore_case(&self.statuses, adr.status_name()))
+            && self.matches_tags(adr)
+            && self.matches_category(adr)
+    }
+
+    /// Checks tag inclusion and exclusion (case-insensitive).
+    fn matches_tags(&self, adr: &Adr) -> bool {
+        let has_any = |wanted: &[String]| {
+            adr.tags()
+                .iter()
+                .any(|tag| contains_ignore_case(wanted, tag))
+        };
+
+        (self.tags.is_empty() || has_any(&self.tags)) && !has_any(&self.exclude_tags)
+    }
+
+    /// Checks category inclusion and exclusion (case-insensitive).
+    fn matches_category(&self, adr: &Adr) -> bool {
+        let category = adr.category();
+
+        (self.categories.is_empty() || contains_ignore_case(&self.categories, category))
+            && !contains_ignore_case(&self.exclude_categories, category)
+    }
+
+    /// Checks whether the ADR was created or updated within the date window.
+    ///
+    /// ADRs without any date never match a window.
+    fn matches_dates(&self, adr: &Adr) -> bool {
+        if self.since.is_none() && self.until.is_none() {
+            return true;
+        }
+
+        [adr.created(), adr.updated()]
+            .into_iter()
+            .flatten()
+            .any(|date| {
+                self.since.is_none_or(|since| date >= since)
+                    && self.until.is_none_or(|until| date <= until)
+            })
+    }
+}
+
+fn contains_ignore_case(values: &[String], value: &str) -> bool {
+    values.iter().any(|v| v.eq_ignore_ascii_case(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AdrId, CustomStatus, Frontmatter, Normalization, Status};
+    use std::path::PathBuf;
+    use time::macros::date;
+
+    fn adr_with_dates(created: Option<Date>, updated: Option<Date>) -> Adr {
+        let mut frontmatter = Frontmatter::new("Test");
+        frontmatter.created = created;
+        frontmatter.updated = updated;
+        adr_from(frontmatter)
+    }
+
+    fn adr_from(frontmatter: Frontmatter) -> Adr {
+        Adr::new(
+            AdrId::new("adr_0001"),
+            "adr_0001.md".to_string(),
+            PathBuf::from("adr_0001.md"),
+            frontmatter,
+            String::new(),
+            String::new(),
+            String::new(),
+        )
+    }
+
+    #[test]
+    fn test_empty_filter_matches_everything() {
+        let filter = AdrFilter::new();
+        assert!(filter.is_empty());
+        assert!(filter.matches(&adr_with_dates(None, None)));
+    }
+
+    #[test]
+    fn test_date_window() {
+        let filter = AdrFilter::new()
+            .with_since(date!(2025 - 01 - 01))
+            .with_until(date!(2025 - 12 - 31));
+        assert!(!filter.is_empty());
+
+        assert!(filter.matches(&adr_with_dates(Some(date!(2025 - 06 - 01)), None)));
+        assert!(filter.matches(&adr_with_dates(Some(date!(2025 - 01 - 01)), None)));
+        assert!(!filter.matches(&adr_with_dates(Some(date!(2024 - 06 - 01)), None)));
+        assert!(!filter.matches(&adr_with_dates(None, None)));
+    }
+
+    #[test]
+    fn test_status_filter() {
+        let filter =
+            AdrFilter::new().with_statuses(vec!["Accepted".to_string(), "proposed".to_string()]);
+
+        assert!(filter.matches(&adr_from(Frontmatter::new("New"))));
+        assert!(!filter.matches(&adr_from(
+            Frontmatter::new("Old").with_status(Status::Deprecated)
+        )));
+    }
+
+    #[test]
+    fn test_custom_status_filter() {
+        let mut frontmatter = Frontmatter::new("Draft");
+        frontmatter.normalized.push(Normalization {
+            field: "status".to_string(),
+            original: "draft".to_string(),
+            normalized: "proposed".to_string(),
+        });
+        let adr = adr_from(frontmatter)
+            .with_custom_statuses(&[CustomStatus::new("draft", Status::Proposed)]);
+
+        assert!(
+            AdrFilter::new()
+                .with_statuses(vec!["Draft".to_string()])
+                .matches(&adr)
+        );
+        // The custom status is matched under its own name, not its base
+        assert!(
+            !AdrFilter::new()
+                .with_statuses(vec!["proposed".to_string()])
+                .matches(&adr)
+        );
+    }
+
+    #[test]
+    fn test_tag_filter() {
+        let adr = adr_from(
+            Frontmatter::new("Test").with_tags(vec!["security".to_string(), "auth".to_string()]),
+        );
+
+        assert!(
+            AdrFilter::new()
+                .with_tags(vec!["Security".to_string()])
+                .matches(&adr)
+        );
+        assert!(
+            !AdrFilter::new()
+                .with_tags(vec!["database".to_string()])
+                .matches(&adr)
+        );
+        assert!(
+            !AdrFilter::new()
+                .with_exclude_tags(vec!["auth".to_string()])
+                .matches(&adr)
+        );
+    }
+
+    #[test]
+    fn test_category_filter() {
+        let adr = adr_from(Frontmatter::new("Test").with_category("security"));
+
+        assert!(
+            AdrFilter::new()
+                .with_categories(vec!["security".to_string()])
+                .matches(&adr)
+        );
+        assert!(
+            !AdrFilter::new()
+                .with_categories(vec!["api".to_string()])
+                .matches(&adr)
+        );
+        assert!(
+            !AdrFilter::new()
+                .with_exclude_categories(vec!["SECURITY".to_string()])
+                .matches(&adr)
+        );
+    }
+
+    #[test]
+    fn test_date_window_matches_updated() {
+        let filter = AdrFilter::new().with_since(date!(2025 - 01 - 01));
+
+        let adr = adr_with_dates(Some(date!(2023 - 03 - 01)), Some(date!(2025 - 02 - 01)));
+        assert!(filter.matches(&adr));
+
+        let adr = adr_with_dates(Some(date!(2023 - 03 - 01)), Some(date!(2024 - 02 - 01)));
+        assert!(!filter.matches(&adr));
+    }
+}
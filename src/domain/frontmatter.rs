@@ -3,10 +3,13 @@
 //! This module defines the structured-madr frontmatter schema that ADRScope
 //! expects in ADR files.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
 use time::Date;
 
-use super::Status;
+use super::{CustomStatus, Level, Status};
 
 /// Parsed YAML frontmatter from an ADR file following the structured-madr schema.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,6 +45,16 @@ pub struct Frontmatter {
     #[serde(default, with = "optional_date")]
     pub updated: Option<Date>,
 
+    /// ISO 8601 date by which the decision should be reviewed again
+    /// (`review-by`, or `expires`).
+    #[serde(
+        rename = "review-by",
+        alias = "expires",
+        default,
+        with = "optional_date"
+    )]
+    pub review_by: Option<Date>,
+
     /// Author or team responsible.
     #[serde(default)]
     pub author: String,
@@ -50,6 +63,14 @@ pub struct Frontmatter {
     #[serde(default)]
     pub project: String,
 
+    /// How widely the decision takes effect.
+    #[serde(default)]
+    pub impact: Option<Level>,
+
+    /// How risky the decision is.
+    #[serde(default)]
+    pub risk: Option<Level>,
+
     /// Technologies affected by decision.
     #[serde(default)]
     pub technologies: Vec<String>,
@@ -58,9 +79,175 @@ pub struct Frontmatter {
     #[serde(default)]
     pub audience: Vec<String>,
 
+    /// People who made the decision (`deciders`, or MADR 4's
+    /// `decision-makers`).
+    #[serde(alias = "decision-makers", default)]
+    pub deciders: Vec<String>,
+
+    /// Forces and concerns that shaped the decision (`decision-drivers`, or
+    /// `decision_drivers`).
+    #[serde(rename = "decision-drivers", alias = "decision_drivers", default)]
+    pub decision_drivers: Vec<String>,
+
+    /// Options that were weighed, the chosen one included
+    /// (`considered-options`, or `considered_options`).
+    #[serde(rename = "considered-options", alias = "considered_options", default)]
+    pub considered_options: Vec<String>,
+
     /// Filenames of related ADRs.
     #[serde(default)]
     pub related: Vec<String>,
+
+    /// Filenames of ADRs this decision supersedes.
+    #[serde(default)]
+    pub supersedes: Vec<String>,
+
+    /// Filenames of ADRs superseding this decision (`superseded-by`, or
+    /// `superseded_by`), the inverse of `supersedes`.
+    #[serde(rename = "superseded-by", alias = "superseded_by", default)]
+    pub superseded_by: Vec<String>,
+
+    /// Status changes over the decision's lifecycle, oldest first.
+    #[serde(default)]
+    pub history: Vec<HistoryEntry>,
+
+    /// Values accepted in a non-canonical form, recorded by the parser.
+    #[serde(skip)]
+    pub normalized: Vec<Normalization>,
+
+    /// Names of the fields given a value in the file, recorded by the parser
+    /// to tell fields left out from those holding their default.
+    #[serde(skip)]
+    pub written: Vec<String>,
+
+    /// Fields outside the schema (e.g. `cost_center: CC-42`), recorded by the parser.
+    #[serde(skip_deserializing, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom: BTreeMap<String, Value>,
+}
+
+/// A status change recorded in the `history` frontmatter list.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// ISO 8601 date of the change.
+    #[serde(with = "date")]
+    pub date: Date,
+
+    /// Status the decision moved to; unknown values read as `proposed`,
+    /// as for the `status` field.
+    #[serde(deserialize_with = "lenient_status::deserialize")]
+    pub status: Status,
+
+    /// Why the status changed.
+    #[serde(default)]
+    pub note: String,
+
+    /// Name of the configured custom status the entry records, if any.
+    #[serde(skip)]
+    pub custom_status: Option<String>,
+}
+
+impl HistoryEntry {
+    /// Creates a history entry without a note.
+    #[must_use]
+    pub const fn new(date: Date, status: Status) -> Self {
+        Self {
+            date,
+            status,
+            note: String::new(),
+            custom_status: None,
+        }
+    }
+
+    /// Returns the name of the normalization field recording an unknown
+    /// status of the entry at `index`, such as `history[2].status`.
+    #[must_use]
+    pub fn status_field(index: usize) -> String {
+        format!("history[{index}].status")
+    }
+
+    /// Returns the index of the entry a normalization field names, if it is
+    /// a history entry's status.
+    #[must_use]
+    pub fn index_of(field: &str) -> Option<usize> {
+        field
+            .strip_prefix("history[")?
+            .strip_suffix("].status")?
+            .parse()
+            .ok()
+    }
+
+    /// Returns the name the status is displayed under: the custom status
+    /// name if there is one, the built-in status otherwise.
+    #[must_use]
+    pub fn status_name(&self) -> &str {
+        self.custom_status
+            .as_deref()
+            .unwrap_or_else(|| self.status.as_str())
+    }
+
+    /// Sets the note.
+    #[must_use]
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.note = note.into();
+        self
+    }
+}
+
+/// A frontmatter value that was accepted but not written in canonical form.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Normalization {
+    /// Frontmatter field holding the value.
+    pub field: String,
+    /// Value as written in the file.
+    pub original: String,
+    /// Canonical form the value was normalized to.
+    pub normalized: String,
+}
+
+impl Frontmatter {
+    /// Names of the fields defined by the structured-madr schema.
+    pub const FIELDS: &'static [&'static str] = &[
+        "title",
+        "description",
+        "type",
+        "category",
+        "tags",
+        "status",
+        "created",
+        "updated",
+        "review-by",
+        "expires",
+        "author",
+        "project",
+        "impact",
+        "risk",
+        "technologies",
+        "audience",
+        "deciders",
+        "decision-makers",
+        "decision-drivers",
+        "decision_drivers",
+        "considered-options",
+        "considered_options",
+        "related",
+        "supersedes",
+        "superseded-by",
+        "superseded_by",
+        "history",
+    ];
+
+    /// Names of the schema fields holding lists of values.
+    pub const LIST_FIELDS: &'static [&'static str] = &[
+        "tags",
+        "technologies",
+        "audience",
+        "deciders",
+        "decision-drivers",
+        "considered-options",
+        "related",
+        "supersedes",
+        "superseded-by",
+    ];
 }
 
 fn default_type() -> String {
@@ -78,11 +265,23 @@ impl Default for Frontmatter {
             status: Status::default(),
             created: None,
             updated: None,
+            review_by: None,
             author: String::new(),
             project: String::new(),
+            impact: None,
+            risk: None,
             technologies: Vec::new(),
             audience: Vec::new(),
+            deciders: Vec::new(),
+            decision_drivers: Vec::new(),
+            considered_options: Vec::new(),
             related: Vec::new(),
+            supersedes: Vec::new(),
+            superseded_by: Vec::new(),
+            history: Vec::new(),
+            normalized: Vec::new(),
+            written: Vec::new(),
+            custom: BTreeMap::new(),
         }
     }
 }
@@ -146,6 +345,27 @@ impl Frontmatter {
         self
     }
 
+    /// Sets the review date.
+    #[must_use]
+    pub const fn with_review_by(mut self, date: Date) -> Self {
+        self.review_by = Some(date);
+        self
+    }
+
+    /// Sets the impact level.
+    #[must_use]
+    pub const fn with_impact(mut self, impact: Level) -> Self {
+        self.impact = Some(impact);
+        self
+    }
+
+    /// Sets the risk level.
+    #[must_use]
+    pub const fn with_risk(mut self, risk: Level) -> Self {
+        self.risk = Some(risk);
+        self
+    }
+
     /// Adds tags.
     #[must_use]
     pub fn with_tags(mut self, tags: Vec<String>) -> Self {
@@ -160,57 +380,259 @@ impl Frontmatter {
         self
     }
 
+    /// Sets the people who made the decision.
+    #[must_use]
+    pub fn with_deciders(mut self, deciders: Vec<String>) -> Self {
+        self.deciders = deciders;
+        self
+    }
+
+    /// Sets the decision drivers.
+    #[must_use]
+    pub fn with_decision_drivers(mut self, drivers: Vec<String>) -> Self {
+        self.decision_drivers = drivers;
+        self
+    }
+
+    /// Sets the options that were considered.
+    #[must_use]
+    pub fn with_considered_options(mut self, options: Vec<String>) -> Self {
+        self.considered_options = options;
+        self
+    }
+
     /// Adds related ADRs.
     #[must_use]
     pub fn with_related(mut self, related: Vec<String>) -> Self {
         self.related = related;
         self
     }
+
+    /// Adds superseded ADRs.
+    #[must_use]
+    pub fn with_supersedes(mut self, supersedes: Vec<String>) -> Self {
+        self.supersedes = supersedes;
+        self
+    }
+
+    /// Adds ADRs superseding this one.
+    #[must_use]
+    pub fn with_superseded_by(mut self, superseded_by: Vec<String>) -> Self {
+        self.superseded_by = superseded_by;
+        self
+    }
+
+    /// Sets the status history.
+    #[must_use]
+    pub fn with_history(mut self, history: Vec<HistoryEntry>) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Checks whether a field has a value, by its name in the file
+    /// (`review-by`, `decision-makers`).
+    ///
+    /// `type` and `status` count only when written, since they otherwise
+    /// hold their default. Names outside the schema are looked up among the
+    /// custom fields.
+    #[must_use]
+    pub fn has_field(&self, name: &str) -> bool {
+        match name {
+            "title" => !self.title.is_empty(),
+            "description" => !self.description.is_empty(),
+            "type" | "status" => self.written.iter().any(|field| field == name),
+            "category" => !self.category.is_empty(),
+            "tags" => !self.tags.is_empty(),
+            "created" => self.created.is_some(),
+            "updated" => self.updated.is_some(),
+            "review-by" | "expires" => self.review_by.is_some(),
+            "author" => !self.author.is_empty(),
+            "project" => !self.project.is_empty(),
+            "impact" => self.impact.is_some(),
+            "risk" => self.risk.is_some(),
+            "technologies" => !self.technologies.is_empty(),
+            "audience" => !self.audience.is_empty(),
+            "deciders" | "decision-makers" => !self.deciders.is_empty(),
+            "decision-drivers" | "decision_drivers" => !self.decision_drivers.is_empty(),
+            "considered-options" | "considered_options" => !self.considered_options.is_empty(),
+            "related" => !self.related.is_empty(),
+            "supersedes" => !self.supersedes.is_empty(),
+            "superseded-by" | "superseded_by" => !self.superseded_by.is_empty(),
+            "history" => !self.history.is_empty(),
+            _ => self.custom.get(name).is_some_and(|value| match value {
+                Value::Null => false,
+                Value::String(s) => !s.is_empty(),
+                Value::Sequence(items) => !items.is_empty(),
+                Value::Mapping(fields) => !fields.is_empty(),
+                _ => true,
+            }),
+        }
+    }
+
+    /// Returns the values of a custom field as text.
+    ///
+    /// A scalar yields one value and a list yields each of its scalar items;
+    /// nested mappings are skipped.
+    #[must_use]
+    pub fn custom_values(&self, field: &str) -> Vec<String> {
+        match self.custom.get(field) {
+            Some(Value::Sequence(items)) => items.iter().filter_map(scalar_text).collect(),
+            Some(value) => scalar_text(value).into_iter().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Maps unknown statuses, of the ADR and of its history entries, to
+    /// canonical ones using the given synonyms.
+    ///
+    /// Synonyms are matched case-insensitively. A mapped status is no longer
+    /// recorded as normalized, since the mapping was configured deliberately.
+    pub fn apply_status_synonyms(&mut self, synonyms: &BTreeMap<String, Status>) {
+        let find = |original: &str| {
+            synonyms
+                .iter()
+                .find(|(synonym, _)| synonym.eq_ignore_ascii_case(original))
+                .map(|(_, status)| *status)
+        };
+        self.resolve_status(find);
+        self.resolve_history(|entry, original| {
+            find(original).map(|status| entry.status = status).is_some()
+        });
+    }
+
+    /// Matches an unknown status against the given custom statuses.
+    ///
+    /// A matching status sets the base status and is returned, so that the
+    /// ADR can be displayed under its custom name; it is no longer recorded
+    /// as normalized.
+    pub fn apply_custom_statuses<'a>(
+        &mut self,
+        statuses: &'a [CustomStatus],
+    ) -> Option<&'a CustomStatus> {
+        let find = |original: &str| {
+            statuses
+                .iter()
+                .find(|status| status.name.eq_ignore_ascii_case(original))
+        };
+        let mut matched = None;
+        self.resolve_status(|original| {
+            matched = find(original);
+            matched.map(|status| status.base)
+        });
+        self.resolve_history(|entry, original| {
+            find(original)
+                .map(|status| {
+                    entry.status = status.base;
+                    entry.custom_status = Some(status.name.clone());
+                })
+                .is_some()
+        });
+        matched
+    }
+
+    /// Replaces an unknown status with the one `resolve` finds for its
+    /// original value, dropping its normalization record.
+    fn resolve_status(&mut self, mut resolve: impl FnMut(&str) -> Option<Status>) {
+        let Some(index) = self.normalized.iter().position(|n| n.field == "status") else {
+            return;
+        };
+        if let Some(status) = resolve(self.normalized[index].original.trim()) {
+            self.status = status;
+            self.normalized.remove(index);
+        }
+    }
+
+    /// Resolves the unknown statuses of history entries with `resolve`,
+    /// which updates the entry and returns true if it knows the original
+    /// value; resolved statuses drop their normalization record.
+    fn resolve_history(&mut self, mut resolve: impl FnMut(&mut HistoryEntry, &str) -> bool) {
+        let history = &mut self.history;
+        self.normalized.retain(|normalization| {
+            HistoryEntry::index_of(&normalization.field)
+                .and_then(|index| history.get_mut(index))
+                .is_none_or(|entry| !resolve(entry, normalization.original.trim()))
+        });
+    }
 }
 
-/// Lenient deserialization for Status that warns once per unknown value.
-mod lenient_status {
-    use std::cell::RefCell;
-    use std::collections::HashSet;
+/// Returns a scalar YAML value as text.
+fn scalar_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
 
+/// Lenient deserialization for Status.
+///
+/// Unknown values default to `proposed`; the parser records them so that they
+/// can be mapped through configured synonyms or reported during validation.
+mod lenient_status {
     use serde::{Deserialize, Deserializer};
 
     use super::Status;
 
-    thread_local! {
-        /// Track unknown statuses we've already warned about (warn once per unique value per thread).
-        static WARNED_STATUSES: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
-    }
-
     pub fn deserialize<'de, D>(deserializer: D) -> Result<Status, D::Error>
     where
         D: Deserializer<'de>,
     {
         let opt: Option<String> = Option::deserialize(deserializer)?;
-        match opt {
-            Some(s) if !s.is_empty() => match s.to_lowercase().as_str() {
-                "proposed" => Ok(Status::Proposed),
-                "accepted" => Ok(Status::Accepted),
-                "deprecated" => Ok(Status::Deprecated),
-                "superseded" => Ok(Status::Superseded),
-                unknown => {
-                    // Only warn once per unique unknown status value per thread
-                    WARNED_STATUSES.with(|set| {
-                        if set.borrow_mut().insert(unknown.to_string()) {
-                            eprintln!(
-                                "Warning: Unknown ADR status '{unknown}', defaulting to 'proposed'"
-                            );
-                        }
-                    });
-                    Ok(Status::Proposed)
-                },
-            },
-            _ => Ok(Status::default()),
-        }
+        Ok(opt.and_then(|s| s.parse().ok()).unwrap_or_default())
     }
 }
 
+/// Parses a date leniently.
+///
+/// Besides the canonical ISO 8601 form (`2025-01-15`) this accepts
+/// `2025/01/15`, `15 Jan 2025`, `15 January 2025`, and full timestamps such
+/// as `2025-01-15T10:30:00Z` or YAML's `2025-01-15 10:30:00 -5`, whose date
+/// part is kept.
+#[must_use]
+pub fn parse_date(value: &str) -> Option<Date> {
+    use time::format_description::well_known::{Iso8601, Rfc3339};
+    use time::macros::format_description;
+    use time::{OffsetDateTime, PrimitiveDateTime};
+
+    let value = value.trim();
+    Date::parse(value, &Iso8601::DATE)
+        .or_else(|_| Date::parse(value, format_description!("[year]/[month]/[day]")))
+        .or_else(|_| {
+            Date::parse(
+                value,
+                format_description!(
+                    "[day padding:none] [month repr:short case_sensitive:false] [year]"
+                ),
+            )
+        })
+        .or_else(|_| {
+            Date::parse(
+                value,
+                format_description!(
+                    "[day padding:none] [month repr:long case_sensitive:false] [year]"
+                ),
+            )
+        })
+        .or_else(|_| OffsetDateTime::parse(value, &Rfc3339).map(OffsetDateTime::date))
+        .or_else(|_| {
+            PrimitiveDateTime::parse(value, &Iso8601::DEFAULT).map(PrimitiveDateTime::date)
+        })
+        .ok()
+        .or_else(|| {
+            // YAML timestamps may separate the time with spaces
+            let (date, time) = value.split_once(char::is_whitespace)?;
+            let (hour, _) = time.trim_start().split_once(':')?;
+            let is_hour = (1..=2).contains(&hour.len()) && hour.bytes().all(|b| b.is_ascii_digit());
+            is_hour
+                .then(|| Date::parse(date, &Iso8601::DATE).ok())
+                .flatten()
+        })
+}
+
 /// Custom serialization for optional dates in ISO 8601 format.
+///
+/// Deserialization is lenient, see [`parse_date`].
 mod optional_date {
     use serde::{self, Deserialize, Deserializer, Serializer};
     use time::{Date, format_description::well_known::Iso8601};
@@ -237,14 +659,42 @@ mod optional_date {
     {
         let opt: Option<String> = Option::deserialize(deserializer)?;
         match opt {
-            Some(s) if !s.is_empty() => Date::parse(&s, &Iso8601::DATE)
-                .map(Some)
-                .map_err(|e| serde::de::Error::custom(format!("invalid date format '{s}': {e}"))),
+            Some(s) if !s.is_empty() => super::parse_date(&s).map(Some).ok_or_else(|| {
+                serde::de::Error::custom(format!("invalid date format '{s}': expected YYYY-MM-DD"))
+            }),
             _ => Ok(None),
         }
     }
 }
 
+/// Custom serialization for required dates in ISO 8601 format.
+///
+/// Deserialization is lenient, see [`parse_date`].
+mod date {
+    use serde::{self, Deserialize, Deserializer, Serializer};
+    use time::{Date, format_description::well_known::Iso8601};
+
+    pub fn serialize<S>(date: &Date, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let s = date
+            .format(&Iso8601::DATE)
+            .map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&s)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Date, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        super::parse_date(&s).ok_or_else(|| {
+            serde::de::Error::custom(format!("invalid date format '{s}': expected YYYY-MM-DD"))
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -292,6 +742,80 @@ created: "2025-01-15"
         assert!(fm.created.is_some());
     }
 
+    #[test]
+    fn test_frontmatter_history_deserialization() {
+        use time::macros::date;
+
+        let yaml = r#"
+title: Use PostgreSQL
+status: accepted
+history:
+  - date: 2025-01-15
+    status: proposed
+  - date: 2025/02/01
+    status: accepted
+    note: Approved by the architecture board
+"#;
+        let fm: Frontmatter = serde_yaml::from_str(yaml).expect("should parse");
+        assert_eq!(
+            fm.history,
+            vec![
+                HistoryEntry::new(date!(2025 - 01 - 15), Status::Proposed),
+                HistoryEntry::new(date!(2025 - 02 - 01), Status::Accepted)
+                    .with_note("Approved by the architecture board"),
+            ]
+        );
+
+        let json = serde_json::to_string(&fm.history[0]).expect("should serialize");
+        assert_eq!(
+            json,
+            r#"{"date":"2025-01-15","status":"proposed","note":""}"#
+        );
+
+        // Unknown statuses are read leniently, as for the status field
+        let unknown = "title: Test\nhistory:\n  - date: 2025-01-15\n    status: approved\n";
+        let fm: Frontmatter = serde_yaml::from_str(unknown).expect("should parse");
+        assert_eq!(fm.history[0].status, Status::Proposed);
+
+        let invalid = "title: Test\nhistory:\n  - date: someday\n    status: accepted\n";
+        assert!(serde_yaml::from_str::<Frontmatter>(invalid).is_err());
+    }
+
+    #[test]
+    fn test_frontmatter_superseded_by() {
+        for key in ["superseded-by", "superseded_by"] {
+            let yaml = format!("title: Use MySQL\nstatus: superseded\n{key}:\n  - adr_0042.md\n");
+            let fm: Frontmatter = serde_yaml::from_str(&yaml).expect("should parse");
+            assert_eq!(fm.superseded_by, vec!["adr_0042.md"]);
+        }
+
+        let json = serde_json::to_string(&Frontmatter::new("Test")).expect("should serialize");
+        assert!(json.contains("\"superseded-by\":[]"));
+    }
+
+    #[test]
+    fn test_frontmatter_madr_fields() {
+        let yaml = r#"
+title: Use Plain JUnit5
+decision-makers:
+  - Alice
+  - Bob
+decision_drivers:
+  - Easy to maintain
+considered-options:
+  - Plain JUnit5
+  - Hamcrest
+"#;
+        let fm: Frontmatter = serde_yaml::from_str(yaml).expect("should parse");
+        assert_eq!(fm.deciders, vec!["Alice", "Bob"]);
+        assert_eq!(fm.decision_drivers, vec!["Easy to maintain"]);
+        assert_eq!(fm.considered_options, vec!["Plain JUnit5", "Hamcrest"]);
+
+        let json = serde_json::to_string(&fm).expect("should serialize");
+        assert!(json.contains("\"deciders\":[\"Alice\",\"Bob\"]"));
+        assert!(json.contains("\"decision-drivers\":[\"Easy to maintain\"]"));
+    }
+
     #[test]
     fn test_frontmatter_serialization() {
         let fm = Frontmatter::new("Test").with_status(Status::Accepted);
@@ -299,6 +823,32 @@ created: "2025-01-15"
         let json = serde_json::to_string(&fm).expect("should serialize");
         assert!(json.contains("\"title\":\"Test\""));
         assert!(json.contains("\"status\":\"accepted\""));
+        assert!(!json.contains("\"custom\""));
+    }
+
+    #[test]
+    fn test_frontmatter_custom_fields() {
+        let mut fm = Frontmatter::new("Test");
+        let custom: BTreeMap<String, Value> = serde_yaml::from_str(
+            "cost_center: CC-42\nrisk_level: 3\nregions: [eu, us]\nowner: {team: a}\n",
+        )
+        .expect("should parse");
+        fm.custom = custom;
+
+        assert_eq!(fm.custom_values("cost_center"), vec!["CC-42"]);
+        assert_eq!(fm.custom_values("risk_level"), vec!["3"]);
+        assert_eq!(fm.custom_values("regions"), vec!["eu", "us"]);
+        assert!(fm.custom_values("owner").is_empty());
+        assert!(fm.custom_values("missing").is_empty());
+
+        let json = serde_json::to_value(&fm).expect("should serialize");
+        assert_eq!(json["custom"]["cost_center"], "CC-42");
+        assert_eq!(json["custom"]["regions"][1], "us");
+
+        // Custom fields are only ever recorded by the parser
+        let fm: Frontmatter =
+            serde_yaml::from_str("title: Test\ncustom: {a: 1}\n").expect("should parse");
+        assert!(fm.custom.is_empty());
     }
 
     #[test]
@@ -347,6 +897,44 @@ created: "2025-01-15"
         assert_eq!(roundtrip.updated, fm.updated);
     }
 
+    #[test]
+    fn test_parse_date_formats() {
+        use time::macros::date;
+
+        for value in [
+            "2025-01-15",
+            "2025/01/15",
+            "15 Jan 2025",
+            "15 january 2025",
+            "2025-01-15T10:30:00Z",
+            "2025-01-15T10:30:00+02:00",
+            "2025-01-15T10:30:00",
+            "2025-01-15 10:30:00",
+            "2025-01-15 9:05:00.10 -5",
+        ] {
+            assert_eq!(parse_date(value), Some(date!(2025 - 01 - 15)), "{value}");
+        }
+        assert_eq!(parse_date("5 Jan 2025"), Some(date!(2025 - 01 - 05)));
+        assert_eq!(parse_date("01/15/2025"), None);
+        assert_eq!(parse_date("2025-13-01"), None);
+        assert_eq!(parse_date("2025-01-15 soon"), None);
+        assert_eq!(parse_date("soon"), None);
+    }
+
+    #[test]
+    fn test_frontmatter_lenient_dates() {
+        use time::macros::date;
+
+        let yaml = "title: Dates\ncreated: 2025/01/15\nupdated: 2025-02-01T08:00:00Z\n";
+        let fm: Frontmatter = serde_yaml::from_str(yaml).expect("should parse");
+        assert_eq!(fm.created, Some(date!(2025 - 01 - 15)));
+        assert_eq!(fm.updated, Some(date!(2025 - 02 - 01)));
+
+        let err = serde_yaml::from_str::<Frontmatter>("title: Dates\ncreated: soon\n")
+            .expect_err("should reject");
+        assert!(err.to_string().contains("expected YYYY-MM-DD"));
+    }
+
     #[test]
     fn test_frontmatter_unknown_status_defaults_to_proposed() {
         // Unknown status values should parse successfully with default status
@@ -363,6 +951,76 @@ category: architecture
         assert_eq!(fm.status, Status::Proposed);
     }
 
+    #[test]
+    fn test_apply_status_synonyms() {
+        let mut fm = Frontmatter::new("Synonyms");
+        fm.normalized.push(Normalization {
+            field: "status".to_string(),
+            original: "Approved".to_string(),
+            normalized: "proposed".to_string(),
+        });
+
+        fm.apply_status_synonyms(&BTreeMap::from([("draft".to_string(), Status::Proposed)]));
+        assert_eq!(fm.status, Status::Proposed);
+        assert_eq!(fm.normalized.len(), 1);
+
+        fm.apply_status_synonyms(&BTreeMap::from([(
+            "approved".to_string(),
+            Status::Accepted,
+        )]));
+        assert_eq!(fm.status, Status::Accepted);
+        assert!(fm.normalized.is_empty());
+    }
+
+    #[test]
+    fn test_apply_custom_statuses() {
+        let statuses = [CustomStatus::new("rejected", Status::Deprecated)];
+        let mut fm = Frontmatter::new("Custom");
+        assert!(fm.apply_custom_statuses(&statuses).is_none());
+
+        fm.normalized.push(Normalization {
+            field: "status".to_string(),
+            original: "Rejected".to_string(),
+            normalized: "proposed".to_string(),
+        });
+        let matched = fm.apply_custom_statuses(&statuses);
+        assert_eq!(matched.map(|s| s.name.as_str()), Some("rejected"));
+        assert_eq!(fm.status, Status::Deprecated);
+        assert!(fm.normalized.is_empty());
+    }
+
+    #[test]
+    fn test_history_statuses_are_resolved() {
+        use time::macros::date;
+
+        let mut fm = Frontmatter::new("History").with_history(vec![
+            HistoryEntry::new(date!(2025 - 01 - 15), Status::Proposed),
+            HistoryEntry::new(date!(2025 - 02 - 01), Status::Proposed),
+            HistoryEntry::new(date!(2025 - 03 - 01), Status::Proposed),
+        ]);
+        for (index, original) in [(0, "Draft"), (1, "approved"), (2, "shelved")] {
+            fm.normalized.push(Normalization {
+                field: HistoryEntry::status_field(index),
+                original: original.to_string(),
+                normalized: "proposed".to_string(),
+            });
+        }
+
+        fm.apply_status_synonyms(&BTreeMap::from([(
+            "approved".to_string(),
+            Status::Accepted,
+        )]));
+        fm.apply_custom_statuses(&[CustomStatus::new("draft", Status::Proposed)]);
+        assert_eq!(fm.history[0].status_name(), "draft");
+        assert_eq!(fm.history[1].status_name(), "accepted");
+        assert_eq!(fm.history[2].status_name(), "proposed");
+
+        // Only the status no configuration knows is still recorded
+        assert_eq!(fm.normalized.len(), 1);
+        assert_eq!(HistoryEntry::index_of(&fm.normalized[0].field), Some(2));
+        assert_eq!(HistoryEntry::index_of("status"), None);
+    }
+
     #[test]
     fn test_frontmatter_missing_status_defaults_to_proposed() {
         let yaml = r#"
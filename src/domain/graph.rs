@@ -3,7 +3,9 @@
 //! This module provides types for representing ADR relationships as a graph,
 //! enabling network visualization of related decisions.
 
-use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
 
 use super::{Adr, Status};
 
@@ -17,6 +19,28 @@ pub struct Node {
     /// The ADR title for display.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    /// The decision at the end of this ADR's supersession chain, if it has
+    /// been superseded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current: Option<String>,
+    /// The cluster the node is drawn in, if the graph is grouped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// Number of edges pointing at this ADR.
+    pub in_degree: usize,
+    /// Number of edges this ADR records.
+    pub out_degree: usize,
+    /// Betweenness centrality, from 0 to 1: the share of shortest paths
+    /// between other ADRs that pass through this one.
+    pub centrality: f64,
+}
+
+impl Node {
+    /// Returns the number of edges touching this ADR, in either direction.
+    #[must_use]
+    pub const fn degree(&self) -> usize {
+        self.in_degree + self.out_degree
+    }
 }
 
 impl Node {
@@ -25,8 +49,13 @@ impl Node {
     pub fn from_adr(adr: &Adr) -> Self {
         Self {
             id: adr.id().as_str().to_string(),
-            status: adr.status().as_str().to_string(),
+            status: adr.status_name().to_string(),
             title: Some(adr.title().to_string()),
+            current: None,
+            group: None,
+            in_degree: 0,
+            out_degree: 0,
+            centrality: 0.0,
         }
     }
 
@@ -37,12 +66,45 @@ impl Node {
             id: id.into(),
             status: Status::default().as_str().to_string(),
             title: None,
+            current: None,
+            group: None,
+            in_degree: 0,
+            out_degree: 0,
+            centrality: 0.0,
         }
     }
 }
 
+/// ADR attribute graph nodes are clustered by, so large graphs read as
+/// groups of related decisions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GraphGrouping {
+    /// The `category` frontmatter field.
+    Category,
+    /// The `project` frontmatter field, or the monorepo package.
+    Project,
+    /// The first tag.
+    Tag,
+}
+
+impl GraphGrouping {
+    /// Returns the group an ADR belongs to, if it has a value to group by.
+    #[must_use]
+    pub fn group_of(self, adr: &Adr) -> Option<&str> {
+        let group = match self {
+            Self::Category => Some(adr.category()),
+            Self::Project => Some(adr.project())
+                .filter(|project| !project.is_empty())
+                .or_else(|| adr.package()),
+            Self::Tag => adr.tags().first().map(String::as_str),
+        };
+        group.filter(|group| !group.is_empty())
+    }
+}
+
 /// The type of relationship between two ADRs.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum EdgeType {
     /// A general relationship (from `related` field).
@@ -63,7 +125,7 @@ impl EdgeType {
 }
 
 /// An edge connecting two ADRs in the graph.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Edge {
     /// Source ADR identifier.
     pub source: String,
@@ -118,38 +180,142 @@ impl Graph {
     }
 
     /// Builds a graph from a collection of ADRs.
+    ///
+    /// References in `related`, `supersedes`, and `superseded-by` may be
+    /// plain filenames (`adr_0005.md`) or project-qualified
+    /// (`payments/adr_0003`); see [`ReferenceResolver`] for how they are
+    /// matched to ADRs. A `superseded-by` reference yields the same edge as
+    /// a `supersedes` reference in the other direction, so a supersession
+    /// recorded on both ADRs is one edge. Wikilinks in an ADR's body count
+    /// as `related` references.
+    ///
+    /// Every relationship is one edge: `related` is symmetric, so two ADRs
+    /// that list each other, in frontmatter or wikilinks, are joined once,
+    /// from the first to record it. An ADR related to itself yields no
+    /// edge, and each missing ADR one placeholder node.
     #[must_use]
     pub fn from_adrs(adrs: &[Adr]) -> Self {
-        let mut nodes: Vec<Node> = adrs.iter().map(Node::from_adr).collect();
-        let mut edges: Vec<Edge> = Vec::new();
-
-        // Build a set of known ADR IDs for resolving references
-        let known_ids: std::collections::HashSet<&str> =
-            adrs.iter().map(|a| a.id().as_str()).collect();
+        let resolver = ReferenceResolver::new(adrs);
+        let mut builder = GraphBuilder::default();
+        for adr in adrs {
+            builder.add_node(Node::from_adr(adr));
+        }
 
         // Process relationships
+        let mut inverse: Vec<Edge> = Vec::new();
         for adr in adrs {
             let source_id = adr.id().as_str();
+            let references = adr
+                .related()
+                .iter()
+                .map(|r| (r, EdgeType::Related))
+                .chain(adr.supersedes().iter().map(|r| (r, EdgeType::Supersedes)))
+                .chain(adr.wikilinks().iter().map(|r| (r, EdgeType::Related)));
+
+            for (reference, edge_type) in references {
+                let target_id = builder.resolve(&resolver, adr, reference);
+                builder.add_edge(Edge::new(source_id, target_id, edge_type));
+            }
+            for reference in adr.superseded_by() {
+                let target_id = builder.resolve(&resolver, adr, reference);
+                inverse.push(Edge::supersedes(target_id, source_id));
+            }
+        }
 
-            // Handle `related` references
-            for related_ref in adr.related() {
-                // Extract ID from filename reference (e.g., "adr_0005.md" -> "adr_0005")
-                let target_id = extract_id_from_ref(related_ref);
+        // Supersessions recorded on both ADRs are listed once
+        for edge in inverse {
+            builder.add_edge(edge);
+        }
 
-                // Add edge
-                edges.push(Edge::related(source_id, &target_id));
+        let GraphBuilder {
+            mut nodes, edges, ..
+        } = builder;
+        for node in &mut nodes {
+            node.current = current_decision(&edges, &node.id).map(ToString::to_string);
+        }
 
-                // If target doesn't exist in our collection, add a placeholder node
-                if !known_ids.contains(target_id.as_str()) {
-                    nodes.push(Node::placeholder(&target_id));
-                }
+        let mut graph = Self { nodes, edges };
+        graph.compute_metrics();
+        graph
+    }
+
+    /// Sets each node's degrees and betweenness centrality.
+    ///
+    /// Centrality treats relationships as undirected, since a decision that
+    /// many others relate to is a hub whichever side recorded the link. It
+    /// is computed with Brandes' algorithm and normalized by the number of
+    /// pairs of other nodes.
+    fn compute_metrics(&mut self) {
+        let index: HashMap<&str, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.id.as_str(), i))
+            .collect();
+        let count = self.nodes.len();
+        let mut in_degree = vec![0; count];
+        let mut out_degree = vec![0; count];
+        let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); count];
+        for edge in &self.edges {
+            let (Some(&source), Some(&target)) = (
+                index.get(edge.source.as_str()),
+                index.get(edge.target.as_str()),
+            ) else {
+                continue;
+            };
+            out_degree[source] += 1;
+            in_degree[target] += 1;
+            if source != target && !neighbors[source].contains(&target) {
+                neighbors[source].push(target);
+                neighbors[target].push(source);
             }
         }
 
-        // Remove duplicate nodes (placeholders for ADRs we later found)
-        nodes.dedup_by(|a, b| a.id == b.id);
+        let centrality = betweenness(&neighbors);
+        for (i, node) in self.nodes.iter_mut().enumerate() {
+            node.in_degree = in_degree[i];
+            node.out_degree = out_degree[i];
+            node.centrality = centrality[i];
+        }
+    }
 
-        Self { nodes, edges }
+    /// Follows `supersedes` edges forward from an ADR to the decision that
+    /// currently stands in its place.
+    ///
+    /// Returns `None` if nothing supersedes the ADR. When several ADRs
+    /// supersede the same one, the first is followed; a cycle ends the chain
+    /// at the last ADR not yet visited.
+    #[must_use]
+    pub fn current_decision<'a>(&'a self, id: &'a str) -> Option<&'a str> {
+        current_decision(&self.edges, id)
+    }
+
+    /// Assigns each ADR's node to the group it belongs to.
+    ///
+    /// ADRs without a value to group by, and referenced ADRs missing from
+    /// the collection, stay ungrouped.
+    #[must_use]
+    pub fn with_groups(mut self, adrs: &[Adr], grouping: GraphGrouping) -> Self {
+        let groups: HashMap<&str, &str> = adrs
+            .iter()
+            .filter_map(|adr| Some((adr.id().as_str(), grouping.group_of(adr)?)))
+            .collect();
+        for node in &mut self.nodes {
+            node.group = groups.get(node.id.as_str()).map(ToString::to_string);
+        }
+        self
+    }
+
+    /// Returns the groups nodes are drawn in, in the order first seen.
+    #[must_use]
+    pub fn groups(&self) -> Vec<&str> {
+        let mut groups: Vec<&str> = Vec::new();
+        for group in self.nodes.iter().filter_map(|node| node.group.as_deref()) {
+            if !groups.contains(&group) {
+                groups.push(group);
+            }
+        }
+        groups
     }
 
     /// Returns the number of nodes in the graph.
@@ -177,6 +343,183 @@ impl Default for Graph {
     }
 }
 
+/// Collects a graph's nodes and edges, each once, in the order first added.
+#[derive(Debug, Default)]
+struct GraphBuilder {
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+    ids: HashSet<String>,
+    edge_keys: HashSet<(EdgeType, String, String)>,
+}
+
+impl GraphBuilder {
+    /// Adds a node unless one with the same ID exists.
+    fn add_node(&mut self, node: Node) {
+        if self.ids.insert(node.id.clone()) {
+            self.nodes.push(node);
+        }
+    }
+
+    /// Adds an edge unless it joins an ADR to itself or the same
+    /// relationship exists, in either direction for `related` edges.
+    fn add_edge(&mut self, edge: Edge) {
+        if edge.edge_type == EdgeType::Related && edge.source == edge.target {
+            return;
+        }
+        let (first, second) = if edge.edge_type == EdgeType::Related && edge.target < edge.source {
+            (&edge.target, &edge.source)
+        } else {
+            (&edge.source, &edge.target)
+        };
+        if self
+            .edge_keys
+            .insert((edge.edge_type, first.clone(), second.clone()))
+        {
+            self.edges.push(edge);
+        }
+    }
+
+    /// Resolves a reference made by `from` to the target's ID, adding a
+    /// placeholder node if the target is not in the collection.
+    fn resolve(&mut self, resolver: &ReferenceResolver, from: &Adr, reference: &str) -> String {
+        if let Some(id) = resolver.resolve(from, reference) {
+            return id.to_string();
+        }
+        let target_id = extract_id_from_ref(reference);
+        self.add_node(Node::placeholder(&target_id));
+        target_id
+    }
+}
+
+/// Resolves ADR references to the IDs of ADRs in a collection.
+///
+/// A reference (with or without a `.md` suffix) resolves, in order, to:
+///
+/// 1. the ADR whose ID matches it exactly;
+/// 2. an ADR in the referencing ADR's own package (`adr_0003` from within
+///    `payments` resolves to `payments/adr_0003`);
+/// 3. an ADR whose `project` frontmatter field matches the qualifier of a
+///    project-qualified reference (`payments/adr_0003`).
+#[derive(Debug, Clone)]
+pub struct ReferenceResolver {
+    ids: HashSet<String>,
+    qualified: HashMap<String, String>,
+}
+
+impl ReferenceResolver {
+    /// Creates a resolver over the given ADRs.
+    #[must_use]
+    pub fn new(adrs: &[Adr]) -> Self {
+        let ids = adrs.iter().map(|a| a.id().to_string()).collect();
+        let qualified = adrs
+            .iter()
+            .filter(|a| !a.project().is_empty())
+            .map(|a| {
+                let key = format!("{}/{}", a.project(), extract_id_from_ref(a.filename()));
+                (key, a.id().to_string())
+            })
+            .collect();
+
+        Self { ids, qualified }
+    }
+
+    /// Resolves a reference made by `from`, returning the target ADR's ID.
+    #[must_use]
+    pub fn resolve(&self, from: &Adr, reference: &str) -> Option<&str> {
+        let target = extract_id_from_ref(reference);
+
+        if let Some(id) = self.ids.get(&target) {
+            return Some(id);
+        }
+
+        if let Some(package) = from.package()
+            && let Some(id) = self.ids.get(&format!("{package}/{target}"))
+        {
+            return Some(id);
+        }
+
+        self.qualified.get(&target).map(String::as_str)
+    }
+}
+
+/// Computes the normalized betweenness centrality of each node of an
+/// undirected graph given as adjacency lists, rounded to four decimals.
+#[allow(clippy::cast_precision_loss)]
+fn betweenness(neighbors: &[Vec<usize>]) -> Vec<f64> {
+    let count = neighbors.len();
+    let mut centrality = vec![0.0; count];
+
+    for source in 0..count {
+        // Breadth-first search counting shortest paths from the source
+        let mut order = Vec::with_capacity(count);
+        let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); count];
+        let mut paths = vec![0.0_f64; count];
+        let mut distance: Vec<Option<usize>> = vec![None; count];
+        paths[source] = 1.0;
+        distance[source] = Some(0);
+        let mut queue = std::collections::VecDeque::from([source]);
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            let next = distance[node].map(|d| d + 1);
+            for &neighbor in &neighbors[node] {
+                if distance[neighbor].is_none() {
+                    distance[neighbor] = next;
+                    queue.push_back(neighbor);
+                }
+                if distance[neighbor] == next {
+                    paths[neighbor] += paths[node];
+                    predecessors[neighbor].push(node);
+                }
+            }
+        }
+
+        // Accumulate each node's dependency, farthest nodes first
+        let mut dependency = vec![0.0_f64; count];
+        for &node in order.iter().rev() {
+            for &predecessor in &predecessors[node] {
+                dependency[predecessor] +=
+                    paths[predecessor] / paths[node] * (1.0 + dependency[node]);
+            }
+            if node != source {
+                centrality[node] += dependency[node];
+            }
+        }
+    }
+
+    // Each path was counted from both ends
+    let ordered_pairs = (count.saturating_sub(1) * count.saturating_sub(2)) as f64;
+    centrality
+        .into_iter()
+        .map(|value| {
+            if ordered_pairs > 0.0 {
+                (value / ordered_pairs * 10_000.0).round() / 10_000.0
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// Walks `supersedes` edges forward from `id`; see [`Graph::current_decision`].
+fn current_decision<'a>(edges: &'a [Edge], id: &'a str) -> Option<&'a str> {
+    let mut visited = HashSet::from([id]);
+    let mut current = None;
+    let mut target = id;
+
+    while let Some(edge) = edges
+        .iter()
+        .find(|e| e.edge_type == EdgeType::Supersedes && e.target == target)
+    {
+        if !visited.insert(edge.source.as_str()) {
+            break;
+        }
+        current = Some(edge.source.as_str());
+        target = edge.source.as_str();
+    }
+
+    current
+}
+
 /// Extracts an ADR ID from a reference string.
 ///
 /// Handles formats like "adr_0005.md" or just "adr_0005".
@@ -246,6 +589,126 @@ mod tests {
         assert_eq!(graph.edges[0].target, "adr_0002");
     }
 
+    #[test]
+    fn test_graph_deduplicates_edges() {
+        let adrs = vec![
+            create_test_adr(
+                "adr_0001",
+                vec![
+                    "adr_0002.md".to_string(),
+                    "adr_0002".to_string(),
+                    "adr_0001.md".to_string(),
+                ],
+            ),
+            // Lists the same relationship from the other side
+            create_test_adr("adr_0002", vec!["adr_0001.md".to_string()])
+                .with_wikilinks(vec!["adr_0001".to_string()]),
+            create_superseding_adr("adr_0003", &["adr_0002", "adr_0002.md"]),
+            // Supersedes is directed: the reverse is a different edge
+            create_superseding_adr("adr_0004", &["adr_0003"]),
+            create_superseding_adr("adr_0003", &["adr_0004"]),
+        ];
+
+        let graph = Graph::from_adrs(&adrs);
+
+        // The duplicate adr_0003 keeps the first ADR's node
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(
+            graph.edges,
+            vec![
+                Edge::related("adr_0001", "adr_0002"),
+                Edge::supersedes("adr_0003", "adr_0002"),
+                Edge::supersedes("adr_0004", "adr_0003"),
+                Edge::supersedes("adr_0003", "adr_0004"),
+            ]
+        );
+        let hub = &graph.nodes[1];
+        assert_eq!((hub.in_degree, hub.out_degree), (2, 0));
+    }
+
+    #[test]
+    fn test_graph_wikilink_edges() {
+        let adrs = vec![
+            create_test_adr("adr_0001", vec!["adr_0002.md".to_string()]).with_wikilinks(vec![
+                "adr_0001".to_string(),
+                "adr_0002".to_string(),
+                "adr_0003".to_string(),
+            ]),
+            create_test_adr("adr_0002", vec![]),
+            create_test_adr("adr_0003", vec![]),
+        ];
+
+        let graph = Graph::from_adrs(&adrs);
+
+        // The frontmatter edge is kept once, and self-links are dropped
+        assert_eq!(graph.edges.len(), 2);
+        assert_eq!(graph.edges[1], Edge::related("adr_0001", "adr_0003"));
+    }
+
+    #[test]
+    fn test_graph_with_groups() {
+        let adr = |id: &str, frontmatter: Frontmatter| {
+            Adr::new(
+                AdrId::new(id),
+                format!("{id}.md"),
+                PathBuf::from(format!("{id}.md")),
+                frontmatter.with_related(vec!["adr_0009.md".to_string()]),
+                String::new(),
+                String::new(),
+                String::new(),
+            )
+        };
+        let adrs = vec![
+            adr(
+                "adr_0001",
+                Frontmatter::new("One")
+                    .with_category("database")
+                    .with_tags(vec!["postgres".to_string()]),
+            ),
+            adr("adr_0002", Frontmatter::new("Two").with_project("payments")),
+            adr(
+                "adr_0003",
+                Frontmatter::new("Three").with_category("database"),
+            ),
+        ];
+
+        let graph = Graph::from_adrs(&adrs).with_groups(&adrs, GraphGrouping::Category);
+        let groups: Vec<_> = graph.nodes.iter().map(|n| n.group.as_deref()).collect();
+        assert_eq!(groups, [Some("database"), None, Some("database"), None]);
+        assert_eq!(graph.groups(), ["database"]);
+
+        let graph = Graph::from_adrs(&adrs).with_groups(&adrs, GraphGrouping::Project);
+        assert_eq!(graph.groups(), ["payments"]);
+        let graph = Graph::from_adrs(&adrs).with_groups(&adrs, GraphGrouping::Tag);
+        assert_eq!(graph.groups(), ["postgres"]);
+        assert!(Graph::from_adrs(&adrs).groups().is_empty());
+    }
+
+    #[test]
+    fn test_graph_metrics() {
+        // A hub related to by three leaves, one of which also relates to another
+        let adrs = vec![
+            create_test_adr("hub", vec![]),
+            create_test_adr("a", vec!["hub.md".to_string()]),
+            create_test_adr("b", vec!["hub.md".to_string()]),
+            create_test_adr("c", vec!["hub.md".to_string(), "leaf.md".to_string()]),
+            create_test_adr("leaf", vec![]),
+        ];
+
+        let graph = Graph::from_adrs(&adrs);
+        let node = |id: &str| graph.nodes.iter().find(|n| n.id == id).unwrap();
+
+        assert_eq!((node("hub").in_degree, node("hub").out_degree), (3, 0));
+        assert_eq!((node("c").in_degree, node("c").out_degree), (0, 2));
+        assert_eq!(node("c").degree(), 2);
+        // hub lies on the paths a-b, a-c, b-c, a-leaf, b-leaf: 5 of 6 pairs
+        assert!((node("hub").centrality - 5.0 / 6.0).abs() < 1e-4);
+        // c lies on the paths hub-leaf, a-leaf, b-leaf: 3 of 6 pairs
+        assert!((node("c").centrality - 0.5).abs() < 1e-4);
+        assert!(node("a").centrality.abs() < f64::EPSILON);
+        assert!(node("leaf").centrality.abs() < f64::EPSILON);
+    }
+
     #[test]
     fn test_graph_with_missing_reference() {
         let adrs = vec![create_test_adr(
@@ -260,6 +723,176 @@ mod tests {
         assert!(graph.nodes.iter().any(|n| n.id == "adr_missing"));
     }
 
+    fn create_project_adr(id: &str, project: &str, related: Vec<String>) -> Adr {
+        let frontmatter = Frontmatter::new(format!("Test {id}"))
+            .with_project(project)
+            .with_related(related);
+        Adr::new(
+            AdrId::new(id),
+            format!("{id}.md"),
+            PathBuf::from(format!("{id}.md")),
+            frontmatter,
+            String::new(),
+            String::new(),
+            String::new(),
+        )
+    }
+
+    #[test]
+    fn test_graph_resolves_project_qualified_reference() {
+        let adrs = vec![
+            create_project_adr(
+                "adr_0001",
+                "orders",
+                vec!["payments/adr_0003.md".to_string()],
+            ),
+            create_project_adr("adr_0003", "payments", vec![]),
+        ];
+
+        let graph = Graph::from_adrs(&adrs);
+
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.edges[0].target, "adr_0003");
+    }
+
+    #[test]
+    fn test_graph_resolves_package_references() {
+        let adrs = vec![
+            create_test_adr("adr_0001", vec!["adr_0002.md".to_string()]).with_package("orders"),
+            create_test_adr("adr_0002", vec!["payments/adr_0001".to_string()])
+                .with_package("orders"),
+            create_test_adr("adr_0001", vec![]).with_package("payments"),
+        ];
+
+        let graph = Graph::from_adrs(&adrs);
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edges[0].target, "orders/adr_0002");
+        assert_eq!(graph.edges[1].target, "payments/adr_0001");
+    }
+
+    #[test]
+    fn test_graph_supersedes_edges() {
+        let frontmatter =
+            Frontmatter::new("Replacement").with_supersedes(vec!["adr_0001.md".to_string()]);
+        let adrs = vec![
+            create_test_adr("adr_0001", vec![]),
+            Adr::new(
+                AdrId::new("adr_0002"),
+                "adr_0002.md".to_string(),
+                PathBuf::from("adr_0002.md"),
+                frontmatter,
+                String::new(),
+                String::new(),
+                String::new(),
+            ),
+        ];
+
+        let graph = Graph::from_adrs(&adrs);
+
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(graph.edges[0].edge_type, EdgeType::Supersedes);
+        assert_eq!(graph.edges[0].target, "adr_0001");
+    }
+
+    #[test]
+    fn test_graph_superseded_by_edges() {
+        let adr = |id: &str, frontmatter: Frontmatter| {
+            Adr::new(
+                AdrId::new(id),
+                format!("{id}.md"),
+                PathBuf::from(format!("{id}.md")),
+                frontmatter,
+                String::new(),
+                String::new(),
+                String::new(),
+            )
+        };
+        let adrs = vec![
+            adr(
+                "adr_0001",
+                Frontmatter::new("Old").with_superseded_by(vec!["adr_0002.md".to_string()]),
+            ),
+            adr(
+                "adr_0002",
+                Frontmatter::new("New").with_supersedes(vec!["adr_0001.md".to_string()]),
+            ),
+            adr(
+                "adr_0003",
+                Frontmatter::new("Older").with_superseded_by(vec!["adr_0002".to_string()]),
+            ),
+        ];
+
+        let graph = Graph::from_adrs(&adrs);
+
+        assert_eq!(
+            graph.edges,
+            vec![
+                Edge::supersedes("adr_0002", "adr_0001"),
+                Edge::supersedes("adr_0002", "adr_0003"),
+            ]
+        );
+        assert_eq!(graph.current_decision("adr_0003"), Some("adr_0002"));
+    }
+
+    fn create_superseding_adr(id: &str, supersedes: &[&str]) -> Adr {
+        let frontmatter = Frontmatter::new(format!("Test {id}"))
+            .with_supersedes(supersedes.iter().map(ToString::to_string).collect());
+        Adr::new(
+            AdrId::new(id),
+            format!("{id}.md"),
+            PathBuf::from(format!("{id}.md")),
+            frontmatter,
+            String::new(),
+            String::new(),
+            String::new(),
+        )
+    }
+
+    #[test]
+    fn test_graph_current_decision() {
+        let adrs = vec![
+            create_test_adr("adr_0001", vec![]),
+            create_superseding_adr("adr_0002", &["adr_0001.md"]),
+            create_superseding_adr("adr_0003", &["adr_0002"]),
+            create_test_adr("adr_0004", vec![]),
+        ];
+
+        let graph = Graph::from_adrs(&adrs);
+
+        assert_eq!(graph.current_decision("adr_0001"), Some("adr_0003"));
+        assert_eq!(graph.current_decision("adr_0002"), Some("adr_0003"));
+        assert_eq!(graph.current_decision("adr_0003"), None);
+        assert_eq!(graph.current_decision("adr_0004"), None);
+        assert_eq!(graph.nodes[0].current.as_deref(), Some("adr_0003"));
+        assert!(graph.nodes[2].current.is_none());
+    }
+
+    #[test]
+    fn test_graph_current_decision_cycle() {
+        let adrs = vec![
+            create_superseding_adr("adr_0001", &["adr_0002"]),
+            create_superseding_adr("adr_0002", &["adr_0001"]),
+        ];
+
+        let graph = Graph::from_adrs(&adrs);
+
+        assert_eq!(graph.current_decision("adr_0001"), Some("adr_0002"));
+        assert_eq!(graph.current_decision("adr_0002"), Some("adr_0001"));
+    }
+
+    #[test]
+    fn test_graph_single_placeholder_per_missing_reference() {
+        let adrs = vec![
+            create_test_adr("adr_0001", vec!["adr_missing.md".to_string()]),
+            create_test_adr("adr_0002", vec!["adr_missing.md".to_string()]),
+        ];
+
+        let graph = Graph::from_adrs(&adrs);
+
+        assert_eq!(graph.node_count(), 3);
+    }
+
     #[test]
     fn test_extract_id_from_ref() {
         assert_eq!(extract_id_from_ref("adr_0005.md"), "adr_0005");
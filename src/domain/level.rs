@@ -0,0 +1,113 @@
+//! Impact and risk levels.
+//!
+//! Review boards often triage decisions by how much they affect and how
+//! risky they are. Both are rated on the same ordered scale.
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// A rating of an ADR's impact or risk, from lowest to highest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    /// Little effect; easily reversed.
+    Low,
+    /// Noticeable effect on a few teams or systems.
+    Medium,
+    /// Broad effect; costly to reverse.
+    High,
+    /// Affects the whole organization or carries severe consequences.
+    Critical,
+}
+
+impl Level {
+    /// Returns the level as a lowercase string.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+            Self::Critical => "critical",
+        }
+    }
+
+    /// Returns the display color for this level in hex format.
+    #[must_use]
+    pub const fn color(&self) -> &'static str {
+        match self {
+            Self::Low => "#10b981",      // green
+            Self::Medium => "#f59e0b",   // amber
+            Self::High => "#f97316",     // orange
+            Self::Critical => "#ef4444", // red
+        }
+    }
+
+    /// Returns all levels, lowest first.
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[Self::Low, Self::Medium, Self::High, Self::Critical]
+    }
+}
+
+impl std::fmt::Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "low" => Ok(Self::Low),
+            "medium" => Ok(Self::Medium),
+            "high" => Ok(Self::High),
+            "critical" => Ok(Self::Critical),
+            _ => Err(format!(
+                "invalid level '{s}', expected one of low, medium, high, critical"
+            )),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Level {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_from_str() {
+        assert_eq!("low".parse::<Level>().ok(), Some(Level::Low));
+        assert_eq!("High".parse::<Level>().ok(), Some(Level::High));
+        assert_eq!(" CRITICAL ".parse::<Level>().ok(), Some(Level::Critical));
+        assert!("huge".parse::<Level>().is_err());
+    }
+
+    #[test]
+    fn test_level_order() {
+        assert!(Level::Low < Level::Medium);
+        assert!(Level::High < Level::Critical);
+        assert_eq!(Level::all().len(), 4);
+    }
+
+    #[test]
+    fn test_level_serde() {
+        assert_eq!(
+            serde_json::to_string(&Level::Medium).expect("should serialize"),
+            "\"medium\""
+        );
+        let level: Level = serde_json::from_str("\"Medium\"").expect("should deserialize");
+        assert_eq!(level, Level::Medium);
+        assert!(serde_json::from_str::<Level>("\"huge\"").is_err());
+    }
+}
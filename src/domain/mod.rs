@@ -5,19 +5,35 @@
 
 mod adr;
 mod facets;
+mod filter;
 mod frontmatter;
 mod graph;
+mod level;
+mod query;
+mod search;
 mod stats;
 mod status;
+mod team;
 mod validation;
 
-pub use adr::{Adr, AdrId};
+pub use adr::{Adr, AdrId, find_adrs};
 pub use facets::{Facet, FacetValue, Facets};
-pub use frontmatter::Frontmatter;
-pub use graph::{Edge, EdgeType, Graph, Node};
-pub use stats::AdrStatistics;
-pub use status::Status;
+pub use filter::AdrFilter;
+pub use frontmatter::{Frontmatter, HistoryEntry, Normalization, parse_date};
+pub use graph::{Edge, EdgeType, Graph, GraphGrouping, Node, ReferenceResolver};
+pub use level::Level;
+pub use query::{Clause, QueryField, SearchQuery, Term};
+pub use search::{SearchField, SearchIndex, tokenize};
+pub use stats::{AdrStatistics, BurndownPoint, DEFAULT_TOP_N};
+pub use status::{CustomStatus, Status, status_names};
+pub use team::{OwnerRule, TeamMapping};
 pub use validation::{
-    RecommendedFieldsRule, RequiredFieldsRule, Severity, ValidationIssue, ValidationReport,
-    ValidationRule, Validator, default_rules,
+    BodySectionsRule, CollectionRule, DEFAULT_FILENAME_PATTERN, DEFAULT_ORPHAN_THRESHOLD,
+    DEFAULT_RECOMMENDED_FIELDS, DEFAULT_REQUIRED_FIELDS, DEFAULT_SECTIONS, DateConsistencyRule,
+    DateFormatRule, FieldLengthRule, FilenameRule, MAX_DESCRIPTION_LENGTH, MAX_TITLE_LENGTH,
+    OrphanRule, RecommendedFieldsRule, RelatedLinksRule, RequiredFieldsRule, ReviewDueRule,
+    RuleConfig, RuleLevel, Severity, StaleProposalRule, StatusHistoryRule, StatusValueRule,
+    SupersessionRule, TagFormatRule, TitleStyle, TitleStyleRule, ValidationIssue, ValidationReport,
+    ValidationRule, Validator, VocabularyRule, default_collection_rules, default_rules,
+    rules_with_fields, suppressed_rules,
 };
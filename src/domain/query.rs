@@ -0,0 +1,270 @@
+//! Search query language.
+//!
+//! Queries combine free text with field qualifiers and negation, such as
+//! `tag:database status:accepted postgres -redis`. Words are ANDed, `OR`
+//! separates alternatives, a leading `-` excludes matches, and double quotes
+//! keep spaces in a value (`author:"Platform Team"`). The viewer parses
+//! queries the same way, so searches behave alike in both.
+
+use super::Adr;
+
+/// An ADR attribute a query word can be scoped to with `name:value`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryField {
+    /// `tag:`, any of the tags.
+    Tag,
+    /// `tech:` or `technology:`, any of the technologies.
+    Technology,
+    /// `status:`, the status, custom statuses under their own name.
+    Status,
+    /// `category:`.
+    Category,
+    /// `author:`.
+    Author,
+    /// `project:`.
+    Project,
+    /// `team:`, the owning team.
+    Team,
+}
+
+impl QueryField {
+    /// Returns the field a qualifier names, ignoring case.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "tag" | "tags" => Some(Self::Tag),
+            "tech" | "technology" | "technologies" => Some(Self::Technology),
+            "status" => Some(Self::Status),
+            "category" => Some(Self::Category),
+            "author" => Some(Self::Author),
+            "project" => Some(Self::Project),
+            "team" => Some(Self::Team),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the ADR has the value in this field, ignoring case.
+    #[must_use]
+    pub fn matches(self, adr: &Adr, value: &str) -> bool {
+        let equals = |field: &str| field.to_lowercase() == value.to_lowercase();
+        match self {
+            Self::Tag => adr.tags().iter().any(|tag| equals(tag)),
+            Self::Technology => adr.technologies().iter().any(|tech| equals(tech)),
+            Self::Status => equals(adr.status_name()),
+            Self::Category => equals(adr.category()),
+            Self::Author => equals(adr.author()),
+            Self::Project => equals(adr.project()),
+            Self::Team => adr.team().is_some_and(equals),
+        }
+    }
+}
+
+/// What a clause of a query looks for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Term {
+    /// Text whose every token must begin a token of the ADR's indexed
+    /// fields (see [`SearchIndex`](super::SearchIndex)).
+    Text(String),
+    /// A value a field must have.
+    Field(QueryField, String),
+}
+
+/// A word of a query, possibly negated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Clause {
+    /// True if ADRs matching the term are excluded.
+    pub negated: bool,
+    /// What the clause looks for.
+    pub term: Term,
+}
+
+impl Clause {
+    /// Parses a word, quotes still in place.
+    fn parse(word: &str) -> Option<Self> {
+        let (negated, word) = match word.strip_prefix('-') {
+            Some(rest) if !rest.is_empty() => (true, rest),
+            _ => (false, word),
+        };
+        let term = word
+            .split_once(':')
+            .and_then(|(name, value)| {
+                let value = unquote(value);
+                let field = QueryField::from_name(name)?;
+                (!value.is_empty()).then_some(Term::Field(field, value))
+            })
+            .unwrap_or_else(|| Term::Text(unquote(word)));
+
+        // Words without letters or digits, like a lone "-", match nothing
+        match &term {
+            Term::Text(text) if super::tokenize(text).next().is_none() => None,
+            _ => Some(Self { negated, term }),
+        }
+    }
+}
+
+/// A parsed search query: alternatives, any of which an ADR must satisfy by
+/// matching all of its clauses.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    /// Clauses separated by `OR`, each list ANDed.
+    pub alternatives: Vec<Vec<Clause>>,
+}
+
+impl SearchQuery {
+    /// Parses a query. Every input is a valid query; words that are not
+    /// field qualifiers are searched as text.
+    #[must_use]
+    pub fn parse(input: &str) -> Self {
+        let mut alternatives = vec![Vec::new()];
+        for word in words(input) {
+            if word == "OR" {
+                alternatives.push(Vec::new());
+            } else if let (Some(clause), Some(clauses)) =
+                (Clause::parse(&word), alternatives.last_mut())
+            {
+                clauses.push(clause);
+            }
+        }
+        alternatives.retain(|clauses| !clauses.is_empty());
+        Self { alternatives }
+    }
+
+    /// Returns true if the query has no clauses, matching every ADR.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.alternatives.is_empty()
+    }
+
+    /// Returns the text terms of the query, which are looked up in the
+    /// search index.
+    pub fn texts(&self) -> impl Iterator<Item = &str> {
+        self.alternatives
+            .iter()
+            .flatten()
+            .filter_map(|clause| match &clause.term {
+                Term::Text(text) => Some(text.as_str()),
+                Term::Field(..) => None,
+            })
+    }
+}
+
+/// Splits a query into words at whitespace outside double quotes.
+fn words(input: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut quoted = false;
+    for c in input.chars() {
+        if c == '"' {
+            quoted = !quoted;
+        }
+        if c.is_whitespace() && !quoted {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+        } else {
+            word.push(c);
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/// Removes the double quotes from a value.
+fn unquote(value: &str) -> String {
+    value.replace('"', "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AdrId, Frontmatter, Status};
+    use std::path::PathBuf;
+
+    fn text(negated: bool, text: &str) -> Clause {
+        Clause {
+            negated,
+            term: Term::Text(text.to_string()),
+        }
+    }
+
+    fn field(negated: bool, field: QueryField, value: &str) -> Clause {
+        Clause {
+            negated,
+            term: Term::Field(field, value.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_parse_query() {
+        let query = SearchQuery::parse("tag:database Status:accepted postgres -redis");
+        assert_eq!(
+            query.alternatives,
+            [vec![
+                field(false, QueryField::Tag, "database"),
+                field(false, QueryField::Status, "accepted"),
+                text(false, "postgres"),
+                text(true, "redis"),
+            ]]
+        );
+        assert_eq!(query.texts().collect::<Vec<_>>(), ["postgres", "redis"]);
+    }
+
+    #[test]
+    fn test_parse_query_alternatives_and_quotes() {
+        let query = SearchQuery::parse(
+            "OR kafka OR -tech:\"event store\" author:\"Platform Team\" \"event sourcing\" OR",
+        );
+        assert_eq!(
+            query.alternatives,
+            [
+                vec![text(false, "kafka")],
+                vec![
+                    field(true, QueryField::Technology, "event store"),
+                    field(false, QueryField::Author, "Platform Team"),
+                    text(false, "event sourcing"),
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_query_plain_words() {
+        // Unknown qualifiers, empty values, and punctuation are text
+        let query = SearchQuery::parse("https://example.com tag: - -- c++");
+        assert_eq!(
+            query.alternatives,
+            [vec![
+                text(false, "https://example.com"),
+                text(false, "tag:"),
+                text(false, "c++"),
+            ]]
+        );
+        assert!(SearchQuery::parse("  - OR ").is_empty());
+    }
+
+    #[test]
+    fn test_query_field_matches() {
+        let adr = Adr::new(
+            AdrId::new("adr-0001"),
+            "adr-0001.md".to_string(),
+            PathBuf::from("adr-0001.md"),
+            Frontmatter::new("Use PostgreSQL")
+                .with_status(Status::Accepted)
+                .with_tags(vec!["Database".to_string()])
+                .with_author("Platform Team"),
+            String::new(),
+            String::new(),
+            String::new(),
+        )
+        .with_team(Some("payments".to_string()));
+
+        assert!(QueryField::Tag.matches(&adr, "database"));
+        assert!(!QueryField::Tag.matches(&adr, "data"));
+        assert!(QueryField::Status.matches(&adr, "ACCEPTED"));
+        assert!(QueryField::Author.matches(&adr, "platform team"));
+        assert!(QueryField::Team.matches(&adr, "payments"));
+        assert!(!QueryField::Category.matches(&adr, "database"));
+    }
+}
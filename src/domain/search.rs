@@ -0,0 +1,288 @@
+//! Full-text search index.
+//!
+//! Generate builds an inverted index of the ADR collection, mapping each
+//! token to the ADRs containing it with a score weighted by the field it
+//! appears in, so the viewer looks up query terms instead of scanning every
+//! record's text. Queries are evaluated against it as described in
+//! [`SearchQuery`].
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+
+use super::Adr;
+use super::query::{SearchQuery, Term};
+
+/// A field of an ADR covered by the search index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchField {
+    /// The title.
+    Title,
+    /// The tags.
+    Tags,
+    /// The technologies.
+    Technologies,
+    /// The description.
+    Description,
+    /// The plain-text body.
+    Body,
+}
+
+impl SearchField {
+    /// All indexed fields, highest weight first.
+    pub const ALL: &[Self] = &[
+        Self::Title,
+        Self::Tags,
+        Self::Technologies,
+        Self::Description,
+        Self::Body,
+    ];
+
+    /// Returns the score an occurrence of a token in this field adds.
+    #[must_use]
+    pub const fn weight(self) -> u32 {
+        match self {
+            Self::Title => 10,
+            Self::Tags | Self::Technologies => 5,
+            Self::Description => 3,
+            Self::Body => 1,
+        }
+    }
+
+    /// Returns the text of this field of an ADR.
+    fn text(self, adr: &Adr) -> String {
+        match self {
+            Self::Title => adr.title().to_string(),
+            Self::Tags => adr.tags().join(" "),
+            Self::Technologies => adr.technologies().join(" "),
+            Self::Description => adr.description().to_string(),
+            Self::Body => adr.body_text().to_string(),
+        }
+    }
+}
+
+/// Splits text into lowercase search tokens: runs of letters and digits.
+///
+/// The viewer tokenizes queries the same way, so `event-sourcing` matches
+/// `event sourcing` and `Node.js` matches `node js`.
+pub fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// An inverted index from tokens to the ADRs containing them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SearchIndex {
+    /// Score per occurrence of a token in each indexed field.
+    pub weights: BTreeMap<SearchField, u32>,
+    /// Each token's ADRs, by ID, with their scores.
+    pub tokens: BTreeMap<String, BTreeMap<String, u32>>,
+}
+
+impl SearchIndex {
+    /// Indexes the given fields of the ADRs.
+    ///
+    /// An ADR's score for a token is the sum of the weights of the fields it
+    /// occurs in, once per occurrence.
+    #[must_use]
+    pub fn new(adrs: &[Adr], fields: &[SearchField]) -> Self {
+        let mut tokens: BTreeMap<String, BTreeMap<String, u32>> = BTreeMap::new();
+        for adr in adrs {
+            let mut scores: HashMap<String, u32> = HashMap::new();
+            for &field in fields {
+                for token in tokenize(&field.text(adr)) {
+                    *scores.entry(token).or_default() += field.weight();
+                }
+            }
+            for (token, score) in scores {
+                tokens
+                    .entry(token)
+                    .or_default()
+                    .insert(adr.id().to_string(), score);
+            }
+        }
+
+        Self {
+            weights: fields
+                .iter()
+                .map(|&field| (field, field.weight()))
+                .collect(),
+            tokens,
+        }
+    }
+
+    /// Returns the IDs of the given ADRs matching the query, best match
+    /// first, with their scores.
+    ///
+    /// An ADR's score is the sum of its scores for the text it matches, in
+    /// the best-scoring alternative of the query; ties are in ID order. An
+    /// empty query matches every ADR.
+    #[must_use]
+    pub fn search<'a>(&self, adrs: &'a [Adr], query: &SearchQuery) -> Vec<(&'a str, u32)> {
+        let texts: HashMap<&str, HashMap<&str, u32>> = query
+            .texts()
+            .map(|text| (text, self.text_scores(text)))
+            .collect();
+
+        let mut results: Vec<(&str, u32)> = adrs
+            .iter()
+            .filter_map(|adr| {
+                let id = adr.id().as_str();
+                if query.is_empty() {
+                    return Some((id, 0));
+                }
+                let best = query
+                    .alternatives
+                    .iter()
+                    .filter_map(|clauses| {
+                        clauses.iter().try_fold(0, |total, clause| {
+                            let score = match &clause.term {
+                                Term::Text(text) => texts[text.as_str()].get(id).copied(),
+                                Term::Field(field, value) => field.matches(adr, value).then_some(0),
+                            };
+                            match (score, clause.negated) {
+                                (Some(score), false) => Some(total + score),
+                                (None, true) => Some(total),
+                                _ => None,
+                            }
+                        })
+                    })
+                    .max()?;
+                Some((id, best))
+            })
+            .collect();
+        results.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        results
+    }
+
+    /// Returns the scores of the ADRs having, for every token of the text,
+    /// a token it is a prefix of, so results narrow as a query is typed.
+    /// A score sums the ADR's scores for all tokens matched.
+    fn text_scores(&self, text: &str) -> HashMap<&str, u32> {
+        let mut scores: Option<HashMap<&str, u32>> = None;
+        for term in tokenize(text) {
+            let mut matches: HashMap<&str, u32> = HashMap::new();
+            for (_, postings) in self
+                .tokens
+                .range(term.clone()..)
+                .take_while(|(token, _)| token.starts_with(&term))
+            {
+                for (id, score) in postings {
+                    *matches.entry(id.as_str()).or_default() += score;
+                }
+            }
+            scores = Some(match scores {
+                None => matches,
+                Some(scores) => scores
+                    .into_iter()
+                    .filter_map(|(id, score)| matches.get(id).map(|more| (id, score + more)))
+                    .collect(),
+            });
+        }
+
+        scores.unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AdrId, Frontmatter};
+    use std::path::PathBuf;
+
+    fn adr(id: &str, frontmatter: Frontmatter, body_text: &str) -> Adr {
+        Adr::new(
+            AdrId::new(id),
+            format!("{id}.md"),
+            PathBuf::from(format!("{id}.md")),
+            frontmatter,
+            String::new(),
+            String::new(),
+            body_text.to_string(),
+        )
+    }
+
+    fn sample() -> Vec<Adr> {
+        vec![
+            adr(
+                "adr-0001",
+                Frontmatter::new("Use PostgreSQL")
+                    .with_tags(vec!["database".to_string()])
+                    .with_technologies(vec!["postgres".to_string()]),
+                "We need a relational database. Postgres is familiar.",
+            ),
+            adr(
+                "adr-0002",
+                Frontmatter::new("Cache sessions in Redis"),
+                "Sessions are cached; the database stays the source of truth.",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_tokenize() {
+        let tokens: Vec<String> = tokenize("Event-Sourcing with Node.js (v2), Café").collect();
+        assert_eq!(
+            tokens,
+            ["event", "sourcing", "with", "node", "js", "v2", "café"]
+        );
+    }
+
+    #[test]
+    fn test_index_weights_fields() {
+        let index = SearchIndex::new(&sample(), SearchField::ALL);
+
+        assert_eq!(index.weights[&SearchField::Title], 10);
+        assert_eq!(index.tokens["postgresql"]["adr-0001"], 10);
+        // Once in the tag, once in the body
+        assert_eq!(index.tokens["database"]["adr-0001"], 6);
+        assert_eq!(index.tokens["database"]["adr-0002"], 1);
+        // Once in the technologies, once in the body
+        assert_eq!(index.tokens["postgres"]["adr-0001"], 6);
+        assert!(!index.tokens.contains_key(""));
+    }
+
+    #[test]
+    fn test_index_without_body() {
+        let index = SearchIndex::new(&sample(), &[SearchField::Title]);
+
+        assert_eq!(index.weights.len(), 1);
+        assert!(!index.tokens.contains_key("database"));
+        assert_eq!(index.tokens["sessions"].len(), 1);
+    }
+
+    #[test]
+    fn test_search() {
+        let adrs = sample();
+        let index = SearchIndex::new(&adrs, SearchField::ALL);
+        let search = |query: &str| index.search(&adrs, &SearchQuery::parse(query));
+
+        assert_eq!(search("database"), [("adr-0001", 6), ("adr-0002", 1)]);
+        // Prefixes match every token they begin, here postgres and postgresql
+        assert_eq!(search("Postg"), [("adr-0001", 16)]);
+        // Every term must match
+        assert_eq!(search("database redis"), [("adr-0002", 11)]);
+        assert!(search("database mysql").is_empty());
+        assert_eq!(search("  "), [("adr-0001", 0), ("adr-0002", 0)]);
+    }
+
+    #[test]
+    fn test_search_fields_and_operators() {
+        let adrs = sample();
+        let index = SearchIndex::new(&adrs, SearchField::ALL);
+        let search = |query: &str| index.search(&adrs, &SearchQuery::parse(query));
+
+        assert_eq!(search("tag:database"), [("adr-0001", 0)]);
+        assert_eq!(search("database -redis"), [("adr-0001", 6)]);
+        assert_eq!(search("-tag:DATABASE"), [("adr-0002", 0)]);
+        assert_eq!(search("status:proposed -cache"), [("adr-0001", 0)]);
+        // Alternatives score by their best match
+        assert_eq!(
+            search("redis OR tech:postgres database"),
+            [("adr-0002", 10), ("adr-0001", 6)]
+        );
+        assert!(search("tag:database redis").is_empty());
+    }
+}
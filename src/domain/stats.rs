@@ -1,42 +1,109 @@
 //! Statistics aggregation for ADR collections.
 //!
 //! This module provides types for computing and representing summary
-//! statistics about an ADR collection.
+//! statistics about an ADR collection, including a monthly burndown of the
+//! proposed-ADR backlog.
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use serde::Serialize;
 use time::Date;
 
-use super::{Adr, Status};
+use super::{Adr, CustomStatus, Graph, Level, Status, status_names};
 
 /// Aggregated statistics for an ADR collection.
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct AdrStatistics {
     /// Total number of ADRs.
     pub total_count: usize,
-    /// Counts by status.
-    pub by_status: HashMap<String, usize>,
+    /// Custom statuses in use, listed alongside the built-in ones.
+    #[serde(skip)]
+    pub custom_statuses: Vec<CustomStatus>,
+    /// Counts by status, keyed by the name the status is displayed under.
+    pub by_status: BTreeMap<String, usize>,
     /// Counts by category.
-    pub by_category: HashMap<String, usize>,
+    pub by_category: BTreeMap<String, usize>,
+    /// Counts by category, then status.
+    pub by_category_status: BTreeMap<String, BTreeMap<String, usize>>,
     /// Counts by author.
-    pub by_author: HashMap<String, usize>,
+    pub by_author: BTreeMap<String, usize>,
     /// Counts by tag.
-    pub by_tag: HashMap<String, usize>,
+    pub by_tag: BTreeMap<String, usize>,
     /// Counts by technology.
-    pub by_technology: HashMap<String, usize>,
+    pub by_technology: BTreeMap<String, usize>,
     /// Counts by project.
-    pub by_project: HashMap<String, usize>,
+    pub by_project: BTreeMap<String, usize>,
+    /// Counts by owning team.
+    pub by_team: BTreeMap<String, usize>,
+    /// Counts by impact level.
+    pub by_impact: BTreeMap<String, usize>,
+    /// Counts by risk level.
+    pub by_risk: BTreeMap<String, usize>,
     /// Counts by year.
-    pub by_year: HashMap<i32, usize>,
+    pub by_year: BTreeMap<i32, usize>,
     /// Earliest created date.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub earliest_date: Option<Date>,
     /// Latest created date.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latest_date: Option<Date>,
+    /// Proposed ADRs opened and decided per month, oldest first.
+    pub proposed_burndown: Vec<BurndownPoint>,
+    /// ADRs related to at least one other, most connected first.
+    pub most_connected: Vec<ConnectedAdr>,
 }
 
+/// An ADR's place in the relationship graph.
+///
+/// Decisions with many relationships, or that lie between otherwise
+/// distant ones, are hubs whose changes ripple furthest.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConnectedAdr {
+    /// The ADR identifier.
+    pub id: String,
+    /// The ADR title.
+    pub title: String,
+    /// Number of relationships other ADRs record to this one.
+    pub in_degree: usize,
+    /// Number of relationships this ADR records.
+    pub out_degree: usize,
+    /// Betweenness centrality, from 0 to 1.
+    pub centrality: f64,
+}
+
+impl ConnectedAdr {
+    /// Returns the number of relationships in either direction.
+    #[must_use]
+    pub const fn degree(&self) -> usize {
+        self.in_degree + self.out_degree
+    }
+}
+
+/// Movement of the proposed-ADR backlog in one month.
+///
+/// An ADR is open from its `created` date until it is decided. A decided
+/// ADR (accepted, deprecated, or superseded) counts as closed in the month
+/// of its `updated` date, which `accept` and `deprecate` record; without a
+/// later `updated` date it is taken to have been decided when it was created
+/// and is not counted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BurndownPoint {
+    /// Month, as `YYYY-MM`.
+    pub month: String,
+    /// ADRs proposed during the month.
+    pub opened: usize,
+    /// Proposed ADRs decided during the month.
+    pub closed: usize,
+    /// ADRs still proposed at the end of the month.
+    pub open: usize,
+}
+
+/// Number of values listed per dimension in the text summary by default.
+pub const DEFAULT_TOP_N: usize = 5;
+
+/// Number of recent months shown in the text summary's backlog line.
+const BURNDOWN_SUMMARY_MONTHS: usize = 6;
+
 impl AdrStatistics {
     /// Computes statistics from a collection of ADRs.
     #[must_use]
@@ -45,10 +112,16 @@ impl AdrStatistics {
             total_count: adrs.len(),
             ..Self::default()
         };
+        for status in adrs.iter().filter_map(Adr::custom_status) {
+            if !stats.custom_statuses.contains(status) {
+                stats.custom_statuses.push(status.clone());
+            }
+        }
+        let statuses: Vec<String> = stats.statuses().into_iter().map(str::to_string).collect();
 
         // Initialize all status values with 0
-        for status in Status::all() {
-            stats.by_status.insert(status.as_str().to_string(), 0);
+        for status in &statuses {
+            stats.by_status.insert(status.clone(), 0);
         }
 
         let mut earliest: Option<Date> = None;
@@ -58,7 +131,7 @@ impl AdrStatistics {
             // Count by status
             *stats
                 .by_status
-                .entry(adr.status().as_str().to_string())
+                .entry(adr.status_name().to_string())
                 .or_insert(0) += 1;
 
             // Count by category
@@ -67,6 +140,12 @@ impl AdrStatistics {
                     .by_category
                     .entry(adr.category().to_string())
                     .or_insert(0) += 1;
+                *stats
+                    .by_category_status
+                    .entry(adr.category().to_string())
+                    .or_insert_with(|| statuses.iter().map(|status| (status.clone(), 0)).collect())
+                    .entry(adr.status_name().to_string())
+                    .or_insert(0) += 1;
             }
 
             // Count by author
@@ -92,6 +171,19 @@ impl AdrStatistics {
                     .or_insert(0) += 1;
             }
 
+            // Count by owning team
+            if let Some(team) = adr.team() {
+                *stats.by_team.entry(team.to_string()).or_insert(0) += 1;
+            }
+
+            // Count by impact and risk
+            if let Some(impact) = adr.impact() {
+                *stats.by_impact.entry(impact.to_string()).or_insert(0) += 1;
+            }
+            if let Some(risk) = adr.risk() {
+                *stats.by_risk.entry(risk.to_string()).or_insert(0) += 1;
+            }
+
             // Track date ranges
             if let Some(created) = adr.created() {
                 // Count by year
@@ -109,21 +201,170 @@ impl AdrStatistics {
 
         stats.earliest_date = earliest;
         stats.latest_date = latest;
+        stats.proposed_burndown = Self::burndown(adrs);
+        stats.most_connected = Self::most_connected(adrs);
 
         stats
     }
 
-    /// Returns the top N items from a count map, sorted by count descending.
-    pub fn top_n<S: AsRef<str>>(counts: &HashMap<S, usize>, n: usize) -> Vec<(&str, usize)> {
+    /// Lists the ADRs with relationships, by degree and then centrality.
+    fn most_connected(adrs: &[Adr]) -> Vec<ConnectedAdr> {
+        let mut connected: Vec<ConnectedAdr> = Graph::from_adrs(adrs)
+            .nodes
+            .into_iter()
+            .filter(|node| node.degree() > 0)
+            .filter_map(|node| {
+                Some(ConnectedAdr {
+                    title: node.title?,
+                    id: node.id,
+                    in_degree: node.in_degree,
+                    out_degree: node.out_degree,
+                    centrality: node.centrality,
+                })
+            })
+            .collect();
+        connected.sort_by(|a, b| {
+            b.degree()
+                .cmp(&a.degree())
+                .then(b.centrality.total_cmp(&a.centrality))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        connected
+    }
+
+    /// Computes the monthly proposed-ADR burndown, from the first month an
+    /// ADR was proposed to the last month the backlog changed.
+    fn burndown(adrs: &[Adr]) -> Vec<BurndownPoint> {
+        let month = |date: Date| (date.year(), u8::from(date.month()));
+
+        // (opened, closed) per month
+        let mut changes: BTreeMap<(i32, u8), (usize, usize)> = BTreeMap::new();
+        for adr in adrs {
+            let Some(created) = adr.created() else {
+                continue;
+            };
+            let decided = adr.updated().filter(|updated| *updated > created);
+            if adr.status() != Status::Proposed && decided.is_none() {
+                continue;
+            }
+            changes.entry(month(created)).or_default().0 += 1;
+            if let Some(decided) = decided.filter(|_| adr.status() != Status::Proposed) {
+                changes.entry(month(decided)).or_default().1 += 1;
+            }
+        }
+
+        let (Some(&first), Some(&last)) = (changes.keys().next(), changes.keys().next_back())
+        else {
+            return Vec::new();
+        };
+        let mut points = Vec::new();
+        let mut open = 0;
+        let (mut year, mut month) = first;
+        while (year, month) <= last {
+            let (opened, closed) = changes.get(&(year, month)).copied().unwrap_or_default();
+            open = open + opened - closed;
+            points.push(BurndownPoint {
+                month: format!("{year}-{month:02}"),
+                opened,
+                closed,
+                open,
+            });
+            (year, month) = if month == 12 {
+                (year + 1, 1)
+            } else {
+                (year, month + 1)
+            };
+        }
+        points
+    }
+
+    /// Returns the names of the statuses in the order they are listed: the
+    /// built-in statuses and the custom statuses in use, placed by position.
+    #[must_use]
+    pub fn statuses(&self) -> Vec<&str> {
+        status_names(&self.custom_statuses)
+    }
+
+    /// Returns the category × status cross-tabulation as rows of counts in
+    /// [`Self::statuses`] order, categories with the most proposed ADRs first.
+    #[must_use]
+    pub fn category_status_rows(&self) -> Vec<(&str, Vec<usize>)> {
+        let proposed = |counts: &BTreeMap<String, usize>| {
+            counts.get(Status::Proposed.as_str()).copied().unwrap_or(0)
+        };
+        let mut rows: Vec<_> = self
+            .by_category_status
+            .iter()
+            .map(|(category, counts)| {
+                let row = self
+                    .statuses()
+                    .into_iter()
+                    .map(|status| counts.get(status).copied().unwrap_or(0))
+                    .collect::<Vec<_>>();
+                (category.as_str(), proposed(counts), row)
+            })
+            .collect();
+        rows.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        rows.into_iter()
+            .map(|(category, _, row)| (category, row))
+            .collect()
+    }
+
+    /// Formats the category × status cross-tabulation as a markdown table.
+    #[must_use]
+    pub fn category_status_table(&self) -> String {
+        use std::fmt::Write;
+
+        let mut output = String::from("| Category |");
+        let statuses = self.statuses();
+        for status in &statuses {
+            let _ = write!(output, " {status} |");
+        }
+        output.push_str("\n|----------|");
+        for _ in &statuses {
+            output.push_str("------|");
+        }
+        output.push('\n');
+        for (category, counts) in self.category_status_rows() {
+            let _ = write!(output, "| {category} |");
+            for count in counts {
+                let _ = write!(output, " {count} |");
+            }
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Returns the counts of a level breakdown (`by_impact` or `by_risk`)
+    /// lowest level first, leaving out levels no ADR has.
+    #[must_use]
+    pub fn level_counts(counts: &BTreeMap<String, usize>) -> Vec<(Level, usize)> {
+        Level::all()
+            .iter()
+            .filter_map(|level| Some((*level, *counts.get(level.as_str())?)))
+            .collect()
+    }
+
+    /// Returns the top N items from a count map, sorted by count descending
+    /// and then by name, so ties are listed in a stable order.
+    pub fn top_n<S: AsRef<str>>(counts: &BTreeMap<S, usize>, n: usize) -> Vec<(&str, usize)> {
         let mut items: Vec<_> = counts.iter().map(|(k, &v)| (k.as_ref(), v)).collect();
-        items.sort_by(|a, b| b.1.cmp(&a.1));
+        items.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
         items.truncate(n);
         items
     }
 
-    /// Formats the statistics as a human-readable summary string.
+    /// Formats the statistics as a human-readable summary string, listing
+    /// the top [`DEFAULT_TOP_N`] values per dimension.
     #[must_use]
     pub fn summary(&self) -> String {
+        self.summary_limited(Some(DEFAULT_TOP_N))
+    }
+
+    /// Formats the statistics as a human-readable summary string, listing
+    /// at most `limit` values per dimension, or all of them when `None`.
+    #[must_use]
+    pub fn summary_limited(&self, limit: Option<usize>) -> String {
         use std::fmt::Write;
 
         let mut output = String::new();
@@ -133,9 +374,8 @@ impl AdrStatistics {
 
         // Status breakdown
         let mut status_parts: Vec<String> = Vec::new();
-        for status in Status::all() {
-            let key = status.as_str().to_string();
-            let count = self.by_status.get(&key).copied().unwrap_or(0);
+        for status in self.statuses() {
+            let count = self.by_status.get(status).copied().unwrap_or(0);
             if count > 0 {
                 status_parts.push(format!("{} ({})", status, count));
             }
@@ -144,18 +384,77 @@ impl AdrStatistics {
             let _ = writeln!(output, "By Status: {}", status_parts.join(", "));
         }
 
-        // Category breakdown (top 5)
-        if !self.by_category.is_empty() {
-            let top = Self::top_n(&self.by_category, 5);
-            let parts: Vec<String> = top.iter().map(|(k, v)| format!("{k} ({v})")).collect();
-            let _ = writeln!(output, "By Category: {}", parts.join(", "));
+        // Impact and risk, lowest level first
+        for (label, counts) in [("By Impact", &self.by_impact), ("By Risk", &self.by_risk)] {
+            let parts: Vec<String> = Self::level_counts(counts)
+                .iter()
+                .map(|(level, count)| format!("{level} ({count})"))
+                .collect();
+            if !parts.is_empty() {
+                let _ = writeln!(output, "{label}: {}", parts.join(", "));
+            }
         }
 
-        // Author breakdown (top 5)
-        if !self.by_author.is_empty() {
-            let top = Self::top_n(&self.by_author, 5);
+        // Breakdowns by dimension
+        for (label, counts) in [
+            ("By Category", &self.by_category),
+            ("Teams", &self.by_team),
+            ("Authors", &self.by_author),
+            ("Tags", &self.by_tag),
+            ("Technologies", &self.by_technology),
+            ("Projects", &self.by_project),
+        ] {
+            if counts.is_empty() {
+                continue;
+            }
+            let top = Self::top_n(counts, limit.unwrap_or(counts.len()));
             let parts: Vec<String> = top.iter().map(|(k, v)| format!("{k} ({v})")).collect();
-            let _ = writeln!(output, "Authors: {}", parts.join(", "));
+            let more = counts.len() - top.len();
+            if more > 0 {
+                let _ = writeln!(output, "{label}: {}, +{more} more", parts.join(", "));
+            } else {
+                let _ = writeln!(output, "{label}: {}", parts.join(", "));
+            }
+        }
+
+        // Hub decisions
+        if !self.most_connected.is_empty() {
+            let top =
+                &self.most_connected[..limit.unwrap_or(usize::MAX).min(self.most_connected.len())];
+            let parts: Vec<String> = top
+                .iter()
+                .map(|adr| format!("{} ({})", adr.id, adr.degree()))
+                .collect();
+            let more = self.most_connected.len() - top.len();
+            if more > 0 {
+                let _ = writeln!(output, "Most Connected: {}, +{more} more", parts.join(", "));
+            } else {
+                let _ = writeln!(output, "Most Connected: {}", parts.join(", "));
+            }
+        }
+
+        // Category x status
+        let rows = self.category_status_rows();
+        if !rows.is_empty() {
+            let width = rows
+                .iter()
+                .map(|(category, _)| category.len())
+                .max()
+                .unwrap_or(0)
+                .max("Category".len());
+            let _ = writeln!(output, "By Category and Status:");
+            let _ = write!(output, "  {:width$}", "Category");
+            for status in self.statuses() {
+                let _ = write!(output, "  {status:>10}");
+            }
+            let _ = writeln!(output);
+            for (category, counts) in rows {
+                let _ = write!(output, "  {category:width$}");
+                for count in counts {
+                    let _ = write!(output, "  {count:>10}");
+                }
+                let _ = writeln!(output);
+            }
         }
 
         // Date range
@@ -166,6 +465,19 @@ impl AdrStatistics {
             _ => {},
         }
 
+        // Proposed backlog (recent months)
+        if !self.proposed_burndown.is_empty() {
+            let recent = &self.proposed_burndown[self
+                .proposed_burndown
+                .len()
+                .saturating_sub(BURNDOWN_SUMMARY_MONTHS)..];
+            let parts: Vec<String> = recent
+                .iter()
+                .map(|point| format!("{} ({})", point.month, point.open))
+                .collect();
+            let _ = writeln!(output, "Proposed Backlog: {}", parts.join(", "));
+        }
+
         output
     }
 }
@@ -173,7 +485,7 @@ impl AdrStatistics {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::{AdrId, Frontmatter};
+    use crate::domain::{AdrId, CustomStatus, Frontmatter, Normalization};
     use std::path::PathBuf;
     use time::macros::date;
 
@@ -264,9 +576,175 @@ mod tests {
         assert_eq!(stats.latest_date, Some(date!(2025 - 06 - 15)));
     }
 
+    #[test]
+    fn test_category_status_crosstab() {
+        let adrs = vec![
+            create_test_adr("ADR 1", Status::Accepted, "api"),
+            create_test_adr("ADR 2", Status::Proposed, "security"),
+            create_test_adr("ADR 3", Status::Proposed, "security"),
+            create_test_adr("ADR 4", Status::Deprecated, "api"),
+            create_test_adr("ADR 5", Status::Proposed, ""),
+        ];
+
+        let stats = AdrStatistics::from_adrs(&adrs);
+
+        assert_eq!(stats.by_category_status["api"]["accepted"], 1);
+        assert_eq!(stats.by_category_status["api"]["proposed"], 0);
+        assert_eq!(
+            stats.category_status_rows(),
+            vec![("security", vec![2, 0, 0, 0]), ("api", vec![0, 1, 1, 0])]
+        );
+        assert!(stats.category_status_table().contains(
+            "| Category | proposed | accepted | deprecated | superseded |\n|----------|------|------|------|------|\n| security | 2 | 0 | 0 | 0 |\n"
+        ));
+        assert!(stats.summary().contains(
+            "  Category    proposed    accepted  deprecated  superseded\n  security           2           0           0           0\n"
+        ));
+    }
+
+    #[test]
+    fn test_statistics_custom_statuses() {
+        let custom = [
+            CustomStatus::new("draft", Status::Proposed).with_order(5),
+            CustomStatus::new("on-hold", Status::Proposed),
+        ];
+        let mut frontmatter = Frontmatter::new("Draft").with_category("api");
+        frontmatter.normalized.push(Normalization {
+            field: "status".to_string(),
+            original: "draft".to_string(),
+            normalized: "proposed".to_string(),
+        });
+        let draft = Adr::new(
+            AdrId::new("draft"),
+            "draft.md".to_string(),
+            PathBuf::from("draft.md"),
+            frontmatter,
+            String::new(),
+            String::new(),
+            String::new(),
+        )
+        .with_custom_statuses(&custom);
+        let adrs = vec![draft, create_test_adr("ADR 2", Status::Accepted, "api")];
+
+        let stats = AdrStatistics::from_adrs(&adrs);
+        assert_eq!(
+            stats.statuses(),
+            vec!["draft", "proposed", "accepted", "deprecated", "superseded"]
+        );
+        assert_eq!(stats.by_status["draft"], 1);
+        assert_eq!(stats.by_status["proposed"], 0);
+        assert_eq!(
+            stats.category_status_rows(),
+            vec![("api", vec![1, 0, 1, 0, 0])]
+        );
+        assert!(
+            stats
+                .summary()
+                .contains("By Status: draft (1), accepted (1)")
+        );
+    }
+
+    #[test]
+    fn test_proposed_burndown() {
+        let adr = |id: &str, status, created, updated: Option<Date>| {
+            let mut frontmatter = Frontmatter::new(id)
+                .with_status(status)
+                .with_created(created);
+            frontmatter.updated = updated;
+            Adr::new(
+                AdrId::new(id),
+                format!("{id}.md"),
+                PathBuf::from(format!("{id}.md")),
+                frontmatter,
+                String::new(),
+                String::new(),
+                String::new(),
+            )
+        };
+        let adrs = vec![
+            adr("1", Status::Proposed, date!(2024 - 11 - 03), None),
+            adr(
+                "2",
+                Status::Accepted,
+                date!(2024 - 11 - 20),
+                Some(date!(2025 - 02 - 01)),
+            ),
+            // Accepted when written: never part of the backlog
+            adr("3", Status::Accepted, date!(2025 - 01 - 10), None),
+            adr("4", Status::Proposed, date!(2025 - 01 - 12), None),
+        ];
+
+        let burndown = AdrStatistics::from_adrs(&adrs).proposed_burndown;
+        let series: Vec<_> = burndown
+            .iter()
+            .map(|point| (point.month.as_str(), point.opened, point.closed, point.open))
+            .collect();
+        assert_eq!(
+            series,
+            vec![
+                ("2024-11", 2, 0, 2),
+                ("2024-12", 0, 0, 2),
+                ("2025-01", 1, 0, 3),
+                ("2025-02", 0, 1, 2),
+            ]
+        );
+        assert!(
+            AdrStatistics::from_adrs(&adrs)
+                .summary()
+                .contains("Proposed Backlog: 2024-11 (2), 2024-12 (2), 2025-01 (3), 2025-02 (2)")
+        );
+        assert!(AdrStatistics::from_adrs(&[]).proposed_burndown.is_empty());
+    }
+
+    #[test]
+    fn test_most_connected() {
+        let adr = |id: &str, related: &[&str]| {
+            let related = related
+                .iter()
+                .map(|target| format!("{target}.md"))
+                .collect();
+            Adr::new(
+                AdrId::new(id),
+                format!("{id}.md"),
+                PathBuf::from(format!("{id}.md")),
+                Frontmatter::new(format!("ADR {id}")).with_related(related),
+                String::new(),
+                String::new(),
+                String::new(),
+            )
+        };
+        let adrs = vec![
+            adr("hub", &[]),
+            adr("a", &["hub"]),
+            adr("b", &["hub"]),
+            adr("c", &["hub", "missing"]),
+            adr("alone", &[]),
+        ];
+
+        let stats = AdrStatistics::from_adrs(&adrs);
+        let ranked: Vec<_> = stats
+            .most_connected
+            .iter()
+            .map(|adr| (adr.id.as_str(), adr.in_degree, adr.out_degree))
+            .collect();
+        // Unconnected ADRs and missing targets are left out
+        assert_eq!(
+            ranked,
+            vec![("hub", 3, 0), ("c", 0, 2), ("a", 0, 1), ("b", 0, 1)]
+        );
+        assert_eq!(stats.most_connected[0].title, "ADR hub");
+        assert!(stats.most_connected[0].centrality > stats.most_connected[1].centrality);
+        assert!(
+            stats
+                .summary_limited(Some(2))
+                .contains("Most Connected: hub (3), c (2), +2 more\n")
+        );
+        assert!(AdrStatistics::from_adrs(&[]).most_connected.is_empty());
+    }
+
     #[test]
     fn test_top_n() {
-        let mut counts = HashMap::new();
+        let mut counts = BTreeMap::new();
         counts.insert("a", 10);
         counts.insert("b", 5);
         counts.insert("c", 20);
@@ -277,6 +755,37 @@ mod tests {
         assert_eq!(top.len(), 2);
         assert_eq!(top[0], ("c", 20));
         assert_eq!(top[1], ("a", 10));
+
+        counts.insert("e", 5);
+        assert_eq!(
+            AdrStatistics::top_n(&counts, 10),
+            vec![("c", 20), ("a", 10), ("b", 5), ("e", 5), ("d", 1)]
+        );
+    }
+
+    #[test]
+    fn test_summary_limited() {
+        let adrs: Vec<_> = ["a", "b", "c", "d", "e", "f", "g"]
+            .iter()
+            .map(|category| create_test_adr("ADR", Status::Accepted, category))
+            .collect();
+        let stats = AdrStatistics::from_adrs(&adrs);
+
+        assert!(
+            stats
+                .summary()
+                .contains("By Category: a (1), b (1), c (1), d (1), e (1), +2 more\n")
+        );
+        assert!(
+            stats
+                .summary_limited(Some(2))
+                .contains("By Category: a (1), b (1), +5 more\n")
+        );
+        assert!(
+            stats
+                .summary_limited(None)
+                .contains("By Category: a (1), b (1), c (1), d (1), e (1), f (1), g (1)\n")
+        );
     }
 
     #[test]
@@ -441,6 +950,63 @@ mod tests {
         assert_eq!(stats.by_project.get("project-beta"), Some(&1));
     }
 
+    #[test]
+    fn test_statistics_by_level() {
+        let with_levels = |title: &str, impact: Level, risk: Option<Level>| {
+            let mut frontmatter = Frontmatter::new(title).with_impact(impact);
+            frontmatter.risk = risk;
+            Adr::new(
+                AdrId::new(title),
+                format!("{title}.md"),
+                PathBuf::from(format!("{title}.md")),
+                frontmatter,
+                String::new(),
+                String::new(),
+                String::new(),
+            )
+        };
+        let adrs = vec![
+            with_levels("a", Level::High, Some(Level::Low)),
+            with_levels("b", Level::Low, None),
+            with_levels("c", Level::High, Some(Level::Critical)),
+        ];
+
+        let stats = AdrStatistics::from_adrs(&adrs);
+
+        assert_eq!(
+            AdrStatistics::level_counts(&stats.by_impact),
+            vec![(Level::Low, 1), (Level::High, 2)]
+        );
+        assert_eq!(
+            AdrStatistics::level_counts(&stats.by_risk),
+            vec![(Level::Low, 1), (Level::Critical, 1)]
+        );
+        let summary = stats.summary();
+        assert!(summary.contains("By Impact: low (1), high (2)\n"));
+        assert!(summary.contains("By Risk: low (1), critical (1)\n"));
+    }
+
+    #[test]
+    fn test_statistics_by_team() {
+        let adrs = vec![
+            create_test_adr("ADR 1", Status::Accepted, "arch").with_team(Some("platform".into())),
+            create_test_adr("ADR 2", Status::Proposed, "arch").with_team(Some("platform".into())),
+            create_test_adr("ADR 3", Status::Accepted, "api").with_team(Some("payments".into())),
+            create_test_adr("ADR 4", Status::Accepted, "api"),
+        ];
+
+        let stats = AdrStatistics::from_adrs(&adrs);
+
+        assert_eq!(stats.by_team.get("platform"), Some(&2));
+        assert_eq!(stats.by_team.get("payments"), Some(&1));
+        assert_eq!(stats.by_team.len(), 2);
+        assert!(
+            stats
+                .summary()
+                .contains("Teams: platform (2), payments (1)\n")
+        );
+    }
+
     #[test]
     fn test_statistics_by_tag() {
         let fm1 = Frontmatter::new("ADR 1")
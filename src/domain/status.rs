@@ -1,7 +1,9 @@
 //! ADR status lifecycle states.
 //!
 //! Status represents the lifecycle state of an Architecture Decision Record,
-//! following the structured-madr specification.
+//! following the structured-madr specification. Organizations with their own
+//! vocabulary can define [`CustomStatus`]es on top of it; each one counts as
+//! a built-in status for lifecycle rules but is displayed under its own name.
 
 use serde::{Deserialize, Serialize};
 
@@ -54,6 +56,19 @@ impl Status {
         }
     }
 
+    /// Returns the position of this status when statuses are listed.
+    ///
+    /// Custom statuses are placed relative to these values.
+    #[must_use]
+    pub const fn order(&self) -> i32 {
+        match self {
+            Self::Proposed => 10,
+            Self::Accepted => 20,
+            Self::Deprecated => 30,
+            Self::Superseded => 40,
+        }
+    }
+
     /// Returns all possible status values.
     #[must_use]
     pub const fn all() -> &'static [Self] {
@@ -86,6 +101,128 @@ impl std::str::FromStr for Status {
     }
 }
 
+/// Position of custom statuses that do not set one, after the built-in ones.
+const DEFAULT_CUSTOM_ORDER: i32 = 50;
+
+/// A status defined in configuration, such as `draft`, `rejected`, or `on-hold`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomStatus {
+    /// Name written in the `status` field, matched case-insensitively.
+    pub name: String,
+    /// Built-in status it counts as for lifecycle rules (default `proposed`).
+    #[serde(default)]
+    pub base: Status,
+    /// Display color in hex format, defaulting to the base status color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Emoji shown next to the name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+    /// Position when statuses are listed; built-in statuses are 10 to 40.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<i32>,
+}
+
+impl CustomStatus {
+    /// Creates a custom status counting as the given built-in status.
+    #[must_use]
+    pub fn new(name: impl Into<String>, base: Status) -> Self {
+        Self {
+            name: name.into(),
+            base,
+            color: None,
+            emoji: None,
+            order: None,
+        }
+    }
+
+    /// Sets the display color.
+    #[must_use]
+    pub fn with_color(mut self, color: impl Into<String>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the emoji shown next to the name.
+    #[must_use]
+    pub fn with_emoji(mut self, emoji: impl Into<String>) -> Self {
+        self.emoji = Some(emoji.into());
+        self
+    }
+
+    /// Sets the position when statuses are listed.
+    #[must_use]
+    pub const fn with_order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Returns the display color, falling back to the base status color.
+    #[must_use]
+    pub fn display_color(&self) -> &str {
+        self.color.as_deref().unwrap_or_else(|| self.base.color())
+    }
+
+    /// Returns the position when statuses are listed.
+    #[must_use]
+    pub fn position(&self) -> i32 {
+        self.order.unwrap_or(DEFAULT_CUSTOM_ORDER)
+    }
+
+    /// Checks that the status can be used as a name and CSS class.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message if the name is empty, contains characters other
+    /// than lowercase letters, digits, `-`, and `_`, or is a built-in status,
+    /// or if the color is not a hex color.
+    pub fn check(&self) -> Result<(), String> {
+        let valid_name = !self.name.is_empty()
+            && self
+                .name
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'_');
+        if !valid_name {
+            return Err(format!(
+                "status '{}' must use lowercase letters, digits, '-', and '_'",
+                self.name
+            ));
+        }
+        if self.name.parse::<Status>().is_ok() {
+            return Err(format!("status '{}' is already built in", self.name));
+        }
+        if let Some(color) = &self.color {
+            let hex = color.strip_prefix('#').unwrap_or_default();
+            if !matches!(hex.len(), 3 | 6) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(format!(
+                    "status '{}' color '{color}' must be a hex color such as #8b5cf6",
+                    self.name
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the names of the built-in statuses and the given custom statuses
+/// in the order they are listed.
+#[must_use]
+pub fn status_names<'a>(custom: impl IntoIterator<Item = &'a CustomStatus>) -> Vec<&'a str> {
+    let mut statuses: Vec<(i32, &str)> = Status::all()
+        .iter()
+        .map(|status| (status.order(), status.as_str()))
+        .collect();
+    for status in custom {
+        if !statuses.iter().any(|(_, name)| *name == status.name) {
+            statuses.push((status.position(), &status.name));
+        }
+    }
+    // Stable, so custom statuses sharing a position keep their given order
+    statuses.sort_by_key(|(order, _)| *order);
+    statuses.into_iter().map(|(_, name)| name).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,4 +294,66 @@ mod tests {
         let status: Status = serde_json::from_str("\"proposed\"").expect("should parse");
         assert_eq!(status, Status::Proposed);
     }
+
+    #[test]
+    fn test_custom_status_order() {
+        let statuses = [
+            CustomStatus::new("on-hold", Status::Proposed),
+            CustomStatus::new("draft", Status::Proposed).with_order(5),
+            CustomStatus::new("rejected", Status::Deprecated).with_order(35),
+        ];
+        assert_eq!(
+            status_names(&statuses),
+            vec![
+                "draft",
+                "proposed",
+                "accepted",
+                "deprecated",
+                "rejected",
+                "superseded",
+                "on-hold"
+            ]
+        );
+        assert_eq!(statuses[0].display_color(), Status::Proposed.color());
+        assert_eq!(
+            statuses[2].clone().with_color("#111").display_color(),
+            "#111"
+        );
+    }
+
+    #[test]
+    fn test_custom_status_check() {
+        assert!(
+            CustomStatus::new("on-hold", Status::Proposed)
+                .check()
+                .is_ok()
+        );
+        assert!(
+            CustomStatus::new("On Hold", Status::Proposed)
+                .check()
+                .is_err()
+        );
+        assert!(
+            CustomStatus::new("Accepted", Status::Accepted)
+                .check()
+                .is_err()
+        );
+        assert!(
+            CustomStatus::new("accepted", Status::Accepted)
+                .check()
+                .is_err()
+        );
+        assert!(
+            CustomStatus::new("draft", Status::Proposed)
+                .with_color("purple")
+                .check()
+                .is_err()
+        );
+        assert!(
+            CustomStatus::new("draft", Status::Proposed)
+                .with_color("#8b5cf6")
+                .check()
+                .is_ok()
+        );
+    }
 }
@@ -0,0 +1,212 @@
+//! Team ownership of ADRs.
+//!
+//! Author strings drift as people change names, spelling, and teams, so ADRs
+//! can be attributed to an owning team instead. Ownership is resolved from
+//! CODEOWNERS-style path rules first, then from the members listed for each
+//! team.
+
+use std::collections::BTreeMap;
+use std::path::{Component, Path};
+
+use glob::Pattern;
+use serde::Deserialize;
+
+use super::Adr;
+
+/// A CODEOWNERS-style rule assigning the ADR files under a path to a team.
+///
+/// ```toml
+/// [[owners]]
+/// path = "services/payments/"
+/// team = "payments"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OwnerRule {
+    /// Glob pattern matched against the ADR file path. A pattern ending in
+    /// `/` matches every file beneath that directory.
+    pub path: String,
+    /// Team owning the matching ADRs.
+    pub team: String,
+}
+
+impl OwnerRule {
+    /// Creates a rule assigning files matching `path` to `team`.
+    #[must_use]
+    pub fn new(path: impl Into<String>, team: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            team: team.into(),
+        }
+    }
+
+    /// Compiles the path into a glob pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not a valid glob pattern.
+    pub fn pattern(&self) -> Result<Pattern, glob::PatternError> {
+        let path = self.path.trim_start_matches('/');
+        if path.ends_with('/') {
+            Pattern::new(&format!("{path}**"))
+        } else {
+            Pattern::new(path)
+        }
+    }
+
+    /// Returns true if the rule matches the given file path.
+    fn matches(&self, path: &str) -> bool {
+        self.pattern().is_ok_and(|pattern| pattern.matches(path))
+    }
+}
+
+/// Maps ADRs to the teams that own them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TeamMapping {
+    /// Authors belonging to each team.
+    members: BTreeMap<String, Vec<String>>,
+    /// Path rules, the last matching rule winning.
+    owners: Vec<OwnerRule>,
+}
+
+impl TeamMapping {
+    /// Creates a mapping from team members and path rules.
+    #[must_use]
+    pub const fn new(members: BTreeMap<String, Vec<String>>, owners: Vec<OwnerRule>) -> Self {
+        Self { members, owners }
+    }
+
+    /// Returns true if the mapping assigns no ADR to any team.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty() && self.owners.is_empty()
+    }
+
+    /// Returns the team owning an ADR.
+    ///
+    /// As in a CODEOWNERS file, the last path rule matching the ADR file
+    /// wins. Without a matching rule, the team listing the ADR's author is
+    /// used; authors are compared case-insensitively.
+    #[must_use]
+    pub fn team_for(&self, adr: &Adr) -> Option<&str> {
+        let path = normalize_path(adr.source_path());
+        self.owners
+            .iter()
+            .rev()
+            .find(|rule| rule.matches(&path))
+            .map(|rule| rule.team.as_str())
+            .or_else(|| self.team_of_author(adr.author()))
+    }
+
+    /// Returns the first team listing the given author as a member.
+    fn team_of_author(&self, author: &str) -> Option<&str> {
+        let author = author.trim();
+        if author.is_empty() {
+            return None;
+        }
+        self.members
+            .iter()
+            .find(|(_, members)| {
+                members
+                    .iter()
+                    .any(|member| member.trim().eq_ignore_ascii_case(author))
+            })
+            .map(|(team, _)| team.as_str())
+    }
+}
+
+/// Joins a path with `/`, dropping leading `./` and root components so it
+/// can be matched like a CODEOWNERS path.
+fn normalize_path(path: &Path) -> String {
+    path.components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part.to_string_lossy()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AdrId, Frontmatter};
+    use std::path::PathBuf;
+
+    fn adr(path: &str, author: &str) -> Adr {
+        Adr::new(
+            AdrId::new("adr_0001"),
+            "adr_0001.md".to_string(),
+            PathBuf::from(path),
+            Frontmatter::new("Test").with_author(author),
+            String::new(),
+            String::new(),
+            String::new(),
+        )
+    }
+
+    fn mapping() -> TeamMapping {
+        TeamMapping::new(
+            BTreeMap::from([
+                (
+                    "platform".to_string(),
+                    vec!["Alice".to_string(), "Bob Smith".to_string()],
+                ),
+                ("payments".to_string(), vec!["Carol".to_string()]),
+            ]),
+            vec![
+                OwnerRule::new("services/", "platform"),
+                OwnerRule::new("/services/payments/", "payments"),
+                OwnerRule::new("docs/adr/*-security-*.md", "security"),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_team_from_author() {
+        let teams = mapping();
+        assert_eq!(
+            teams.team_for(&adr("docs/adr/adr-0001.md", "bob smith")),
+            Some("platform")
+        );
+        assert_eq!(
+            teams.team_for(&adr("docs/adr/adr-0002.md", "Carol")),
+            Some("payments")
+        );
+        assert_eq!(teams.team_for(&adr("docs/adr/adr-0003.md", "Dave")), None);
+        assert_eq!(teams.team_for(&adr("docs/adr/adr-0004.md", "")), None);
+    }
+
+    #[test]
+    fn test_team_from_path_rules() {
+        let teams = mapping();
+        // The last matching rule wins, and path rules take precedence over authors
+        assert_eq!(
+            teams.team_for(&adr("./services/payments/docs/adr/0001.md", "Alice")),
+            Some("payments")
+        );
+        assert_eq!(
+            teams.team_for(&adr("services/search/docs/adr/0001.md", "Carol")),
+            Some("platform")
+        );
+        assert_eq!(
+            teams.team_for(&adr("docs/adr/0007-security-review.md", "Carol")),
+            Some("security")
+        );
+    }
+
+    #[test]
+    fn test_empty_mapping() {
+        let teams = TeamMapping::default();
+        assert!(teams.is_empty());
+        assert_eq!(teams.team_for(&adr("docs/adr/adr-0001.md", "Alice")), None);
+        assert!(!mapping().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_pattern_never_matches() {
+        let rule = OwnerRule::new("docs/[", "docs");
+        assert!(rule.pattern().is_err());
+        assert!(!rule.matches("docs/["));
+    }
+}
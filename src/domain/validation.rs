@@ -3,9 +3,14 @@
 //! This module provides a validation framework for checking ADRs against
 //! configurable rules, producing detailed reports.
 
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 
-use super::Adr;
+use regex::Regex;
+use serde::Deserialize;
+use time::{Date, OffsetDateTime};
+
+use super::{Adr, EdgeType, Graph, HistoryEntry, ReferenceResolver, Status};
 
 /// Severity level for validation issues.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -46,6 +51,8 @@ pub struct ValidationIssue {
     pub line: Option<usize>,
     /// Name of the rule that produced this issue.
     pub rule: String,
+    /// Stable code of the rule that produced this issue (e.g. `V0001`).
+    pub code: Option<String>,
 }
 
 impl ValidationIssue {
@@ -63,6 +70,7 @@ impl ValidationIssue {
             message: message.into(),
             line: None,
             rule: rule.into(),
+            code: None,
         }
     }
 
@@ -84,6 +92,21 @@ impl ValidationIssue {
         self.line = Some(line);
         self
     }
+
+    /// Sets the rule code.
+    #[must_use]
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Returns the rule label shown in reports, e.g. `V0001 required-fields`.
+    #[must_use]
+    pub fn label(&self) -> String {
+        self.code
+            .as_ref()
+            .map_or_else(|| self.rule.clone(), |code| format!("{code} {}", self.rule))
+    }
 }
 
 impl std::fmt::Display for ValidationIssue {
@@ -96,7 +119,7 @@ impl std::fmt::Display for ValidationIssue {
             self.path.display(),
             location,
             self.message,
-            self.rule
+            self.label()
         )
     }
 }
@@ -106,6 +129,8 @@ impl std::fmt::Display for ValidationIssue {
 pub struct ValidationReport {
     /// All issues found during validation.
     issues: Vec<ValidationIssue>,
+    /// Issues of rules suppressed for their ADR, kept apart from `issues`.
+    suppressed: Vec<ValidationIssue>,
 }
 
 impl ValidationReport {
@@ -131,6 +156,26 @@ impl ValidationReport {
         &self.issues
     }
 
+    /// Returns the issues of suppressed rules, which count neither as
+    /// errors nor as warnings.
+    #[must_use]
+    pub fn suppressed(&self) -> &[ValidationIssue] {
+        &self.suppressed
+    }
+
+    /// Moves the issues of the given rules, named by name or code, to the
+    /// suppressed issues.
+    pub fn suppress(&mut self, rules: &[String]) {
+        let (suppressed, issues): (Vec<_>, Vec<_>) = std::mem::take(&mut self.issues)
+            .into_iter()
+            .partition(|issue| {
+                let code = issue.code.as_deref().unwrap_or_default();
+                rules.iter().any(|rule| names_rule(rule, &issue.rule, code))
+            });
+        self.issues = issues;
+        self.suppressed.extend(suppressed);
+    }
+
     /// Returns issues filtered by severity.
     #[must_use]
     pub fn issues_by_severity(&self, severity: Severity) -> Vec<&ValidationIssue> {
@@ -197,7 +242,36 @@ impl ValidationReport {
     /// Merges another report into this one.
     pub fn merge(&mut self, other: Self) {
         self.issues.extend(other.issues);
+        self.suppressed.extend(other.suppressed);
+    }
+}
+
+/// Opening of the HTML comment suppressing rules for an ADR, e.g.
+/// `<!-- adrscope-ignore: recommended-fields, V0011 -->`.
+const SUPPRESS_COMMENT: &str = "adrscope-ignore:";
+
+/// Frontmatter field listing the rules suppressed for an ADR.
+const SUPPRESS_FIELD: &str = "lint_ignore";
+
+/// Returns the rules suppressed for an ADR, by name or code, from
+/// `adrscope-ignore` comments in its body and its `lint_ignore` field.
+#[must_use]
+pub fn suppressed_rules(adr: &Adr) -> Vec<String> {
+    let mut rules = adr.frontmatter().custom_values(SUPPRESS_FIELD);
+    let mut body = adr.body_markdown();
+    while let Some(start) = body.find("<!--") {
+        let comment = &body[start + 4..];
+        let end = comment.find("-->").unwrap_or(comment.len());
+        if let Some(list) = comment[..end].trim().strip_prefix(SUPPRESS_COMMENT) {
+            rules.extend(
+                list.split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|rule| !rule.is_empty())
+                    .map(ToString::to_string),
+            );
+        }
+        body = &comment[end..];
     }
+    rules
 }
 
 /// Trait for implementing validation rules.
@@ -207,6 +281,11 @@ pub trait ValidationRule: Send + Sync {
     /// Returns the human-readable name of this rule.
     fn name(&self) -> &str;
 
+    /// Returns the stable code of this rule (e.g. `V0001`).
+    ///
+    /// Issues reported by the rule are tagged with this code.
+    fn code(&self) -> &str;
+
     /// Returns a description of what this rule checks.
     fn description(&self) -> &str;
 
@@ -214,17 +293,157 @@ pub trait ValidationRule: Send + Sync {
     fn validate(&self, adr: &Adr, report: &mut ValidationReport);
 }
 
+/// Trait for validation rules that look at all ADRs at once, such as
+/// checks that references between ADRs agree.
+pub trait CollectionRule: Send + Sync {
+    /// Returns the human-readable name of this rule.
+    fn name(&self) -> &str;
+
+    /// Returns the stable code of this rule (e.g. `V0010`).
+    ///
+    /// Issues reported by the rule are tagged with this code.
+    fn code(&self) -> &str;
+
+    /// Returns a description of what this rule checks.
+    fn description(&self) -> &str;
+
+    /// Validates a collection of ADRs, appending any issues to the report
+    /// under the path of the ADR they concern.
+    fn validate(&self, adrs: &[Adr], report: &mut ValidationReport);
+}
+
+/// Level a rule is configured at: off, or reporting its issues as
+/// warnings or errors regardless of the severity the rule gives them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+    /// The rule does not run.
+    Off,
+    /// The rule's issues are warnings.
+    Warning,
+    /// The rule's issues are errors.
+    Error,
+}
+
+/// Which rules run, and at what severity.
+///
+/// Rules are named by name (`recommended-fields`) or code (`V0002`),
+/// ignoring case. A rule runs when it is listed in `only` (or `only` is
+/// empty), is not listed in `skip`, and is not configured `off`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleConfig {
+    levels: BTreeMap<String, RuleLevel>,
+    only: Vec<String>,
+    skip: Vec<String>,
+}
+
+impl RuleConfig {
+    /// Creates a configuration running every rule at its own severity.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the levels of rules, keyed by rule name or code.
+    #[must_use]
+    pub fn with_levels(mut self, levels: BTreeMap<String, RuleLevel>) -> Self {
+        self.levels = levels;
+        self
+    }
+
+    /// Runs only the given rules.
+    #[must_use]
+    pub fn with_only(mut self, rules: Vec<String>) -> Self {
+        self.only = rules;
+        self
+    }
+
+    /// Skips the given rules.
+    #[must_use]
+    pub fn with_skip(mut self, rules: Vec<String>) -> Self {
+        self.skip = rules;
+        self
+    }
+
+    /// Checks whether the rule with the given name and code runs.
+    #[must_use]
+    pub fn is_enabled(&self, name: &str, code: &str) -> bool {
+        let names = |key: &String| names_rule(key, name, code);
+        (self.only.is_empty() || self.only.iter().any(names))
+            && !self.skip.iter().any(names)
+            && self.level(name, code) != Some(RuleLevel::Off)
+    }
+
+    /// Returns the level configured for a rule, if any.
+    #[must_use]
+    pub fn level(&self, name: &str, code: &str) -> Option<RuleLevel> {
+        self.levels
+            .iter()
+            .find(|(key, _)| names_rule(key, name, code))
+            .map(|(_, level)| *level)
+    }
+
+    /// Removes the issues of rules that do not run from a report, and gives
+    /// the others their configured severity.
+    ///
+    /// This also covers issues reported outside a [`Validator`], such as
+    /// missing assets.
+    pub fn apply(&self, report: &mut ValidationReport) {
+        report.issues.retain(|issue| {
+            self.is_enabled(&issue.rule, issue.code.as_deref().unwrap_or_default())
+        });
+        for issue in &mut report.issues {
+            self.apply_level(issue);
+        }
+    }
+
+    /// Gives an issue the severity configured for its rule.
+    fn apply_level(&self, issue: &mut ValidationIssue) {
+        match self.level(&issue.rule, issue.code.as_deref().unwrap_or_default()) {
+            Some(RuleLevel::Warning) => issue.severity = Severity::Warning,
+            Some(RuleLevel::Error) => issue.severity = Severity::Error,
+            Some(RuleLevel::Off) | None => {},
+        }
+    }
+}
+
+/// Checks whether a configured key names the rule with the given name and
+/// code.
+fn names_rule(key: &str, name: &str, code: &str) -> bool {
+    key.eq_ignore_ascii_case(name) || (!code.is_empty() && key.eq_ignore_ascii_case(code))
+}
+
 /// A validator that runs multiple rules against ADRs.
 #[derive(Default)]
 pub struct Validator {
     rules: Vec<Box<dyn ValidationRule>>,
+    collection_rules: Vec<Box<dyn CollectionRule>>,
+    config: RuleConfig,
 }
 
 impl Validator {
     /// Creates a new validator with the given rules.
     #[must_use]
     pub fn new(rules: Vec<Box<dyn ValidationRule>>) -> Self {
-        Self { rules }
+        Self {
+            rules,
+            collection_rules: Vec::new(),
+            config: RuleConfig::default(),
+        }
+    }
+
+    /// Sets which of the rules run, and at what severity.
+    #[must_use]
+    pub fn with_config(mut self, config: RuleConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the rules that validate all ADRs at once.
+    #[must_use]
+    pub fn with_collection_rules(mut self, rules: Vec<Box<dyn CollectionRule>>) -> Self {
+        self.collection_rules = rules;
+        self
     }
 
     /// Adds a rule to the validator.
@@ -232,13 +451,16 @@ impl Validator {
         self.rules.push(rule);
     }
 
+    /// Adds a rule that validates all ADRs at once.
+    pub fn add_collection_rule(&mut self, rule: Box<dyn CollectionRule>) {
+        self.collection_rules.push(rule);
+    }
+
     /// Validates a single ADR using all configured rules.
     #[must_use]
     pub fn validate(&self, adr: &Adr) -> ValidationReport {
         let mut report = ValidationReport::new();
-        for rule in &self.rules {
-            rule.validate(adr, &mut report);
-        }
+        self.validate_into(adr, &mut report);
         report
     }
 
@@ -248,34 +470,107 @@ impl Validator {
         let mut report = ValidationReport::new();
 
         for adr in adrs {
-            for rule in &self.rules {
-                rule.validate(adr, &mut report);
-            }
+            self.validate_into(adr, &mut report);
         }
+        report.merge(self.validate_collection(adrs));
+
+        report
+    }
 
+    /// Validates a collection of ADRs using only the rules that look at all
+    /// ADRs at once.
+    #[must_use]
+    pub fn validate_collection(&self, adrs: &[Adr]) -> ValidationReport {
+        let mut report = ValidationReport::new();
+        for rule in &self.collection_rules {
+            if self.config.is_enabled(rule.name(), rule.code()) {
+                let start = report.issues.len();
+                rule.validate(adrs, &mut report);
+                self.tag_issues(&mut report, start, rule.code());
+            }
+        }
         report
     }
 
+    /// Runs all enabled rules against an ADR, tagging new issues with the
+    /// rule code and configured severity.
+    fn validate_into(&self, adr: &Adr, report: &mut ValidationReport) {
+        for rule in &self.rules {
+            if self.config.is_enabled(rule.name(), rule.code()) {
+                let start = report.issues.len();
+                rule.validate(adr, report);
+                self.tag_issues(report, start, rule.code());
+            }
+        }
+    }
+
+    /// Tags the issues added to a report since `start` with a rule's code
+    /// and configured severity.
+    fn tag_issues(&self, report: &mut ValidationReport, start: usize, code: &str) {
+        for issue in &mut report.issues[start..] {
+            if issue.code.is_none() {
+                issue.code = Some(code.to_string());
+            }
+            self.config.apply_level(issue);
+        }
+    }
+
     /// Returns the configured rules.
     #[must_use]
     pub fn rules(&self) -> &[Box<dyn ValidationRule>] {
         &self.rules
     }
+
+    /// Returns the configured rules that validate all ADRs at once.
+    #[must_use]
+    pub fn collection_rules(&self) -> &[Box<dyn CollectionRule>] {
+        &self.collection_rules
+    }
+
+    /// Returns which rules run, and at what severity.
+    #[must_use]
+    pub const fn config(&self) -> &RuleConfig {
+        &self.config
+    }
 }
 
 // ============================================================================
 // Built-in validation rules
 // ============================================================================
 
+/// Fields checked by [`RequiredFieldsRule`] unless configured otherwise.
+pub const DEFAULT_REQUIRED_FIELDS: &[&str] = &["title"];
+
+/// Fields checked by [`RecommendedFieldsRule`] unless configured otherwise.
+pub const DEFAULT_RECOMMENDED_FIELDS: &[&str] = &["description", "created", "category"];
+
 /// Rule that checks for required frontmatter fields.
-#[derive(Debug, Clone, Copy, Default)]
-pub struct RequiredFieldsRule;
+///
+/// Fields are named as in the file (`review-by`); names outside the
+/// structured-madr schema are looked up among the custom fields. See
+/// [`Frontmatter::has_field`](super::Frontmatter::has_field).
+#[derive(Debug, Clone)]
+pub struct RequiredFieldsRule {
+    fields: Vec<String>,
+}
 
 impl RequiredFieldsRule {
-    /// Creates a new required fields rule.
+    /// Creates a rule requiring the given fields.
     #[must_use]
-    pub const fn new() -> Self {
-        Self
+    pub const fn new(fields: Vec<String>) -> Self {
+        Self { fields }
+    }
+}
+
+impl Default for RequiredFieldsRule {
+    /// Requires the [`DEFAULT_REQUIRED_FIELDS`].
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_REQUIRED_FIELDS
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        )
     }
 }
 
@@ -284,30 +579,52 @@ impl ValidationRule for RequiredFieldsRule {
         "required-fields"
     }
 
+    fn code(&self) -> &str {
+        "V0001"
+    }
+
     fn description(&self) -> &str {
         "Checks that required frontmatter fields are present"
     }
 
     fn validate(&self, adr: &Adr, report: &mut ValidationReport) {
-        if adr.title().is_empty() {
-            report.add_issue(ValidationIssue::error(
-                adr.source_path().clone(),
-                "missing required field 'title'",
-                self.name(),
-            ));
+        for field in &self.fields {
+            if !adr.frontmatter().has_field(field) {
+                report.add_issue(ValidationIssue::error(
+                    adr.source_path().clone(),
+                    format!("missing required field '{field}'"),
+                    self.name(),
+                ));
+            }
         }
     }
 }
 
 /// Rule that warns about missing optional but recommended fields.
-#[derive(Debug, Clone, Copy, Default)]
-pub struct RecommendedFieldsRule;
+///
+/// Fields are named as for [`RequiredFieldsRule`].
+#[derive(Debug, Clone)]
+pub struct RecommendedFieldsRule {
+    fields: Vec<String>,
+}
 
 impl RecommendedFieldsRule {
-    /// Creates a new recommended fields rule.
+    /// Creates a rule recommending the given fields.
     #[must_use]
-    pub const fn new() -> Self {
-        Self
+    pub const fn new(fields: Vec<String>) -> Self {
+        Self { fields }
+    }
+}
+
+impl Default for RecommendedFieldsRule {
+    /// Recommends the [`DEFAULT_RECOMMENDED_FIELDS`].
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_RECOMMENDED_FIELDS
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        )
     }
 }
 
@@ -316,133 +633,1295 @@ impl ValidationRule for RecommendedFieldsRule {
         "recommended-fields"
     }
 
+    fn code(&self) -> &str {
+        "V0002"
+    }
+
     fn description(&self) -> &str {
         "Warns about missing recommended fields"
     }
 
     fn validate(&self, adr: &Adr, report: &mut ValidationReport) {
-        if adr.description().is_empty() {
-            report.add_issue(ValidationIssue::warning(
-                adr.source_path().clone(),
-                "missing recommended field 'description'",
-                self.name(),
-            ));
+        for field in &self.fields {
+            if !adr.frontmatter().has_field(field) {
+                report.add_issue(ValidationIssue::warning(
+                    adr.source_path().clone(),
+                    format!("missing recommended field '{field}'"),
+                    self.name(),
+                ));
+            }
         }
+    }
+}
 
-        if adr.created().is_none() {
-            report.add_issue(ValidationIssue::warning(
-                adr.source_path().clone(),
-                "missing recommended field 'created'",
-                self.name(),
-            ));
-        }
+/// Rule that warns about dates written in a non-canonical format.
+///
+/// Dates such as `2025/01/15` or `15 Jan 2025` are accepted when parsing, but
+/// the structured-madr schema expects `YYYY-MM-DD`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DateFormatRule;
 
-        if adr.category().is_empty() {
-            report.add_issue(ValidationIssue::warning(
-                adr.source_path().clone(),
-                "missing recommended field 'category'",
-                self.name(),
-            ));
-        }
+impl DateFormatRule {
+    /// Creates a new date format rule.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
     }
 }
 
-/// Returns the default set of validation rules.
-#[must_use]
-pub fn default_rules() -> Vec<Box<dyn ValidationRule>> {
-    vec![
-        Box::new(RequiredFieldsRule),
-        Box::new(RecommendedFieldsRule),
-    ]
-}
+impl ValidationRule for DateFormatRule {
+    fn name(&self) -> &str {
+        "date-format"
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::domain::{AdrId, Frontmatter};
-    use std::path::PathBuf;
+    fn code(&self) -> &str {
+        "V0004"
+    }
 
-    fn create_test_adr(title: &str) -> Adr {
-        let frontmatter = Frontmatter::new(title);
-        Adr::new(
-            AdrId::new("test"),
-            "test.md".to_string(),
-            PathBuf::from("test.md"),
-            frontmatter,
-            String::new(),
-            String::new(),
-            String::new(),
-        )
+    fn description(&self) -> &str {
+        "Warns about dates not written as YYYY-MM-DD"
     }
 
-    #[test]
-    fn test_validation_issue_display() {
-        let issue =
-            ValidationIssue::error(PathBuf::from("test.md"), "missing title", "required-fields");
-        let display = issue.to_string();
-        assert!(display.contains("error:"));
-        assert!(display.contains("test.md"));
-        assert!(display.contains("missing title"));
-        assert!(display.contains("[required-fields]"));
+    fn validate(&self, adr: &Adr, report: &mut ValidationReport) {
+        for normalization in &adr.frontmatter().normalized {
+            if matches!(
+                normalization.field.as_str(),
+                "created" | "updated" | "review-by" | "expires"
+            ) {
+                report.add_issue(ValidationIssue::warning(
+                    adr.source_path().clone(),
+                    format!(
+                        "'{}' date '{}' should be written as {}",
+                        normalization.field, normalization.original, normalization.normalized
+                    ),
+                    self.name(),
+                ));
+            }
+        }
     }
+}
 
-    #[test]
-    fn test_validation_issue_with_line() {
-        let issue = ValidationIssue::warning(
-            PathBuf::from("test.md"),
-            "missing description",
-            "recommended",
-        )
-        .with_line(5);
+/// Rule that warns about status values outside the ADR lifecycle.
+///
+/// Unknown statuses read as `proposed` unless mapped to a canonical status
+/// through configured synonyms.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StatusValueRule;
 
-        let display = issue.to_string();
-        assert!(display.contains(":5:"));
+impl StatusValueRule {
+    /// Creates a new status value rule.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
     }
+}
 
-    #[test]
-    fn test_validation_report() {
-        let mut report = ValidationReport::new();
-        assert!(report.is_empty());
-        assert!(report.is_valid());
-
-        report.add_issue(ValidationIssue::warning(
-            PathBuf::from("a.md"),
-            "warning 1",
-            "test",
-        ));
-        assert!(!report.is_empty());
-        assert!(report.is_valid()); // Warnings don't fail validation
+impl ValidationRule for StatusValueRule {
+    fn name(&self) -> &str {
+        "status-value"
+    }
 
-        report.add_issue(ValidationIssue::error(
-            PathBuf::from("b.md"),
-            "error 1",
-            "test",
-        ));
-        assert!(!report.is_valid());
-        assert!(report.has_errors());
+    fn code(&self) -> &str {
+        "V0005"
+    }
 
-        assert_eq!(report.warning_count(), 1);
-        assert_eq!(report.error_count(), 1);
-        assert_eq!(report.len(), 2);
+    fn description(&self) -> &str {
+        "Warns about unknown status values"
     }
 
-    #[test]
-    fn test_required_fields_rule() {
-        let rule = RequiredFieldsRule;
-        let mut report = ValidationReport::new();
+    fn validate(&self, adr: &Adr, report: &mut ValidationReport) {
+        for normalization in &adr.frontmatter().normalized {
+            if normalization.field == "status" {
+                report.add_issue(ValidationIssue::warning(
+                    adr.source_path().clone(),
+                    format!(
+                        "unknown status '{}', treated as '{}'",
+                        normalization.original, normalization.normalized
+                    ),
+                    self.name(),
+                ));
+            }
+        }
+    }
+}
 
-        // ADR with title should pass
-        let adr = create_test_adr("Test Title");
-        rule.validate(&adr, &mut report);
-        assert!(report.is_valid());
+/// Rule that warns about a status history that is out of order or does not
+/// match the ADR's dates and status.
+///
+/// Entries must be listed oldest first, fall between `created` and `updated`
+/// when those are set, and end with the current status.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StatusHistoryRule;
 
-        // ADR without title should fail
-        let mut report = ValidationReport::new();
+impl StatusHistoryRule {
+    /// Creates a new status history rule.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl ValidationRule for StatusHistoryRule {
+    fn name(&self) -> &str {
+        "status-history"
+    }
+
+    fn code(&self) -> &str {
+        "V0007"
+    }
+
+    fn description(&self) -> &str {
+        "Warns about status history entries out of chronological order"
+    }
+
+    fn validate(&self, adr: &Adr, report: &mut ValidationReport) {
+        let history = adr.history();
+        let mut warn = |message: String| {
+            report.add_issue(ValidationIssue::warning(
+                adr.source_path().clone(),
+                message,
+                self.name(),
+            ));
+        };
+
+        for pair in history.windows(2) {
+            if pair[1].date < pair[0].date {
+                warn(format!(
+                    "status history entry '{}' ({}) is dated before the entry above it ({})",
+                    pair[1].date,
+                    pair[1].status_name(),
+                    pair[0].date
+                ));
+            }
+        }
+
+        if let (Some(created), Some(first)) = (adr.created(), history.iter().map(|e| e.date).min())
+            && first < created
+        {
+            warn(format!(
+                "status history entry '{first}' is dated before 'created' ({created})"
+            ));
+        }
+
+        if let (Some(updated), Some(last)) = (adr.updated(), history.iter().map(|e| e.date).max())
+            && last > updated
+        {
+            warn(format!(
+                "status history entry '{last}' is dated after 'updated' ({updated})"
+            ));
+        }
+
+        if let Some(last) = history.last()
+            && !last.status_name().eq_ignore_ascii_case(adr.status_name())
+        {
+            warn(format!(
+                "status is '{}' but the last status history entry records '{}'",
+                adr.status_name(),
+                last.status_name()
+            ));
+        }
+
+        for normalization in &adr.frontmatter().normalized {
+            if let Some(entry) =
+                HistoryEntry::index_of(&normalization.field).and_then(|index| history.get(index))
+            {
+                warn(format!(
+                    "status history entry '{}' has unknown status '{}', treated as '{}'",
+                    entry.date, normalization.original, normalization.normalized
+                ));
+            }
+        }
+    }
+}
+
+/// Rule that warns about `created` and `updated` dates that contradict each
+/// other or the ADR's status.
+///
+/// `updated` must not precede `created`, `created` must not lie in the
+/// future, and superseded ADRs must record when they were superseded in
+/// `updated`.
+#[derive(Debug, Clone, Copy)]
+pub struct DateConsistencyRule {
+    date: Date,
+}
+
+impl DateConsistencyRule {
+    /// Creates a rule treating dates after `date` as in the future.
+    #[must_use]
+    pub const fn new(date: Date) -> Self {
+        Self { date }
+    }
+}
+
+impl Default for DateConsistencyRule {
+    /// Treats dates after today as in the future.
+    fn default() -> Self {
+        Self::new(OffsetDateTime::now_utc().date())
+    }
+}
+
+impl ValidationRule for DateConsistencyRule {
+    fn name(&self) -> &str {
+        "date-consistency"
+    }
+
+    fn code(&self) -> &str {
+        "V0012"
+    }
+
+    fn description(&self) -> &str {
+        "Warns about contradictory created and updated dates"
+    }
+
+    fn validate(&self, adr: &Adr, report: &mut ValidationReport) {
+        let mut warn = |message: String| {
+            report.add_issue(ValidationIssue::warning(
+                adr.source_path().clone(),
+                message,
+                self.name(),
+            ));
+        };
+
+        if let (Some(created), Some(updated)) = (adr.created(), adr.updated())
+            && updated < created
+        {
+            warn(format!(
+                "'updated' ({updated}) is before 'created' ({created})"
+            ));
+        }
+
+        if let Some(created) = adr.created()
+            && created > self.date
+        {
+            warn(format!("'created' ({created}) is in the future"));
+        }
+
+        if adr.status() == Status::Superseded && adr.updated().is_none() {
+            warn(
+                "superseded ADR has no 'updated' date recording when it was superseded".to_string(),
+            );
+        }
+    }
+}
+
+/// Rule that warns about decisions whose review date has passed.
+///
+/// Only proposed and accepted decisions are checked; see [`Adr::review_due`].
+#[derive(Debug, Clone, Copy)]
+pub struct ReviewDueRule {
+    date: Date,
+}
+
+impl ReviewDueRule {
+    /// Creates a rule checking review dates against `date`.
+    #[must_use]
+    pub const fn new(date: Date) -> Self {
+        Self { date }
+    }
+}
+
+impl Default for ReviewDueRule {
+    /// Checks review dates against today's date.
+    fn default() -> Self {
+        Self::new(OffsetDateTime::now_utc().date())
+    }
+}
+
+impl ValidationRule for ReviewDueRule {
+    fn name(&self) -> &str {
+        "review-due"
+    }
+
+    fn code(&self) -> &str {
+        "V0008"
+    }
+
+    fn description(&self) -> &str {
+        "Warns about decisions past their review date"
+    }
+
+    fn validate(&self, adr: &Adr, report: &mut ValidationReport) {
+        if let Some(review_by) = adr.review_by()
+            && adr.review_due(self.date)
+        {
+            report.add_issue(ValidationIssue::warning(
+                adr.source_path().clone(),
+                format!("review was due on {review_by}"),
+                self.name(),
+            ));
+        }
+    }
+}
+
+/// Rule that warns about decisions left proposed for longer than a given
+/// number of days since they were created.
+///
+/// ADRs without a `created` date are not checked.
+#[derive(Debug, Clone, Copy)]
+pub struct StaleProposalRule {
+    max_age: u32,
+    date: Date,
+}
+
+impl StaleProposalRule {
+    /// Creates a rule allowing decisions to stay proposed for `max_age`
+    /// days, counted up to `date`.
+    #[must_use]
+    pub const fn new(max_age: u32, date: Date) -> Self {
+        Self { max_age, date }
+    }
+
+    /// Creates a rule allowing decisions to stay proposed for `max_age`
+    /// days, counted up to today.
+    #[must_use]
+    pub fn until_today(max_age: u32) -> Self {
+        Self::new(max_age, OffsetDateTime::now_utc().date())
+    }
+}
+
+impl ValidationRule for StaleProposalRule {
+    fn name(&self) -> &str {
+        "stale-proposal"
+    }
+
+    fn code(&self) -> &str {
+        "V0018"
+    }
+
+    fn description(&self) -> &str {
+        "Warns about decisions proposed for too long"
+    }
+
+    fn validate(&self, adr: &Adr, report: &mut ValidationReport) {
+        if adr.status() != Status::Proposed {
+            return;
+        }
+        if let Some(created) = adr.created() {
+            let age = (self.date - created).whole_days();
+            if age > i64::from(self.max_age) {
+                report.add_issue(ValidationIssue::warning(
+                    adr.source_path().clone(),
+                    format!(
+                        "proposed for {age} days since {created}, longer than {} days",
+                        self.max_age
+                    ),
+                    self.name(),
+                ));
+            }
+        }
+    }
+}
+
+/// Rule that checks `related`, `supersedes`, and `superseded-by` references
+/// and body `[[wikilinks]]` resolve to an ADR in the collection.
+///
+/// References are matched as in the relationship graph (see
+/// [`ReferenceResolver`]), where dangling ones become placeholder nodes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RelatedLinksRule;
+
+impl RelatedLinksRule {
+    /// Creates a new related links rule.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl CollectionRule for RelatedLinksRule {
+    fn name(&self) -> &str {
+        "related-links"
+    }
+
+    fn code(&self) -> &str {
+        "V0010"
+    }
+
+    fn description(&self) -> &str {
+        "Checks that referenced ADRs exist"
+    }
+
+    fn validate(&self, adrs: &[Adr], report: &mut ValidationReport) {
+        let resolver = ReferenceResolver::new(adrs);
+
+        for adr in adrs {
+            let fields = [
+                ("related", adr.related()),
+                ("supersedes", adr.supersedes()),
+                ("superseded-by", adr.superseded_by()),
+                ("wikilink", adr.wikilinks()),
+            ];
+            for (field, references) in fields {
+                for reference in references {
+                    if resolver.resolve(adr, reference).is_none() {
+                        report.add_issue(ValidationIssue::error(
+                            adr.source_path().clone(),
+                            format!("'{field}' reference '{reference}' does not match any ADR"),
+                            self.name(),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Rule that checks an ADR's status agrees with its supersession references.
+///
+/// A superseded ADR needs an existing successor, recorded with
+/// `superseded-by` or with `supersedes` on the successor; following the
+/// chain of successors must end at a decision that is not itself
+/// superseded; and no ADR may supersede itself.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SupersessionRule;
+
+impl SupersessionRule {
+    /// Creates a new supersession rule.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl CollectionRule for SupersessionRule {
+    fn name(&self) -> &str {
+        "supersession"
+    }
+
+    fn code(&self) -> &str {
+        "V0013"
+    }
+
+    fn description(&self) -> &str {
+        "Checks that superseded ADRs name an existing successor"
+    }
+
+    fn validate(&self, adrs: &[Adr], report: &mut ValidationReport) {
+        let resolver = ReferenceResolver::new(adrs);
+        let graph = Graph::from_adrs(adrs);
+        let by_id: HashMap<&str, &Adr> = adrs.iter().map(|a| (a.id().as_str(), a)).collect();
+
+        for adr in adrs {
+            let id = adr.id().as_str();
+            let path = adr.source_path();
+
+            if adr
+                .supersedes()
+                .iter()
+                .chain(adr.superseded_by())
+                .any(|reference| resolver.resolve(adr, reference) == Some(id))
+            {
+                report.add_issue(ValidationIssue::error(
+                    path.clone(),
+                    "ADR supersedes itself",
+                    self.name(),
+                ));
+            }
+
+            if adr.status() != Status::Superseded {
+                continue;
+            }
+            let has_successor = graph.edges.iter().any(|edge| {
+                edge.edge_type == EdgeType::Supersedes
+                    && edge.target == id
+                    && edge.source != id
+                    && by_id.contains_key(edge.source.as_str())
+            });
+            if !has_successor {
+                report.add_issue(ValidationIssue::warning(
+                    path.clone(),
+                    "status is 'superseded' but no existing ADR supersedes it",
+                    self.name(),
+                ));
+            } else if let Some(current) = graph.current_decision(id)
+                && by_id
+                    .get(current)
+                    .is_some_and(|successor| successor.status() == Status::Superseded)
+            {
+                report.add_issue(ValidationIssue::warning(
+                    path.clone(),
+                    format!("its successor '{current}' is itself superseded, with no successor"),
+                    self.name(),
+                ));
+            }
+        }
+    }
+}
+
+/// Body sections checked by [`BodySectionsRule`] unless configured
+/// otherwise. Alternative headings for a section are separated by `|`.
+pub const DEFAULT_SECTIONS: &[&str] = &[
+    "Context|Context and Problem Statement",
+    "Decision|Decision Outcome",
+    "Consequences",
+];
+
+/// Rule that warns when expected sections are missing from the body, or
+/// have no content.
+///
+/// Each section is given as a heading, matched at any level and ignoring
+/// case, with alternatives separated by `|` (`Decision|Decision Outcome`).
+/// A section is empty when nothing but headings and HTML comments follows
+/// it before the next heading of the same or a higher level.
+#[derive(Debug, Clone)]
+pub struct BodySectionsRule {
+    sections: Vec<String>,
+}
+
+impl BodySectionsRule {
+    /// Creates a rule checking for the given sections.
+    #[must_use]
+    pub const fn new(sections: Vec<String>) -> Self {
+        Self { sections }
+    }
+}
+
+impl Default for BodySectionsRule {
+    /// Checks for the [`DEFAULT_SECTIONS`].
+    fn default() -> Self {
+        Self::new(DEFAULT_SECTIONS.iter().map(ToString::to_string).collect())
+    }
+}
+
+impl ValidationRule for BodySectionsRule {
+    fn name(&self) -> &str {
+        "body-structure"
+    }
+
+    fn code(&self) -> &str {
+        "V0011"
+    }
+
+    fn description(&self) -> &str {
+        "Warns about missing or empty body sections"
+    }
+
+    fn validate(&self, adr: &Adr, report: &mut ValidationReport) {
+        let headings = headings(adr.body_markdown());
+
+        for section in &self.sections {
+            let name = section.split('|').next().unwrap_or_default().trim();
+            let found = headings.iter().position(|heading| {
+                section
+                    .split('|')
+                    .any(|alternative| heading.title.eq_ignore_ascii_case(alternative.trim()))
+            });
+
+            let message = match found {
+                None => format!("missing section '{name}'"),
+                Some(index) if section_is_empty(&headings, index) => {
+                    format!("section '{}' is empty", headings[index].title)
+                },
+                Some(_) => continue,
+            };
+            report.add_issue(ValidationIssue::warning(
+                adr.source_path().clone(),
+                message,
+                self.name(),
+            ));
+        }
+    }
+}
+
+/// A markdown heading and whether text follows it before the next heading.
+#[derive(Debug)]
+struct Heading<'a> {
+    level: usize,
+    title: &'a str,
+    has_text: bool,
+}
+
+/// Returns the ATX headings (`## Context`) of a markdown body, skipping
+/// fenced code blocks.
+fn headings(markdown: &str) -> Vec<Heading<'_>> {
+    let mut headings: Vec<Heading<'_>> = Vec::new();
+    let mut fence: Option<&str> = None;
+    let mut in_comment = false;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+        } else if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            fence = Some(&trimmed[..3]);
+        } else if let Some((level, title)) = heading(line) {
+            headings.push(Heading {
+                level,
+                title,
+                has_text: false,
+            });
+            continue;
+        }
+
+        // Text outside HTML comments, such as template instructions, counts
+        let mut text = trimmed;
+        let mut visible = false;
+        while !text.is_empty() {
+            if in_comment {
+                let Some(end) = text.find("-->") else {
+                    break;
+                };
+                text = &text[end + 3..];
+                in_comment = false;
+            } else if let Some(start) = text.find("<!--") {
+                visible |= !text[..start].trim().is_empty();
+                text = &text[start + 4..];
+                in_comment = true;
+            } else {
+                visible = true;
+                break;
+            }
+        }
+        if visible && let Some(last) = headings.last_mut() {
+            last.has_text = true;
+        }
+    }
+    headings
+}
+
+/// Parses an ATX heading line into its level and title.
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let line = line
+        .strip_prefix("   ")
+        .or_else(|| line.strip_prefix("  "))
+        .unwrap_or(line);
+    let line = line.strip_prefix(' ').unwrap_or(line);
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let rest = &line[level..];
+    if !(1..=6).contains(&level) || !(rest.is_empty() || rest.starts_with([' ', '\t'])) {
+        return None;
+    }
+
+    // Drop a closing `###` sequence and an explicit `{#anchor}`
+    let mut title = rest.trim();
+    if let Some(start) = title.rfind(" {#")
+        && title.ends_with('}')
+    {
+        title = title[..start].trim_end();
+    }
+    let without_hashes = title.trim_end_matches('#');
+    if without_hashes.is_empty() || without_hashes.ends_with(' ') {
+        title = without_hashes.trim_end();
+    }
+    Some((level, title))
+}
+
+/// Returns true if no text follows the heading at `index`, including in
+/// its subsections.
+fn section_is_empty(headings: &[Heading<'_>], index: usize) -> bool {
+    let level = headings[index].level;
+    !headings[index].has_text
+        && !headings[index + 1..]
+            .iter()
+            .take_while(|heading| heading.level > level)
+            .any(|heading| heading.has_text)
+}
+
+/// Filename pattern checked by [`FilenameRule`] unless configured
+/// otherwise: `adr-0001-use-postgres.md` or `0001-use-postgres.md`, with
+/// the slug optional.
+pub const DEFAULT_FILENAME_PATTERN: &str = r"(adr-)?\d{4}(-[a-z0-9-]+)?\.md";
+
+/// Rule that warns when an ADR's filename does not follow the naming
+/// convention.
+///
+/// The pattern is a regular expression matched against the whole filename,
+/// without the directory.
+#[derive(Debug, Clone)]
+pub struct FilenameRule {
+    pattern: String,
+    regex: Regex,
+}
+
+impl FilenameRule {
+    /// Creates a rule checking filenames against the given pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern is not a valid regular expression.
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self {
+            pattern: pattern.to_string(),
+            regex: Regex::new(&format!("^(?:{pattern})$"))?,
+        })
+    }
+}
+
+impl ValidationRule for FilenameRule {
+    fn name(&self) -> &str {
+        "filename"
+    }
+
+    fn code(&self) -> &str {
+        "V0014"
+    }
+
+    fn description(&self) -> &str {
+        "Warns about filenames not following the naming convention"
+    }
+
+    fn validate(&self, adr: &Adr, report: &mut ValidationReport) {
+        let filename = adr.filename();
+        if !self.regex.is_match(filename) {
+            report.add_issue(ValidationIssue::warning(
+                adr.source_path().clone(),
+                format!(
+                    "filename '{filename}' does not match the pattern '{}'",
+                    self.pattern
+                ),
+                self.name(),
+            ));
+        }
+    }
+}
+
+/// Rule that warns about tags not written in kebab-case, such as
+/// `Event Sourcing` or `event_sourcing` for `event-sourcing`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TagFormatRule;
+
+impl ValidationRule for TagFormatRule {
+    fn name(&self) -> &str {
+        "tag-format"
+    }
+
+    fn code(&self) -> &str {
+        "V0015"
+    }
+
+    fn description(&self) -> &str {
+        "Warns about tags not written in kebab-case"
+    }
+
+    fn validate(&self, adr: &Adr, report: &mut ValidationReport) {
+        for tag in adr.tags() {
+            if !is_kebab_case(tag) {
+                report.add_issue(ValidationIssue::warning(
+                    adr.source_path().clone(),
+                    format!("tag '{tag}' is not kebab-case; use '{}'", kebab_case(tag)),
+                    self.name(),
+                ));
+            }
+        }
+    }
+}
+
+/// Checks whether a value is lowercase words and numbers joined by single
+/// hyphens.
+fn is_kebab_case(value: &str) -> bool {
+    value
+        .split('-')
+        .all(|word| !word.is_empty() && word.chars().all(|c| c.is_lowercase() || c.is_numeric()))
+}
+
+/// Converts a value to kebab-case, splitting words at spaces, punctuation,
+/// and camelCase boundaries.
+fn kebab_case(value: &str) -> String {
+    let mut words: Vec<String> = Vec::new();
+    let mut word = String::new();
+    let mut previous_lower = false;
+    for c in value.chars() {
+        let boundary = !c.is_alphanumeric() || (c.is_uppercase() && previous_lower);
+        if boundary && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        if c.is_alphanumeric() {
+            word.extend(c.to_lowercase());
+        }
+        previous_lower = c.is_lowercase() || c.is_numeric();
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words.join("-")
+}
+
+/// Rule that warns about tags and categories missing from the allowed
+/// vocabulary, suggesting the closest allowed value for likely typos.
+///
+/// An empty list allows any value.
+#[derive(Debug, Clone, Default)]
+pub struct VocabularyRule {
+    tags: Vec<String>,
+    categories: Vec<String>,
+}
+
+impl VocabularyRule {
+    /// Creates a rule allowing the given tags and categories.
+    #[must_use]
+    pub const fn new(tags: Vec<String>, categories: Vec<String>) -> Self {
+        Self { tags, categories }
+    }
+
+    /// Reports a value missing from an allowed list.
+    fn check(
+        &self,
+        adr: &Adr,
+        field: &str,
+        value: &str,
+        allowed: &[String],
+        report: &mut ValidationReport,
+    ) {
+        if allowed.is_empty() || allowed.iter().any(|a| a == value) {
+            return;
+        }
+        let message = closest(value, allowed).map_or_else(
+            || format!("{field} '{value}' is not in the allowed {field} list"),
+            |suggestion| {
+                format!(
+                    "{field} '{value}' is not in the allowed {field} list; did you mean '{suggestion}'?"
+                )
+            },
+        );
+        report.add_issue(ValidationIssue::warning(
+            adr.source_path().clone(),
+            message,
+            self.name(),
+        ));
+    }
+}
+
+impl ValidationRule for VocabularyRule {
+    fn name(&self) -> &str {
+        "vocabulary"
+    }
+
+    fn code(&self) -> &str {
+        "V0016"
+    }
+
+    fn description(&self) -> &str {
+        "Warns about tags and categories missing from the allowed lists"
+    }
+
+    fn validate(&self, adr: &Adr, report: &mut ValidationReport) {
+        if !adr.category().is_empty() {
+            self.check(adr, "category", adr.category(), &self.categories, report);
+        }
+        for tag in adr.tags() {
+            self.check(adr, "tag", tag, &self.tags, report);
+        }
+    }
+}
+
+/// Returns the allowed value closest to `value`, if it is near enough to be
+/// a likely typo: a third of the value's characters may differ, ignoring
+/// case.
+fn closest<'a>(value: &str, allowed: &'a [String]) -> Option<&'a str> {
+    let value = value.to_lowercase();
+    let limit = (value.chars().count() / 3).max(1);
+    allowed
+        .iter()
+        .map(|candidate| (edit_distance(&value, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= limit)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.as_str())
+}
+
+/// Returns the Levenshtein distance between two strings, in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Longest title structured-madr allows, in characters.
+pub const MAX_TITLE_LENGTH: usize = 100;
+
+/// Longest description structured-madr allows, in characters.
+pub const MAX_DESCRIPTION_LENGTH: usize = 300;
+
+/// Rule that warns about titles and descriptions longer than structured-madr
+/// allows ([`MAX_TITLE_LENGTH`] and [`MAX_DESCRIPTION_LENGTH`]).
+///
+/// Empty values are left to the required and recommended field rules.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FieldLengthRule;
+
+impl ValidationRule for FieldLengthRule {
+    fn name(&self) -> &str {
+        "field-length"
+    }
+
+    fn code(&self) -> &str {
+        "V0020"
+    }
+
+    fn description(&self) -> &str {
+        "Warns about titles and descriptions longer than structured-madr allows"
+    }
+
+    fn validate(&self, adr: &Adr, report: &mut ValidationReport) {
+        let fields = [
+            ("title", adr.title(), MAX_TITLE_LENGTH),
+            ("description", adr.description(), MAX_DESCRIPTION_LENGTH),
+        ];
+        for (field, value, max) in fields {
+            let length = value.chars().count();
+            if length > max {
+                report.add_issue(ValidationIssue::warning(
+                    adr.source_path().clone(),
+                    format!("{field} is {length} characters long, more than {max}"),
+                    self.name(),
+                ));
+            }
+        }
+    }
+}
+
+/// Style ADR titles are expected to be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TitleStyle {
+    /// Starting with a verb in the imperative mood, e.g. `Use PostgreSQL`.
+    Imperative,
+    /// Every word capitalized except short function words, e.g.
+    /// `Use PostgreSQL for the Order Service`.
+    TitleCase,
+}
+
+/// Words left lowercase inside a title-case title.
+const MINOR_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "from", "in", "into", "nor", "of", "on",
+    "or", "over", "per", "the", "to", "via", "vs", "with",
+];
+
+/// Verbs ending in `-ing`, `-ed`, or `-s` that are imperative as written.
+const IMPERATIVE_EXCEPTIONS: &[&str] = &[
+    "bring", "cling", "embed", "fling", "ring", "shed", "shred", "sing", "sling", "spring",
+    "sting", "string", "swing", "wring",
+];
+
+/// Rule that warns about titles not written in the configured style.
+///
+/// The imperative check is a heuristic: it flags titles starting with a
+/// word ending in `-ing`, `-ed`, or a third-person `-s`, such as `Using`,
+/// `Adopted`, or `Uses`.
+#[derive(Debug, Clone, Copy)]
+pub struct TitleStyleRule {
+    style: TitleStyle,
+}
+
+impl TitleStyleRule {
+    /// Creates a rule expecting titles in the given style.
+    #[must_use]
+    pub const fn new(style: TitleStyle) -> Self {
+        Self { style }
+    }
+}
+
+impl ValidationRule for TitleStyleRule {
+    fn name(&self) -> &str {
+        "title-style"
+    }
+
+    fn code(&self) -> &str {
+        "V0021"
+    }
+
+    fn description(&self) -> &str {
+        "Warns about titles not written in the configured style"
+    }
+
+    fn validate(&self, adr: &Adr, report: &mut ValidationReport) {
+        let title = adr.title();
+        let message = match self.style {
+            TitleStyle::Imperative => title
+                .split_whitespace()
+                .next()
+                .filter(|word| !is_imperative(word))
+                .map(|word| {
+                    format!(
+                        "title should start with a verb in the imperative mood, \
+                         such as 'Use' rather than 'Using' or 'Uses', not '{word}'"
+                    )
+                }),
+            TitleStyle::TitleCase => {
+                let expected = title_case(title);
+                (expected != title).then(|| format!("title is not in title case; use '{expected}'"))
+            },
+        };
+        if let Some(message) = message {
+            report.add_issue(ValidationIssue::warning(
+                adr.source_path().clone(),
+                message,
+                self.name(),
+            ));
+        }
+    }
+}
+
+/// Checks whether a title's first word could be a verb in the imperative
+/// mood, as far as its ending tells.
+fn is_imperative(word: &str) -> bool {
+    let word = word.to_lowercase();
+    if word.len() <= 3 || IMPERATIVE_EXCEPTIONS.contains(&word.as_str()) {
+        return true;
+    }
+    let gerund = word.ends_with("ing");
+    let past = word.ends_with("ed") && !word.ends_with("eed");
+    let third_person = word.ends_with('s')
+        && !["ss", "us", "is", "as"]
+            .iter()
+            .any(|ending| word.ends_with(ending));
+    !(gerund || past || third_person)
+}
+
+/// Capitalizes the words of a title, except minor words inside it.
+///
+/// Words already containing capitals, such as `gRPC`, are kept as written.
+fn title_case(title: &str) -> String {
+    let words: Vec<&str> = title.split(' ').collect();
+    words
+        .iter()
+        .enumerate()
+        .map(|(index, word)| {
+            let inner = index > 0 && index + 1 < words.len();
+            let minor = inner && MINOR_WORDS.contains(&word.to_lowercase().as_str());
+            if minor || word.chars().any(char::is_uppercase) {
+                return (*word).to_string();
+            }
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars).collect()
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Collections with fewer ADRs than this are not checked for orphaned ADRs.
+pub const DEFAULT_ORPHAN_THRESHOLD: usize = 10;
+
+/// Rule that warns about ADRs with no relationship to any other ADR: none
+/// of their own, and none pointing at them.
+///
+/// Relationships are the edges of the relationship graph (see
+/// [`Graph::from_adrs`]). Small collections, where most decisions stand
+/// alone, are not checked.
+#[derive(Debug, Clone, Copy)]
+pub struct OrphanRule {
+    threshold: usize,
+}
+
+impl OrphanRule {
+    /// Creates a rule checking collections of at least `threshold` ADRs.
+    #[must_use]
+    pub const fn new(threshold: usize) -> Self {
+        Self { threshold }
+    }
+}
+
+impl Default for OrphanRule {
+    /// Checks collections of at least [`DEFAULT_ORPHAN_THRESHOLD`] ADRs.
+    fn default() -> Self {
+        Self::new(DEFAULT_ORPHAN_THRESHOLD)
+    }
+}
+
+impl CollectionRule for OrphanRule {
+    fn name(&self) -> &str {
+        "orphan"
+    }
+
+    fn code(&self) -> &str {
+        "V0019"
+    }
+
+    fn description(&self) -> &str {
+        "Warns about ADRs not connected to any other ADR"
+    }
+
+    fn validate(&self, adrs: &[Adr], report: &mut ValidationReport) {
+        if adrs.len() < self.threshold {
+            return;
+        }
+
+        let graph = Graph::from_adrs(adrs);
+        for adr in adrs {
+            let id = adr.id().as_str();
+            let connected = graph
+                .edges
+                .iter()
+                .any(|edge| edge.source != edge.target && (edge.source == id || edge.target == id));
+            if !connected {
+                report.add_issue(ValidationIssue::warning(
+                    adr.source_path().clone(),
+                    "not related to any other ADR",
+                    self.name(),
+                ));
+            }
+        }
+    }
+}
+
+/// Returns the default set of rules that validate all ADRs at once.
+#[must_use]
+pub fn default_collection_rules() -> Vec<Box<dyn CollectionRule>> {
+    vec![Box::new(RelatedLinksRule), Box::new(SupersessionRule)]
+}
+
+/// Returns the default set of validation rules.
+#[must_use]
+pub fn default_rules() -> Vec<Box<dyn ValidationRule>> {
+    rules_with_fields(
+        RequiredFieldsRule::default(),
+        RecommendedFieldsRule::default(),
+    )
+}
+
+/// Returns the default set of validation rules, with the given rules
+/// checking for required and recommended fields.
+#[must_use]
+pub fn rules_with_fields(
+    required: RequiredFieldsRule,
+    recommended: RecommendedFieldsRule,
+) -> Vec<Box<dyn ValidationRule>> {
+    vec![
+        Box::new(required),
+        Box::new(recommended),
+        Box::new(DateFormatRule),
+        Box::new(StatusValueRule),
+        Box::new(StatusHistoryRule),
+        Box::new(DateConsistencyRule::default()),
+        Box::new(TagFormatRule),
+        Box::new(FieldLengthRule),
+        Box::new(ReviewDueRule::default()),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AdrId, CustomStatus, Frontmatter, Normalization};
+    use std::path::PathBuf;
+
+    fn create_test_adr(title: &str) -> Adr {
+        let frontmatter = Frontmatter::new(title);
+        Adr::new(
+            AdrId::new("test"),
+            "test.md".to_string(),
+            PathBuf::from("test.md"),
+            frontmatter,
+            String::new(),
+            String::new(),
+            String::new(),
+        )
+    }
+
+    #[test]
+    fn test_validation_issue_display() {
+        let issue =
+            ValidationIssue::error(PathBuf::from("test.md"), "missing title", "required-fields");
+        let display = issue.to_string();
+        assert!(display.contains("error:"));
+        assert!(display.contains("test.md"));
+        assert!(display.contains("missing title"));
+        assert!(display.contains("[required-fields]"));
+    }
+
+    #[test]
+    fn test_validation_issue_with_line() {
+        let issue = ValidationIssue::warning(
+            PathBuf::from("test.md"),
+            "missing description",
+            "recommended",
+        )
+        .with_line(5);
+
+        let display = issue.to_string();
+        assert!(display.contains(":5:"));
+    }
+
+    #[test]
+    fn test_validation_report() {
+        let mut report = ValidationReport::new();
+        assert!(report.is_empty());
+        assert!(report.is_valid());
+
+        report.add_issue(ValidationIssue::warning(
+            PathBuf::from("a.md"),
+            "warning 1",
+            "test",
+        ));
+        assert!(!report.is_empty());
+        assert!(report.is_valid()); // Warnings don't fail validation
+
+        report.add_issue(ValidationIssue::error(
+            PathBuf::from("b.md"),
+            "error 1",
+            "test",
+        ));
+        assert!(!report.is_valid());
+        assert!(report.has_errors());
+
+        assert_eq!(report.warning_count(), 1);
+        assert_eq!(report.error_count(), 1);
+        assert_eq!(report.len(), 2);
+    }
+
+    #[test]
+    fn test_required_fields_rule() {
+        let rule = RequiredFieldsRule::default();
+        let mut report = ValidationReport::new();
+
+        // ADR with title should pass
+        let adr = create_test_adr("Test Title");
+        rule.validate(&adr, &mut report);
+        assert!(report.is_valid());
+
+        // ADR without title should fail
+        let mut report = ValidationReport::new();
         let adr = create_test_adr("");
         rule.validate(&adr, &mut report);
         assert!(report.has_errors());
     }
 
+    #[test]
+    fn test_configured_field_rules() {
+        let fields = |names: &[&str]| names.iter().map(ToString::to_string).collect();
+        let mut frontmatter = Frontmatter::new("Test ADR").with_author("Jane Doe");
+        frontmatter.custom.insert(
+            "cost-center".to_string(),
+            serde_yaml::Value::String("CC-42".to_string()),
+        );
+        let adr = Adr::new(
+            AdrId::new("test"),
+            "test.md".to_string(),
+            PathBuf::from("test.md"),
+            frontmatter,
+            String::new(),
+            String::new(),
+            String::new(),
+        );
+
+        let mut report = ValidationReport::new();
+        RequiredFieldsRule::new(fields(&["title", "author", "cost-center", "status"]))
+            .validate(&adr, &mut report);
+        RecommendedFieldsRule::new(fields(&["review-by", "author"])).validate(&adr, &mut report);
+
+        let messages: Vec<_> = report.issues().iter().map(|i| i.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "missing required field 'status'",
+                "missing recommended field 'review-by'",
+            ]
+        );
+        assert_eq!(report.error_count(), 1);
+    }
+
     #[test]
     fn test_validator_with_multiple_rules() {
         let validator = Validator::new(default_rules());
@@ -451,6 +1930,96 @@ mod tests {
 
         // Should have warnings for missing description, created, category
         assert!(report.warning_count() > 0);
+        assert!(
+            report
+                .issues()
+                .iter()
+                .all(|issue| issue.code.as_deref() == Some("V0002"))
+        );
+    }
+
+    #[test]
+    fn test_validator_rule_config() {
+        let adr = create_test_adr("");
+
+        let levels = BTreeMap::from([
+            ("recommended-fields".to_string(), RuleLevel::Error),
+            ("v0001".to_string(), RuleLevel::Warning),
+        ]);
+        let validator =
+            Validator::new(default_rules()).with_config(RuleConfig::new().with_levels(levels));
+        let report = validator.validate(&adr);
+        assert_eq!(report.warnings().len(), 1);
+        assert_eq!(report.warnings()[0].rule, "required-fields");
+        assert_eq!(report.errors().len(), 3);
+
+        let config = RuleConfig::new()
+            .with_only(vec!["V0001".to_string(), "recommended-fields".to_string()])
+            .with_skip(vec!["recommended-fields".to_string()]);
+        let report = Validator::new(default_rules())
+            .with_config(config.clone())
+            .validate(&adr);
+        assert_eq!(report.issues().len(), 1);
+        assert_eq!(report.issues()[0].rule, "required-fields");
+
+        // Issues reported outside the validator are filtered too
+        let mut report = ValidationReport::new();
+        report.add_issues([
+            ValidationIssue::error(PathBuf::from("a.md"), "x", "required-fields")
+                .with_code("V0001"),
+            ValidationIssue::warning(PathBuf::from("a.md"), "y", "assets").with_code("V0006"),
+        ]);
+        config.apply(&mut report);
+        assert_eq!(report.issues().len(), 1);
+
+        let off = BTreeMap::from([("required-fields".to_string(), RuleLevel::Off)]);
+        RuleConfig::new().with_levels(off).apply(&mut report);
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn test_suppressed_rules() {
+        let mut frontmatter = Frontmatter::new("Test");
+        frontmatter.custom.insert(
+            "lint_ignore".to_string(),
+            serde_yaml::from_str("[filename]").unwrap(),
+        );
+        let adr = Adr::new(
+            AdrId::new("test"),
+            "test.md".to_string(),
+            PathBuf::from("test.md"),
+            frontmatter,
+            "<!-- adrscope-ignore: recommended-fields, V0011 -->\n\n\
+             Text <!-- a note --> <!--adrscope-ignore:tag-format-->\n"
+                .to_string(),
+            String::new(),
+            String::new(),
+        );
+
+        let rules = suppressed_rules(&adr);
+        assert_eq!(
+            rules,
+            ["filename", "recommended-fields", "V0011", "tag-format"]
+        );
+
+        let mut report = Validator::new(default_rules()).validate(&adr);
+        let warnings = report.warning_count();
+        assert!(warnings > 0);
+        report.suppress(&rules);
+        assert_eq!(report.warning_count(), 0);
+        assert_eq!(report.suppressed().len(), warnings);
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn test_validation_issue_label() {
+        let issue =
+            ValidationIssue::error(PathBuf::from("test.md"), "missing title", "required-fields");
+        assert_eq!(issue.label(), "required-fields");
+
+        let issue = issue.with_code("V0001");
+        assert_eq!(issue.label(), "V0001 required-fields");
+        assert!(issue.to_string().contains("[V0001 required-fields]"));
     }
 
     #[test]
@@ -515,21 +2084,23 @@ mod tests {
 
     #[test]
     fn test_required_fields_rule_metadata() {
-        let rule = RequiredFieldsRule::new();
+        let rule = RequiredFieldsRule::default();
         assert_eq!(rule.name(), "required-fields");
+        assert_eq!(rule.code(), "V0001");
         assert!(!rule.description().is_empty());
     }
 
     #[test]
     fn test_recommended_fields_rule_metadata() {
-        let rule = RecommendedFieldsRule::new();
+        let rule = RecommendedFieldsRule::default();
         assert_eq!(rule.name(), "recommended-fields");
+        assert_eq!(rule.code(), "V0002");
         assert!(!rule.description().is_empty());
     }
 
     #[test]
     fn test_recommended_fields_rule_validation() {
-        let rule = RecommendedFieldsRule::new();
+        let rule = RecommendedFieldsRule::default();
         let mut report = ValidationReport::new();
 
         // ADR with no description, category, or created date
@@ -549,4 +2120,690 @@ mod tests {
         // Should have warnings for description, created, and category
         assert_eq!(report.warning_count(), 3);
     }
+
+    #[test]
+    fn test_date_format_rule() {
+        let rule = DateFormatRule::new();
+        assert_eq!(rule.code(), "V0004");
+
+        let mut frontmatter = Frontmatter::new("Test ADR");
+        frontmatter.normalized.push(Normalization {
+            field: "created".to_string(),
+            original: "15 Jan 2025".to_string(),
+            normalized: "2025-01-15".to_string(),
+        });
+        let adr = Adr::new(
+            AdrId::new("test"),
+            "test.md".to_string(),
+            PathBuf::from("test.md"),
+            frontmatter,
+            String::new(),
+            String::new(),
+            String::new(),
+        );
+
+        let mut report = ValidationReport::new();
+        rule.validate(&adr, &mut report);
+        assert_eq!(report.warning_count(), 1);
+        assert_eq!(
+            report.issues()[0].message,
+            "'created' date '15 Jan 2025' should be written as 2025-01-15"
+        );
+
+        let mut report = ValidationReport::new();
+        rule.validate(&create_test_adr("Test"), &mut report);
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn test_status_value_rule() {
+        let rule = StatusValueRule::new();
+        assert_eq!(rule.code(), "V0005");
+
+        let mut frontmatter = Frontmatter::new("Test ADR");
+        frontmatter.normalized.push(Normalization {
+            field: "status".to_string(),
+            original: "published".to_string(),
+            normalized: "proposed".to_string(),
+        });
+        let adr = Adr::new(
+            AdrId::new("test"),
+            "test.md".to_string(),
+            PathBuf::from("test.md"),
+            frontmatter,
+            String::new(),
+            String::new(),
+            String::new(),
+        );
+
+        let mut report = ValidationReport::new();
+        rule.validate(&adr, &mut report);
+        assert_eq!(report.warning_count(), 1);
+        assert_eq!(
+            report.issues()[0].message,
+            "unknown status 'published', treated as 'proposed'"
+        );
+    }
+
+    #[test]
+    fn test_review_due_rule() {
+        use crate::domain::Status;
+        use time::macros::date;
+
+        let rule = ReviewDueRule::new(date!(2025 - 06 - 01));
+        assert_eq!(rule.code(), "V0008");
+
+        let validate = |frontmatter: Frontmatter| {
+            let adr = Adr::new(
+                AdrId::new("test"),
+                "test.md".to_string(),
+                PathBuf::from("test.md"),
+                frontmatter,
+                String::new(),
+                String::new(),
+                String::new(),
+            );
+            let mut report = ValidationReport::new();
+            rule.validate(&adr, &mut report);
+            report
+                .issues()
+                .iter()
+                .map(|issue| issue.message.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let overdue = Frontmatter::new("Test ADR")
+            .with_status(Status::Accepted)
+            .with_review_by(date!(2025 - 05 - 01));
+        assert_eq!(validate(overdue), vec!["review was due on 2025-05-01"]);
+
+        let upcoming = Frontmatter::new("Test ADR")
+            .with_status(Status::Accepted)
+            .with_review_by(date!(2025 - 07 - 01));
+        assert!(validate(upcoming).is_empty());
+
+        let retired = Frontmatter::new("Test ADR")
+            .with_status(Status::Superseded)
+            .with_review_by(date!(2025 - 05 - 01));
+        assert!(validate(retired).is_empty());
+    }
+
+    #[test]
+    fn test_stale_proposal_rule() {
+        use crate::domain::Status;
+        use time::macros::date;
+
+        let rule = StaleProposalRule::new(90, date!(2025 - 06 - 01));
+        let validate = |frontmatter: Frontmatter| {
+            let adr = Adr::new(
+                AdrId::new("test"),
+                "test.md".to_string(),
+                PathBuf::from("test.md"),
+                frontmatter,
+                String::new(),
+                String::new(),
+                String::new(),
+            );
+            let mut report = ValidationReport::new();
+            rule.validate(&adr, &mut report);
+            report
+                .issues()
+                .iter()
+                .map(|issue| issue.message.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let stale = Frontmatter::new("Test ADR").with_created(date!(2025 - 01 - 15));
+        assert_eq!(
+            validate(stale.clone()),
+            vec!["proposed for 137 days since 2025-01-15, longer than 90 days"]
+        );
+        assert!(validate(stale.with_status(Status::Accepted)).is_empty());
+
+        let recent = Frontmatter::new("Test ADR").with_created(date!(2025 - 03 - 03));
+        assert!(validate(recent).is_empty());
+        assert!(validate(Frontmatter::new("Test ADR")).is_empty());
+    }
+
+    #[test]
+    fn test_related_links_rule() {
+        let adr = |id: &str, frontmatter: Frontmatter| {
+            Adr::new(
+                AdrId::new(id),
+                format!("{id}.md"),
+                PathBuf::from(format!("{id}.md")),
+                frontmatter,
+                String::new(),
+                String::new(),
+                String::new(),
+            )
+        };
+        let adrs = [
+            adr(
+                "adr-0002",
+                Frontmatter::new("Two")
+                    .with_related(vec!["adr-0001.md".to_string(), "adr-0009.md".to_string()])
+                    .with_supersedes(vec!["adr-0001".to_string()]),
+            ),
+            adr("adr-0001", Frontmatter::new("One")),
+        ];
+
+        let rule = RelatedLinksRule::new();
+        assert_eq!(rule.code(), "V0010");
+
+        let mut report = ValidationReport::new();
+        rule.validate(&adrs, &mut report);
+        assert_eq!(report.error_count(), 1);
+        assert_eq!(
+            report.issues()[0].message,
+            "'related' reference 'adr-0009.md' does not match any ADR"
+        );
+    }
+
+    #[test]
+    fn test_related_links_rule_wikilinks() {
+        let adrs = [Adr::new(
+            AdrId::new("adr-0002"),
+            "adr-0002.md".to_string(),
+            PathBuf::from("adr-0002.md"),
+            Frontmatter::new("Two"),
+            String::new(),
+            String::new(),
+            String::new(),
+        )
+        .with_wikilinks(vec!["adr-0002".to_string(), "adr-0001".to_string()])];
+
+        let mut report = ValidationReport::new();
+        RelatedLinksRule::new().validate(&adrs, &mut report);
+        assert_eq!(report.error_count(), 1);
+        assert_eq!(
+            report.issues()[0].message,
+            "'wikilink' reference 'adr-0001' does not match any ADR"
+        );
+    }
+
+    #[test]
+    fn test_tag_format_rule() {
+        let adr = Adr::new(
+            AdrId::new("test"),
+            "test.md".to_string(),
+            PathBuf::from("test.md"),
+            Frontmatter::new("Test ADR").with_tags(vec![
+                "event-sourcing".to_string(),
+                "http2".to_string(),
+                "Event Sourcing".to_string(),
+                "eventSourcing".to_string(),
+                "event_sourcing".to_string(),
+                "event--sourcing".to_string(),
+            ]),
+            String::new(),
+            String::new(),
+            String::new(),
+        );
+
+        let rule = TagFormatRule;
+        assert_eq!(rule.code(), "V0015");
+
+        let mut report = ValidationReport::new();
+        rule.validate(&adr, &mut report);
+        assert_eq!(report.warning_count(), 4);
+        assert_eq!(
+            report.issues()[0].message,
+            "tag 'Event Sourcing' is not kebab-case; use 'event-sourcing'"
+        );
+        assert!(
+            report
+                .issues()
+                .iter()
+                .all(|issue| issue.message.ends_with("use 'event-sourcing'"))
+        );
+    }
+
+    #[test]
+    fn test_vocabulary_rule() {
+        let adr = Adr::new(
+            AdrId::new("test"),
+            "test.md".to_string(),
+            PathBuf::from("test.md"),
+            Frontmatter::new("Test ADR")
+                .with_category("Infra")
+                .with_tags(vec![
+                    "database".to_string(),
+                    "databse".to_string(),
+                    "kubernetes".to_string(),
+                ]),
+            String::new(),
+            String::new(),
+            String::new(),
+        );
+        let validate = |rule: &VocabularyRule| {
+            let mut report = ValidationReport::new();
+            rule.validate(&adr, &mut report);
+            report
+        };
+
+        // Anything goes without allowed lists
+        assert_eq!(validate(&VocabularyRule::default()).warning_count(), 0);
+
+        let rule = VocabularyRule::new(
+            vec!["database".to_string(), "caching".to_string()],
+            vec!["infra".to_string(), "security".to_string()],
+        );
+        assert_eq!(rule.code(), "V0016");
+        let report = validate(&rule);
+        let messages: Vec<_> = report.issues().iter().map(|i| i.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "category 'Infra' is not in the allowed category list; did you mean 'infra'?",
+                "tag 'databse' is not in the allowed tag list; did you mean 'database'?",
+                "tag 'kubernetes' is not in the allowed tag list",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("databse", "database"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("same", "same"), 0);
+    }
+
+    #[test]
+    fn test_filename_rule() {
+        let validate = |rule: &FilenameRule, filename: &str| {
+            let adr = Adr::new(
+                AdrId::new("test"),
+                filename.to_string(),
+                PathBuf::from(format!("docs/decisions/{filename}")),
+                Frontmatter::new("Test ADR"),
+                String::new(),
+                String::new(),
+                String::new(),
+            );
+            let mut report = ValidationReport::new();
+            rule.validate(&adr, &mut report);
+            report.warning_count()
+        };
+
+        let rule = FilenameRule::new(DEFAULT_FILENAME_PATTERN).unwrap();
+        assert_eq!(rule.code(), "V0014");
+        assert_eq!(validate(&rule, "adr-0001-use-postgres.md"), 0);
+        assert_eq!(validate(&rule, "0001-use-postgres.md"), 0);
+        assert_eq!(validate(&rule, "adr-0001.md"), 0);
+        assert_eq!(validate(&rule, "adr-1-use-postgres.md"), 1);
+        assert_eq!(validate(&rule, "adr-0001-Use_Postgres.md"), 1);
+        assert_eq!(validate(&rule, "notes-adr-0001.md"), 1);
+
+        // The pattern must match the whole filename
+        let rule = FilenameRule::new(r"\d{4}-[a-z]+\.md").unwrap();
+        assert_eq!(validate(&rule, "0001-cache.md"), 0);
+        assert_eq!(validate(&rule, "x0001-cache.md"), 1);
+
+        assert!(FilenameRule::new("adr-(").is_err());
+    }
+
+    #[test]
+    fn test_supersession_rule() {
+        let adr = |id: &str, frontmatter: Frontmatter| {
+            Adr::new(
+                AdrId::new(id),
+                format!("{id}.md"),
+                PathBuf::from(format!("{id}.md")),
+                frontmatter,
+                String::new(),
+                String::new(),
+                String::new(),
+            )
+        };
+        let adrs = [
+            adr(
+                "adr-0001",
+                Frontmatter::new("One")
+                    .with_status(Status::Superseded)
+                    .with_superseded_by(vec!["adr-0002".to_string()]),
+            ),
+            adr(
+                "adr-0002",
+                Frontmatter::new("Two").with_status(Status::Superseded),
+            ),
+            adr(
+                "adr-0003",
+                Frontmatter::new("Three").with_supersedes(vec!["adr-0003".to_string()]),
+            ),
+            adr(
+                "adr-0004",
+                Frontmatter::new("Four")
+                    .with_status(Status::Superseded)
+                    .with_superseded_by(vec!["adr-0009".to_string()]),
+            ),
+            adr(
+                "adr-0005",
+                Frontmatter::new("Five").with_status(Status::Superseded),
+            ),
+            adr(
+                "adr-0006",
+                Frontmatter::new("Six").with_supersedes(vec!["adr-0005".to_string()]),
+            ),
+        ];
+
+        let rule = SupersessionRule::new();
+        assert_eq!(rule.code(), "V0013");
+
+        let mut report = ValidationReport::new();
+        rule.validate(&adrs, &mut report);
+        let issues: Vec<_> = report
+            .issues()
+            .iter()
+            .map(|issue| (issue.path.to_string_lossy().into_owned(), issue.severity))
+            .collect();
+        assert_eq!(
+            issues,
+            [
+                ("adr-0001.md".to_string(), Severity::Warning),
+                ("adr-0002.md".to_string(), Severity::Warning),
+                ("adr-0003.md".to_string(), Severity::Error),
+                ("adr-0004.md".to_string(), Severity::Warning),
+            ]
+        );
+        assert_eq!(
+            report.issues()[0].message,
+            "its successor 'adr-0002' is itself superseded, with no successor"
+        );
+    }
+
+    #[test]
+    fn test_field_length_rule() {
+        let adr = Adr::new(
+            AdrId::new("test"),
+            "test.md".to_string(),
+            PathBuf::from("test.md"),
+            Frontmatter::new("é".repeat(100)).with_description("x".repeat(301)),
+            String::new(),
+            String::new(),
+            String::new(),
+        );
+        let mut report = ValidationReport::new();
+        FieldLengthRule.validate(&adr, &mut report);
+
+        assert_eq!(report.len(), 1);
+        assert_eq!(
+            report.issues()[0].message,
+            "description is 301 characters long, more than 300"
+        );
+    }
+
+    #[test]
+    fn test_title_style_rule() {
+        let check = |style: TitleStyle, title: &str| {
+            let adr = create_test_adr(title);
+            let mut report = ValidationReport::new();
+            TitleStyleRule::new(style).validate(&adr, &mut report);
+            report.issues().first().map(|issue| issue.message.clone())
+        };
+
+        for title in [
+            "Use PostgreSQL",
+            "Adopt gRPC",
+            "Bring Your Own Key",
+            "Access logs",
+        ] {
+            assert_eq!(check(TitleStyle::Imperative, title), None, "{title}");
+        }
+        for title in ["Using PostgreSQL", "Adopted gRPC", "Migrates to Kafka"] {
+            assert!(check(TitleStyle::Imperative, title).is_some(), "{title}");
+        }
+
+        assert_eq!(
+            check(
+                TitleStyle::TitleCase,
+                "Use PostgreSQL for the Order Service"
+            ),
+            None
+        );
+        assert_eq!(
+            check(
+                TitleStyle::TitleCase,
+                "use gRPC for internal services and the rest"
+            ),
+            Some(
+                "title is not in title case; use 'Use gRPC for Internal Services and the Rest'"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_orphan_rule() {
+        let adr = |id: &str, related: &[&str]| {
+            Adr::new(
+                AdrId::new(id),
+                format!("{id}.md"),
+                PathBuf::from(format!("{id}.md")),
+                Frontmatter::new(id)
+                    .with_related(related.iter().map(ToString::to_string).collect()),
+                String::new(),
+                String::new(),
+                String::new(),
+            )
+        };
+        let adrs = [
+            adr("adr-0001", &["adr-0002"]),
+            adr("adr-0002", &[]),
+            adr("adr-0003", &["adr-0003"]),
+            adr("adr-0004", &[]),
+        ];
+
+        let orphans = |rule: OrphanRule| {
+            let mut report = ValidationReport::new();
+            rule.validate(&adrs, &mut report);
+            report
+                .issues()
+                .iter()
+                .map(|issue| issue.path.display().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        // Referenced ADRs are connected; links to themselves are not
+        assert_eq!(orphans(OrphanRule::new(4)), ["adr-0003.md", "adr-0004.md"]);
+        assert!(orphans(OrphanRule::default()).is_empty());
+    }
+
+    #[test]
+    fn test_validator_runs_collection_rules() {
+        let adrs = [Adr::new(
+            AdrId::new("adr-0001"),
+            "adr-0001.md".to_string(),
+            PathBuf::from("adr-0001.md"),
+            Frontmatter::new("One").with_related(vec!["adr-0009".to_string()]),
+            String::new(),
+            String::new(),
+            String::new(),
+        )];
+
+        let validator = Validator::default().with_collection_rules(default_collection_rules());
+        let report = validator.validate_all(&adrs);
+        assert_eq!(report.error_count(), 1);
+        assert_eq!(report.issues()[0].code.as_deref(), Some("V0010"));
+    }
+
+    #[test]
+    fn test_body_sections_rule() {
+        let validate = |rule: &BodySectionsRule, body: &str| {
+            let adr = Adr::new(
+                AdrId::new("test"),
+                "test.md".to_string(),
+                PathBuf::from("test.md"),
+                Frontmatter::new("Test ADR"),
+                body.to_string(),
+                String::new(),
+                String::new(),
+            );
+            let mut report = ValidationReport::new();
+            rule.validate(&adr, &mut report);
+            report
+                .issues()
+                .iter()
+                .map(|issue| issue.message.clone())
+                .collect::<Vec<_>>()
+        };
+        let rule = BodySectionsRule::default();
+        assert_eq!(rule.code(), "V0011");
+
+        let madr = "## Context and Problem Statement\n\nWhy.\n\n\
+                    ## Decision Outcome\n\n### Consequences ###\n\n* Good\n";
+        assert!(validate(&rule, madr).is_empty());
+
+        let sparse = "# Title\n\n## context {#ctx}\n\n<!-- Describe the context -->\n\n\
+                      ## Decision\n\n### Details\n\n```markdown\n## Consequences\n```\n";
+        assert_eq!(
+            validate(&rule, sparse),
+            vec![
+                "section 'context' is empty",
+                "missing section 'Consequences'"
+            ]
+        );
+
+        let custom = BodySectionsRule::new(vec!["Options".to_string()]);
+        assert_eq!(
+            validate(&custom, "## Context\n\nWhy.\n"),
+            vec!["missing section 'Options'"]
+        );
+    }
+
+    #[test]
+    fn test_heading() {
+        assert_eq!(heading("## Context"), Some((2, "Context")));
+        assert_eq!(heading("  ### Use C# ##"), Some((3, "Use C#")));
+        assert_eq!(heading("#hashtag"), None);
+        assert_eq!(heading("####### Too deep"), None);
+    }
+
+    #[test]
+    fn test_date_consistency_rule() {
+        use time::macros::date;
+
+        let rule = DateConsistencyRule::new(date!(2025 - 06 - 01));
+        assert_eq!(rule.code(), "V0012");
+
+        let validate = |frontmatter: Frontmatter| {
+            let adr = Adr::new(
+                AdrId::new("test"),
+                "test.md".to_string(),
+                PathBuf::from("test.md"),
+                frontmatter,
+                String::new(),
+                String::new(),
+                String::new(),
+            );
+            let mut report = ValidationReport::new();
+            rule.validate(&adr, &mut report);
+            report
+                .issues()
+                .iter()
+                .map(|issue| issue.message.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let consistent = Frontmatter::new("Test ADR")
+            .with_created(date!(2025 - 01 - 15))
+            .with_updated(date!(2025 - 02 - 01));
+        assert!(validate(consistent).is_empty());
+
+        let backwards = Frontmatter::new("Test ADR")
+            .with_created(date!(2025 - 07 - 01))
+            .with_updated(date!(2025 - 02 - 01));
+        assert_eq!(
+            validate(backwards),
+            vec![
+                "'updated' (2025-02-01) is before 'created' (2025-07-01)",
+                "'created' (2025-07-01) is in the future"
+            ]
+        );
+
+        let superseded = Frontmatter::new("Test ADR")
+            .with_status(Status::Superseded)
+            .with_created(date!(2025 - 01 - 15));
+        assert_eq!(
+            validate(superseded),
+            vec!["superseded ADR has no 'updated' date recording when it was superseded"]
+        );
+    }
+
+    #[test]
+    fn test_status_history_rule() {
+        use time::macros::date;
+
+        let rule = StatusHistoryRule::new();
+        assert_eq!(rule.code(), "V0007");
+
+        let validate = |frontmatter: Frontmatter| {
+            let adr = Adr::new(
+                AdrId::new("test"),
+                "test.md".to_string(),
+                PathBuf::from("test.md"),
+                frontmatter,
+                String::new(),
+                String::new(),
+                String::new(),
+            );
+            let mut report = ValidationReport::new();
+            rule.validate(&adr, &mut report);
+            report
+                .issues()
+                .iter()
+                .map(|issue| issue.message.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let consistent = Frontmatter::new("Test ADR")
+            .with_status(Status::Accepted)
+            .with_created(date!(2025 - 01 - 10))
+            .with_updated(date!(2025 - 02 - 01))
+            .with_history(vec![
+                HistoryEntry::new(date!(2025 - 01 - 10), Status::Proposed),
+                HistoryEntry::new(date!(2025 - 02 - 01), Status::Accepted).with_note("Approved"),
+            ]);
+        assert!(validate(consistent).is_empty());
+
+        let inconsistent = Frontmatter::new("Test ADR")
+            .with_status(Status::Deprecated)
+            .with_created(date!(2025 - 01 - 10))
+            .with_updated(date!(2025 - 02 - 01))
+            .with_history(vec![
+                HistoryEntry::new(date!(2025 - 01 - 05), Status::Proposed),
+                HistoryEntry::new(date!(2025 - 03 - 01), Status::Accepted),
+                HistoryEntry::new(date!(2025 - 02 - 01), Status::Accepted),
+            ]);
+        assert_eq!(
+            validate(inconsistent),
+            vec![
+                "status history entry '2025-02-01' (accepted) is dated before the entry above it (2025-03-01)",
+                "status history entry '2025-01-05' is dated before 'created' (2025-01-10)",
+                "status history entry '2025-03-01' is dated after 'updated' (2025-02-01)",
+                "status is 'deprecated' but the last status history entry records 'accepted'",
+            ]
+        );
+
+        // Statuses no configuration knows are reported, custom ones compared by name
+        let mut unknown = Frontmatter::new("Test ADR").with_history(vec![
+            HistoryEntry::new(date!(2025 - 01 - 10), Status::Proposed),
+            HistoryEntry::new(date!(2025 - 02 - 01), Status::Proposed),
+        ]);
+        for (index, original) in [(0, "draft"), (1, "shelved")] {
+            unknown.normalized.push(Normalization {
+                field: HistoryEntry::status_field(index),
+                original: original.to_string(),
+                normalized: "proposed".to_string(),
+            });
+        }
+        unknown.apply_custom_statuses(&[CustomStatus::new("draft", Status::Proposed)]);
+        assert_eq!(
+            validate(unknown),
+            vec![
+                "status history entry '2025-02-01' has unknown status 'shelved', treated as 'proposed'"
+            ]
+        );
+    }
 }
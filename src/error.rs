@@ -39,10 +39,15 @@ pub enum Error {
     },
 
     /// YAML parsing failed.
-    #[error("YAML parsing failed in {path}")]
+    #[error(
+        "YAML parsing failed in {path}{}",
+        .location.as_ref().map_or_else(String::new, |l| format!(" at {l}"))
+    )]
     YamlParse {
         /// Path to the file that failed to parse.
         path: PathBuf,
+        /// Location of the error in the original file, if known.
+        location: Option<SourceLocation>,
         /// The underlying YAML error.
         #[source]
         source: serde_yaml::Error,
@@ -96,6 +101,256 @@ pub enum Error {
     /// JSON serialization error.
     #[error("JSON serialization failed: {0}")]
     JsonSerialize(String),
+
+    /// Configuration file error.
+    #[error("invalid configuration in {path}: {message}")]
+    InvalidConfig {
+        /// Path to the configuration file.
+        path: PathBuf,
+        /// Description of the configuration issue.
+        message: String,
+    },
+
+    /// No ADR matches an ID, file name, or number given on the command line.
+    #[error("no ADR matches '{0}'")]
+    AdrNotFound(String),
+
+    /// An ID, file name, or number given on the command line matches several ADRs.
+    #[error("'{query}' matches several ADRs: {}", .matches.join(", "))]
+    AmbiguousAdr {
+        /// The query as given.
+        query: String,
+        /// IDs of the matching ADRs.
+        matches: Vec<String>,
+    },
+
+    /// An external program (browser, editor) could not be started.
+    #[error("failed to launch {program}")]
+    Launch {
+        /// The program that was run.
+        program: String,
+        /// The underlying I/O error.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The terminal could not be set up or read from.
+    #[error("terminal error")]
+    Terminal {
+        /// The underlying I/O error.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A webhook notification could not be delivered.
+    #[error("webhook notification to {url} failed: {message}")]
+    Webhook {
+        /// The webhook URL.
+        url: String,
+        /// What went wrong.
+        message: String,
+    },
+
+    /// The generated viewer loads resources from other hosts in strict offline mode.
+    #[error("{path} references external resources: {}", .urls.join(", "))]
+    ExternalResources {
+        /// Path of the viewer that was not written.
+        path: PathBuf,
+        /// External URLs the viewer would load.
+        urls: Vec<String>,
+    },
+
+    /// The preview server could not listen on its address.
+    #[error("failed to serve on {address}")]
+    Serve {
+        /// The address that could not be bound.
+        address: String,
+        /// The underlying I/O error.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// TOML frontmatter parsing failed.
+    #[error(
+        "TOML parsing failed in {path}{}: {message}",
+        .location.as_ref().map_or_else(String::new, |l| format!(" at {l}"))
+    )]
+    TomlParse {
+        /// Path to the file that failed to parse.
+        path: PathBuf,
+        /// Location of the error in the original file, if known.
+        location: Option<SourceLocation>,
+        /// Description of what's wrong.
+        message: String,
+    },
+
+    /// Filename pattern for the validate command is not a valid regular
+    /// expression.
+    #[error("invalid filename pattern: {0}")]
+    FilenamePattern(String),
+
+    /// Frontmatter schema file is not valid JSON or not a valid JSON Schema.
+    #[error("invalid JSON Schema in {path}: {message}")]
+    InvalidSchema {
+        /// Path to the schema file.
+        path: PathBuf,
+        /// Description of what's wrong.
+        message: String,
+    },
+
+    /// A SQLite export could not be written.
+    #[error("failed to write SQLite database {path}: {message}")]
+    Sqlite {
+        /// Path of the database file.
+        path: PathBuf,
+        /// What went wrong.
+        message: String,
+    },
+
+    /// A status given on the command line is neither built in nor
+    /// configured.
+    #[error("unknown status '{status}' (expected one of: {})", .known.join(", "))]
+    UnknownStatus {
+        /// The status as given.
+        status: String,
+        /// The built-in and configured status names.
+        known: Vec<String>,
+    },
+}
+
+/// A position in a source file, with the offending line for display.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column number, in characters.
+    pub column: usize,
+    /// Text of the offending line.
+    pub source_line: String,
+}
+
+impl SourceLocation {
+    /// Computes the location of a byte index within `content`.
+    ///
+    /// Indices past the end (or inside a multi-byte character) are clamped
+    /// to the nearest preceding character boundary.
+    #[must_use]
+    pub fn from_index(content: &str, index: usize) -> Self {
+        let mut index = index.min(content.len());
+        while !content.is_char_boundary(index) {
+            index -= 1;
+        }
+
+        let line_start = content[..index].rfind('\n').map_or(0, |pos| pos + 1);
+        let line_end = content[index..]
+            .find('\n')
+            .map_or(content.len(), |pos| index + pos);
+
+        Self {
+            line: content[..line_start].matches('\n').count() + 1,
+            column: content[line_start..index].chars().count() + 1,
+            source_line: content[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+
+    /// Renders the offending line with a caret under the column.
+    ///
+    /// ```text
+    ///  3 | invalid: [unclosed bracket
+    ///    |          ^ did not find expected ',' or ']'
+    /// ```
+    #[must_use]
+    pub fn snippet(&self, label: &str) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let caret = format!("{}^ {label}", " ".repeat(self.column - 1));
+        format!(
+            "{gutter} |\n{number} | {}\n{gutter} | {}",
+            self.source_line,
+            caret.trim_end()
+        )
+    }
+}
+
+impl std::fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+impl Error {
+    /// Returns a source snippet pointing at the error, if its location is known.
+    #[must_use]
+    pub fn snippet(&self) -> Option<String> {
+        match self {
+            Self::YamlParse {
+                location: Some(location),
+                source,
+                ..
+            } => {
+                // serde_yaml appends positions relative to the frontmatter block
+                let message = source.to_string();
+                let message = message
+                    .split_once(" at line ")
+                    .map_or(message.as_str(), |(message, _)| message);
+                Some(location.snippet(message))
+            },
+            // The message is already part of the error itself
+            Self::TomlParse {
+                location: Some(location),
+                ..
+            } => Some(location.snippet("")),
+            _ => None,
+        }
+    }
+
+    /// Returns where in its file the error was found, if known.
+    #[must_use]
+    pub const fn location(&self) -> Option<&SourceLocation> {
+        match self {
+            Self::YamlParse { location, .. } | Self::TomlParse { location, .. } => {
+                location.as_ref()
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the stable code identifying this kind of error.
+    ///
+    /// Codes never change meaning between releases; `adrscope explain <CODE>`
+    /// prints likely causes and fixes.
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::FileRead { .. } => "E0001",
+            Self::FileWrite { .. } => "E0002",
+            Self::InvalidFrontmatter { .. } => "E0003",
+            Self::YamlParse { .. } => "E0004",
+            Self::MissingField { .. } => "E0005",
+            Self::TemplateRender { .. } => "E0006",
+            Self::NoAdrsFound { .. } => "E0007",
+            Self::ValidationFailed(_) => "E0008",
+            Self::InvalidFilename(_) => "E0009",
+            Self::GlobPattern(_) => "E0010",
+            Self::DateParse { .. } => "E0011",
+            Self::JsonSerialize(_) => "E0012",
+            Self::InvalidConfig { .. } => "E0013",
+            Self::AdrNotFound(_) => "E0014",
+            Self::AmbiguousAdr { .. } => "E0015",
+            Self::Launch { .. } => "E0016",
+            Self::Terminal { .. } => "E0017",
+            Self::Webhook { .. } => "E0018",
+            Self::ExternalResources { .. } => "E0019",
+            Self::Serve { .. } => "E0020",
+            Self::TomlParse { .. } => "E0021",
+            Self::FilenamePattern(_) => "E0022",
+            Self::InvalidSchema { .. } => "E0023",
+            Self::Sqlite { .. } => "E0024",
+            Self::UnknownStatus { .. } => "E0025",
+        }
+    }
 }
 
 impl From<askama::Error> for Error {
@@ -188,6 +443,25 @@ mod tests {
         assert!(display.contains("invalid glob pattern"));
     }
 
+    #[test]
+    fn test_error_display_filename_pattern() {
+        let err = Error::FilenamePattern("adr-(: unclosed group".to_string());
+        let display = err.to_string();
+        assert!(display.contains("invalid filename pattern"));
+        assert!(display.contains("adr-("));
+    }
+
+    #[test]
+    fn test_error_display_invalid_schema() {
+        let err = Error::InvalidSchema {
+            path: PathBuf::from("adr.schema.json"),
+            message: "expected value at line 1 column 1".to_string(),
+        };
+        let display = err.to_string();
+        assert!(display.contains("invalid JSON Schema"));
+        assert!(display.contains("adr.schema.json"));
+    }
+
     #[test]
     fn test_error_display_date_parse() {
         let err = Error::DateParse {
@@ -206,6 +480,78 @@ mod tests {
         assert!(display.contains("JSON serialization failed"));
     }
 
+    #[test]
+    fn test_error_display_invalid_config() {
+        let err = Error::InvalidConfig {
+            path: PathBuf::from("adrscope.toml"),
+            message: "unknown profile 'ci'".to_string(),
+        };
+        let display = err.to_string();
+        assert!(display.contains("invalid configuration"));
+        assert!(display.contains("adrscope.toml"));
+    }
+
+    #[test]
+    fn test_error_display_ambiguous_adr() {
+        let err = Error::AmbiguousAdr {
+            query: "1".to_string(),
+            matches: vec!["adr-0001".to_string(), "payments/adr-0001".to_string()],
+        };
+        assert_eq!(
+            err.to_string(),
+            "'1' matches several ADRs: adr-0001, payments/adr-0001"
+        );
+    }
+
+    #[test]
+    fn test_error_display_external_resources() {
+        let err = Error::ExternalResources {
+            path: PathBuf::from("adrs.html"),
+            urls: vec![
+                "https://example.com/a.png".to_string(),
+                "https://cdn.example.com/b.css".to_string(),
+            ],
+        };
+        assert_eq!(
+            err.to_string(),
+            "adrs.html references external resources: https://example.com/a.png, https://cdn.example.com/b.css"
+        );
+    }
+
+    #[test]
+    fn test_source_location_from_index() {
+        let content = "---\ntitle: Test\ntags: foo\n---\n";
+
+        let location = SourceLocation::from_index(content, 22);
+        assert_eq!(location.line, 3);
+        assert_eq!(location.column, 7);
+        assert_eq!(location.source_line, "tags: foo");
+        assert_eq!(location.to_string(), "line 3, column 7");
+
+        let location = SourceLocation::from_index(content, 1000);
+        assert_eq!(location.line, 5);
+        assert_eq!(location.column, 1);
+    }
+
+    #[test]
+    fn test_source_location_snippet() {
+        let location = SourceLocation::from_index("title: Test\ntags: foo", 18);
+        assert_eq!(
+            location.snippet("expected a sequence"),
+            "  |\n2 | tags: foo\n  |       ^ expected a sequence"
+        );
+    }
+
+    #[test]
+    fn test_error_code() {
+        let err = Error::InvalidFrontmatter {
+            path: PathBuf::from("test.md"),
+            message: "missing closing delimiter".to_string(),
+        };
+        assert_eq!(err.code(), "E0003");
+        assert_eq!(Error::ValidationFailed(1).code(), "E0008");
+    }
+
     #[test]
     fn test_error_from_askama() {
         // Create an askama error and convert it
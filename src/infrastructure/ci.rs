@@ -0,0 +1,65 @@
+//! Continuous integration support.
+//!
+//! In GitHub Actions, `validate`, `stats`, and `generate` append a markdown
+//! summary to the file named by `GITHUB_STEP_SUMMARY`, which is shown on the
+//! workflow run page.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+
+/// Environment variable naming the GitHub Actions step summary file.
+pub const STEP_SUMMARY_VAR: &str = "GITHUB_STEP_SUMMARY";
+
+/// Appends markdown to the GitHub Actions step summary, if one is set.
+///
+/// Returns the path of the summary file, or `None` outside GitHub Actions.
+///
+/// # Errors
+///
+/// Returns an error if the summary file cannot be written.
+pub fn append_step_summary(markdown: &str) -> Result<Option<PathBuf>> {
+    let Some(path) = std::env::var_os(STEP_SUMMARY_VAR).filter(|path| !path.is_empty()) else {
+        return Ok(None);
+    };
+    let path = PathBuf::from(path);
+    append(&path, markdown)?;
+    Ok(Some(path))
+}
+
+/// Appends a markdown block to a file, separated from earlier blocks by a
+/// blank line.
+fn append(path: &Path, markdown: &str) -> Result<()> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| write!(file, "{}\n\n", markdown.trim_end()))
+        .map_err(|source| Error::FileWrite {
+            path: path.to_path_buf(),
+            source,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_append() {
+        let temp = TempDir::new().expect("should create temp dir");
+        let path = temp.path().join("summary.md");
+
+        append(&path, "# Validation\n").expect("should append");
+        append(&path, "# Statistics").expect("should append");
+
+        assert_eq!(
+            std::fs::read_to_string(&path).expect("should read"),
+            "# Validation\n\n# Statistics\n\n"
+        );
+        assert!(append(&temp.path().join("missing/summary.md"), "x").is_err());
+    }
+}
@@ -0,0 +1,542 @@
+//! Project configuration file support.
+//!
+//! ADRScope reads optional settings from an `adrscope.toml` file. Values from
+//! the file take precedence over built-in defaults, and command-line flags take
+//! precedence over the file.
+//!
+//! Named profiles (`[profile.<name>]`) are overlaid on the top-level settings
+//! when selected, so one file can serve several workflows:
+//!
+//! ```toml
+//! input = "docs/decisions"
+//!
+//! [profile.ci]
+//! validate.strict = true
+//! generate.reproducible = true
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::Deserialize;
+use toml::Table;
+
+use super::FileSystem;
+use super::renderer::{Analytics, BadgeRule, RecordField, Theme};
+use crate::domain::{
+    CustomStatus, GraphGrouping, OwnerRule, RuleLevel, Status, TeamMapping, TitleStyle,
+};
+use crate::error::{Error, Result};
+
+/// Name of the configuration file looked up in the working directory.
+pub const CONFIG_FILE_NAME: &str = "adrscope.toml";
+
+/// Key holding the named profiles.
+const PROFILE_KEY: &str = "profile";
+
+/// Settings loaded from an `adrscope.toml` file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Input directory containing ADR files.
+    pub input: Option<String>,
+    /// Glob pattern for matching ADR files.
+    pub pattern: Option<String>,
+    /// Glob patterns of files to leave out.
+    pub exclude: Vec<String>,
+    /// Nonstandard status values mapped to canonical statuses.
+    pub status_synonyms: BTreeMap<String, Status>,
+    /// Statuses defined on top of the built-in lifecycle.
+    pub statuses: Vec<CustomStatus>,
+    /// Authors belonging to each team.
+    pub teams: BTreeMap<String, Vec<String>>,
+    /// CODEOWNERS-style rules assigning ADR paths to teams.
+    pub owners: Vec<OwnerRule>,
+    /// Settings for the generate command.
+    pub generate: GenerateConfig,
+    /// Settings for the wiki command.
+    pub wiki: WikiConfig,
+    /// Settings for the validate command.
+    pub validate: ValidateConfig,
+    /// Settings for the build command.
+    pub build: BuildConfig,
+    /// Settings for the serve command.
+    pub serve: ServeConfig,
+}
+
+/// Settings for the generate command.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+#[allow(
+    clippy::struct_excessive_bools,
+    reason = "each bool is a configuration key mirroring a generate flag"
+)]
+pub struct GenerateConfig {
+    /// Output HTML file path.
+    pub output: Option<String>,
+    /// Page title.
+    pub title: Option<String>,
+    /// Theme preference.
+    pub theme: Option<Theme>,
+    /// Whether to produce byte-identical output for identical input.
+    pub reproducible: bool,
+    /// Whether to embed each ADR's raw markdown in the viewer data.
+    pub embed_markdown: bool,
+    /// Whether to render curly quotes, dashes, and ellipses in ADR bodies.
+    pub smart_punctuation: bool,
+    /// Optional record fields to embed, replacing the default selection.
+    pub embed_fields: Option<Vec<RecordField>>,
+    /// Optional record fields to leave out of the viewer data.
+    pub omit_fields: Vec<RecordField>,
+    /// Custom frontmatter fields shown as badges on ADR cards.
+    pub badges: Vec<BadgeRule>,
+    /// Custom frontmatter fields offered as viewer filters.
+    pub facets: Vec<String>,
+    /// Whether to write an `index.json` next to the viewer.
+    pub index: bool,
+    /// URL of the directory the viewer is served from.
+    pub base_url: Option<String>,
+    /// Whether to insert the content hash into the output file name.
+    pub hash_filename: bool,
+    /// File with HTML inserted into the viewer header.
+    pub header_html: Option<String>,
+    /// File with HTML inserted into the viewer footer.
+    pub footer_html: Option<String>,
+    /// Analytics script added to the viewer, off unless configured.
+    pub analytics: Option<Analytics>,
+    /// File with a Mermaid build embedded for drawing diagrams.
+    pub mermaid_js: Option<String>,
+    /// Largest asset, in kilobytes, inlined into the viewer.
+    pub max_inline_kb: Option<u64>,
+    /// Whether to fail when the viewer would load resources from other hosts.
+    pub strict_offline: bool,
+    /// Webhook URL notified after the viewer is generated.
+    pub notify_url: Option<String>,
+    /// File caching parsed ADRs between runs.
+    pub cache: Option<String>,
+    /// ADR attribute the viewer's graph clusters nodes by.
+    pub graph_group_by: Option<GraphGrouping>,
+}
+
+/// Settings for the wiki command.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WikiConfig {
+    /// Output directory for wiki files.
+    pub output: Option<String>,
+    /// GitHub Pages URL of the HTML viewer, linked from the index page.
+    pub pages_url: Option<String>,
+}
+
+/// Settings for the validate command.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ValidateConfig {
+    /// Whether to fail on warnings.
+    pub strict: bool,
+    /// Frontmatter fields every ADR must have.
+    pub required: Option<Vec<String>>,
+    /// Frontmatter fields every ADR should have.
+    pub recommended: Option<Vec<String>>,
+    /// Body sections every ADR is expected to have.
+    pub sections: Option<Vec<String>>,
+    /// Regular expression ADR filenames are expected to match.
+    pub filename_pattern: Option<String>,
+    /// Tags ADRs may use; any tag is allowed when empty.
+    pub allowed_tags: Vec<String>,
+    /// Categories ADRs may use; any category is allowed when empty.
+    pub allowed_categories: Vec<String>,
+    /// JSON Schema file the frontmatter of every ADR must conform to.
+    pub schema: Option<String>,
+    /// Style titles are expected to be written in.
+    pub title_style: Option<TitleStyle>,
+    /// Number of ADRs from which ADRs related to no other are reported.
+    pub orphan_threshold: Option<usize>,
+    /// Days a decision may stay proposed after its creation date.
+    pub max_proposed_age: Option<u32>,
+    /// Levels of rules, keyed by rule name or code.
+    pub rules: BTreeMap<String, RuleLevel>,
+}
+
+/// Settings for the build command.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BuildConfig {
+    /// Steps to run, in place of validate and generate.
+    pub steps: Option<Vec<BuildStep>>,
+    /// File the stats step writes to, in a format chosen by its extension.
+    pub stats_output: Option<String>,
+}
+
+/// Settings for the serve command.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServeConfig {
+    /// Host name or address to listen on.
+    pub host: Option<String>,
+    /// Port to listen on.
+    pub port: Option<u16>,
+}
+
+/// A step of the build command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BuildStep {
+    /// Validate the ADRs.
+    Validate,
+    /// Generate the HTML viewer.
+    Generate,
+    /// Generate wiki pages.
+    Wiki,
+    /// Write statistics to a file.
+    Stats,
+}
+
+impl BuildStep {
+    /// Steps run when none are configured.
+    pub const DEFAULT: &'static [Self] = &[Self::Validate, Self::Generate];
+
+    /// Returns the step name.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Validate => "validate",
+            Self::Generate => "generate",
+            Self::Wiki => "wiki",
+            Self::Stats => "stats",
+        }
+    }
+}
+
+impl Config {
+    /// Loads the configuration file at `path` if given, otherwise discovers
+    /// one in the working directory.
+    ///
+    /// Unlike discovery, an explicitly given file must exist.
+    pub fn resolve<F: FileSystem>(
+        fs: &F,
+        path: Option<&Path>,
+        profile: Option<&str>,
+    ) -> Result<Self> {
+        path.map_or_else(
+            || Self::discover(fs, profile),
+            |path| Self::load(fs, path, profile),
+        )
+    }
+
+    /// Loads the configuration file from the working directory, if present.
+    ///
+    /// Returns the default configuration when no file exists. Selecting a
+    /// profile without a configuration file is an error.
+    pub fn discover<F: FileSystem>(fs: &F, profile: Option<&str>) -> Result<Self> {
+        let path = Path::new(CONFIG_FILE_NAME);
+        if fs.exists(path) {
+            return Self::load(fs, path, profile);
+        }
+
+        profile.map_or_else(
+            || Ok(Self::default()),
+            |name| {
+                Err(Error::InvalidConfig {
+                    path: path.to_path_buf(),
+                    message: format!("profile '{name}' selected but no configuration file found"),
+                })
+            },
+        )
+    }
+
+    /// Loads a configuration file, applying the named profile if given.
+    pub fn load<F: FileSystem>(fs: &F, path: &Path, profile: Option<&str>) -> Result<Self> {
+        let content = fs.read_to_string(path)?;
+        Self::parse(&content, profile).map_err(|message| Error::InvalidConfig {
+            path: path.to_path_buf(),
+            message,
+        })
+    }
+
+    /// Parses configuration from TOML, applying the named profile if given.
+    pub fn parse(content: &str, profile: Option<&str>) -> std::result::Result<Self, String> {
+        let mut table: Table = content
+            .parse()
+            .map_err(|e: toml::de::Error| e.to_string())?;
+
+        let profiles = match table.remove(PROFILE_KEY) {
+            Some(toml::Value::Table(profiles)) => profiles,
+            Some(_) => return Err(format!("'{PROFILE_KEY}' must be a table")),
+            None => Table::new(),
+        };
+
+        if let Some(name) = profile {
+            match profiles.get(name) {
+                Some(toml::Value::Table(overlay)) => merge_tables(&mut table, overlay.clone()),
+                Some(_) => return Err(format!("profile '{name}' must be a table")),
+                None => return Err(format!("unknown profile '{name}'")),
+            }
+        }
+
+        let config = Self::deserialize(table).map_err(|e| e.to_string())?;
+        for rule in &config.owners {
+            rule.pattern()
+                .map_err(|e| format!("invalid owner path '{}': {e}", rule.path))?;
+        }
+        for (index, status) in config.statuses.iter().enumerate() {
+            status.check()?;
+            if config.statuses[..index]
+                .iter()
+                .any(|other| other.name == status.name)
+            {
+                return Err(format!("status '{}' is defined twice", status.name));
+            }
+        }
+        Ok(config)
+    }
+
+    /// Returns the mapping of ADRs to owning teams.
+    #[must_use]
+    pub fn team_mapping(&self) -> TeamMapping {
+        TeamMapping::new(self.teams.clone(), self.owners.clone())
+    }
+}
+
+/// Recursively overlays `overlay` onto `base`, replacing non-table values.
+fn merge_tables(base: &mut Table, overlay: Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(existing)), toml::Value::Table(nested)) => {
+                merge_tables(existing, nested);
+            },
+            (_, value) => {
+                base.insert(key, value);
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+
+    const SAMPLE: &str = r##"
+input = "decisions"
+exclude = ["template.md", "**/drafts/**"]
+
+[status_synonyms]
+approved = "accepted"
+draft = "proposed"
+
+[[statuses]]
+name = "rejected"
+base = "deprecated"
+color = "#7c3aed"
+emoji = "❌"
+order = 35
+
+[[statuses]]
+name = "on-hold"
+
+[teams]
+platform = ["Alice", "Bob"]
+
+[[owners]]
+path = "services/payments/"
+team = "payments"
+
+[generate]
+title = "Our ADRs"
+theme = "dark"
+omit_fields = ["body_text"]
+facets = ["cost_center"]
+graph_group_by = "category"
+
+[[generate.badges]]
+field = "risk"
+colors = { high = "#ef4444" }
+
+[wiki]
+pages_url = "https://example.github.io/project/adrs.html"
+
+[validate]
+strict = false
+title_style = "title-case"
+
+[validate.rules]
+recommended-fields = "error"
+V0014 = "off"
+
+[build]
+steps = ["validate", "generate", "wiki"]
+
+[serve]
+port = 4000
+
+[profile.ci]
+validate.strict = true
+generate.reproducible = true
+
+[profile.local.generate]
+output = "target/adrs.html"
+
+[profile.pages.generate.analytics]
+provider = "plausible"
+domain = "adrs.example.com"
+"##;
+
+    #[test]
+    fn test_parse_without_profile() {
+        let config = Config::parse(SAMPLE, None).expect("should parse");
+
+        assert_eq!(config.input.as_deref(), Some("decisions"));
+        assert_eq!(config.exclude, vec!["template.md", "**/drafts/**"]);
+        assert_eq!(
+            config.status_synonyms.get("approved"),
+            Some(&Status::Accepted)
+        );
+        assert_eq!(
+            config.statuses,
+            vec![
+                CustomStatus::new("rejected", Status::Deprecated)
+                    .with_color("#7c3aed")
+                    .with_emoji("\u{274c}")
+                    .with_order(35),
+                CustomStatus::new("on-hold", Status::Proposed),
+            ]
+        );
+        assert_eq!(config.generate.title.as_deref(), Some("Our ADRs"));
+        assert_eq!(config.generate.theme, Some(Theme::Dark));
+        assert_eq!(config.generate.omit_fields, vec![RecordField::BodyText]);
+        assert_eq!(config.generate.badges.len(), 1);
+        assert_eq!(config.generate.badges[0].field, "risk");
+        assert_eq!(config.generate.facets, vec!["cost_center"]);
+        assert_eq!(
+            config.generate.graph_group_by,
+            Some(GraphGrouping::Category)
+        );
+        assert!(config.generate.embed_fields.is_none());
+        assert!(!config.validate.strict);
+        assert_eq!(
+            config.validate.rules.get("recommended-fields"),
+            Some(&RuleLevel::Error)
+        );
+        assert_eq!(config.validate.rules.get("V0014"), Some(&RuleLevel::Off));
+        assert_eq!(config.validate.title_style, Some(TitleStyle::TitleCase));
+        assert!(!config.generate.reproducible);
+        assert_eq!(
+            config.build.steps,
+            Some(vec![
+                BuildStep::Validate,
+                BuildStep::Generate,
+                BuildStep::Wiki
+            ])
+        );
+        assert!(config.build.stats_output.is_none());
+        assert_eq!(config.serve.port, Some(4000));
+        assert!(config.serve.host.is_none());
+        assert!(config.wiki.output.is_none());
+        assert_eq!(
+            config.wiki.pages_url.as_deref(),
+            Some("https://example.github.io/project/adrs.html")
+        );
+        assert_eq!(config.teams["platform"], vec!["Alice", "Bob"]);
+        assert_eq!(
+            config.owners,
+            vec![OwnerRule::new("services/payments/", "payments")]
+        );
+        assert!(!config.team_mapping().is_empty());
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_owner_path() {
+        let err = Config::parse("[[owners]]\npath = \"docs/[\"\nteam = \"docs\"\n", None)
+            .expect_err("should reject");
+        assert!(err.contains("invalid owner path 'docs/['"), "{err}");
+    }
+
+    #[test]
+    fn test_parse_with_profile() {
+        let config = Config::parse(SAMPLE, Some("ci")).expect("should parse");
+
+        assert!(config.validate.strict);
+        assert!(config.generate.reproducible);
+        // Settings not touched by the profile are kept
+        assert_eq!(config.generate.title.as_deref(), Some("Our ADRs"));
+
+        let config = Config::parse(SAMPLE, Some("local")).expect("should parse");
+        assert_eq!(config.generate.output.as_deref(), Some("target/adrs.html"));
+        assert!(!config.validate.strict);
+        assert!(config.generate.analytics.is_none());
+
+        let config = Config::parse(SAMPLE, Some("pages")).expect("should parse");
+        assert_eq!(
+            config.generate.analytics,
+            Some(Analytics::Plausible {
+                domain: "adrs.example.com".to_string(),
+                src: None
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_profile() {
+        let result = Config::parse(SAMPLE, Some("staging"));
+        assert!(result.is_err_and(|e| e.contains("unknown profile 'staging'")));
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_keys() {
+        assert!(Config::parse("inptu = \"docs\"", None).is_err());
+        assert!(Config::parse("[generate]\ntheme = \"neon\"", None).is_err());
+        assert!(Config::parse("[status_synonyms]\nrejected = \"rejected\"", None).is_err());
+        assert!(Config::parse("[generate.analytics]\nprovider = \"ga\"", None).is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_statuses() {
+        for (content, message) in [
+            ("[[statuses]]\nname = \"accepted\"", "already built in"),
+            ("[[statuses]]\nname = \"On Hold\"", "lowercase letters"),
+            (
+                "[[statuses]]\nname = \"draft\"\ncolor = \"red\"",
+                "hex color",
+            ),
+            (
+                "[[statuses]]\nname = \"draft\"\n[[statuses]]\nname = \"draft\"",
+                "defined twice",
+            ),
+        ] {
+            let result = Config::parse(content, None);
+            assert!(result.is_err_and(|e| e.contains(message)), "{content}");
+        }
+    }
+
+    #[test]
+    fn test_discover() {
+        let fs = InMemoryFileSystem::new();
+        assert_eq!(Config::discover(&fs, None).ok(), Some(Config::default()));
+        assert!(matches!(
+            Config::discover(&fs, Some("ci")),
+            Err(Error::InvalidConfig { .. })
+        ));
+
+        fs.add_file(CONFIG_FILE_NAME, SAMPLE);
+        let config = Config::discover(&fs, Some("ci")).expect("should load");
+        assert!(config.validate.strict);
+    }
+
+    #[test]
+    fn test_resolve_explicit_path() {
+        let fs = InMemoryFileSystem::new();
+        fs.add_file(CONFIG_FILE_NAME, "input = \"docs/decisions\"\n");
+        fs.add_file("ci/adrscope.toml", "input = \"architecture\"\n");
+
+        let config =
+            Config::resolve(&fs, Some(Path::new("ci/adrscope.toml")), None).expect("should load");
+        assert_eq!(config.input.as_deref(), Some("architecture"));
+
+        let config = Config::resolve(&fs, None, None).expect("should discover");
+        assert_eq!(config.input.as_deref(), Some("docs/decisions"));
+
+        // A missing explicit file is an error rather than falling back to defaults
+        assert!(Config::resolve(&fs, Some(Path::new("missing.toml")), None).is_err());
+    }
+}
@@ -3,6 +3,7 @@
 //! This module provides a trait for filesystem operations, allowing tests
 //! to mock the filesystem without touching real files.
 
+use std::io::{BufWriter, Write};
 use std::path::{Path, PathBuf};
 
 use crate::error::{Error, Result};
@@ -12,9 +13,22 @@ pub trait FileSystem: Send + Sync {
     /// Reads the contents of a file as a UTF-8 string.
     fn read_to_string(&self, path: &Path) -> Result<String>;
 
+    /// Reads the raw contents of a file.
+    fn read_bytes(&self, path: &Path) -> Result<Vec<u8>>;
+
     /// Writes string contents to a file, creating parent directories as needed.
     fn write(&self, path: &Path, contents: &str) -> Result<()>;
 
+    /// Creates or truncates a file for writing in pieces, creating parent
+    /// directories as needed. Written data is complete once flushed.
+    fn create(&self, path: &Path) -> Result<Box<dyn Write>>;
+
+    /// Copies a file, creating parent directories of the destination as needed.
+    fn copy(&self, from: &Path, to: &Path) -> Result<()>;
+
+    /// Moves a file to a new path.
+    fn rename(&self, from: &Path, to: &Path) -> Result<()>;
+
     /// Lists all files matching a glob pattern in a directory.
     fn glob(&self, base: &Path, pattern: &str) -> Result<Vec<PathBuf>>;
 
@@ -45,6 +59,13 @@ impl FileSystem for RealFileSystem {
         })
     }
 
+    fn read_bytes(&self, path: &Path) -> Result<Vec<u8>> {
+        std::fs::read(path).map_err(|source| Error::FileRead {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
     fn write(&self, path: &Path, contents: &str) -> Result<()> {
         // Create parent directories if they don't exist
         if let Some(parent) = path.parent() {
@@ -62,6 +83,41 @@ impl FileSystem for RealFileSystem {
         })
     }
 
+    fn create(&self, path: &Path) -> Result<Box<dyn Write>> {
+        let write_error = |source| Error::FileWrite {
+            path: path.to_path_buf(),
+            source,
+        };
+        if let Some(parent) = path.parent() {
+            if !parent.exists() {
+                std::fs::create_dir_all(parent).map_err(write_error)?;
+            }
+        }
+
+        let file = std::fs::File::create(path).map_err(write_error)?;
+        Ok(Box::new(BufWriter::new(file)))
+    }
+
+    fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+        if let Some(parent) = to.parent() {
+            self.create_dir_all(parent)?;
+        }
+
+        std::fs::copy(from, to)
+            .map(|_| ())
+            .map_err(|source| Error::FileWrite {
+                path: to.to_path_buf(),
+                source,
+            })
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        std::fs::rename(from, to).map_err(|source| Error::FileWrite {
+            path: to.to_path_buf(),
+            source,
+        })
+    }
+
     fn glob(&self, base: &Path, pattern: &str) -> Result<Vec<PathBuf>> {
         let full_pattern = base.join(pattern);
         let pattern_str = full_pattern.to_string_lossy();
@@ -127,17 +183,45 @@ pub mod test_support {
             })
         }
 
+        fn read_bytes(&self, path: &Path) -> Result<Vec<u8>> {
+            self.read_to_string(path).map(String::into_bytes)
+        }
+
         fn write(&self, path: &Path, contents: &str) -> Result<()> {
             let mut files = self.files.write().expect("lock poisoned");
             files.insert(path.to_path_buf(), contents.to_string());
             Ok(())
         }
 
+        fn create(&self, path: &Path) -> Result<Box<dyn Write>> {
+            self.write(path, "")?;
+            Ok(Box::new(MemoryFile {
+                files: Arc::clone(&self.files),
+                path: path.to_path_buf(),
+                buffer: Vec::new(),
+            }))
+        }
+
+        fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+            let contents = self.read_to_string(from)?;
+            self.write(to, &contents)
+        }
+
+        fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+            let mut files = self.files.write().expect("lock poisoned");
+            let contents = files.remove(from).ok_or_else(|| Error::FileRead {
+                path: from.to_path_buf(),
+                source: std::io::Error::new(std::io::ErrorKind::NotFound, "file not found"),
+            })?;
+            files.insert(to.to_path_buf(), contents);
+            Ok(())
+        }
+
         fn glob(&self, base: &Path, pattern: &str) -> Result<Vec<PathBuf>> {
             let files = self.files.read().expect("lock poisoned");
 
             // Simple pattern matching for testing
-            // Supports "*.md" and "**/*.md"
+            // Supports file name patterns ("*.md", "adr-*.md"), optionally under "**/"
             let is_recursive = pattern.starts_with("**/");
             let suffix = if is_recursive {
                 &pattern[3..] // Remove "**/"
@@ -181,15 +265,30 @@ pub mod test_support {
         }
     }
 
+    /// A file being written to an in-memory filesystem, stored on flush.
+    struct MemoryFile {
+        files: Arc<RwLock<HashMap<PathBuf, String>>>,
+        path: PathBuf,
+        buffer: Vec<u8>,
+    }
+
+    impl Write for MemoryFile {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.buffer.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            let contents = String::from_utf8_lossy(&self.buffer).into_owned();
+            let mut files = self.files.write().expect("lock poisoned");
+            files.insert(self.path.clone(), contents);
+            Ok(())
+        }
+    }
+
     /// Simple glob pattern matching for testing.
     fn matches_simple_pattern(name: &str, pattern: &str) -> bool {
-        if pattern == "*" {
-            true
-        } else if let Some(suffix) = pattern.strip_prefix("*.") {
-            name.ends_with(&format!(".{suffix}"))
-        } else {
-            name == pattern
-        }
+        glob::Pattern::new(pattern).is_ok_and(|pattern| pattern.matches(name))
     }
 
     #[cfg(test)]
@@ -259,6 +358,19 @@ mod tests {
         assert!(path.exists());
     }
 
+    #[test]
+    fn test_real_fs_create() {
+        let temp = TempDir::new().expect("should create temp dir");
+        let path = temp.path().join("nested/out.ndjson");
+
+        let fs = RealFileSystem::new();
+        let mut file = fs.create(&path).expect("should create");
+        file.write_all(b"{}\n{}\n").expect("should write");
+        file.flush().expect("should flush");
+
+        assert_eq!(fs.read_to_string(&path).expect("should read"), "{}\n{}\n");
+    }
+
     #[test]
     fn test_real_fs_glob() {
         let temp = TempDir::new().expect("should create temp dir");
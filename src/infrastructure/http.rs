@@ -0,0 +1,268 @@
+//! Minimal HTTP server for local previews.
+//!
+//! Used by the `serve` command. Only `GET` and `HEAD` are supported, every
+//! connection carries a single request, and responses are never cached, so
+//! a reload always shows the current state of the ADRs. Not intended to be
+//! exposed beyond the local machine.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+use crate::error::{Error, Result};
+
+/// How long to wait for a client to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// An HTTP response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    /// Status code.
+    pub status: u16,
+    /// Value of the `Content-Type` header.
+    pub content_type: &'static str,
+    /// Value of the `Location` header, for redirects.
+    pub location: Option<String>,
+    /// Response body.
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    /// Creates a `200 OK` response.
+    #[must_use]
+    pub fn ok(content_type: &'static str, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status: 200,
+            content_type,
+            location: None,
+            body: body.into(),
+        }
+    }
+
+    /// Creates a `302 Found` redirect to `location`.
+    #[must_use]
+    pub fn redirect(location: impl Into<String>) -> Self {
+        Self {
+            status: 302,
+            content_type: "text/plain; charset=utf-8",
+            location: Some(location.into()),
+            body: Vec::new(),
+        }
+    }
+
+    /// Creates a `404 Not Found` response with a plain-text message.
+    #[must_use]
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::text(404, message)
+    }
+
+    /// Creates a `500 Internal Server Error` response with a plain-text message.
+    #[must_use]
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::text(500, message)
+    }
+
+    /// Creates a plain-text response with the given status.
+    fn text(status: u16, message: impl Into<String>) -> Self {
+        let mut body = message.into();
+        body.push('\n');
+        Self {
+            status,
+            content_type: "text/plain; charset=utf-8",
+            location: None,
+            body: body.into_bytes(),
+        }
+    }
+
+    /// Returns the reason phrase for the status code.
+    const fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            302 => "Found",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            _ => "Internal Server Error",
+        }
+    }
+
+    /// Writes the response, omitting the body if `head_only` is set.
+    fn write_to(&self, writer: &mut impl Write, head_only: bool) -> std::io::Result<()> {
+        write!(writer, "HTTP/1.1 {} {}\r\n", self.status, self.reason())?;
+        write!(writer, "Content-Type: {}\r\n", self.content_type)?;
+        write!(writer, "Content-Length: {}\r\n", self.body.len())?;
+        if let Some(location) = &self.location {
+            write!(writer, "Location: {location}\r\n")?;
+        }
+        write!(
+            writer,
+            "Cache-Control: no-store\r\nConnection: close\r\n\r\n"
+        )?;
+        if !head_only {
+            writer.write_all(&self.body)?;
+        }
+        writer.flush()
+    }
+}
+
+/// Binds a listener to `host:port`.
+///
+/// # Errors
+///
+/// Returns an error if the address is in use or cannot be bound.
+pub fn bind(host: &str, port: u16) -> Result<TcpListener> {
+    let address = format!("{host}:{port}");
+    TcpListener::bind(&address).map_err(|source| Error::Serve { address, source })
+}
+
+/// Serves requests on `listener` until the process is stopped.
+///
+/// Each connection is handled on its own thread, so a slow or idle client
+/// does not hold up the others. `handler` receives the decoded request path
+/// without its query string.
+pub fn serve<H>(listener: &TcpListener, handler: H)
+where
+    H: Fn(&str) -> Response + Sync,
+{
+    thread::scope(|scope| {
+        for stream in listener.incoming().flatten() {
+            let handler = &handler;
+            scope.spawn(move || {
+                // A client that goes away mid-request needs no answer
+                let _ = handle_connection(stream, handler);
+            });
+        }
+    });
+}
+
+/// Reads one request from a connection and writes the response.
+fn handle_connection<H>(stream: TcpStream, handler: &H) -> std::io::Result<()>
+where
+    H: Fn(&str) -> Response,
+{
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+    respond(&mut reader, &mut writer, handler)
+}
+
+/// Parses a request from `reader` and writes the handler's response.
+fn respond<H>(
+    reader: &mut impl BufRead,
+    writer: &mut impl Write,
+    handler: &H,
+) -> std::io::Result<()>
+where
+    H: Fn(&str) -> Response,
+{
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    // Skip the headers; nothing in them changes the response
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 0 && !header.trim_end().is_empty() {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Response::text(400, "malformed request").write_to(writer, false);
+    };
+    let head_only = method == "HEAD";
+    if method != "GET" && !head_only {
+        return Response::text(405, "only GET and HEAD are supported").write_to(writer, false);
+    }
+
+    let path = target.split(['?', '#']).next().unwrap_or_default();
+    let response = percent_decode(path).map_or_else(
+        || Response::text(400, "malformed request path"),
+        |path| handler(&path),
+    );
+    response.write_to(writer, head_only)
+}
+
+/// Decodes `%XX` escapes in a URL path, returning `None` for invalid
+/// escapes or non-UTF-8 results.
+fn percent_decode(path: &str) -> Option<String> {
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = std::str::from_utf8(bytes.get(i + 1..i + 3)?).ok()?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exchange(request: &str) -> String {
+        let mut output = Vec::new();
+        respond(&mut request.as_bytes(), &mut output, &|path: &str| {
+            if path == "/" {
+                Response::ok("text/html; charset=utf-8", "<h1>ADRs</h1>")
+            } else if let Some(id) = path.strip_prefix("/adrs/") {
+                Response::redirect(format!("/#adr={id}"))
+            } else {
+                Response::not_found(format!("{path} not found"))
+            }
+        })
+        .expect("should respond");
+        String::from_utf8(output).expect("response should be UTF-8")
+    }
+
+    #[test]
+    fn test_get() {
+        let response = exchange("GET /?q=1 HTTP/1.1\r\nHost: localhost\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Type: text/html; charset=utf-8\r\n"));
+        assert!(response.contains("Content-Length: 13\r\n"));
+        assert!(response.contains("Cache-Control: no-store\r\n"));
+        assert!(response.ends_with("\r\n\r\n<h1>ADRs</h1>"));
+    }
+
+    #[test]
+    fn test_head_omits_body() {
+        let response = exchange("HEAD / HTTP/1.1\r\n\r\n");
+        assert!(response.contains("Content-Length: 13\r\n"));
+        assert!(response.ends_with("\r\n\r\n"));
+    }
+
+    #[test]
+    fn test_redirect_and_not_found() {
+        let response = exchange("GET /adrs/adr%2D0001 HTTP/1.1\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 302 Found\r\n"));
+        assert!(response.contains("Location: /#adr=adr-0001\r\n"));
+
+        let response = exchange("GET /missing HTTP/1.1\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+        assert!(response.ends_with("/missing not found\n"));
+    }
+
+    #[test]
+    fn test_rejects_bad_requests() {
+        assert!(exchange("POST / HTTP/1.1\r\n\r\n").starts_with("HTTP/1.1 405 "));
+        assert!(exchange("\r\n").starts_with("HTTP/1.1 400 "));
+        assert!(exchange("GET /%zz HTTP/1.1\r\n\r\n").starts_with("HTTP/1.1 400 "));
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(
+            percent_decode("/adrs/payments%2Fadr-0003.md").as_deref(),
+            Some("/adrs/payments/adr-0003.md")
+        );
+        assert_eq!(percent_decode("/%E2%9C%93").as_deref(), Some("/✓"));
+        assert_eq!(percent_decode("/%4"), None);
+        assert_eq!(percent_decode("/%FF"), None);
+    }
+}
@@ -0,0 +1,109 @@
+//! Opening files in external programs.
+//!
+//! Used by the `open` command to show the viewer in the default browser and
+//! ADR sources in the user's editor.
+
+use std::fmt::Write;
+use std::path::Path;
+use std::process::Command;
+
+use crate::error::{Error, Result};
+
+/// Builds a `file://` URL for a path, with an optional fragment.
+///
+/// Relative paths are made absolute against the working directory.
+#[must_use]
+pub fn file_url(path: &Path, fragment: Option<&str>) -> String {
+    let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    let path = path.to_string_lossy().replace('\\', "/");
+
+    let mut url = String::from("file://");
+    if !path.starts_with('/') {
+        // Windows drive paths: file:///C:/...
+        url.push('/');
+    }
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~/:".contains(&byte) {
+            url.push(char::from(byte));
+        } else {
+            let _ = write!(url, "%{byte:02X}");
+        }
+    }
+    if let Some(fragment) = fragment {
+        url.push('#');
+        url.push_str(fragment);
+    }
+    url
+}
+
+/// Opens a URL or file with the platform's default application.
+///
+/// # Errors
+///
+/// Returns an error if the platform opener cannot be started.
+pub fn open_in_browser(target: &str) -> Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+    command.arg(target);
+
+    spawn(&mut command).map(|_| ())
+}
+
+/// Opens a file in `$VISUAL` or `$EDITOR`, waiting for the editor to exit.
+///
+/// Falls back to the platform's default application when neither is set.
+///
+/// # Errors
+///
+/// Returns an error if the editor cannot be started.
+pub fn open_in_editor(path: &Path) -> Result<()> {
+    let editor = ["VISUAL", "EDITOR"]
+        .into_iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.trim().is_empty());
+
+    let Some(editor) = editor else {
+        return open_in_browser(&path.to_string_lossy());
+    };
+
+    // Editors are often configured with arguments, e.g. "code --wait"
+    let mut words = editor.split_whitespace();
+    let mut command = Command::new(words.next().unwrap_or_default());
+    command.args(words).arg(path);
+
+    spawn(&mut command)?
+        .wait()
+        .map(|_| ())
+        .map_err(|source| Error::Launch {
+            program: editor.clone(),
+            source,
+        })
+}
+
+/// Starts a command, reporting the program name on failure.
+fn spawn(command: &mut Command) -> Result<std::process::Child> {
+    command.spawn().map_err(|source| Error::Launch {
+        program: command.get_program().to_string_lossy().into_owned(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(unix)]
+    fn test_file_url() {
+        let url = file_url(Path::new("/tmp/my adrs/adrs.html"), Some("adr=adr-0017"));
+        assert_eq!(url, "file:///tmp/my%20adrs/adrs.html#adr=adr-0017");
+        assert!(file_url(Path::new("adrs.html"), None).starts_with("file:///"));
+    }
+}
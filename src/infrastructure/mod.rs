@@ -1,12 +1,19 @@
 //! Infrastructure layer for external concerns.
 //!
 //! This module contains implementations that interact with external systems:
-//! filesystem, parsing libraries, and rendering.
+//! filesystem, configuration files, parsing libraries, and rendering.
 
+pub mod ci;
+pub mod config;
 pub mod fs;
+pub mod http;
+pub mod launch;
 pub mod parser;
 pub mod renderer;
+pub mod sqlite;
+pub mod webhook;
 
+pub use config::{BuildStep, CONFIG_FILE_NAME, Config};
 pub use fs::{FileSystem, RealFileSystem};
 pub use parser::{AdrParser, DefaultAdrParser};
-pub use renderer::{HtmlRenderer, RenderConfig, Theme};
+pub use renderer::{Analytics, BadgeRule, HtmlRenderer, RecordField, RenderConfig, Theme};
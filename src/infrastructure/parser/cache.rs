@@ -0,0 +1,317 @@
+//! Cache of parsed ADRs keyed by file content.
+//!
+//! Rendering each ADR's markdown dominates generation time for large
+//! collections. [`CachingParser`] wraps another parser and reuses its
+//! earlier result for every file whose content hash is unchanged, so
+//! repeated runs only parse the ADRs that were edited. The cache is a JSON
+//! file; one that cannot be read, or was written by another ADRScope
+//! version, is ignored and rebuilt.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::AdrParser;
+use crate::domain::{Adr, AdrId, Frontmatter, Normalization};
+use crate::error::{Error, Result};
+use crate::infrastructure::FileSystem;
+
+/// Contents of the cache file.
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    /// ADRScope version that wrote the cache.
+    version: String,
+    /// Parsed ADRs by source path.
+    entries: BTreeMap<String, CacheEntry>,
+}
+
+/// A parsed ADR and the hash of the content it was parsed from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    hash: String,
+    id: String,
+    filename: String,
+    frontmatter: Frontmatter,
+    // Not deserialized as part of the frontmatter, so kept alongside it
+    custom: BTreeMap<String, serde_yaml::Value>,
+    normalized: Vec<Normalization>,
+    written: Vec<String>,
+    body_markdown: String,
+    body_html: String,
+    body_text: String,
+    wikilinks: Vec<String>,
+}
+
+impl CacheEntry {
+    fn new(hash: String, adr: &Adr) -> Self {
+        let frontmatter = adr.frontmatter();
+        Self {
+            hash,
+            id: adr.id().to_string(),
+            filename: adr.filename().to_string(),
+            frontmatter: frontmatter.clone(),
+            custom: frontmatter.custom.clone(),
+            normalized: frontmatter.normalized.clone(),
+            written: frontmatter.written.clone(),
+            body_markdown: adr.body_markdown().to_string(),
+            body_html: adr.body_html().to_string(),
+            body_text: adr.body_text().to_string(),
+            wikilinks: adr.wikilinks().to_vec(),
+        }
+    }
+
+    fn to_adr(&self, path: &Path) -> Adr {
+        let mut frontmatter = self.frontmatter.clone();
+        frontmatter.custom.clone_from(&self.custom);
+        frontmatter.normalized.clone_from(&self.normalized);
+        frontmatter.written.clone_from(&self.written);
+        Adr::new(
+            AdrId::new(&self.id),
+            self.filename.clone(),
+            path.to_path_buf(),
+            frontmatter,
+            self.body_markdown.clone(),
+            self.body_html.clone(),
+            self.body_text.clone(),
+        )
+        .with_wikilinks(self.wikilinks.clone())
+    }
+}
+
+/// How many files a [`CachingParser`] took from the cache and parsed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Files whose cached result was reused.
+    pub reused: usize,
+    /// Files that were parsed because they were new or changed.
+    pub parsed: usize,
+}
+
+/// A parser that reuses cached results for unchanged files.
+#[derive(Debug)]
+pub struct CachingParser<'a, P: AdrParser> {
+    inner: &'a P,
+    previous: BTreeMap<String, CacheEntry>,
+    current: Mutex<BTreeMap<String, CacheEntry>>,
+    reused: AtomicUsize,
+}
+
+impl<'a, P: AdrParser> CachingParser<'a, P> {
+    /// Creates a caching parser with an empty cache.
+    #[must_use]
+    pub fn new(inner: &'a P) -> Self {
+        Self::with_entries(inner, BTreeMap::new())
+    }
+
+    /// Creates a caching parser primed with the cache file at `path`.
+    ///
+    /// A missing, unreadable, or outdated cache file starts an empty cache.
+    #[must_use]
+    pub fn load<F: FileSystem>(inner: &'a P, fs: &F, path: &Path) -> Self {
+        let entries = fs
+            .read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|cache| cache.version == env!("CARGO_PKG_VERSION"))
+            .map(|cache| cache.entries)
+            .unwrap_or_default();
+        Self::with_entries(inner, entries)
+    }
+
+    fn with_entries(inner: &'a P, previous: BTreeMap<String, CacheEntry>) -> Self {
+        Self {
+            inner,
+            previous,
+            current: Mutex::new(BTreeMap::new()),
+            reused: AtomicUsize::new(0),
+        }
+    }
+
+    /// Writes the files parsed through this parser to the cache file.
+    ///
+    /// Files that were not parsed this time are dropped from the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache file cannot be written.
+    pub fn save<F: FileSystem>(&self, fs: &F, path: &Path) -> Result<()> {
+        let cache = CacheFile {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            entries: self.entries().clone(),
+        };
+        let json =
+            serde_json::to_string(&cache).map_err(|e| Error::JsonSerialize(e.to_string()))?;
+        fs.write(path, &json)
+    }
+
+    /// Returns how many files were reused from the cache and parsed.
+    #[must_use]
+    pub fn stats(&self) -> CacheStats {
+        let reused = self.reused.load(Ordering::Relaxed);
+        CacheStats {
+            reused,
+            parsed: self.entries().len().saturating_sub(reused),
+        }
+    }
+
+    fn entries(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, CacheEntry>> {
+        // Entries are only ever inserted whole, so a poisoned map is intact
+        self.current.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<P: AdrParser> AdrParser for CachingParser<'_, P> {
+    fn parse(&self, path: &Path, content: &str) -> Result<Adr> {
+        let key = path.to_string_lossy().into_owned();
+        let hash = content_hash(content);
+
+        let entry = match self.previous.get(&key).filter(|entry| entry.hash == hash) {
+            Some(entry) => {
+                self.reused.fetch_add(1, Ordering::Relaxed);
+                entry.clone()
+            },
+            None => CacheEntry::new(hash, &self.inner.parse(path, content)?),
+        };
+        let adr = entry.to_adr(path);
+        self.entries().insert(key, entry);
+        Ok(adr)
+    }
+}
+
+/// Returns the hex-encoded SHA-256 of a file's content.
+fn content_hash(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .fold(String::new(), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::DefaultAdrParser;
+    use crate::infrastructure::fs::test_support::InMemoryFileSystem;
+
+    const CONTENT: &str = "---
+title: Use Kafka
+status: approved
+created: 2025/01/15
+impact: high
+ticket: ARCH-42
+history:
+  - date: 2025-01-20
+    status: accepted
+---
+
+## Decision
+
+Use **Kafka**, as in [[adr-0000]].
+";
+
+    fn parse(parser: &impl AdrParser, path: &str, content: &str) -> Adr {
+        parser
+            .parse(Path::new(path), content)
+            .expect("should parse")
+    }
+
+    #[test]
+    fn test_cached_adr_matches_parsed_adr() {
+        let fs = InMemoryFileSystem::new();
+        let inner = DefaultAdrParser::new();
+        let expected = parse(&inner, "docs/adr-0001.md", CONTENT);
+
+        let first = CachingParser::new(&inner);
+        parse(&first, "docs/adr-0001.md", CONTENT);
+        first
+            .save(&fs, Path::new("cache.json"))
+            .expect("should save");
+
+        let second = CachingParser::load(&inner, &fs, Path::new("cache.json"));
+        let cached = parse(&second, "docs/adr-0001.md", CONTENT);
+        assert_eq!(
+            second.stats(),
+            CacheStats {
+                reused: 1,
+                parsed: 0
+            }
+        );
+
+        assert_eq!(cached.id(), expected.id());
+        assert_eq!(cached.filename(), expected.filename());
+        assert_eq!(cached.source_path(), expected.source_path());
+        assert_eq!(cached.body_markdown(), expected.body_markdown());
+        assert_eq!(cached.body_html(), expected.body_html());
+        assert_eq!(cached.body_text(), expected.body_text());
+        assert_eq!(cached.wikilinks(), ["adr-0000"]);
+        assert_eq!(
+            serde_json::to_value(cached.frontmatter()).ok(),
+            serde_json::to_value(expected.frontmatter()).ok()
+        );
+        assert_eq!(cached.frontmatter().custom, expected.frontmatter().custom);
+        assert_eq!(
+            cached.frontmatter().normalized,
+            expected.frontmatter().normalized
+        );
+        assert!(!cached.frontmatter().normalized.is_empty());
+    }
+
+    #[test]
+    fn test_changed_files_are_parsed() {
+        let fs = InMemoryFileSystem::new();
+        let inner = DefaultAdrParser::new();
+
+        let first = CachingParser::new(&inner);
+        parse(&first, "docs/adr-0001.md", CONTENT);
+        parse(&first, "docs/adr-0002.md", CONTENT);
+        first
+            .save(&fs, Path::new("cache.json"))
+            .expect("should save");
+
+        let second = CachingParser::load(&inner, &fs, Path::new("cache.json"));
+        parse(&second, "docs/adr-0001.md", CONTENT);
+        let edited = parse(
+            &second,
+            "docs/adr-0002.md",
+            &CONTENT.replace("Use Kafka", "Use Pulsar"),
+        );
+        assert_eq!(edited.title(), "Use Pulsar");
+        assert_eq!(
+            second.stats(),
+            CacheStats {
+                reused: 1,
+                parsed: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_unusable_cache_is_ignored() {
+        let fs = InMemoryFileSystem::new();
+        let inner = DefaultAdrParser::new();
+
+        fs.add_file("broken.json", "{not json");
+        let parser = CachingParser::load(&inner, &fs, Path::new("broken.json"));
+        parse(&parser, "docs/adr-0001.md", CONTENT);
+        assert_eq!(parser.stats().parsed, 1);
+
+        let outdated = format!(
+            "{{\"version\":\"0.0.0\",\"entries\":{}}}",
+            serde_json::to_string(&*parser.entries()).expect("should serialize")
+        );
+        fs.add_file("outdated.json", outdated);
+        let parser = CachingParser::load(&inner, &fs, Path::new("outdated.json"));
+        parse(&parser, "docs/adr-0001.md", CONTENT);
+        assert_eq!(parser.stats().reused, 0);
+
+        let parser = CachingParser::load(&inner, &fs, Path::new("missing.json"));
+        parse(&parser, "docs/adr-0001.md", CONTENT);
+        assert_eq!(parser.stats().parsed, 1);
+    }
+}
@@ -0,0 +1,154 @@
+//! Classic ADRs without frontmatter.
+//!
+//! Collections written with adr-tools (Michael Nygard's template) or early
+//! MADR versions keep their metadata in the markdown itself:
+//!
+//! ```markdown
+//! # 2. Use PostgreSQL
+//!
+//! Date: 2018-02-10
+//!
+//! ## Status
+//!
+//! Accepted
+//! ```
+//!
+//! MADR writes `* Status: accepted`, `* Date: 2018-02-10`, and `* Deciders:
+//! Alice, Bob` list items under the title instead. The title, status, date,
+//! and deciders are inferred from either layout, so such collections can be
+//! read without migrating them to frontmatter.
+
+use serde_yaml::{Mapping, Value};
+
+/// Infers frontmatter fields from a classic ADR, returning them with the
+/// body that follows the title heading.
+///
+/// Returns `None` unless the file opens with a `# Title` heading and states
+/// its status, in a `## Status` section or a `Status:` line.
+pub(super) fn infer_fields(content: &str) -> Option<(Mapping, &str)> {
+    let content = content.trim_start_matches('\u{feff}').trim_start();
+    let (heading, body) = content.split_once('\n').unwrap_or((content, ""));
+    let title = strip_number(heading.trim_end().strip_prefix("# ")?.trim());
+    if title.is_empty() {
+        return None;
+    }
+    let body = body.trim_start_matches(['\n', '\r']);
+
+    let mut status = None;
+    let mut date = None;
+    let mut deciders = None;
+    let mut section = None;
+    for line in body.lines().map(str::trim) {
+        if let Some(heading) = line.strip_prefix("## ") {
+            section = Some(heading.trim().to_lowercase());
+            continue;
+        }
+        match section.as_deref() {
+            // Metadata lines between the title and the first section
+            None => match metadata(line) {
+                Some(("status", value)) => status = status.or(Some(value)),
+                Some(("date", value)) => date = date.or(Some(value)),
+                Some(("deciders", value)) => deciders = deciders.or(Some(value)),
+                _ => {},
+            },
+            Some("status") if status.is_none() && !line.is_empty() => status = Some(line),
+            _ => {},
+        }
+    }
+
+    let mut fields = Mapping::new();
+    fields.insert("title".into(), title.into());
+    fields.insert("status".into(), first_word(status?).into());
+    if let Some(date) = date.and_then(|date| date.split_whitespace().next()) {
+        fields.insert("created".into(), Value::from(date));
+    }
+    if let Some(deciders) = deciders {
+        let deciders: Vec<Value> = deciders
+            .split(',')
+            .map(str::trim)
+            .filter(|decider| !decider.is_empty())
+            .map(Value::from)
+            .collect();
+        fields.insert("deciders".into(), Value::Sequence(deciders));
+    }
+    Some((fields, body))
+}
+
+/// Splits a `Status: accepted` line, also written as a list item or with a
+/// bold key, into its lowercase key and value.
+fn metadata(line: &str) -> Option<(&'static str, &str)> {
+    let (key, value) = line.trim_start_matches(['*', '-', ' ']).split_once(':')?;
+    let key = match key.trim_end_matches('*').trim().to_lowercase().as_str() {
+        "status" => "status",
+        "date" => "date",
+        "deciders" | "decision-makers" => "deciders",
+        _ => return None,
+    };
+    Some((key, value.trim_start_matches('*').trim()))
+}
+
+/// Returns the lowercase first word of a status line, so `Superseded by
+/// [3. Use MySQL](0003-use-mysql.md)` reads as `superseded`.
+fn first_word(status: &str) -> String {
+    status
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+/// Removes the `2. ` numbering adr-tools puts in front of titles.
+fn strip_number(title: &str) -> &str {
+    title
+        .split_once(". ")
+        .filter(|(number, _)| !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()))
+        .map_or(title, |(_, title)| title.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field<'a>(fields: &'a Mapping, key: &str) -> Option<&'a str> {
+        fields.get(key).and_then(Value::as_str)
+    }
+
+    #[test]
+    fn test_infer_nygard_fields() {
+        let content = "# 2. Use PostgreSQL\r\n\r\nDate: 2018-02-10\r\n\r\n## Status\r\n\r\nSuperseded by [3. Use MySQL](0003-use-mysql.md)\r\n\r\n## Context\r\n\r\nWe need a database.\r\n";
+        let (fields, body) = infer_fields(content).expect("should infer");
+
+        assert_eq!(field(&fields, "title"), Some("Use PostgreSQL"));
+        assert_eq!(field(&fields, "status"), Some("superseded"));
+        assert_eq!(field(&fields, "created"), Some("2018-02-10"));
+        assert!(body.starts_with("Date: 2018-02-10"));
+        assert!(body.contains("We need a database."));
+    }
+
+    #[test]
+    fn test_infer_madr_fields() {
+        let content = "# Use Markdown Architectural Decision Records\n\n* Status: accepted\n* Deciders: Oliver Kopp, Olaf Zimmermann\n* **Date:** 2017-12-20\n\n## Context and Problem Statement\n\nStatus: not metadata\n";
+        let (fields, _) = infer_fields(content).expect("should infer");
+
+        assert_eq!(
+            field(&fields, "title"),
+            Some("Use Markdown Architectural Decision Records")
+        );
+        assert_eq!(field(&fields, "status"), Some("accepted"));
+        assert_eq!(field(&fields, "created"), Some("2017-12-20"));
+        assert_eq!(
+            fields.get("deciders"),
+            Some(&Value::from(vec!["Oliver Kopp", "Olaf Zimmermann"]))
+        );
+    }
+
+    #[test]
+    fn test_infer_requires_title_and_status() {
+        assert!(infer_fields("No heading\n\n## Status\n\nAccepted\n").is_none());
+        assert!(infer_fields("# README\n\nSome decisions.\n").is_none());
+        assert!(infer_fields("## Status\n\nAccepted\n").is_none());
+        assert_eq!(strip_number("10. Use Rust"), "Use Rust");
+        assert_eq!(strip_number("v2. Use Rust"), "v2. Use Rust");
+    }
+}
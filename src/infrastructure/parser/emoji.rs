@@ -0,0 +1,189 @@
+//! Emoji shortcodes.
+//!
+//! Maps GitHub's `:shortcode:` names to emoji for the shortcodes most common in
+//! technical writing. Unknown shortcodes are left as written, as on GitHub.
+
+/// Shortcodes and their emoji, sorted by shortcode.
+const SHORTCODES: &[(&str, &str)] = &[
+    ("+1", "\u{1f44d}"),
+    ("-1", "\u{1f44e}"),
+    ("100", "\u{1f4af}"),
+    ("alarm_clock", "\u{23f0}"),
+    ("arrow_down", "\u{2b07}\u{fe0f}"),
+    ("arrow_left", "\u{2b05}\u{fe0f}"),
+    ("arrow_right", "\u{27a1}\u{fe0f}"),
+    ("arrow_up", "\u{2b06}\u{fe0f}"),
+    ("art", "\u{1f3a8}"),
+    ("bangbang", "\u{203c}\u{fe0f}"),
+    ("bar_chart", "\u{1f4ca}"),
+    ("beetle", "\u{1fab2}"),
+    ("bell", "\u{1f514}"),
+    ("bike", "\u{1f6b2}"),
+    ("bomb", "\u{1f4a3}"),
+    ("book", "\u{1f4d6}"),
+    ("bookmark", "\u{1f516}"),
+    ("books", "\u{1f4da}"),
+    ("boom", "\u{1f4a5}"),
+    ("brain", "\u{1f9e0}"),
+    ("bug", "\u{1f41b}"),
+    ("building_construction", "\u{1f3d7}\u{fe0f}"),
+    ("bulb", "\u{1f4a1}"),
+    ("calendar", "\u{1f4c6}"),
+    ("card_index", "\u{1f4c7}"),
+    ("chart_with_downwards_trend", "\u{1f4c9}"),
+    ("chart_with_upwards_trend", "\u{1f4c8}"),
+    ("check", "\u{2714}\u{fe0f}"),
+    ("clap", "\u{1f44f}"),
+    ("clipboard", "\u{1f4cb}"),
+    ("clock", "\u{1f570}\u{fe0f}"),
+    ("closed_lock_with_key", "\u{1f510}"),
+    ("cloud", "\u{2601}\u{fe0f}"),
+    ("construction", "\u{1f6a7}"),
+    ("dart", "\u{1f3af}"),
+    ("dizzy", "\u{1f4ab}"),
+    ("dollar", "\u{1f4b5}"),
+    ("exclamation", "\u{2757}"),
+    ("eyes", "\u{1f440}"),
+    ("fire", "\u{1f525}"),
+    ("floppy_disk", "\u{1f4be}"),
+    ("gear", "\u{2699}\u{fe0f}"),
+    ("gem", "\u{1f48e}"),
+    ("globe_with_meridians", "\u{1f310}"),
+    ("green_circle", "\u{1f7e2}"),
+    ("hammer", "\u{1f528}"),
+    ("hammer_and_wrench", "\u{1f6e0}\u{fe0f}"),
+    ("heart", "\u{2764}\u{fe0f}"),
+    ("heavy_check_mark", "\u{2714}\u{fe0f}"),
+    ("heavy_minus_sign", "\u{2796}"),
+    ("heavy_plus_sign", "\u{2795}"),
+    ("hourglass", "\u{231b}"),
+    ("hourglass_flowing_sand", "\u{23f3}"),
+    ("information_source", "\u{2139}\u{fe0f}"),
+    ("key", "\u{1f511}"),
+    ("label", "\u{1f3f7}\u{fe0f}"),
+    ("ledger", "\u{1f4d2}"),
+    ("link", "\u{1f517}"),
+    ("lock", "\u{1f512}"),
+    ("loudspeaker", "\u{1f4e2}"),
+    ("mag", "\u{1f50d}"),
+    ("mega", "\u{1f4e3}"),
+    ("memo", "\u{1f4dd}"),
+    ("money_with_wings", "\u{1f4b8}"),
+    ("no_entry", "\u{26d4}"),
+    ("no_entry_sign", "\u{1f6ab}"),
+    ("ok", "\u{1f197}"),
+    ("ok_hand", "\u{1f44c}"),
+    ("orange_circle", "\u{1f7e0}"),
+    ("package", "\u{1f4e6}"),
+    ("page_facing_up", "\u{1f4c4}"),
+    ("paperclip", "\u{1f4ce}"),
+    ("pencil", "\u{1f4dd}"),
+    ("pencil2", "\u{270f}\u{fe0f}"),
+    ("pin", "\u{1f4cc}"),
+    ("pushpin", "\u{1f4cc}"),
+    ("question", "\u{2753}"),
+    ("raised_hands", "\u{1f64c}"),
+    ("recycle", "\u{267b}\u{fe0f}"),
+    ("red_circle", "\u{1f534}"),
+    ("rocket", "\u{1f680}"),
+    ("rotating_light", "\u{1f6a8}"),
+    ("scroll", "\u{1f4dc}"),
+    ("shield", "\u{1f6e1}\u{fe0f}"),
+    ("smile", "\u{1f604}"),
+    ("sparkles", "\u{2728}"),
+    ("speech_balloon", "\u{1f4ac}"),
+    ("star", "\u{2b50}"),
+    ("stop_sign", "\u{1f6d1}"),
+    ("tada", "\u{1f389}"),
+    ("test_tube", "\u{1f9ea}"),
+    ("thinking", "\u{1f914}"),
+    ("thumbsdown", "\u{1f44e}"),
+    ("thumbsup", "\u{1f44d}"),
+    ("trophy", "\u{1f3c6}"),
+    ("turtle", "\u{1f422}"),
+    ("twisted_rightwards_arrows", "\u{1f500}"),
+    ("unlock", "\u{1f513}"),
+    ("warning", "\u{26a0}\u{fe0f}"),
+    ("wastebasket", "\u{1f5d1}\u{fe0f}"),
+    ("white_check_mark", "\u{2705}"),
+    ("white_circle", "\u{26aa}"),
+    ("wrench", "\u{1f527}"),
+    ("x", "\u{274c}"),
+    ("yellow_circle", "\u{1f7e1}"),
+    ("zap", "\u{26a1}"),
+];
+
+/// Returns the emoji for a shortcode name (without colons).
+#[must_use]
+pub fn lookup(name: &str) -> Option<&'static str> {
+    SHORTCODES
+        .binary_search_by(|(shortcode, _)| shortcode.cmp(&name))
+        .ok()
+        .map(|index| SHORTCODES[index].1)
+}
+
+/// Replaces every known `:shortcode:` in the text with its emoji.
+#[must_use]
+pub fn replace_shortcodes(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find(':') {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let emoji = after
+            .find(':')
+            .map(|end| &after[..end])
+            .filter(|name| is_shortcode(name))
+            .and_then(|name| lookup(name).map(|emoji| (name, emoji)));
+
+        if let Some((name, emoji)) = emoji {
+            output.push_str(emoji);
+            rest = &after[name.len() + 1..];
+        } else {
+            output.push(':');
+            rest = after;
+        }
+    }
+    output.push_str(rest);
+
+    output
+}
+
+/// Returns true if the text can be a shortcode name.
+fn is_shortcode(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '+' | '-'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shortcodes_are_sorted() {
+        assert!(SHORTCODES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(lookup("rocket"), Some("\u{1f680}"));
+        assert_eq!(lookup("+1"), Some("\u{1f44d}"));
+        assert_eq!(lookup("not_an_emoji"), None);
+    }
+
+    #[test]
+    fn test_replace_shortcodes() {
+        assert_eq!(
+            replace_shortcodes("Ship it :rocket:!"),
+            "Ship it \u{1f680}!"
+        );
+        assert_eq!(
+            replace_shortcodes(":warning: at 10:30: :unknown: :x:"),
+            "\u{26a0}\u{fe0f} at 10:30: :unknown: \u{274c}"
+        );
+        assert_eq!(replace_shortcodes("key: value"), "key: value");
+    }
+}
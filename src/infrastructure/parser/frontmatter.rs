@@ -1,13 +1,23 @@
-//! YAML frontmatter parsing.
+//! Frontmatter parsing.
 //!
-//! Extracts and parses the YAML frontmatter block from ADR files.
+//! Extracts and parses the frontmatter block from ADR files: YAML between
+//! `---` delimiters, or TOML between `+++` delimiters as written by Hugo.
+//! TOML fields are converted to the YAML value model, so both formats are
+//! read, normalized, and edited the same way. Files without frontmatter are
+//! read as classic Nygard or MADR ADRs when their headings allow it.
 
+use std::collections::BTreeMap;
+use std::ops::Range;
 use std::path::Path;
 
-use crate::domain::Frontmatter;
-use crate::error::{Error, Result};
+use serde_yaml::{Mapping, Value};
+use time::format_description::well_known::Iso8601;
 
-/// Parser for YAML frontmatter in ADR files.
+use super::classic;
+use crate::domain::{self, Frontmatter, HistoryEntry, Normalization, Status};
+use crate::error::{Error, Result, SourceLocation};
+
+/// Parser for YAML and TOML frontmatter in ADR files.
 #[derive(Debug, Clone, Default)]
 pub struct FrontmatterParser;
 
@@ -19,18 +29,53 @@ impl FrontmatterParser {
     }
 
     /// Parses frontmatter from file content, returning the frontmatter and remaining body.
+    ///
+    /// Without a frontmatter block, the title, status, and date are inferred
+    /// from the headings of a classic Nygard or MADR ADR instead.
     pub fn parse<'a>(&self, path: &Path, content: &'a str) -> Result<(Frontmatter, &'a str)> {
-        let (yaml, body) =
-            extract_frontmatter(content).ok_or_else(|| Error::InvalidFrontmatter {
-                path: path.to_path_buf(),
-                message: "missing or invalid frontmatter delimiters (---)".to_string(),
-            })?;
+        let Some((format, range, body_start)) = locate_frontmatter(content) else {
+            return parse_classic(path, content);
+        };
+        let start = range.start;
+        let text = &content[range];
 
-        let frontmatter: Frontmatter =
-            serde_yaml::from_str(yaml).map_err(|source| Error::YamlParse {
-                path: path.to_path_buf(),
-                source,
-            })?;
+        let (mut frontmatter, fields): (Frontmatter, _) =
+            match format {
+                Format::Yaml => {
+                    let frontmatter =
+                        serde_yaml::from_str(text).map_err(|source| Error::YamlParse {
+                            path: path.to_path_buf(),
+                            // serde_yaml reports positions within the frontmatter block
+                            location: source.location().map(|location| {
+                                SourceLocation::from_index(content, start + location.index())
+                            }),
+                            source,
+                        })?;
+                    let fields = match serde_yaml::from_str(text) {
+                        Ok(Value::Mapping(fields)) => Some(fields),
+                        _ => None,
+                    };
+                    (frontmatter, fields)
+                },
+                Format::Toml => {
+                    let fields = toml_fields(text).map_err(|e| Error::TomlParse {
+                        path: path.to_path_buf(),
+                        // Spans are also relative to the frontmatter block
+                        location: e
+                            .span()
+                            .map(|span| SourceLocation::from_index(content, start + span.start)),
+                        message: e.message().to_string(),
+                    })?;
+                    // Type errors carry no position once converted
+                    let frontmatter = serde_yaml::from_value(Value::Mapping(fields.clone()))
+                        .map_err(|e| Error::TomlParse {
+                            path: path.to_path_buf(),
+                            location: None,
+                            message: e.to_string(),
+                        })?;
+                    (frontmatter, Some(fields))
+                },
+            };
 
         // Validate required fields
         if frontmatter.title.is_empty() {
@@ -40,40 +85,364 @@ impl FrontmatterParser {
             });
         }
 
-        Ok((frontmatter, body))
+        if let Some(fields) = fields {
+            frontmatter.normalized = normalized_fields(&fields);
+            frontmatter.written = written_fields(&fields);
+            frontmatter.custom = custom_fields(&fields);
+        }
+
+        Ok((frontmatter, body_of(content, body_start)))
+    }
+}
+
+/// Reads a classic ADR without frontmatter.
+fn parse_classic<'a>(path: &Path, content: &'a str) -> Result<(Frontmatter, &'a str)> {
+    let invalid = |message: String| Error::InvalidFrontmatter {
+        path: path.to_path_buf(),
+        message,
+    };
+    let (fields, body) = classic::infer_fields(content).ok_or_else(|| {
+        invalid(
+            "missing or invalid frontmatter delimiters (--- or +++), and no '# Title' heading \
+             with a status to read instead"
+                .to_string(),
+        )
+    })?;
+
+    let mut frontmatter: Frontmatter = serde_yaml::from_value(Value::Mapping(fields.clone()))
+        .map_err(|e| invalid(e.to_string()))?;
+    frontmatter.normalized = normalized_fields(&fields);
+    frontmatter.written = written_fields(&fields);
+    Ok((frontmatter, body))
+}
+
+/// Records the fields that were accepted in a non-canonical form: dates not
+/// written as `YYYY-MM-DD` and unknown statuses, of the ADR or of a history
+/// entry, which read as `proposed`.
+fn normalized_fields(fields: &Mapping) -> Vec<Normalization> {
+    let normalization = |field: &str, original: &str, normalized: String| Normalization {
+        field: field.to_string(),
+        original: original.to_string(),
+        normalized,
+    };
+    let status = fields
+        .get("status")
+        .and_then(unknown_status)
+        .map(|status| normalization("status", status, Status::default().to_string()));
+
+    let history = fields
+        .get("history")
+        .and_then(Value::as_sequence)
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .filter_map(|(index, entry)| {
+            let status = entry.get("status").and_then(unknown_status)?;
+            Some(normalization(
+                &HistoryEntry::status_field(index),
+                status,
+                Status::default().to_string(),
+            ))
+        });
+
+    let dates = ["created", "updated", "review-by", "expires"]
+        .into_iter()
+        .filter_map(|field| {
+            let original = fields.get(field)?.as_str()?;
+            let normalized = domain::parse_date(original)?.format(&Iso8601::DATE).ok()?;
+            (normalized != original).then(|| normalization(field, original, normalized))
+        });
+
+    status.into_iter().chain(history).chain(dates).collect()
+}
+
+/// Returns a status value that is not one of the built-in statuses.
+fn unknown_status(value: &Value) -> Option<&str> {
+    value
+        .as_str()
+        .filter(|status| !status.is_empty() && status.parse::<Status>().is_err())
+}
+
+/// Collects the names of the fields given a value.
+fn written_fields(fields: &Mapping) -> Vec<String> {
+    fields
+        .iter()
+        .filter(|(_, value)| !value.is_null())
+        .filter_map(|(key, _)| key.as_str().map(ToString::to_string))
+        .collect()
+}
+
+/// Collects the fields that are not part of the schema.
+fn custom_fields(fields: &Mapping) -> BTreeMap<String, Value> {
+    fields
+        .iter()
+        .filter_map(|(key, value)| {
+            let key = key.as_str()?;
+            (!Frontmatter::FIELDS.contains(&key)).then(|| (key.to_string(), value.clone()))
+        })
+        .collect()
+}
+
+/// Parses a TOML frontmatter block into YAML values.
+fn toml_fields(toml: &str) -> std::result::Result<Mapping, toml::de::Error> {
+    let table: toml::Table = toml::from_str(toml)?;
+    match toml_to_yaml(toml::Value::Table(table)) {
+        Value::Mapping(fields) => Ok(fields),
+        _ => Ok(Mapping::new()),
     }
 }
 
-/// Extracts the YAML frontmatter block and remaining body from content.
+/// Converts a TOML value to YAML; dates and times become strings, as they
+/// are written in YAML frontmatter.
+fn toml_to_yaml(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(s) => Value::String(s),
+        toml::Value::Integer(i) => Value::Number(i.into()),
+        toml::Value::Float(f) => Value::Number(f.into()),
+        toml::Value::Boolean(b) => Value::Bool(b),
+        toml::Value::Datetime(datetime) => Value::String(datetime.to_string()),
+        toml::Value::Array(items) => Value::Sequence(items.into_iter().map(toml_to_yaml).collect()),
+        toml::Value::Table(table) => Value::Mapping(
+            table
+                .into_iter()
+                .map(|(key, value)| (Value::String(key), toml_to_yaml(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Converts a YAML value to TOML, which has no null; `null` becomes an
+/// empty string.
+fn yaml_to_toml(value: &Value) -> toml::Value {
+    match value {
+        Value::Null => toml::Value::String(String::new()),
+        Value::Bool(b) => toml::Value::Boolean(*b),
+        Value::Number(n) => n.as_i64().map_or_else(
+            || toml::Value::Float(n.as_f64().unwrap_or_default()),
+            toml::Value::Integer,
+        ),
+        Value::String(s) => toml::Value::String(s.clone()),
+        Value::Sequence(items) => toml::Value::Array(items.iter().map(yaml_to_toml).collect()),
+        Value::Mapping(fields) => toml::Value::Table(
+            fields
+                .iter()
+                .filter_map(|(key, value)| Some((key.as_str()?.to_string(), yaml_to_toml(value))))
+                .collect(),
+        ),
+        Value::Tagged(tagged) => yaml_to_toml(&tagged.value),
+    }
+}
+
+/// Reads the raw frontmatter fields of a file, including fields outside the
+/// schema.
+///
+/// Returns `None` if the content has no frontmatter block or it is not a
+/// mapping.
+#[must_use]
+pub fn read_fields(content: &str) -> Option<Mapping> {
+    let (format, range, _) = locate_frontmatter(content)?;
+    match format {
+        Format::Yaml => match serde_yaml::from_str(&content[range]) {
+            Ok(Value::Mapping(fields)) => Some(fields),
+            Ok(Value::Null) => Some(Mapping::new()),
+            _ => None,
+        },
+        Format::Toml => toml_fields(&content[range]).ok(),
+    }
+}
+
+/// Sets a top-level frontmatter field, leaving the rest of the file as written.
+///
+/// An existing field (including the indented lines of a list value) is
+/// replaced in place; a new field is added at the end of the block, or in
+/// TOML before the first table. Returns `None` if the content has no
+/// frontmatter block.
+#[must_use]
+pub fn set_field(content: &str, key: &str, value: &Value) -> Option<String> {
+    let (format, range, _) = locate_frontmatter(content)?;
+    let newline = if content.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let field = match format {
+        Format::Yaml => render_field(key, value, newline),
+        Format::Toml => format!("{key} = {}", yaml_to_toml(value)),
+    };
+    let text = &content[range.clone()];
+
+    let mut out = String::with_capacity(content.len() + field.len());
+    out.push_str(&content[..range.start]);
+    match field_range(text, key, format) {
+        Some(lines) => {
+            out.push_str(&text[..lines.start]);
+            out.push_str(&field);
+            out.push_str(&text[lines.end..]);
+        },
+        None if text.is_empty() => {
+            out.push_str(&field);
+            out.push_str(newline);
+        },
+        None => {
+            if let Some(table) = table_start(text).filter(|_| format == Format::Toml) {
+                out.push_str(&text[..table]);
+                out.push_str(&field);
+                out.push_str(newline);
+                out.push_str(&text[table..]);
+            } else {
+                out.push_str(text);
+                out.push_str(newline);
+                out.push_str(&field);
+            }
+        },
+    }
+    out.push_str(&content[range.end..]);
+    Some(out)
+}
+
+/// Renders a field as YAML lines (without a final line break), writing
+/// sequences as block lists, with the lines of mapping items indented under
+/// their dash.
+fn render_field(key: &str, value: &Value, newline: &str) -> String {
+    let scalar = |value: &Value| {
+        serde_yaml::to_string(value)
+            .map(|yaml| yaml.trim_end().to_string())
+            .unwrap_or_default()
+    };
+
+    match value {
+        Value::Sequence(items) if !items.is_empty() => {
+            let mut lines = vec![format!("{key}:")];
+            for item in items {
+                let item = scalar(item);
+                let mut item_lines = item.lines();
+                lines.push(format!("  - {}", item_lines.next().unwrap_or_default()));
+                lines.extend(item_lines.map(|line| format!("    {line}")));
+            }
+            lines.join(newline)
+        },
+        Value::Sequence(_) => format!("{key}: []"),
+        _ => format!("{key}: {}", scalar(value)),
+    }
+}
+
+/// Finds the byte range of a top-level field in a frontmatter block, from the
+/// start of its key to the end of its last value line (excluding the line
+/// break).
 ///
-/// Returns `None` if the content doesn't start with `---` or doesn't have
-/// a closing `---` delimiter.
-fn extract_frontmatter(content: &str) -> Option<(&str, &str)> {
-    // Content must start with "---"
-    let content = content.strip_prefix("---")?;
+/// Value lines following the key are indented, or start a YAML list item or
+/// close a multi-line TOML array. TOML fields inside tables are not top-level.
+fn field_range(text: &str, key: &str, format: Format) -> Option<Range<usize>> {
+    let (separator, continuation) = match format {
+        Format::Yaml => (':', [' ', '\t', '-']),
+        Format::Toml => ('=', [' ', '\t', ']']),
+    };
+    let top_level = &text[..table_start(text)
+        .filter(|_| format == Format::Toml)
+        .unwrap_or(text.len())];
+
+    let mut offset = 0;
+    let mut lines = top_level.split_inclusive('\n').map(|line| {
+        let start = offset;
+        offset += line.len();
+        (start, line.trim_end_matches(['\n', '\r']))
+    });
 
-    // Find the closing "---"
-    // We need to handle both "---\n" and just "---" at end
-    let closing_pos = find_closing_delimiter(content)?;
+    let (start, first) = lines.by_ref().find(|(_, line)| {
+        line.strip_prefix(key)
+            .is_some_and(|rest| rest.trim_start().starts_with(separator))
+    })?;
+
+    let mut end = start + first.len();
+    for (line_start, line) in lines {
+        if line.starts_with(continuation) {
+            end = line_start + line.len();
+        } else if !line.trim().is_empty() {
+            break;
+        }
+    }
+    Some(start..end)
+}
 
-    let yaml = content[..closing_pos].trim();
-    let body = content[closing_pos + 3..].trim_start_matches(['\n', '\r']);
+/// Returns the offset of the first table header in a TOML block.
+fn table_start(toml: &str) -> Option<usize> {
+    let mut offset = 0;
+    toml.split_inclusive('\n').find_map(|line| {
+        let start = offset;
+        offset += line.len();
+        line.starts_with('[').then_some(start)
+    })
+}
 
-    Some((yaml, body))
+/// Syntax of a frontmatter block, selected by its opening delimiter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    /// YAML between `---` lines.
+    Yaml,
+    /// TOML between `+++` lines.
+    Toml,
 }
 
-/// Finds the position of the closing `---` delimiter.
+/// Locates the frontmatter block, returning its format, the byte range of
+/// its contents, and the offset at which the body starts.
 ///
-/// The closing delimiter must be at the start of a line (after a newline).
-fn find_closing_delimiter(content: &str) -> Option<usize> {
-    // Look for "\n---" to find a delimiter at the start of a line
-    content.find("\n---").map(|pos| pos + 1)
+/// Tolerates a UTF-8 byte order mark, leading blank lines, CRLF line endings,
+/// and trailing whitespace after the delimiters. A YAML block may be closed
+/// by `---` or `...`, a TOML block by `+++`, including on the last line of a
+/// file without a newline.
+fn locate_frontmatter(content: &str) -> Option<(Format, Range<usize>, usize)> {
+    let mut offset = 0;
+    let mut lines = content.split_inclusive('\n').map(|line| {
+        let start = offset;
+        offset += line.len();
+        (start, line)
+    });
+
+    // Skip the byte order mark and blank lines before the opening delimiter
+    let (block_start, opening) = lines
+        .by_ref()
+        .map(|(start, line)| (start, line.trim_start_matches('\u{feff}')))
+        .find(|(_, line)| !line.trim().is_empty())?;
+    let format = match opening.trim_end() {
+        "---" => Format::Yaml,
+        "+++" => Format::Toml,
+        _ => return None,
+    };
+    let block_start = block_start + content[block_start..].find('\n')? + 1;
+
+    let (closing_start, closing) = lines.find(|(_, line)| is_closing_delimiter(line, format))?;
+    let block = &content[block_start..closing_start];
+    let start = block_start + (block.len() - block.trim_start().len());
+    let range = start..start + block.trim().len();
+
+    Some((format, range, closing_start + closing.len()))
+}
+
+/// Returns true if the line closes the block, ignoring trailing whitespace:
+/// `---` or YAML's document end marker `...` for YAML, `+++` for TOML.
+fn is_closing_delimiter(line: &str, format: Format) -> bool {
+    match format {
+        Format::Yaml => matches!(line.trim_end(), "---" | "..."),
+        Format::Toml => line.trim_end() == "+++",
+    }
+}
+
+/// Returns the body following the frontmatter, without leading line breaks.
+fn body_of(content: &str, body_start: usize) -> &str {
+    content[body_start..].trim_start_matches(['\n', '\r'])
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::path::PathBuf;
+    use time::macros::date;
+
+    /// Extracts the frontmatter block and remaining body from content.
+    fn extract_frontmatter(content: &str) -> Option<(&str, &str)> {
+        let (_, range, body_start) = locate_frontmatter(content)?;
+        Some((&content[range], body_of(content, body_start)))
+    }
 
     #[test]
     fn test_extract_frontmatter_basic() {
@@ -116,6 +485,79 @@ Paragraph 2.
         assert!(extract_frontmatter(content).is_none());
     }
 
+    #[test]
+    fn test_extract_frontmatter_tolerates_authoring_quirks() {
+        let expected = Some(("title: Test", "Body"));
+
+        // Byte order mark
+        assert_eq!(
+            extract_frontmatter("\u{feff}---\ntitle: Test\n---\nBody"),
+            expected
+        );
+        // Leading blank lines
+        assert_eq!(
+            extract_frontmatter("\n  \n---\ntitle: Test\n---\nBody"),
+            expected
+        );
+        // CRLF line endings
+        assert_eq!(
+            extract_frontmatter("---\r\ntitle: Test\r\n---\r\nBody"),
+            expected
+        );
+        // Trailing spaces after the delimiters
+        assert_eq!(
+            extract_frontmatter("--- \ntitle: Test\n---\t\nBody"),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_extract_frontmatter_document_end_marker() {
+        assert_eq!(
+            extract_frontmatter("---\ntitle: Test\n...\nBody"),
+            Some(("title: Test", "Body"))
+        );
+        // `...` only closes the block, it cannot open one
+        assert!(extract_frontmatter("...\ntitle: Test\n...\nBody").is_none());
+    }
+
+    #[test]
+    fn test_extract_frontmatter_closing_at_eof() {
+        assert_eq!(
+            extract_frontmatter("---\ntitle: Test\n---"),
+            Some(("title: Test", ""))
+        );
+        assert_eq!(
+            extract_frontmatter("---\r\ntitle: Test\r\n... "),
+            Some(("title: Test", ""))
+        );
+    }
+
+    #[test]
+    fn test_extract_frontmatter_rejects_non_delimiters() {
+        assert!(extract_frontmatter("----\ntitle: Test\n---\nBody").is_none());
+        assert!(extract_frontmatter("# Title\n---\ntitle: Test\n---\n").is_none());
+        // A horizontal rule-like line inside the YAML is not a delimiter
+        assert_eq!(
+            extract_frontmatter("---\ntitle: Test\n--- x\n---\nBody").map(|(yaml, _)| yaml),
+            Some("title: Test\n--- x")
+        );
+    }
+
+    #[test]
+    fn test_extract_frontmatter_toml() {
+        assert_eq!(
+            extract_frontmatter("+++\ntitle = \"Test\"\n+++ \nBody"),
+            Some(("title = \"Test\"", "Body"))
+        );
+        // Each format is closed by its own delimiter
+        assert!(extract_frontmatter("+++\ntitle = \"Test\"\n---\nBody").is_none());
+        assert_eq!(
+            locate_frontmatter("+++\ntitle = \"Test\"\n+++\n").map(|(format, ..)| format),
+            Some(Format::Toml)
+        );
+    }
+
     #[test]
     fn test_extract_frontmatter_missing_closing() {
         let content = r"---
@@ -206,6 +648,35 @@ Body
         assert!(matches!(result, Err(Error::YamlParse { .. })));
     }
 
+    #[test]
+    fn test_parse_frontmatter_error_location() {
+        let content = r"---
+title: Test
+status: accepted
+tags: security
+---
+Body
+";
+
+        let parser = FrontmatterParser::new();
+        let path = PathBuf::from("test.md");
+        let err = parser.parse(&path, content).unwrap_err();
+        let Error::YamlParse {
+            location: Some(location),
+            ..
+        } = &err
+        else {
+            panic!("expected a located YAML error, got {err:?}");
+        };
+
+        // Line 4 of the file, pointing at the value
+        assert_eq!(location.line, 4);
+        assert_eq!(location.column, 7);
+        assert_eq!(location.source_line, "tags: security");
+        assert!(err.to_string().ends_with("at line 4, column 7"));
+        assert!(err.snippet().unwrap().contains("^ tags: invalid type"));
+    }
+
     #[test]
     fn test_parse_frontmatter_with_dates() {
         let content = r#"---
@@ -222,6 +693,160 @@ Body
 
         assert!(frontmatter.created.is_some());
         assert!(frontmatter.updated.is_some());
+        assert!(frontmatter.normalized.is_empty());
+    }
+
+    #[test]
+    fn test_parse_frontmatter_records_unknown_status() {
+        let parser = FrontmatterParser::new();
+        let path = PathBuf::from("test.md");
+
+        let (frontmatter, _) = parser
+            .parse(&path, "---\ntitle: Status\nstatus: approved\n---\n")
+            .expect("should parse");
+        assert_eq!(frontmatter.status, Status::Proposed);
+        assert_eq!(
+            frontmatter.normalized,
+            vec![Normalization {
+                field: "status".to_string(),
+                original: "approved".to_string(),
+                normalized: "proposed".to_string(),
+            }]
+        );
+
+        let (frontmatter, _) = parser
+            .parse(&path, "---\ntitle: Status\nstatus: Accepted\n---\n")
+            .expect("should parse");
+        assert_eq!(frontmatter.status, Status::Accepted);
+        assert!(frontmatter.normalized.is_empty());
+
+        // History entries are read leniently as well
+        let (frontmatter, _) = parser
+            .parse(
+                &path,
+                "---\ntitle: Status\nstatus: accepted\nhistory:\n  - date: 2025-01-15\n    \
+                 status: proposed\n  - date: 2025-02-01\n    status: approved\n---\n",
+            )
+            .expect("should parse");
+        assert_eq!(frontmatter.history[1].status, Status::Proposed);
+        assert_eq!(
+            frontmatter.normalized,
+            vec![Normalization {
+                field: "history[1].status".to_string(),
+                original: "approved".to_string(),
+                normalized: "proposed".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_frontmatter_records_custom_fields() {
+        let content = "---\ntitle: Custom\nticket: OPS-12\npriority: 2\nreviewed: true\n\
+                       owners: [a, b]\nrisk: high\n---\n";
+
+        let parser = FrontmatterParser::new();
+        let (frontmatter, _) = parser
+            .parse(&PathBuf::from("test.md"), content)
+            .expect("should parse");
+
+        assert_eq!(
+            frontmatter.custom.keys().collect::<Vec<_>>(),
+            vec!["owners", "priority", "reviewed", "ticket"]
+        );
+        assert_eq!(frontmatter.custom_values("owners"), vec!["a", "b"]);
+        assert_eq!(frontmatter.custom_values("priority"), vec!["2"]);
+        assert_eq!(frontmatter.custom_values("reviewed"), vec!["true"]);
+        assert_eq!(frontmatter.custom_values("ticket"), vec!["OPS-12"]);
+        // Schema fields are not custom
+        assert_eq!(frontmatter.risk, Some(crate::domain::Level::High));
+    }
+
+    #[test]
+    fn test_parse_frontmatter_records_written_fields() {
+        let parser = FrontmatterParser::new();
+        let (frontmatter, _) = parser
+            .parse(
+                &PathBuf::from("test.md"),
+                "---\ntitle: Written\nstatus: accepted\ntype:\nticket: OPS-12\nowners: []\n---\n",
+            )
+            .expect("should parse");
+
+        assert_eq!(frontmatter.written, ["title", "status", "ticket", "owners"]);
+        assert!(frontmatter.has_field("status"));
+        assert!(!frontmatter.has_field("type"));
+        assert!(frontmatter.has_field("ticket"));
+        assert!(!frontmatter.has_field("owners"));
+        assert!(!frontmatter.has_field("author"));
+
+        // Without a status, the default is not counted as written
+        let (frontmatter, _) = parser
+            .parse(&PathBuf::from("test.md"), "---\ntitle: Default\n---\n")
+            .expect("should parse");
+        assert!(!frontmatter.has_field("status"));
+    }
+
+    #[test]
+    fn test_parse_frontmatter_records_normalized_dates() {
+        let content = "---\ntitle: Dates\ncreated: 2025/01/15\nupdated: 2025-01-20\n---\nBody\n";
+
+        let parser = FrontmatterParser::new();
+        let (frontmatter, _) = parser
+            .parse(&PathBuf::from("test.md"), content)
+            .expect("should parse");
+
+        assert_eq!(
+            frontmatter.normalized,
+            vec![Normalization {
+                field: "created".to_string(),
+                original: "2025/01/15".to_string(),
+                normalized: "2025-01-15".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_frontmatter_review_by() {
+        let parser = FrontmatterParser::new();
+
+        let content = "---\ntitle: Review\nreview-by: 2026-01-15\n---\nBody\n";
+        let (frontmatter, _) = parser
+            .parse(&PathBuf::from("test.md"), content)
+            .expect("should parse");
+        assert_eq!(frontmatter.review_by, Some(date!(2026 - 01 - 15)));
+        assert!(frontmatter.custom.is_empty());
+
+        let content = "---\ntitle: Review\nexpires: 15 Jan 2026\n---\nBody\n";
+        let (frontmatter, _) = parser
+            .parse(&PathBuf::from("test.md"), content)
+            .expect("should parse");
+        assert_eq!(frontmatter.review_by, Some(date!(2026 - 01 - 15)));
+        assert_eq!(frontmatter.normalized[0].field, "expires");
+    }
+
+    #[test]
+    fn test_parse_frontmatter_levels() {
+        use crate::domain::Level;
+
+        let parser = FrontmatterParser::new();
+
+        let content = "---\ntitle: Levels\nimpact: High\nrisk: low\n---\nBody\n";
+        let (frontmatter, _) = parser
+            .parse(&PathBuf::from("test.md"), content)
+            .expect("should parse");
+        assert_eq!(frontmatter.impact, Some(Level::High));
+        assert_eq!(frontmatter.risk, Some(Level::Low));
+
+        let content = "---\ntitle: Levels\nimpact: huge\n---\nBody\n";
+        let err = parser
+            .parse(&PathBuf::from("test.md"), content)
+            .expect_err("should reject unknown level");
+        let Error::YamlParse { source, .. } = &err else {
+            panic!("expected a YAML error, got {err:?}");
+        };
+        assert!(
+            source.to_string().contains("invalid level 'huge'"),
+            "{source}"
+        );
     }
 
     #[test]
@@ -241,4 +866,172 @@ Body
 
         assert_eq!(frontmatter.related, vec!["adr_0001.md", "adr_0005.md"]);
     }
+
+    #[test]
+    fn test_set_field() {
+        let content = "---\ntitle: Test\nstatus: proposed # initial\ntags:\n  - a\n  - b\n\nauthor: Team\n---\n\nBody\n";
+
+        let updated = set_field(content, "status", &Value::from("accepted")).unwrap();
+        assert_eq!(
+            updated,
+            "---\ntitle: Test\nstatus: accepted\ntags:\n  - a\n  - b\n\nauthor: Team\n---\n\nBody\n"
+        );
+
+        let updated = set_field(content, "tags", &Value::from(vec!["c"])).unwrap();
+        assert!(updated.contains("tags:\n  - c\n\nauthor: Team\n"));
+
+        let updated = set_field(content, "updated", &Value::from("2025-01-15")).unwrap();
+        assert!(updated.ends_with("author: Team\nupdated: 2025-01-15\n---\n\nBody\n"));
+
+        let updated = set_field(content, "title", &Value::from("Cache: Redis")).unwrap();
+        assert!(updated.starts_with("---\ntitle: 'Cache: Redis'\nstatus:"));
+
+        assert!(set_field("No frontmatter", "status", &Value::from("accepted")).is_none());
+    }
+
+    #[test]
+    fn test_read_fields() {
+        let fields = read_fields("---\ntitle: Test\ntags: [a]\nrisk: high\n---\nBody").unwrap();
+        assert_eq!(fields.get("risk"), Some(&Value::from("high")));
+        assert_eq!(fields.get("tags"), Some(&Value::from(vec!["a"])));
+        assert_eq!(read_fields("---\n---\nBody"), Some(Mapping::new()));
+        assert!(read_fields("---\n- a\n---\n").is_none());
+    }
+
+    #[test]
+    fn test_parse_toml_frontmatter() {
+        let content = r#"+++
+title = "Use Rust"
+status = "approved"
+created = 2025-01-15
+updated = "2025/01/20"
+tags = ["rust", "cli"]
+ticket = "ARCH-42"
++++
+
+Body here.
+"#;
+
+        let parser = FrontmatterParser::new();
+        let path = PathBuf::from("test.md");
+        let (frontmatter, body) = parser.parse(&path, content).expect("should parse");
+
+        assert_eq!(frontmatter.title, "Use Rust");
+        assert_eq!(frontmatter.tags, vec!["rust", "cli"]);
+        assert_eq!(frontmatter.created, Some(date!(2025 - 01 - 15)));
+        assert_eq!(frontmatter.updated, Some(date!(2025 - 01 - 20)));
+        assert_eq!(frontmatter.custom_values("ticket"), vec!["ARCH-42"]);
+        let normalized: Vec<&str> = frontmatter
+            .normalized
+            .iter()
+            .map(|n| n.field.as_str())
+            .collect();
+        assert_eq!(normalized, vec!["status", "updated"]);
+        assert_eq!(body.trim(), "Body here.");
+    }
+
+    #[test]
+    fn test_parse_toml_frontmatter_errors() {
+        let parser = FrontmatterParser::new();
+        let path = PathBuf::from("test.md");
+
+        let err = parser
+            .parse(
+                &path,
+                "+++\ntitle = \"Test\"\nstatus = accepted\n+++\nBody\n",
+            )
+            .unwrap_err();
+        let Error::TomlParse {
+            location: Some(location),
+            ..
+        } = &err
+        else {
+            panic!("expected a located TOML error, got {err:?}");
+        };
+        assert_eq!(location.line, 3);
+        assert_eq!(location.source_line, "status = accepted");
+        assert!(err.snippet().is_some());
+
+        let err = parser
+            .parse(
+                &path,
+                "+++\ntitle = \"Test\"\ntags = \"security\"\n+++\nBody\n",
+            )
+            .unwrap_err();
+        assert!(matches!(err, Error::TomlParse { location: None, .. }));
+
+        let err = parser
+            .parse(&path, "+++\ntitle = \"\"\n+++\nBody\n")
+            .unwrap_err();
+        assert!(matches!(err, Error::MissingField { field: "title", .. }));
+    }
+
+    #[test]
+    fn test_parse_classic_adr() {
+        let content = "# 1. Record architecture decisions\n\nDate: 2018/02/10\n\n## Status\n\nRejected\n\n## Context\n\nBody.\n";
+
+        let parser = FrontmatterParser::new();
+        let path = PathBuf::from("0001-record-architecture-decisions.md");
+        let (frontmatter, body) = parser.parse(&path, content).expect("should parse");
+
+        assert_eq!(frontmatter.title, "Record architecture decisions");
+        assert_eq!(frontmatter.created, Some(date!(2018 - 02 - 10)));
+        // Statuses outside the schema are reported as with frontmatter
+        assert_eq!(frontmatter.status, Status::Proposed);
+        let normalized: Vec<&str> = frontmatter
+            .normalized
+            .iter()
+            .map(|n| n.original.as_str())
+            .collect();
+        assert_eq!(normalized, vec!["rejected", "2018/02/10"]);
+        assert!(body.ends_with("## Context\n\nBody.\n"));
+
+        let err = parser.parse(&path, "# Notes\n\nBody.\n").unwrap_err();
+        assert!(matches!(err, Error::InvalidFrontmatter { .. }));
+    }
+
+    #[test]
+    fn test_set_field_toml() {
+        let content = "+++\ntitle = \"Test\"\nstatus = \"proposed\"\ntags = [\n  \"a\",\n  \"b\",\n]\n\n[params]\nstatus = \"kept\"\n+++\n\nBody\n";
+
+        let updated = set_field(content, "status", &Value::from("accepted")).unwrap();
+        assert_eq!(
+            updated,
+            "+++\ntitle = \"Test\"\nstatus = \"accepted\"\ntags = [\n  \"a\",\n  \"b\",\n]\n\n[params]\nstatus = \"kept\"\n+++\n\nBody\n"
+        );
+
+        let updated = set_field(content, "tags", &Value::from(vec!["c"])).unwrap();
+        assert!(updated.contains("tags = [\"c\"]\n\n[params]\n"));
+
+        // New fields stay outside tables
+        let updated = set_field(content, "updated", &Value::from("2025-01-15")).unwrap();
+        assert!(updated.contains("]\n\nupdated = \"2025-01-15\"\n[params]\n"));
+        let fields = read_fields(&updated).unwrap();
+        assert_eq!(fields.get("updated"), Some(&Value::from("2025-01-15")));
+        assert_eq!(fields.get("tags"), Some(&Value::from(vec!["a", "b"])));
+
+        assert_eq!(
+            set_field(
+                "+++\ntitle = \"T\"\n+++\n",
+                "title",
+                &Value::from("Cache: \"Redis\"")
+            )
+            .unwrap(),
+            "+++\ntitle = 'Cache: \"Redis\"'\n+++\n"
+        );
+    }
+
+    #[test]
+    fn test_set_field_preserves_line_endings() {
+        let content = "---\r\ntitle: Test\r\n---\r\nBody\r\n";
+        let updated = set_field(content, "status", &Value::from("accepted")).unwrap();
+        assert_eq!(
+            updated,
+            "---\r\ntitle: Test\r\nstatus: accepted\r\n---\r\nBody\r\n"
+        );
+        assert_eq!(
+            set_field("---\n---\nBody", "title", &Value::from("T")).unwrap(),
+            "---\ntitle: T\n---\nBody"
+        );
+    }
 }
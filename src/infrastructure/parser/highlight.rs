@@ -0,0 +1,496 @@
+//! Syntax highlighting for fenced code blocks.
+//!
+//! A small lexer for the languages most common in ADRs. It recognizes
+//! comments, strings, numbers, keywords, and literals, and wraps each in a
+//! `<span class="hl-...">` that the viewer colors for its light and dark
+//! themes. Code in any other language is left plain.
+
+use std::fmt::Write;
+
+/// How one language's tokens are written.
+struct Language {
+    /// Names used after the opening fence.
+    names: &'static [&'static str],
+    /// Reserved words.
+    keywords: &'static [&'static str],
+    /// Built-in constants such as `true` and `null`.
+    literals: &'static [&'static str],
+    /// Markers that start a comment running to the end of the line.
+    line_comments: &'static [&'static str],
+    /// Opening and closing markers of block comments.
+    block_comment: Option<(&'static str, &'static str)>,
+    /// String delimiters, longest first; each closes the string it opens.
+    strings: &'static [&'static str],
+    /// Whether keywords match regardless of case, as in SQL.
+    case_insensitive: bool,
+}
+
+const C_LIKE_LITERALS: &[&str] = &["true", "false", "null"];
+const HASH_COMMENT: &[&str] = &["#"];
+const SLASH_COMMENT: &[&str] = &["//"];
+const C_BLOCK_COMMENT: Option<(&str, &str)> = Some(("/*", "*/"));
+const QUOTES: &[&str] = &["\"", "'"];
+
+/// Supported languages.
+const LANGUAGES: &[Language] = &[
+    Language {
+        names: &["rust", "rs"],
+        keywords: &[
+            "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+            "extern", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+            "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait",
+            "type", "unsafe", "use", "where", "while",
+        ],
+        literals: &["true", "false", "None", "Some", "Ok", "Err"],
+        line_comments: SLASH_COMMENT,
+        block_comment: C_BLOCK_COMMENT,
+        strings: &["\""],
+        case_insensitive: false,
+    },
+    Language {
+        names: &["javascript", "js", "jsx", "mjs", "typescript", "ts", "tsx"],
+        keywords: &[
+            "async",
+            "await",
+            "break",
+            "case",
+            "catch",
+            "class",
+            "const",
+            "continue",
+            "default",
+            "delete",
+            "do",
+            "else",
+            "export",
+            "extends",
+            "finally",
+            "for",
+            "from",
+            "function",
+            "if",
+            "import",
+            "in",
+            "instanceof",
+            "interface",
+            "let",
+            "new",
+            "of",
+            "return",
+            "static",
+            "switch",
+            "this",
+            "throw",
+            "try",
+            "type",
+            "typeof",
+            "var",
+            "void",
+            "while",
+            "yield",
+        ],
+        literals: &["true", "false", "null", "undefined", "NaN"],
+        line_comments: SLASH_COMMENT,
+        block_comment: C_BLOCK_COMMENT,
+        strings: &["\"", "'", "`"],
+        case_insensitive: false,
+    },
+    Language {
+        names: &["python", "py"],
+        keywords: &[
+            "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del",
+            "elif", "else", "except", "finally", "for", "from", "global", "if", "import", "in",
+            "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+            "with", "yield",
+        ],
+        literals: &["True", "False", "None"],
+        line_comments: HASH_COMMENT,
+        block_comment: None,
+        strings: &["\"\"\"", "'''", "\"", "'"],
+        case_insensitive: false,
+    },
+    Language {
+        names: &["go", "golang"],
+        keywords: &[
+            "break",
+            "case",
+            "chan",
+            "const",
+            "continue",
+            "default",
+            "defer",
+            "else",
+            "fallthrough",
+            "for",
+            "func",
+            "go",
+            "goto",
+            "if",
+            "import",
+            "interface",
+            "map",
+            "package",
+            "range",
+            "return",
+            "select",
+            "struct",
+            "switch",
+            "type",
+            "var",
+        ],
+        literals: &["true", "false", "nil", "iota"],
+        line_comments: SLASH_COMMENT,
+        block_comment: C_BLOCK_COMMENT,
+        strings: &["\"", "`", "'"],
+        case_insensitive: false,
+    },
+    Language {
+        names: &[
+            "java", "kotlin", "kt", "csharp", "cs", "c#", "c", "cpp", "c++", "h", "hpp",
+        ],
+        keywords: &[
+            "abstract",
+            "break",
+            "case",
+            "catch",
+            "class",
+            "const",
+            "continue",
+            "default",
+            "do",
+            "else",
+            "enum",
+            "extends",
+            "final",
+            "finally",
+            "for",
+            "fun",
+            "if",
+            "implements",
+            "import",
+            "interface",
+            "namespace",
+            "new",
+            "override",
+            "package",
+            "private",
+            "protected",
+            "public",
+            "return",
+            "static",
+            "struct",
+            "switch",
+            "this",
+            "throw",
+            "throws",
+            "try",
+            "typedef",
+            "using",
+            "val",
+            "var",
+            "void",
+            "when",
+            "while",
+        ],
+        literals: &["true", "false", "null", "nullptr", "NULL"],
+        line_comments: SLASH_COMMENT,
+        block_comment: C_BLOCK_COMMENT,
+        strings: QUOTES,
+        case_insensitive: false,
+    },
+    Language {
+        names: &["ruby", "rb"],
+        keywords: &[
+            "begin", "class", "def", "do", "else", "elsif", "end", "ensure", "if", "module",
+            "require", "rescue", "return", "self", "unless", "until", "when", "while", "yield",
+        ],
+        literals: &["true", "false", "nil"],
+        line_comments: HASH_COMMENT,
+        block_comment: None,
+        strings: QUOTES,
+        case_insensitive: false,
+    },
+    Language {
+        names: &["sql", "postgresql", "psql", "mysql"],
+        keywords: &[
+            "add",
+            "alter",
+            "and",
+            "as",
+            "asc",
+            "by",
+            "column",
+            "constraint",
+            "create",
+            "delete",
+            "desc",
+            "distinct",
+            "drop",
+            "exists",
+            "foreign",
+            "from",
+            "group",
+            "having",
+            "index",
+            "inner",
+            "insert",
+            "into",
+            "join",
+            "key",
+            "left",
+            "limit",
+            "not",
+            "on",
+            "or",
+            "order",
+            "outer",
+            "primary",
+            "references",
+            "right",
+            "select",
+            "set",
+            "table",
+            "union",
+            "unique",
+            "update",
+            "values",
+            "view",
+            "where",
+            "with",
+        ],
+        literals: &["true", "false", "null"],
+        line_comments: &["--"],
+        block_comment: C_BLOCK_COMMENT,
+        strings: &["'"],
+        case_insensitive: true,
+    },
+    Language {
+        names: &["bash", "sh", "shell", "zsh", "console"],
+        keywords: &[
+            "case", "do", "done", "elif", "else", "esac", "export", "fi", "for", "function", "if",
+            "in", "local", "return", "then", "until", "while",
+        ],
+        literals: &["true", "false"],
+        line_comments: HASH_COMMENT,
+        block_comment: None,
+        strings: QUOTES,
+        case_insensitive: false,
+    },
+    Language {
+        names: &["yaml", "yml", "toml", "ini"],
+        keywords: &[],
+        literals: &["true", "false", "null", "yes", "no", "on", "off"],
+        line_comments: HASH_COMMENT,
+        block_comment: None,
+        strings: QUOTES,
+        case_insensitive: false,
+    },
+    Language {
+        names: &["json", "jsonc"],
+        keywords: &[],
+        literals: C_LIKE_LITERALS,
+        line_comments: SLASH_COMMENT,
+        block_comment: C_BLOCK_COMMENT,
+        strings: &["\""],
+        case_insensitive: false,
+    },
+    Language {
+        names: &["hcl", "terraform", "tf"],
+        keywords: &[
+            "data",
+            "dynamic",
+            "for",
+            "for_each",
+            "if",
+            "in",
+            "locals",
+            "module",
+            "output",
+            "provider",
+            "resource",
+            "terraform",
+            "variable",
+        ],
+        literals: C_LIKE_LITERALS,
+        line_comments: &["#", "//"],
+        block_comment: C_BLOCK_COMMENT,
+        strings: &["\""],
+        case_insensitive: false,
+    },
+];
+
+/// Highlights code as HTML, or returns `None` for an unsupported language.
+///
+/// The result is escaped and ready to place inside `<pre><code>`.
+pub fn highlight(language: &str, code: &str) -> Option<String> {
+    let language = find_language(language)?;
+    let mut html = String::with_capacity(code.len() * 2);
+    let mut rest = code;
+
+    while let Some(c) = rest.chars().next() {
+        let (class, len) = token(language, rest, c);
+        let (text, tail) = rest.split_at(len);
+        match class {
+            Some(class) => {
+                let _ = write!(html, "<span class=\"hl-{class}\">");
+                push_escaped(&mut html, text);
+                html.push_str("</span>");
+            },
+            None => push_escaped(&mut html, text),
+        }
+        rest = tail;
+    }
+
+    Some(html)
+}
+
+/// Looks up a language by the name written after the fence.
+fn find_language(name: &str) -> Option<&'static Language> {
+    LANGUAGES
+        .iter()
+        .find(|language| language.names.iter().any(|n| n.eq_ignore_ascii_case(name)))
+}
+
+/// Returns the class and byte length of the token at the start of `rest`.
+fn token(language: &Language, rest: &str, first: char) -> (Option<&'static str>, usize) {
+    if let Some((open, close)) = language.block_comment {
+        if let Some(body) = rest.strip_prefix(open) {
+            let len = body
+                .find(close)
+                .map_or(rest.len(), |end| open.len() + end + close.len());
+            return (Some("comment"), len);
+        }
+    }
+    if language
+        .line_comments
+        .iter()
+        .any(|marker| rest.starts_with(marker))
+    {
+        return (Some("comment"), rest.find('\n').unwrap_or(rest.len()));
+    }
+    if let Some(quote) = language
+        .strings
+        .iter()
+        .find(|quote| rest.starts_with(**quote))
+    {
+        return (Some("string"), string_len(rest, quote));
+    }
+    if first.is_ascii_digit() {
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+            .unwrap_or(rest.len());
+        return (Some("number"), len);
+    }
+    if first.is_alphabetic() || first == '_' {
+        let len = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        let word = &rest[..len];
+        let matches = |words: &[&str]| {
+            words.iter().any(|w| {
+                if language.case_insensitive {
+                    w.eq_ignore_ascii_case(word)
+                } else {
+                    *w == word
+                }
+            })
+        };
+        let class = if matches(language.keywords) {
+            Some("keyword")
+        } else if matches(language.literals) {
+            Some("literal")
+        } else {
+            None
+        };
+        return (class, len);
+    }
+    (None, first.len_utf8())
+}
+
+/// Returns the byte length of the string starting at `rest`, including its
+/// quotes. Backslashes escape the next character; an unterminated string
+/// runs to the end of the line, or of the code for multi-line delimiters.
+fn string_len(rest: &str, quote: &str) -> usize {
+    let multiline = quote.len() > 1 || quote == "`";
+    let mut chars = rest.char_indices().skip(quote.chars().count());
+    while let Some((index, c)) = chars.next() {
+        if c == '\\' {
+            chars.next();
+        } else if rest[index..].starts_with(quote) {
+            return index + quote.len();
+        } else if c == '\n' && !multiline {
+            return index;
+        }
+    }
+    rest.len()
+}
+
+/// Appends text with HTML special characters escaped.
+fn push_escaped(html: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            c => html.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_rust() {
+        let html =
+            highlight("rust", "fn main() { let s = \"a<b\"; } // done").expect("rust is supported");
+        assert_eq!(
+            html,
+            "<span class=\"hl-keyword\">fn</span> main() { \
+             <span class=\"hl-keyword\">let</span> s = \
+             <span class=\"hl-string\">&quot;a&lt;b&quot;</span>; } \
+             <span class=\"hl-comment\">// done</span>"
+        );
+    }
+
+    #[test]
+    fn test_highlight_tokens() {
+        let html = highlight("py", "x = 42 if y else None  # note\n'''doc\nstring'''")
+            .expect("python is supported");
+        assert!(html.contains("<span class=\"hl-number\">42</span>"));
+        assert!(html.contains("<span class=\"hl-keyword\">if</span>"));
+        assert!(html.contains("<span class=\"hl-literal\">None</span>"));
+        assert!(html.contains("<span class=\"hl-comment\"># note</span>\n"));
+        assert!(html.contains("<span class=\"hl-string\">'''doc\nstring'''</span>"));
+
+        // Keywords inside identifiers and strings are not highlighted
+        let html = highlight("js", "const format = 'if'; /* a\nb */").expect("js is supported");
+        assert!(html.contains("format"));
+        assert!(!html.contains(">for<"));
+        assert!(html.contains("<span class=\"hl-string\">'if'</span>"));
+        assert!(html.contains("<span class=\"hl-comment\">/* a\nb */</span>"));
+
+        // SQL keywords are case-insensitive
+        let html = highlight("SQL", "SELECT id FROM adrs").expect("sql is supported");
+        assert!(html.contains("<span class=\"hl-keyword\">SELECT</span>"));
+        assert!(html.contains("<span class=\"hl-keyword\">FROM</span>"));
+    }
+
+    #[test]
+    fn test_highlight_unterminated_and_escaped_strings() {
+        let html = highlight("json", "{\"a\\\"b\": 1}").expect("json is supported");
+        assert!(html.contains("<span class=\"hl-string\">&quot;a\\&quot;b&quot;</span>"));
+
+        let html = highlight("sh", "echo \"open\nnext").expect("shell is supported");
+        assert!(html.contains("<span class=\"hl-string\">&quot;open</span>\nnext"));
+    }
+
+    #[test]
+    fn test_highlight_unsupported() {
+        assert!(highlight("mermaid", "graph TD").is_none());
+        assert!(highlight("", "plain").is_none());
+        assert!(highlight("TypeScript", "let x").is_some());
+    }
+}
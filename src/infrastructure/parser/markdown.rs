@@ -1,8 +1,24 @@
 //! Markdown to HTML rendering.
 //!
-//! Uses pulldown-cmark for CommonMark-compliant markdown parsing.
+//! Uses pulldown-cmark for CommonMark-compliant markdown parsing. Headings
+//! receive the same anchors GitHub generates, so links written against the
+//! GitHub rendering of an ADR keep working in the viewer and the wiki.
+//! Fenced code in a common language is syntax highlighted, and `mermaid`
+//! blocks are kept as diagram sources for the viewer to draw. Wikilinks
+//! (`[[adr-0002]]`) become links that open the named ADR in the viewer.
 
-use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd, html};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use pulldown_cmark::{
+    BlockQuoteKind, CodeBlockKind, CowStr, Event, LinkType, Options, Parser, Tag, TagEnd,
+    TextMergeStream, html,
+};
+
+use super::{emoji, highlight};
+use crate::domain::AdrId;
+use crate::infrastructure::renderer::{adr_fragment, section_fragment};
 
 /// Renders markdown content to HTML.
 #[derive(Debug, Clone)]
@@ -25,35 +41,132 @@ impl MarkdownRenderer {
         options.insert(Options::ENABLE_STRIKETHROUGH);
         options.insert(Options::ENABLE_TASKLISTS);
         options.insert(Options::ENABLE_HEADING_ATTRIBUTES);
+        options.insert(Options::ENABLE_FOOTNOTES);
+        options.insert(Options::ENABLE_GFM);
+        options.insert(Options::ENABLE_DEFINITION_LIST);
+        options.insert(Options::ENABLE_WIKILINKS);
 
         Self { options }
     }
 
+    /// Sets whether straight quotes, `--`, `---`, and `...` are rendered as
+    /// curly quotes, dashes, and ellipses.
+    #[must_use]
+    pub fn with_smart_punctuation(mut self, smart: bool) -> Self {
+        self.options.set(Options::ENABLE_SMART_PUNCTUATION, smart);
+        self
+    }
+
     /// Renders markdown content to HTML.
+    ///
+    /// Headings without an explicit `{#id}` get a GitHub-compatible anchor.
+    /// Footnotes are numbered in order of reference and listed at the end,
+    /// each with a link back to where it was referenced. Callouts
+    /// (`> [!NOTE]` or `:::note` blocks) render as GitHub-style alerts.
+    /// Emoji shortcodes (`:rocket:`) and bare URLs are expanded as on GitHub.
+    /// Fenced code blocks are highlighted when their language is known;
+    /// `mermaid` blocks become `<pre class="mermaid">` diagram sources.
+    /// Wikilinks (`[[adr-0002]]`, `[[adr-0002#Context|the context]]`) link
+    /// to the named ADR, and optionally a section of it, in the viewer.
     #[must_use]
     pub fn render(&self, markdown: &str) -> String {
-        let parser = Parser::new_ext(markdown, self.options);
+        self.render_with_links(markdown, |_| None)
+    }
+
+    /// Renders markdown content to HTML, rewriting link and image targets.
+    ///
+    /// `rewrite` is called with each destination as written; returning
+    /// `Some` replaces it, e.g. to inline an image as a `data:` URL.
+    /// Wikilinks always point into the viewer and are not passed to it.
+    #[must_use]
+    pub fn render_with_links<F>(&self, markdown: &str, rewrite: F) -> String
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let markdown = admonitions_to_callouts(markdown);
+        let markdown = markdown.as_ref();
+        // Slugs are taken from the text as written, before shortcodes are
+        // expanded, so `## :sparkles: Features` anchors at `sparkles-features`
+        let events = with_footnotes(with_inline_extensions(with_heading_ids(
+            TextMergeStream::new(Parser::new_ext(markdown, self.options))
+                .map(callout_html)
+                .map(|event| rewrite_link(event, &rewrite))
+                .collect(),
+        )));
+        let events = with_highlighting(with_diagrams(events));
         let mut html_output = String::with_capacity(markdown.len() * 2);
-        html::push_html(&mut html_output, parser);
+        html::push_html(&mut html_output, events.into_iter());
         html_output
     }
 
+    /// Returns the destinations of all links and images, in document order.
+    ///
+    /// Wikilinks name ADRs rather than files and are left out; see
+    /// [`Self::wikilinks`].
+    #[must_use]
+    pub fn link_destinations(&self, markdown: &str) -> Vec<String> {
+        Parser::new_ext(markdown, self.options)
+            .filter_map(|event| match event {
+                Event::Start(Tag::Link {
+                    link_type: LinkType::WikiLink { .. },
+                    ..
+                }) => None,
+                Event::Start(Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. }) => {
+                    Some(dest_url.into_string())
+                },
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the destinations of all images, in document order.
+    #[must_use]
+    pub fn image_destinations(&self, markdown: &str) -> Vec<String> {
+        Parser::new_ext(markdown, self.options)
+            .filter_map(|event| match event {
+                Event::Start(Tag::Image { dest_url, .. }) => Some(dest_url.into_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the ADRs referenced by wikilinks, once each in document order.
+    ///
+    /// `[[adr-0002#Context|the context]]` references `adr-0002`.
+    #[must_use]
+    pub fn wikilinks(&self, markdown: &str) -> Vec<String> {
+        let mut seen = HashSet::new();
+        Parser::new_ext(markdown, self.options)
+            .filter_map(|event| match event {
+                Event::Start(Tag::Link {
+                    link_type: LinkType::WikiLink { .. },
+                    dest_url,
+                    ..
+                }) => {
+                    let (reference, _) = split_wikilink(&dest_url);
+                    seen.insert(reference.to_string())
+                        .then(|| reference.to_string())
+                },
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Extracts plain text from markdown for search indexing.
     #[must_use]
     pub fn render_plain_text(&self, markdown: &str) -> String {
-        let parser = Parser::new_ext(markdown, self.options);
+        let markdown = admonitions_to_callouts(markdown);
+        let parser = Parser::new_ext(&markdown, self.options);
         let mut text = String::with_capacity(markdown.len());
         let mut in_code_block = false;
 
         for event in parser {
             match event {
-                Event::Text(t) | Event::Code(t) => {
-                    if !in_code_block {
-                        if !text.is_empty() && !text.ends_with(' ') {
-                            text.push(' ');
-                        }
-                        text.push_str(&t);
+                Event::Text(t) | Event::Code(t) if !in_code_block => {
+                    if !text.is_empty() && !text.ends_with(' ') {
+                        text.push(' ');
                     }
+                    text.push_str(&t);
                 },
                 Event::Start(Tag::CodeBlock(_)) => {
                     in_code_block = true;
@@ -61,10 +174,8 @@ impl MarkdownRenderer {
                 Event::End(TagEnd::CodeBlock) => {
                     in_code_block = false;
                 },
-                Event::SoftBreak | Event::HardBreak => {
-                    if !text.is_empty() && !text.ends_with(' ') {
-                        text.push(' ');
-                    }
+                Event::SoftBreak | Event::HardBreak if !text.is_empty() && !text.ends_with(' ') => {
+                    text.push(' ');
                 },
                 _ => {},
             }
@@ -75,6 +186,513 @@ impl MarkdownRenderer {
     }
 }
 
+/// Callout kinds with their `:::` admonition name and display title.
+const CALLOUTS: &[(BlockQuoteKind, &str, &str)] = &[
+    (BlockQuoteKind::Note, "note", "Note"),
+    (BlockQuoteKind::Tip, "tip", "Tip"),
+    (BlockQuoteKind::Important, "important", "Important"),
+    (BlockQuoteKind::Warning, "warning", "Warning"),
+    (BlockQuoteKind::Caution, "caution", "Caution"),
+];
+
+/// Replaces a link or image destination when `rewrite` returns a new one.
+///
+/// Wikilinks are pointed at the viewer's deep link for the ADR they name.
+fn rewrite_link<'a, F>(event: Event<'a>, rewrite: &F) -> Event<'a>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    match event {
+        Event::Start(Tag::Link {
+            link_type: link_type @ LinkType::WikiLink { .. },
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Link {
+            link_type,
+            dest_url: CowStr::from(wikilink_href(&dest_url)),
+            title,
+            id,
+        }),
+        Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Link {
+            link_type,
+            dest_url: rewrite(&dest_url).map_or(dest_url, CowStr::from),
+            title,
+            id,
+        }),
+        Event::Start(Tag::Image {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Image {
+            link_type,
+            dest_url: rewrite(&dest_url).map_or(dest_url, CowStr::from),
+            title,
+            id,
+        }),
+        event => event,
+    }
+}
+
+/// Splits a wikilink target into the ADR reference (without any `.md`
+/// suffix) and the section after `#`, if any.
+fn split_wikilink(target: &str) -> (&str, Option<&str>) {
+    let (reference, section) = target
+        .split_once('#')
+        .map_or((target, None), |(reference, section)| {
+            (reference, Some(section))
+        });
+    let reference = reference.trim();
+    (
+        reference.strip_suffix(".md").unwrap_or(reference),
+        section.map(str::trim).filter(|s| !s.is_empty()),
+    )
+}
+
+/// Returns the viewer deep link for a wikilink target.
+fn wikilink_href(target: &str) -> String {
+    let (reference, section) = split_wikilink(target);
+    let id = AdrId::new(reference);
+    let fragment = section.map_or_else(
+        || adr_fragment(&id),
+        |section| section_fragment(&id, &slugify(section)),
+    );
+    format!("#{fragment}")
+}
+
+/// Rewrites `:::note` ... `:::` admonitions as `> [!NOTE]` callouts.
+///
+/// Unknown admonition kinds and fenced code blocks, including `:::` lines
+/// in code inside an admonition, are left untouched.
+fn admonitions_to_callouts(markdown: &str) -> Cow<'_, str> {
+    if !markdown.contains(":::") {
+        return Cow::Borrowed(markdown);
+    }
+
+    let mut output = String::with_capacity(markdown.len() + 64);
+    let mut in_fence = false;
+    let mut in_admonition = false;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        }
+
+        if in_admonition {
+            if trimmed == ":::" && !in_fence {
+                in_admonition = false;
+            } else {
+                let _ = writeln!(output, "> {line}");
+            }
+            continue;
+        }
+
+        let kind = trimmed
+            .strip_prefix(":::")
+            .map(|name| name.trim().to_lowercase())
+            .filter(|_| !in_fence)
+            .and_then(|name| CALLOUTS.iter().find(|(_, n, _)| *n == name));
+
+        if let Some((_, name, _)) = kind {
+            let _ = writeln!(output, "> [!{}]", name.to_uppercase());
+            in_admonition = true;
+        } else {
+            let _ = writeln!(output, "{line}");
+        }
+    }
+
+    Cow::Owned(output)
+}
+
+/// Renders callout blockquotes like GitHub: a titled, classed `div`.
+fn callout_html(event: Event<'_>) -> Event<'_> {
+    let title = |kind| {
+        CALLOUTS
+            .iter()
+            .find(|(k, _, _)| *k == kind)
+            .map_or(("note", "Note"), |(_, name, title)| (*name, *title))
+    };
+
+    match event {
+        Event::Start(Tag::BlockQuote(Some(kind))) => {
+            let (name, title) = title(kind);
+            Event::Html(
+                format!(
+                    "<div class=\"markdown-alert markdown-alert-{name}\">\n\
+                     <p class=\"markdown-alert-title\">{title}</p>\n"
+                )
+                .into(),
+            )
+        },
+        Event::End(TagEnd::BlockQuote(Some(_))) => Event::Html("</div>\n".into()),
+        event => event,
+    }
+}
+
+/// Renders `mermaid` code blocks as `<pre class="mermaid">`, the markup
+/// Mermaid draws diagrams from.
+fn with_diagrams(events: Vec<Event<'_>>) -> Vec<Event<'_>> {
+    let mut in_diagram = false;
+
+    events
+        .into_iter()
+        .map(|event| match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(ref info))) if is_mermaid(info) => {
+                in_diagram = true;
+                Event::Html("<pre class=\"mermaid\">".into())
+            },
+            Event::End(TagEnd::CodeBlock) if in_diagram => {
+                in_diagram = false;
+                Event::Html("</pre>\n".into())
+            },
+            event => event,
+        })
+        .collect()
+}
+
+/// Returns whether a fence's info string names a Mermaid diagram.
+fn is_mermaid(info: &str) -> bool {
+    info.split_whitespace()
+        .next()
+        .is_some_and(|language| language.eq_ignore_ascii_case("mermaid"))
+}
+
+/// Replaces the text of fenced code blocks in a known language with
+/// highlighted HTML.
+fn with_highlighting(events: Vec<Event<'_>>) -> Vec<Event<'_>> {
+    let mut language: Option<CowStr<'_>> = None;
+
+    events
+        .into_iter()
+        .map(|event| match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(ref info))) => {
+                language = info
+                    .split(|c: char| c.is_whitespace() || c == ',')
+                    .next()
+                    .map(|name| CowStr::from(name.to_string()));
+                event
+            },
+            Event::End(TagEnd::CodeBlock) => {
+                language = None;
+                event
+            },
+            Event::Text(text) => language
+                .as_deref()
+                .and_then(|language| highlight::highlight(language, &text))
+                .map_or(Event::Text(text), |html| Event::Html(html.into())),
+            event => event,
+        })
+        .collect()
+}
+
+/// Expands emoji shortcodes and autolinks bare URLs in text outside of code.
+///
+/// URLs are not linked inside existing links or images.
+fn with_inline_extensions(events: Vec<Event<'_>>) -> Vec<Event<'_>> {
+    let mut output = Vec::with_capacity(events.len());
+    let mut in_code_block = false;
+    let mut link_depth = 0usize;
+
+    for event in events {
+        match event {
+            Event::Text(text) if !in_code_block => {
+                let text = if text.contains(':') {
+                    CowStr::from(emoji::replace_shortcodes(&text))
+                } else {
+                    text
+                };
+                if link_depth == 0 {
+                    push_autolinked(&mut output, text);
+                } else {
+                    output.push(Event::Text(text));
+                }
+            },
+            event => {
+                match &event {
+                    Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+                    Event::End(TagEnd::CodeBlock) => in_code_block = false,
+                    Event::Start(Tag::Link { .. } | Tag::Image { .. }) => link_depth += 1,
+                    Event::End(TagEnd::Link | TagEnd::Image) => {
+                        link_depth = link_depth.saturating_sub(1);
+                    },
+                    _ => {},
+                }
+                output.push(event);
+            },
+        }
+    }
+
+    output
+}
+
+/// Pushes text, turning bare `http://`, `https://`, and `www.` URLs into links.
+fn push_autolinked<'a>(output: &mut Vec<Event<'a>>, text: CowStr<'a>) {
+    let mut rest: &str = &text;
+    let mut found = false;
+
+    while let Some((start, end)) = find_url(rest) {
+        found = true;
+        if start > 0 {
+            output.push(Event::Text(rest[..start].to_string().into()));
+        }
+        let url = &rest[start..end];
+        let dest = if url.starts_with("www.") {
+            format!("http://{url}")
+        } else {
+            url.to_string()
+        };
+        output.push(Event::Start(Tag::Link {
+            link_type: LinkType::Autolink,
+            dest_url: dest.into(),
+            title: CowStr::Borrowed(""),
+            id: CowStr::Borrowed(""),
+        }));
+        output.push(Event::Text(url.to_string().into()));
+        output.push(Event::End(TagEnd::Link));
+        rest = &rest[end..];
+    }
+
+    if !found {
+        output.push(Event::Text(text));
+    } else if !rest.is_empty() {
+        output.push(Event::Text(rest.to_string().into()));
+    }
+}
+
+/// Finds the first bare URL in the text, following GFM's extended autolinks.
+///
+/// A URL must start at a word boundary and ends at whitespace or `<`.
+/// Trailing punctuation and unbalanced closing parentheses are not part of
+/// the URL. Candidates whose host has no `.` are skipped.
+fn find_url(text: &str) -> Option<(usize, usize)> {
+    let mut offset = 0;
+    while let Some(start) = ["https://", "http://", "www."]
+        .iter()
+        .filter_map(|prefix| {
+            text[offset..]
+                .match_indices(prefix)
+                .map(|(index, _)| offset + index)
+                .find(|&index| {
+                    text[..index]
+                        .chars()
+                        .next_back()
+                        .is_none_or(|c| c.is_whitespace() || "(*_~".contains(c))
+                })
+        })
+        .min()
+    {
+        if let Some(end) = url_len(&text[start..]) {
+            return Some((start, start + end));
+        }
+        // Prefixes are ASCII, so the next byte starts a character
+        offset = start + 1;
+    }
+    None
+}
+
+/// Returns the length of the URL at the start of the candidate, or `None` if
+/// its host has no `.`.
+fn url_len(candidate: &str) -> Option<usize> {
+    let mut end = candidate
+        .find(|c: char| c.is_whitespace() || c == '<')
+        .unwrap_or(candidate.len());
+    loop {
+        let url = &candidate[..end];
+        let trim = url.ends_with(['?', '!', '.', ',', ':', '*', '_', '~'])
+            || (url.ends_with(')') && url.matches(')').count() > url.matches('(').count());
+        if !trim {
+            break;
+        }
+        end -= 1;
+    }
+
+    let url = &candidate[..end];
+    let host = url
+        .trim_start_matches("https://")
+        .trim_start_matches("http://");
+    host.contains('.').then_some(end)
+}
+
+/// Assigns a slug to every heading that has no explicit ID.
+fn with_heading_ids(mut events: Vec<Event<'_>>) -> Vec<Event<'_>> {
+    let mut slugger = Slugger::new();
+
+    for start in 0..events.len() {
+        if !matches!(events[start], Event::Start(Tag::Heading { id: None, .. })) {
+            continue;
+        }
+
+        let text: String = events[start + 1..]
+            .iter()
+            .take_while(|event| !matches!(event, Event::End(TagEnd::Heading(_))))
+            .filter_map(|event| match event {
+                Event::Text(t) | Event::Code(t) => Some(t.as_ref()),
+                _ => None,
+            })
+            .collect();
+        let slug = slugger.slug(&text);
+
+        if let Event::Start(Tag::Heading { id, .. }) = &mut events[start] {
+            if !slug.is_empty() {
+                *id = Some(slug.into());
+            }
+        }
+    }
+
+    events
+}
+
+/// Moves footnote definitions to a numbered list at the end of the document.
+///
+/// References become superscript links to their definition, and every
+/// definition links back to each of its references. Definitions that are
+/// never referenced are dropped, as on GitHub.
+fn with_footnotes(events: Vec<Event<'_>>) -> Vec<Event<'_>> {
+    let mut body = Vec::with_capacity(events.len());
+    let mut definitions: HashMap<CowStr<'_>, Vec<Event<'_>>> = HashMap::new();
+    let mut current: Option<(CowStr<'_>, Vec<Event<'_>>)> = None;
+    // Labels in order of first reference, with their reference count
+    let mut referenced: Vec<(CowStr<'_>, usize)> = Vec::new();
+
+    for event in events {
+        match event {
+            Event::Start(Tag::FootnoteDefinition(label)) => current = Some((label, Vec::new())),
+            Event::End(TagEnd::FootnoteDefinition) => {
+                if let Some((label, content)) = current.take() {
+                    definitions.entry(label).or_insert(content);
+                }
+            },
+            Event::FootnoteReference(label) => {
+                let index = referenced
+                    .iter()
+                    .position(|(l, _)| *l == label)
+                    .unwrap_or_else(|| {
+                        referenced.push((label, 0));
+                        referenced.len() - 1
+                    });
+                referenced[index].1 += 1;
+                let number = index + 1;
+                let count = referenced[number - 1].1;
+                let html = format!(
+                    "<sup class=\"footnote-ref\"><a href=\"#fn-{number}\" id=\"{}\">{number}</a></sup>",
+                    reference_id(number, count)
+                );
+                match &mut current {
+                    Some((_, content)) => content.push(Event::InlineHtml(html.into())),
+                    None => body.push(Event::InlineHtml(html.into())),
+                }
+            },
+            event => match &mut current {
+                Some((_, content)) => content.push(event),
+                None => body.push(event),
+            },
+        }
+    }
+
+    let notes: Vec<_> = referenced
+        .iter()
+        .enumerate()
+        .filter_map(|(index, (label, count))| {
+            definitions
+                .remove(label)
+                .map(|content| (index + 1, *count, content))
+        })
+        .collect();
+    if notes.is_empty() {
+        return body;
+    }
+
+    body.push(Event::Html("<section class=\"footnotes\">\n<ol>\n".into()));
+    for (number, count, mut content) in notes {
+        body.push(Event::Html(format!("<li id=\"fn-{number}\">\n").into()));
+        let mut backrefs = String::new();
+        for n in 1..=count {
+            let _ = write!(
+                backrefs,
+                " <a href=\"#{}\" class=\"footnote-backref\">\u{21a9}</a>",
+                reference_id(number, n)
+            );
+        }
+        // Keep the back-links inside the last paragraph, as GitHub does
+        let end = if matches!(content.last(), Some(Event::End(TagEnd::Paragraph))) {
+            content.len() - 1
+        } else {
+            content.len()
+        };
+        content.insert(end, Event::InlineHtml(backrefs.into()));
+        body.extend(content);
+        body.push(Event::Html("</li>\n".into()));
+    }
+    body.push(Event::Html("</ol>\n</section>\n".into()));
+
+    body
+}
+
+/// Returns the anchor of the `count`-th reference to footnote `number`.
+fn reference_id(number: usize, count: usize) -> String {
+    if count == 1 {
+        format!("fnref-{number}")
+    } else {
+        format!("fnref-{number}-{count}")
+    }
+}
+
+/// Converts heading text to an anchor slug the way GitHub does.
+///
+/// The text is lowercased, punctuation and symbols (including emoji) are
+/// removed, and spaces become hyphens: `"Decision: Use gRPC!"` becomes
+/// `"decision-use-grpc"`.
+#[must_use]
+pub fn slugify(heading: &str) -> String {
+    heading
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            '-' | '_' => Some(c),
+            c if c.is_alphanumeric() => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Generates unique heading slugs within a document.
+///
+/// Repeated headings are numbered like GitHub does: `options`, `options-1`,
+/// `options-2`, ...
+#[derive(Debug, Clone, Default)]
+pub struct Slugger {
+    seen: HashSet<String>,
+}
+
+impl Slugger {
+    /// Creates a slugger for a new document.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the slug for a heading, numbered if it was already used.
+    pub fn slug(&mut self, heading: &str) -> String {
+        let base = slugify(heading);
+        let mut slug = base.clone();
+        let mut count = 0;
+        while !self.seen.insert(slug.clone()) {
+            count += 1;
+            slug = format!("{base}-{count}");
+        }
+        slug
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,7 +701,196 @@ mod tests {
     fn test_render_heading() {
         let renderer = MarkdownRenderer::new();
         let html = renderer.render("# Hello World");
-        assert!(html.contains("<h1>Hello World</h1>"));
+        assert!(html.contains("<h1 id=\"hello-world\">Hello World</h1>"));
+    }
+
+    #[test]
+    fn test_render_heading_ids() {
+        let renderer = MarkdownRenderer::new();
+        let html = renderer
+            .render("## Options\n\n### Options\n\n## `gRPC` vs REST\n\n## Custom {#pinned}");
+        assert!(html.contains("<h2 id=\"options\">"));
+        assert!(html.contains("<h3 id=\"options-1\">"));
+        assert!(html.contains("<h2 id=\"grpc-vs-rest\">"));
+        assert!(html.contains("<h2 id=\"pinned\">"));
+    }
+
+    #[test]
+    fn test_render_footnotes() {
+        let renderer = MarkdownRenderer::new();
+        let md = "Uses Raft[^raft] and Paxos[^paxos], see Raft[^raft].\n\n\
+                  [^paxos]: Lamport, 1998.\n\n\
+                  [^raft]: Ongaro and Ousterhout, 2014.\n\n\
+                  [^unused]: Never referenced.\n";
+        let html = renderer.render(md);
+
+        assert!(
+            html.contains(
+                "<sup class=\"footnote-ref\"><a href=\"#fn-1\" id=\"fnref-1\">1</a></sup>"
+            )
+        );
+        assert!(html.contains("<a href=\"#fn-2\" id=\"fnref-2\">2</a>"));
+        assert!(html.contains("<a href=\"#fn-1\" id=\"fnref-1-2\">1</a>"));
+        // Definitions are listed in reference order with back-links
+        let notes = &html[html
+            .find("<section class=\"footnotes\">")
+            .expect("footnotes")..];
+        assert!(notes.find("Ongaro").expect("raft") < notes.find("Lamport").expect("paxos"));
+        assert!(notes.contains(
+            "2014. <a href=\"#fnref-1\" class=\"footnote-backref\">\u{21a9}</a> \
+             <a href=\"#fnref-1-2\" class=\"footnote-backref\">\u{21a9}</a></p>"
+        ));
+        assert!(!html.contains("Never referenced"));
+    }
+
+    #[test]
+    fn test_render_without_footnotes() {
+        let renderer = MarkdownRenderer::new();
+        assert!(!renderer.render("No notes here.").contains("footnotes"));
+    }
+
+    #[test]
+    fn test_plain_text_footnotes() {
+        let renderer = MarkdownRenderer::new();
+        let text = renderer.render_plain_text("Uses Raft[^1].\n\n[^1]: Ongaro, 2014.\n");
+        assert_eq!(text, "Uses Raft . Ongaro, 2014.");
+    }
+
+    #[test]
+    fn test_render_callouts() {
+        let renderer = MarkdownRenderer::new();
+        let html = renderer.render("> [!WARNING]\n> Migration is irreversible.\n\n> Plain quote.");
+
+        assert!(html.contains(
+            "<div class=\"markdown-alert markdown-alert-warning\">\n\
+             <p class=\"markdown-alert-title\">Warning</p>\n<p>Migration is irreversible.</p>\n</div>"
+        ));
+        assert!(!html.contains("[!WARNING]"));
+        assert!(html.contains("<blockquote>\n<p>Plain quote.</p>"));
+    }
+
+    #[test]
+    fn test_render_admonitions() {
+        let renderer = MarkdownRenderer::new();
+        let md = ":::tip\nPrefer **managed** services.\n\nSee below.\n:::\n\n\
+                  ```\n:::note\n```\n\n:::custom\nUntouched.\n";
+        let html = renderer.render(md);
+
+        assert!(html.contains("<div class=\"markdown-alert markdown-alert-tip\">"));
+        assert!(html.contains(
+            "<p>Prefer <strong>managed</strong> services.</p>\n<p>See below.</p>\n</div>"
+        ));
+        assert!(html.contains("<code>:::note\n</code>"));
+        assert!(html.contains(":::custom"));
+        assert_eq!(html.matches("markdown-alert-title").count(), 1);
+    }
+
+    #[test]
+    fn test_render_admonition_with_fenced_code() {
+        let renderer = MarkdownRenderer::new();
+        let md = ":::note
+Configure it:
+
+```
+:::
+:::warning
+```
+
+Done.
+:::
+
+After.
+";
+        let html = renderer.render(md);
+
+        assert!(html.contains(
+            "<code>:::
+:::warning
+</code>"
+        ));
+        assert!(html.contains(
+            "<p>Done.</p>
+</div>"
+        ));
+        assert!(html.contains(
+            "</div>
+<p>After.</p>"
+        ));
+        assert_eq!(html.matches("markdown-alert-title").count(), 1);
+    }
+
+    #[test]
+    fn test_plain_text_callouts() {
+        let renderer = MarkdownRenderer::new();
+        let text =
+            renderer.render_plain_text(":::note\nKeep it simple.\n:::\n\n> [!TIP]\n> Be brief.");
+        assert_eq!(text, "Keep it simple. Be brief.");
+    }
+
+    #[test]
+    fn test_render_emoji_shortcodes() {
+        let renderer = MarkdownRenderer::new();
+        let html = renderer
+            .render("Ship it :rocket: at 10:30 :unknown:\n\n`:rocket:`\n\n```\n:tada:\n```");
+        assert!(html.contains("<p>Ship it \u{1f680} at 10:30 :unknown:</p>"));
+        assert!(html.contains("<code>:rocket:</code>"));
+        assert!(html.contains(":tada:\n</code></pre>"));
+
+        let html = renderer.render("## :sparkles: Features");
+        assert!(html.contains("<h2 id=\"sparkles-features\">\u{2728} Features</h2>"));
+    }
+
+    #[test]
+    fn test_render_autolinks() {
+        let renderer = MarkdownRenderer::new();
+        let html = renderer.render(
+            "See https://example.com/docs. Or (www.example.org/a_(b)) and \
+             [https://linked.example](https://linked.example).",
+        );
+        assert!(html.contains(
+            "See <a href=\"https://example.com/docs\">https://example.com/docs</a>. Or ("
+        ));
+        assert!(
+            html.contains(
+                "<a href=\"http://www.example.org/a_(b)\">www.example.org/a_(b)</a>) and"
+            )
+        );
+        assert_eq!(html.matches("<a href").count(), 3);
+
+        let html =
+            renderer.render("`https://example.com` and http://localhost and xhttps://example.com");
+        assert!(!html.contains("<a href"));
+    }
+
+    #[test]
+    fn test_find_url() {
+        assert_eq!(find_url("go to https://a.io/x?"), Some((6, 20)));
+        assert_eq!(find_url("no url here"), None);
+        assert_eq!(find_url("https://"), None);
+        // A candidate without a dotted host does not end the search
+        assert_eq!(find_url("http://localhost or https://a.io"), Some((20, 32)));
+        assert_eq!(find_url("see www. or www.y.io"), Some((12, 20)));
+    }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Decision: Use gRPC!"), "decision-use-grpc");
+        assert_eq!(slugify("✅ accepted"), "-accepted");
+        assert_eq!(slugify("Pros & Cons"), "pros--cons");
+        assert_eq!(
+            slugify("snake_case and kebab-case"),
+            "snake_case-and-kebab-case"
+        );
+        assert_eq!(slugify("Überblick 2025"), "überblick-2025");
+    }
+
+    #[test]
+    fn test_slugger_numbers_duplicates() {
+        let mut slugger = Slugger::new();
+        assert_eq!(slugger.slug("Options"), "options");
+        assert_eq!(slugger.slug("Options"), "options-1");
+        assert_eq!(slugger.slug("Options 1"), "options-1-1");
+        assert_eq!(slugger.slug("Options"), "options-2");
     }
 
     #[test]
@@ -108,7 +915,73 @@ mod tests {
         let renderer = MarkdownRenderer::new();
         let html = renderer.render("```rust\nfn main() {}\n```");
         assert!(html.contains("<code"));
-        assert!(html.contains("fn main()"));
+        assert!(html.contains("main()"));
+    }
+
+    #[test]
+    fn test_render_code_block_highlighting() {
+        let renderer = MarkdownRenderer::new();
+
+        let html = renderer.render("```rust title=\"main\"\nlet x = \"<b>\";\n```");
+        assert_eq!(
+            html,
+            "<pre><code class=\"language-rust\">\
+             <span class=\"hl-keyword\">let</span> x = \
+             <span class=\"hl-string\">&quot;&lt;b&gt;&quot;</span>;\n</code></pre>\n"
+        );
+
+        // Unknown languages, indented blocks, and inline code stay plain
+        let html = renderer.render("```text\nlet x\n```");
+        assert_eq!(
+            html,
+            "<pre><code class=\"language-text\">let x\n</code></pre>\n"
+        );
+        let html = renderer.render("    let x = 1;\n\n`let y`");
+        assert!(!html.contains("hl-"));
+    }
+
+    #[test]
+    fn test_render_mermaid_diagrams() {
+        let renderer = MarkdownRenderer::new();
+
+        let html = renderer.render("```mermaid\ngraph TD\n  A-->B\n```\n\nAfter");
+        assert_eq!(
+            html,
+            "<pre class=\"mermaid\">graph TD\n  A--&gt;B\n</pre>\n<p>After</p>\n"
+        );
+
+        // Other blocks are untouched
+        let html = renderer.render("```mermaid\ngraph TD\n```\n\n```\nplain\n```");
+        assert!(html.ends_with("<pre><code>plain\n</code></pre>\n"));
+
+        // Like other code, diagram sources are left out of the plain text
+        let text = renderer.render_plain_text("```mermaid\ngraph TD\n```");
+        assert!(!text.contains("graph"));
+    }
+
+    #[test]
+    fn test_render_definition_lists() {
+        let renderer = MarkdownRenderer::new();
+        let html = renderer.render("ADR\n: Architecture Decision Record\n");
+        assert_eq!(
+            html,
+            "<dl>\n<dt>ADR</dt>\n<dd>Architecture Decision Record</dd>\n</dl>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_smart_punctuation() {
+        let markdown = "\"Fast\" -- or 'cheap'...";
+        assert_eq!(
+            MarkdownRenderer::new().render(markdown),
+            "<p>\"Fast\" -- or 'cheap'...</p>\n"
+        );
+        assert_eq!(
+            MarkdownRenderer::new()
+                .with_smart_punctuation(true)
+                .render(markdown),
+            "<p>\u{201c}Fast\u{201d} \u{2013} or \u{2018}cheap\u{2019}\u{2026}</p>\n"
+        );
     }
 
     #[test]
@@ -145,6 +1018,36 @@ mod tests {
         assert!(html.contains("<a href=\"https://example.com\">Link text</a>"));
     }
 
+    #[test]
+    fn test_render_wikilinks() {
+        let renderer = MarkdownRenderer::new();
+        let html = renderer.render(
+            "See [[adr-0002]], [[payments/adr-0003.md|payments]], and [[adr-0002#Decision Drivers]].",
+        );
+        assert!(html.contains(r##"<a href="#adr=adr-0002">adr-0002</a>"##));
+        assert!(html.contains(r##"<a href="#adr=payments%2Fadr-0003">payments</a>"##));
+        assert!(html.contains(r##"<a href="#adr=adr-0002/decision-drivers">"##));
+    }
+
+    #[test]
+    fn test_wikilinks() {
+        let renderer = MarkdownRenderer::new();
+        let md = "[[adr-0002]] and [[adr-0003.md|three]], [[adr-0002#Context]]\n\n\
+                  `[[adr-0004]]` is code. [Plan](plan.pdf)";
+        assert_eq!(renderer.wikilinks(md), ["adr-0002", "adr-0003"]);
+        assert_eq!(renderer.link_destinations(md), ["plan.pdf"]);
+    }
+
+    #[test]
+    fn test_image_destinations() {
+        let renderer = MarkdownRenderer::new();
+        let md = "![Flow](images/flow.png) [Plan](plan.pdf) ![Logo](../logo.svg \"Logo\")";
+        assert_eq!(
+            renderer.image_destinations(md),
+            ["images/flow.png", "../logo.svg"]
+        );
+    }
+
     #[test]
     fn test_render_strikethrough() {
         let renderer = MarkdownRenderer::new();
@@ -192,6 +1095,24 @@ This is the end.";
         assert!(!text.contains("  ")); // no double spaces
     }
 
+    #[test]
+    fn test_render_with_links() {
+        let renderer = MarkdownRenderer::new();
+        let markdown = "![Diagram](adr-0001/diagram.svg) and [docs](https://example.com)";
+
+        assert_eq!(
+            renderer.link_destinations(markdown),
+            vec!["adr-0001/diagram.svg", "https://example.com"]
+        );
+
+        let html = renderer.render_with_links(markdown, |dest| {
+            dest.starts_with("adr-0001/")
+                .then(|| "data:image/svg+xml;base64,PHN2Zy8+".to_string())
+        });
+        assert!(html.contains(r#"<img src="data:image/svg+xml;base64,PHN2Zy8+" alt="Diagram" />"#));
+        assert!(html.contains(r#"<a href="https://example.com">docs</a>"#));
+    }
+
     #[test]
     fn test_plain_text_basic() {
         let renderer = MarkdownRenderer::new();
@@ -3,7 +3,11 @@
 //! This module provides parsers for extracting frontmatter and converting
 //! markdown to HTML.
 
+mod cache;
+mod classic;
+mod emoji;
 mod frontmatter;
+mod highlight;
 mod markdown;
 
 use std::path::Path;
@@ -11,8 +15,9 @@ use std::path::Path;
 use crate::domain::{Adr, AdrId};
 use crate::error::Result;
 
-pub use frontmatter::FrontmatterParser;
-pub use markdown::MarkdownRenderer;
+pub use cache::{CacheStats, CachingParser};
+pub use frontmatter::{FrontmatterParser, read_fields, set_field};
+pub use markdown::{MarkdownRenderer, Slugger, slugify};
 
 /// Trait for parsing ADR files.
 pub trait AdrParser: Send + Sync {
@@ -56,6 +61,8 @@ impl AdrParser for DefaultAdrParser {
         // Extract plain text for search indexing
         let body_text = self.markdown_renderer.render_plain_text(body_markdown);
 
+        let wikilinks = self.markdown_renderer.wikilinks(body_markdown);
+
         Ok(Adr::new(
             id,
             filename,
@@ -64,7 +71,8 @@ impl AdrParser for DefaultAdrParser {
             body_markdown.to_string(),
             body_html,
             body_text,
-        ))
+        )
+        .with_wikilinks(wikilinks))
     }
 }
 
@@ -109,7 +117,7 @@ PostgreSQL provides the features we need.
         assert_eq!(adr.title(), "Use PostgreSQL for Primary Storage");
         assert_eq!(adr.status(), Status::Accepted);
         assert_eq!(adr.category(), "architecture");
-        assert!(adr.body_html().contains("<h1>"));
+        assert!(adr.body_html().contains("<h1 id=\"context\">"));
         assert!(adr.body_text().contains("Context"));
     }
 
@@ -1,14 +1,23 @@
 //! HTML viewer generation using askama templates.
 
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
 use askama::Template;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
 use time::OffsetDateTime;
 
-use crate::domain::{Adr, Facets, Graph};
+use super::schema::SCHEMA_VERSION;
+use crate::domain::{
+    Adr, AdrId, CustomStatus, Facets, Graph, GraphGrouping, SearchField, SearchIndex,
+};
 use crate::error::{Error, Result};
 
 /// Theme for the HTML viewer.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Theme {
     /// Light theme.
     Light,
@@ -44,6 +53,336 @@ impl std::str::FromStr for Theme {
     }
 }
 
+/// Optional per-record fields of the embedded viewer data.
+///
+/// The `id` and `frontmatter` of each record are always embedded because the
+/// viewer cannot function without them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordField {
+    /// Original filename.
+    Filename,
+    /// Pre-rendered HTML body, shown in the detail panel.
+    BodyHtml,
+    /// Plain text body, used for full-text search.
+    BodyText,
+    /// Raw markdown body, used for source view and copy.
+    BodyMarkdown,
+    /// Owning monorepo package.
+    Package,
+    /// Owning team.
+    Team,
+}
+
+impl RecordField {
+    /// Fields embedded when no explicit selection is made.
+    pub const DEFAULT: &'static [Self] = &[
+        Self::Filename,
+        Self::BodyHtml,
+        Self::BodyText,
+        Self::Package,
+        Self::Team,
+    ];
+
+    /// Returns the field's key in the embedded JSON.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Filename => "filename",
+            Self::BodyHtml => "body_html",
+            Self::BodyText => "body_text",
+            Self::BodyMarkdown => "body_markdown",
+            Self::Package => "package",
+            Self::Team => "team",
+        }
+    }
+}
+
+/// Maps a custom frontmatter field to a badge on ADR cards.
+///
+/// ```toml
+/// [[generate.badges]]
+/// field = "risk"
+/// colors = { high = "#ef4444", low = "#10b981" }
+///
+/// [[generate.badges]]
+/// field = "ticket"
+/// link = "https://jira.example.com/browse/{value}"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BadgeRule {
+    /// Frontmatter field holding the badge value.
+    pub field: String,
+    /// Text shown before the value; defaults to the field name.
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Badge colors by value, matched case-insensitively.
+    #[serde(default)]
+    pub colors: BTreeMap<String, String>,
+    /// Color for values without an entry in `colors`.
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Link target, with `{value}` replaced by the URL-encoded value.
+    #[serde(default)]
+    pub link: Option<String>,
+}
+
+/// Placeholder in [`BadgeRule::link`] replaced by the badge value.
+#[allow(clippy::literal_string_with_formatting_args)]
+const LINK_PLACEHOLDER: &str = "{value}";
+
+impl BadgeRule {
+    /// Creates a rule showing the given field as a plain badge.
+    #[must_use]
+    pub fn new(field: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            label: None,
+            colors: BTreeMap::new(),
+            color: None,
+            link: None,
+        }
+    }
+
+    /// Returns the ADR's badge, if it has a value for the field.
+    #[must_use]
+    pub fn badge(&self, adr: &Adr) -> Option<Badge> {
+        let value = self.value(adr)?;
+        let color = self
+            .colors
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(&value))
+            .map(|(_, color)| color)
+            .or(self.color.as_ref())
+            .cloned();
+        let url = self
+            .link
+            .as_ref()
+            .map(|link| link.replace(LINK_PLACEHOLDER, &encode_uri_component(&value)));
+
+        Some(Badge {
+            label: self.label.clone().unwrap_or_else(|| self.field.clone()),
+            value,
+            color,
+            url,
+        })
+    }
+
+    /// Returns the ADR's value for the field, which may also be one of the
+    /// `impact` and `risk` schema fields. List items are joined.
+    fn value(&self, adr: &Adr) -> Option<String> {
+        let level = match self.field.as_str() {
+            "impact" => adr.impact(),
+            "risk" => adr.risk(),
+            _ => None,
+        };
+        let values = adr.frontmatter().custom_values(&self.field);
+        (!values.is_empty())
+            .then(|| values.join(", "))
+            .or_else(|| level.map(|level| level.to_string()))
+    }
+}
+
+/// A badge shown on an ADR card.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Badge {
+    /// Field label.
+    pub label: String,
+    /// Field value.
+    pub value: String,
+    /// Background color, if configured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Link target, if configured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Privacy-friendly analytics added to the viewer page.
+///
+/// ```toml
+/// [generate.analytics]
+/// provider = "plausible"
+/// domain = "adrs.example.com"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "provider", rename_all = "lowercase", deny_unknown_fields)]
+pub enum Analytics {
+    /// Plausible Analytics, which sets no cookies.
+    Plausible {
+        /// Site domain as registered with Plausible.
+        domain: String,
+        /// Script URL, for self-hosted instances.
+        #[serde(default)]
+        src: Option<String>,
+    },
+    /// Matomo, with cookies disabled.
+    Matomo {
+        /// Base URL of the Matomo instance, e.g. `https://matomo.example.com/`.
+        url: String,
+        /// Site ID in Matomo.
+        site_id: u32,
+    },
+}
+
+/// Mermaid build loaded by viewers with diagrams, unless one is embedded.
+pub const MERMAID_SCRIPT: &str = "https://cdn.jsdelivr.net/npm/mermaid@11/dist/mermaid.min.js";
+
+/// Markup of a diagram in rendered ADR bodies.
+const DIAGRAM_MARKUP: &str = "<pre class=\"mermaid\">";
+
+/// Script loaded by [`Analytics::Plausible`] unless `src` is set.
+const PLAUSIBLE_SCRIPT: &str = "https://plausible.io/js/script.js";
+
+impl Analytics {
+    /// Returns the URL of the tracking script the browser loads.
+    #[must_use]
+    pub fn script_url(&self) -> String {
+        match self {
+            Self::Plausible { src, .. } => {
+                src.clone().unwrap_or_else(|| PLAUSIBLE_SCRIPT.to_string())
+            },
+            Self::Matomo { url, .. } => format!("{}matomo.js", matomo_base(url)),
+        }
+    }
+
+    /// Returns the script tag to insert into the page head.
+    #[must_use]
+    pub fn script(&self) -> String {
+        match self {
+            Self::Plausible { domain, .. } => format!(
+                r#"<script defer data-domain="{}" src="{}"></script>"#,
+                escape_attribute(domain),
+                escape_attribute(&self.script_url())
+            ),
+            Self::Matomo { url, site_id } => {
+                let base = matomo_base(url);
+                // JSON string literals are valid JavaScript; escaping `</`
+                // keeps the URL from closing the script element
+                let base = serde_json::to_string(&base)
+                    .unwrap_or_default()
+                    .replace("</", "<\\/");
+                format!(
+                    "<script>
+var _paq = window._paq = window._paq || [];
+_paq.push(['disableCookies']);
+_paq.push(['trackPageView']);
+_paq.push(['enableLinkTracking']);
+(function() {{
+  var u = {base};
+  _paq.push(['setTrackerUrl', u + 'matomo.php']);
+  _paq.push(['setSiteId', '{site_id}']);
+  var d = document, g = d.createElement('script'), s = d.getElementsByTagName('script')[0];
+  g.async = true; g.src = u + 'matomo.js'; s.parentNode.insertBefore(g, s);
+}})();
+</script>"
+                )
+            },
+        }
+    }
+}
+
+/// Returns the Matomo base URL with a trailing slash.
+fn matomo_base(url: &str) -> String {
+    if url.ends_with('/') {
+        url.to_string()
+    } else {
+        format!("{url}/")
+    }
+}
+
+/// Escapes a value for use in a double-quoted HTML attribute.
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Returns the URL fragment that makes the viewer open an ADR, e.g.
+/// `adr=payments%2Fadr-0003`.
+#[must_use]
+pub fn adr_fragment(id: &AdrId) -> String {
+    format!("adr={}", encode_uri_component(id.as_str()))
+}
+
+/// Returns the URL fragment that makes the viewer open an ADR at the heading
+/// with the given anchor, e.g. `adr=adr-0007/consequences`.
+#[must_use]
+pub fn section_fragment(id: &AdrId, anchor: &str) -> String {
+    format!("{}/{}", adr_fragment(id), encode_uri_component(anchor))
+}
+
+/// Percent-encodes everything except unreserved URL characters.
+fn encode_uri_component(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| {
+            if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+                char::from(byte).to_string()
+            } else {
+                format!("%{byte:02X}")
+            }
+        })
+        .collect()
+}
+
+/// Returns the SHA-256 of the ADR corpus as lowercase hex.
+///
+/// The hash covers each ADR's ID, frontmatter, markdown, and rendered body
+/// (including inlined assets), in ID order, so it changes exactly when the
+/// viewer content does and not when files are merely touched or reordered.
+#[must_use]
+pub fn content_hash(adrs: &[Adr]) -> String {
+    let mut sorted: Vec<&Adr> = adrs.iter().collect();
+    sorted.sort_by(|a, b| a.id().as_str().cmp(b.id().as_str()));
+
+    let mut hasher = Sha256::new();
+    for adr in sorted {
+        let frontmatter = serde_json::to_string(adr.frontmatter()).unwrap_or_default();
+        for part in [
+            adr.id().as_str(),
+            &frontmatter,
+            adr.body_markdown(),
+            adr.body_html(),
+        ] {
+            hasher.update(part.as_bytes());
+            hasher.update([0]);
+        }
+    }
+    hasher
+        .finalize()
+        .iter()
+        .fold(String::new(), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+/// Record keys that are always embedded.
+const REQUIRED_RECORD_KEYS: &[&str] = &["id", "frontmatter"];
+
+/// Record keys holding ADR bodies, left out of the index.
+const BODY_RECORD_KEYS: &[&str] = &["body_html", "body_text", "body_markdown"];
+
+/// Strips the ADR bodies and the search index built from them from viewer
+/// data, leaving metadata, facets, and graph for the `index.json` sidecar.
+#[must_use]
+pub fn index_data(data: &Value) -> Value {
+    let mut index = data.clone();
+    if let Some(root) = index.as_object_mut() {
+        root.remove("search");
+    }
+    if let Some(records) = index.get_mut("records").and_then(|r| r.as_array_mut()) {
+        for record in records.iter_mut().filter_map(Value::as_object_mut) {
+            record.retain(|key, _| !BODY_RECORD_KEYS.contains(&key.as_str()));
+        }
+    }
+    index
+}
+
 /// Configuration for HTML rendering.
 #[derive(Debug, Clone, Default)]
 pub struct RenderConfig {
@@ -53,6 +392,31 @@ pub struct RenderConfig {
     pub theme: Theme,
     /// Whether to embed all assets inline.
     pub embed_assets: bool,
+    /// Whether to derive the generation timestamp from the ADRs instead of the clock.
+    pub reproducible: bool,
+    /// Whether to include each ADR's raw markdown in the embedded data.
+    pub embed_markdown: bool,
+    /// Optional record fields to embed, replacing [`RecordField::DEFAULT`].
+    pub embed_fields: Option<Vec<RecordField>>,
+    /// Optional record fields to leave out of the embedded data.
+    pub omit_fields: Vec<RecordField>,
+    /// Custom frontmatter fields shown as badges on ADR cards.
+    pub badges: Vec<BadgeRule>,
+    /// Custom frontmatter fields offered as filters.
+    pub facets: Vec<String>,
+    /// Absolute URL the viewer is served from, for canonical and OpenGraph links.
+    pub url: Option<String>,
+    /// Raw HTML inserted into the page header, next to the title.
+    pub header_html: Option<String>,
+    /// Raw HTML inserted into the page footer.
+    pub footer_html: Option<String>,
+    /// Analytics script added to the page, off by default.
+    pub analytics: Option<Analytics>,
+    /// Mermaid build embedded for drawing diagrams, instead of loading it
+    /// from [`MERMAID_SCRIPT`].
+    pub mermaid_js: Option<String>,
+    /// ADR attribute the graph clusters nodes by, if any.
+    pub graph_grouping: Option<GraphGrouping>,
 }
 
 impl RenderConfig {
@@ -63,6 +427,18 @@ impl RenderConfig {
             title: title.into(),
             theme: Theme::default(),
             embed_assets: true,
+            reproducible: false,
+            embed_markdown: false,
+            embed_fields: None,
+            omit_fields: Vec::new(),
+            badges: Vec::new(),
+            facets: Vec::new(),
+            url: None,
+            header_html: None,
+            footer_html: None,
+            analytics: None,
+            mermaid_js: None,
+            graph_grouping: None,
         }
     }
 
@@ -72,6 +448,118 @@ impl RenderConfig {
         self.theme = theme;
         self
     }
+
+    /// Sets whether output is reproducible.
+    #[must_use]
+    pub const fn with_reproducible(mut self, reproducible: bool) -> Self {
+        self.reproducible = reproducible;
+        self
+    }
+
+    /// Sets whether raw markdown is embedded.
+    #[must_use]
+    pub const fn with_embed_markdown(mut self, embed_markdown: bool) -> Self {
+        self.embed_markdown = embed_markdown;
+        self
+    }
+
+    /// Sets the record fields to embed.
+    #[must_use]
+    pub fn with_embed_fields(mut self, fields: Vec<RecordField>) -> Self {
+        self.embed_fields = Some(fields);
+        self
+    }
+
+    /// Sets the record fields to leave out.
+    #[must_use]
+    pub fn with_omit_fields(mut self, fields: Vec<RecordField>) -> Self {
+        self.omit_fields = fields;
+        self
+    }
+
+    /// Sets the custom fields shown as badges.
+    #[must_use]
+    pub fn with_badges(mut self, badges: Vec<BadgeRule>) -> Self {
+        self.badges = badges;
+        self
+    }
+
+    /// Sets the custom fields offered as filters.
+    #[must_use]
+    pub fn with_facets(mut self, facets: Vec<String>) -> Self {
+        self.facets = facets;
+        self
+    }
+
+    /// Sets the absolute URL the viewer is served from.
+    #[must_use]
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Sets the raw HTML inserted into the page header.
+    #[must_use]
+    pub fn with_header_html(mut self, html: impl Into<String>) -> Self {
+        self.header_html = Some(html.into());
+        self
+    }
+
+    /// Sets the raw HTML inserted into the page footer.
+    #[must_use]
+    pub fn with_footer_html(mut self, html: impl Into<String>) -> Self {
+        self.footer_html = Some(html.into());
+        self
+    }
+
+    /// Sets the analytics script added to the page.
+    #[must_use]
+    pub fn with_analytics(mut self, analytics: Analytics) -> Self {
+        self.analytics = Some(analytics);
+        self
+    }
+
+    /// Sets the Mermaid build embedded for drawing diagrams.
+    #[must_use]
+    pub fn with_mermaid_js(mut self, js: impl Into<String>) -> Self {
+        self.mermaid_js = Some(js.into());
+        self
+    }
+
+    /// Sets the ADR attribute the graph clusters nodes by.
+    #[must_use]
+    pub const fn with_graph_grouping(mut self, grouping: GraphGrouping) -> Self {
+        self.graph_grouping = Some(grouping);
+        self
+    }
+
+    /// Returns the script tag that loads Mermaid.
+    fn mermaid_script(&self) -> String {
+        self.mermaid_js.as_ref().map_or_else(
+            || format!("<script defer src=\"{MERMAID_SCRIPT}\"></script>"),
+            // A closing tag in the script would end the element early
+            |js| {
+                format!(
+                    "<script>\n{}\n</script>",
+                    js.replace("</script", "<\\/script")
+                )
+            },
+        )
+    }
+
+    /// Returns the optional record fields that will be embedded.
+    #[must_use]
+    pub fn record_fields(&self) -> Vec<RecordField> {
+        let mut fields = self
+            .embed_fields
+            .clone()
+            .unwrap_or_else(|| RecordField::DEFAULT.to_vec());
+        if self.embed_markdown && !fields.contains(&RecordField::BodyMarkdown) {
+            fields.push(RecordField::BodyMarkdown);
+        }
+        fields.retain(|f| !self.omit_fields.contains(f));
+        fields
+    }
 }
 
 /// Data structure embedded in the HTML for JavaScript consumption.
@@ -85,6 +573,8 @@ pub struct ViewerData {
     pub facets: Facets,
     /// Relationship graph.
     pub graph: Graph,
+    /// Inverted index for the viewer's search.
+    pub search: SearchIndex,
 }
 
 /// Metadata embedded in the viewer.
@@ -94,10 +584,18 @@ pub struct ViewerMeta {
     pub generated: String,
     /// Generator name and version.
     pub generator: String,
-    /// Schema version.
+    /// Schema version of the embedded data (see [`SCHEMA_VERSION`]).
     pub schema_version: String,
     /// Source directory.
     pub source_dir: String,
+    /// SHA-256 of the ADR corpus (see [`content_hash`]).
+    pub content_hash: String,
+    /// Absolute URL the viewer is served from, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Custom statuses in use, for styling them, in the order they are listed.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub statuses: Vec<CustomStatus>,
 }
 
 impl ViewerMeta {
@@ -109,8 +607,29 @@ impl ViewerMeta {
                 .format(&time::format_description::well_known::Rfc3339)
                 .unwrap_or_else(|_| "unknown".to_string()),
             generator: format!("adrscope/{}", env!("CARGO_PKG_VERSION")),
-            schema_version: "1.0.0".to_string(),
+            schema_version: SCHEMA_VERSION.to_string(),
             source_dir: source_dir.into(),
+            content_hash: String::new(),
+            url: None,
+            statuses: Vec::new(),
+        }
+    }
+
+    /// Creates metadata that depends only on the ADRs, not on the clock.
+    ///
+    /// The generation timestamp is the most recent `updated` or `created`
+    /// date across all ADRs, so identical input yields identical output.
+    #[must_use]
+    pub fn reproducible(source_dir: impl Into<String>, adrs: &[Adr]) -> Self {
+        let latest = adrs
+            .iter()
+            .flat_map(|adr| [adr.updated(), adr.created()])
+            .flatten()
+            .max();
+
+        Self {
+            generated: latest.map_or_else(|| "unknown".to_string(), |d| format!("{d}T00:00:00Z")),
+            ..Self::new(source_dir)
         }
     }
 }
@@ -123,6 +642,16 @@ pub struct ViewerTemplate<'a> {
     pub title: &'a str,
     /// Theme preference.
     pub theme: &'a str,
+    /// Absolute URL of the viewer, for canonical and OpenGraph links.
+    pub url: Option<&'a str>,
+    /// Custom HTML for the header.
+    pub header_html: Option<&'a str>,
+    /// Custom HTML for the footer.
+    pub footer_html: Option<&'a str>,
+    /// Analytics script tag.
+    pub analytics: Option<&'a str>,
+    /// Script tag loading Mermaid, if any ADR has a diagram.
+    pub mermaid: Option<&'a str>,
     /// Serialized JSON data for embedding.
     pub data_json: &'a str,
     /// Embedded CSS.
@@ -149,22 +678,117 @@ impl HtmlRenderer {
         source_dir: &str,
         config: &RenderConfig,
     ) -> Result<String> {
+        let data = self.data(adrs, source_dir, config)?;
+        self.render_data(&data, config)
+    }
+
+    /// Builds the data embedded in the viewer, keeping only the selected
+    /// record fields.
+    pub fn data(&self, adrs: Vec<Adr>, source_dir: &str, config: &RenderConfig) -> Result<Value> {
         // Build the embedded data
+        let mut meta = if config.reproducible {
+            ViewerMeta::reproducible(source_dir, &adrs)
+        } else {
+            ViewerMeta::new(source_dir)
+        };
+        meta.content_hash = content_hash(&adrs);
+        meta.url.clone_from(&config.url);
+        for status in adrs.iter().filter_map(Adr::custom_status) {
+            if !meta.statuses.contains(status) {
+                meta.statuses.push(status.clone());
+            }
+        }
+        meta.statuses.sort_by_key(CustomStatus::position);
+
+        let mut graph = Graph::from_adrs(&adrs);
+        if let Some(grouping) = config.graph_grouping {
+            graph = graph.with_groups(&adrs, grouping);
+        }
+        // Bodies are only searched when they are embedded
+        let fields = config.record_fields();
+        let search_fields: Vec<SearchField> = SearchField::ALL
+            .iter()
+            .copied()
+            .filter(|&field| field != SearchField::Body || fields.contains(&RecordField::BodyText))
+            .collect();
         let data = ViewerData {
-            meta: ViewerMeta::new(source_dir),
-            facets: Facets::from_adrs(&adrs),
-            graph: Graph::from_adrs(&adrs),
+            meta,
+            facets: Facets::from_adrs(&adrs).with_custom_fields(&adrs, &config.facets),
+            graph,
+            search: SearchIndex::new(&adrs, &search_fields),
             records: adrs,
         };
 
         // Serialize to JSON
+        let mut value =
+            serde_json::to_value(&data).map_err(|e| Error::JsonSerialize(e.to_string()))?;
+
+        // Keep only the selected record fields
+        if let Some(records) = value.get_mut("records").and_then(|r| r.as_array_mut()) {
+            for (record, adr) in records.iter_mut().zip(&data.records) {
+                if let Some(record) = record.as_object_mut() {
+                    // Custom statuses are shown under their own name
+                    if let (Some(status), Some(frontmatter)) = (
+                        adr.custom_status(),
+                        record.get_mut("frontmatter").and_then(Value::as_object_mut),
+                    ) {
+                        frontmatter.insert("status".to_string(), status.name.clone().into());
+                    }
+                    record.retain(|key, _| {
+                        REQUIRED_RECORD_KEYS.contains(&key.as_str())
+                            || fields.iter().any(|f| f.as_str() == key)
+                    });
+                    // Raw markdown is not part of the ADR's serialized form
+                    if fields.contains(&RecordField::BodyMarkdown) {
+                        record.insert(
+                            RecordField::BodyMarkdown.as_str().to_string(),
+                            adr.body_markdown().into(),
+                        );
+                    }
+                    let badges: Vec<Badge> = config
+                        .badges
+                        .iter()
+                        .filter_map(|rule| rule.badge(adr))
+                        .collect();
+                    if !badges.is_empty() {
+                        record.insert(
+                            "badges".to_string(),
+                            serde_json::to_value(badges)
+                                .map_err(|e| Error::JsonSerialize(e.to_string()))?,
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(value)
+    }
+
+    /// Renders a viewer around data built by [`Self::data`].
+    pub fn render_data(&self, data: &Value, config: &RenderConfig) -> Result<String> {
         let data_json =
-            serde_json::to_string(&data).map_err(|e| Error::JsonSerialize(e.to_string()))?;
+            serde_json::to_string(data).map_err(|e| Error::JsonSerialize(e.to_string()))?;
+
+        let analytics = config.analytics.as_ref().map(Analytics::script);
+        // Mermaid is only loaded when an embedded body has a diagram
+        let has_diagrams = data["records"].as_array().is_some_and(|records| {
+            records.iter().any(|record| {
+                record["body_html"]
+                    .as_str()
+                    .is_some_and(|html| html.contains(DIAGRAM_MARKUP))
+            })
+        });
+        let mermaid = has_diagrams.then(|| config.mermaid_script());
 
         // Render the template
         let template = ViewerTemplate {
             title: &config.title,
             theme: config.theme.as_str(),
+            url: config.url.as_deref(),
+            header_html: config.header_html.as_deref(),
+            footer_html: config.footer_html.as_deref(),
+            analytics: analytics.as_deref(),
+            mermaid: mermaid.as_deref(),
             data_json: &data_json,
             css: include_str!("../../../templates/styles.css"),
             js: include_str!("../../../templates/app.js"),
@@ -207,7 +831,487 @@ mod tests {
 
         assert!(meta.generated.contains("T")); // ISO 8601 format
         assert!(meta.generator.starts_with("adrscope/"));
-        assert_eq!(meta.schema_version, "1.0.0");
+        assert_eq!(meta.schema_version, SCHEMA_VERSION);
         assert_eq!(meta.source_dir, "docs/decisions");
     }
+
+    #[test]
+    fn test_render_embed_markdown() {
+        use crate::domain::{AdrId, Frontmatter};
+        use std::path::PathBuf;
+
+        let adrs = vec![Adr::new(
+            AdrId::new("adr_0001"),
+            "adr_0001.md".to_string(),
+            PathBuf::from("adr_0001.md"),
+            Frontmatter::new("Test"),
+            "## Raw *markdown*".to_string(),
+            "<h2>Raw</h2>".to_string(),
+            "Raw".to_string(),
+        )];
+        let renderer = HtmlRenderer::new();
+
+        let config = RenderConfig::new("ADRs");
+        let html = renderer
+            .render(adrs.clone(), "docs", &config)
+            .expect("should render");
+        assert!(!html.contains("\"body_markdown\""));
+
+        let config = config.with_embed_markdown(true);
+        let html = renderer
+            .render(adrs, "docs", &config)
+            .expect("should render");
+        assert!(html.contains("\"body_markdown\":\"## Raw *markdown*\""));
+    }
+
+    #[test]
+    fn test_index_data_strips_bodies() {
+        use crate::domain::{AdrId, Frontmatter};
+        use std::path::PathBuf;
+
+        let adrs = vec![Adr::new(
+            AdrId::new("adr_0001"),
+            "adr_0001.md".to_string(),
+            PathBuf::from("adr_0001.md"),
+            Frontmatter::new("Test"),
+            "## Raw".to_string(),
+            "<h2>Raw</h2>".to_string(),
+            "Raw".to_string(),
+        )];
+        let config = RenderConfig::new("ADRs").with_embed_markdown(true);
+        let data = HtmlRenderer::new()
+            .data(adrs, "docs", &config)
+            .expect("should build data");
+
+        let index = index_data(&data);
+        let record = index["records"][0].as_object().expect("record");
+        assert_eq!(
+            record.keys().map(String::as_str).collect::<Vec<_>>(),
+            vec!["filename", "frontmatter", "id"]
+        );
+        assert_eq!(index["meta"], data["meta"]);
+        assert_eq!(index["graph"], data["graph"]);
+        assert!(index.get("search").is_none());
+        assert!(data["records"][0].get("body_html").is_some());
+    }
+
+    #[test]
+    fn test_data_search_index() {
+        use crate::domain::{AdrId, Frontmatter};
+        use std::path::PathBuf;
+
+        let adrs = || {
+            vec![Adr::new(
+                AdrId::new("adr_0001"),
+                "adr_0001.md".to_string(),
+                PathBuf::from("adr_0001.md"),
+                Frontmatter::new("Use PostgreSQL").with_tags(vec!["database".to_string()]),
+                String::new(),
+                String::new(),
+                "Replication across regions".to_string(),
+            )]
+        };
+        let renderer = HtmlRenderer::new();
+
+        let data = renderer
+            .data(adrs(), "docs", &RenderConfig::new("ADRs"))
+            .expect("should build data");
+        assert_eq!(data["search"]["weights"]["title"], 10);
+        assert_eq!(data["search"]["tokens"]["postgresql"]["adr_0001"], 10);
+        assert_eq!(data["search"]["tokens"]["database"]["adr_0001"], 5);
+        assert_eq!(data["search"]["tokens"]["replication"]["adr_0001"], 1);
+
+        // Bodies left out of the records are left out of the index
+        let config = RenderConfig::new("ADRs").with_omit_fields(vec![RecordField::BodyText]);
+        let data = renderer
+            .data(adrs(), "docs", &config)
+            .expect("should build data");
+        assert!(data["search"]["weights"].get("body").is_none());
+        assert!(data["search"]["tokens"].get("replication").is_none());
+    }
+
+    #[test]
+    fn test_data_custom_statuses() {
+        use crate::domain::{AdrId, Frontmatter, Normalization, Status};
+        use std::path::PathBuf;
+
+        let mut frontmatter = Frontmatter::new("Test");
+        frontmatter.normalized.push(Normalization {
+            field: "status".to_string(),
+            original: "on-hold".to_string(),
+            normalized: "proposed".to_string(),
+        });
+        let statuses = [CustomStatus::new("on-hold", Status::Proposed).with_color("#8b5cf6")];
+        let adrs = vec![
+            Adr::new(
+                AdrId::new("adr_0001"),
+                "adr_0001.md".to_string(),
+                PathBuf::from("adr_0001.md"),
+                frontmatter,
+                String::new(),
+                String::new(),
+                String::new(),
+            )
+            .with_custom_statuses(&statuses),
+        ];
+        let data = HtmlRenderer::new()
+            .data(adrs, "docs", &RenderConfig::new("ADRs"))
+            .expect("should build data");
+
+        assert_eq!(data["records"][0]["frontmatter"]["status"], "on-hold");
+        assert_eq!(data["graph"]["nodes"][0]["status"], "on-hold");
+        assert_eq!(
+            data["meta"]["statuses"],
+            serde_json::json!([{"name": "on-hold", "base": "proposed", "color": "#8b5cf6"}])
+        );
+        let facets = data["facets"]["statuses"].as_array().expect("statuses");
+        assert_eq!(
+            facets[0],
+            serde_json::json!({"value": "on-hold", "count": 1})
+        );
+    }
+
+    #[test]
+    fn test_data_custom_fields() {
+        use crate::domain::{AdrId, Frontmatter};
+        use std::path::PathBuf;
+
+        let mut frontmatter = Frontmatter::new("Test");
+        frontmatter.custom =
+            serde_yaml::from_str("cost_center: CC-42\nregions: [eu, us]").expect("should parse");
+        let adrs = vec![Adr::new(
+            AdrId::new("adr_0001"),
+            "adr_0001.md".to_string(),
+            PathBuf::from("adr_0001.md"),
+            frontmatter,
+            String::new(),
+            String::new(),
+            String::new(),
+        )];
+
+        let renderer = HtmlRenderer::new();
+        let data = renderer
+            .data(adrs.clone(), "docs", &RenderConfig::new("ADRs"))
+            .expect("should build data");
+        assert_eq!(
+            data["records"][0]["frontmatter"]["custom"],
+            serde_json::json!({"cost_center": "CC-42", "regions": ["eu", "us"]})
+        );
+        assert!(data["facets"].get("custom").is_none());
+
+        let config = RenderConfig::new("ADRs").with_facets(vec!["regions".to_string()]);
+        let data = renderer
+            .data(adrs, "docs", &config)
+            .expect("should build data");
+        assert_eq!(
+            data["facets"]["custom"],
+            serde_json::json!({"regions": [
+                {"value": "eu", "count": 1},
+                {"value": "us", "count": 1}
+            ]})
+        );
+    }
+
+    #[test]
+    fn test_data_graph_groups() {
+        use crate::domain::{AdrId, Frontmatter};
+        use std::path::PathBuf;
+
+        let adrs = vec![Adr::new(
+            AdrId::new("adr_0001"),
+            "adr_0001.md".to_string(),
+            PathBuf::from("adr_0001.md"),
+            Frontmatter::new("Test").with_category("database"),
+            String::new(),
+            String::new(),
+            String::new(),
+        )];
+
+        let renderer = HtmlRenderer::new();
+        let data = renderer
+            .data(adrs.clone(), "docs", &RenderConfig::new("ADRs"))
+            .expect("should build data");
+        assert!(data["graph"]["nodes"][0].get("group").is_none());
+
+        let config = RenderConfig::new("ADRs").with_graph_grouping(GraphGrouping::Category);
+        let data = renderer
+            .data(adrs, "docs", &config)
+            .expect("should build data");
+        assert_eq!(data["graph"]["nodes"][0]["group"], "database");
+    }
+
+    #[test]
+    fn test_render_url() {
+        let renderer = HtmlRenderer::new();
+
+        let html = renderer
+            .render(Vec::new(), "docs", &RenderConfig::new("ADRs"))
+            .expect("should render");
+        assert!(!html.contains("og:url"));
+        assert!(!html.contains("\"url\""));
+
+        let config =
+            RenderConfig::new("Team \"A\" ADRs").with_url("https://example.com/adrs.html?a=1&b=2");
+        let html = renderer
+            .render(Vec::new(), "docs", &config)
+            .expect("should render");
+        assert!(html.contains(
+            r#"<meta property="og:url" content="https://example.com/adrs.html?a=1&#38;b=2">"#
+        ));
+        assert!(html.contains(r#"<meta property="og:title" content="Team &#34;A&#34; ADRs">"#));
+        assert!(html.contains("\"url\":\"https://example.com/adrs.html?a=1&b=2\""));
+    }
+
+    #[test]
+    fn test_render_header_footer_html() {
+        let renderer = HtmlRenderer::new();
+
+        let html = renderer
+            .render(Vec::new(), "docs", &RenderConfig::new("ADRs"))
+            .expect("should render");
+        assert!(!html.contains("class=\"header-custom\""));
+        assert!(!html.contains("class=\"footer-custom\""));
+
+        let config = RenderConfig::new("ADRs")
+            .with_header_html(r#"<a href="https://portal.example.com">Portal</a>"#)
+            .with_footer_html("<strong>Internal use only</strong>");
+        let html = renderer
+            .render(Vec::new(), "docs", &config)
+            .expect("should render");
+        assert!(html.contains(
+            r#"<div class="header-custom"><a href="https://portal.example.com">Portal</a></div>"#
+        ));
+        assert!(
+            html.contains(r#"<div class="footer-custom"><strong>Internal use only</strong></div>"#)
+        );
+    }
+
+    #[test]
+    fn test_render_analytics() {
+        let renderer = HtmlRenderer::new();
+
+        let html = renderer
+            .render(Vec::new(), "docs", &RenderConfig::new("ADRs"))
+            .expect("should render");
+        assert!(!html.contains("plausible"));
+        assert!(!html.contains("_paq"));
+
+        let config = RenderConfig::new("ADRs").with_analytics(Analytics::Plausible {
+            domain: "adrs.example.com".to_string(),
+            src: None,
+        });
+        let html = renderer
+            .render(Vec::new(), "docs", &config)
+            .expect("should render");
+        assert!(html.contains(
+            r#"<script defer data-domain="adrs.example.com" src="https://plausible.io/js/script.js"></script>"#
+        ));
+
+        let script = Analytics::Matomo {
+            url: "https://matomo.example.com".to_string(),
+            site_id: 3,
+        }
+        .script();
+        assert!(script.contains(r#"var u = "https://matomo.example.com/";"#));
+        assert!(script.contains("_paq.push(['setSiteId', '3']);"));
+        assert!(script.contains("_paq.push(['disableCookies']);"));
+        assert_eq!(
+            Analytics::Matomo {
+                url: "https://matomo.example.com".to_string(),
+                site_id: 3,
+            }
+            .script_url(),
+            "https://matomo.example.com/matomo.js"
+        );
+
+        let script = Analytics::Plausible {
+            domain: "a\"><script>".to_string(),
+            src: Some("https://stats.example.com/js/script.js".to_string()),
+        }
+        .script();
+        assert!(script.contains(r#"data-domain="a&quot;&gt;&lt;script&gt;""#));
+        assert!(script.contains(r#"src="https://stats.example.com/js/script.js""#));
+    }
+
+    #[test]
+    fn test_render_config_record_fields() {
+        let config = RenderConfig::new("ADRs");
+        assert_eq!(config.record_fields(), RecordField::DEFAULT);
+
+        let config = config
+            .with_embed_markdown(true)
+            .with_omit_fields(vec![RecordField::BodyText]);
+        assert_eq!(
+            config.record_fields(),
+            vec![
+                RecordField::Filename,
+                RecordField::BodyHtml,
+                RecordField::Package,
+                RecordField::Team,
+                RecordField::BodyMarkdown
+            ]
+        );
+
+        let config = RenderConfig::new("ADRs").with_embed_fields(vec![RecordField::BodyText]);
+        assert_eq!(config.record_fields(), vec![RecordField::BodyText]);
+    }
+
+    #[test]
+    fn test_render_omit_fields() {
+        use crate::domain::{AdrId, Frontmatter};
+        use std::path::PathBuf;
+
+        let adrs = vec![Adr::new(
+            AdrId::new("adr_0001"),
+            "adr_0001.md".to_string(),
+            PathBuf::from("adr_0001.md"),
+            Frontmatter::new("Test"),
+            String::new(),
+            "<p>Rendered</p>".to_string(),
+            "Searchable".to_string(),
+        )];
+
+        let config = RenderConfig::new("ADRs")
+            .with_omit_fields(vec![RecordField::BodyHtml, RecordField::BodyText]);
+        let html = HtmlRenderer::new()
+            .render(adrs, "docs", &config)
+            .expect("should render");
+
+        assert!(html.contains("\"id\":\"adr_0001\""));
+        assert!(html.contains("\"frontmatter\""));
+        assert!(!html.contains("\"body_html\""));
+        assert!(!html.contains("Searchable"));
+    }
+
+    #[test]
+    fn test_viewer_meta_reproducible() {
+        use crate::domain::{AdrId, Frontmatter};
+        use std::path::PathBuf;
+        use time::macros::date;
+
+        let adr = |created, updated| {
+            let mut frontmatter = Frontmatter::new("Test").with_created(created);
+            frontmatter.updated = updated;
+            Adr::new(
+                AdrId::new("adr_0001"),
+                "adr_0001.md".to_string(),
+                PathBuf::from("adr_0001.md"),
+                frontmatter,
+                String::new(),
+                String::new(),
+                String::new(),
+            )
+        };
+        let adrs = vec![
+            adr(date!(2025 - 01 - 10), Some(date!(2025 - 03 - 02))),
+            adr(date!(2025 - 02 - 01), None),
+        ];
+
+        let meta = ViewerMeta::reproducible("docs/decisions", &adrs);
+        assert_eq!(meta.generated, "2025-03-02T00:00:00Z");
+        assert_eq!(ViewerMeta::reproducible("docs", &[]).generated, "unknown");
+    }
+
+    #[test]
+    fn test_content_hash() {
+        use crate::domain::{AdrId, Frontmatter};
+        use std::path::PathBuf;
+
+        let adr = |id: &str, body: &str| {
+            Adr::new(
+                AdrId::new(id),
+                format!("{id}.md"),
+                PathBuf::from(format!("{id}.md")),
+                Frontmatter::new("Test"),
+                body.to_string(),
+                String::new(),
+                String::new(),
+            )
+        };
+        let hash = content_hash(&[adr("adr_0001", "a"), adr("adr_0002", "b")]);
+
+        assert_eq!(hash.len(), 64);
+        assert_eq!(
+            content_hash(&[adr("adr_0002", "b"), adr("adr_0001", "a")]),
+            hash
+        );
+        assert_ne!(
+            content_hash(&[adr("adr_0001", "a"), adr("adr_0002", "c")]),
+            hash
+        );
+        assert_ne!(content_hash(&[adr("adr_0001", "a")]), hash);
+    }
+
+    #[test]
+    fn test_badge_rule() {
+        use crate::domain::{AdrId, Frontmatter};
+        use std::path::PathBuf;
+
+        let mut frontmatter = Frontmatter::new("Test");
+        frontmatter.custom.insert("risk".to_string(), "High".into());
+        frontmatter
+            .custom
+            .insert("ticket".to_string(), "OPS 12".into());
+        let adr = Adr::new(
+            AdrId::new("adr_0001"),
+            "adr_0001.md".to_string(),
+            PathBuf::from("adr_0001.md"),
+            frontmatter,
+            String::new(),
+            String::new(),
+            String::new(),
+        );
+
+        let mut risk = BadgeRule::new("risk");
+        risk.colors
+            .insert("high".to_string(), "#ef4444".to_string());
+        risk.color = Some("#6b7280".to_string());
+        assert_eq!(
+            risk.badge(&adr),
+            Some(Badge {
+                label: "risk".to_string(),
+                value: "High".to_string(),
+                color: Some("#ef4444".to_string()),
+                url: None,
+            })
+        );
+
+        let mut ticket = BadgeRule::new("ticket");
+        ticket.label = Some("Ticket".to_string());
+        ticket.link = Some("https://jira.example.com/browse/{value}".to_string());
+        let badge = ticket.badge(&adr).expect("should have badge");
+        assert_eq!(badge.label, "Ticket");
+        assert_eq!(
+            badge.url.as_deref(),
+            Some("https://jira.example.com/browse/OPS%2012")
+        );
+        assert!(badge.color.is_none());
+
+        assert!(BadgeRule::new("missing").badge(&adr).is_none());
+
+        // Impact and risk are schema fields, read when not set as custom fields
+        let rated = Adr::new(
+            AdrId::new("adr_0002"),
+            "adr_0002.md".to_string(),
+            PathBuf::from("adr_0002.md"),
+            Frontmatter::new("Rated").with_risk(crate::domain::Level::Critical),
+            String::new(),
+            String::new(),
+            String::new(),
+        );
+        let badge = risk.badge(&rated).expect("should have badge");
+        assert_eq!(badge.value, "critical");
+        assert_eq!(badge.color.as_deref(), Some("#6b7280"));
+
+        let html = HtmlRenderer::new()
+            .render(
+                vec![adr],
+                "docs",
+                &RenderConfig::new("ADRs").with_badges(vec![risk]),
+            )
+            .expect("should render");
+        assert!(html.contains(
+            "\"badges\":[{\"color\":\"#ef4444\",\"label\":\"risk\",\"value\":\"High\"}]"
+        ));
+    }
 }
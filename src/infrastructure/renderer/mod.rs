@@ -3,7 +3,14 @@
 //! This module provides the HTML renderer using askama templates.
 
 mod html;
+mod offline;
+pub mod schema;
 mod wiki;
 
-pub use html::{HtmlRenderer, RenderConfig, Theme, ViewerData};
+pub use html::{
+    Analytics, Badge, BadgeRule, HtmlRenderer, MERMAID_SCRIPT, RecordField, RenderConfig, Theme,
+    ViewerData, adr_fragment, content_hash, index_data, section_fragment,
+};
+pub use offline::external_references;
+pub use schema::{SCHEMA_VERSION, SchemaCheck};
 pub use wiki::WikiRenderer;
@@ -0,0 +1,152 @@
+//! Detection of external resources in generated HTML.
+//!
+//! The viewer is meant to work without network access. This module finds
+//! the resources a browser would fetch from elsewhere when showing a page:
+//! `src`/`srcset`/`poster` attributes, stylesheets and icons linked with
+//! `<link>`, and CSS `url(...)` and `@import` references. Plain links
+//! (`<a href>`) are navigation, not resources, and are not reported.
+
+/// Attributes whose value is fetched by the browser.
+const RESOURCE_ATTRIBUTES: &[&str] = &["src", "srcset", "poster", "background"];
+
+/// `<link>` relations that do not make the browser fetch anything.
+const NON_FETCHING_RELS: &[&str] = &["canonical", "alternate", "author", "license", "help"];
+
+/// Returns the external URLs referenced as resources by an HTML page,
+/// without duplicates.
+///
+/// ADR bodies embedded as JSON are scanned as well; their escaped quotes
+/// and slashes are unescaped first.
+#[must_use]
+pub fn external_references(html: &str) -> Vec<String> {
+    let html = html.replace("\\\"", "\"").replace("\\/", "/");
+    let mut references = Vec::new();
+    let mut add = |value: &str| {
+        let value = value.trim();
+        if is_external(value) && !references.iter().any(|r| r == value) {
+            references.push(value.to_string());
+        }
+    };
+
+    for attribute in RESOURCE_ATTRIBUTES {
+        for value in attribute_values(&html, attribute) {
+            if *attribute == "srcset" {
+                value
+                    .split(',')
+                    .filter_map(|candidate| candidate.split_whitespace().next())
+                    .for_each(&mut add);
+            } else {
+                add(value);
+            }
+        }
+    }
+
+    for tag in html.match_indices("<link").map(|(start, _)| {
+        let rest = &html[start..];
+        &rest[..rest.find('>').unwrap_or(rest.len())]
+    }) {
+        let rel = attribute_values(tag, "rel")
+            .first()
+            .copied()
+            .unwrap_or_default();
+        if !rel
+            .split_whitespace()
+            .any(|rel| NON_FETCHING_RELS.contains(&rel.to_ascii_lowercase().as_str()))
+        {
+            attribute_values(tag, "href").into_iter().for_each(&mut add);
+        }
+    }
+
+    for (start, _) in html.match_indices("url(") {
+        let rest = html[start + 4..].trim_start_matches(['"', '\'']);
+        add(&rest[..rest.find(['"', '\'', ')']).unwrap_or(rest.len())]);
+    }
+
+    for (start, _) in html.match_indices("@import") {
+        let rest = html[start + 7..]
+            .trim_start()
+            .trim_start_matches("url(")
+            .trim_start_matches(['"', '\'']);
+        add(&rest[..rest.find(['"', '\'', ')', ';']).unwrap_or(rest.len())]);
+    }
+
+    references
+}
+
+/// Returns true if a URL points to another host.
+fn is_external(url: &str) -> bool {
+    let url = url.to_ascii_lowercase();
+    url.starts_with("http://") || url.starts_with("https://") || url.starts_with("//")
+}
+
+/// Returns the values of an attribute wherever it appears in `html`.
+fn attribute_values<'a>(html: &'a str, name: &str) -> Vec<&'a str> {
+    let pattern = format!("{name}=");
+    html.match_indices(&pattern)
+        .filter(|(start, _)| {
+            html[..*start]
+                .chars()
+                .next_back()
+                .is_some_and(char::is_whitespace)
+        })
+        .map(|(start, _)| {
+            let start = start + pattern.len();
+            let rest = &html[start..];
+            match rest.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let value = &rest[1..];
+                    &value[..value.find(quote).unwrap_or(value.len())]
+                },
+                _ => &rest[..rest.find([' ', '>', '\t', '\n']).unwrap_or(rest.len())],
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_external_references() {
+        let html = r#"<!DOCTYPE html>
+<html>
+<head>
+    <link rel="canonical" href="https://example.com/adrs.html">
+    <link rel="stylesheet" href="https://fonts.googleapis.com/css?family=Inter">
+    <script defer src="https://plausible.io/js/script.js"></script>
+    <style>
+body { background: url('https://cdn.example.com/bg.png'); }
+@import "https://cdn.example.com/theme.css";
+.local { background: url(data:image/png;base64,AAAA); }
+    </style>
+</head>
+<body>
+    <a href="https://github.com/zircote/adrscope">ADRScope</a>
+    <img src="data:image/png;base64,AAAA">
+    <img srcset="//cdn.example.com/a.png 1x, assets/b.png 2x">
+    <script>
+const ADRSCOPE_DATA = {"body_html":"<p><img src=\"https:\/\/example.com\/diagram.svg\"></p>"};
+    </script>
+</body>
+</html>"#;
+
+        assert_eq!(
+            external_references(html),
+            vec![
+                "https://plausible.io/js/script.js",
+                "https://example.com/diagram.svg",
+                "//cdn.example.com/a.png",
+                "https://fonts.googleapis.com/css?family=Inter",
+                "https://cdn.example.com/bg.png",
+                "https://cdn.example.com/theme.css",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_external_references_ignores_similar_names() {
+        let html = r#"<img data-src="https://example.com/a.png"><p>img.src="https://x"</p>"#;
+        assert!(external_references(html).is_empty());
+    }
+}
@@ -0,0 +1,244 @@
+//! Viewer data schema versioning and compatibility checks.
+//!
+//! Every generated viewer embeds its data together with a `schema_version`
+//! following semantic versioning: the major version changes when existing
+//! fields are removed or change meaning, the minor version when fields are
+//! added. External consumers should accept any data with the same major
+//! version. See `docs/viewer-schema.md` for the full schema.
+
+use serde_json::Value;
+
+/// Version of the embedded viewer data schema.
+///
+/// Bump this whenever the shape of [`super::ViewerData`] changes.
+pub const SCHEMA_VERSION: &str = "1.16.0";
+
+/// Prefix of the script line holding the data in generated HTML.
+const DATA_MARKER: &str = "const ADRSCOPE_DATA = ";
+
+/// Finds the embedded viewer data in a generated HTML file or JSON document.
+///
+/// Returns `None` if the content contains no viewer data.
+#[must_use]
+pub fn extract_viewer_json(content: &str) -> Option<&str> {
+    let trimmed = content.trim();
+    if trimmed.starts_with('{') {
+        return Some(trimmed);
+    }
+
+    let start = content.find(DATA_MARKER)? + DATA_MARKER.len();
+    let line = content[start..].lines().next()?;
+    Some(line.trim_end().trim_end_matches(';'))
+}
+
+/// Outcome of checking viewer data against the expected schema.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaCheck {
+    /// Schema version declared by the data, if any.
+    pub schema_version: Option<String>,
+    /// Number of records in the data.
+    pub record_count: usize,
+    /// Incompatibilities that prevent consuming the data.
+    pub errors: Vec<String>,
+    /// Differences that consumers may safely ignore.
+    pub warnings: Vec<String>,
+}
+
+impl SchemaCheck {
+    /// Returns true if the data is compatible with this schema version.
+    #[must_use]
+    pub fn is_compatible(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Checks viewer data against the schema of this version of ADRScope.
+#[must_use]
+pub fn check_viewer_data(data: &Value) -> SchemaCheck {
+    let mut check = SchemaCheck::default();
+
+    let Some(root) = data.as_object() else {
+        check
+            .errors
+            .push("viewer data must be a JSON object".to_string());
+        return check;
+    };
+
+    match root.get("meta").and_then(Value::as_object) {
+        Some(meta) => {
+            for key in ["generated", "generator", "schema_version", "source_dir"] {
+                if !meta.get(key).is_some_and(Value::is_string) {
+                    check.errors.push(format!("meta.{key} must be a string"));
+                }
+            }
+            if let Some(version) = meta.get("schema_version").and_then(Value::as_str) {
+                check_version(version, &mut check);
+                check.schema_version = Some(version.to_string());
+            }
+        },
+        None => check.errors.push("missing 'meta' object".to_string()),
+    }
+
+    match root.get("records").and_then(Value::as_array) {
+        Some(records) => {
+            check.record_count = records.len();
+            for (index, record) in records.iter().enumerate() {
+                check_record(index, record, &mut check);
+            }
+        },
+        None => check.errors.push("missing 'records' array".to_string()),
+    }
+
+    if !root.get("facets").is_some_and(Value::is_object) {
+        check.errors.push("missing 'facets' object".to_string());
+    }
+
+    match root.get("graph").and_then(Value::as_object) {
+        Some(graph) => {
+            for key in ["nodes", "edges"] {
+                if !graph.get(key).is_some_and(Value::is_array) {
+                    check.errors.push(format!("graph.{key} must be an array"));
+                }
+            }
+        },
+        None => check.errors.push("missing 'graph' object".to_string()),
+    }
+
+    check
+}
+
+/// Compares a declared schema version with [`SCHEMA_VERSION`].
+fn check_version(version: &str, check: &mut SchemaCheck) {
+    let (Some(found), Some(expected)) = (parse_version(version), parse_version(SCHEMA_VERSION))
+    else {
+        check
+            .errors
+            .push(format!("invalid schema version '{version}'"));
+        return;
+    };
+
+    if found.0 != expected.0 {
+        check.errors.push(format!(
+            "schema version {version} is incompatible with {SCHEMA_VERSION}"
+        ));
+    } else if found > expected {
+        check.warnings.push(format!(
+            "schema version {version} is newer than {SCHEMA_VERSION}; unknown fields are ignored"
+        ));
+    }
+}
+
+/// Checks the fields every record must carry.
+fn check_record(index: usize, record: &Value, check: &mut SchemaCheck) {
+    let Some(record) = record.as_object() else {
+        check
+            .errors
+            .push(format!("records[{index}] must be an object"));
+        return;
+    };
+
+    if !record.get("id").is_some_and(Value::is_string) {
+        check
+            .errors
+            .push(format!("records[{index}].id must be a string"));
+    }
+
+    match record.get("frontmatter").and_then(Value::as_object) {
+        Some(frontmatter) => {
+            for key in ["title", "status"] {
+                if !frontmatter.get(key).is_some_and(Value::is_string) {
+                    check.errors.push(format!(
+                        "records[{index}].frontmatter.{key} must be a string"
+                    ));
+                }
+            }
+        },
+        None => check
+            .errors
+            .push(format!("records[{index}] is missing 'frontmatter'")),
+    }
+}
+
+/// Parses a `major.minor.patch` version.
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let mut parts = version.split('.').map(|part| part.parse().ok());
+    let parsed = (parts.next()??, parts.next()??, parts.next()??);
+    parts.next().is_none().then_some(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sample(version: &str) -> Value {
+        json!({
+            "meta": {
+                "generated": "2025-01-01T00:00:00Z",
+                "generator": "adrscope/0.3.0",
+                "schema_version": version,
+                "source_dir": "docs/decisions"
+            },
+            "records": [
+                {"id": "adr_0001", "frontmatter": {"title": "Test", "status": "accepted"}}
+            ],
+            "facets": {},
+            "graph": {"nodes": [], "edges": []}
+        })
+    }
+
+    #[test]
+    fn test_extract_viewer_json() {
+        let html = "<script>\nconst ADRSCOPE_DATA = {\"meta\":{}};\nfunction x() {}\n</script>";
+        assert_eq!(extract_viewer_json(html), Some("{\"meta\":{}}"));
+        assert_eq!(extract_viewer_json("  {\"a\":1}\n"), Some("{\"a\":1}"));
+        assert_eq!(extract_viewer_json("<html></html>"), None);
+    }
+
+    #[test]
+    fn test_check_current_version() {
+        let check = check_viewer_data(&sample(SCHEMA_VERSION));
+        assert!(check.is_compatible());
+        assert!(check.warnings.is_empty());
+        assert_eq!(check.schema_version.as_deref(), Some(SCHEMA_VERSION));
+        assert_eq!(check.record_count, 1);
+    }
+
+    #[test]
+    fn test_check_version_compatibility() {
+        assert!(check_viewer_data(&sample("1.0.0")).is_compatible());
+
+        let newer = check_viewer_data(&sample("1.99.0"));
+        assert!(newer.is_compatible());
+        assert_eq!(newer.warnings.len(), 1);
+
+        assert!(!check_viewer_data(&sample("2.0.0")).is_compatible());
+        assert!(!check_viewer_data(&sample("one")).is_compatible());
+    }
+
+    #[test]
+    fn test_check_structure() {
+        let mut data = sample(SCHEMA_VERSION);
+        data["records"][0]["frontmatter"]
+            .as_object_mut()
+            .unwrap()
+            .remove("status");
+        data.as_object_mut().unwrap().remove("graph");
+
+        let check = check_viewer_data(&data);
+        assert_eq!(
+            check.errors,
+            vec![
+                "records[0].frontmatter.status must be a string".to_string(),
+                "missing 'graph' object".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("1.2.3"), Some((1, 2, 3)));
+        assert_eq!(parse_version("1.2"), None);
+        assert_eq!(parse_version("1.2.3.4"), None);
+    }
+}
@@ -2,11 +2,12 @@
 //!
 //! Generates markdown files suitable for GitHub Wiki.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Write;
 
-use crate::domain::{Adr, AdrStatistics, Status};
+use crate::domain::{Adr, AdrStatistics, CustomStatus, Graph, Level, Status, status_names};
 use crate::error::Result;
+use crate::infrastructure::parser::Slugger;
 
 /// Renderer for wiki-style markdown output.
 #[derive(Debug, Clone, Default)]
@@ -32,25 +33,39 @@ impl WikiRenderer {
             let _ = writeln!(output);
         }
 
-        let _ = writeln!(output, "| ID | Title | Status | Category | Created |");
-        let _ = writeln!(output, "|:---|:------|:------:|:---------|:--------|");
+        let _ = writeln!(
+            output,
+            "| ID | Title | Status | Category | Created | Superseded By |"
+        );
+        let _ = writeln!(
+            output,
+            "|:---|:------|:------:|:---------|:--------|:--------------|"
+        );
+
+        let graph = Graph::from_adrs(adrs);
 
         for adr in adrs {
             let created = adr
                 .created()
                 .map_or_else(|| "-".to_string(), |d| d.to_string());
 
-            let status_badge = status_badge(adr.status());
+            let status_badge = status_badge(adr.status_name());
+
+            // Point readers of old decisions at the one in force today
+            let current = graph
+                .current_decision(adr.id().as_str())
+                .map_or_else(|| "-".to_string(), |id| adr_link(adrs, id));
 
             let _ = writeln!(
                 output,
-                "| {} | [{}]({}) | {} | {} | {} |",
+                "| {} | [{}]({}) | {} | {} | {} | {} |",
                 adr.id(),
                 adr.title(),
                 adr.filename(),
                 status_badge,
                 adr.category(),
-                created
+                created,
+                current
             );
         }
 
@@ -66,30 +81,42 @@ impl WikiRenderer {
         let _ = writeln!(output);
 
         // Group ADRs by status
-        let mut by_status: HashMap<Status, Vec<&Adr>> = HashMap::new();
+        let mut by_status: HashMap<&str, Vec<&Adr>> = HashMap::new();
         for adr in adrs {
-            by_status.entry(adr.status()).or_default().push(adr);
+            by_status.entry(adr.status_name()).or_default().push(adr);
         }
 
-        // Output in a fixed order
-        for status in Status::all() {
-            if let Some(group) = by_status.get(status) {
-                if !group.is_empty() {
-                    let _ = writeln!(output, "## {} {}", status_emoji(*status), status);
-                    let _ = writeln!(output);
+        // Output in the order statuses are listed
+        let custom: Vec<&CustomStatus> = adrs.iter().filter_map(Adr::custom_status).collect();
+        let sections: Vec<(String, &Vec<&Adr>)> = status_names(custom.iter().copied())
+            .into_iter()
+            .filter_map(|status| {
+                by_status.get(status).map(|group| {
+                    let emoji = status_emoji(status, custom.iter().copied());
+                    (format!("{emoji} {status}"), group)
+                })
+            })
+            .collect();
+        write_contents(
+            &mut output,
+            "ADRs by Status",
+            sections.iter().map(|(h, _)| h),
+        );
 
-                    for adr in group {
-                        let _ = writeln!(
-                            output,
-                            "- [{}]({}) - {}",
-                            adr.title(),
-                            adr.filename(),
-                            adr.description()
-                        );
-                    }
-                    let _ = writeln!(output);
-                }
+        for (heading, group) in sections {
+            let _ = writeln!(output, "## {heading}");
+            let _ = writeln!(output);
+
+            for adr in group {
+                let _ = writeln!(
+                    output,
+                    "- [{}]({}) - {}",
+                    adr.title(),
+                    adr.filename(),
+                    adr.description()
+                );
             }
+            let _ = writeln!(output);
         }
 
         output
@@ -115,8 +142,9 @@ impl WikiRenderer {
         }
 
         // Sort categories alphabetically
-        let mut categories: Vec<_> = by_category.keys().collect();
-        categories.sort();
+        let mut categories: Vec<_> = by_category.keys().copied().collect();
+        categories.sort_unstable();
+        write_contents(&mut output, "ADRs by Category", categories.iter());
 
         for category in categories {
             if let Some(group) = by_category.get(category) {
@@ -124,7 +152,7 @@ impl WikiRenderer {
                 let _ = writeln!(output);
 
                 for adr in group {
-                    let status = status_badge(adr.status());
+                    let status = status_badge(adr.status_name());
                     let _ = writeln!(
                         output,
                         "- [{}]({}) {} - {}",
@@ -141,6 +169,65 @@ impl WikiRenderer {
         output
     }
 
+    /// Generates ADRs grouped by owning team, unmapped ADRs last.
+    #[must_use]
+    pub fn render_by_team(&self, adrs: &[Adr]) -> String {
+        let mut output = String::new();
+
+        let _ = writeln!(output, "# ADRs by Team");
+        let _ = writeln!(output);
+
+        let mut by_team: BTreeMap<&str, Vec<&Adr>> = BTreeMap::new();
+        let mut unowned: Vec<&Adr> = Vec::new();
+        for adr in adrs {
+            match adr.team() {
+                Some(team) => by_team.entry(team).or_default().push(adr),
+                None => unowned.push(adr),
+            }
+        }
+
+        let mut groups: Vec<_> = by_team.into_iter().collect();
+        if !unowned.is_empty() {
+            groups.push(("Unowned", unowned));
+        }
+        write_contents(
+            &mut output,
+            "ADRs by Team",
+            groups.iter().map(|(team, _)| team),
+        );
+
+        for (team, group) in groups {
+            let _ = writeln!(output, "## {team}");
+            let _ = writeln!(output);
+
+            for adr in group {
+                let _ = writeln!(
+                    output,
+                    "- [{}]({}) {} - {}",
+                    adr.title(),
+                    adr.filename(),
+                    status_badge(adr.status_name()),
+                    truncate(adr.description(), 80)
+                );
+            }
+            let _ = writeln!(output);
+        }
+
+        output
+    }
+
+    /// Generates ADRs grouped by impact level, highest first.
+    #[must_use]
+    pub fn render_by_impact(&self, adrs: &[Adr]) -> String {
+        render_by_level(adrs, "ADRs by Impact", Adr::impact)
+    }
+
+    /// Generates ADRs grouped by risk level, highest first.
+    #[must_use]
+    pub fn render_by_risk(&self, adrs: &[Adr]) -> String {
+        render_by_level(adrs, "ADRs by Risk", Adr::risk)
+    }
+
     /// Generates a chronological timeline of ADRs.
     #[must_use]
     pub fn render_timeline(&self, adrs: &[Adr]) -> String {
@@ -166,7 +253,7 @@ impl WikiRenderer {
                     let _ = writeln!(output);
                 }
 
-                let status = status_badge(adr.status());
+                let status = status_badge(adr.status_name());
                 let _ = writeln!(
                     output,
                     "- **{}** [{}]({}) {}",
@@ -184,7 +271,7 @@ impl WikiRenderer {
             let _ = writeln!(output, "\n## Undated");
             let _ = writeln!(output);
             for adr in undated {
-                let status = status_badge(adr.status());
+                let status = status_badge(adr.status_name());
                 let _ = writeln!(output, "- [{}]({}) {}", adr.title(), adr.filename(), status);
             }
         }
@@ -192,9 +279,10 @@ impl WikiRenderer {
         output
     }
 
-    /// Generates a statistics summary page.
+    /// Generates a statistics summary page, listing at most `limit` authors
+    /// and tags, or all of them when `None`.
     #[must_use]
-    pub fn render_statistics(&self, stats: &AdrStatistics) -> String {
+    pub fn render_statistics(&self, stats: &AdrStatistics, limit: Option<usize>) -> String {
         let mut output = String::new();
 
         let _ = writeln!(output, "# ADR Statistics");
@@ -205,9 +293,10 @@ impl WikiRenderer {
         // Status breakdown
         let _ = writeln!(output, "## By Status");
         let _ = writeln!(output);
-        for status in Status::all() {
-            let count = stats.by_status.get(status.as_str()).copied().unwrap_or(0);
-            let _ = writeln!(output, "- {} {}: {}", status_emoji(*status), status, count);
+        for status in stats.statuses() {
+            let count = stats.by_status.get(status).copied().unwrap_or(0);
+            let emoji = status_emoji(status, &stats.custom_statuses);
+            let _ = writeln!(output, "- {emoji} {status}: {count}");
         }
         let _ = writeln!(output);
 
@@ -215,26 +304,65 @@ impl WikiRenderer {
         if !stats.by_category.is_empty() {
             let _ = writeln!(output, "## By Category");
             let _ = writeln!(output);
-            let mut categories: Vec<_> = stats.by_category.iter().collect();
-            categories.sort_by(|a, b| b.1.cmp(a.1));
-            for (category, count) in categories {
+            for (category, count) in AdrStatistics::top_n(&stats.by_category, usize::MAX) {
                 let _ = writeln!(output, "- {category}: {count}");
             }
             let _ = writeln!(output);
         }
 
+        // Category x status
+        if !stats.by_category_status.is_empty() {
+            let _ = writeln!(output, "## By Category and Status");
+            let _ = writeln!(output);
+            output.push_str(&stats.category_status_table());
+            let _ = writeln!(output);
+        }
+
+        // Team breakdown
+        if !stats.by_team.is_empty() {
+            let _ = writeln!(output, "## By Team");
+            let _ = writeln!(output);
+            for (team, count) in AdrStatistics::top_n(&stats.by_team, usize::MAX) {
+                let _ = writeln!(output, "- {team}: {count}");
+            }
+            let _ = writeln!(output);
+        }
+
+        // Impact and risk breakdowns
+        for (heading, counts) in [("By Impact", &stats.by_impact), ("By Risk", &stats.by_risk)] {
+            if counts.is_empty() {
+                continue;
+            }
+            let _ = writeln!(output, "## {heading}");
+            let _ = writeln!(output);
+            for (level, count) in AdrStatistics::level_counts(counts).into_iter().rev() {
+                let _ = writeln!(output, "- {} {level}: {count}", level_emoji(level));
+            }
+            let _ = writeln!(output);
+        }
+
         // Author breakdown
         if !stats.by_author.is_empty() {
             let _ = writeln!(output, "## By Author");
             let _ = writeln!(output);
-            let mut authors: Vec<_> = stats.by_author.iter().collect();
-            authors.sort_by(|a, b| b.1.cmp(a.1));
-            for (author, count) in authors.iter().take(10) {
+            let limit = limit.unwrap_or(usize::MAX);
+            for (author, count) in AdrStatistics::top_n(&stats.by_author, limit) {
                 let _ = writeln!(output, "- {author}: {count}");
             }
             let _ = writeln!(output);
         }
 
+        // Tag breakdown
+        if !stats.by_tag.is_empty() {
+            let _ = writeln!(output, "## By Tag");
+            let _ = writeln!(output);
+            let limit = limit.unwrap_or(usize::MAX);
+            for (tag, count) in AdrStatistics::top_n(&stats.by_tag, limit) {
+                let _ = writeln!(output, "- {tag}: {count}");
+            }
+            let _ = writeln!(output);
+        }
+
         // Date range
         if let (Some(earliest), Some(latest)) = (&stats.earliest_date, &stats.latest_date) {
             let _ = writeln!(output, "## Date Range");
@@ -243,18 +371,54 @@ impl WikiRenderer {
             let _ = writeln!(output, "- **Latest:** {latest}");
         }
 
+        // Proposed backlog
+        if !stats.proposed_burndown.is_empty() {
+            let _ = writeln!(output);
+            let _ = writeln!(output, "## Proposed Backlog");
+            let _ = writeln!(output);
+            let _ = writeln!(output, "| Month | Opened | Decided | Open |");
+            let _ = writeln!(output, "|-------|--------|---------|------|");
+            for point in &stats.proposed_burndown {
+                let _ = writeln!(
+                    output,
+                    "| {} | {} | {} | {} |",
+                    point.month, point.opened, point.closed, point.open
+                );
+            }
+        }
+
+        // Hub decisions
+        if !stats.most_connected.is_empty() {
+            let _ = writeln!(output);
+            let _ = writeln!(output, "## Most Connected");
+            let _ = writeln!(output);
+            let limit = limit.unwrap_or(usize::MAX);
+            for adr in stats.most_connected.iter().take(limit) {
+                let _ = writeln!(
+                    output,
+                    "- {}: {} ({} in, {} out)",
+                    adr.id, adr.title, adr.in_degree, adr.out_degree
+                );
+            }
+        }
+
         output
     }
 
-    /// Generates all wiki files.
+    /// Generates all wiki files, listing at most `limit` values per dimension
+    /// on the statistics page.
+    ///
+    /// The by-team, by-impact, and by-risk pages are only generated when
+    /// some ADR has an owning team, impact, or risk.
     pub fn render_all(
         &self,
         adrs: &[Adr],
         pages_url: Option<&str>,
+        limit: Option<usize>,
     ) -> Result<Vec<(String, String)>> {
         let stats = AdrStatistics::from_adrs(adrs);
 
-        Ok(vec![
+        let mut files = vec![
             (
                 "ADR-Index.md".to_string(),
                 self.render_index(adrs, pages_url),
@@ -264,17 +428,61 @@ impl WikiRenderer {
                 "ADR-By-Category.md".to_string(),
                 self.render_by_category(adrs),
             ),
-            ("ADR-Timeline.md".to_string(), self.render_timeline(adrs)),
-            (
-                "ADR-Statistics.md".to_string(),
-                self.render_statistics(&stats),
-            ),
-        ])
+        ];
+        if adrs.iter().any(|adr| adr.team().is_some()) {
+            files.push(("ADR-By-Team.md".to_string(), self.render_by_team(adrs)));
+        }
+        if adrs.iter().any(|adr| adr.impact().is_some()) {
+            files.push(("ADR-By-Impact.md".to_string(), self.render_by_impact(adrs)));
+        }
+        if adrs.iter().any(|adr| adr.risk().is_some()) {
+            files.push(("ADR-By-Risk.md".to_string(), self.render_by_risk(adrs)));
+        }
+        files.push(("ADR-Timeline.md".to_string(), self.render_timeline(adrs)));
+        files.push((
+            "ADR-Statistics.md".to_string(),
+            self.render_statistics(&stats, limit),
+        ));
+        Ok(files)
     }
 }
 
-/// Returns an emoji for the given status.
-fn status_emoji(status: Status) -> &'static str {
+/// Writes a list of links to a page's sections.
+///
+/// Anchors are generated with the same algorithm GitHub uses for headings,
+/// starting with the page title.
+fn write_contents<S: AsRef<str>>(
+    output: &mut String,
+    title: &str,
+    sections: impl IntoIterator<Item = S>,
+) {
+    let mut slugger = Slugger::new();
+    slugger.slug(title);
+
+    for section in sections {
+        let section = section.as_ref();
+        let _ = writeln!(output, "- [{section}](#{})", slugger.slug(section));
+    }
+    let _ = writeln!(output);
+}
+
+/// Returns a link to the ADR with the given ID, or the bare ID if it is not
+/// in the collection.
+fn adr_link(adrs: &[Adr], id: &str) -> String {
+    adrs.iter().find(|a| a.id().as_str() == id).map_or_else(
+        || id.to_string(),
+        |adr| format!("[{id}]({})", adr.filename()),
+    )
+}
+
+/// Returns an emoji for the named status: a custom status's own emoji, or
+/// else that of the built-in status it is or is based on.
+fn status_emoji<'a>(name: &str, custom: impl IntoIterator<Item = &'a CustomStatus>) -> &'a str {
+    let custom = custom.into_iter().find(|status| status.name == name);
+    if let Some(emoji) = custom.and_then(|status| status.emoji.as_deref()) {
+        return emoji;
+    }
+    let status = custom.map_or_else(|| name.parse().unwrap_or_default(), |status| status.base);
     match status {
         Status::Proposed => "\u{1F7E1}",   // yellow circle
         Status::Accepted => "\u{2705}",    // green check
@@ -283,9 +491,68 @@ fn status_emoji(status: Status) -> &'static str {
     }
 }
 
-/// Returns a markdown badge for the given status.
-fn status_badge(status: Status) -> String {
-    format!("`{}`", status.as_str())
+/// Returns an emoji for the given impact or risk level.
+fn level_emoji(level: Level) -> &'static str {
+    match level {
+        Level::Low => "\u{1F7E2}",      // green circle
+        Level::Medium => "\u{1F7E1}",   // yellow circle
+        Level::High => "\u{1F7E0}",     // orange circle
+        Level::Critical => "\u{1F534}", // red circle
+    }
+}
+
+/// Writes ADRs grouped by a level, highest first, unrated ADRs last.
+fn render_by_level(adrs: &[Adr], title: &str, level: fn(&Adr) -> Option<Level>) -> String {
+    let mut output = String::new();
+
+    let _ = writeln!(output, "# {title}");
+    let _ = writeln!(output);
+
+    let mut groups: Vec<(String, Vec<&Adr>)> = Level::all()
+        .iter()
+        .rev()
+        .map(|&value| {
+            let group = adrs
+                .iter()
+                .filter(|adr| level(adr) == Some(value))
+                .collect();
+            (format!("{} {value}", level_emoji(value)), group)
+        })
+        .collect();
+    groups.push((
+        "Unrated".to_string(),
+        adrs.iter().filter(|adr| level(adr).is_none()).collect(),
+    ));
+    groups.retain(|(_, group)| !group.is_empty());
+    write_contents(
+        &mut output,
+        title,
+        groups.iter().map(|(heading, _)| heading),
+    );
+
+    for (heading, group) in groups {
+        let _ = writeln!(output, "## {heading}");
+        let _ = writeln!(output);
+
+        for adr in group {
+            let _ = writeln!(
+                output,
+                "- [{}]({}) {} - {}",
+                adr.title(),
+                adr.filename(),
+                status_badge(adr.status_name()),
+                truncate(adr.description(), 80)
+            );
+        }
+        let _ = writeln!(output);
+    }
+
+    output
+}
+
+/// Returns a markdown badge for the named status.
+fn status_badge(status: &str) -> String {
+    format!("`{status}`")
 }
 
 /// Truncates a string to the given length, adding ellipsis if needed.
@@ -300,7 +567,7 @@ fn truncate(s: &str, max_len: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::{AdrId, Frontmatter};
+    use crate::domain::{AdrId, Frontmatter, Normalization};
     use std::path::PathBuf;
     use time::macros::date;
 
@@ -338,6 +605,26 @@ mod tests {
         assert!(output.contains("adr_0001.md"));
     }
 
+    #[test]
+    fn test_render_index_superseded_by() {
+        let old = create_test_adr("adr_0001", "Use MySQL", Status::Superseded, "database");
+        let new = Adr::new(
+            AdrId::new("adr_0002"),
+            "adr_0002.md".to_string(),
+            PathBuf::from("adr_0002.md"),
+            Frontmatter::new("Use PostgreSQL").with_supersedes(vec!["adr_0001.md".to_string()]),
+            String::new(),
+            String::new(),
+            String::new(),
+        );
+
+        let output = WikiRenderer::new().render_index(&[old, new], None);
+
+        assert!(output.contains("| Created | Superseded By |"));
+        assert!(output.contains("| 2025-01-15 | [adr_0002](adr_0002.md) |"));
+        assert!(output.contains("| - | - |"));
+    }
+
     #[test]
     fn test_render_by_status() {
         let adrs = vec![
@@ -351,6 +638,63 @@ mod tests {
 
         assert!(output.contains("# ADRs by Status"));
         assert!(output.contains("## ")); // Status headers
+        assert!(output.contains("- [\u{2705} accepted](#-accepted)"));
+        assert!(output.contains("- [\u{1F7E1} proposed](#-proposed)"));
+    }
+
+    #[test]
+    fn test_render_custom_statuses() {
+        let custom = [
+            CustomStatus::new("rejected", Status::Deprecated),
+            CustomStatus::new("draft", Status::Proposed)
+                .with_emoji("\u{270F}\u{FE0F}")
+                .with_order(5),
+        ];
+        let with_status = |id: &str, status: &str| {
+            let mut frontmatter = Frontmatter::new(id);
+            frontmatter.normalized.push(Normalization {
+                field: "status".to_string(),
+                original: status.to_string(),
+                normalized: "proposed".to_string(),
+            });
+            Adr::new(
+                AdrId::new(id),
+                format!("{id}.md"),
+                PathBuf::from(format!("{id}.md")),
+                frontmatter,
+                String::new(),
+                String::new(),
+                String::new(),
+            )
+            .with_custom_statuses(&custom)
+        };
+        let adrs = vec![
+            with_status("adr_0001", "rejected"),
+            with_status("adr_0002", "draft"),
+            create_test_adr("adr_0003", "ADR 3", Status::Accepted, "cat"),
+        ];
+
+        let renderer = WikiRenderer::new();
+        let output = renderer.render_by_status(&adrs);
+        let draft = output
+            .find("## \u{270F}\u{FE0F} draft")
+            .expect("draft section");
+        let accepted = output
+            .find("## \u{2705} accepted")
+            .expect("accepted section");
+        let rejected = output
+            .find("## \u{1F534} rejected")
+            .expect("rejected section");
+        assert!(draft < accepted && accepted < rejected);
+        assert!(
+            renderer
+                .render_index(&adrs, None)
+                .contains("| `rejected` |")
+        );
+
+        let stats = renderer.render_statistics(&AdrStatistics::from_adrs(&adrs), None);
+        assert!(stats.contains("- \u{270F}\u{FE0F} draft: 1\n- \u{1F7E1} proposed: 0\n"));
+        assert!(stats.contains("- \u{1F534} rejected: 1\n"));
     }
 
     #[test]
@@ -366,6 +710,7 @@ mod tests {
         assert!(output.contains("# ADRs by Category"));
         assert!(output.contains("## api"));
         assert!(output.contains("## architecture"));
+        assert!(output.contains("- [api](#api)\n- [architecture](#architecture)"));
     }
 
     #[test]
@@ -376,16 +721,16 @@ mod tests {
 
     #[test]
     fn test_status_badge() {
-        assert_eq!(status_badge(Status::Accepted), "`accepted`");
-        assert_eq!(status_badge(Status::Proposed), "`proposed`");
+        assert_eq!(status_badge("accepted"), "`accepted`");
+        assert_eq!(status_badge("proposed"), "`proposed`");
     }
 
     #[test]
     fn test_status_emoji() {
-        assert_eq!(status_emoji(Status::Proposed), "\u{1F7E1}");
-        assert_eq!(status_emoji(Status::Accepted), "\u{2705}");
-        assert_eq!(status_emoji(Status::Deprecated), "\u{1F534}");
-        assert_eq!(status_emoji(Status::Superseded), "\u{26AA}");
+        assert_eq!(status_emoji(Status::Proposed.as_str(), []), "\u{1F7E1}");
+        assert_eq!(status_emoji(Status::Accepted.as_str(), []), "\u{2705}");
+        assert_eq!(status_emoji(Status::Deprecated.as_str(), []), "\u{1F534}");
+        assert_eq!(status_emoji(Status::Superseded.as_str(), []), "\u{26AA}");
     }
 
     #[test]
@@ -444,12 +789,38 @@ mod tests {
 
         let stats = AdrStatistics::from_adrs(&adrs);
         let renderer = WikiRenderer::new();
-        let output = renderer.render_statistics(&stats);
+        let output = renderer.render_statistics(&stats, Some(10));
 
         assert!(output.contains("# ADR Statistics"));
         assert!(output.contains("**Total ADRs:** 3"));
         assert!(output.contains("## By Status"));
         assert!(output.contains("## By Category"));
+        assert!(output.contains("## Proposed Backlog"));
+        assert!(output.contains("| arch | 1 | 1 | 0 | 0 |"));
+        assert!(!output.contains("## Most Connected"));
+    }
+
+    #[test]
+    fn test_render_statistics_most_connected() {
+        let related = Adr::new(
+            AdrId::new("adr_0002"),
+            "adr_0002.md".to_string(),
+            PathBuf::from("adr_0002.md"),
+            Frontmatter::new("ADR 2").with_related(vec!["adr_0001.md".to_string()]),
+            String::new(),
+            String::new(),
+            String::new(),
+        );
+        let adrs = vec![
+            create_test_adr("adr_0001", "ADR 1", Status::Accepted, "arch"),
+            related,
+        ];
+
+        let stats = AdrStatistics::from_adrs(&adrs);
+        let output = WikiRenderer::new().render_statistics(&stats, Some(1));
+
+        assert!(output.contains("## Most Connected\n\n- adr_0001: ADR 1 (1 in, 0 out)\n"));
+        assert!(!output.contains("- adr_0002:"));
     }
 
     #[test]
@@ -472,7 +843,7 @@ mod tests {
 
         let stats = AdrStatistics::from_adrs(&[adr]);
         let renderer = WikiRenderer::new();
-        let output = renderer.render_statistics(&stats);
+        let output = renderer.render_statistics(&stats, None);
 
         assert!(output.contains("## By Author"));
         assert!(output.contains("Test Author"));
@@ -487,7 +858,7 @@ mod tests {
 
         let renderer = WikiRenderer::new();
         let files = renderer
-            .render_all(&adrs, Some("https://example.com"))
+            .render_all(&adrs, Some("https://example.com"), Some(10))
             .expect("should render all");
 
         assert_eq!(files.len(), 5);
@@ -498,6 +869,80 @@ mod tests {
         assert!(filenames.contains(&"ADR-By-Category.md"));
         assert!(filenames.contains(&"ADR-Timeline.md"));
         assert!(filenames.contains(&"ADR-Statistics.md"));
+        assert!(!filenames.contains(&"ADR-By-Team.md"));
+    }
+
+    #[test]
+    fn test_render_by_team() {
+        let adrs = vec![
+            create_test_adr("adr_0001", "ADR 1", Status::Accepted, "arch")
+                .with_team(Some("platform".to_string())),
+            create_test_adr("adr_0002", "ADR 2", Status::Proposed, "api"),
+            create_test_adr("adr_0003", "ADR 3", Status::Accepted, "api")
+                .with_team(Some("payments".to_string())),
+        ];
+
+        let renderer = WikiRenderer::new();
+        let output = renderer.render_by_team(&adrs);
+
+        let payments = output.find("## payments").expect("payments section");
+        let platform = output.find("## platform").expect("platform section");
+        let unowned = output.find("## Unowned").expect("unowned section");
+        assert!(payments < platform && platform < unowned);
+        assert!(output.contains("- [ADR 2](adr_0002.md)"));
+
+        let stats = renderer.render_statistics(&AdrStatistics::from_adrs(&adrs), None);
+        assert!(stats.contains("## By Team\n\n- payments: 1\n- platform: 1\n"));
+
+        let files = renderer
+            .render_all(&adrs, None, None)
+            .expect("should render all");
+        assert_eq!(files.len(), 6);
+        assert_eq!(files[3].0, "ADR-By-Team.md");
+    }
+
+    #[test]
+    fn test_render_by_level() {
+        let rated = |id: &str, title: &str, impact: Level| {
+            let adr = create_test_adr(id, title, Status::Accepted, "arch");
+            let frontmatter = adr.frontmatter().clone().with_impact(impact);
+            Adr::new(
+                adr.id().clone(),
+                adr.filename().to_string(),
+                adr.source_path().clone(),
+                frontmatter,
+                String::new(),
+                String::new(),
+                String::new(),
+            )
+        };
+        let adrs = vec![
+            rated("adr_0001", "ADR 1", Level::Low),
+            rated("adr_0002", "ADR 2", Level::Critical),
+            create_test_adr("adr_0003", "ADR 3", Status::Proposed, "api"),
+        ];
+
+        let renderer = WikiRenderer::new();
+        let output = renderer.render_by_impact(&adrs);
+
+        let critical = output
+            .find("## \u{1F534} critical")
+            .expect("critical section");
+        let low = output.find("## \u{1F7E2} low").expect("low section");
+        let unrated = output.find("## Unrated").expect("unrated section");
+        assert!(critical < low && low < unrated);
+        assert!(!output.contains("medium"));
+
+        let stats = renderer.render_statistics(&AdrStatistics::from_adrs(&adrs), None);
+        assert!(stats.contains("## By Impact\n\n- \u{1F534} critical: 1\n- \u{1F7E2} low: 1\n"));
+        assert!(!stats.contains("## By Risk"));
+
+        let files = renderer
+            .render_all(&adrs, None, None)
+            .expect("should render all");
+        let filenames: Vec<&str> = files.iter().map(|(name, _)| name.as_str()).collect();
+        assert!(filenames.contains(&"ADR-By-Impact.md"));
+        assert!(!filenames.contains(&"ADR-By-Risk.md"));
     }
 
     #[test]
@@ -0,0 +1,243 @@
+//! SQLite export.
+//!
+//! Writes an ADR collection to a SQLite database of normalized tables, so
+//! teams can query their decision history with SQL. Requires the `sqlite`
+//! feature.
+//!
+//! | Table | Rows |
+//! |-------|------|
+//! | `adrs` | One per ADR, with its frontmatter fields and markdown body |
+//! | `tags` | One per ADR and tag |
+//! | `technologies` | One per ADR and technology |
+//! | `relations` | One per edge of the decision graph |
+
+use std::path::Path;
+
+use crate::domain::Adr;
+use crate::error::{Error, Result};
+
+/// Statements creating the database tables.
+#[cfg(feature = "sqlite")]
+const SCHEMA: &str = "
+CREATE TABLE adrs (
+    id TEXT PRIMARY KEY,
+    title TEXT NOT NULL,
+    status TEXT NOT NULL,
+    category TEXT,
+    description TEXT,
+    author TEXT,
+    project TEXT,
+    package TEXT,
+    team TEXT,
+    impact TEXT,
+    risk TEXT,
+    created TEXT,
+    updated TEXT,
+    review_by TEXT,
+    path TEXT NOT NULL,
+    body TEXT NOT NULL
+);
+CREATE TABLE tags (
+    adr_id TEXT NOT NULL REFERENCES adrs (id),
+    tag TEXT NOT NULL,
+    PRIMARY KEY (adr_id, tag)
+);
+CREATE TABLE technologies (
+    adr_id TEXT NOT NULL REFERENCES adrs (id),
+    technology TEXT NOT NULL,
+    PRIMARY KEY (adr_id, technology)
+);
+CREATE TABLE relations (
+    source TEXT NOT NULL REFERENCES adrs (id),
+    target TEXT NOT NULL,
+    type TEXT NOT NULL
+);
+CREATE INDEX tags_tag ON tags (tag);
+CREATE INDEX technologies_technology ON technologies (technology);
+CREATE INDEX relations_target ON relations (target);
+";
+
+/// Writes ADRs to a new SQLite database, replacing any file at `path`.
+///
+/// Dates are stored as ISO 8601 text (`2025-01-15`), which SQLite compares
+/// and formats as dates; fields an ADR leaves empty are `NULL`. Relations
+/// are the edges of [`Graph::from_adrs`](crate::domain::Graph::from_adrs),
+/// so their targets may name ADRs missing from the collection.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be replaced or the database cannot
+/// be written.
+#[cfg(feature = "sqlite")]
+pub fn write_database(path: &Path, adrs: &[Adr]) -> Result<()> {
+    use rusqlite::{Connection, params};
+
+    use crate::domain::Graph;
+
+    let error = |e: rusqlite::Error| Error::Sqlite {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    };
+    let text = |value: &str| {
+        Some(value)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+
+    if path.exists() {
+        std::fs::remove_file(path).map_err(|source| Error::FileWrite {
+            path: path.to_path_buf(),
+            source,
+        })?;
+    }
+    let mut connection = Connection::open(path).map_err(error)?;
+    let transaction = connection.transaction().map_err(error)?;
+    transaction.execute_batch(SCHEMA).map_err(error)?;
+    {
+        let mut insert_adr = transaction
+            .prepare(
+                "INSERT INTO adrs (id, title, status, category, description, author, project, \
+                 package, team, impact, risk, created, updated, review_by, path, body) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
+            )
+            .map_err(error)?;
+        let mut insert_tag = transaction
+            .prepare("INSERT OR IGNORE INTO tags (adr_id, tag) VALUES (?1, ?2)")
+            .map_err(error)?;
+        let mut insert_technology = transaction
+            .prepare("INSERT OR IGNORE INTO technologies (adr_id, technology) VALUES (?1, ?2)")
+            .map_err(error)?;
+        let mut insert_relation = transaction
+            .prepare("INSERT INTO relations (source, target, type) VALUES (?1, ?2, ?3)")
+            .map_err(error)?;
+
+        for adr in adrs {
+            let id = adr.id().as_str();
+            insert_adr
+                .execute(params![
+                    id,
+                    adr.title(),
+                    adr.status_name(),
+                    text(adr.category()),
+                    text(adr.description()),
+                    text(adr.author()),
+                    text(adr.project()),
+                    adr.package(),
+                    adr.team(),
+                    adr.impact().map(|level| level.as_str()),
+                    adr.risk().map(|level| level.as_str()),
+                    adr.created().map(|date| date.to_string()),
+                    adr.updated().map(|date| date.to_string()),
+                    adr.review_by().map(|date| date.to_string()),
+                    adr.source_path().display().to_string(),
+                    adr.body_markdown(),
+                ])
+                .map_err(error)?;
+            for tag in adr.tags() {
+                insert_tag.execute(params![id, tag]).map_err(error)?;
+            }
+            for technology in adr.technologies() {
+                insert_technology
+                    .execute(params![id, technology])
+                    .map_err(error)?;
+            }
+        }
+        for edge in Graph::from_adrs(adrs).edges {
+            insert_relation
+                .execute(params![edge.source, edge.target, edge.edge_type.as_str()])
+                .map_err(error)?;
+        }
+    }
+    transaction.commit().map_err(error)
+}
+
+/// Writes ADRs to a new SQLite database, replacing any file at `path`.
+///
+/// # Errors
+///
+/// Always fails, as this build has no SQLite support.
+#[cfg(not(feature = "sqlite"))]
+pub fn write_database(path: &Path, _adrs: &[Adr]) -> Result<()> {
+    Err(Error::Sqlite {
+        path: path.to_path_buf(),
+        message: "adrscope was built without the 'sqlite' feature".to_string(),
+    })
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use super::*;
+    use crate::domain::{AdrId, Frontmatter, Status};
+    use rusqlite::Connection;
+    use std::path::PathBuf;
+    use time::macros::date;
+
+    fn adr(id: &str, frontmatter: Frontmatter) -> Adr {
+        Adr::new(
+            AdrId::new(id),
+            format!("{id}.md"),
+            PathBuf::from(format!("docs/decisions/{id}.md")),
+            frontmatter,
+            format!("# {id}\n"),
+            String::new(),
+            String::new(),
+        )
+    }
+
+    #[test]
+    fn test_write_database() {
+        let adrs = vec![
+            adr(
+                "adr-0001",
+                Frontmatter::new("Use PostgreSQL")
+                    .with_status(Status::Accepted)
+                    .with_created(date!(2022 - 03 - 01))
+                    .with_technologies(vec!["postgres".to_string()])
+                    .with_tags(vec!["database".to_string(), "database".to_string()]),
+            ),
+            adr(
+                "adr-0002",
+                Frontmatter::new("Use Redis")
+                    .with_created(date!(2024 - 05 - 01))
+                    .with_technologies(vec!["redis".to_string(), "postgres".to_string()])
+                    .with_related(vec!["adr-0001.md".to_string(), "adr-0009.md".to_string()]),
+            ),
+        ];
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("adrs.db");
+        std::fs::write(&path, "stale").unwrap();
+
+        write_database(&path, &adrs).unwrap();
+
+        let connection = Connection::open(&path).unwrap();
+        let stale: Vec<String> = connection
+            .prepare(
+                "SELECT a.id FROM adrs a JOIN technologies t ON t.adr_id = a.id \
+                 WHERE a.status = 'accepted' AND t.technology = 'postgres' \
+                 AND COALESCE(a.updated, a.created) < '2023-01-01'",
+            )
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap();
+        assert_eq!(stale, ["adr-0001"]);
+
+        let (category, body): (Option<String>, String) = connection
+            .query_row(
+                "SELECT category, body FROM adrs WHERE id = 'adr-0001'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!((category, body.as_str()), (None, "# adr-0001\n"));
+
+        let count = |sql: &str| -> i64 { connection.query_row(sql, [], |row| row.get(0)).unwrap() };
+        assert_eq!(count("SELECT COUNT(*) FROM tags"), 1);
+        assert_eq!(count("SELECT COUNT(*) FROM relations"), 2);
+        assert_eq!(
+            count("SELECT COUNT(*) FROM relations WHERE type = 'related' AND target = 'adr-0009'"),
+            1
+        );
+    }
+}
@@ -0,0 +1,41 @@
+//! Webhook notifications.
+//!
+//! Posts a JSON payload to a URL after the viewer is generated, so chat
+//! channels (Slack, Teams, ...) learn when the decision log changes. Requires
+//! the `webhook` feature.
+
+use serde_json::Value;
+
+use crate::error::{Error, Result};
+
+/// Posts a JSON payload to a webhook URL.
+///
+/// # Errors
+///
+/// Returns an error if the request fails or the endpoint does not answer
+/// with a success status.
+#[cfg(feature = "webhook")]
+pub fn post_json(url: &str, payload: &Value) -> Result<()> {
+    let body = serde_json::to_string(payload).map_err(|e| Error::JsonSerialize(e.to_string()))?;
+    ureq::post(url)
+        .header("Content-Type", "application/json")
+        .send(&body)
+        .map(|_| ())
+        .map_err(|error| Error::Webhook {
+            url: url.to_string(),
+            message: error.to_string(),
+        })
+}
+
+/// Posts a JSON payload to a webhook URL.
+///
+/// # Errors
+///
+/// Always fails, as this build has no HTTP client.
+#[cfg(not(feature = "webhook"))]
+pub fn post_json(url: &str, _payload: &Value) -> Result<()> {
+    Err(Error::Webhook {
+        url: url.to_string(),
+        message: "adrscope was built without the 'webhook' feature".to_string(),
+    })
+}
@@ -48,8 +48,9 @@
 
 pub mod application;
 pub mod cli;
+pub mod codes;
 pub mod domain;
 pub mod error;
 pub mod infrastructure;
 
-pub use error::{Error, Result};
+pub use error::{Error, Result, SourceLocation};
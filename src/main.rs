@@ -1,16 +1,21 @@
 //! ADRScope CLI binary entry point.
 
-use clap::Parser;
+use clap::{CommandFactory, Parser};
+use clap_complete::CompleteEnv;
 
 use adrscope::cli::{Cli, run};
 
 fn main() {
+    // Answers shell completion requests (COMPLETE=<shell>) and exits
+    CompleteEnv::with_factory(Cli::command).complete();
+
     let cli = Cli::parse();
 
     match run(cli) {
         Ok(code) => std::process::exit(code),
         Err(e) => {
-            eprintln!("Error: {e}");
+            eprintln!("Error [{}]: {e}", e.code());
+            eprintln!("Run `adrscope explain {}` for details.", e.code());
             std::process::exit(1);
         },
     }
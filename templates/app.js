@@ -17,6 +17,7 @@
         records: ADRSCOPE_DATA.records || [],
         facets: ADRSCOPE_DATA.facets || {},
         graph: ADRSCOPE_DATA.graph || { nodes: [], edges: [] },
+        customStatuses: (ADRSCOPE_DATA.meta && ADRSCOPE_DATA.meta.statuses) || [],
         filteredRecords: [],
         selectedId: null,
         selectedIndex: -1,
@@ -26,9 +27,15 @@
             statuses: [],
             category: '',
             author: '',
+            decider: '',
+            team: '',
             project: '',
+            custom: {},
             tags: [],
             technologies: [],
+            impacts: [],
+            risks: [],
+            dueForReview: false,
             dateFrom: '',
             dateTo: ''
         },
@@ -46,9 +53,20 @@
         statusFilters: document.getElementById('status-filters'),
         categoryFilter: document.getElementById('category-filter'),
         authorFilter: document.getElementById('author-filter'),
+        deciderSection: document.getElementById('decider-section'),
+        deciderFilter: document.getElementById('decider-filter'),
+        teamSection: document.getElementById('team-section'),
+        teamFilter: document.getElementById('team-filter'),
         projectFilter: document.getElementById('project-filter'),
+        customFacets: document.getElementById('custom-facets'),
         tagFilters: document.getElementById('tag-filters'),
         techFilters: document.getElementById('tech-filters'),
+        impactSection: document.getElementById('impact-section'),
+        impactFilters: document.getElementById('impact-filters'),
+        riskSection: document.getElementById('risk-section'),
+        riskFilters: document.getElementById('risk-filters'),
+        reviewSection: document.getElementById('review-section'),
+        reviewFilters: document.getElementById('review-filters'),
         dateFrom: document.getElementById('date-from'),
         dateTo: document.getElementById('date-to'),
         clearFilters: document.getElementById('clear-filters'),
@@ -80,11 +98,117 @@
     // Initialization
     // =========================================================================
     function init() {
+        initStatusStyles();
         initFilters();
         initEventListeners();
         initTheme();
         applyFilters();
         updateFooterStats();
+        openFromHash();
+        window.addEventListener('hashchange', openFromHash);
+        // Mermaid may load after the first ADR is shown
+        window.addEventListener('load', function() {
+            renderDiagrams(elements.detailContent);
+        });
+    }
+
+    // Built-in status colors; custom statuses fall back to their base status
+    var statusColors = {
+        proposed: '#f59e0b',
+        accepted: '#10b981',
+        deprecated: '#ef4444',
+        superseded: '#6b7280'
+    };
+
+    function findCustomStatus(name) {
+        for (var i = 0; i < state.customStatuses.length; i++) {
+            if (state.customStatuses[i].name === name) return state.customStatuses[i];
+        }
+        return null;
+    }
+
+    // The built-in status a custom status counts as for lifecycle rules
+    function baseStatus(name) {
+        var custom = findCustomStatus(name);
+        return custom ? custom.base : name;
+    }
+
+    // Colors custom statuses, whose names are restricted to [a-z0-9_-]
+    function initStatusStyles() {
+        if (state.customStatuses.length === 0) return;
+        var rules = state.customStatuses.map(function(s) {
+            var color = s.color || statusColors[s.base] || '#6b7280';
+            statusColors[s.name] = color;
+            var cls = '.status-' + s.name;
+            return '.status-chip' + cls + ', .status-badge' + cls + ' { color: ' + color +
+                '; background: color-mix(in srgb, ' + color + ' 15%, transparent); }\n' +
+                '.timeline-item' + cls + '::before { border-color: ' + color + '; }';
+        });
+        var style = document.createElement('style');
+        style.textContent = rules.join('\n');
+        document.head.appendChild(style);
+    }
+
+    // Deep links: #adr=<id> opens that ADR (used by `adrscope open <id>`),
+    // and #adr=<id>/<heading> scrolls to one of its sections
+    function openFromHash() {
+        var match = /^#adr=([^\/]+)(?:\/(.+))?$/.exec(window.location.hash);
+        if (!match) return;
+        var id, section;
+        try {
+            id = decodeURIComponent(match[1]);
+            section = match[2] ? decodeURIComponent(match[2]) : null;
+        } catch (e) {
+            return;
+        }
+        // IDs written with an unencoded slash, e.g. #adr=payments/adr-0003
+        var fullId = section ? id + '/' + section : id;
+        if (section && state.records.some(function(r) { return r.id === fullId; })) {
+            id = fullId;
+            section = null;
+        }
+        id = resolveReference(id);
+        if (id !== state.selectedId) {
+            selectAdr(id);
+        }
+        if (section && id === state.selectedId) {
+            showSection(section);
+        }
+    }
+
+    // Wikilinks name ADRs as written; like the graph, a bare name is looked
+    // up in the selected ADR's package when no ADR has exactly that ID
+    function resolveReference(id) {
+        var exists = function(candidate) {
+            return state.records.some(function(r) { return r.id === candidate; });
+        };
+        if (exists(id)) return id;
+        var current = state.records.find(function(r) { return r.id === state.selectedId; });
+        if (current && current.package && exists(current.package + '/' + id)) {
+            return current.package + '/' + id;
+        }
+        return id;
+    }
+
+    function updateHash(id, section) {
+        var hash = id ? '#adr=' + encodeURIComponent(id) : '';
+        if (hash && section) {
+            hash += '/' + encodeURIComponent(section);
+        }
+        if (window.location.hash !== hash) {
+            history.replaceState(null, '', window.location.pathname + window.location.search + hash);
+        }
+    }
+
+    // Scrolls the open ADR to the element with the given id and links to it
+    function showSection(section) {
+        var target = Array.prototype.find.call(
+            elements.detailContent.querySelectorAll('.detail-body [id]'),
+            function(el) { return el.id === section; }
+        );
+        if (!target) return;
+        target.scrollIntoView({ block: 'start' });
+        updateHash(state.selectedId, section);
     }
 
     function initFilters() {
@@ -101,7 +225,8 @@
 
             const labelSpan = document.createElement('span');
             labelSpan.className = 'label';
-            labelSpan.textContent = s.value;
+            const custom = findCustomStatus(s.value);
+            labelSpan.textContent = custom && custom.emoji ? custom.emoji + ' ' + s.value : s.value;
 
             const countSpan = document.createElement('span');
             countSpan.className = 'count';
@@ -141,6 +266,38 @@
             elements.authorFilter.appendChild(opt);
         });
 
+        // Decider filter, shown only when ADRs name their deciders
+        var deciders = state.facets.deciders || [];
+        elements.deciderSection.classList.toggle('hidden', deciders.length === 0);
+        elements.deciderFilter.textContent = '';
+        const defaultDecider = document.createElement('option');
+        defaultDecider.value = '';
+        defaultDecider.textContent = 'All deciders';
+        elements.deciderFilter.appendChild(defaultDecider);
+
+        deciders.forEach(d => {
+            const opt = document.createElement('option');
+            opt.value = d.value;
+            opt.textContent = d.value + ' (' + d.count + ')';
+            elements.deciderFilter.appendChild(opt);
+        });
+
+        // Team filter, shown only when teams are mapped
+        var teams = state.facets.teams || [];
+        elements.teamSection.classList.toggle('hidden', teams.length === 0);
+        elements.teamFilter.textContent = '';
+        const defaultTeam = document.createElement('option');
+        defaultTeam.value = '';
+        defaultTeam.textContent = 'All teams';
+        elements.teamFilter.appendChild(defaultTeam);
+
+        teams.forEach(t => {
+            const opt = document.createElement('option');
+            opt.value = t.value;
+            opt.textContent = t.value + ' (' + t.count + ')';
+            elements.teamFilter.appendChild(opt);
+        });
+
         // Project filter
         elements.projectFilter.textContent = '';
         const defaultProj = document.createElement('option');
@@ -155,6 +312,36 @@
             elements.projectFilter.appendChild(opt);
         });
 
+        // Custom field filters, one per configured facet
+        elements.customFacets.textContent = '';
+        var customFacets = state.facets.custom || {};
+        Object.keys(customFacets).forEach(function(field) {
+            var section = document.createElement('div');
+            section.className = 'filter-section';
+            var heading = document.createElement('h3');
+            heading.textContent = field;
+            section.appendChild(heading);
+
+            var select = document.createElement('select');
+            select.className = 'filter-select';
+            var defaultOpt = document.createElement('option');
+            defaultOpt.value = '';
+            defaultOpt.textContent = 'All';
+            select.appendChild(defaultOpt);
+            customFacets[field].forEach(function(v) {
+                var opt = document.createElement('option');
+                opt.value = v.value;
+                opt.textContent = v.value + ' (' + v.count + ')';
+                select.appendChild(opt);
+            });
+            select.addEventListener('change', function() {
+                state.filters.custom[field] = select.value;
+                applyFilters();
+            });
+            section.appendChild(select);
+            elements.customFacets.appendChild(section);
+        });
+
         // Tag filters
         elements.tagFilters.textContent = '';
         state.facets.tags.slice(0, 20).forEach(t => {
@@ -174,6 +361,68 @@
             label.textContent = t.value + ' (' + t.count + ')';
             elements.techFilters.appendChild(label);
         });
+
+        // Impact and risk filters, shown only when rated
+        initLevelFilters(elements.impactSection, elements.impactFilters, state.facets.impacts || []);
+        initLevelFilters(elements.riskSection, elements.riskFilters, state.facets.risks || []);
+
+        // Review filter, evaluated against today's date when viewing
+        elements.reviewFilters.textContent = '';
+        var hasReviewDates = state.records.some(function(r) { return r.frontmatter['review-by']; });
+        elements.reviewSection.classList.toggle('hidden', !hasReviewDates);
+        if (hasReviewDates) {
+            var dueCount = state.records.filter(isReviewDue).length;
+            var reviewChip = document.createElement('label');
+            reviewChip.className = 'tag-chip';
+            reviewChip.textContent = 'Due for review (' + dueCount + ')';
+            elements.reviewFilters.appendChild(reviewChip);
+        }
+    }
+
+    // Level chips colored by level, lowest first as listed in the facets
+    function initLevelFilters(section, container, levels) {
+        container.textContent = '';
+        section.classList.toggle('hidden', levels.length === 0);
+        levels.forEach(function(l) {
+            var chip = document.createElement('label');
+            chip.className = 'status-chip level-chip level-' + l.value;
+            chip.dataset.value = l.value;
+
+            var labelSpan = document.createElement('span');
+            labelSpan.className = 'label';
+            labelSpan.textContent = l.value;
+
+            var countSpan = document.createElement('span');
+            countSpan.className = 'count';
+            countSpan.textContent = l.count;
+
+            chip.appendChild(labelSpan);
+            chip.appendChild(countSpan);
+            container.appendChild(chip);
+        });
+    }
+
+    function toggleLevelFilter(e, key) {
+        var chip = e.target.closest('.level-chip');
+        if (!chip) return;
+        chip.classList.toggle('active');
+        var selected = state.filters[key];
+        var idx = selected.indexOf(chip.dataset.value);
+        if (idx > -1) {
+            selected.splice(idx, 1);
+        } else {
+            selected.push(chip.dataset.value);
+        }
+        applyFilters();
+    }
+
+    // Proposed and accepted decisions whose review-by date is today or earlier
+    function isReviewDue(adr) {
+        var fm = adr.frontmatter;
+        var reviewBy = fm['review-by'];
+        var status = baseStatus(fm.status);
+        if (!reviewBy || (status !== 'proposed' && status !== 'accepted')) return false;
+        return reviewBy <= new Date().toISOString().slice(0, 10);
     }
 
     function initEventListeners() {
@@ -182,7 +431,7 @@
         elements.search.addEventListener('input', function() {
             clearTimeout(searchTimeout);
             searchTimeout = setTimeout(function() {
-                state.filters.search = elements.search.value.trim().toLowerCase();
+                state.filters.search = elements.search.value.trim();
                 applyFilters();
             }, 300);
         });
@@ -214,6 +463,16 @@
             applyFilters();
         });
 
+        elements.deciderFilter.addEventListener('change', function() {
+            state.filters.decider = elements.deciderFilter.value;
+            applyFilters();
+        });
+
+        elements.teamFilter.addEventListener('change', function() {
+            state.filters.team = elements.teamFilter.value;
+            applyFilters();
+        });
+
         elements.projectFilter.addEventListener('change', function() {
             state.filters.project = elements.projectFilter.value;
             applyFilters();
@@ -251,6 +510,24 @@
             }
         });
 
+        // Impact and risk filters
+        elements.impactFilters.addEventListener('click', function(e) {
+            toggleLevelFilter(e, 'impacts');
+        });
+        elements.riskFilters.addEventListener('click', function(e) {
+            toggleLevelFilter(e, 'risks');
+        });
+
+        // Review filter
+        elements.reviewFilters.addEventListener('click', function(e) {
+            const chip = e.target.closest('.tag-chip');
+            if (chip) {
+                chip.classList.toggle('active');
+                state.filters.dueForReview = chip.classList.contains('active');
+                applyFilters();
+            }
+        });
+
         // Date filters
         elements.dateFrom.addEventListener('change', function() {
             state.filters.dateFrom = elements.dateFrom.value;
@@ -346,6 +623,234 @@
 
         html.dataset.theme = next;
         localStorage.setItem('adrscope-theme', next);
+
+        // Redraw diagrams in the new theme's colors
+        elements.detailContent.querySelectorAll('pre.mermaid[data-source]').forEach(function(el) {
+            el.textContent = el.dataset.source;
+            el.removeAttribute('data-processed');
+        });
+        renderDiagrams(elements.detailContent);
+    }
+
+    function isDarkTheme() {
+        var theme = document.documentElement.dataset.theme;
+        return theme === 'dark' ||
+            (theme === 'auto' && window.matchMedia('(prefers-color-scheme: dark)').matches);
+    }
+
+    // Adds a link to each heading, and keeps in-page links such as
+    // footnotes within the ADR instead of replacing the viewer's hash
+    // Links to other sites open in a new tab, keeping the viewer open
+    function addExternalLinkTargets(bodyDiv) {
+        bodyDiv.querySelectorAll('a[href^="http://"], a[href^="https://"]').forEach(function(link) {
+            link.target = '_blank';
+            link.rel = 'noopener noreferrer';
+        });
+    }
+
+    function addSectionLinks(bodyDiv, id) {
+        bodyDiv.querySelectorAll('h1[id], h2[id], h3[id], h4[id], h5[id], h6[id]').forEach(function(heading) {
+            var link = document.createElement('a');
+            link.className = 'section-link';
+            link.href = '#adr=' + encodeURIComponent(id) + '/' + encodeURIComponent(heading.id);
+            link.setAttribute('aria-label', 'Link to this section');
+            link.textContent = '#';
+            heading.appendChild(link);
+        });
+
+        bodyDiv.addEventListener('click', function(e) {
+            var link = e.target.closest('a[href^="#"]');
+            if (!link) return;
+            var href = link.getAttribute('href');
+            var section;
+            if (link.classList.contains('section-link')) {
+                section = link.parentNode.id;
+            } else if (href.indexOf('#adr=') !== 0) {
+                try {
+                    section = decodeURIComponent(href.slice(1));
+                } catch (err) {
+                    return;
+                }
+            } else {
+                // Links to other ADRs open them through the hash
+                return;
+            }
+            e.preventDefault();
+            showSection(section);
+        });
+    }
+
+    // =========================================================================
+    // Diagrams
+    // =========================================================================
+    // Draws the Mermaid diagrams in an element, once Mermaid has loaded
+    function renderDiagrams(container) {
+        var nodes = container.querySelectorAll('pre.mermaid:not([data-processed])');
+        if (nodes.length === 0 || typeof window.mermaid === 'undefined') return;
+
+        nodes.forEach(function(el) {
+            if (!el.dataset.source) el.dataset.source = el.textContent;
+        });
+        window.mermaid.initialize({
+            startOnLoad: false,
+            securityLevel: 'strict',
+            theme: isDarkTheme() ? 'dark' : 'default'
+        });
+        window.mermaid.run({ nodes: Array.prototype.slice.call(nodes) }).catch(function() {
+            // Invalid diagrams are left as their source
+        });
+    }
+
+    // =========================================================================
+    // Search
+    // =========================================================================
+    var searchIndex = ADRSCOPE_DATA.search || null;
+    // Sorted once, to find the tokens a query term is a prefix of
+    var searchTokens = searchIndex ? Object.keys(searchIndex.tokens).sort() : [];
+
+    // Splits text into lowercase runs of letters and digits, as generate does
+    function tokenize(text) {
+        return text.split(/[^\p{Alphabetic}\p{N}]+/u).filter(Boolean).map(function(token) {
+            return token.toLowerCase();
+        });
+    }
+
+    // Returns the scores of the ADRs having, for every token of the text, a
+    // token it is a prefix of, by ID
+    function textScores(text) {
+        var scores = null;
+        tokenize(text).forEach(function(term) {
+            var lo = 0;
+            var hi = searchTokens.length;
+            while (lo < hi) {
+                var mid = (lo + hi) >> 1;
+                if (searchTokens[mid] < term) lo = mid + 1;
+                else hi = mid;
+            }
+
+            var matches = Object.create(null);
+            for (var i = lo; i < searchTokens.length && searchTokens[i].lastIndexOf(term, 0) === 0; i++) {
+                var postings = searchIndex.tokens[searchTokens[i]];
+                Object.keys(postings).forEach(function(id) {
+                    matches[id] = (matches[id] || 0) + postings[id];
+                });
+            }
+
+            if (scores === null) {
+                scores = matches;
+                return;
+            }
+            var both = Object.create(null);
+            Object.keys(scores).forEach(function(id) {
+                if (id in matches) both[id] = scores[id] + matches[id];
+            });
+            scores = both;
+        });
+        return scores || Object.create(null);
+    }
+
+    // Values of the fields query words can be scoped to, as in the CLI
+    // (src/domain/query.rs)
+    var queryFields = Object.assign(Object.create(null), {
+        tag: function(adr) { return adr.frontmatter.tags || []; },
+        technology: function(adr) { return adr.frontmatter.technologies || []; },
+        status: function(adr) { return [adr.frontmatter.status]; },
+        category: function(adr) { return [adr.frontmatter.category || '']; },
+        author: function(adr) { return [adr.frontmatter.author || '']; },
+        project: function(adr) { return [adr.frontmatter.project || '']; },
+        team: function(adr) { return adr.team ? [adr.team] : []; }
+    });
+    queryFields.tags = queryFields.tag;
+    queryFields.tech = queryFields.technologies = queryFields.technology;
+
+    // Splits a query into words at whitespace outside double quotes
+    function queryWords(input) {
+        var words = [];
+        var word = '';
+        var quoted = false;
+        for (var i = 0; i < input.length; i++) {
+            var c = input[i];
+            if (c === '"') quoted = !quoted;
+            if (/\s/.test(c) && !quoted) {
+                if (word) words.push(word);
+                word = '';
+            } else {
+                word += c;
+            }
+        }
+        if (word) words.push(word);
+        return words;
+    }
+
+    // Parses a query like `tag:database status:accepted postgres -redis`
+    // into alternatives separated by OR, each a list of clauses that must
+    // all hold
+    function parseQuery(input) {
+        var alternatives = [[]];
+        queryWords(input).forEach(function(word) {
+            if (word === 'OR') {
+                alternatives.push([]);
+                return;
+            }
+            var negated = word.length > 1 && word[0] === '-';
+            if (negated) word = word.slice(1);
+
+            var colon = word.indexOf(':');
+            if (colon !== -1) {
+                var name = word.slice(0, colon).toLowerCase();
+                var value = word.slice(colon + 1).replace(/"/g, '');
+                if (name in queryFields && value) {
+                    alternatives[alternatives.length - 1].push({ negated: negated, field: name, value: value });
+                    return;
+                }
+            }
+            // Words without letters or digits match nothing
+            var text = word.replace(/"/g, '');
+            if (tokenize(text).length) {
+                alternatives[alternatives.length - 1].push({ negated: negated, text: text });
+            }
+        });
+        return alternatives.filter(function(clauses) { return clauses.length > 0; });
+    }
+
+    // Returns the scores of the ADRs matching any alternative of a parsed
+    // query, by ID; each scores by its text matches in its best alternative
+    function queryScores(alternatives) {
+        var texts = Object.create(null);
+        alternatives.forEach(function(clauses) {
+            clauses.forEach(function(clause) {
+                if (clause.text !== undefined && !(clause.text in texts)) {
+                    texts[clause.text] = textScores(clause.text);
+                }
+            });
+        });
+
+        var scores = Object.create(null);
+        state.records.forEach(function(adr) {
+            var best = -1;
+            alternatives.forEach(function(clauses) {
+                var total = 0;
+                var matched = clauses.every(function(clause) {
+                    var score = null;
+                    if (clause.field) {
+                        var value = clause.value.toLowerCase();
+                        var hit = queryFields[clause.field](adr).some(function(v) {
+                            return String(v).toLowerCase() === value;
+                        });
+                        if (hit) score = 0;
+                    } else if (adr.id in texts[clause.text]) {
+                        score = texts[clause.text][adr.id];
+                    }
+                    if (clause.negated) return score === null;
+                    if (score === null) return false;
+                    total += score;
+                    return true;
+                });
+                if (matched && total > best) best = total;
+            });
+            if (best >= 0) scores[adr.id] = best;
+        });
+        return scores;
     }
 
     // =========================================================================
@@ -353,19 +858,14 @@
     // =========================================================================
     function applyFilters() {
         var filtered = state.records.slice();
+        var scores = null;
 
         // Search filter
-        if (state.filters.search) {
-            var query = state.filters.search;
+        var query = parseQuery(state.filters.search);
+        if (query.length > 0) {
+            scores = queryScores(query);
             filtered = filtered.filter(function(adr) {
-                var searchable = [
-                    adr.frontmatter.title,
-                    adr.frontmatter.description,
-                    (adr.frontmatter.tags || []).join(' '),
-                    (adr.frontmatter.technologies || []).join(' '),
-                    adr.body_text || ''
-                ].join(' ').toLowerCase();
-                return searchable.indexOf(query) !== -1;
+                return adr.id in scores;
             });
         }
 
@@ -390,6 +890,20 @@
             });
         }
 
+        // Decider filter
+        if (state.filters.decider) {
+            filtered = filtered.filter(function(adr) {
+                return (adr.frontmatter.deciders || []).indexOf(state.filters.decider) !== -1;
+            });
+        }
+
+        // Team filter
+        if (state.filters.team) {
+            filtered = filtered.filter(function(adr) {
+                return adr.team === state.filters.team;
+            });
+        }
+
         // Project filter
         if (state.filters.project) {
             filtered = filtered.filter(function(adr) {
@@ -397,6 +911,16 @@
             });
         }
 
+        // Custom field filters
+        Object.keys(state.filters.custom).forEach(function(field) {
+            var value = state.filters.custom[field];
+            if (!value) return;
+            filtered = filtered.filter(function(adr) {
+                var custom = adr.frontmatter.custom || {};
+                return customValues(custom[field]).indexOf(value) !== -1;
+            });
+        });
+
         // Tag filter
         if (state.filters.tags.length > 0) {
             filtered = filtered.filter(function(adr) {
@@ -415,6 +939,23 @@
             });
         }
 
+        // Impact and risk filters (match any selected level)
+        if (state.filters.impacts.length > 0) {
+            filtered = filtered.filter(function(adr) {
+                return state.filters.impacts.indexOf(adr.frontmatter.impact) !== -1;
+            });
+        }
+        if (state.filters.risks.length > 0) {
+            filtered = filtered.filter(function(adr) {
+                return state.filters.risks.indexOf(adr.frontmatter.risk) !== -1;
+            });
+        }
+
+        // Review filter
+        if (state.filters.dueForReview) {
+            filtered = filtered.filter(isReviewDue);
+        }
+
         // Date range filter
         if (state.filters.dateFrom) {
             filtered = filtered.filter(function(adr) {
@@ -451,6 +992,14 @@
                     aVal = a.frontmatter.created || '';
                     bVal = b.frontmatter.created || '';
                     break;
+                case 'relevance':
+                    if (scores) {
+                        aVal = scores[a.id];
+                        bVal = scores[b.id];
+                        break;
+                    }
+                    // Without a search, the most recently updated come first
+                    // falls through
                 case 'updated':
                 default:
                     aVal = a.frontmatter.updated || a.frontmatter.created || '';
@@ -483,9 +1032,15 @@
             statuses: [],
             category: '',
             author: '',
+            decider: '',
+            team: '',
             project: '',
+            custom: {},
             tags: [],
             technologies: [],
+            impacts: [],
+            risks: [],
+            dueForReview: false,
             dateFrom: '',
             dateTo: ''
         };
@@ -494,7 +1049,11 @@
         elements.search.value = '';
         elements.categoryFilter.value = '';
         elements.authorFilter.value = '';
+        elements.deciderFilter.value = '';
+        elements.teamFilter.value = '';
         elements.projectFilter.value = '';
+        elements.customFacets.querySelectorAll('select')
+            .forEach(function(el) { el.value = ''; });
         elements.dateFrom.value = '';
         elements.dateTo.value = '';
 
@@ -650,6 +1209,14 @@
             catBadge.className = 'category-badge';
             catBadge.textContent = adr.frontmatter.category || 'uncategorized';
             meta.appendChild(catBadge);
+            ['impact', 'risk'].forEach(function(field) {
+                if (adr.frontmatter[field]) {
+                    meta.appendChild(createLevelBadge(field, adr.frontmatter[field]));
+                }
+            });
+            (adr.badges || []).forEach(function(badge) {
+                meta.appendChild(createCustomBadge(badge));
+            });
             card.appendChild(meta);
 
             // Tags
@@ -678,6 +1245,32 @@
         });
     }
 
+    function createLevelBadge(field, level) {
+        var el = document.createElement('span');
+        el.className = 'custom-badge level-badge level-' + level;
+        el.textContent = field + ': ' + level;
+        return el;
+    }
+
+    function createCustomBadge(badge) {
+        var el = document.createElement(badge.url ? 'a' : 'span');
+        el.className = 'custom-badge';
+        el.textContent = badge.label + ': ' + badge.value;
+        if (badge.color) {
+            el.style.backgroundColor = badge.color;
+            el.classList.add('colored');
+        }
+        if (badge.url) {
+            el.href = badge.url;
+            el.target = '_blank';
+            el.rel = 'noopener';
+            el.addEventListener('click', function(e) {
+                e.stopPropagation();
+            });
+        }
+        return el;
+    }
+
     function renderTimelineView() {
         elements.timeline.textContent = '';
 
@@ -754,20 +1347,65 @@
             return;
         }
 
-        // Simple force-directed layout simulation
-        var nodes = state.graph.nodes.map(function(n, i) {
-            return {
-                id: n.id,
-                status: n.status,
-                x: canvas.width / 2 + Math.cos(i * 2 * Math.PI / state.graph.nodes.length) * 200,
-                y: canvas.height / 2 + Math.sin(i * 2 * Math.PI / state.graph.nodes.length) * 200,
-                radius: 20
-            };
+        // Nodes are laid out on a circle per group, the groups themselves
+        // on a circle around the center; ungrouped nodes form one more group
+        var groups = [];
+        var members = {};
+        state.graph.nodes.forEach(function(n) {
+            var group = n.group || '';
+            if (!members[group]) {
+                members[group] = [];
+                groups.push(group);
+            }
+            members[group].push(n);
+        });
+        var ring = groups.length > 1 ? Math.min(canvas.width, canvas.height) / 3 : 0;
+
+        var nodes = [];
+        groups.forEach(function(group, g) {
+            var count = members[group].length;
+            var spread = groups.length > 1 ? Math.max(30, count * 12) : 200;
+            var cx = canvas.width / 2 + Math.cos(g * 2 * Math.PI / groups.length) * ring;
+            var cy = canvas.height / 2 + Math.sin(g * 2 * Math.PI / groups.length) * ring;
+            members[group].forEach(function(n, i) {
+                nodes.push({
+                    id: n.id,
+                    status: n.status,
+                    group: group,
+                    x: cx + (count > 1 ? Math.cos(i * 2 * Math.PI / count) * spread : 0),
+                    y: cy + (count > 1 ? Math.sin(i * 2 * Math.PI / count) * spread : 0),
+                    // Hub decisions are drawn larger, up to twice the size
+                    radius: 20 + Math.min(n.in_degree + n.out_degree, 10) * 2
+                });
+            });
         });
 
         var nodeMap = {};
         nodes.forEach(function(n) { nodeMap[n.id] = n; });
 
+        // Draw a labeled box around each group
+        groups.forEach(function(group) {
+            if (!group) return;
+            var inGroup = nodes.filter(function(n) { return n.group === group; });
+            var pad = 32;
+            var left = Math.min.apply(null, inGroup.map(function(n) { return n.x; })) - pad;
+            var top = Math.min.apply(null, inGroup.map(function(n) { return n.y; })) - pad;
+            var right = Math.max.apply(null, inGroup.map(function(n) { return n.x; })) + pad;
+            var bottom = Math.max.apply(null, inGroup.map(function(n) { return n.y; })) + pad;
+
+            ctx.save();
+            ctx.strokeStyle = getComputedStyle(document.documentElement).getPropertyValue('--color-border');
+            ctx.setLineDash([4, 4]);
+            ctx.strokeRect(left, top, right - left, bottom - top);
+            ctx.restore();
+
+            ctx.fillStyle = getComputedStyle(document.documentElement).getPropertyValue('--color-text-muted');
+            ctx.font = '12px sans-serif';
+            ctx.textAlign = 'left';
+            ctx.textBaseline = 'bottom';
+            ctx.fillText(group, left, top - 4);
+        });
+
         // Draw edges
         ctx.strokeStyle = getComputedStyle(document.documentElement).getPropertyValue('--color-border');
         ctx.lineWidth = 1;
@@ -784,13 +1422,6 @@
         });
 
         // Draw nodes
-        var statusColors = {
-            proposed: '#f59e0b',
-            accepted: '#10b981',
-            deprecated: '#ef4444',
-            superseded: '#6b7280'
-        };
-
         nodes.forEach(function(node) {
             ctx.beginPath();
             ctx.arc(node.x, node.y, node.radius, 0, 2 * Math.PI);
@@ -824,6 +1455,7 @@
 
         // Show panel
         elements.detailPanel.classList.remove('hidden');
+        updateHash(id);
 
         // Update navigation
         elements.prevAdr.disabled = state.selectedIndex <= 0;
@@ -844,6 +1476,25 @@
         titleDiv.appendChild(document.createTextNode(' ' + fm.title));
         elements.detailContent.appendChild(titleDiv);
 
+        // Superseded banner pointing at the decision at the end of the chain
+        var node = state.graph.nodes.find(function(n) { return n.id === adr.id; });
+        if (node && node.current) {
+            var currentId = node.current;
+            var current = state.records.find(function(r) { return r.id === currentId; });
+            var banner = document.createElement('div');
+            banner.className = 'detail-superseded';
+            banner.appendChild(document.createTextNode('Superseded by '));
+            var currentLink = document.createElement('a');
+            currentLink.href = '#adr=' + encodeURIComponent(currentId);
+            currentLink.textContent = current ? currentId + ': ' + current.frontmatter.title : currentId;
+            currentLink.addEventListener('click', function(e) {
+                e.preventDefault();
+                selectAdr(currentId);
+            });
+            banner.appendChild(currentLink);
+            elements.detailContent.appendChild(banner);
+        }
+
         // Meta grid
         var metaDiv = document.createElement('div');
         metaDiv.className = 'detail-meta';
@@ -851,11 +1502,26 @@
         var metaItems = [
             { label: 'Category', value: fm.category || '-' },
             { label: 'Author', value: fm.author || '-' },
+            { label: 'Deciders', value: (fm.deciders || []).join(', ') || '-' },
+            { label: 'Team', value: adr.team || '-' },
             { label: 'Project', value: fm.project || '-' },
+            { label: 'Impact', value: fm.impact || '-' },
+            { label: 'Risk', value: fm.risk || '-' },
             { label: 'Created', value: formatDate(fm.created) },
             { label: 'Updated', value: formatDate(fm.updated) },
+            { label: 'Review By', value: formatDate(fm['review-by']) },
             { label: 'Tags', value: (fm.tags || []).join(', ') || '-' }
         ];
+        if (adr.package) {
+            metaItems.unshift({ label: 'Package', value: adr.package });
+        }
+        var custom = fm.custom || {};
+        Object.keys(custom).forEach(function(field) {
+            var values = customValues(custom[field]);
+            if (values.length > 0) {
+                metaItems.push({ label: field, value: values.join(', ') });
+            }
+        });
 
         metaItems.forEach(function(item) {
             var itemDiv = document.createElement('div');
@@ -876,8 +1542,70 @@
         var bodyDiv = document.createElement('div');
         bodyDiv.className = 'detail-body';
         // body_html is pre-rendered markdown from the server - trusted content
-        bodyDiv.innerHTML = adr.body_html;
+        bodyDiv.innerHTML = adr.body_html || '';
         elements.detailContent.appendChild(bodyDiv);
+        addSectionLinks(bodyDiv, adr.id);
+        addExternalLinkTargets(bodyDiv);
+        renderDiagrams(bodyDiv);
+
+        // Source view (only when raw markdown was embedded at generation time)
+        if (typeof adr.body_markdown === 'string') {
+            elements.detailContent.appendChild(renderSource(adr.body_markdown));
+        }
+
+        // MADR decision drivers and considered options
+        [
+            { title: 'Decision Drivers', items: fm['decision-drivers'] },
+            { title: 'Considered Options', items: fm['considered-options'] }
+        ].forEach(function(section) {
+            if (!section.items || section.items.length === 0) return;
+            var sectionDiv = document.createElement('div');
+            sectionDiv.className = 'detail-related detail-madr';
+            var h3 = document.createElement('h3');
+            h3.textContent = section.title;
+            sectionDiv.appendChild(h3);
+            var list = document.createElement('ul');
+            section.items.forEach(function(item) {
+                var li = document.createElement('li');
+                li.textContent = item;
+                list.appendChild(li);
+            });
+            sectionDiv.appendChild(list);
+            elements.detailContent.appendChild(sectionDiv);
+        });
+
+        // Status history timeline
+        if (fm.history && fm.history.length > 0) {
+            var historyDiv = document.createElement('div');
+            historyDiv.className = 'detail-related detail-history';
+            var historyH3 = document.createElement('h3');
+            historyH3.textContent = 'Status History';
+            historyDiv.appendChild(historyH3);
+
+            var timeline = document.createElement('ol');
+            timeline.className = 'history-timeline';
+            fm.history.forEach(function(entry) {
+                var item = document.createElement('li');
+                var dateSpan = document.createElement('span');
+                dateSpan.className = 'history-date';
+                dateSpan.textContent = formatDate(entry.date);
+                var badge = document.createElement('span');
+                badge.className = 'status-badge status-' + entry.status;
+                badge.textContent = entry.status;
+                item.appendChild(dateSpan);
+                item.appendChild(badge);
+                if (entry.note) {
+                    var note = document.createElement('span');
+                    note.className = 'history-note';
+                    note.textContent = entry.note;
+                    item.appendChild(note);
+                }
+                timeline.appendChild(item);
+            });
+
+            historyDiv.appendChild(timeline);
+            elements.detailContent.appendChild(historyDiv);
+        }
 
         // Related ADRs
         if (fm.related && fm.related.length > 0) {
@@ -890,11 +1618,16 @@
             var relatedList = document.createElement('div');
             relatedList.className = 'related-list';
 
-            fm.related.forEach(function(r) {
+            // Graph edges carry the resolved target of each reference, in order
+            var relatedEdges = state.graph.edges.filter(function(e) {
+                return e.source === adr.id && e.type === 'related';
+            });
+
+            fm.related.forEach(function(r, i) {
                 var link = document.createElement('a');
                 link.href = '#';
                 link.className = 'related-link';
-                var relId = r.replace('.md', '');
+                var relId = relatedEdges[i] ? relatedEdges[i].target : r.replace('.md', '');
                 link.dataset.id = relId;
                 link.textContent = r;
                 link.addEventListener('click', function(e) {
@@ -912,11 +1645,49 @@
         }
     }
 
+    function renderSource(markdown) {
+        var sourceDiv = document.createElement('div');
+        sourceDiv.className = 'detail-source';
+
+        var actions = document.createElement('div');
+        actions.className = 'source-actions';
+
+        var pre = document.createElement('pre');
+        pre.className = 'source-markdown hidden';
+        pre.textContent = markdown;
+
+        var toggleBtn = document.createElement('button');
+        toggleBtn.className = 'source-btn';
+        toggleBtn.textContent = 'View source';
+        toggleBtn.addEventListener('click', function() {
+            var hidden = pre.classList.toggle('hidden');
+            toggleBtn.textContent = hidden ? 'View source' : 'Hide source';
+        });
+
+        var copyBtn = document.createElement('button');
+        copyBtn.className = 'source-btn';
+        copyBtn.textContent = 'Copy markdown';
+        copyBtn.addEventListener('click', function() {
+            if (!navigator.clipboard) return;
+            navigator.clipboard.writeText(markdown).then(function() {
+                copyBtn.textContent = 'Copied';
+                setTimeout(function() { copyBtn.textContent = 'Copy markdown'; }, 1500);
+            });
+        });
+
+        actions.appendChild(toggleBtn);
+        actions.appendChild(copyBtn);
+        sourceDiv.appendChild(actions);
+        sourceDiv.appendChild(pre);
+        return sourceDiv;
+    }
+
     function closeDetail() {
         elements.detailPanel.classList.add('hidden');
         state.selectedId = null;
         state.selectedIndex = -1;
         document.querySelectorAll('[data-id].selected').forEach(function(el) { el.classList.remove('selected'); });
+        updateHash(null);
     }
 
     function navigatePrev() {
@@ -1030,6 +1801,14 @@
     // =========================================================================
     // Utility Functions
     // =========================================================================
+    // Returns a custom field's scalar values as text, as the generator does
+    function customValues(value) {
+        var items = Array.isArray(value) ? value : [value];
+        return items.filter(function(item) {
+            return ['string', 'number', 'boolean'].indexOf(typeof item) !== -1;
+        }).map(String);
+    }
+
     function formatDate(dateStr) {
         if (!dateStr) return '-';
         try {
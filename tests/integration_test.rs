@@ -15,10 +15,13 @@ use adrscope::Error;
 use adrscope::application::{GenerateOptions, GenerateUseCase, ValidateOptions, ValidateUseCase};
 use adrscope::cli::run;
 use adrscope::cli::{
-    Cli, Commands, FormatArg, GenerateArgs, StatsArgs, ThemeArg, ValidateArgs, WikiArgs,
+    Cli, Commands, FilterArgs, FormatArg, GenerateArgs, ReviewArgs, SourceArgs, StatsArgs,
+    ThemeArg, ValidateArgs, ValidateFormatArg, VerifyArgs, WikiArgs,
 };
+use adrscope::domain::AdrFilter;
 use adrscope::infrastructure::fs::FileSystem;
 use adrscope::infrastructure::fs::test_support::InMemoryFileSystem;
+use time::macros::date;
 
 fn sample_adr(id: &str, title: &str, status: &str, category: &str) -> String {
     format!(
@@ -76,6 +79,37 @@ fn test_generate_single_adr() {
     assert!(output.contains("Use PostgreSQL"));
 }
 
+#[test]
+fn test_generate_with_date_filter() {
+    let fs = InMemoryFileSystem::new();
+    fs.add_file(
+        "docs/decisions/adr-0001.md",
+        sample_adr("adr-0001", "Use PostgreSQL", "accepted", "database")
+            .replace("2025-01-15", "2024-06-01"),
+    );
+    fs.add_file(
+        "docs/decisions/adr-0002.md",
+        sample_adr("adr-0002", "Use Redis", "accepted", "caching"),
+    );
+
+    let use_case = GenerateUseCase::new(fs.clone());
+    let filter = AdrFilter::new()
+        .with_since(date!(2025 - 01 - 01))
+        .with_until(date!(2025 - 12 - 31));
+    let options = GenerateOptions::new("docs/decisions")
+        .with_output("output.html")
+        .with_filter(filter);
+
+    let result = use_case.execute(&options).unwrap();
+    assert_eq!(result.adr_count, 1);
+
+    let output = fs
+        .read_to_string(std::path::Path::new("output.html"))
+        .unwrap();
+    assert!(output.contains("Use Redis"));
+    assert!(!output.contains("Use PostgreSQL"));
+}
+
 #[test]
 fn test_generate_multiple_adrs() {
     let fs = InMemoryFileSystem::new();
@@ -255,15 +289,33 @@ fn test_cli_generate_handler() {
 
     let cli = Cli {
         verbose: false,
+        config: None,
+        profile: None,
         command: Commands::Generate(GenerateArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            output: temp_dir.join("output.html").to_string_lossy().to_string(),
-            title: "Test ADRs".to_string(),
-            theme: ThemeArg::Auto,
-            pattern: "**/*.md".to_string(),
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            output: Some(temp_dir.join("output.html").to_string_lossy().to_string()),
+            title: Some("Test ADRs".to_string()),
+            theme: Some(ThemeArg::Auto),
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            filter: FilterArgs::default(),
+            reproducible: None,
+            embed_markdown: None,
+            embed_fields: None,
+            omit_fields: Vec::new(),
+            index: None,
+            base_url: None,
+            hash_filename: None,
+            header_html: None,
+            footer_html: None,
+            strict_offline: None,
+            notify_url: None,
+            cache: None,
         }),
     };
 
@@ -277,6 +329,111 @@ fn test_cli_generate_handler() {
     cleanup_temp_dir(&temp_dir);
 }
 
+#[test]
+fn test_cli_explicit_config_file() {
+    let temp_dir = create_temp_dir();
+    write_test_adr(
+        &temp_dir,
+        "adr-0001.md",
+        "Use PostgreSQL",
+        "accepted",
+        "database",
+    );
+
+    let config_path = temp_dir.join("ci.toml");
+    let config = format!(
+        "input = {:?}\n\n[generate]\noutput = {:?}\ntitle = \"Configured ADRs\"\n",
+        temp_dir.join("docs/decisions").to_string_lossy(),
+        temp_dir.join("configured.html").to_string_lossy(),
+    );
+    fs::write(&config_path, config).expect("Failed to write config");
+
+    let cli = Cli {
+        verbose: false,
+        config: Some(config_path),
+        profile: None,
+        command: Commands::Generate(GenerateArgs::default()),
+    };
+    assert_eq!(run(cli).expect("generate should succeed"), 0);
+
+    let html = fs::read_to_string(temp_dir.join("configured.html")).expect("viewer written");
+    assert!(html.contains("Configured ADRs"));
+
+    // A missing explicit configuration file is an error
+    let cli = Cli {
+        verbose: false,
+        config: Some(temp_dir.join("missing.toml")),
+        profile: None,
+        command: Commands::Generate(GenerateArgs::default()),
+    };
+    assert!(run(cli).is_err());
+
+    cleanup_temp_dir(&temp_dir);
+}
+
+#[test]
+fn test_cli_verify_handler() {
+    let temp_dir = create_temp_dir();
+    write_test_adr(
+        &temp_dir,
+        "adr-0001.md",
+        "Use PostgreSQL",
+        "accepted",
+        "database",
+    );
+    let output = temp_dir.join("output.html").to_string_lossy().to_string();
+
+    let generate = Cli {
+        verbose: false,
+        config: None,
+        profile: None,
+        command: Commands::Generate(GenerateArgs {
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            output: Some(output.clone()),
+            title: None,
+            theme: None,
+            pattern: None,
+            source: SourceArgs::default(),
+            filter: FilterArgs::default(),
+            reproducible: None,
+            embed_markdown: None,
+            embed_fields: None,
+            omit_fields: Vec::new(),
+            index: None,
+            base_url: None,
+            hash_filename: None,
+            header_html: None,
+            footer_html: None,
+            strict_offline: None,
+            notify_url: None,
+            cache: None,
+        }),
+    };
+    assert_eq!(run(generate).unwrap(), 0);
+
+    let verify = |file: String| Cli {
+        verbose: true,
+        config: None,
+        profile: None,
+        command: Commands::Verify(VerifyArgs { file: Some(file) }),
+    };
+    assert_eq!(run(verify(output)).unwrap(), 0);
+
+    // A file without viewer data is reported as incompatible
+    let adr = temp_dir
+        .join("docs/decisions/adr-0001.md")
+        .to_string_lossy()
+        .to_string();
+    assert_eq!(run(verify(adr)).unwrap(), 1);
+
+    cleanup_temp_dir(&temp_dir);
+}
+
 #[test]
 fn test_cli_generate_handler_verbose() {
     let temp_dir = create_temp_dir();
@@ -290,15 +447,33 @@ fn test_cli_generate_handler_verbose() {
 
     let cli = Cli {
         verbose: true,
+        config: None,
+        profile: None,
         command: Commands::Generate(GenerateArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            output: temp_dir.join("output.html").to_string_lossy().to_string(),
-            title: "Test ADRs".to_string(),
-            theme: ThemeArg::Light,
-            pattern: "**/*.md".to_string(),
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            output: Some(temp_dir.join("output.html").to_string_lossy().to_string()),
+            title: Some("Test ADRs".to_string()),
+            theme: Some(ThemeArg::Light),
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            filter: FilterArgs::default(),
+            reproducible: None,
+            embed_markdown: None,
+            embed_fields: None,
+            omit_fields: Vec::new(),
+            index: None,
+            base_url: None,
+            hash_filename: None,
+            header_html: None,
+            footer_html: None,
+            strict_offline: None,
+            notify_url: None,
+            cache: None,
         }),
     };
 
@@ -322,13 +497,27 @@ fn test_cli_validate_handler() {
 
     let cli = Cli {
         verbose: false,
+        config: None,
+        profile: None,
         command: Commands::Validate(ValidateArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            pattern: "**/*.md".to_string(),
-            strict: false,
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            strict: None,
+            schema: None,
+            max_proposed_age: None,
+            only: Vec::new(),
+            skip: Vec::new(),
+            format: ValidateFormatArg::Text,
+            files: Vec::new(),
+            changed_only: false,
+            filename_pattern: None,
+            report_file: Vec::new(),
         }),
     };
 
@@ -362,13 +551,27 @@ Content.
 
     let cli = Cli {
         verbose: true,
+        config: None,
+        profile: None,
         command: Commands::Validate(ValidateArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            pattern: "**/*.md".to_string(),
-            strict: true,
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            strict: Some(true),
+            schema: None,
+            max_proposed_age: None,
+            only: Vec::new(),
+            skip: Vec::new(),
+            format: ValidateFormatArg::Text,
+            files: Vec::new(),
+            changed_only: false,
+            filename_pattern: None,
+            report_file: Vec::new(),
         }),
     };
 
@@ -379,6 +582,108 @@ Content.
     cleanup_temp_dir(&temp_dir);
 }
 
+#[test]
+fn test_cli_boolean_env_values() {
+    let temp_dir = create_temp_dir();
+
+    // Write a minimal ADR without recommended fields
+    let decisions_dir = temp_dir.join("docs/decisions");
+    fs::create_dir_all(&decisions_dir).expect("Failed to create decisions directory");
+    fs::write(
+        decisions_dir.join("adr-0001.md"),
+        "---\ntitle: Minimal ADR\nstatus: proposed\n---\n\nContent.\n",
+    )
+    .expect("Failed to write test ADR");
+
+    let validate = |strict: &str| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_adrscope"))
+            .arg("validate")
+            .current_dir(&temp_dir)
+            .env("ADRSCOPE_STRICT", strict)
+            .output()
+            .expect("Failed to run adrscope")
+    };
+
+    // Warnings only fail validation in strict mode
+    for value in ["0", "false", "no", "off"] {
+        let output = validate(value);
+        assert_eq!(
+            output.status.code(),
+            Some(0),
+            "ADRSCOPE_STRICT={value}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    for value in ["1", "true", "yes", "on"] {
+        assert_eq!(
+            validate(value).status.code(),
+            Some(1),
+            "ADRSCOPE_STRICT={value}"
+        );
+    }
+
+    // A configured strict mode can be turned off for a single run
+    fs::write(
+        temp_dir.join("adrscope.toml"),
+        "[validate]\nstrict = true\n",
+    )
+    .expect("Failed to write config");
+    assert_eq!(validate("false").status.code(), Some(0));
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_adrscope"))
+        .args(["validate", "--strict=false"])
+        .current_dir(&temp_dir)
+        .output()
+        .expect("Failed to run adrscope");
+    assert_eq!(output.status.code(), Some(0));
+
+    cleanup_temp_dir(&temp_dir);
+}
+
+#[test]
+fn test_cli_custom_status_filter() {
+    let temp_dir = create_temp_dir();
+    write_test_adr(
+        &temp_dir,
+        "adr-0001.md",
+        "Use PostgreSQL",
+        "accepted",
+        "database",
+    );
+    write_test_adr(
+        &temp_dir,
+        "adr-0002.md",
+        "Use MongoDB",
+        "rejected",
+        "database",
+    );
+    fs::write(
+        temp_dir.join("adrscope.toml"),
+        "[[statuses]]\nname = \"rejected\"\nbase = \"deprecated\"\n",
+    )
+    .expect("Failed to write config");
+
+    let list = |status: &str| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_adrscope"))
+            .args(["list", "--status", status])
+            .current_dir(&temp_dir)
+            .output()
+            .expect("Failed to run adrscope")
+    };
+
+    // Configured statuses are matched under their own name, ignoring case
+    let output = list("Rejected");
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Use MongoDB"));
+    assert!(!stdout.contains("Use PostgreSQL"));
+
+    let output = list("on-hold");
+    assert_ne!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown status 'on-hold'"));
+
+    cleanup_temp_dir(&temp_dir);
+}
+
 #[test]
 fn test_cli_stats_handler() {
     let temp_dir = create_temp_dir();
@@ -393,13 +698,20 @@ fn test_cli_stats_handler() {
 
     let cli = Cli {
         verbose: false,
+        config: None,
+        profile: None,
         command: Commands::Stats(StatsArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            pattern: "**/*.md".to_string(),
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
             format: FormatArg::Text,
+            top: None,
+            full: false,
         }),
     };
 
@@ -423,13 +735,20 @@ fn test_cli_stats_handler_json_format() {
 
     let cli = Cli {
         verbose: true,
+        config: None,
+        profile: None,
         command: Commands::Stats(StatsArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            pattern: "**/*.md".to_string(),
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
             format: FormatArg::Json,
+            top: None,
+            full: false,
         }),
     };
 
@@ -453,13 +772,20 @@ fn test_cli_stats_handler_markdown_format() {
 
     let cli = Cli {
         verbose: false,
+        config: None,
+        profile: None,
         command: Commands::Stats(StatsArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            pattern: "**/*.md".to_string(),
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
             format: FormatArg::Markdown,
+            top: None,
+            full: false,
         }),
     };
 
@@ -470,6 +796,40 @@ fn test_cli_stats_handler_markdown_format() {
     cleanup_temp_dir(&temp_dir);
 }
 
+#[test]
+fn test_cli_review_handler() {
+    let temp_dir = create_temp_dir();
+    write_test_adr(
+        &temp_dir,
+        "adr-0001.md",
+        "Use PostgreSQL",
+        "accepted",
+        "database",
+    );
+
+    let cli = Cli {
+        verbose: true,
+        config: None,
+        profile: None,
+        command: Commands::Review(ReviewArgs {
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            date: Some(date!(2025 - 06 - 01)),
+        }),
+    };
+
+    let result = run(cli);
+    assert_eq!(result.unwrap(), 0);
+
+    cleanup_temp_dir(&temp_dir);
+}
+
 #[test]
 fn test_cli_wiki_handler() {
     let temp_dir = create_temp_dir();
@@ -485,14 +845,22 @@ fn test_cli_wiki_handler() {
 
     let cli = Cli {
         verbose: false,
+        config: None,
+        profile: None,
         command: Commands::Wiki(WikiArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            output: wiki_dir.to_string_lossy().to_string(),
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            output: Some(wiki_dir.to_string_lossy().to_string()),
             pages_url: Some("https://example.com/adrs".to_string()),
-            pattern: "**/*.md".to_string(),
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            filter: FilterArgs::default(),
+            top: None,
+            full: false,
         }),
     };
 
@@ -522,14 +890,22 @@ fn test_cli_wiki_handler_verbose_no_url() {
 
     let cli = Cli {
         verbose: true,
+        config: None,
+        profile: None,
         command: Commands::Wiki(WikiArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            output: wiki_dir.to_string_lossy().to_string(),
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            output: Some(wiki_dir.to_string_lossy().to_string()),
             pages_url: None,
-            pattern: "**/*.md".to_string(),
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            filter: FilterArgs::default(),
+            top: None,
+            full: false,
         }),
     };
 
@@ -548,12 +924,28 @@ fn test_cli_generate_no_adrs_error() {
 
     let cli = Cli {
         verbose: false,
+        config: None,
+        profile: None,
         command: Commands::Generate(GenerateArgs {
-            input: empty_dir.to_string_lossy().to_string(),
-            output: temp_dir.join("output.html").to_string_lossy().to_string(),
-            title: "Test ADRs".to_string(),
-            theme: ThemeArg::Auto,
-            pattern: "**/*.md".to_string(),
+            input: vec![empty_dir.to_string_lossy().to_string()],
+            output: Some(temp_dir.join("output.html").to_string_lossy().to_string()),
+            title: Some("Test ADRs".to_string()),
+            theme: Some(ThemeArg::Auto),
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            filter: FilterArgs::default(),
+            reproducible: None,
+            embed_markdown: None,
+            embed_fields: None,
+            omit_fields: Vec::new(),
+            index: None,
+            base_url: None,
+            hash_filename: None,
+            header_html: None,
+            footer_html: None,
+            strict_offline: None,
+            notify_url: None,
+            cache: None,
         }),
     };
 
@@ -592,15 +984,33 @@ status: accepted
 
     let cli = Cli {
         verbose: false,
+        config: None,
+        profile: None,
         command: Commands::Generate(GenerateArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            output: temp_dir.join("output.html").to_string_lossy().to_string(),
-            title: "Test ADRs".to_string(),
-            theme: ThemeArg::Auto,
-            pattern: "**/*.md".to_string(),
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            output: Some(temp_dir.join("output.html").to_string_lossy().to_string()),
+            title: Some("Test ADRs".to_string()),
+            theme: Some(ThemeArg::Auto),
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            filter: FilterArgs::default(),
+            reproducible: None,
+            embed_markdown: None,
+            embed_fields: None,
+            omit_fields: Vec::new(),
+            index: None,
+            base_url: None,
+            hash_filename: None,
+            header_html: None,
+            footer_html: None,
+            strict_offline: None,
+            notify_url: None,
+            cache: None,
         }),
     };
 
@@ -641,14 +1051,22 @@ invalid: yaml: here: broken
 
     let cli = Cli {
         verbose: false,
+        config: None,
+        profile: None,
         command: Commands::Wiki(WikiArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            output: wiki_dir.to_string_lossy().to_string(),
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            output: Some(wiki_dir.to_string_lossy().to_string()),
             pages_url: None,
-            pattern: "**/*.md".to_string(),
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            filter: FilterArgs::default(),
+            top: None,
+            full: false,
         }),
     };
 
@@ -687,13 +1105,20 @@ status: bad
 
     let cli = Cli {
         verbose: false,
+        config: None,
+        profile: None,
         command: Commands::Stats(StatsArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            pattern: "**/*.md".to_string(),
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
             format: FormatArg::Text,
+            top: None,
+            full: false,
         }),
     };
 
@@ -734,13 +1159,27 @@ malformed:
 
     let cli = Cli {
         verbose: false,
+        config: None,
+        profile: None,
         command: Commands::Validate(ValidateArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            pattern: "**/*.md".to_string(),
-            strict: false,
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            strict: None,
+            schema: None,
+            max_proposed_age: None,
+            only: Vec::new(),
+            skip: Vec::new(),
+            format: ValidateFormatArg::Text,
+            files: Vec::new(),
+            changed_only: false,
+            filename_pattern: None,
+            report_file: Vec::new(),
         }),
     };
 
@@ -772,13 +1211,27 @@ category: test
 
     let cli = Cli {
         verbose: false,
+        config: None,
+        profile: None,
         command: Commands::Validate(ValidateArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            pattern: "**/*.md".to_string(),
-            strict: false,
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            strict: None,
+            schema: None,
+            max_proposed_age: None,
+            only: Vec::new(),
+            skip: Vec::new(),
+            format: ValidateFormatArg::Text,
+            files: Vec::new(),
+            changed_only: false,
+            filename_pattern: None,
+            report_file: Vec::new(),
         }),
     };
 
@@ -960,15 +1413,33 @@ fn test_functional_generate_html_content() {
 
     let cli = Cli {
         verbose: false,
+        config: None,
+        profile: None,
         command: Commands::Generate(GenerateArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            output: output_path.to_string_lossy().to_string(),
-            title: "Test Project ADRs".to_string(),
-            theme: ThemeArg::Auto,
-            pattern: "**/*.md".to_string(),
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            output: Some(output_path.to_string_lossy().to_string()),
+            title: Some("Test Project ADRs".to_string()),
+            theme: Some(ThemeArg::Auto),
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            filter: FilterArgs::default(),
+            reproducible: None,
+            embed_markdown: None,
+            embed_fields: None,
+            omit_fields: Vec::new(),
+            index: None,
+            base_url: None,
+            hash_filename: None,
+            header_html: None,
+            footer_html: None,
+            strict_offline: None,
+            notify_url: None,
+            cache: None,
         }),
     };
 
@@ -1057,14 +1528,22 @@ fn test_functional_wiki_generates_all_files() {
 
     let cli = Cli {
         verbose: false,
+        config: None,
+        profile: None,
         command: Commands::Wiki(WikiArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            output: wiki_dir.to_string_lossy().to_string(),
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            output: Some(wiki_dir.to_string_lossy().to_string()),
             pages_url: Some("https://example.com/adrs".to_string()),
-            pattern: "**/*.md".to_string(),
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            filter: FilterArgs::default(),
+            top: None,
+            full: false,
         }),
     };
 
@@ -1205,13 +1684,20 @@ fn test_functional_stats_text_format() {
     // Test text format
     let cli = Cli {
         verbose: false,
+        config: None,
+        profile: None,
         command: Commands::Stats(StatsArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            pattern: "**/*.md".to_string(),
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
             format: FormatArg::Text,
+            top: None,
+            full: false,
         }),
     };
 
@@ -1407,15 +1893,33 @@ fn test_functional_generate_with_relationships() {
 
     let cli = Cli {
         verbose: false,
+        config: None,
+        profile: None,
         command: Commands::Generate(GenerateArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            output: output_path.to_string_lossy().to_string(),
-            title: "Relationship Test".to_string(),
-            theme: ThemeArg::Dark,
-            pattern: "**/*.md".to_string(),
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            output: Some(output_path.to_string_lossy().to_string()),
+            title: Some("Relationship Test".to_string()),
+            theme: Some(ThemeArg::Dark),
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            filter: FilterArgs::default(),
+            reproducible: None,
+            embed_markdown: None,
+            embed_fields: None,
+            omit_fields: Vec::new(),
+            index: None,
+            base_url: None,
+            hash_filename: None,
+            header_html: None,
+            footer_html: None,
+            strict_offline: None,
+            notify_url: None,
+            cache: None,
         }),
     };
 
@@ -1537,12 +2041,28 @@ This tests HTML escaping in < > & " characters.
 
     let cli = Cli {
         verbose: false,
+        config: None,
+        profile: None,
         command: Commands::Generate(GenerateArgs {
-            input: decisions_dir.to_string_lossy().to_string(),
-            output: output_path.to_string_lossy().to_string(),
-            title: "Edge Cases Test".to_string(),
-            theme: ThemeArg::Auto,
-            pattern: "**/*.md".to_string(),
+            input: vec![decisions_dir.to_string_lossy().to_string()],
+            output: Some(output_path.to_string_lossy().to_string()),
+            title: Some("Edge Cases Test".to_string()),
+            theme: Some(ThemeArg::Auto),
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            filter: FilterArgs::default(),
+            reproducible: None,
+            embed_markdown: None,
+            embed_fields: None,
+            omit_fields: Vec::new(),
+            index: None,
+            base_url: None,
+            hash_filename: None,
+            header_html: None,
+            footer_html: None,
+            strict_offline: None,
+            notify_url: None,
+            cache: None,
         }),
     };
 
@@ -1608,15 +2128,33 @@ fn test_functional_large_adr_collection() {
 
     let cli = Cli {
         verbose: false,
+        config: None,
+        profile: None,
         command: Commands::Generate(GenerateArgs {
-            input: temp_dir
-                .join("docs/decisions")
-                .to_string_lossy()
-                .to_string(),
-            output: output_path.to_string_lossy().to_string(),
-            title: "Large Collection Test".to_string(),
-            theme: ThemeArg::Auto,
-            pattern: "**/*.md".to_string(),
+            input: vec![
+                temp_dir
+                    .join("docs/decisions")
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            output: Some(output_path.to_string_lossy().to_string()),
+            title: Some("Large Collection Test".to_string()),
+            theme: Some(ThemeArg::Auto),
+            pattern: Some("**/*.md".to_string()),
+            source: SourceArgs::default(),
+            filter: FilterArgs::default(),
+            reproducible: None,
+            embed_markdown: None,
+            embed_fields: None,
+            omit_fields: Vec::new(),
+            index: None,
+            base_url: None,
+            hash_filename: None,
+            header_html: None,
+            footer_html: None,
+            strict_offline: None,
+            notify_url: None,
+            cache: None,
         }),
     };
 